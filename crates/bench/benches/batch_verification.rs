@@ -0,0 +1,95 @@
+use std::str::FromStr;
+
+use ark_ff::UniformRand;
+use decaf377::{Fq, Fr};
+use decaf377_fmd as fmd;
+use decaf377_ka as ka;
+use penumbra_asset::{balance, Balance, Value};
+use penumbra_keys::{keys::Diversifier, Address};
+use penumbra_proof_params::{
+    BatchVerifier, OUTPUT_PROOF_PROVING_KEY, OUTPUT_PROOF_VERIFICATION_KEY,
+};
+use penumbra_shielded_pool::{note, Note, OutputProof, Rseed};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand_core::OsRng;
+
+fn output_proofs(n: usize) -> Vec<(OutputProof, balance::Commitment, note::StateCommitment)> {
+    let diversifier_bytes = [1u8; 16];
+    let pk_d_bytes = decaf377::basepoint().vartime_compress().0;
+    let clue_key_bytes = [1; 32];
+    let diversifier = Diversifier(diversifier_bytes);
+    let address = Address::from_components(
+        diversifier,
+        ka::Public(pk_d_bytes),
+        fmd::ClueKey(clue_key_bytes),
+    )
+    .expect("generated 1 address");
+    let value_to_send = Value::from_str("1upenumbra").expect("valid value");
+
+    (0..n)
+        .map(|i| {
+            let note = Note::from_parts(address, value_to_send, Rseed([i as u8; 32]))
+                .expect("can make a note");
+            let v_blinding = Fr::rand(&mut OsRng);
+            let balance_commitment = (-Balance::from(value_to_send)).commit(v_blinding);
+            let note_commitment = note.commit();
+            let proof = OutputProof::prove(
+                Fq::rand(&mut OsRng),
+                Fq::rand(&mut OsRng),
+                &OUTPUT_PROOF_PROVING_KEY,
+                note,
+                v_blinding,
+                balance_commitment,
+                note_commitment,
+            )
+            .expect("can generate proof");
+            (proof, balance_commitment, note_commitment)
+        })
+        .collect()
+}
+
+fn output_verification_time(c: &mut Criterion) {
+    let mut group = c.benchmark_group("output verification");
+    let proofs = output_proofs(32);
+
+    for n in [1, 2, 4, 8, 16, 32] {
+        let proofs = &proofs[..n];
+
+        group.bench_with_input(BenchmarkId::new("individual", n), proofs, |b, proofs| {
+            b.iter(|| {
+                for (proof, balance_commitment, note_commitment) in proofs {
+                    proof
+                        .verify(
+                            &OUTPUT_PROOF_VERIFICATION_KEY,
+                            *balance_commitment,
+                            *note_commitment,
+                        )
+                        .expect("proof verifies");
+                }
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("batch", n), proofs, |b, proofs| {
+            b.iter(|| {
+                let mut batch = BatchVerifier::new();
+                for (proof, balance_commitment, note_commitment) in proofs {
+                    proof
+                        .queue(
+                            &mut batch,
+                            &OUTPUT_PROOF_VERIFICATION_KEY,
+                            *balance_commitment,
+                            *note_commitment,
+                        )
+                        .expect("can queue proof");
+                }
+                batch.verify(&mut OsRng).expect("proofs verify");
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, output_verification_time);
+criterion_main!(benches);
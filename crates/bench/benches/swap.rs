@@ -102,12 +102,14 @@ fn swap_proving_time(c: &mut Criterion) {
     });
 
     // Also print out the number of constraints.
+    let min_output = swap_plaintext.min_output;
     let circuit = SwapCircuit::new(
         swap_plaintext,
         Fr::from(0u64),
         balance_commitment,
         swap_commitment,
         fee_commitment,
+        min_output,
     );
 
     let cs = ConstraintSystem::new_ref();
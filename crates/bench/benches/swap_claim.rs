@@ -11,7 +11,7 @@ use penumbra_dex::{
 };
 use penumbra_fee::Fee;
 use penumbra_keys::keys::{Bip44Path, NullifierKey, SeedPhrase, SpendKey};
-use penumbra_num::Amount;
+use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_proof_params::SWAPCLAIM_PROOF_PROVING_KEY;
 use penumbra_sct::Nullifier;
 use penumbra_tct as tct;
@@ -101,8 +101,11 @@ fn swap_claim_proving_time(c: &mut Criterion) {
         height: height.into(),
         trading_pair: swap_plaintext.trading_pair,
         epoch_starting_height: (epoch_duration * position.epoch()).into(),
+        limit_price_1: U128x128::default(),
+        limit_price_2: U128x128::default(),
     };
-    let (lambda_1, lambda_2) = output_data.pro_rata_outputs((delta_1_i, delta_2_i));
+    let (lambda_1, lambda_2) =
+        output_data.swap_outputs((delta_1_i, delta_2_i), swap_plaintext.min_output);
 
     let (output_rseed_1, output_rseed_2) = swap_plaintext.output_rseeds();
     let note_blinding_1 = output_rseed_1.derive_note_blinding();
@@ -2,7 +2,11 @@ use std::{io::Read, str::FromStr};
 
 use anyhow::Result;
 use camino::Utf8PathBuf;
-use penumbra_custody::threshold;
+use dialoguer::Confirm;
+use penumbra_custody::{
+    soft_kms::{self, SpendKeyStore},
+    threshold,
+};
 use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
 use rand_core::OsRng;
 use url::Url;
@@ -33,7 +37,7 @@ pub struct InitCmd {
 #[derive(Debug, clap::Subcommand)]
 pub enum InitSubCmd {
     /// Initialize `pcli` with a basic, file-based custody backend.
-    #[clap(subcommand, display_order = 100)]
+    #[clap(display_order = 100)]
     SoftKms(SoftKmsInitCmd),
     /// Initialize `pcli` with a manual threshold signing backend.
     #[clap(subcommand, display_order = 150)]
@@ -44,13 +48,26 @@ pub enum InitSubCmd {
         /// The full viewing key for the wallet to view.
         full_viewing_key: String,
     },
+    /// Encrypt the plaintext spend key of an existing soft KMS config in place.
+    #[clap(display_order = 800)]
+    EncryptSpendKey {},
     /// Wipe all `pcli` configuration and data, INCLUDING KEYS.
     #[clap(display_order = 900)]
     UnsafeWipe {},
 }
 
+#[derive(Debug, clap::Parser)]
+pub struct SoftKmsInitCmd {
+    /// Encrypt the spend key with a passphrase, which will be prompted for
+    /// whenever the key is needed.
+    #[clap(long, action)]
+    encrypted: bool,
+    #[clap(subcommand)]
+    subcmd: SoftKmsInitSubCmd,
+}
+
 #[derive(Debug, clap::Subcommand)]
-pub enum SoftKmsInitCmd {
+pub enum SoftKmsInitSubCmd {
     /// Generate a new seed phrase and import its corresponding key.
     #[clap(display_order = 100)]
     Generate,
@@ -68,9 +85,19 @@ pub enum SoftKmsInitCmd {
 }
 
 impl SoftKmsInitCmd {
+    fn spend_key_store(&self) -> Result<SpendKeyStore> {
+        let mut spend_key: SpendKeyStore = self.subcmd.spend_key()?.into();
+        if self.encrypted {
+            soft_kms::encrypt_spend_key(&mut spend_key)?;
+        }
+        Ok(spend_key)
+    }
+}
+
+impl SoftKmsInitSubCmd {
     fn spend_key(&self) -> Result<SpendKey> {
         Ok(match self {
-            SoftKmsInitCmd::Generate => {
+            SoftKmsInitSubCmd::Generate => {
                 let seed_phrase = SeedPhrase::generate(OsRng);
 
                 // xxx: Something better should be done here, this is in danger of being
@@ -82,7 +109,7 @@ impl SoftKmsInitCmd {
                 let path = Bip44Path::new(0);
                 SpendKey::from_seed_phrase_bip44(seed_phrase, &path)
             }
-            SoftKmsInitCmd::ImportPhrase {
+            SoftKmsInitSubCmd::ImportPhrase {
                 legacy_raw_bip39_derivation,
             } => {
                 let mut seed_phrase = String::new();
@@ -131,6 +158,9 @@ pub enum ThresholdInitCmd {
         /// total number of signers (one for each --home).
         #[clap(long, value_delimiter = ' ', multiple_values = true)]
         home: Vec<Utf8PathBuf>,
+        /// A relay that the signers will use to run the signing protocol.
+        #[clap(long)]
+        relay: Option<Url>,
     },
     /// Generate a config file without using a trusted dealer.
     Dkg {
@@ -140,10 +170,25 @@ pub enum ThresholdInitCmd {
         /// The maximum number of signers that can make a signature
         #[clap(short, long)]
         num_participants: u16,
+        /// Run the DKG through this relay, instead of pasting messages by hand.
+        ///
+        /// The relay will also be used to run the signing protocol later on.
+        #[clap(long, requires = "session")]
+        relay: Option<Url>,
+        /// A name for the DKG, agreed on by all the participants beforehand.
+        ///
+        /// This is used to find the other participants on the relay.
+        #[clap(long)]
+        session: Option<String>,
     },
 }
 
-fn exec_deal(threshold: u16, home: Vec<Utf8PathBuf>, grpc_url: Url) -> Result<()> {
+fn exec_deal(
+    threshold: u16,
+    home: Vec<Utf8PathBuf>,
+    grpc_url: Url,
+    relay: Option<Url>,
+) -> Result<()> {
     if threshold < 2 {
         anyhow::bail!("threshold must be >= 2");
     }
@@ -159,6 +204,7 @@ fn exec_deal(threshold: u16, home: Vec<Utf8PathBuf>, grpc_url: Url) -> Result<()
             grpc_url: grpc_url.clone(),
             view_url: None,
             disable_warning: false,
+            threshold_relay_url: relay.clone(),
         };
         println!("  Writing signer {} config to {}", i, path);
         std::fs::create_dir_all(path)?;
@@ -167,10 +213,38 @@ fn exec_deal(threshold: u16, home: Vec<Utf8PathBuf>, grpc_url: Url) -> Result<()
     Ok(())
 }
 
+fn exec_encrypt_spend_key(home_dir: &camino::Utf8Path) -> Result<()> {
+    let config_path = home_dir.join(crate::CONFIG_FILE_NAME);
+    let mut config = PcliConfig::load(&config_path)?;
+
+    let CustodyConfig::SoftKms(soft_kms_config) = &mut config.custody else {
+        anyhow::bail!("only soft KMS configs have a spend key to encrypt");
+    };
+    if soft_kms_config.spend_key.is_encrypted() {
+        anyhow::bail!("the spend key in {} is already encrypted", config_path);
+    }
+    soft_kms::encrypt_spend_key(&mut soft_kms_config.spend_key)?;
+
+    println!("Writing encrypted spend key to {}", config_path);
+    config.save(&config_path)?;
+
+    Ok(())
+}
+
 impl InitCmd {
     pub async fn exec(&self, home_dir: impl AsRef<camino::Utf8Path>) -> Result<()> {
-        if let InitSubCmd::Threshold(ThresholdInitCmd::Deal { threshold, home }) = &self.subcmd {
-            exec_deal(threshold.clone(), home.clone(), self.grpc_url.clone())?;
+        if let InitSubCmd::Threshold(ThresholdInitCmd::Deal {
+            threshold,
+            home,
+            relay,
+        }) = &self.subcmd
+        {
+            exec_deal(
+                threshold.clone(),
+                home.clone(),
+                self.grpc_url.clone(),
+                relay.clone(),
+            )?;
             return Ok(());
         }
         let home_dir = home_dir.as_ref();
@@ -181,6 +255,9 @@ impl InitCmd {
                 std::fs::remove_dir_all(home_dir)?;
                 return Ok(());
             }
+            InitSubCmd::EncryptSpendKey {} => {
+                return exec_encrypt_spend_key(home_dir);
+            }
             _ => {
                 // Check that the data_dir is empty before running init:
                 if home_dir.exists() && home_dir.read_dir()?.next().is_some() {
@@ -192,28 +269,63 @@ impl InitCmd {
             }
         }
 
-        let (full_viewing_key, custody) = match &self.subcmd {
-            InitSubCmd::UnsafeWipe {} => unreachable!("this case is handled above"),
+        let (full_viewing_key, custody, threshold_relay_url) = match &self.subcmd {
+            InitSubCmd::UnsafeWipe {} | InitSubCmd::EncryptSpendKey {} => {
+                unreachable!("this case is handled above")
+            }
             InitSubCmd::SoftKms(cmd) => {
-                let spend_key = cmd.spend_key()?;
+                let spend_key = cmd.spend_key_store()?;
                 (
                     spend_key.full_viewing_key().clone(),
                     CustodyConfig::SoftKms(spend_key.into()),
+                    None,
                 )
             }
             InitSubCmd::Threshold(ThresholdInitCmd::Dkg {
                 threshold,
                 num_participants,
+                relay,
+                session,
             }) => {
-                let config = threshold::dkg(*threshold, *num_participants, &ActualTerminal).await?;
-                (config.fvk().clone(), CustodyConfig::Threshold(config))
+                let config = match (relay, session) {
+                    (Some(relay_url), Some(session)) => {
+                        let channel =
+                            tonic::transport::Endpoint::new(relay_url.to_string())?.connect_lazy();
+                        let terminal =
+                            threshold::NetworkTerminal::dkg(channel, session, ActualTerminal);
+                        println!("Running DKG session {:?} through {}...", session, relay_url);
+                        threshold::dkg(*threshold, *num_participants, &terminal).await?
+                    }
+                    _ => threshold::dkg(*threshold, *num_participants, &ActualTerminal).await?,
+                };
+                // Nothing authenticated the other participants during the DKG, so
+                // they must check that they all got the same result out of band.
+                println!(
+                    "DKG complete. Check with each of the other {} participants that they got this fingerprint:\n\n    {}\n",
+                    num_participants.saturating_sub(1),
+                    config.fingerprint()
+                );
+                if !Confirm::new()
+                    .with_prompt("Did every participant get the same fingerprint?")
+                    .default(false)
+                    .interact()?
+                {
+                    anyhow::bail!(
+                        "DKG fingerprint was not confirmed; refusing to write the config"
+                    );
+                }
+                (
+                    config.fvk().clone(),
+                    CustodyConfig::Threshold(config),
+                    relay.clone(),
+                )
             }
             InitSubCmd::Threshold(ThresholdInitCmd::Deal { .. }) => {
                 panic!("this should already have been handled above")
             }
             InitSubCmd::ViewOnly { full_viewing_key } => {
                 let full_viewing_key = full_viewing_key.parse()?;
-                (full_viewing_key, CustodyConfig::ViewOnly)
+                (full_viewing_key, CustodyConfig::ViewOnly, None)
             }
         };
 
@@ -223,6 +335,7 @@ impl InitCmd {
             grpc_url: self.grpc_url.clone(),
             view_url: None,
             disable_warning: false,
+            threshold_relay_url,
         };
 
         // Create the config directory, if
@@ -37,6 +37,11 @@ pub enum QueryCmd {
     Key {
         /// The key to query.
         key: String,
+        /// The height to query the key at, rather than the latest height.
+        ///
+        /// Only recent heights are retained by the node.
+        #[clap(long)]
+        height: Option<u64>,
     },
     /// Queries shielded pool data.
     #[clap(subcommand)]
@@ -94,7 +99,7 @@ impl QueryCmd {
             return ibc.exec(app).await;
         }
 
-        let key = match self {
+        let (key, height) = match self {
             QueryCmd::Tx(_)
             | QueryCmd::Chain(_)
             | QueryCmd::Validator(_)
@@ -104,8 +109,8 @@ impl QueryCmd {
             | QueryCmd::Ibc(_) => {
                 unreachable!("query handled in guard");
             }
-            QueryCmd::ShieldedPool(p) => p.key().clone(),
-            QueryCmd::Key { key } => key.clone(),
+            QueryCmd::ShieldedPool(p) => (p.key().clone(), None),
+            QueryCmd::Key { key, height } => (key.clone(), *height),
         };
 
         use penumbra_proto::storage::v1alpha1::query_service_client::QueryServiceClient;
@@ -113,6 +118,7 @@ impl QueryCmd {
 
         let req = penumbra_proto::storage::v1alpha1::KeyValueRequest {
             key: key.clone(),
+            height,
             ..Default::default()
         };
 
@@ -97,8 +97,19 @@ impl ChainCmd {
                 &format!("{}", params.stake_params.missed_blocks_maximum),
             ])
             .add_row(vec![
-                "Proposal Deposit Amount (upenumbra)",
-                &format!("{}", params.governance_params.proposal_deposit_amount),
+                "Jail Duration (blocks)",
+                &format!("{}", params.stake_params.jail_duration_blocks),
+            ])
+            .add_row(vec![
+                "Max Commission Change per Epoch (bps)",
+                &format!(
+                    "{}",
+                    params.stake_params.max_commission_change_bps_per_epoch
+                ),
+            ])
+            .add_row(vec![
+                "Commission Increase Notice (epochs)",
+                &format!("{}", params.stake_params.commission_increase_notice_epochs),
             ])
             .add_row(vec![
                 "IBC Enabled",
@@ -4,13 +4,20 @@ use std::{
 };
 
 use anyhow::{Context, Result};
+use comfy_table::{presets, Table};
 use futures::TryStreamExt;
-use penumbra_governance::Vote;
-use penumbra_proto::core::component::governance::v1alpha1::{
-    query_service_client::QueryServiceClient as GovernanceQueryServiceClient,
-    AllTalliedDelegatorVotesForProposalRequest, ProposalDataRequest, ProposalListRequest,
-    ProposalListResponse, ValidatorVotesRequest, ValidatorVotesResponse,
-    VotingPowerAtProposalStartRequest,
+use penumbra_app::params::AppParameters;
+use penumbra_governance::{ProposalKind, Vote};
+use penumbra_proto::core::{
+    app::v1alpha1::{
+        query_service_client::QueryServiceClient as AppQueryServiceClient, AppParametersRequest,
+    },
+    component::governance::v1alpha1::{
+        query_service_client::QueryServiceClient as GovernanceQueryServiceClient,
+        AllTalliedDelegatorVotesForProposalRequest, ProposalDataRequest, ProposalListRequest,
+        ProposalListResponse, ValidatorVotesRequest, ValidatorVotesResponse,
+        VotingPowerAtProposalStartRequest,
+    },
 };
 use penumbra_stake::IdentityKey;
 use serde::Serialize;
@@ -34,6 +41,8 @@ pub enum GovernanceCmd {
         #[clap(subcommand)]
         query: PerProposalCmd,
     },
+    /// Display the governance parameters for each kind of proposal.
+    Parameters,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -85,6 +94,43 @@ impl GovernanceCmd {
                 }
                 Ok(())
             }
+            GovernanceCmd::Parameters => {
+                let mut app_client = AppQueryServiceClient::new(app.pd_channel().await?);
+                let params: AppParameters = app_client
+                    .app_parameters(AppParametersRequest {
+                        ..Default::default()
+                    })
+                    .await?
+                    .into_inner()
+                    .app_parameters
+                    .ok_or_else(|| anyhow::anyhow!("empty AppParametersResponse message"))?
+                    .try_into()?;
+                let params = params.governance_params;
+
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec![
+                    "Proposal Kind",
+                    "Voting Blocks",
+                    "Deposit (upenumbra)",
+                    "Valid Quorum",
+                    "Pass Threshold",
+                    "Slash Threshold",
+                ]);
+                for kind in ProposalKind::ALL {
+                    let kind_params = params.for_kind(kind);
+                    table.add_row(vec![
+                        kind.to_string(),
+                        kind_params.voting_blocks.to_string(),
+                        kind_params.deposit_amount.to_string(),
+                        kind_params.valid_quorum.to_string(),
+                        kind_params.pass_threshold.to_string(),
+                        kind_params.slash_threshold.to_string(),
+                    ]);
+                }
+                println!("{table}");
+                Ok(())
+            }
             GovernanceCmd::Proposal { proposal_id, query } => {
                 match query {
                     &PerProposalCmd::Definition => {
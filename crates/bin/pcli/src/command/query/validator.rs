@@ -1,10 +1,11 @@
-use std::{fs::File, io::Write};
+use std::{collections::BTreeMap, fs::File, io::Write};
 
 use anyhow::{Context, Result};
 use comfy_table::{presets, Table};
 use futures::TryStreamExt;
 use penumbra_proto::core::component::stake::v1alpha1::{
-    query_service_client::QueryServiceClient as StakeQueryServiceClient, ValidatorInfoRequest,
+    query_service_client::QueryServiceClient as StakeQueryServiceClient,
+    PendingValidatorDefinitionsRequest, ValidatorInfoRequest,
 };
 use penumbra_stake::{
     validator::{self, ValidatorToml},
@@ -57,6 +58,25 @@ impl ValidatorCmd {
                     .map(TryInto::try_into)
                     .collect::<Result<Vec<validator::Info>, _>>()?;
 
+                // Upcoming commission changes, by validator.
+                let pending_definitions = client
+                    .pending_validator_definitions(PendingValidatorDefinitionsRequest {
+                        ..Default::default()
+                    })
+                    .await?
+                    .into_inner()
+                    .try_collect::<Vec<_>>()
+                    .await?
+                    .into_iter()
+                    .map(|rsp| {
+                        let pending: validator::PendingDefinition = rsp
+                            .pending_definition
+                            .ok_or_else(|| anyhow::anyhow!("missing pending definition"))?
+                            .try_into()?;
+                        Ok((pending.identity_key, pending))
+                    })
+                    .collect::<Result<BTreeMap<IdentityKey, validator::PendingDefinition>>>()?;
+
                 // Sort by voting power (descending), active first, then inactive
                 validators.sort_by(|a, b| {
                     let av = if matches!(a.status.state, validator::State::Active) {
@@ -104,13 +124,18 @@ impl ValidatorCmd {
                         0.0
                     };
                     let power_percent = 100.0 * active_voting_power / total_voting_power;
-                    let commission_bps = v
-                        .validator
-                        .funding_streams
-                        .as_ref()
-                        .iter()
-                        .map(|fs| fs.rate_bps())
-                        .sum::<u16>();
+                    let commission_bps = v.validator.funding_streams.commission_bps();
+                    // Show scheduled commission changes below the current commission.
+                    let pending_commission = pending_definitions
+                        .get(&v.validator.identity_key)
+                        .map(|p| {
+                            format!(
+                                "→ {}bps (epoch {})",
+                                p.funding_streams.commission_bps(),
+                                p.effective_epoch_index
+                            )
+                        })
+                        .unwrap_or_default();
 
                     table.add_row(vec![
                         format!("{voting_power:.3}"),
@@ -125,7 +150,7 @@ impl ValidatorCmd {
                     table.add_row(vec![
                         "".into(),
                         "".into(),
-                        "".into(),
+                        pending_commission,
                         "".into(),
                         "".into(),
                         format!("  \x1b[1;92m{}\x1b[0m", v.validator.name),
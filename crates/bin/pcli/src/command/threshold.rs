@@ -1,30 +1,92 @@
-use anyhow::Result;
+use std::net::SocketAddr;
+
+use anyhow::{Context, Result};
+use ed25519_consensus::VerificationKey;
+use penumbra_custody::threshold::{NetworkTerminal, Relay};
+use penumbra_proto::custody::threshold::v1alpha1::threshold_relay_service_server::ThresholdRelayServiceServer;
+use url::Url;
 
 use crate::{terminal::ActualTerminal, App};
 
 #[derive(Debug, clap::Subcommand)]
 pub enum ThresholdCmd {
     /// Contribute to signing a transaction with threshold custody
-    Sign,
+    Sign {
+        /// Run the signing protocol through this relay, instead of pasting messages by hand.
+        ///
+        /// Defaults to the relay in the config file, if any.
+        #[clap(long)]
+        relay: Option<Url>,
+    },
+    /// Run a relay for the threshold protocols, so participants don't have to pass messages by hand.
+    ///
+    /// This doesn't require any keys, since the relay only forwards signed messages.
+    Relay {
+        /// The address to listen on.
+        #[clap(long, default_value = "127.0.0.1:8086")]
+        bind: SocketAddr,
+        /// Only allow the participant with this hex-encoded verification key to use the relay.
+        ///
+        /// Can be given several times. By default, anyone can use the relay.
+        #[clap(long)]
+        participant: Vec<String>,
+    },
 }
 
 impl ThresholdCmd {
     pub fn offline(&self) -> bool {
         match self {
-            ThresholdCmd::Sign => true,
+            ThresholdCmd::Sign { .. } => true,
+            ThresholdCmd::Relay { .. } => true,
+        }
+    }
+
+    /// Run the relay, which doesn't need a config, unlike the other commands.
+    pub async fn exec_relay(bind: SocketAddr, participants: &[String]) -> Result<()> {
+        let mut relay = Relay::new();
+        if !participants.is_empty() {
+            let participants = participants
+                .iter()
+                .map(|x| {
+                    let bytes = hex::decode(x)?;
+                    Ok(VerificationKey::try_from(bytes.as_slice())?)
+                })
+                .collect::<Result<Vec<_>>>()
+                .context("invalid participant verification key")?;
+            relay = relay.with_participants(participants);
         }
+
+        println!("Running threshold relay on {}", bind);
+        tonic::transport::Server::builder()
+            .add_service(ThresholdRelayServiceServer::new(relay))
+            .serve(bind)
+            .await?;
+        Ok(())
     }
 
     #[tracing::instrument(skip(self, app))]
     pub async fn exec(&self, app: &mut App) -> Result<()> {
+        if let ThresholdCmd::Relay { bind, participant } = self {
+            return Self::exec_relay(*bind, participant).await;
+        }
         let config = match &app.config.custody {
             crate::config::CustodyConfig::Threshold(config) => config,
             _ => anyhow::bail!("this command can only be used with the threshold custody backend"),
         };
         match self {
-            ThresholdCmd::Sign => {
-                penumbra_custody::threshold::follow(config, &ActualTerminal).await
+            ThresholdCmd::Sign { relay } => {
+                match relay.as_ref().or(app.config.threshold_relay_url.as_ref()) {
+                    Some(relay_url) => {
+                        let channel =
+                            tonic::transport::Endpoint::new(relay_url.to_string())?.connect_lazy();
+                        let terminal = NetworkTerminal::follower(channel, config, ActualTerminal);
+                        println!("Waiting for a signing request through {}...", relay_url);
+                        penumbra_custody::threshold::follow(config, &terminal).await
+                    }
+                    None => penumbra_custody::threshold::follow(config, &ActualTerminal).await,
+                }
             }
+            ThresholdCmd::Relay { .. } => unreachable!("relay command already executed"),
         }
     }
 }
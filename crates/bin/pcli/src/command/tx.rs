@@ -15,11 +15,11 @@ use regex::Regex;
 
 use liquidity_position::PositionCmd;
 use penumbra_asset::{asset, asset::DenomMetadata, Value, STAKING_TOKEN_ASSET_ID};
-use penumbra_dex::{lp::position, swap_claim::SwapClaimPlan};
+use penumbra_dex::{lp::position, swap_claim::SwapClaimPlan, SwapExecution};
 use penumbra_fee::Fee;
 use penumbra_governance::{proposal::ProposalToml, proposal_state::State as ProposalState, Vote};
 use penumbra_keys::keys::AddressIndex;
-use penumbra_num::Amount;
+use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_proto::{
     core::component::{
         chain::v1alpha1::{
@@ -28,7 +28,9 @@ use penumbra_proto::{
         },
         dex::v1alpha1::{
             query_service_client::QueryServiceClient as DexQueryServiceClient,
-            LiquidityPositionByIdRequest, PositionId,
+            simulate_trade_request::{routing::Setting, Routing},
+            simulation_service_client::SimulationServiceClient,
+            LiquidityPositionByIdRequest, PositionId, SimulateTradeRequest,
         },
         governance::v1alpha1::{
             query_service_client::QueryServiceClient as GovernanceQueryServiceClient,
@@ -44,8 +46,11 @@ use penumbra_proto::{
 };
 use penumbra_shielded_pool::Ics20Withdrawal;
 use penumbra_stake::rate::RateData;
-use penumbra_stake::{DelegationToken, IdentityKey, Penalty, UnbondingToken, UndelegateClaimPlan};
-use penumbra_transaction::{gas::swap_claim_gas_cost, memo::MemoPlaintext};
+use penumbra_stake::{
+    DelegationToken, IdentityKey, Penalty, RedelegateClaim, RedelegationToken, UnbondingToken,
+    UndelegateClaimPlan,
+};
+use penumbra_transaction::{gas::swap_claim_gas_cost, memo::MemoPlaintext, PaymentRequest};
 use penumbra_view::ViewClient;
 use penumbra_wallet::plan::{self, Planner};
 use proposal::ProposalCmd;
@@ -73,6 +78,18 @@ pub enum TxCmd {
         #[clap(long)]
         memo: Option<String>,
     },
+    /// Pay a payment request, as created by `pcli view payment-request create`.
+    ///
+    /// This sends the requested values to the request's address, with a memo
+    /// referring to the request so that the payee can match the payment to it.
+    #[clap(display_order = 110)]
+    Pay {
+        /// The payment request to pay, starting with `penumbrapaymentrequest1`.
+        payment_request: String,
+        /// Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0", display_order = 300)]
+        source: u32,
+    },
     /// Deposit stake into a validator's delegation pool.
     #[clap(display_order = 200)]
     Delegate {
@@ -94,7 +111,22 @@ pub enum TxCmd {
         #[clap(long, default_value = "0", display_order = 300)]
         source: u32,
     },
-    /// Claim any undelegations that have finished unbonding.
+    /// Move stake from one validator's delegation pool to another's, without unbonding.
+    ///
+    /// The redelegated stake remains liable for slashing of the original validator
+    /// until the unbonding period an undelegation would have had ends.
+    #[clap(display_order = 200)]
+    Redelegate {
+        /// The identity key of the validator to redelegate to.
+        #[clap(long, display_order = 100)]
+        to: String,
+        /// The amount of delegation tokens to redelegate.
+        amount: String,
+        /// Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0", display_order = 300)]
+        source: u32,
+    },
+    /// Claim any undelegations and redelegations that have finished unbonding.
     #[clap(display_order = 200)]
     UndelegateClaim {},
     /// Swap tokens of one denomination for another using the DEX.
@@ -112,6 +144,15 @@ pub enum TxCmd {
         /// The denomination to swap the input into, e.g. `gm`
         #[clap(long, display_order = 100)]
         into: String,
+        /// The minimum output of the swap, written as a typed value 1.87penumbra, 12cubes, etc.
+        /// If the batch can't output at least this much, the input is refunded instead.
+        #[clap(long, display_order = 200, conflicts_with = "max_slippage")]
+        min_output: Option<String>,
+        /// The maximum slippage, in percent, below the output of a simulated
+        /// trade at the current prices. If the batch outputs less, the input
+        /// is refunded instead.
+        #[clap(long, display_order = 250)]
+        max_slippage: Option<f64>,
         /// Only spend funds originally received by the given account.
         #[clap(long, default_value = "0", display_order = 300)]
         source: u32,
@@ -189,6 +230,11 @@ pub enum TxCmd {
         /// Only withdraw funds from the specified wallet id within Penumbra.
         #[clap(long, default_value = "0", display_order = 200)]
         source: u32,
+
+        /// Optional. Attach the provided text as the transfer's memo, e.g. packet-forwarding
+        /// instructions for the counterparty chain.
+        #[clap(long, display_order = 250)]
+        memo: Option<String>,
     },
 }
 
@@ -233,10 +279,12 @@ impl TxCmd {
     pub fn offline(&self) -> bool {
         match self {
             TxCmd::Send { .. } => false,
+            TxCmd::Pay { .. } => false,
             TxCmd::Sweep { .. } => false,
             TxCmd::Swap { .. } => false,
             TxCmd::Delegate { .. } => false,
             TxCmd::Undelegate { .. } => false,
+            TxCmd::Redelegate { .. } => false,
             TxCmd::UndelegateClaim { .. } => false,
             TxCmd::Vote { .. } => false,
             TxCmd::Proposal(proposal_cmd) => proposal_cmd.offline(),
@@ -302,6 +350,42 @@ impl TxCmd {
                     .context("can't build send transaction")?;
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::Pay {
+                payment_request,
+                source,
+            } => {
+                let payment_request: PaymentRequest = payment_request
+                    .parse()
+                    .context("payment request is invalid")?;
+                payment_request.verify_invoice()?;
+
+                let wallet_id = app.config.full_viewing_key.wallet_id();
+                let view = app
+                    .view
+                    .as_mut()
+                    .context("view service must be initialized")?;
+                let current_height = ViewClient::status(view, wallet_id).await?.full_sync_height;
+                if payment_request.is_expired(current_height) {
+                    anyhow::bail!(
+                        "payment request expired at height {}",
+                        payment_request.expiry_height.unwrap_or_default()
+                    );
+                }
+
+                let return_address = app
+                    .config
+                    .full_viewing_key
+                    .ephemeral_address(OsRng, AddressIndex::new(*source))
+                    .0;
+
+                let plan = Planner::new(OsRng)
+                    .set_gas_prices(gas_prices)
+                    .payment_request(&payment_request, return_address)?
+                    .plan(view, wallet_id, AddressIndex::new(*source))
+                    .await
+                    .context("can't build payment transaction")?;
+                app.build_and_submit_transaction(plan).await?;
+            }
             TxCmd::DaoDeposit { values, source } => {
                 let values = values
                     .iter()
@@ -351,11 +435,70 @@ impl TxCmd {
             TxCmd::Swap {
                 input,
                 into,
+                min_output,
+                max_slippage,
                 source,
             } => {
                 let input = input.parse::<Value>()?;
                 let into = asset::REGISTRY.parse_unit(into.as_str()).base();
 
+                let min_output = match (min_output, max_slippage) {
+                    (Some(min_output), _) => {
+                        let min_output = min_output.parse::<Value>()?;
+                        if min_output.asset_id != into.id() {
+                            anyhow::bail!("minimum output must be denominated in the output asset");
+                        }
+                        min_output.amount
+                    }
+                    (None, Some(max_slippage)) => {
+                        if !(0.0..=100.0).contains(max_slippage) {
+                            anyhow::bail!(
+                                "maximum slippage must be a percentage between 0 and 100"
+                            );
+                        }
+                        let simulated_execution: SwapExecution =
+                            SimulationServiceClient::new(app.pd_channel().await?)
+                                .simulate_trade(SimulateTradeRequest {
+                                    input: Some(input.into()),
+                                    output: Some(into.id().into()),
+                                    routing: Some(Routing {
+                                        setting: Some(Setting::Default(Default::default())),
+                                    }),
+                                })
+                                .await?
+                                .into_inner()
+                                .output
+                                .ok_or_else(|| {
+                                    anyhow::anyhow!("proto response missing swap execution")
+                                })?
+                                .try_into()
+                                .context("cannot parse simulation response")?;
+                        let simulated_output = simulated_execution.output.amount;
+                        // Work in basis points, to avoid floating point arithmetic on amounts.
+                        let max_slippage_bps = (max_slippage * 100.0).round() as u64;
+                        let min_output: Amount = (U128x128::from(simulated_output)
+                            * U128x128::ratio(10_000 - max_slippage_bps, 10_000)?)?
+                        .round_down()
+                        .try_into()?;
+                        let asset_cache = app.view().assets().await?;
+                        println!(
+                            "Simulated output is {}, refunding if the swap outputs less than {}",
+                            Value {
+                                amount: simulated_output,
+                                asset_id: into.id(),
+                            }
+                            .format(&asset_cache),
+                            Value {
+                                amount: min_output,
+                                asset_id: into.id(),
+                            }
+                            .format(&asset_cache),
+                        );
+                        min_output
+                    }
+                    (None, None) => Amount::zero(),
+                };
+
                 let fvk = app.config.full_viewing_key.clone();
 
                 // If a source address was specified, use it for the swap, otherwise,
@@ -378,7 +521,13 @@ impl TxCmd {
                 let estimated_claim_fee = Fee::from_staking_token_amount(
                     Amount::from(2u32) * gas_prices.price(&swap_claim_gas_cost()),
                 );
-                planner.swap(input, into.id(), estimated_claim_fee, claim_address)?;
+                planner.swap(
+                    input,
+                    into.id(),
+                    min_output,
+                    estimated_claim_fee,
+                    claim_address,
+                )?;
 
                 let wallet_id = app.config.full_viewing_key.wallet_id();
                 let plan = planner
@@ -406,19 +555,20 @@ impl TxCmd {
 
                 let asset_cache = app.view().assets().await?;
 
-                let pro_rata_outputs = swap_record
-                    .output_data
-                    .pro_rata_outputs((swap_plaintext.delta_1_i, swap_plaintext.delta_2_i));
+                let swap_outputs = swap_record.output_data.swap_outputs(
+                    (swap_plaintext.delta_1_i, swap_plaintext.delta_2_i),
+                    swap_plaintext.min_output,
+                );
                 println!("Swap submitted and batch confirmed!");
                 println!(
                     "You will receive outputs of {} and {}. Claiming now...",
                     Value {
-                        amount: pro_rata_outputs.0,
+                        amount: swap_outputs.0,
                         asset_id: swap_record.output_data.trading_pair.asset_1()
                     }
                     .format(&asset_cache),
                     Value {
-                        amount: pro_rata_outputs.1,
+                        amount: swap_outputs.1,
                         asset_id: swap_record.output_data.trading_pair.asset_2()
                     }
                     .format(&asset_cache),
@@ -525,6 +675,51 @@ impl TxCmd {
 
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::Redelegate { to, amount, source } => {
+                let delegation_value @ Value {
+                    amount: _,
+                    asset_id,
+                } = amount.parse::<Value>()?;
+
+                let delegation_token: DelegationToken = app
+                    .view()
+                    .assets()
+                    .await?
+                    .get(&asset_id)
+                    .ok_or_else(|| anyhow::anyhow!("unknown asset id {}", asset_id))?
+                    .clone()
+                    .try_into()
+                    .context("could not parse supplied denomination as a delegation token")?;
+
+                let from = delegation_token.validator();
+                let to = to.parse::<IdentityKey>()?;
+                if from == to {
+                    anyhow::bail!("cannot redelegate to the same validator");
+                }
+
+                let mut client = StakeQueryServiceClient::new(app.pd_channel().await?);
+                let from_rate_data: RateData = client
+                    .current_validator_rate(tonic::Request::new(from.into()))
+                    .await?
+                    .into_inner()
+                    .try_into()?;
+                let to_rate_data: RateData = client
+                    .current_validator_rate(tonic::Request::new(to.into()))
+                    .await?
+                    .into_inner()
+                    .try_into()?;
+
+                let mut planner = Planner::new(OsRng);
+                planner.set_gas_prices(gas_prices);
+                let wallet_id = app.config.full_viewing_key.wallet_id().clone();
+                let plan = planner
+                    .redelegate(delegation_value.amount, from_rate_data, to_rate_data)
+                    .plan(app.view(), wallet_id, AddressIndex::new(*source))
+                    .await
+                    .context("can't plan redelegation")?;
+
+                app.build_and_submit_transaction(plan).await?;
+            }
             TxCmd::UndelegateClaim {} => {
                 let wallet_id = app.config.full_viewing_key.wallet_id(); // this should be optional? or saved in the client statefully?
 
@@ -623,6 +818,87 @@ impl TxCmd {
                         app.build_and_submit_transaction(plan).await?;
                     }
                 }
+
+                // Redelegations are claimed the same way, once the unbonding period of the
+                // validator the stake was redelegated from is over.
+                let notes = app
+                    .view
+                    .as_mut()
+                    .context("view service must be initialized")?
+                    .unspent_notes_by_address_and_asset(wallet_id)
+                    .await?;
+
+                for (address_index, notes_by_asset) in notes.into_iter() {
+                    for (token, notes) in
+                        notes_by_asset.into_iter().filter_map(|(asset_id, notes)| {
+                            // Filter for notes that are redelegation tokens.
+                            let denom = asset_cache
+                                .get(&asset_id)
+                                .expect("asset ID should exist in asset cache")
+                                .clone();
+                            match RedelegationToken::try_from(denom) {
+                                Ok(token) => Some((token, notes)),
+                                Err(_) => None,
+                            }
+                        })
+                    {
+                        println!("claiming {}", token.denom().default_unit());
+                        let from_validator_identity = token.from_validator();
+                        let start_epoch_index = token.start_epoch_index();
+                        let end_epoch_index = current_epoch.index;
+
+                        let params = app
+                            .view
+                            .as_mut()
+                            .context("view service must be initialized")?
+                            .app_params()
+                            .await?;
+
+                        let mut client = StakeQueryServiceClient::new(channel.clone());
+                        let penalty: Penalty = client
+                            .validator_penalty(tonic::Request::new(ValidatorPenaltyRequest {
+                                chain_id: params.chain_params.chain_id.to_string(),
+                                identity_key: Some(from_validator_identity.clone().into()),
+                                start_epoch_index,
+                                end_epoch_index,
+                            }))
+                            .await?
+                            .into_inner()
+                            .penalty
+                            .ok_or_else(|| {
+                                anyhow::anyhow!(
+                                    "no penalty returned for validator {}",
+                                    from_validator_identity
+                                )
+                            })?
+                            .try_into()?;
+
+                        let mut planner = Planner::new(OsRng);
+                        planner.set_gas_prices(gas_prices.clone());
+                        let redelegation_amount = notes.iter().map(|n| n.note.amount()).sum();
+                        for note in notes {
+                            planner.spend(note.note, note.position);
+                        }
+
+                        let plan = planner
+                            .redelegate_claim(RedelegateClaim {
+                                from_validator_identity,
+                                to_validator_identity: token.to_validator(),
+                                start_epoch_index,
+                                penalty,
+                                redelegation_amount,
+                            })
+                            .plan(
+                                app.view
+                                    .as_mut()
+                                    .context("view service must be initialized")?,
+                                app.config.full_viewing_key.wallet_id(),
+                                address_index,
+                            )
+                            .await?;
+                        app.build_and_submit_transaction(plan).await?;
+                    }
+                }
             }
             TxCmd::Proposal(ProposalCmd::Submit {
                 file,
@@ -848,6 +1124,7 @@ impl TxCmd {
                 timeout_timestamp,
                 channel,
                 source,
+                memo,
             } => {
                 let destination_chain_address = to;
 
@@ -899,6 +1176,7 @@ impl TxCmd {
                     return_address: ephemeral_return_address,
                     // TODO: impl From<u64> for ChannelId
                     source_channel: ChannelId::from_str(format!("channel-{}", channel).as_ref())?,
+                    memo: memo.clone().unwrap_or_default(),
                 };
 
                 let plan = Planner::new(OsRng)
@@ -1105,7 +1383,43 @@ impl TxCmd {
                     .await?;
                 app.build_and_submit_transaction(plan).await?;
             }
-            TxCmd::Position(PositionCmd::RewardClaim {}) => todo!(),
+            TxCmd::Position(PositionCmd::RewardClaim {
+                fee,
+                source,
+                trading_pair,
+            }) => {
+                let view: &mut dyn ViewClient = app
+                    .view
+                    .as_mut()
+                    .context("view service must be initialized")?;
+
+                let position_rewards = view.position_rewards(*trading_pair).await?;
+
+                if position_rewards.is_empty() {
+                    println!("No withdrawn positions have rewards to claim.");
+                    return Ok(());
+                }
+
+                let fee = Fee::from_staking_token_amount((*fee).into());
+
+                let mut planner = Planner::new(OsRng);
+                planner.set_gas_prices(gas_prices);
+                for (position_id, rewards) in position_rewards {
+                    planner.position_reward_claim(position_id, rewards.amount);
+                }
+
+                let plan = planner
+                    .fee(fee)
+                    .plan(
+                        app.view
+                            .as_mut()
+                            .context("view service must be initialized")?,
+                        app.config.full_viewing_key.wallet_id(),
+                        AddressIndex::new(*source),
+                    )
+                    .await?;
+                app.build_and_submit_transaction(plan).await?;
+            }
             TxCmd::Position(PositionCmd::Replicate(replicate_cmd)) => {
                 replicate_cmd.exec(app).await?;
             }
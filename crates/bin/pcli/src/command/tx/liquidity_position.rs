@@ -64,9 +64,18 @@ pub enum PositionCmd {
         position_id: position::Id,
     },
 
-    /// Debits a withdrawn position NFT and credits a claimed position NFT and any liquidity incentives.
-    #[clap(hide(true))] // remove when reward claims exist
-    RewardClaim {},
+    /// Debits all withdrawn position NFTs with unclaimed rewards and credits claimed position NFTs and any liquidity incentives.
+    RewardClaim {
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0")]
+        fee: u64,
+        /// Only spend funds originally received by the given address index.
+        #[clap(long, default_value = "0")]
+        source: u32,
+        /// Only claim rewards for positions on the given trading pair.
+        #[clap(long)]
+        trading_pair: Option<TradingPair>,
+    },
     /// Replicate a trading function
     #[clap(subcommand)]
     Replicate(ReplicateCmd),
@@ -1,4 +1,5 @@
 use anyhow::{Context, Result};
+use ibc_types::core::client::ClientId;
 
 use penumbra_app::params::AppParameters;
 use penumbra_governance::{proposal::ChangedAppParameters, Proposal, ProposalPayload};
@@ -12,7 +13,7 @@ pub enum ProposalCmd {
         /// The file to output the template to.
         #[clap(long, global = true)]
         file: Option<camino::Utf8PathBuf>,
-        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, or dao-spend].
+        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, dao-spend, upgrade-plan, or ibc-client-recovery].
         #[clap(subcommand)]
         kind: ProposalKindCmd,
     },
@@ -73,6 +74,15 @@ pub enum ProposalKindCmd {
     },
     /// Generate a template for an upgrade propopsal,
     UpgradePlan,
+    /// Generate a template for an IBC client recovery proposal.
+    IbcClientRecovery {
+        /// The frozen or expired client to recover.
+        #[clap(long)]
+        subject_client_id: ClientId,
+        /// The active client whose state should be substituted into the subject.
+        #[clap(long)]
+        substitute_client_id: ClientId,
+    },
 }
 
 impl ProposalKindCmd {
@@ -88,6 +98,7 @@ impl ProposalKindCmd {
                 new: Box::new(ChangedAppParameters {
                     chain_params: None,
                     dao_params: None,
+                    dex_params: None,
                     ibc_params: None,
                     stake_params: None,
                     fee_params: None,
@@ -113,7 +124,17 @@ impl ProposalKindCmd {
                     }
                 }
             }
-            ProposalKindCmd::UpgradePlan { .. } => ProposalPayload::UpgradePlan { height: 0 },
+            ProposalKindCmd::UpgradePlan { .. } => ProposalPayload::UpgradePlan {
+                height: 0,
+                name: String::new(),
+            },
+            ProposalKindCmd::IbcClientRecovery {
+                subject_client_id,
+                substitute_client_id,
+            } => ProposalPayload::IbcClientRecovery {
+                subject_client_id: subject_client_id.clone(),
+                substitute_client_id: substitute_client_id.clone(),
+            },
         };
 
         Ok(Proposal {
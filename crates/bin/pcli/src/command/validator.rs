@@ -13,7 +13,11 @@ use penumbra_governance::{
 };
 use penumbra_keys::keys::AddressIndex;
 use penumbra_proto::{
-    core::component::stake::v1alpha1::Validator as ProtoValidator, DomainType, Message,
+    core::component::stake::v1alpha1::Validator as ProtoValidator,
+    storage::v1alpha1::{
+        query_service_client::QueryServiceClient as StorageQueryServiceClient, KeyValueRequest,
+    },
+    DomainType, Message,
 };
 use penumbra_stake::{
     validator,
@@ -50,6 +54,15 @@ pub enum ValidatorCmd {
         #[clap(long, default_value = "", global = true, display_order = 400)]
         reason: String,
     },
+    /// Request the release of your validator from jail, once its jail duration has elapsed.
+    Unjail {
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0", display_order = 200)]
+        fee: u64,
+        /// Optional. Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0", display_order = 300)]
+        source: u32,
+    },
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -99,13 +112,16 @@ impl ValidatorCmd {
                 DefinitionCmd::Template { .. } | DefinitionCmd::Fetch { .. },
             ) => true,
             ValidatorCmd::Vote { .. } => false,
+            ValidatorCmd::Unjail { .. } => false,
         }
     }
 
     // TODO: move use of sk into custody service
     pub async fn exec(&self, app: &mut App) -> Result<()> {
         let sk = match &app.config.custody {
-            CustodyConfig::SoftKms(config) => config.spend_key.clone(),
+            CustodyConfig::SoftKms(config) => config
+                .spend_key
+                .unlock(crate::terminal::prompt_passphrase)?,
             _ => {
                 anyhow::bail!("Validator commands require SoftKMS backend");
             }
@@ -229,6 +245,63 @@ impl ValidatorCmd {
 
                 println!("Cast validator vote");
             }
+            ValidatorCmd::Unjail { fee, source } => {
+                // TODO: support submitting a separate governance key.
+                let identity_key = IdentityKey(*sk.full_viewing_key().spend_verification_key());
+                // Currently this is always just copied from the identity key
+                let governance_key = GovernanceKey(identity_key.0);
+
+                // The request names the jailing it releases the validator from,
+                // so look up the height at which the validator was jailed.
+                let mut client = StorageQueryServiceClient::new(app.pd_channel().await?);
+                let jail_height = match client
+                    .key_value(KeyValueRequest {
+                        key: penumbra_stake::state_key::jail_height_by_validator(&identity_key),
+                        ..Default::default()
+                    })
+                    .await?
+                    .into_inner()
+                    .value
+                {
+                    Some(value) => u64::decode(value.value.as_ref())?,
+                    // Validators jailed before jail heights were recorded.
+                    None => 0,
+                };
+
+                let body = validator::UnjailBody {
+                    identity_key,
+                    governance_key,
+                    jail_height,
+                };
+
+                // TODO: support signing with a separate governance key
+                let governance_auth_key = sk.spend_auth_key();
+
+                // Generate an authorizing signature with the governance key for the unjail body
+                let body_bytes = body.encode_to_vec();
+                let auth_sig = governance_auth_key.sign(OsRng, &body_bytes);
+
+                let unjail = validator::Unjail { body, auth_sig };
+
+                let fee = Fee::from_staking_token_amount((*fee).into());
+
+                let wallet_id = app.config.full_viewing_key.wallet_id();
+
+                let plan = plan::validator_unjail(
+                    wallet_id,
+                    app.view
+                        .as_mut()
+                        .context("view service must be initialized")?,
+                    OsRng,
+                    unjail,
+                    fee,
+                    AddressIndex::new(*source),
+                )
+                .await?;
+                app.build_and_submit_transaction(plan).await?;
+
+                println!("Requested validator unjail");
+            }
             ValidatorCmd::Definition(DefinitionCmd::Template {
                 file,
                 tendermint_validator_keyfile,
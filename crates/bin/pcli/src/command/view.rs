@@ -2,6 +2,8 @@ use anyhow::Result;
 
 use address::AddressCmd;
 use balance::BalanceCmd;
+use export::ExportCmd;
+use payment_request::PaymentRequestCmd;
 use staked::StakedCmd;
 use transaction_hashes::TransactionHashesCmd;
 use tx::TxCmd;
@@ -11,6 +13,8 @@ use crate::App;
 
 mod address;
 mod balance;
+mod export;
+mod payment_request;
 mod staked;
 mod wallet_id;
 
@@ -39,6 +43,11 @@ pub enum ViewCmd {
     ListTransactionHashes(TransactionHashesCmd),
     /// Displays a transaction's details by hash.
     Tx(TxCmd),
+    /// Create payment requests, and check whether they've been paid.
+    #[clap(subcommand)]
+    PaymentRequest(PaymentRequestCmd),
+    /// Export your transaction history, for accounting.
+    Export(ExportCmd),
 }
 
 impl ViewCmd {
@@ -52,6 +61,8 @@ impl ViewCmd {
             ViewCmd::Sync => false,
             ViewCmd::ListTransactionHashes(transactions_cmd) => transactions_cmd.offline(),
             ViewCmd::Tx(tx_cmd) => tx_cmd.offline(),
+            ViewCmd::PaymentRequest(payment_request_cmd) => payment_request_cmd.offline(),
+            ViewCmd::Export(export_cmd) => export_cmd.offline(),
         }
     }
 
@@ -66,6 +77,12 @@ impl ViewCmd {
             ViewCmd::Tx(tx_cmd) => {
                 tx_cmd.exec(app).await?;
             }
+            ViewCmd::PaymentRequest(payment_request_cmd) => {
+                payment_request_cmd.exec(app).await?;
+            }
+            ViewCmd::Export(export_cmd) => {
+                export_cmd.exec(app).await?;
+            }
             ViewCmd::ListTransactionHashes(transactions_cmd) => {
                 let view_client = app.view();
                 transactions_cmd
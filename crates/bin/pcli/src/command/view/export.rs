@@ -0,0 +1,274 @@
+use std::{collections::BTreeMap, fs::File, io::Write};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use tendermint::Time;
+use tonic::transport::Channel;
+
+use penumbra_asset::{asset::Cache, Value};
+use penumbra_proto::util::tendermint_proxy::v1alpha1::{
+    tendermint_proxy_service_client::TendermintProxyServiceClient, GetBlockByHeightRequest,
+};
+use penumbra_view::{LedgerEntry, ViewClient};
+
+use crate::App;
+
+#[derive(Clone, clap::ValueEnum, Debug)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+/// Exports the wallet's transaction history, as one row per transaction and account.
+///
+/// Each row has the net values the account received and sent in the transaction, the fee it paid,
+/// how its swaps were filled, the rates of its delegations, and the transaction's memo.
+#[derive(Debug, clap::Args)]
+pub struct ExportCmd {
+    /// The format to export in.
+    #[clap(long, value_enum, default_value = "csv")]
+    format: ExportFormat,
+    /// Only export transactions at or after this height.
+    #[clap(long)]
+    start_height: Option<u64>,
+    /// Only export transactions at or before this height.
+    #[clap(long)]
+    end_height: Option<u64>,
+    /// Only export transactions at or after this date, as YYYY-MM-DD or an RFC 3339 timestamp.
+    #[clap(long)]
+    start_date: Option<String>,
+    /// Only export transactions at or before this date, as YYYY-MM-DD or an RFC 3339 timestamp.
+    #[clap(long)]
+    end_date: Option<String>,
+    /// Only export rows for this account.
+    #[clap(long)]
+    account: Option<u32>,
+    /// Write the export to this file, rather than to stdout.
+    #[clap(long, short)]
+    output: Option<String>,
+}
+
+/// A row of the export, with its values formatted using the wallet's asset cache.
+#[derive(Debug, Serialize)]
+struct Row {
+    height: u64,
+    time: String,
+    transaction_id: String,
+    account: u32,
+    inflows: Vec<String>,
+    outflows: Vec<String>,
+    fee: String,
+    swap_fills: Vec<String>,
+    delegation_conversions: Vec<String>,
+    memo: String,
+}
+
+impl Row {
+    const CSV_HEADER: [&'static str; 10] = [
+        "height",
+        "time",
+        "transaction_id",
+        "account",
+        "inflows",
+        "outflows",
+        "fee",
+        "swap_fills",
+        "delegation_conversions",
+        "memo",
+    ];
+
+    fn new(entry: LedgerEntry, time: Option<Time>, cache: &Cache) -> Self {
+        let format_values = |values: &[Value]| -> Vec<String> {
+            values.iter().map(|value| value.format(cache)).collect()
+        };
+        let format_conversion = |inputs: &[Value], outputs: &[Value]| {
+            format!(
+                "{} -> {}",
+                format_values(inputs).join(" + "),
+                format_values(outputs).join(" + ")
+            )
+        };
+
+        Row {
+            height: entry.height,
+            time: time.map(|time| time.to_string()).unwrap_or_default(),
+            transaction_id: entry.transaction_id.to_string(),
+            account: entry.account,
+            inflows: format_values(&entry.inflows),
+            outflows: format_values(&entry.outflows),
+            fee: entry.fee.map(|fee| fee.0.format(cache)).unwrap_or_default(),
+            swap_fills: entry
+                .swap_fills
+                .iter()
+                .map(|fill| format_conversion(&fill.inputs, &fill.outputs))
+                .collect(),
+            delegation_conversions: entry
+                .delegation_conversions
+                .iter()
+                .map(|conversion| format_conversion(&[conversion.input], &[conversion.output]))
+                .collect(),
+            memo: entry.memo.unwrap_or_default(),
+        }
+    }
+
+    fn csv_record(&self) -> [String; 10] {
+        [
+            self.height.to_string(),
+            self.time.clone(),
+            self.transaction_id.clone(),
+            self.account.to_string(),
+            self.inflows.join("; "),
+            self.outflows.join("; "),
+            self.fee.clone(),
+            self.swap_fills.join("; "),
+            self.delegation_conversions.join("; "),
+            self.memo.clone(),
+        ]
+    }
+}
+
+impl ExportCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let channel = app.pd_channel().await?;
+        let mut tendermint = TendermintProxyServiceClient::new(channel);
+        let wallet_id = app.config.full_viewing_key.wallet_id();
+        let view = app.view();
+        let sync_height = view.status(wallet_id).await?.full_sync_height;
+
+        // Resolve any dates to the range of heights of the blocks within them.
+        let mut start_height = self.start_height.unwrap_or(1).max(1);
+        let mut end_height = self.end_height.unwrap_or(sync_height).min(sync_height);
+        if let Some(start_date) = &self.start_date {
+            let start = parse_date(start_date, false)?;
+            let first =
+                first_height_after(&mut tendermint, end_height, |time| time >= start).await?;
+            start_height = start_height.max(first);
+        }
+        if let Some(end_date) = &self.end_date {
+            let end = parse_date(end_date, true)?;
+            let first_after =
+                first_height_after(&mut tendermint, end_height, |time| time > end).await?;
+            end_height = end_height.min(first_after - 1);
+        }
+
+        let entries = if start_height > end_height {
+            Vec::new()
+        } else {
+            view.transaction_ledger(Some(start_height), Some(end_height), self.account)
+                .await?
+        };
+
+        let cache = view.assets().await?;
+        let mut times = BTreeMap::new();
+        let mut rows = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let time = match times.get(&entry.height) {
+                Some(time) => *time,
+                None => {
+                    let time = block_time(&mut tendermint, entry.height).await?;
+                    times.insert(entry.height, time);
+                    time
+                }
+            };
+            rows.push(Row::new(entry, time, &cache));
+        }
+
+        let mut writer: Box<dyn Write> = match &self.output {
+            Some(path) => {
+                Box::new(File::create(path).with_context(|| format!("cannot create file {path}"))?)
+            }
+            None => Box::new(std::io::stdout()),
+        };
+        match self.format {
+            ExportFormat::Json => {
+                serde_json::to_writer_pretty(&mut writer, &rows)?;
+                writeln!(writer)?;
+            }
+            ExportFormat::Csv => {
+                writeln!(writer, "{}", Row::CSV_HEADER.join(","))?;
+                for row in &rows {
+                    let record = row.csv_record().map(|field| csv_field(&field));
+                    writeln!(writer, "{}", record.join(","))?;
+                }
+            }
+        }
+        writer.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Parses a date as an RFC 3339 timestamp, or as a day, YYYY-MM-DD.
+///
+/// A day is taken to start at its first instant, or with `end_of_day`, to end at its last.
+fn parse_date(date: &str, end_of_day: bool) -> Result<Time> {
+    let timestamp = if date.len() == "YYYY-MM-DD".len() {
+        if end_of_day {
+            format!("{date}T23:59:59.999999999Z")
+        } else {
+            format!("{date}T00:00:00Z")
+        }
+    } else {
+        date.to_string()
+    };
+    Time::parse_from_rfc3339(&timestamp)
+        .with_context(|| format!("invalid date {date}, expected YYYY-MM-DD or RFC 3339"))
+}
+
+/// Fetches the time of the block at the given height, if the node still has it.
+async fn block_time(
+    client: &mut TendermintProxyServiceClient<Channel>,
+    height: u64,
+) -> Result<Option<Time>> {
+    let time = client
+        .get_block_by_height(GetBlockByHeightRequest {
+            height: height.try_into()?,
+        })
+        .await?
+        .into_inner()
+        .block
+        .and_then(|block| block.header)
+        .and_then(|header| header.time);
+
+    time.map(|time| {
+        Time::from_unix_timestamp(time.seconds, time.nanos.try_into()?)
+            .context("invalid block time")
+    })
+    .transpose()
+}
+
+/// Finds the first height up to `max_height` whose block time satisfies `predicate`, or
+/// `max_height + 1` if there is none, assuming that once a block time satisfies it, all later
+/// ones do too.
+async fn first_height_after(
+    client: &mut TendermintProxyServiceClient<Channel>,
+    max_height: u64,
+    predicate: impl Fn(Time) -> bool,
+) -> Result<u64> {
+    let (mut low, mut high) = (1, max_height + 1);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let time = block_time(client, mid)
+            .await?
+            .with_context(|| format!("missing time of block {mid}"))?;
+        if predicate(time) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    Ok(low)
+}
+
+/// Escapes a field of a CSV record, quoting it if needed.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use comfy_table::{presets, Table};
+use rand_core::OsRng;
+
+use penumbra_asset::{asset, Value};
+use penumbra_keys::keys::AddressIndex;
+use penumbra_num::Amount;
+use penumbra_proto::{
+    custody::v1alpha1::AuthorizePaymentRequestRequest,
+    view::v1alpha1::NotesForPaymentRequestRequest,
+};
+use penumbra_transaction::PaymentRequest;
+use penumbra_view::ViewClient;
+
+use crate::App;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum PaymentRequestCmd {
+    /// Create a payment request, to be paid with `pcli tx pay`.
+    ///
+    /// The request pays to a fresh ephemeral address of the given account.
+    Create {
+        /// The amounts to request, written as typed values 1.87penumbra, 12cubes, etc.
+        #[clap(required = true)]
+        values: Vec<String>,
+        /// The account to be paid to.
+        #[clap(long, default_value = "0")]
+        account: u32,
+        /// Optional. Text to include in the memo of the payment.
+        #[clap(long)]
+        memo: Option<String>,
+        /// Optional. The height after which the request can no longer be paid.
+        #[clap(long)]
+        expiry_height: Option<u64>,
+        /// Optional. An invoice id to sign with the wallet's spend authorization key.
+        #[clap(long)]
+        invoice_id: Option<String>,
+    },
+    /// Show the payments received for a payment request.
+    Status {
+        /// The payment request, starting with `penumbrapaymentrequest1`.
+        payment_request: String,
+    },
+}
+
+impl PaymentRequestCmd {
+    pub fn offline(&self) -> bool {
+        match self {
+            PaymentRequestCmd::Create { .. } => true,
+            PaymentRequestCmd::Status { .. } => false,
+        }
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        match self {
+            PaymentRequestCmd::Create {
+                values,
+                account,
+                memo,
+                expiry_height,
+                invoice_id,
+            } => {
+                let values = values
+                    .iter()
+                    .map(|v| v.parse())
+                    .collect::<Result<Vec<Value>, _>>()?;
+                let (address, _dtk) = app
+                    .config
+                    .full_viewing_key
+                    .ephemeral_address(OsRng, AddressIndex::new(*account));
+
+                let mut payment_request = PaymentRequest::new(
+                    address,
+                    values,
+                    memo.clone().unwrap_or_default(),
+                    *expiry_height,
+                )?;
+
+                if let Some(invoice_id) = invoice_id {
+                    payment_request = app
+                        .custody
+                        .authorize_payment_request(AuthorizePaymentRequestRequest {
+                            payment_request: Some(payment_request.into()),
+                            invoice_id: invoice_id.clone(),
+                            pre_authorizations: Vec::new(),
+                        })
+                        .await?
+                        .into_inner()
+                        .payment_request
+                        .context("custody service returned no payment request")?
+                        .try_into()?;
+                    payment_request.verify_invoice()?;
+                }
+
+                println!("{payment_request}");
+            }
+            PaymentRequestCmd::Status { payment_request } => {
+                let payment_request: PaymentRequest = payment_request
+                    .parse()
+                    .context("payment request is invalid")?;
+                let wallet_id = app.config.full_viewing_key.wallet_id();
+
+                let view = app.view();
+                let asset_cache = view.assets().await?;
+                let current_height = view.status(wallet_id).await?.full_sync_height;
+                let notes = view
+                    .notes_for_payment_request(NotesForPaymentRequestRequest {
+                        payment_request: Some(payment_request.clone().into()),
+                        wallet_id: Some(wallet_id.into()),
+                    })
+                    .await?;
+
+                let mut received = BTreeMap::<asset::Id, Amount>::new();
+                for record in &notes {
+                    *received.entry(record.note.asset_id()).or_default() += record.note.amount();
+                }
+
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec!["Requested", "Received"]);
+                let mut paid = true;
+                for value in &payment_request.values {
+                    let received = received.get(&value.asset_id).copied().unwrap_or_default();
+                    paid &= received >= value.amount;
+                    table.add_row(vec![
+                        value.format(&asset_cache),
+                        Value {
+                            amount: received,
+                            asset_id: value.asset_id,
+                        }
+                        .format(&asset_cache),
+                    ]);
+                }
+                println!("{table}");
+
+                if paid {
+                    println!("Payment request is paid.");
+                } else if payment_request.is_expired(current_height) {
+                    println!("Payment request expired unpaid.");
+                } else {
+                    println!("Payment request is not yet paid.");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -361,12 +361,22 @@ impl TxCmd {
                     penumbra_transaction::ActionView::Undelegate(_) => {
                         ["Undelegation".to_string(), "".to_string()]
                     }
+                    penumbra_transaction::ActionView::Redelegate(_) => {
+                        ["Redelegation".to_string(), "".to_string()]
+                    }
+                    penumbra_transaction::ActionView::RedelegateClaim(_) => {
+                        ["Redelegation Claim".to_string(), "".to_string()]
+                    }
                     penumbra_transaction::ActionView::UndelegateClaim(_) => {
                         ["Undelegation Claim".to_string(), "".to_string()]
                     }
                     penumbra_transaction::ActionView::ValidatorDefinition(_) => {
                         ["Upload Validator Definition".to_string(), "".to_string()]
                     }
+                    penumbra_transaction::ActionView::ValidatorUnjail(unjail) => [
+                        "Unjail Validator".to_string(),
+                        unjail.body.identity_key.to_string(),
+                    ],
                     penumbra_transaction::ActionView::IbcRelay(_) => {
                         ["IBC Action".to_string(), "".to_string()]
                     }
@@ -24,6 +24,10 @@ pub struct PcliConfig {
     pub full_viewing_key: FullViewingKey,
     /// The custody backend to use.
     pub custody: CustodyConfig,
+    /// If set, threshold custody runs its protocols through this relay,
+    /// instead of having messages pasted by hand.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold_relay_url: Option<Url>,
 }
 
 impl PcliConfig {
@@ -86,11 +90,13 @@ mod tests {
             custody: CustodyConfig::SoftKms(SoftKmsConfig::from(
                 penumbra_keys::test_keys::SPEND_KEY.clone(),
             )),
+            threshold_relay_url: None,
         };
 
         let mut config2 = config.clone();
         config2.custody = CustodyConfig::ViewOnly;
         config2.disable_warning = true;
+        config2.threshold_relay_url = Some(Url::parse("http://localhost:8086").unwrap());
 
         let toml_config = toml::to_string_pretty(&config).unwrap();
         let toml_config2 = toml::to_string_pretty(&config2).unwrap();
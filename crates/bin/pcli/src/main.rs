@@ -122,6 +122,12 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
+    // The threshold relay doesn't use any keys, so it doesn't need a config.
+    if let Command::Threshold(ThresholdCmd::Relay { bind, participant }) = &opt.cmd {
+        ThresholdCmd::exec_relay(*bind, participant).await?;
+        return Ok(());
+    }
+
     let (mut app, cmd) = opt.into_app().await?;
 
     if !cmd.offline() {
@@ -8,7 +8,10 @@ use anyhow::Result;
 use camino::Utf8PathBuf;
 use clap::Parser;
 use directories::ProjectDirs;
-use penumbra_custody::soft_kms::SoftKms;
+use penumbra_custody::{
+    soft_kms::{self, SoftKms},
+    threshold::{NetworkTerminal, Threshold},
+};
 use penumbra_proto::{
     custody::v1alpha1::{
         custody_protocol_service_client::CustodyProtocolServiceClient,
@@ -20,6 +23,7 @@ use penumbra_proto::{
     },
 };
 use penumbra_view::ViewService;
+use std::sync::Arc;
 use tracing_subscriber::EnvFilter;
 
 #[derive(Debug, Parser)]
@@ -67,17 +71,30 @@ impl Opt {
             }
             CustodyConfig::SoftKms(config) => {
                 tracing::info!("using software KMS custody service");
-                let soft_kms = SoftKms::new(config.clone());
+                let soft_kms = SoftKms::new(config.clone())
+                    .with_passphrase(Arc::new(crate::terminal::prompt_passphrase))
+                    .with_spend_history(self.home.join(soft_kms::SPEND_HISTORY_FILE_NAME))?;
                 let custody_svc = CustodyProtocolServiceServer::new(soft_kms);
                 CustodyProtocolServiceClient::new(box_grpc_svc::local(custody_svc))
             }
-            CustodyConfig::Threshold(config) => {
-                tracing::info!("using manual threshold custody service");
-                let threshold_kms =
-                    penumbra_custody::threshold::Threshold::new(config.clone(), ActualTerminal);
-                let custody_svc = CustodyProtocolServiceServer::new(threshold_kms);
-                CustodyProtocolServiceClient::new(box_grpc_svc::local(custody_svc))
-            }
+            CustodyConfig::Threshold(threshold_config) => match &config.threshold_relay_url {
+                Some(relay_url) => {
+                    tracing::info!(%relay_url, "using networked threshold custody service");
+                    let channel =
+                        tonic::transport::Endpoint::new(relay_url.to_string())?.connect_lazy();
+                    let terminal =
+                        NetworkTerminal::coordinator(channel, threshold_config, ActualTerminal);
+                    let threshold_kms = Threshold::new(threshold_config.clone(), terminal);
+                    let custody_svc = CustodyProtocolServiceServer::new(threshold_kms);
+                    CustodyProtocolServiceClient::new(box_grpc_svc::local(custody_svc))
+                }
+                None => {
+                    tracing::info!("using manual threshold custody service");
+                    let threshold_kms = Threshold::new(threshold_config.clone(), ActualTerminal);
+                    let custody_svc = CustodyProtocolServiceServer::new(threshold_kms);
+                    CustodyProtocolServiceClient::new(box_grpc_svc::local(custody_svc))
+                }
+            },
         };
 
         // ...and the view service...
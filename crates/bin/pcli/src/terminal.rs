@@ -1,8 +1,10 @@
 use anyhow::Result;
-use penumbra_custody::threshold::Terminal;
-use penumbra_transaction::plan::TransactionPlan;
+use penumbra_asset::asset::Cache;
+use penumbra_custody::{soft_kms, threshold::Terminal};
+use penumbra_transaction::plan::{ActionPlan, TransactionPlan};
 use tokio::io::{self, AsyncBufReadExt};
 use tonic::async_trait;
+use zeroize::Zeroizing;
 
 /// For threshold custody, we need to implement this weird terminal abstraction.
 ///
@@ -12,11 +14,10 @@ pub struct ActualTerminal;
 #[async_trait]
 impl Terminal for ActualTerminal {
     async fn confirm_transaction(&self, transaction: &TransactionPlan) -> Result<bool> {
-        println!("Do you approve this transaction?");
-        println!("{}", serde_json::to_string_pretty(transaction)?);
-        println!("Press enter to continue");
-        self.next_response().await?;
-        Ok(true)
+        println!("{}", describe_plan(transaction));
+        println!("Do you approve this transaction? [y/N]");
+        let answer = self.next_response().await?.unwrap_or_default();
+        Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
     }
 
     async fn explain(&self, msg: &str) -> Result<()> {
@@ -42,3 +43,71 @@ impl Terminal for ActualTerminal {
         Ok(Some(line))
     }
 }
+
+/// Describes a transaction plan, so that someone can decide whether or not to sign it.
+fn describe_plan(plan: &TransactionPlan) -> String {
+    let cache = Cache::with_known_assets();
+    let mut lines = vec![
+        format!("Chain: {}", plan.chain_id),
+        format!("Fee: {}", plan.fee.0.format(&cache)),
+    ];
+    if plan.expiry_height != 0 {
+        lines.push(format!("Expiry height: {}", plan.expiry_height));
+    }
+    lines.push("Actions:".to_string());
+    for action in &plan.actions {
+        let description = match action {
+            ActionPlan::Spend(spend) => format!("Spend {}", spend.note.value().format(&cache)),
+            ActionPlan::Output(output) => format!(
+                "Output {} to {}",
+                output.value.format(&cache),
+                output.dest_address
+            ),
+            ActionPlan::Delegate(delegate) => format!(
+                "Delegate {} unbonded stake to {}",
+                delegate.unbonded_amount, delegate.validator_identity
+            ),
+            ActionPlan::Undelegate(undelegate) => format!(
+                "Undelegate {} delegation tokens from {}",
+                undelegate.delegation_amount, undelegate.validator_identity
+            ),
+            ActionPlan::Redelegate(redelegate) => format!(
+                "Redelegate {} delegation tokens from {} to {}",
+                redelegate.from_delegation_amount,
+                redelegate.from_validator_identity,
+                redelegate.to_validator_identity
+            ),
+            ActionPlan::RedelegateClaim(_) => "Claim redelegated stake".to_string(),
+            ActionPlan::UndelegateClaim(_) => "Claim undelegated stake".to_string(),
+            ActionPlan::ValidatorDefinition(_) => "Update validator definition".to_string(),
+            ActionPlan::ValidatorUnjail(_) => "Release validator from jail".to_string(),
+            ActionPlan::Swap(_) => "Swap".to_string(),
+            ActionPlan::SwapClaim(_) => "Claim swap outputs".to_string(),
+            ActionPlan::IbcAction(_) => "IBC relay".to_string(),
+            ActionPlan::ProposalSubmit(_) => "Submit governance proposal".to_string(),
+            ActionPlan::ProposalWithdraw(_) => "Withdraw governance proposal".to_string(),
+            ActionPlan::DelegatorVote(_) => "Vote on governance proposal".to_string(),
+            ActionPlan::ValidatorVote(_) => "Vote on governance proposal as validator".to_string(),
+            ActionPlan::ProposalDepositClaim(_) => "Claim proposal deposit".to_string(),
+            ActionPlan::PositionOpen(_) => "Open liquidity position".to_string(),
+            ActionPlan::PositionClose(_) => "Close liquidity position".to_string(),
+            ActionPlan::PositionWithdraw(_) => "Withdraw liquidity position".to_string(),
+            ActionPlan::PositionRewardClaim(_) => "Claim liquidity position rewards".to_string(),
+            ActionPlan::DaoSpend(_) => "Spend from the DAO".to_string(),
+            ActionPlan::DaoOutput(_) => "Output from the DAO".to_string(),
+            ActionPlan::DaoDeposit(_) => "Deposit to the DAO".to_string(),
+            ActionPlan::Withdrawal(withdrawal) => format!(
+                "IBC withdrawal of {} to {}",
+                withdrawal.value().format(&cache),
+                withdrawal.destination_chain_address
+            ),
+        };
+        lines.push(format!("  {}", description));
+    }
+    lines.join("\n")
+}
+
+/// Prompts for the passphrase unlocking an encrypted spend key.
+pub fn prompt_passphrase() -> Result<Zeroizing<String>> {
+    soft_kms::prompt_passphrase("Enter passphrase to unlock spend key: ")
+}
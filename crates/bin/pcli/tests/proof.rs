@@ -12,7 +12,7 @@ use penumbra_dex::{
 use penumbra_fee::Fee;
 use penumbra_governance::DelegatorVoteProof;
 use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
-use penumbra_num::Amount;
+use penumbra_num::{fixpoint::U128x128, Amount};
 use penumbra_proof_params::{
     DELEGATOR_VOTE_PROOF_PROVING_KEY, DELEGATOR_VOTE_PROOF_VERIFICATION_KEY,
     NULLIFIER_DERIVATION_PROOF_PROVING_KEY, NULLIFIER_DERIVATION_PROOF_VERIFICATION_KEY,
@@ -199,6 +199,7 @@ fn swap_proof_parameters_vs_current_swap_circuit() {
 
     let blinding_r = Fq::rand(&mut OsRng);
     let blinding_s = Fq::rand(&mut OsRng);
+    let min_output = swap_plaintext.min_output;
     let proof = SwapProof::prove(
         blinding_r,
         blinding_s,
@@ -211,7 +212,13 @@ fn swap_proof_parameters_vs_current_swap_circuit() {
     )
     .expect("can create proof");
 
-    let proof_result = proof.verify(vk, balance_commitment, swap_commitment, fee_commitment);
+    let proof_result = proof.verify(
+        vk,
+        balance_commitment,
+        swap_commitment,
+        fee_commitment,
+        min_output,
+    );
 
     assert!(proof_result.is_ok());
 }
@@ -267,8 +274,11 @@ fn swap_claim_parameters_vs_current_swap_claim_circuit() {
         height: height.into(),
         trading_pair: swap_plaintext.trading_pair,
         epoch_starting_height: (epoch_duration * position.epoch()).into(),
+        limit_price_1: U128x128::default(),
+        limit_price_2: U128x128::default(),
     };
-    let (lambda_1, lambda_2) = output_data.pro_rata_outputs((delta_1_i, delta_2_i));
+    let (lambda_1, lambda_2) =
+        output_data.swap_outputs((delta_1_i, delta_2_i), swap_plaintext.min_output);
 
     let (output_rseed_1, output_rseed_2) = swap_plaintext.output_rseeds();
     let note_blinding_1 = output_rseed_1.derive_note_blinding();
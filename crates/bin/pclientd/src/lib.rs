@@ -1,5 +1,7 @@
 use std::net::SocketAddr;
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
@@ -16,7 +18,7 @@ use penumbra_proto::{
     custody::v1alpha1::custody_protocol_service_server::CustodyProtocolServiceServer,
     view::v1alpha1::view_protocol_service_server::ViewProtocolServiceServer,
 };
-use penumbra_view::{Storage, ViewService};
+use penumbra_view::{Storage, SyncMode, ViewService};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 
@@ -26,6 +28,7 @@ use std::io::{self, BufRead, Write};
 use std::str::FromStr;
 use tonic::transport::Server;
 use url::Url;
+use zeroize::Zeroizing;
 
 mod proxy;
 pub use proxy::{
@@ -46,6 +49,21 @@ pub struct PclientdConfig {
     pub bind_addr: SocketAddr,
     /// Optional KMS config for custody mode
     pub kms_config: Option<soft_kms::Config>,
+    /// If the KMS spend key is encrypted, how long it stays unlocked after
+    /// being unlocked for a request, in seconds. If unset, it stays unlocked
+    /// until `pclientd` exits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kms_unlock_timeout_secs: Option<u64>,
+    /// Whether the authorization policy of the KMS config is enforced.
+    ///
+    /// Older versions of `pclientd` ignored the authorization policy, while
+    /// `init` wrote an example policy that refuses all signing, so configs
+    /// without this setting leave the policy unenforced. `init` enables it.
+    #[serde(default)]
+    pub enforce_auth_policy: bool,
+    /// How the view service finds the notes relevant to it.
+    #[serde(default)]
+    pub sync_mode: SyncMode,
 }
 
 impl PclientdConfig {
@@ -61,6 +79,17 @@ impl PclientdConfig {
     }
 }
 
+/// The environment variable from which the passphrase of an encrypted KMS spend
+/// key is read. If it's unset, the passphrase is prompted for on the terminal.
+const KMS_PASSPHRASE_ENV: &str = "PENUMBRA_PCLIENTD_KMS_PASSPHRASE";
+
+fn kms_passphrase() -> Result<Zeroizing<String>> {
+    match std::env::var(KMS_PASSPHRASE_ENV) {
+        Ok(passphrase) => Ok(Zeroizing::new(passphrase)),
+        Err(_) => soft_kms::prompt_passphrase("Enter passphrase to unlock pclientd spend key: "),
+    }
+}
+
 fn default_home() -> Utf8PathBuf {
     let path = ProjectDirs::from("zone", "penumbra", "pclientd")
         .expect("Failed to get platform data dir")
@@ -103,7 +132,12 @@ pub enum Command {
         /// Sets the address to bind to to serve gRPC.
         #[clap(long, display_order = 900, default_value = "127.0.0.1:8081")]
         bind_addr: SocketAddr,
+        /// In custody mode, encrypt the spend key with a passphrase.
+        #[clap(long, action, display_order = 300)]
+        encrypted: bool,
     },
+    /// Encrypt the plaintext spend key of an existing custody mode config in place.
+    EncryptSpendKey {},
     /// Start running `pclientd`.
     Start {},
     /// Delete `pclientd` storage to reset local state.
@@ -123,6 +157,12 @@ impl Opt {
         path
     }
 
+    fn spend_history_path(&self) -> Utf8PathBuf {
+        let mut path = self.home.clone();
+        path.push(soft_kms::SPEND_HISTORY_FILE_NAME);
+        path
+    }
+
     fn check_home_nonempty(&self) -> Result<()> {
         if self.home.exists() {
             if !self.home.is_dir() {
@@ -185,6 +225,7 @@ impl Opt {
                 custody,
                 grpc_url,
                 bind_addr,
+                encrypted,
             } => {
                 // Check that the home directory is empty.
                 opt.check_home_nonempty()?;
@@ -261,16 +302,29 @@ impl Opt {
                         }),
                     ];
                     soft_kms::Config {
-                        spend_key,
+                        spend_key: spend_key.into(),
                         auth_policy,
                     }
                 });
+                let kms_config = match kms_config {
+                    Some(mut kms_config) if *encrypted => {
+                        soft_kms::encrypt_spend_key(&mut kms_config.spend_key)?;
+                        Some(kms_config)
+                    }
+                    None if *encrypted => {
+                        anyhow::bail!("--encrypted requires a seed phrase for custody mode")
+                    }
+                    kms_config => kms_config,
+                };
 
                 let client_config = PclientdConfig {
                     kms_config,
                     full_viewing_key,
                     grpc_url: grpc_url.clone(),
                     bind_addr: *bind_addr,
+                    kms_unlock_timeout_secs: None,
+                    enforce_auth_policy: true,
+                    sync_mode: SyncMode::default(),
                 };
 
                 let encoded = toml::to_string_pretty(&client_config)
@@ -286,6 +340,20 @@ impl Opt {
 
                 Ok(())
             }
+            Command::EncryptSpendKey {} => {
+                let mut config = PclientdConfig::load(opt.config_path())
+                    .context("Failed to load pclientd config file")?;
+                let kms_config = config
+                    .kms_config
+                    .as_mut()
+                    .ok_or_else(|| anyhow::anyhow!("pclientd is not configured in custody mode"))?;
+                soft_kms::encrypt_spend_key(&mut kms_config.spend_key)?;
+
+                println!("Writing encrypted spend key to {}", opt.config_path());
+                config.save(opt.config_path())?;
+
+                Ok(())
+            }
             Command::Start {} => {
                 let config = PclientdConfig::load(opt.config_path()).context(
                     "Failed to load pclientd config file. Have you run `pclientd init` with a FVK?",
@@ -314,13 +382,33 @@ impl Opt {
                 let tendermint_proxy_proxy = TendermintProxyProxy(proxy_channel.clone());
 
                 let view_service = ViewProtocolServiceServer::new(
-                    ViewService::new(storage, config.grpc_url).await?,
+                    ViewService::new_with_sync_mode(
+                        storage,
+                        config.grpc_url,
+                        config.sync_mode.clone(),
+                    )
+                    .await?,
                 );
-                let custody_service = config.kms_config.as_ref().map(|kms_config| {
-                    CustodyProtocolServiceServer::new(SoftKms::new(
-                        kms_config.spend_key.clone().into(),
-                    ))
-                });
+                let custody_service = config
+                    .kms_config
+                    .as_ref()
+                    .map(|kms_config| {
+                        let mut kms_config = kms_config.clone();
+                        if !config.enforce_auth_policy && !kms_config.auth_policy.is_empty() {
+                            tracing::warn!(
+                                "the KMS authorization policy is not enforced; set `enforce_auth_policy = true` in the config to enforce it"
+                            );
+                            kms_config.auth_policy.clear();
+                        }
+                        let mut soft_kms = SoftKms::new(kms_config)
+                            .with_passphrase(Arc::new(kms_passphrase))
+                            .with_spend_history(opt.spend_history_path())?;
+                        if let Some(timeout) = config.kms_unlock_timeout_secs {
+                            soft_kms = soft_kms.with_unlock_timeout(Duration::from_secs(timeout));
+                        }
+                        anyhow::Ok(CustodyProtocolServiceServer::new(soft_kms))
+                    })
+                    .transpose()?;
 
                 let server = Server::builder()
                     .accept_http1(true)
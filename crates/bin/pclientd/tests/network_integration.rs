@@ -35,9 +35,12 @@ fn generate_config() -> anyhow::Result<PclientdConfig> {
             .parse()?,
         bind_addr: "127.0.0.1:8081".parse()?,
         kms_config: Some(soft_kms::Config {
-            spend_key: test_keys::SPEND_KEY.clone(),
+            spend_key: test_keys::SPEND_KEY.clone().into(),
             auth_policy: Vec::new(),
         }),
+        kms_unlock_timeout_secs: None,
+        enforce_auth_policy: true,
+        sync_mode: Default::default(),
     })
 }
 
@@ -256,6 +259,7 @@ async fn swap_claim_flow() -> anyhow::Result<()> {
                     asset_id: None,
                 }),
                 claim_address: Some((*test_keys::ADDRESS_1).into()),
+                min_output: None,
             }],
             ..Default::default()
         })
@@ -7,12 +7,12 @@ mod consensus;
 mod info;
 mod mempool;
 mod metrics;
-mod snapshot;
 
 pub mod auto_https;
 pub mod events;
+pub mod migrate;
+pub mod snapshot;
 pub mod testnet;
-pub mod upgrade;
 
 pub use crate::metrics::register_metrics;
 pub use consensus::Consensus;
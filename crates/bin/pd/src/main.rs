@@ -15,16 +15,17 @@ use ibc_proto::ibc::core::client::v1::query_server::QueryServer as ClientQuerySe
 use ibc_proto::ibc::core::connection::v1::query_server::QueryServer as ConnectionQueryServer;
 use metrics_exporter_prometheus::PrometheusBuilder;
 use pd::events::EventIndexLayer;
+use pd::migrate;
+use pd::snapshot::SnapshotStore;
 use pd::testnet::{
     config::{get_testnet_dir, parse_tm_address, url_has_necessary_parts},
     generate::TestnetConfig,
     join::testnet_join,
 };
-use pd::upgrade;
 use penumbra_app::SUBSTORE_PREFIXES;
 use penumbra_proto::core::component::dex::v1alpha1::simulation_service_server::SimulationServiceServer;
 use penumbra_proto::util::tendermint_proxy::v1alpha1::tendermint_proxy_service_server::TendermintProxyServiceServer;
-use penumbra_storage::{StateDelta, Storage};
+use penumbra_storage::{PruningConfig, RootHash, Storage};
 use penumbra_tendermint_proxy::TendermintProxy;
 use penumbra_tower_trace::remote_addr;
 use rand::Rng;
@@ -121,6 +122,43 @@ enum RootCommand {
         /// But, it is a potential DoS vector, so it is disabled by default.
         #[clap(short, long, display_order = 500)]
         enable_expensive_rpc: bool,
+
+        /// Create a state sync snapshot every `snapshot_interval` blocks.
+        ///
+        /// Snapshots are stored in the `snapshots` directory of the `home`
+        /// directory, and served to CometBFT nodes joining the network
+        /// with state sync enabled. If unset, no snapshots are created.
+        #[clap(long, env = "PENUMBRA_PD_SNAPSHOT_INTERVAL", display_order = 600)]
+        snapshot_interval: Option<u64>,
+        /// The number of state sync snapshots to keep, older snapshots are deleted.
+        #[clap(
+            long,
+            env = "PENUMBRA_PD_SNAPSHOT_KEEP_RECENT",
+            default_value = "2",
+            display_order = 601
+        )]
+        snapshot_keep_recent: usize,
+        /// Prune the chain state, keeping only the `keep_recent` most recent versions.
+        ///
+        /// Pruning runs in the background after each block. If unset, every
+        /// version of the chain state is kept.
+        #[clap(long, env = "PENUMBRA_PD_KEEP_RECENT", display_order = 700)]
+        keep_recent: Option<u64>,
+        /// When pruning, also keep every version that is a multiple of this interval.
+        #[clap(long, env = "PENUMBRA_PD_KEEP_EVERY", display_order = 701)]
+        keep_every: Option<u64>,
+    },
+    /// Prune the chain state of a stopped full node.
+    Prune {
+        /// The home directory of the full node.
+        #[clap(long, env = "PENUMBRA_PD_HOME", display_order = 100)]
+        home: PathBuf,
+        /// The number of most recent versions of the chain state to keep.
+        #[clap(long, display_order = 200)]
+        keep_recent: u64,
+        /// Also keep every version that is a multiple of this interval.
+        #[clap(long, display_order = 201)]
+        keep_every: Option<u64>,
     },
     /// Generate, join, or reset a testnet.
     Testnet {
@@ -145,12 +183,17 @@ enum RootCommand {
         #[clap(long, display_order = 300)]
         prune: bool,
     },
-    /// Run a migration on the exported storage state of the full node,
-    /// and create a genesis file.
-    Upgrade {
-        /// The directory containing exported state to which the upgrade will be applied.
-        #[clap(long, display_order = 200)]
-        upgrade_path: PathBuf,
+    /// Run the migration for the upgrade plan the chain has halted for on the storage state of
+    /// the full node, and create a genesis file.
+    #[clap(alias = "upgrade")]
+    Migrate {
+        /// The directory containing the node state to which the migration will be applied.
+        #[clap(long, alias = "upgrade-path", display_order = 200)]
+        target_directory: PathBuf,
+        /// The hex-encoded app hash the migrated state is expected to have. If set, the migrated
+        /// state is only written if it matches.
+        #[clap(long, display_order = 250)]
+        expected_app_hash: Option<String>,
         #[clap(long, display_order = 300)]
         /// Timestamp of the genesis file in RFC3339 format. If unset, defaults to the current time,
         /// unless the migration script overrides it.
@@ -188,7 +231,7 @@ enum TestnetCommand {
         #[clap(long)]
         chain_id: Option<String>,
         /// The duration, in number of blocks, that a governance proposal
-        /// can be voted on.
+        /// of any kind can be voted on.
         #[clap(long)]
         proposal_voting_blocks: Option<u64>,
         /// Base hostname for a validator's p2p service. If multiple validators
@@ -279,6 +322,10 @@ async fn main() -> anyhow::Result<()> {
             metrics_bind,
             cometbft_addr,
             enable_expensive_rpc,
+            snapshot_interval,
+            snapshot_keep_recent,
+            keep_recent,
+            keep_every,
         } => {
             tracing::info!(
                 ?abci_bind,
@@ -287,6 +334,8 @@ async fn main() -> anyhow::Result<()> {
                 ?metrics_bind,
                 %cometbft_addr,
                 ?enable_expensive_rpc,
+                ?snapshot_interval,
+                ?keep_recent,
                 "starting pd"
             );
 
@@ -336,7 +385,31 @@ async fn main() -> anyhow::Result<()> {
                 }));
             let info = pd::Info::new(storage.clone());
             let tm_proxy = TendermintProxy::new(cometbft_addr);
-            let snapshot = pd::Snapshot {};
+
+            let snapshot_store = SnapshotStore::new(
+                pd_home.join("snapshots"),
+                SUBSTORE_PREFIXES.to_vec(),
+                snapshot_keep_recent,
+            )?;
+            match snapshot_interval {
+                Some(0) => anyhow::bail!("'--snapshot-interval' must be greater than zero"),
+                Some(interval) => {
+                    snapshot_store.clone().spawn(storage.clone(), interval);
+                }
+                None => {}
+            }
+            let snapshot = pd::Snapshot::new(storage.clone(), snapshot_store);
+
+            match keep_recent {
+                Some(keep_recent) => {
+                    let pruning = pruning_config(keep_recent, keep_every)?;
+                    storage.spawn_pruner(pruning);
+                }
+                None if keep_every.is_some() => {
+                    anyhow::bail!("'--keep-every' requires '--keep-recent' to be set")
+                }
+                None => {}
+            }
 
             let abci_server = tokio::task::Builder::new()
                 .name("abci_server")
@@ -370,6 +443,7 @@ async fn main() -> anyhow::Result<()> {
                     compact_block::v1alpha1::query_service_server::QueryServiceServer as CompactBlockQueryServiceServer,
                     dex::v1alpha1::query_service_server::QueryServiceServer as DexQueryServiceServer,
                     governance::v1alpha1::query_service_server::QueryServiceServer as GovernanceQueryServiceServer,
+                    ibc::v1alpha1::query_service_server::QueryServiceServer as IbcQueryServiceServer,
                     sct::v1alpha1::query_service_server::QueryServiceServer as SctQueryServiceServer,
                     shielded_pool::v1alpha1::query_service_server::QueryServiceServer as ShieldedPoolQueryServiceServer,
                     stake::v1alpha1::query_service_server::QueryServiceServer as StakeQueryServiceServer,
@@ -446,6 +520,7 @@ async fn main() -> anyhow::Result<()> {
                 .add_service(we(ClientQueryServer::new(ibc.clone())))
                 .add_service(we(ChannelQueryServer::new(ibc.clone())))
                 .add_service(we(ConnectionQueryServer::new(ibc.clone())))
+                .add_service(we(IbcQueryServiceServer::new(ibc.clone())))
                 .add_service(we(TendermintProxyServiceServer::new(tm_proxy.clone())))
                 .add_service(we(tonic_reflection::server::Builder::configure()
                     .register_encoded_file_descriptor_set(penumbra_proto::FILE_DESCRIPTOR_SET)
@@ -700,25 +775,74 @@ async fn main() -> anyhow::Result<()> {
 
             tracing::info!("pruning JMT tree");
             export_path.push("rocksdb");
+            let export = Storage::load(export_path.clone(), SUBSTORE_PREFIXES.to_vec()).await?;
+            let root_hash = export.latest_snapshot().root_hash().await?;
+            export.prune(&PruningConfig::new(1)).await?;
+            export.release().await;
+
+            // Reload the exported storage, to check the pruned state from disk.
             let export = Storage::load(export_path, SUBSTORE_PREFIXES.to_vec()).await?;
-            let _ = StateDelta::new(export.latest_snapshot());
-            // TODO:
-            // - add utilities in `penumbra_storage` to prune a tree
-            // - apply the delta to the exported storage
-            // - apply checks: root hash, size, etc.
-            todo!()
+            let pruned_root_hash = export.latest_snapshot().root_hash().await?;
+            if pruned_root_hash != root_hash {
+                anyhow::bail!(
+                    "pruning changed the root hash of the exported state: {} != {}",
+                    hex::encode(pruned_root_hash.0),
+                    hex::encode(root_hash.0)
+                );
+            }
+            tracing::info!(root_hash = %hex::encode(root_hash.0), "done pruning");
         }
-        RootCommand::Upgrade {
-            upgrade_path,
+        RootCommand::Prune {
+            home,
+            keep_recent,
+            keep_every,
+        } => {
+            let rocksdb_home = home.join("rocksdb");
+            let pruning = pruning_config(keep_recent, keep_every)?;
+            tracing::info!(?rocksdb_home, ?pruning, "pruning chain state");
+
+            let storage = Storage::load(rocksdb_home, SUBSTORE_PREFIXES.to_vec()).await?;
+            let root_hash = storage.latest_snapshot().root_hash().await?;
+            storage.prune(&pruning).await?;
+            tracing::info!(
+                version = storage.latest_version(),
+                root_hash = %hex::encode(root_hash.0),
+                "done pruning"
+            );
+            storage.release().await;
+        }
+        RootCommand::Migrate {
+            target_directory,
+            expected_app_hash,
             genesis_start,
         } => {
-            use upgrade::Upgrade::SimpleUpgrade;
-            tracing::info!("upgrading state from {}", upgrade_path.display());
-            SimpleUpgrade
-                .migrate(upgrade_path.clone(), genesis_start)
+            let expected_app_hash = expected_app_hash
+                .map(|app_hash| -> anyhow::Result<RootHash> {
+                    let bytes = hex::decode(app_hash).context("app hash must be hex-encoded")?;
+                    let bytes = bytes
+                        .try_into()
+                        .map_err(|_| anyhow::anyhow!("app hash must be 32 bytes long"))?;
+                    Ok(RootHash(bytes))
+                })
+                .transpose()?;
+            tracing::info!("migrating state in {}", target_directory.display());
+            migrate::migrate(target_directory, expected_app_hash, genesis_start)
                 .await
-                .context("failed to upgrade state")?;
+                .context("failed to migrate state")?;
         }
     }
     Ok(())
 }
+
+/// Builds the pruning configuration from the `--keep-recent` and `--keep-every` options.
+fn pruning_config(keep_recent: u64, keep_every: Option<u64>) -> anyhow::Result<PruningConfig> {
+    if keep_recent == 0 {
+        anyhow::bail!("'--keep-recent' must be greater than zero");
+    }
+    if keep_every == Some(0) {
+        anyhow::bail!("'--keep-every' must be greater than zero");
+    }
+    let mut pruning = PruningConfig::new(keep_recent);
+    pruning.checkpoint_interval = keep_every;
+    Ok(pruning)
+}
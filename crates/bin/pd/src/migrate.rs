@@ -0,0 +1,210 @@
+//! Migrations applied to the chain state when the chain halts for an upgrade plan.
+//!
+//! An `UpgradePlan` proposal names the migration to run. Once the chain reaches the planned
+//! height, it halts and records that name in its state; `pd migrate` then looks it up in the
+//! registry below, applies it in place, and writes the genesis file for the upgraded chain.
+use std::path::PathBuf;
+
+use anyhow::Context;
+use penumbra_app::{genesis, SUBSTORE_PREFIXES};
+use penumbra_chain::{
+    component::{StateReadExt, StateWriteExt},
+    genesis::Content as ChainContent,
+};
+use penumbra_storage::{ExpectedRoots, RootHash, Snapshot, StateDelta, StateWrite, Storage};
+use tendermint::Genesis;
+
+use crate::testnet::generate::{TestnetConfig, TestnetValidator};
+
+/// The registry of migrations known to this version of `pd`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Migration {
+    /// No-op migration
+    Noop,
+    /// A simple migration: adds a key to the consensus state.
+    /// This is useful for testing upgrade mechanisms, including in production.
+    SimpleMigration,
+    /// Migrates from testnet-64 to testnet-65.
+    Testnet65,
+}
+
+impl Migration {
+    /// All the migrations known to this version of `pd`.
+    pub const ALL: [Migration; 3] = [
+        Migration::Noop,
+        Migration::SimpleMigration,
+        Migration::Testnet65,
+    ];
+
+    /// The name an upgrade plan uses to select this migration.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Migration::Noop => "noop",
+            Migration::SimpleMigration => "simple",
+            Migration::Testnet65 => "testnet-65",
+        }
+    }
+
+    /// Looks up the migration selected by an upgrade plan's name.
+    pub fn from_plan_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|migration| migration.name() == name)
+    }
+
+    async fn apply(&self, delta: &mut StateDelta<Snapshot>) -> anyhow::Result<()> {
+        match self {
+            Migration::Noop => (),
+            Migration::SimpleMigration => {
+                delta.put_raw("has_migrated".to_string(), "yes".into());
+                delta.put_block_height(0u64);
+            }
+            Migration::Testnet65 => { /* currently a no-op. */ }
+        }
+        Ok(())
+    }
+}
+
+/// The result of applying a migration to the chain state.
+#[derive(Clone, Debug)]
+pub struct MigrationOutcome {
+    /// The migration that was applied.
+    pub migration: Migration,
+    /// The block height at which the chain halted for the upgrade.
+    pub halt_height: u64,
+    /// The app hash of the migrated state.
+    pub app_hash: RootHash,
+}
+
+/// Applies the migration named by the upgrade the chain has halted for, in place.
+///
+/// The migrated state is committed at the same version as the halted state. If an
+/// `expected_app_hash` is given, the migrated state is only written if it hashes to it, so that a
+/// node whose migration diverged from the rest of the network keeps its halted state. On success,
+/// the upgrade halt is cleared, so that the node can be restarted from the migrated state.
+///
+/// Like any state committed in place, the migrated state is not added to the snapshot cache of
+/// `storage`: it must be reloaded to read the migrated state back.
+pub async fn migrate_in_place(
+    storage: &Storage,
+    expected_app_hash: Option<RootHash>,
+) -> anyhow::Result<MigrationOutcome> {
+    let halted_state = storage.latest_snapshot();
+    let version = halted_state.version();
+    let name = halted_state
+        .upgrade_halt()
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("chain is not halted for an upgrade, nothing to migrate"))?;
+    let migration = Migration::from_plan_name(&name)
+        .ok_or_else(|| anyhow::anyhow!("no migration named {name:?} is known to this pd"))?;
+    let halt_height = halted_state
+        .get_block_height()
+        .await
+        .context("can get block height")?;
+
+    let app_hash_pre_migration = halted_state.root_hash().await?;
+    tracing::info!(?app_hash_pre_migration, migration = %name, "applying migration");
+
+    let mut delta = StateDelta::new(halted_state);
+    migration.apply(&mut delta).await?;
+    delta.clear_upgrade_halt();
+    let app_hash = match expected_app_hash {
+        Some(root_hash) => {
+            let expected_roots = ExpectedRoots {
+                root_hash,
+                substore_root_hashes: vec![],
+            };
+            storage
+                .commit_in_place_expecting(delta, &expected_roots)
+                .await
+                .context("migrated state does not match the expected app hash")?
+        }
+        None => {
+            tracing::warn!("no expected app hash provided, the migrated state is not verified");
+            storage.commit_in_place(delta).await?
+        }
+    };
+    tracing::info!(app_hash_post_migration = %hex::encode(app_hash.0), "migration applied");
+
+    let migrated_version = storage.latest_version();
+    anyhow::ensure!(
+        migrated_version == version,
+        "migration changed the state version from {} to {}",
+        version,
+        migrated_version
+    );
+
+    Ok(MigrationOutcome {
+        migration,
+        halt_height,
+        app_hash,
+    })
+}
+
+/// Builds the genesis for the upgraded chain, checkpointed at the migrated state.
+///
+/// The upgraded chain starts from the migrated storage rather than from genesis content: the
+/// genesis only carries the app hash of the migrated state, which commits to the state of every
+/// component, substores included. The chain parameters are used for the chain ID.
+pub async fn migrated_genesis(
+    storage: &Storage,
+    outcome: &MigrationOutcome,
+    genesis_start: Option<tendermint::time::Time>,
+) -> anyhow::Result<Genesis<genesis::AppState>> {
+    let migrated_state = storage.latest_snapshot();
+    let chain_params = migrated_state
+        .get_chain_params()
+        .await
+        .context("can get chain params")?;
+    let app_state = genesis::Content {
+        chain_content: ChainContent { chain_params },
+        ..Default::default()
+    };
+
+    let mut genesis = TestnetConfig::make_genesis(app_state)?;
+    genesis.app_hash = outcome
+        .app_hash
+        .0
+        .to_vec()
+        .try_into()
+        .expect("infaillible conversion");
+    genesis.initial_height = outcome.halt_height.wrapping_add(1) as i64;
+    genesis.genesis_time = genesis_start.unwrap_or_else(|| {
+        let now = tendermint::time::Time::now();
+        tracing::info!(%now, "no genesis time provided, detecting a testing setup");
+        now
+    });
+    let checkpoint = outcome.app_hash.0.to_vec();
+    Ok(TestnetConfig::make_checkpoint(genesis, Some(checkpoint)))
+}
+
+/// Runs the pending migration on the node state in `target_directory`, and writes the genesis
+/// and a fresh validator state for the upgraded chain alongside it.
+pub async fn migrate(
+    target_directory: PathBuf,
+    expected_app_hash: Option<RootHash>,
+    genesis_start: Option<tendermint::time::Time>,
+) -> anyhow::Result<()> {
+    let rocksdb_home = target_directory.join("rocksdb");
+    let storage = Storage::load(rocksdb_home.clone(), SUBSTORE_PREFIXES.to_vec()).await?;
+    let outcome = migrate_in_place(&storage, expected_app_hash).await?;
+    storage.release().await;
+
+    // The migrated state is not in the snapshot cache, since it was committed in place, so the
+    // genesis is built from storage reloaded from disk.
+    let storage = Storage::load(rocksdb_home, SUBSTORE_PREFIXES.to_vec()).await?;
+    let genesis = migrated_genesis(&storage, &outcome, genesis_start).await?;
+    storage.release().await;
+
+    let genesis_json = serde_json::to_string(&genesis).context("can serialize genesis")?;
+    tracing::info!("genesis: {}", genesis_json);
+    std::fs::write(target_directory.join("genesis.json"), genesis_json)
+        .context("can write genesis")?;
+    std::fs::write(
+        target_directory.join("priv_validator_state.json"),
+        TestnetValidator::initial_state(),
+    )
+    .context("can write validator state")?;
+
+    Ok(())
+}
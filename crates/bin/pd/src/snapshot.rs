@@ -1,15 +1,299 @@
+//! State sync support for `pd`.
+//!
+//! Snapshots of the chain state are periodically written to disk by a
+//! [`SnapshotStore`], and served to CometBFT through the ABCI snapshot
+//! connection. A node joining the network with state sync enabled restores
+//! its state from the chunks it is offered, checking each of them against
+//! the app hash that CometBFT obtained through its light client as it is
+//! written to its storage. The nonverifiable state is not taken from the
+//! snapshot: the state commitment tree is checked against the anchor recorded
+//! in the restored state, and the position indexes of the DEX are rebuilt
+//! locally. The history of the blocks up to the restored height, such as
+//! compact blocks, is not available, and the RPCs serving it refuse requests
+//! for those heights.
 use std::{
     future::Future,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
 };
 
 use futures::FutureExt;
+use penumbra_chain::component::{StateReadExt as _, StateWriteExt as _};
+use penumbra_dex::component::PositionManager as _;
+use penumbra_sct::component::SctManager as _;
+use penumbra_storage::{RootHash, StateDelta, Storage, SubstoreChunk};
+use sha2::{Digest, Sha256};
+use tendermint::abci::{
+    request,
+    response::{self, ApplySnapshotChunkResult},
+};
 use tendermint::v0_37::abci::{SnapshotRequest, SnapshotResponse};
+use tokio::sync::Mutex;
 use tower_abci::BoxError;
 
+mod format;
+mod store;
+
+pub use format::{Chunk, Metadata, FORMAT};
+pub use store::{Manifest, SnapshotStore};
+
 #[derive(Clone, Debug)]
-pub struct Snapshot {}
+pub struct Snapshot {
+    storage: Storage,
+    store: SnapshotStore,
+    /// The state of the restore in progress, if any.
+    restore: Arc<Mutex<Option<Restore>>>,
+}
+
+/// A snapshot that was accepted, and is being restored chunk by chunk.
+///
+/// Chunks are written to the storage as they are received, and the restored
+/// state only becomes visible once the last one has been applied.
+struct Restore {
+    metadata: Metadata,
+    restore: penumbra_storage::Restore,
+    next_chunk: u32,
+    /// The encoded state commitment tree, received so far.
+    state_commitment_tree: Vec<u8>,
+}
+
+impl std::fmt::Debug for Restore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Restore")
+            .field("version", &self.metadata.version)
+            .field("next_chunk", &self.next_chunk)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Snapshot {
+    pub fn new(storage: Storage, store: SnapshotStore) -> Self {
+        Self {
+            storage,
+            store,
+            restore: Default::default(),
+        }
+    }
+
+    fn list_snapshots(&self) -> anyhow::Result<response::ListSnapshots> {
+        let snapshots = self
+            .store
+            .list()?
+            .iter()
+            .map(Manifest::to_abci)
+            .collect::<anyhow::Result<_>>()?;
+        Ok(response::ListSnapshots { snapshots })
+    }
+
+    fn load_snapshot_chunk(
+        &self,
+        req: request::LoadSnapshotChunk,
+    ) -> anyhow::Result<response::LoadSnapshotChunk> {
+        let chunk = self
+            .store
+            .load_chunk(req.height.value(), req.format, req.chunk)?;
+        Ok(response::LoadSnapshotChunk {
+            chunk: chunk.into(),
+        })
+    }
+
+    async fn offer_snapshot(&self, req: request::OfferSnapshot) -> response::OfferSnapshot {
+        let snapshot = req.snapshot;
+        tracing::info!(height = ?snapshot.height, format = snapshot.format, "offered snapshot");
+
+        if snapshot.format != FORMAT {
+            return response::OfferSnapshot::RejectFormat;
+        }
+        if self.storage.latest_version() != u64::MAX {
+            tracing::warn!("refusing to restore a snapshot on top of existing state");
+            return response::OfferSnapshot::Abort;
+        }
+        if Metadata::hash(&snapshot.metadata).as_slice() != snapshot.hash.as_ref() {
+            tracing::warn!("snapshot hash does not match its metadata");
+            return response::OfferSnapshot::Reject;
+        }
+        let metadata = match Metadata::decode(&snapshot.metadata) {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                tracing::warn!(?e, "could not decode snapshot metadata");
+                return response::OfferSnapshot::Reject;
+            }
+        };
+        if metadata.chunk_hashes.len() != snapshot.chunks as usize
+            || metadata.version != snapshot.height.value()
+            || metadata.root_hash.as_slice() != req.app_hash.as_bytes()
+        {
+            tracing::warn!("snapshot metadata is inconsistent with the offer");
+            return response::OfferSnapshot::Reject;
+        }
+
+        // Throw away whatever was written for a snapshot we gave up on.
+        let mut guard = self.restore.lock().await;
+        if let Some(previous) = guard.take() {
+            if let Err(e) = previous.restore.abort().await {
+                tracing::error!(?e, "could not clear a previous restore");
+                return response::OfferSnapshot::Abort;
+            }
+        }
+
+        let restore = match self
+            .storage
+            .begin_restore(metadata.version, RootHash(metadata.root_hash))
+            .await
+        {
+            Ok(restore) => restore,
+            Err(e) => {
+                tracing::error!(?e, "could not start restoring the snapshot");
+                return response::OfferSnapshot::Abort;
+            }
+        };
+        *guard = Some(Restore {
+            metadata,
+            restore,
+            next_chunk: 0,
+            state_commitment_tree: Vec::new(),
+        });
+        response::OfferSnapshot::Accept
+    }
+
+    async fn apply_snapshot_chunk(
+        &self,
+        req: request::ApplySnapshotChunk,
+    ) -> anyhow::Result<response::ApplySnapshotChunk> {
+        let mut guard = self.restore.lock().await;
+        let Some(restore) = guard.as_mut() else {
+            anyhow::bail!("received a snapshot chunk, but no snapshot was accepted");
+        };
+
+        let result = |result| response::ApplySnapshotChunk {
+            result,
+            refetch_chunks: vec![],
+            reject_senders: vec![],
+        };
+
+        if req.index != restore.next_chunk {
+            tracing::warn!(
+                index = req.index,
+                expected = restore.next_chunk,
+                "out of order chunk"
+            );
+            return Ok(response::ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![restore.next_chunk],
+                reject_senders: vec![],
+            });
+        }
+
+        let expected_hash = restore
+            .metadata
+            .chunk_hashes
+            .get(req.index as usize)
+            .ok_or_else(|| anyhow::anyhow!("chunk index {} is out of bounds", req.index))?;
+        let chunk_hash: [u8; 32] = Sha256::digest(&req.chunk).into();
+        if &chunk_hash != expected_hash {
+            tracing::warn!(index = req.index, sender = %req.sender, "chunk hash mismatch");
+            return Ok(response::ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![req.index],
+                reject_senders: vec![req.sender],
+            });
+        }
+
+        let applied = restore.apply(&req.chunk).await;
+        restore.next_chunk += 1;
+        match applied {
+            Ok(()) if (restore.next_chunk as usize) < restore.metadata.chunk_hashes.len() => {
+                tracing::debug!(index = req.index, "applied snapshot chunk");
+                return Ok(result(ApplySnapshotChunkResult::Accept));
+            }
+            Ok(()) => {}
+            Err(e) => {
+                tracing::warn!(?e, index = req.index, sender = %req.sender, "invalid snapshot chunk");
+                let restore = guard.take().expect("a restore is in progress");
+                // Nothing is left behind, so CometBFT can try another snapshot.
+                restore.restore.abort().await?;
+                return Ok(result(ApplySnapshotChunkResult::RejectSnapshot));
+            }
+        }
+
+        // This was the last chunk: rebuild the nonverifiable state on top of
+        // the restored state, and make it visible.
+        let Restore {
+            metadata,
+            mut restore,
+            state_commitment_tree,
+            ..
+        } = guard.take().expect("a restore is in progress");
+        match rebuild_nonverifiable_state(&mut restore, &metadata, &state_commitment_tree).await {
+            Ok(delta) => restore.commit(delta).await?,
+            Err(e) => {
+                tracing::error!(
+                    ?e,
+                    app_hash = hex::encode(metadata.root_hash),
+                    "restored state does not match the snapshot"
+                );
+                restore.abort().await?;
+                return Ok(result(ApplySnapshotChunkResult::RejectSnapshot));
+            }
+        }
+
+        tracing::info!(version = metadata.version, "restored state from snapshot");
+        Ok(result(ApplySnapshotChunkResult::Accept))
+    }
+}
+
+impl Restore {
+    /// Checks a chunk against the root hash of its substore and writes it, or
+    /// holds onto it if it is part of the state commitment tree.
+    async fn apply(&mut self, bytes: &[u8]) -> anyhow::Result<()> {
+        match Chunk::decode(bytes)? {
+            Chunk::Substore {
+                prefix,
+                version,
+                leaves,
+                proof,
+            } => {
+                self.restore
+                    .add_chunk(SubstoreChunk {
+                        prefix,
+                        version,
+                        leaves,
+                        proof,
+                    })
+                    .await
+            }
+            Chunk::StateCommitmentTree(bytes) => {
+                self.state_commitment_tree.extend(bytes);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Checks that every chunk of the snapshot was restored, and rebuilds the
+/// nonverifiable state on top of the restored state.
+async fn rebuild_nonverifiable_state(
+    restore: &mut penumbra_storage::Restore,
+    metadata: &Metadata,
+    state_commitment_tree: &[u8],
+) -> anyhow::Result<StateDelta<penumbra_storage::Snapshot>> {
+    let state = restore.finish().await?;
+    let height = state.get_block_height().await?;
+    anyhow::ensure!(
+        height == metadata.version,
+        "restored state is at height {height}, but the snapshot is at version {}",
+        metadata.version
+    );
+
+    let mut delta = StateDelta::new(state);
+    delta
+        .restore_state_commitment_tree(bincode::deserialize(state_commitment_tree)?)
+        .await?;
+    delta.reindex_positions().await?;
+    delta.put_restored_height(height);
+    Ok(delta)
+}
 
 impl tower_service::Service<SnapshotRequest> for Snapshot {
     type Response = SnapshotResponse;
@@ -22,17 +306,221 @@ impl tower_service::Service<SnapshotRequest> for Snapshot {
     }
 
     fn call(&mut self, req: SnapshotRequest) -> Self::Future {
-        // No-op, we don't implement snapshot support
         use SnapshotRequest as Request;
         use SnapshotResponse as Response;
+        let snapshot = self.clone();
         async move {
             Ok(match req {
-                Request::ListSnapshots => Response::ListSnapshots(Default::default()),
-                Request::OfferSnapshot(_) => Response::OfferSnapshot(Default::default()),
-                Request::LoadSnapshotChunk(_) => Response::LoadSnapshotChunk(Default::default()),
-                Request::ApplySnapshotChunk(_) => Response::ApplySnapshotChunk(Default::default()),
+                Request::ListSnapshots => {
+                    Response::ListSnapshots(snapshot.list_snapshots().unwrap_or_else(|e| {
+                        tracing::error!(?e, "failed to list snapshots");
+                        Default::default()
+                    }))
+                }
+                Request::OfferSnapshot(req) => {
+                    Response::OfferSnapshot(snapshot.offer_snapshot(req).await)
+                }
+                Request::LoadSnapshotChunk(req) => Response::LoadSnapshotChunk(
+                    snapshot.load_snapshot_chunk(req).unwrap_or_else(|e| {
+                        tracing::error!(?e, "failed to load snapshot chunk");
+                        Default::default()
+                    }),
+                ),
+                Request::ApplySnapshotChunk(req) => Response::ApplySnapshotChunk(
+                    snapshot
+                        .apply_snapshot_chunk(req)
+                        .await
+                        .unwrap_or_else(|e| {
+                            tracing::error!(?e, "failed to apply snapshot chunk");
+                            response::ApplySnapshotChunk {
+                                result: ApplySnapshotChunkResult::Abort,
+                                ..Default::default()
+                            }
+                        }),
+                ),
             })
         }
         .boxed()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use penumbra_app::SUBSTORE_PREFIXES;
+    use penumbra_sct::component::StateReadExt as _;
+    use penumbra_storage::{StateRead, StateWrite, TempStorage};
+    use tower_service::Service;
+
+    #[tokio::test]
+    /// Creates a snapshot on one node, and restores it on another one through
+    /// the ABCI snapshot connection.
+    async fn restore_snapshot_on_second_node() -> anyhow::Result<()> {
+        let source = TempStorage::new_with_prefixes(SUBSTORE_PREFIXES.to_vec()).await?;
+        for height in 0..5u64 {
+            let mut delta = StateDelta::new(source.latest_snapshot());
+            delta.put_block_height(height);
+            delta.put_raw(format!("key_{height}"), b"value".to_vec());
+            for prefix in SUBSTORE_PREFIXES.iter() {
+                delta.put_raw(format!("{prefix}/key_{height}"), b"value".to_vec());
+            }
+            delta.nonverifiable_put_raw(format!("nv_{height}").into_bytes(), b"value".to_vec());
+            delta.end_sct_block(false).await?;
+            source.commit(delta).await?;
+        }
+
+        let source_dir = tempfile::tempdir()?;
+        let source_store =
+            SnapshotStore::new(source_dir.path().to_owned(), SUBSTORE_PREFIXES.to_vec(), 2)?;
+        let manifest = source_store.create(source.latest_snapshot()).await?;
+        assert_eq!(manifest.height, 4);
+        let app_hash = source.latest_snapshot().root_hash().await?;
+
+        let mut source_service = Snapshot::new(source.clone(), source_store);
+        let SnapshotResponse::ListSnapshots(list) = source_service
+            .call(SnapshotRequest::ListSnapshots)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?
+        else {
+            anyhow::bail!("unexpected response");
+        };
+        assert_eq!(list.snapshots.len(), 1);
+        let abci_snapshot = list.snapshots[0].clone();
+
+        let target = TempStorage::new_with_prefixes(SUBSTORE_PREFIXES.to_vec()).await?;
+        let target_dir = tempfile::tempdir()?;
+        let target_store =
+            SnapshotStore::new(target_dir.path().to_owned(), SUBSTORE_PREFIXES.to_vec(), 2)?;
+        let mut target_service = Snapshot::new(target.clone(), target_store);
+
+        // A snapshot whose chunks do not match the trusted app hash is rejected,
+        // without writing anything, so that another snapshot can be restored.
+        let mut forged_metadata = Metadata::decode(&abci_snapshot.metadata)?;
+        forged_metadata.root_hash = [1u8; 32];
+        let forged_metadata = forged_metadata.encode()?;
+        let forged_snapshot = tendermint::abci::types::Snapshot {
+            hash: Metadata::hash(&forged_metadata).to_vec().into(),
+            metadata: forged_metadata.into(),
+            ..abci_snapshot.clone()
+        };
+        let result = restore_through(
+            &mut source_service,
+            &mut target_service,
+            &forged_snapshot,
+            &abci_snapshot,
+            [1u8; 32].to_vec(),
+        )
+        .await?;
+        assert_eq!(result, ApplySnapshotChunkResult::RejectSnapshot);
+        assert_eq!(target.latest_version(), u64::MAX);
+
+        let result = restore_through(
+            &mut source_service,
+            &mut target_service,
+            &abci_snapshot,
+            &abci_snapshot,
+            app_hash.0.to_vec(),
+        )
+        .await?;
+        assert_eq!(result, ApplySnapshotChunkResult::Accept);
+
+        let restored = target.latest_snapshot();
+        assert_eq!(restored.version(), source.latest_version());
+        assert_eq!(restored.root_hash().await?, app_hash);
+        // Nonverifiable state is not taken from the snapshot, except for the
+        // state commitment tree, which is checked against the restored anchor.
+        assert_eq!(restored.nonverifiable_get_raw(b"nv_3").await?, None);
+        assert_eq!(
+            restored.state_commitment_tree().await.root(),
+            source
+                .latest_snapshot()
+                .state_commitment_tree()
+                .await
+                .root()
+        );
+        // The history up to the restored height was never executed on this node.
+        let restored_height = restored.get_block_height().await?;
+        assert_eq!(restored.restored_height().await?, Some(restored_height));
+        assert!(restored
+            .check_history_available(restored_height)
+            .await
+            .is_err());
+        restored
+            .check_history_available(restored_height + 1)
+            .await?;
+
+        // A node that already has state must not accept snapshots.
+        let rsp = target_service
+            .call(SnapshotRequest::OfferSnapshot(request::OfferSnapshot {
+                snapshot: abci_snapshot,
+                app_hash: app_hash.0.to_vec().try_into()?,
+            }))
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+        assert!(matches!(
+            rsp,
+            SnapshotResponse::OfferSnapshot(response::OfferSnapshot::Abort)
+        ));
+
+        Ok(())
+    }
+
+    /// Offers `offered` to the target, and applies the chunks of the `source`
+    /// snapshot to it, returning the result of applying the last chunk, or of
+    /// the first one that was not accepted.
+    async fn restore_through(
+        source_service: &mut Snapshot,
+        target_service: &mut Snapshot,
+        offered: &tendermint::abci::types::Snapshot,
+        source: &tendermint::abci::types::Snapshot,
+        app_hash: Vec<u8>,
+    ) -> anyhow::Result<ApplySnapshotChunkResult> {
+        let rsp = target_service
+            .call(SnapshotRequest::OfferSnapshot(request::OfferSnapshot {
+                snapshot: offered.clone(),
+                app_hash: app_hash.try_into()?,
+            }))
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+        assert!(matches!(
+            rsp,
+            SnapshotResponse::OfferSnapshot(response::OfferSnapshot::Accept)
+        ));
+
+        let mut result = ApplySnapshotChunkResult::Unknown;
+        for index in 0..offered.chunks {
+            let SnapshotResponse::LoadSnapshotChunk(chunk) = source_service
+                .call(SnapshotRequest::LoadSnapshotChunk(
+                    request::LoadSnapshotChunk {
+                        height: source.height,
+                        format: source.format,
+                        chunk: index,
+                    },
+                ))
+                .await
+                .map_err(|e| anyhow::anyhow!(e))?
+            else {
+                anyhow::bail!("unexpected response");
+            };
+
+            let SnapshotResponse::ApplySnapshotChunk(applied) = target_service
+                .call(SnapshotRequest::ApplySnapshotChunk(
+                    request::ApplySnapshotChunk {
+                        index,
+                        chunk: chunk.chunk,
+                        sender: "source".to_string(),
+                    },
+                ))
+                .await
+                .map_err(|e| anyhow::anyhow!(e))?
+            else {
+                anyhow::bail!("unexpected response");
+            };
+            result = applied.result;
+            if result != ApplySnapshotChunkResult::Accept {
+                break;
+            }
+        }
+        Ok(result)
+    }
+}
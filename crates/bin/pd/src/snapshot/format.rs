@@ -0,0 +1,78 @@
+//! The encoding of state sync snapshots.
+use penumbra_storage::SubstoreChunk;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// The snapshot format version, to be bumped on every breaking change to
+/// the encoding of chunks or metadata.
+pub const FORMAT: u32 = 2;
+
+/// The target size of a snapshot chunk. CometBFT rejects chunks larger than
+/// 16MB, so we leave some headroom for the last leaf of a chunk and its proof.
+pub const CHUNK_SIZE: usize = 10 * 1024 * 1024;
+
+/// The metadata attached to a snapshot, which lets a syncing node verify
+/// every chunk it receives, and the state it reconstructs from them.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Metadata {
+    /// The JMT version of the snapshotted state.
+    pub version: u64,
+    /// The root hash of the snapshotted state, i.e. the app hash.
+    pub root_hash: [u8; 32],
+    /// The SHA256 hash of each chunk, in order.
+    pub chunk_hashes: Vec<[u8; 32]>,
+}
+
+impl Metadata {
+    pub fn encode(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(bincode::serialize(self)?)
+    }
+
+    pub fn decode(bytes: &[u8]) -> anyhow::Result<Self> {
+        Ok(bincode::deserialize(bytes)?)
+    }
+
+    /// The hash of the encoded metadata, used as the snapshot hash.
+    pub fn hash(encoded: &[u8]) -> [u8; 32] {
+        Sha256::digest(encoded).into()
+    }
+}
+
+/// A chunk of a snapshot.
+///
+/// The verifiable state is exported substore by substore, main store first,
+/// as runs of leaves that are checked against the root hash of their substore
+/// as they are restored. The state commitment tree, which is checked against
+/// the anchor recorded in the restored state, comes last. The rest of the
+/// nonverifiable state is not part of the snapshot, and is rebuilt locally.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Chunk {
+    Substore {
+        prefix: String,
+        version: u64,
+        leaves: Vec<(String, Vec<u8>)>,
+        proof: Vec<u8>,
+    },
+    StateCommitmentTree(Vec<u8>),
+}
+
+impl From<SubstoreChunk> for Chunk {
+    fn from(chunk: SubstoreChunk) -> Self {
+        Chunk::Substore {
+            prefix: chunk.prefix,
+            version: chunk.version,
+            leaves: chunk.leaves,
+            proof: chunk.proof,
+        }
+    }
+}
+
+impl Chunk {
+    pub fn encode(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(bincode::serialize(self)?)
+    }
+
+    pub fn decode(bytes: &[u8]) -> anyhow::Result<Self> {
+        Ok(bincode::deserialize(bytes)?)
+    }
+}
@@ -0,0 +1,212 @@
+//! On-disk management of state sync snapshots.
+use std::path::PathBuf;
+
+use anyhow::Context;
+use futures::StreamExt;
+use penumbra_chain::component::StateReadExt;
+use penumbra_sct::component::StateReadExt as _;
+use penumbra_storage::Storage;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::format::{Chunk, Metadata, CHUNK_SIZE, FORMAT};
+
+/// The description of a snapshot persisted to disk, from which the
+/// snapshot advertised to CometBFT is derived.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Manifest {
+    /// The block height at which the snapshot was taken.
+    pub height: u64,
+    /// The format of the snapshot chunks.
+    pub format: u32,
+    /// The contents of the snapshot, used to verify chunks on restore.
+    pub metadata: Metadata,
+}
+
+impl Manifest {
+    /// Converts the manifest into the snapshot description expected by CometBFT.
+    pub fn to_abci(&self) -> anyhow::Result<tendermint::abci::types::Snapshot> {
+        let metadata = self.metadata.encode()?;
+        Ok(tendermint::abci::types::Snapshot {
+            height: self.height.try_into()?,
+            format: self.format,
+            chunks: self.metadata.chunk_hashes.len().try_into()?,
+            hash: Metadata::hash(&metadata).to_vec().into(),
+            metadata: metadata.into(),
+        })
+    }
+}
+
+/// A directory of state sync snapshots.
+///
+/// Each snapshot is stored in a subdirectory named after its height, which
+/// contains a `manifest.json` file and the chunks of the snapshot.
+#[derive(Clone, Debug)]
+pub struct SnapshotStore {
+    dir: PathBuf,
+    /// The prefixes of the substores to include in the snapshots.
+    substore_prefixes: Vec<String>,
+    /// The number of snapshots to keep around, older ones are deleted.
+    keep_recent: usize,
+}
+
+impl SnapshotStore {
+    pub fn new(
+        dir: PathBuf,
+        substore_prefixes: Vec<String>,
+        keep_recent: usize,
+    ) -> anyhow::Result<Self> {
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("could not create snapshot directory {}", dir.display()))?;
+        Ok(Self {
+            dir,
+            substore_prefixes,
+            keep_recent,
+        })
+    }
+
+    /// Spawns a task that creates a snapshot every `interval` blocks.
+    pub fn spawn(self, storage: Storage, interval: u64) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut rx = storage.subscribe();
+            let mut last_taken = None;
+            while rx.changed().await.is_ok() {
+                let version = rx.borrow_and_update().version();
+                // We might have missed a few versions while writing the last
+                // snapshot, so we check whether we crossed an interval boundary,
+                // and look up the matching state in the snapshot cache.
+                let target = version - version % interval;
+                if target == 0 || last_taken == Some(target) {
+                    continue;
+                }
+                last_taken = Some(target);
+
+                let Some(state) = storage.snapshot(target) else {
+                    tracing::warn!(
+                        ?target,
+                        "state was evicted before a snapshot could be taken"
+                    );
+                    continue;
+                };
+                match self.create(state).await {
+                    Ok(manifest) => {
+                        tracing::info!(height = manifest.height, "created state sync snapshot")
+                    }
+                    Err(e) => tracing::error!(?e, ?target, "failed to create snapshot"),
+                }
+            }
+        })
+    }
+
+    /// Writes a snapshot of the given state to disk, and prunes older snapshots.
+    pub async fn create(&self, state: penumbra_storage::Snapshot) -> anyhow::Result<Manifest> {
+        let height = state.get_block_height().await?;
+        let version = state.version();
+        let tmp_dir = self.dir.join(format!(".tmp-{height}"));
+        let _ = std::fs::remove_dir_all(&tmp_dir);
+        std::fs::create_dir_all(&tmp_dir)?;
+
+        let mut writer = ChunkWriter {
+            dir: tmp_dir.clone(),
+            chunk_hashes: Vec::new(),
+        };
+
+        // The main store comes first, since it commits to the root hashes of
+        // the substores, then every substore.
+        let prefixes = std::iter::once("").chain(self.substore_prefixes.iter().map(|p| p.as_str()));
+        for prefix in prefixes {
+            let mut chunks = state.substore_chunks(prefix, CHUNK_SIZE);
+            while let Some(chunk) = chunks.next().await {
+                writer.write(&chunk?.into())?;
+            }
+        }
+
+        // The state commitment tree is the only nonverifiable state that cannot
+        // be rebuilt from the verifiable state, so it is exported alongside it.
+        let tree = bincode::serialize(&state.state_commitment_tree().await)?;
+        for bytes in tree.chunks(CHUNK_SIZE) {
+            writer.write(&Chunk::StateCommitmentTree(bytes.to_vec()))?;
+        }
+        let chunk_hashes = writer.chunk_hashes;
+
+        let manifest = Manifest {
+            height,
+            format: FORMAT,
+            metadata: Metadata {
+                version,
+                root_hash: state.root_hash().await?.0,
+                chunk_hashes,
+            },
+        };
+        std::fs::write(
+            tmp_dir.join("manifest.json"),
+            serde_json::to_vec(&manifest)?,
+        )?;
+
+        let final_dir = self.dir.join(height.to_string());
+        let _ = std::fs::remove_dir_all(&final_dir);
+        std::fs::rename(&tmp_dir, &final_dir)?;
+
+        self.prune()?;
+        Ok(manifest)
+    }
+
+    /// Returns the manifests of the snapshots available on disk, most recent first.
+    pub fn list(&self) -> anyhow::Result<Vec<Manifest>> {
+        let mut manifests = Vec::new();
+        for dir_entry in std::fs::read_dir(&self.dir)? {
+            let path = dir_entry?.path();
+            // Skip snapshots that are still being written.
+            if path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .map_or(true, |name| name.starts_with('.'))
+            {
+                continue;
+            }
+            match std::fs::read(path.join("manifest.json")) {
+                Ok(bytes) => manifests.push(serde_json::from_slice::<Manifest>(&bytes)?),
+                Err(e) => tracing::warn!(?e, ?path, "skipping snapshot without a manifest"),
+            }
+        }
+        manifests.sort_by(|a, b| b.height.cmp(&a.height));
+        Ok(manifests)
+    }
+
+    /// Reads a chunk of the snapshot taken at `height`.
+    pub fn load_chunk(&self, height: u64, format: u32, index: u32) -> anyhow::Result<Vec<u8>> {
+        if format != FORMAT {
+            anyhow::bail!("unsupported snapshot format {format}");
+        }
+        let path = self
+            .dir
+            .join(height.to_string())
+            .join(format!("chunk-{index}"));
+        std::fs::read(&path).with_context(|| format!("could not read chunk {}", path.display()))
+    }
+
+    /// Deletes all but the `keep_recent` most recent snapshots.
+    fn prune(&self) -> anyhow::Result<()> {
+        for manifest in self.list()?.into_iter().skip(self.keep_recent) {
+            tracing::debug!(height = manifest.height, "pruning snapshot");
+            std::fs::remove_dir_all(self.dir.join(manifest.height.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the chunks of a snapshot to disk as they are produced.
+struct ChunkWriter {
+    dir: PathBuf,
+    chunk_hashes: Vec<[u8; 32]>,
+}
+
+impl ChunkWriter {
+    fn write(&mut self, chunk: &Chunk) -> anyhow::Result<()> {
+        let bytes = chunk.encode()?;
+        let index = self.chunk_hashes.len();
+        std::fs::write(self.dir.join(format!("chunk-{index}")), &bytes)?;
+        self.chunk_hashes.push(Sha256::digest(&bytes).into());
+        Ok(())
+    }
+}
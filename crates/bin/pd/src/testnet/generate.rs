@@ -185,13 +185,13 @@ impl TestnetConfig {
         unbonding_epochs: Option<u64>,
         proposal_voting_blocks: Option<u64>,
     ) -> anyhow::Result<genesis::Content> {
-        let default_gov_params = penumbra_governance::params::GovernanceParameters::default();
-
-        let gov_params = penumbra_governance::params::GovernanceParameters {
-            proposal_voting_blocks: proposal_voting_blocks
-                .unwrap_or(default_gov_params.proposal_voting_blocks),
-            ..default_gov_params
-        };
+        // The voting period override applies to every kind of proposal.
+        let mut gov_params = penumbra_governance::params::GovernanceParameters::default();
+        if let Some(voting_blocks) = proposal_voting_blocks {
+            for kind in penumbra_governance::ProposalKind::ALL {
+                gov_params.for_kind_mut(kind).voting_blocks = voting_blocks;
+            }
+        }
 
         // Look up default app params, so we can fill in defaults.
         let default_app_params = AppParameters::default();
@@ -219,6 +219,7 @@ impl TestnetConfig {
                     // Fall back to chain param defaults
                     epoch_duration: epoch_duration
                         .unwrap_or(default_app_params.chain_params.epoch_duration),
+                    ..Default::default()
                 },
             },
             ..Default::default()
@@ -0,0 +1,232 @@
+use std::path::Path;
+
+use pd::migrate::{migrate_in_place, migrated_genesis, Migration};
+use penumbra_app::{app::App, genesis, TempStorageExt, SUBSTORE_PREFIXES};
+use penumbra_chain::{
+    component::{StateReadExt, StateWriteExt},
+    genesis::Content as ChainContent,
+    params::ChainParameters,
+};
+use penumbra_ibc::IBC_SUBSTORE_PREFIX;
+use penumbra_storage::{RootHash, StateDelta, StateRead, StateWrite, Storage, TempStorage};
+
+/// Initializes storage in `dir` with the given genesis.
+async fn storage_with_genesis(dir: &Path, app_state: genesis::AppState) -> anyhow::Result<Storage> {
+    let storage = Storage::load(dir.join("rocksdb"), SUBSTORE_PREFIXES.to_vec()).await?;
+    let mut app = App::new(storage.latest_snapshot()).await?;
+    app.init_chain(&app_state).await;
+    app.commit(storage.clone()).await;
+    Ok(storage)
+}
+
+/// Reloads the storage in `dir` from disk. A migrated state is committed in place, so it is not
+/// in the snapshot cache of the storage that committed it.
+async fn reload(dir: &Path, storage: Storage) -> anyhow::Result<Storage> {
+    storage.release().await;
+    Storage::load(dir.join("rocksdb"), SUBSTORE_PREFIXES.to_vec()).await
+}
+
+/// Initializes storage in `dir` and halts it for an upgrade plan with the given name, returning
+/// the height of the halt.
+async fn halted_storage(dir: &Path, plan_name: &str) -> anyhow::Result<(Storage, u64)> {
+    let app_state = genesis::AppState::Content(genesis::Content {
+        chain_content: ChainContent {
+            chain_params: ChainParameters {
+                chain_id: "penumbra-migrate-test".to_string(),
+                ..Default::default()
+            },
+        },
+        ..Default::default()
+    });
+    let storage = storage_with_genesis(dir, app_state).await?;
+
+    // Schedule the upgrade for the next block, as an enacted upgrade plan would...
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    // ...alongside some substore state, which must survive the migration.
+    delta.put_raw(
+        format!("{IBC_SUBSTORE_PREFIX}/migrate-test"),
+        b"ibc state".to_vec(),
+    );
+    let halt_height = delta.get_block_height().await?;
+    delta
+        .signal_upgrade(halt_height + 1, plan_name.to_string())
+        .await?;
+
+    // ...and halt for it on commit, as `App::commit` does (without exiting the process).
+    assert!(delta.is_upgrade_height().await?);
+    delta.signal_upgrade_halt().await?;
+    storage.commit(delta).await?;
+
+    Ok((storage, halt_height))
+}
+
+#[tokio::test]
+async fn halted_chain_is_migrated_in_place() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (storage, halt_height) =
+        halted_storage(dir.path(), Migration::SimpleMigration.name()).await?;
+    let halted_version = storage.latest_version();
+    let halted_ibc_root = storage
+        .latest_snapshot()
+        .prefix_root_hash(IBC_SUBSTORE_PREFIX)
+        .await?;
+
+    // The node refuses to restart until the migration has been applied.
+    let error = App::new(storage.latest_snapshot())
+        .await
+        .err()
+        .expect("halted chain must not restart");
+    assert!(error.to_string().contains("pd migrate"));
+
+    let outcome = migrate_in_place(&storage, None).await?;
+    assert_eq!(outcome.migration, Migration::SimpleMigration);
+    assert_eq!(outcome.halt_height, halt_height);
+
+    // The migration is committed in place, and the upgrade halt is cleared.
+    let storage = reload(dir.path(), storage).await?;
+    let migrated_state = storage.latest_snapshot();
+    assert_eq!(migrated_state.version(), halted_version);
+    assert_eq!(migrated_state.root_hash().await?, outcome.app_hash);
+    assert_eq!(
+        migrated_state.get_raw("has_migrated").await?,
+        Some(b"yes".to_vec())
+    );
+    assert_eq!(migrated_state.upgrade_halt().await?, None);
+    // The state of other components carries over untouched, substores included.
+    assert_eq!(
+        migrated_state.prefix_root_hash(IBC_SUBSTORE_PREFIX).await?,
+        halted_ibc_root
+    );
+    assert_eq!(
+        migrated_state
+            .get_raw(&format!("{IBC_SUBSTORE_PREFIX}/migrate-test"))
+            .await?,
+        Some(b"ibc state".to_vec())
+    );
+    assert!(!migrated_state.is_upgrade_height().await?);
+    App::new(storage.latest_snapshot()).await?;
+
+    // The upgraded chain starts from a checkpoint of the migrated state, which commits to the
+    // state of every component.
+    let upgraded_genesis = migrated_genesis(&storage, &outcome, None).await?;
+    assert_eq!(upgraded_genesis.initial_height, (halt_height + 1) as i64);
+    assert_eq!(
+        upgraded_genesis.app_hash.as_bytes(),
+        &outcome.app_hash.0[..]
+    );
+    assert!(matches!(
+        upgraded_genesis.app_state,
+        genesis::AppState::Checkpoint(checkpoint) if checkpoint == outcome.app_hash.0.to_vec()
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unknown_migration_is_rejected() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (storage, _) = halted_storage(dir.path(), "not-a-migration").await?;
+    let halted_root_hash = storage.latest_snapshot().root_hash().await?;
+
+    assert!(migrate_in_place(&storage, None).await.is_err());
+
+    // The state is left untouched, and the chain stays halted.
+    let storage = reload(dir.path(), storage).await?;
+    let state = storage.latest_snapshot();
+    assert_eq!(state.root_hash().await?, halted_root_hash);
+    assert_eq!(
+        state.upgrade_halt().await?,
+        Some("not-a-migration".to_string())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn running_chain_is_not_migrated() -> anyhow::Result<()> {
+    let storage = TempStorage::new_with_prefixes(SUBSTORE_PREFIXES.to_vec())
+        .await?
+        .apply_default_genesis()
+        .await?;
+
+    assert!(migrate_in_place(&storage, None).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn migrated_state_is_checked_against_expected_app_hash() -> anyhow::Result<()> {
+    // The expected app hash is computed independently, by migrating another node.
+    let reference_dir = tempfile::tempdir()?;
+    let (reference, _) =
+        halted_storage(reference_dir.path(), Migration::SimpleMigration.name()).await?;
+    let expected_app_hash = migrate_in_place(&reference, None).await?.app_hash;
+
+    let dir = tempfile::tempdir()?;
+    let (storage, _) = halted_storage(dir.path(), Migration::SimpleMigration.name()).await?;
+    let outcome = migrate_in_place(&storage, Some(expected_app_hash)).await?;
+    assert_eq!(outcome.app_hash, expected_app_hash);
+    let storage = reload(dir.path(), storage).await?;
+    assert_eq!(
+        storage.latest_snapshot().root_hash().await?,
+        expected_app_hash
+    );
+
+    // A node whose migrated state diverges keeps its halted state.
+    let dir = tempfile::tempdir()?;
+    let (storage, _) = halted_storage(dir.path(), Migration::SimpleMigration.name()).await?;
+    let halted_root_hash = storage.latest_snapshot().root_hash().await?;
+    assert!(migrate_in_place(&storage, Some(RootHash([1; 32])))
+        .await
+        .is_err());
+
+    let storage = reload(dir.path(), storage).await?;
+    let state = storage.latest_snapshot();
+    assert_eq!(state.root_hash().await?, halted_root_hash);
+    assert_eq!(state.get_raw("has_migrated").await?, None);
+    assert_eq!(
+        state.upgrade_halt().await?,
+        Some(Migration::SimpleMigration.name().to_string())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn upgrade_scheduled_in_nonverifiable_storage_still_halts() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let storage = storage_with_genesis(dir.path(), Default::default()).await?;
+
+    // Schedule the upgrade where nodes used to schedule it, before upgrading.
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    let halt_height = delta.get_block_height().await?;
+    delta.nonverifiable_put_raw(
+        b"chain/next_upgrade".to_vec(),
+        (halt_height + 1).to_be_bytes().to_vec(),
+    );
+    delta.nonverifiable_put_raw(
+        b"chain/next_upgrade_name".to_vec(),
+        Migration::SimpleMigration.name().as_bytes().to_vec(),
+    );
+    storage.commit(delta).await?;
+
+    // The upgraded node still halts for it...
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    assert!(delta.is_upgrade_height().await?);
+    assert_eq!(
+        delta.next_upgrade_name().await?,
+        Some(Migration::SimpleMigration.name().to_string())
+    );
+    delta.signal_upgrade_halt().await?;
+    storage.commit(delta).await?;
+
+    // ...and the migration clears it.
+    migrate_in_place(&storage, None).await?;
+    let storage = reload(dir.path(), storage).await?;
+    let migrated_state = storage.latest_snapshot();
+    assert!(!migrated_state.is_upgrade_height().await?);
+    assert_eq!(migrated_state.next_upgrade_name().await?, None);
+    assert_eq!(migrated_state.upgrade_halt().await?, None);
+
+    Ok(())
+}
@@ -26,8 +26,11 @@ impl ActionHandler for Action {
             // These actions don't require a context
             Action::Delegate(action) => action.check_stateless(()).await,
             Action::Undelegate(action) => action.check_stateless(()).await,
+            Action::Redelegate(action) => action.check_stateless(()).await,
+            Action::RedelegateClaim(action) => action.check_stateless(()).await,
             Action::UndelegateClaim(action) => action.check_stateless(()).await,
             Action::ValidatorDefinition(action) => action.check_stateless(()).await,
+            Action::ValidatorUnjail(action) => action.check_stateless(()).await,
             Action::ValidatorVote(action) => action.check_stateless(()).await,
             Action::PositionClose(action) => action.check_stateless(()).await,
             Action::PositionOpen(action) => action.check_stateless(()).await,
@@ -56,8 +59,11 @@ impl ActionHandler for Action {
         match self {
             Action::Delegate(action) => action.check_stateful(state).await,
             Action::Undelegate(action) => action.check_stateful(state).await,
+            Action::Redelegate(action) => action.check_stateful(state).await,
+            Action::RedelegateClaim(action) => action.check_stateful(state).await,
             Action::UndelegateClaim(action) => action.check_stateful(state).await,
             Action::ValidatorDefinition(action) => action.check_stateful(state).await,
+            Action::ValidatorUnjail(action) => action.check_stateful(state).await,
             Action::DelegatorVote(action) => action.check_stateful(state).await,
             Action::ValidatorVote(action) => action.check_stateful(state).await,
             Action::PositionClose(action) => action.check_stateful(state).await,
@@ -93,8 +99,11 @@ impl ActionHandler for Action {
         match self {
             Action::Delegate(action) => action.execute(state).await,
             Action::Undelegate(action) => action.execute(state).await,
+            Action::Redelegate(action) => action.execute(state).await,
+            Action::RedelegateClaim(action) => action.execute(state).await,
             Action::UndelegateClaim(action) => action.execute(state).await,
             Action::ValidatorDefinition(action) => action.execute(state).await,
+            Action::ValidatorUnjail(action) => action.execute(state).await,
             Action::DelegatorVote(action) => action.execute(state).await,
             Action::ValidatorVote(action) => action.execute(state).await,
             Action::PositionClose(action) => action.execute(state).await,
@@ -9,6 +9,7 @@ use once_cell::sync::Lazy;
 use penumbra_asset::STAKING_TOKEN_DENOM;
 use penumbra_chain::component::StateReadExt as _;
 use penumbra_dao::component::StateReadExt as _;
+use penumbra_ibc::component::ClientStateReadExt as _;
 use penumbra_keys::keys::{FullViewingKey, NullifierKey};
 use penumbra_proto::DomainType;
 use penumbra_sct::component::StateReadExt as _;
@@ -24,7 +25,7 @@ use crate::dao_ext::DaoStateWriteExt;
 use crate::params::AppParameters;
 use penumbra_governance::{
     component::{StateReadExt as _, StateWriteExt as _},
-    proposal::{Proposal, ProposalPayload},
+    proposal::{Proposal, ProposalKind, ProposalPayload},
     proposal_state::State as ProposalState,
     ProposalNft, ProposalSubmit, VotingReceiptToken,
 };
@@ -104,8 +105,9 @@ impl ActionHandler for ProposalSubmit {
                                 "invalid action in DAO spend proposal (would require proving)"
                             )
                         }
-                        Delegate(_) | Undelegate(_) => {
+                        Delegate(_) | Undelegate(_) | Redelegate(_) | RedelegateClaim(_) => {
                             // Delegation and undelegation is disallowed due to Undelegateclaim requiring proving.
+                            // Redelegation is disallowed along with them, since the DAO can't delegate.
                             anyhow::bail!(
                                 "invalid action in DAO spend proposal (can't claim outputs of undelegation)"
                             )
@@ -116,6 +118,7 @@ impl ActionHandler for ProposalSubmit {
                             anyhow::bail!("invalid action in DAO spend proposal (not allowed to manipulate proposals from within proposals)")
                         }
                         ValidatorDefinition(_)
+                        | ValidatorUnjail(_)
                         | IbcAction(_)
                         | ValidatorVote(_)
                         | PositionOpen(_)
@@ -132,7 +135,23 @@ impl ActionHandler for ProposalSubmit {
                     }
                 }
             }
-            UpgradePlan { .. } => {}
+            UpgradePlan { height: _, name } => {
+                // The name selects the migration `pd migrate` applies once the chain halts, so an
+                // unnamed plan could never be carried out.
+                if name.is_empty() {
+                    anyhow::bail!("upgrade plan proposals must name a migration");
+                }
+            }
+            IbcClientRecovery {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                if subject_client_id == substitute_client_id {
+                    anyhow::bail!(
+                        "ibc client recovery proposals must name different subject and substitute clients"
+                    );
+                }
+            }
         }
 
         Ok(())
@@ -144,14 +163,18 @@ impl ActionHandler for ProposalSubmit {
             proposal, // statelessly verified
         } = self;
 
-        // Check that the deposit amount agrees with the parameters
+        // Check that the deposit amount agrees with the parameters for this kind of proposal
         let governance_parameters = state.get_governance_params().await?;
-        if *deposit_amount != governance_parameters.proposal_deposit_amount {
+        let required_deposit = governance_parameters
+            .for_kind(proposal.kind())
+            .deposit_amount;
+        if *deposit_amount != required_deposit {
             anyhow::bail!(
-                "submitted proposal deposit of {}{} does not match required proposal deposit of {}{}",
+                "submitted proposal deposit of {}{} does not match required {} proposal deposit of {}{}",
                 deposit_amount,
                 *STAKING_TOKEN_DENOM,
-                governance_parameters.proposal_deposit_amount,
+                proposal.kind(),
+                required_deposit,
                 *STAKING_TOKEN_DENOM,
             );
         }
@@ -200,8 +223,26 @@ impl ActionHandler for ProposalSubmit {
                     "submitted DAO spend transaction failed to execute in current chain state",
                 )?;
             }
-            ProposalPayload::UpgradePlan { .. } => {
-                // TODO(erwan): no stateful checks for upgrade plan.
+            ProposalPayload::UpgradePlan { height, name: _ } => {
+                // The upgrade can only be scheduled if it would happen after voting concludes.
+                state
+                    .check_height_in_future_of_voting_end(ProposalKind::UpgradePlan, *height)
+                    .await?;
+            }
+            ProposalPayload::IbcClientRecovery {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                // Both clients must exist, but the rest of the recovery is checked when the
+                // proposal is enacted, since the status of either client may change during voting.
+                state
+                    .get_client_state(subject_client_id)
+                    .await
+                    .context("subject client of ibc client recovery proposal does not exist")?;
+                state
+                    .get_client_state(substitute_client_id)
+                    .await
+                    .context("substitute client of ibc client recovery proposal does not exist")?;
             }
         }
 
@@ -252,16 +293,18 @@ impl ActionHandler for ProposalSubmit {
         state.put_proposal_state(proposal_id, ProposalState::Voting);
 
         // Determine what block it is currently, and calculate when the proposal should start voting
-        // (now!) and finish voting (later...), then write that into the state
-        let governance_params = state
+        // (now!) and finish voting (later...) according to its kind, then write that into the state
+        let voting_blocks = state
             .get_governance_params()
             .await
-            .context("can get chain params")?;
+            .context("can get chain params")?
+            .for_kind(proposal.kind())
+            .voting_blocks;
         let current_block = state
             .get_block_height()
             .await
             .context("can get block height")?;
-        let voting_end = current_block + governance_params.proposal_voting_blocks;
+        let voting_end = current_block + voting_blocks;
         state.put_proposal_voting_start(proposal_id, current_block);
         state.put_proposal_voting_end(proposal_id, voting_end);
 
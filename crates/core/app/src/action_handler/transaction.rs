@@ -2,9 +2,12 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
-use penumbra_chain::NoteSource;
+use penumbra_chain::{component::StateWriteExt as _, NoteSource};
+use penumbra_fee::component::StateWriteExt as _;
+use penumbra_proof_params::BatchVerifier;
 use penumbra_storage::{StateRead, StateWrite};
-use penumbra_transaction::Transaction;
+use penumbra_transaction::{gas::GasCost, Transaction};
+use rand_core::OsRng;
 use tokio::task::JoinSet;
 use tracing::{instrument, Instrument};
 
@@ -15,8 +18,9 @@ mod stateless;
 
 use self::stateful::{claimed_anchor_is_valid, fee_greater_than_base_fee, fmd_parameters_valid};
 use stateless::{
-    check_memo_exists_if_outputs_absent_if_not, no_duplicate_spends, no_duplicate_votes,
-    num_clues_equal_to_num_outputs, valid_binding_signature,
+    check_memo_exists_if_outputs_absent_if_not, check_proof_actions, has_proof,
+    no_duplicate_spends, no_duplicate_votes, num_clues_equal_to_num_outputs,
+    valid_binding_signature,
 };
 
 #[async_trait]
@@ -37,12 +41,23 @@ impl ActionHandler for Transaction {
 
         let context = self.context();
 
+        // Rather than verifying each action's proof on its own, verify all the
+        // proofs for the same circuit at once, which saves most of the pairings.
+        let mut proofs = BatchVerifier::new();
+        check_proof_actions(self, &mut proofs)?;
+
         // Currently, we need to clone the component actions so that the spawned
         // futures can have 'static lifetimes. In the future, we could try to
         // use the yoke crate, but cloning is almost certainly not a big deal
         // for now.
         let mut action_checks = JoinSet::new();
+        let span = tracing::Span::current();
+        action_checks.spawn_blocking(move || span.in_scope(|| proofs.verify(&mut OsRng)));
         for (i, action) in self.actions().cloned().enumerate() {
+            // Actions with proofs have already been checked, with their proofs in the batch.
+            if has_proof(&action) {
+                continue;
+            }
             let context2 = context.clone();
             let span = action.create_span(i);
             action_checks
@@ -98,6 +113,20 @@ impl ActionHandler for Transaction {
         // Delete the note source, in case someone else tries to read it.
         state.object_delete("source");
 
+        // Record the gas used by the transaction, so that the fee component can
+        // adjust the gas prices at the end of the block.
+        state.add_gas_used(self.gas_cost());
+
+        // Record the clues included by the transaction, so that the FMD parameters
+        // can be adjusted to the clue volume at the end of the epoch. The count is
+        // aggregated over the block, and only written to storage in `end_block`.
+        let num_clues = self
+            .transaction_body()
+            .detection_data
+            .map(|data| data.fmd_clues.len())
+            .unwrap_or_default();
+        state.add_fmd_clue_count(num_clues as u64);
+
         Ok(())
     }
 }
@@ -26,21 +26,22 @@ pub(super) async fn fmd_parameters_valid<S: StateRead>(
         .get_current_fmd_parameters()
         .await
         .expect("chain params request must succeed");
+    let grace_period_blocks = state.get_chain_params().await?.fmd_grace_period_blocks;
     let height = state.get_block_height().await?;
     fmd_precision_within_grace_period(
         transaction,
         previous_fmd_parameters,
         current_fmd_parameters,
+        grace_period_blocks,
         height,
     )
 }
 
-const FMD_GRACE_PERIOD_BLOCKS: u64 = 10;
-
 pub fn fmd_precision_within_grace_period(
     tx: &Transaction,
     previous_fmd_parameters: FmdParameters,
     current_fmd_parameters: FmdParameters,
+    grace_period_blocks: u64,
     block_height: u64,
 ) -> anyhow::Result<()> {
     for clue in tx
@@ -49,12 +50,14 @@ pub fn fmd_precision_within_grace_period(
         .unwrap_or_default()
         .fmd_clues
     {
-        // Clue must be using the current `FmdParameters`, or be within
-        // `FMD_GRACE_PERIOD_BLOCKS` of the previous `FmdParameters`.
+        // Clue must be using the current `FmdParameters`, or be using the previous
+        // `FmdParameters` within `grace_period_blocks` of them being replaced.
         if clue.precision_bits() == current_fmd_parameters.precision_bits
             || (clue.precision_bits() == previous_fmd_parameters.precision_bits
                 && block_height
-                    < previous_fmd_parameters.as_of_block_height + FMD_GRACE_PERIOD_BLOCKS)
+                    < current_fmd_parameters
+                        .as_of_block_height
+                        .saturating_add(grace_period_blocks))
         {
             continue;
         } else {
@@ -68,14 +71,20 @@ pub(super) async fn fee_greater_than_base_fee<S: StateRead>(
     state: S,
     transaction: &Transaction,
 ) -> Result<()> {
+    let fee = transaction.transaction_body().fee;
     let current_gas_prices = state
-        .get_gas_prices()
+        .get_gas_prices_for_asset(fee.asset_id())
         .await
-        .expect("gas prices must be present in state");
+        .expect("gas prices must be present in state")
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "consensus rule violated: transaction fee must be paid in the staking token or an approved alternative fee asset"
+            )
+        })?;
 
     let transaction_base_price = current_gas_prices.price(&transaction.gas_cost());
 
-    if transaction.transaction_body().fee.amount() >= transaction_base_price {
+    if fee.amount() >= transaction_base_price {
         Ok(())
     } else {
         Err(anyhow::anyhow!(
@@ -83,3 +92,132 @@ pub(super) async fn fee_greater_than_base_fee<S: StateRead>(
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_dao::DaoDeposit;
+    use penumbra_fee::{component::StateWriteExt as _, Fee, FeeParameters, GasPrices};
+    use penumbra_keys::test_keys;
+    use penumbra_storage::{StateDelta, TempStorage};
+    use penumbra_transaction::{Action, DetectionData, Transaction};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn gas_prices(asset_id: asset::Id, execution_price: u64) -> GasPrices {
+        GasPrices {
+            asset_id,
+            execution_price,
+            ..GasPrices::zero()
+        }
+    }
+
+    /// A transaction with a single action, so that its gas cost is nonzero, paying the given fee.
+    fn transaction_with_fee(fee: Value) -> Transaction {
+        let mut transaction = Transaction::default();
+        transaction.transaction_body.actions = vec![Action::DaoDeposit(DaoDeposit {
+            value: Value {
+                amount: 1u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+        })];
+        transaction.transaction_body.fee = Fee(fee);
+        transaction
+    }
+
+    #[tokio::test]
+    async fn fee_must_be_paid_in_an_approved_asset() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        let alt_asset = asset::Id(decaf377::Fq::from(1u64));
+        let unapproved_asset = asset::Id(decaf377::Fq::from(2u64));
+        state.put_gas_prices(gas_prices(*STAKING_TOKEN_ASSET_ID, 1_000));
+        state.put_fee_params(FeeParameters {
+            alt_gas_prices: vec![gas_prices(alt_asset, 2_000)],
+            ..Default::default()
+        });
+
+        let fee = |amount: u64, asset_id| Value {
+            amount: amount.into(),
+            asset_id,
+        };
+
+        // A DAO deposit costs 10 units of execution gas: 10 of the staking token,
+        // or 20 of the alternative fee asset.
+        fee_greater_than_base_fee(
+            &state,
+            &transaction_with_fee(fee(10, *STAKING_TOKEN_ASSET_ID)),
+        )
+        .await?;
+        fee_greater_than_base_fee(&state, &transaction_with_fee(fee(20, alt_asset))).await?;
+
+        // The alternative fee asset is priced with its own gas prices.
+        assert!(
+            fee_greater_than_base_fee(&state, &transaction_with_fee(fee(10, alt_asset)))
+                .await
+                .is_err()
+        );
+
+        // Assets that governance did not approve cannot pay fees, whatever the amount.
+        assert!(fee_greater_than_base_fee(
+            &state,
+            &transaction_with_fee(fee(1_000_000, unapproved_asset))
+        )
+        .await
+        .is_err());
+
+        Ok(())
+    }
+
+    /// A transaction with a single clue, created with the given precision.
+    fn transaction_with_clue(precision_bits: u8) -> Transaction {
+        let clue = test_keys::ADDRESS_0
+            .clue_key()
+            .expand()
+            .expect("clue key is valid")
+            .create_clue(precision_bits.into(), OsRng)
+            .expect("precision is supported");
+        let mut transaction = Transaction::default();
+        transaction.transaction_body.detection_data = Some(DetectionData {
+            fmd_clues: vec![clue],
+        });
+        transaction
+    }
+
+    #[test]
+    fn previous_fmd_precision_is_accepted_during_grace_period() {
+        let previous = FmdParameters {
+            precision_bits: 1,
+            as_of_block_height: 0,
+        };
+        let current = FmdParameters {
+            precision_bits: 2,
+            as_of_block_height: 100,
+        };
+        let grace_period_blocks = 10;
+        let check = |precision_bits, height| {
+            fmd_precision_within_grace_period(
+                &transaction_with_clue(precision_bits),
+                previous.clone(),
+                current.clone(),
+                grace_period_blocks,
+                height,
+            )
+        };
+
+        // The current precision is always accepted.
+        assert!(check(2, 100).is_ok());
+        assert!(check(2, 1_000).is_ok());
+
+        // The previous precision is accepted for `grace_period_blocks` after the
+        // current parameters became effective, and no longer.
+        assert!(check(1, 100).is_ok());
+        assert!(check(1, 109).is_ok());
+        assert!(check(1, 110).is_err());
+
+        // Any other precision is rejected.
+        assert!(check(3, 100).is_err());
+    }
+}
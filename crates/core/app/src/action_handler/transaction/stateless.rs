@@ -1,7 +1,8 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use anyhow::{Context, Result};
-use penumbra_transaction::{AuthorizingData, Transaction};
+use penumbra_proof_params::BatchVerifier;
+use penumbra_transaction::{Action, AuthorizingData, Transaction};
 
 #[tracing::instrument(skip(tx))]
 pub(super) fn valid_binding_signature(tx: &Transaction) -> Result<()> {
@@ -86,3 +87,58 @@ pub fn check_memo_exists_if_outputs_absent_if_not(tx: &Transaction) -> anyhow::R
         ))
     }
 }
+
+/// Checks the actions of the transaction that carry proofs, queueing their proofs
+/// so that the proofs for each circuit can be verified together.
+pub(super) fn check_proof_actions(
+    tx: &Transaction,
+    batch: &mut BatchVerifier<'static>,
+) -> Result<()> {
+    let context = tx.context();
+    for action in tx.actions() {
+        match action {
+            Action::Spend(spend) => spend.check_stateless_batched(&context, batch)?,
+            Action::Output(output) => output.check_stateless_batched(batch)?,
+            Action::Swap(swap) => swap.check_stateless_batched(batch)?,
+            Action::SwapClaim(swap_claim) => swap_claim.check_stateless_batched(&context, batch)?,
+            Action::DelegatorVote(vote) => vote.check_stateless_batched(&context, batch)?,
+            Action::UndelegateClaim(claim) => claim.check_stateless_batched(batch)?,
+            // These actions carry no Groth16 proofs. They're listed explicitly, so that a
+            // new action with a proof can't be added without queueing its proof.
+            Action::ValidatorDefinition(_)
+            | Action::IbcRelay(_)
+            | Action::ProposalSubmit(_)
+            | Action::ProposalWithdraw(_)
+            | Action::ValidatorVote(_)
+            | Action::ProposalDepositClaim(_)
+            | Action::PositionOpen(_)
+            | Action::PositionClose(_)
+            | Action::PositionWithdraw(_)
+            | Action::PositionRewardClaim(_)
+            | Action::Delegate(_)
+            | Action::Undelegate(_)
+            | Action::Redelegate(_)
+            | Action::RedelegateClaim(_)
+            | Action::ValidatorUnjail(_)
+            | Action::Ics20Withdrawal(_)
+            | Action::DaoSpend(_)
+            | Action::DaoOutput(_)
+            | Action::DaoDeposit(_) => {}
+        }
+    }
+    Ok(())
+}
+
+/// Whether the action carries a proof, in which case it's checked by
+/// [`check_proof_actions`] rather than by its own `check_stateless`.
+pub(super) fn has_proof(action: &Action) -> bool {
+    matches!(
+        action,
+        Action::Spend(_)
+            | Action::Output(_)
+            | Action::Swap(_)
+            | Action::SwapClaim(_)
+            | Action::DelegatorVote(_)
+            | Action::UndelegateClaim(_)
+    )
+}
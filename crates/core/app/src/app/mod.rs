@@ -10,7 +10,7 @@ use penumbra_compact_block::CompactBlock;
 use penumbra_component::Component;
 use penumbra_dao::component::StateWriteExt as _;
 use penumbra_dao::StateReadExt as _;
-use penumbra_dex::component::{Dex, SwapManager};
+use penumbra_dex::component::{Dex, StateReadExt as _, StateWriteExt as _, SwapManager};
 use penumbra_distributions::component::{Distributions, StateReadExt as _, StateWriteExt as _};
 use penumbra_fee::component::{Fee, StateReadExt as _, StateWriteExt as _};
 use penumbra_governance::component::{Governance, StateReadExt as _};
@@ -63,6 +63,15 @@ impl App {
             anyhow::bail!("chain is halted, refusing to restart");
         }
 
+        // Likewise, if the chain halted for an upgrade, it must not come back up until the
+        // corresponding migration has been applied to the state.
+        if let Some(name) = state.upgrade_halt().await? {
+            tracing::error!(%name, "chain is halted for an upgrade, refusing to restart!");
+            anyhow::bail!(
+                "chain is halted for upgrade {name:?}, run `pd migrate` before restarting"
+            );
+        }
+
         Ok(Self { state })
     }
 
@@ -99,6 +108,7 @@ impl App {
                 // `init_chain` methods?
                 state_tx.put_chain_params(app_state.chain_content.chain_params.clone());
                 state_tx.put_dao_params(app_state.dao_content.dao_params.clone());
+                state_tx.put_dex_params(app_state.dex_content.dex_params.clone());
                 state_tx.put_stake_params(app_state.stake_content.stake_params.clone());
                 state_tx
                     .put_governance_params(app_state.governance_content.governance_params.clone());
@@ -108,7 +118,8 @@ impl App {
                     app_state.distributions_content.distributions_params.clone(),
                 );
 
-                // TEMP: Hardcoding FMD parameters until we have a mechanism to change them. See issue #1226.
+                // The FMD parameters start out at zero precision, and are adjusted
+                // at the end of every epoch according to the observed clue volume.
                 state_tx.put_current_fmd_parameters(FmdParameters::default());
                 state_tx.put_previous_fmd_parameters(FmdParameters::default());
 
@@ -192,6 +203,9 @@ impl App {
             if let Some(dao_params) = app_params.new.dao_params {
                 state_tx.put_dao_params(dao_params);
             }
+            if let Some(dex_params) = app_params.new.dex_params {
+                state_tx.put_dex_params(dex_params);
+            }
             if let Some(ibc_params) = app_params.new.ibc_params {
                 state_tx.put_ibc_params(ibc_params);
             }
@@ -331,6 +345,12 @@ impl App {
         let mut state_tx = Arc::try_unwrap(arc_state_tx)
             .expect("components did not retain copies of shared state");
 
+        // Add the clues included in this block to the count for the epoch.
+        state_tx
+            .record_block_fmd_clue_count()
+            .await
+            .expect("able to record fmd clue count");
+
         // Since governance proposals can affect the entirety of application state, and the governance component
         // does not have access to the types defined in this crate so we need to handle validating them here.
         //
@@ -444,6 +464,12 @@ impl App {
             let mut state_tx = Arc::try_unwrap(arc_state_tx)
                 .expect("components did not retain copies of shared state");
 
+            // Adjust the FMD parameters to the clue volume of the epoch that just ended.
+            state_tx
+                .update_fmd_parameters(current_height + 1)
+                .await
+                .expect("able to update fmd parameters");
+
             App::finish_epoch(&mut state_tx).await;
 
             // set the epoch for the next block
@@ -505,7 +531,9 @@ impl App {
             None
         };
 
-        let fmd_parameters = if height == 0 {
+        // Check to see if the FMD parameters have changed, and include them in the compact block
+        // if they have (this is signaled by `penumbra_chain::StateWriteExt::put_current_fmd_parameters`):
+        let fmd_parameters = if state.fmd_parameters_updated() || height == 0 {
             Some(
                 state
                     .get_current_fmd_parameters()
@@ -606,12 +634,12 @@ impl App {
 
         if is_upgrade_height {
             tracing::info!("upgrade height reached, signaling halt");
-            // If we are about to reach an upgrade height, we want to increase the
-            // halt counter to prevent the chain from restarting without manual intervention.
+            // If we are about to reach an upgrade height, we record which upgrade we halted for,
+            // preventing the chain from restarting until `pd migrate` has applied it.
             state
-                .signal_halt()
+                .signal_upgrade_halt()
                 .await
-                .expect("must be able to signal halt");
+                .expect("must be able to signal upgrade halt");
         }
 
         // Commit the pending writes, clearing the state.
@@ -662,6 +690,7 @@ pub trait StateReadExt: StateRead {
             || self.governance_params_updated()
             || self.ibc_params_updated()
             || self.dao_params_updated()
+            || self.dex_params_updated()
             || self.stake_params_updated()
             || self.distributions_params_updated()
             || self.chain_params_updated()
@@ -674,12 +703,14 @@ pub trait StateReadExt: StateRead {
         let ibc_params = self.get_ibc_params().await?;
         let governance_params = self.get_governance_params().await?;
         let dao_params = self.get_dao_params().await?;
+        let dex_params = self.get_dex_params().await?;
         let fee_params = self.get_fee_params().await?;
         let distributions_params = self.get_distributions_params().await?;
 
         Ok(AppParameters {
             chain_params,
             dao_params,
+            dex_params,
             distributions_params,
             fee_params,
             governance_params,
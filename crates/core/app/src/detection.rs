@@ -0,0 +1,179 @@
+//! Fuzzy message detection over blocks.
+//!
+//! Rather than trial-decrypting every payload of every compact block, a client
+//! can hand its detection keys to a filtering service, which examines the clues
+//! of every transaction and only forwards the payloads of the transactions that
+//! were detected, along with the transactions themselves. Transactions without
+//! clues, such as transactions that only swap, can't be ruled out, so they're
+//! always forwarded.
+//!
+//! Every clue accepted by the chain was created at the precision set by the
+//! chain's `FmdParameters` when it was included, so the false positive rate of
+//! the detection is the one chosen by the chain, not by the client.
+
+use std::collections::BTreeSet;
+
+use decaf377_fmd::{Clue, DetectionKey};
+use penumbra_chain::NoteSource;
+use penumbra_compact_block::{CompactBlock, StatePayload};
+use penumbra_transaction::Transaction;
+
+/// Returns true if any of the clues is detected by any of the detection keys.
+pub fn clues_detected(detection_keys: &[DetectionKey], clues: &[Clue]) -> bool {
+    clues
+        .iter()
+        .any(|clue| detection_keys.iter().any(|dtk| dtk.examine(clue)))
+}
+
+/// Returns the transactions with at least one clue detected by one of the detection keys,
+/// and the transactions with no clues at all.
+pub fn detect_transactions(
+    detection_keys: &[DetectionKey],
+    transactions: Vec<Transaction>,
+) -> Vec<Transaction> {
+    transactions
+        .into_iter()
+        .filter(|tx| {
+            let clues = tx
+                .transaction_body()
+                .detection_data
+                .map(|data| data.fmd_clues)
+                .unwrap_or_default();
+            clues.is_empty() || clues_detected(detection_keys, &clues)
+        })
+        .collect()
+}
+
+/// Filters a compact block down to the payloads of the detected transactions.
+///
+/// The note and swap payloads created by any other transaction are rolled up
+/// into their state commitments, so that clients can keep their state
+/// commitment tree in sync without trial-decrypting them. Payloads that weren't
+/// created by a transaction (e.g. genesis allocations or funding stream rewards)
+/// carry no clues, so they are kept as-is.
+pub fn filter_compact_block(
+    mut compact_block: CompactBlock,
+    detected_ids: &BTreeSet<[u8; 32]>,
+) -> CompactBlock {
+    compact_block.state_payloads = compact_block
+        .state_payloads
+        .into_iter()
+        .map(|payload| match payload.source() {
+            Some(NoteSource::Transaction { id }) if !detected_ids.contains(id) => {
+                StatePayload::RolledUp(*payload.commitment())
+            }
+            _ => payload,
+        })
+        .collect();
+    compact_block
+}
+
+/// Runs detection over a block, returning the filtered compact block and the
+/// detected transactions.
+pub fn detect_block(
+    detection_keys: &[DetectionKey],
+    compact_block: CompactBlock,
+    transactions: Vec<Transaction>,
+) -> (CompactBlock, Vec<Transaction>) {
+    let detected = detect_transactions(detection_keys, transactions);
+    let detected_ids = detected.iter().map(|tx| tx.id().0).collect();
+    (filter_compact_block(compact_block, &detected_ids), detected)
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_keys::test_keys;
+    use penumbra_shielded_pool::Note;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn clues_detected_by_matching_key_only() {
+        let dtk = DetectionKey::new(OsRng);
+        let other_dtk = DetectionKey::new(OsRng);
+
+        let clue = dtk
+            .clue_key()
+            .expand()
+            .expect("clue key is valid")
+            .create_clue(decaf377_fmd::MAX_PRECISION - 1, OsRng)
+            .expect("precision is valid");
+
+        assert!(clues_detected(&[other_dtk, dtk], &[clue.clone()]));
+        // At the highest valid precision, a false positive happens with probability 2^-23.
+        let other_dtk = DetectionKey::new(OsRng);
+        assert!(!clues_detected(&[other_dtk], &[clue]));
+        assert!(!clues_detected(&[], &[]));
+    }
+
+    #[test]
+    fn transactions_without_clues_are_detected() {
+        let transaction = Transaction::default();
+        assert!(transaction.transaction_body().detection_data.is_none());
+
+        let id = transaction.id();
+
+        let detected = detect_transactions(&[DetectionKey::new(OsRng)], vec![transaction]);
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].id(), id);
+    }
+
+    #[test]
+    fn undetected_payloads_are_rolled_up() {
+        let note_payload = || {
+            let value = Value {
+                amount: 1u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            };
+            Note::generate(&mut OsRng, &test_keys::ADDRESS_0, value).payload()
+        };
+
+        let detected =
+            StatePayload::from((note_payload(), NoteSource::Transaction { id: [1; 32] }));
+        let undetected =
+            StatePayload::from((note_payload(), NoteSource::Transaction { id: [2; 32] }));
+        let genesis = StatePayload::from((note_payload(), NoteSource::Genesis));
+        let rolled_up = StatePayload::RolledUp(*undetected.commitment());
+
+        let compact_block = CompactBlock {
+            height: 1,
+            state_payloads: vec![detected, undetected, genesis, rolled_up],
+            ..Default::default()
+        };
+        let commitments = compact_block
+            .state_payloads
+            .iter()
+            .map(|payload| *payload.commitment())
+            .collect::<Vec<_>>();
+
+        let filtered = filter_compact_block(compact_block, &[[1; 32]].into_iter().collect());
+
+        // The commitments are all still present, in order, to keep the SCT in sync.
+        assert_eq!(
+            filtered
+                .state_payloads
+                .iter()
+                .map(|payload| *payload.commitment())
+                .collect::<Vec<_>>(),
+            commitments
+        );
+        assert!(matches!(
+            filtered.state_payloads[0],
+            StatePayload::Note { .. }
+        ));
+        assert!(matches!(
+            filtered.state_payloads[1],
+            StatePayload::RolledUp(_)
+        ));
+        assert!(matches!(
+            filtered.state_payloads[2],
+            StatePayload::Note { .. }
+        ));
+        assert!(matches!(
+            filtered.state_payloads[3],
+            StatePayload::RolledUp(_)
+        ));
+    }
+}
@@ -1,5 +1,6 @@
 use penumbra_chain::genesis::Content as ChainContent;
 use penumbra_dao::genesis::Content as DaoContent;
+use penumbra_dex::genesis::Content as DexContent;
 use penumbra_distributions::genesis::Content as DistributionsContent;
 use penumbra_fee::genesis::Content as FeeContent;
 use penumbra_governance::genesis::Content as GovernanceContent;
@@ -39,6 +40,8 @@ pub struct Content {
     pub fee_content: FeeContent,
     /// Distributions module genesis state.
     pub distributions_content: DistributionsContent,
+    /// Dex module genesis state.
+    pub dex_content: DexContent,
 }
 
 impl DomainType for Content {
@@ -77,6 +80,7 @@ impl From<Content> for pb::GenesisContent {
             shielded_pool_content: Some(value.shielded_pool_content.into()),
             fee_content: Some(value.fee_content.into()),
             distributions_content: Some(value.distributions_content.into()),
+            dex_content: Some(value.dex_content.into()),
         }
     }
 }
@@ -136,6 +140,10 @@ impl TryFrom<pb::GenesisContent> for Content {
                 .distributions_content
                 .ok_or_else(|| anyhow::anyhow!("proto response missing distributions content"))?
                 .try_into()?,
+            dex_content: msg
+                .dex_content
+                .ok_or_else(|| anyhow::anyhow!("proto response missing dex content"))?
+                .try_into()?,
         })
     }
 }
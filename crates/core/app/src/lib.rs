@@ -22,6 +22,7 @@ pub static SUBSTORE_PREFIXES: Lazy<Vec<String>> = Lazy::new(|| {
 pub const APP_VERSION: u64 = 1;
 
 pub mod app;
+pub mod detection;
 pub mod genesis;
 pub mod params;
 
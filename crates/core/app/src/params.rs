@@ -1,5 +1,6 @@
 use penumbra_chain::params::ChainParameters;
 use penumbra_dao::params::DaoParameters;
+use penumbra_dex::DexParameters;
 use penumbra_distributions::DistributionsParameters;
 use penumbra_fee::FeeParameters;
 use penumbra_governance::params::GovernanceParameters;
@@ -17,6 +18,7 @@ pub mod change;
 pub struct AppParameters {
     pub chain_params: ChainParameters,
     pub dao_params: DaoParameters,
+    pub dex_params: DexParameters,
     pub distributions_params: DistributionsParameters,
     pub fee_params: FeeParameters,
     pub governance_params: GovernanceParameters,
@@ -41,6 +43,10 @@ impl TryFrom<pb::AppParameters> for AppParameters {
                 .dao_params
                 .ok_or_else(|| anyhow::anyhow!("proto response missing dao params"))?
                 .try_into()?,
+            dex_params: msg
+                .dex_params
+                .ok_or_else(|| anyhow::anyhow!("proto response missing dex params"))?
+                .try_into()?,
             distributions_params: msg
                 .distributions_params
                 .ok_or_else(|| anyhow::anyhow!("proto response missing distribution params"))?
@@ -70,6 +76,7 @@ impl From<AppParameters> for pb::AppParameters {
         pb::AppParameters {
             chain_params: Some(params.chain_params.into()),
             dao_params: Some(params.dao_params.into()),
+            dex_params: Some(params.dex_params.into()),
             distributions_params: Some(params.distributions_params.into()),
             governance_params: Some(params.governance_params.into()),
             ibc_params: Some(params.ibc_params.into()),
@@ -1,12 +1,18 @@
-use std::fmt::Display;
+use std::{collections::BTreeSet, fmt::Display};
 
 use anyhow::Result;
+use penumbra_asset::STAKING_TOKEN_ASSET_ID;
 use penumbra_chain::params::{ChainParameters, Ratio};
 use penumbra_dao::params::DaoParameters;
+use penumbra_dex::{params::MAX_HOPS_LIMIT, DexParameters};
 use penumbra_distributions::params::DistributionsParameters;
 use penumbra_fee::FeeParameters;
-use penumbra_governance::{params::GovernanceParameters, proposal::ChangedAppParameters};
-use penumbra_ibc::params::IBCParameters;
+use penumbra_governance::{
+    params::{GovernanceParameters, ProposalParameters},
+    proposal::ChangedAppParameters,
+    ProposalKind,
+};
+use penumbra_ibc::{params::IBCParameters, MAX_ICS20_RATE_LIMIT_WINDOW_BLOCKS};
 use penumbra_stake::params::StakeParameters;
 
 use super::AppParameters;
@@ -25,6 +31,8 @@ impl AppParameters {
                 ChainParameters {
                     chain_id,
                     epoch_duration,
+                    fmd_target_false_positives_per_epoch: _,
+                    fmd_grace_period_blocks: _,
                 },
             stake_params:
                 StakeParameters {
@@ -35,26 +43,53 @@ impl AppParameters {
                     slashing_penalty_downtime: _,
                     signed_blocks_window_len,
                     missed_blocks_maximum: _,
+                    jail_duration_blocks: _,
+                    max_commission_change_bps_per_epoch: _,
+                    commission_increase_notice_epochs: _,
                 },
             ibc_params:
                 IBCParameters {
                     ibc_enabled: _,
                     inbound_ics20_transfers_enabled: _,
                     outbound_ics20_transfers_enabled: _,
+                    ics20_rate_limits: _,
                 },
             governance_params:
                 GovernanceParameters {
-                    proposal_voting_blocks: _,
-                    proposal_deposit_amount: _,
-                    proposal_valid_quorum,
-                    proposal_pass_threshold,
-                    proposal_slash_threshold,
+                    signaling: _,
+                    emergency: _,
+                    parameter_change:
+                        ProposalParameters {
+                            voting_blocks: _,
+                            deposit_amount: _,
+                            valid_quorum,
+                            pass_threshold,
+                            slash_threshold,
+                        },
+                    dao_spend: _,
+                    upgrade_plan: _,
+                    ibc_client_recovery: _,
+                },
+            fee_params:
+                FeeParameters {
+                    block_space: _,
+                    compact_block_space: _,
+                    verification: _,
+                    execution: _,
+                    alt_gas_prices: _,
                 },
-            fee_params: FeeParameters {},
             distributions_params:
                 DistributionsParameters {
                     staking_issuance_per_block: _,
                 },
+            dex_params:
+                DexParameters {
+                    fixed_candidates: _,
+                    max_hops: _,
+                    arbitrage_enabled: _,
+                    arbitrage_candidates: _,
+                    lp_rewards_per_epoch: _,
+                },
             dao_params:
                 DaoParameters {
                     dao_spend_proposals_enabled: _,
@@ -80,21 +115,23 @@ impl AppParameters {
                 "signed blocks window length",
             ),
         ])?;
+        // The thresholds for other kinds of proposals can be changed, but a parameter change
+        // proposal can't lower the bar for future parameter changes.
         check_invariant([
             (
-                proposal_valid_quorum,
-                &new.governance_params.proposal_valid_quorum,
-                "proposal valid quorum",
+                valid_quorum,
+                &new.governance_params.parameter_change.valid_quorum,
+                "parameter change proposal valid quorum",
             ),
             (
-                proposal_pass_threshold,
-                &new.governance_params.proposal_pass_threshold,
-                "proposal pass threshold",
+                pass_threshold,
+                &new.governance_params.parameter_change.pass_threshold,
+                "parameter change proposal pass threshold",
             ),
             (
-                proposal_slash_threshold,
-                &new.governance_params.proposal_slash_threshold,
-                "proposal slash threshold",
+                slash_threshold,
+                &new.governance_params.parameter_change.slash_threshold,
+                "parameter change proposal slash threshold",
             ),
         ])?;
 
@@ -107,6 +144,8 @@ impl AppParameters {
                 ChainParameters {
                     chain_id,
                     epoch_duration,
+                    fmd_target_false_positives_per_epoch,
+                    fmd_grace_period_blocks,
                 },
             stake_params:
                 StakeParameters {
@@ -117,25 +156,45 @@ impl AppParameters {
                     slashing_penalty_downtime,
                     signed_blocks_window_len,
                     missed_blocks_maximum,
+                    jail_duration_blocks: _,
+                    max_commission_change_bps_per_epoch,
+                    commission_increase_notice_epochs: _,
                 },
             ibc_params:
                 IBCParameters {
                     ibc_enabled,
                     inbound_ics20_transfers_enabled,
                     outbound_ics20_transfers_enabled,
+                    ics20_rate_limits,
                 },
             governance_params:
                 GovernanceParameters {
-                    proposal_voting_blocks,
-                    proposal_deposit_amount,
-                    proposal_valid_quorum,
-                    proposal_pass_threshold,
-                    proposal_slash_threshold,
+                    signaling,
+                    emergency,
+                    parameter_change,
+                    dao_spend,
+                    upgrade_plan,
+                    ibc_client_recovery,
+                },
+            fee_params:
+                FeeParameters {
+                    block_space,
+                    compact_block_space,
+                    verification,
+                    execution,
+                    alt_gas_prices,
                 },
-            fee_params: FeeParameters {},
             distributions_params:
                 DistributionsParameters {
-                    staking_issuance_per_block: _,
+                    staking_issuance_per_block,
+                },
+            dex_params:
+                DexParameters {
+                    fixed_candidates,
+                    max_hops,
+                    arbitrage_enabled: _,
+                    arbitrage_candidates,
+                    lp_rewards_per_epoch,
                 },
             dao_params:
                 DaoParameters {
@@ -149,6 +208,14 @@ impl AppParameters {
                 *epoch_duration >= 1,
                 "epoch duration must be at least one block",
             ),
+            (
+                *fmd_target_false_positives_per_epoch >= 1,
+                "fmd target false positives per epoch must be at least 1",
+            ),
+            (
+                *fmd_grace_period_blocks >= 1,
+                "fmd grace period must be at least one block",
+            ),
             (
                 *unbonding_epochs >= 1,
                 "unbonding must take at least one epoch",
@@ -185,32 +252,159 @@ impl AppParameters {
                 *missed_blocks_maximum >= 1,
                 "missed blocks maximum must be at least 1",
             ),
+            (
+                *max_commission_change_bps_per_epoch >= 1,
+                "max commission change per epoch must be at least 1 basis point",
+            ),
+            (
+                *max_commission_change_bps_per_epoch <= 10_000,
+                "max commission change per epoch must be at most 10,000 basis points",
+            ),
             (
                 (!*inbound_ics20_transfers_enabled && !*outbound_ics20_transfers_enabled)
                     || *ibc_enabled,
                 "IBC must be enabled if either inbound or outbound ICS20 transfers are enabled",
             ),
             (
-                *proposal_voting_blocks >= 1,
-                "proposal voting blocks must be at least 1",
+                ics20_rate_limits
+                    .iter()
+                    .all(|limit| limit.window_blocks >= 1),
+                "ICS20 rate limit windows must be at least one block",
+            ),
+            (
+                ics20_rate_limits
+                    .iter()
+                    .all(|limit| limit.window_blocks <= MAX_ICS20_RATE_LIMIT_WINDOW_BLOCKS),
+                "ICS20 rate limit windows must be at most 17,280 blocks",
+            ),
+            (
+                ics20_rate_limits
+                    .iter()
+                    .map(|limit| (limit.channel_id.clone(), limit.asset_id))
+                    .collect::<BTreeSet<_>>()
+                    .len()
+                    == ics20_rate_limits.len(),
+                "ICS20 rate limits must be unique per channel and asset",
+            ),
+            (
+                block_space.min_price <= block_space.max_price,
+                "block space gas price minimum must not exceed its maximum",
+            ),
+            (
+                !block_space.is_enabled() || block_space.adjustment_denominator >= 1,
+                "block space gas price adjustment denominator must be at least 1",
+            ),
+            (
+                compact_block_space.min_price <= compact_block_space.max_price,
+                "compact block space gas price minimum must not exceed its maximum",
+            ),
+            (
+                !compact_block_space.is_enabled()
+                    || compact_block_space.adjustment_denominator >= 1,
+                "compact block space gas price adjustment denominator must be at least 1",
+            ),
+            (
+                verification.min_price <= verification.max_price,
+                "verification gas price minimum must not exceed its maximum",
+            ),
+            (
+                !verification.is_enabled() || verification.adjustment_denominator >= 1,
+                "verification gas price adjustment denominator must be at least 1",
+            ),
+            (
+                execution.min_price <= execution.max_price,
+                "execution gas price minimum must not exceed its maximum",
+            ),
+            (
+                !execution.is_enabled() || execution.adjustment_denominator >= 1,
+                "execution gas price adjustment denominator must be at least 1",
+            ),
+            (
+                alt_gas_prices
+                    .iter()
+                    .all(|prices| prices.asset_id != *STAKING_TOKEN_ASSET_ID),
+                "alternative fee assets must not include the staking token",
+            ),
+            (
+                alt_gas_prices
+                    .iter()
+                    .map(|prices| prices.asset_id)
+                    .collect::<BTreeSet<_>>()
+                    .len()
+                    == alt_gas_prices.len(),
+                "alternative fee assets must be unique",
             ),
+            (*max_hops >= 1, "dex max hops must be at least 1"),
             (
-                *proposal_deposit_amount >= 1u64.into(),
-                "proposal deposit amount must be at least 1",
+                *max_hops <= MAX_HOPS_LIMIT,
+                "dex max hops must not exceed the routing limit",
             ),
+            // Liquidity incentives are newly issued staking tokens, so they're
+            // bounded by the staking issuance, rather than left to governance alone.
             (
-                *proposal_valid_quorum > Ratio::new(0, 1),
-                "proposal valid quorum must be greater than 0",
+                lp_rewards_per_epoch.value()
+                    <= u128::from(*staking_issuance_per_block) * u128::from(*epoch_duration),
+                "dex lp rewards per epoch must not exceed the staking issuance per epoch",
             ),
             (
-                *proposal_pass_threshold >= Ratio::new(1, 2),
-                "proposal pass threshold must be greater than or equal to 1/2",
+                fixed_candidates.iter().collect::<BTreeSet<_>>().len() == fixed_candidates.len(),
+                "dex fixed candidates must be unique",
             ),
             (
-                *proposal_slash_threshold > Ratio::new(1, 2),
-                "proposal slash threshold must be greater than 1/2",
+                arbitrage_candidates.iter().collect::<BTreeSet<_>>().len()
+                    == arbitrage_candidates.len(),
+                "dex arbitrage candidates must be unique",
             ),
-        ])
+        ])?;
+
+        check_all(
+            [
+                (ProposalKind::Signaling, signaling),
+                (ProposalKind::Emergency, emergency),
+                (ProposalKind::ParameterChange, parameter_change),
+                (ProposalKind::DaoSpend, dao_spend),
+                (ProposalKind::UpgradePlan, upgrade_plan),
+                (ProposalKind::IbcClientRecovery, ibc_client_recovery),
+            ]
+            .into_iter()
+            .flat_map(
+                |(
+                    kind,
+                    ProposalParameters {
+                        voting_blocks,
+                        deposit_amount,
+                        valid_quorum,
+                        pass_threshold,
+                        slash_threshold,
+                    },
+                )| {
+                    [
+                        (
+                            *voting_blocks >= 1,
+                            format!("{kind} proposal voting blocks must be at least 1"),
+                        ),
+                        (
+                            *deposit_amount >= 1u64.into(),
+                            format!("{kind} proposal deposit amount must be at least 1"),
+                        ),
+                        (
+                            *valid_quorum > Ratio::new(0, 1),
+                            format!("{kind} proposal valid quorum must be greater than 0"),
+                        ),
+                        (
+                            *pass_threshold >= Ratio::new(1, 2),
+                            format!(
+                                "{kind} proposal pass threshold must be greater than or equal to 1/2"
+                            ),
+                        ),
+                        (
+                            *slash_threshold > Ratio::new(1, 2),
+                            format!("{kind} proposal slash threshold must be greater than 1/2"),
+                        ),
+                    ]
+                },
+            ),
+        )
     }
 
     /// Converts an `AppParameters` instance to a complete `ChangedAppParameters`.
@@ -218,6 +412,7 @@ impl AppParameters {
         ChangedAppParameters {
             chain_params: Some(self.chain_params.clone()),
             dao_params: Some(self.dao_params.clone()),
+            dex_params: Some(self.dex_params.clone()),
             distributions_params: Some(self.distributions_params.clone()),
             fee_params: Some(self.fee_params.clone()),
             governance_params: Some(self.governance_params.clone()),
@@ -242,6 +437,7 @@ impl AppParameters {
                 || new.governance_params.is_none()
                 || new.fee_params.is_none()
                 || new.dao_params.is_none()
+                || new.dex_params.is_none()
                 || new.distributions_params.is_none())
         {
             anyhow::bail!("all parameters must be specified if no old parameters are provided");
@@ -258,6 +454,11 @@ impl AppParameters {
                     .dao_params
                     .clone()
             }),
+            dex_params: new.dex_params.clone().unwrap_or_else(|| {
+                old.expect("old should be set if new has any None values")
+                    .dex_params
+                    .clone()
+            }),
             distributions_params: new.distributions_params.clone().unwrap_or_else(|| {
                 old.expect("old should be set if new has any None values")
                     .distributions_params
@@ -319,3 +520,105 @@ fn check_invariant<'a, T: Eq + 'a>(
             .map(|(old, new, name)| ((*old == *new), format!("{name} can't be changed"))),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_types::core::channel::ChannelId;
+    use penumbra_ibc::Ics20RateLimit;
+
+    use super::*;
+
+    fn params() -> AppParameters {
+        AppParameters {
+            chain_params: ChainParameters {
+                chain_id: "penumbra-test".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn dex_max_hops_is_bounded() {
+        let mut params = params();
+        params.check_valid().expect("default parameters are valid");
+
+        for max_hops in [1, MAX_HOPS_LIMIT] {
+            params.dex_params.max_hops = max_hops;
+            params
+                .check_valid()
+                .expect("max hops within the bounds is valid");
+        }
+
+        for max_hops in [0, MAX_HOPS_LIMIT + 1, u32::MAX] {
+            params.dex_params.max_hops = max_hops;
+            let err = params
+                .check_valid()
+                .expect_err("max hops outside the bounds is invalid");
+            assert!(err.to_string().contains("dex max hops"));
+        }
+    }
+
+    #[test]
+    fn fmd_target_false_positives_must_be_nonzero() {
+        let mut params = params();
+
+        for target in [1, 64, u64::MAX] {
+            params.chain_params.fmd_target_false_positives_per_epoch = target;
+            params.check_valid().expect("a nonzero fmd target is valid");
+        }
+
+        params.chain_params.fmd_target_false_positives_per_epoch = 0;
+        let err = params
+            .check_valid()
+            .expect_err("a zero fmd target is invalid");
+        assert!(err
+            .to_string()
+            .contains("fmd target false positives per epoch"));
+    }
+
+    #[test]
+    fn dex_lp_rewards_are_bounded_by_staking_issuance() {
+        let mut params = params();
+        params.distributions_params.staking_issuance_per_block = 10;
+        params.chain_params.epoch_duration = 100;
+
+        params.dex_params.lp_rewards_per_epoch = 1_000u64.into();
+        params
+            .check_valid()
+            .expect("lp rewards up to the staking issuance per epoch are valid");
+
+        params.dex_params.lp_rewards_per_epoch = 1_001u64.into();
+        let err = params
+            .check_valid()
+            .expect_err("lp rewards above the staking issuance per epoch are invalid");
+        assert!(err.to_string().contains("dex lp rewards per epoch"));
+    }
+
+    #[test]
+    fn ics20_rate_limit_windows_are_bounded() {
+        let limit = |window_blocks| Ics20RateLimit {
+            channel_id: ChannelId::new(0),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+            max_net_inflow: 1u64.into(),
+            max_net_outflow: 1u64.into(),
+            window_blocks,
+        };
+        let mut params = params();
+
+        for window_blocks in [1, MAX_ICS20_RATE_LIMIT_WINDOW_BLOCKS] {
+            params.ibc_params.ics20_rate_limits = vec![limit(window_blocks)];
+            params
+                .check_valid()
+                .expect("windows within the bounds are valid");
+        }
+
+        for window_blocks in [0, MAX_ICS20_RATE_LIMIT_WINDOW_BLOCKS + 1, u64::MAX] {
+            params.ibc_params.ics20_rate_limits = vec![limit(window_blocks)];
+            let err = params
+                .check_valid()
+                .expect_err("windows outside the bounds are invalid");
+            assert!(err.to_string().contains("ICS20 rate limit windows"));
+        }
+    }
+}
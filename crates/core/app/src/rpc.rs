@@ -1,13 +1,25 @@
+use std::pin::Pin;
+
+use decaf377_fmd::DetectionKey;
+use futures::StreamExt;
 use penumbra_chain::component::StateReadExt as _;
+use penumbra_compact_block::component::StateReadExt as _;
 use penumbra_proto::core::app::v1alpha1::{
     query_service_server::QueryService, AppParametersRequest, AppParametersResponse,
-    TransactionsByHeightRequest, TransactionsByHeightResponse,
+    DetectedBlockRangeRequest, DetectedBlockRangeResponse, TransactionsByHeightRequest,
+    TransactionsByHeightResponse,
 };
-use penumbra_storage::Storage;
+use penumbra_storage::{Snapshot, Storage};
+use penumbra_transaction::Transaction;
+use tokio::sync::mpsc;
 use tonic::Status;
-use tracing::instrument;
+use tracing::{instrument, Instrument};
+
+use crate::{app::StateReadExt as _, detection};
 
-use crate::app::StateReadExt as _;
+/// The maximum number of detection keys accepted in a single detection request,
+/// since every key is tested against every clue of every transaction.
+pub const MAX_DETECTION_KEYS: usize = 64;
 
 // TODO: Hide this and only expose a Router?
 pub struct Server {
@@ -22,6 +34,10 @@ impl Server {
 
 #[tonic::async_trait]
 impl QueryService for Server {
+    type DetectedBlockRangeStream = Pin<
+        Box<dyn futures::Stream<Item = Result<DetectedBlockRangeResponse, tonic::Status>> + Send>,
+    >;
+
     #[instrument(skip(self, request))]
     async fn transactions_by_height(
         &self,
@@ -34,6 +50,10 @@ impl QueryService for Server {
             .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
         let request_inner = request.into_inner();
         let block_height = request_inner.block_height;
+        state
+            .check_history_available(block_height)
+            .await
+            .map_err(|e| tonic::Status::failed_precondition(e.to_string()))?;
 
         let tx_response = state
             .transactions_by_height(block_height)
@@ -68,4 +88,140 @@ impl QueryService for Server {
             app_parameters: Some(app_parameters.into()),
         }))
     }
+
+    #[instrument(
+        skip(self, request),
+        fields(
+            start_height = request.get_ref().start_height,
+            end_height = request.get_ref().end_height,
+            keep_alive = request.get_ref().keep_alive,
+        ),
+    )]
+    async fn detected_block_range(
+        &self,
+        request: tonic::Request<DetectedBlockRangeRequest>,
+    ) -> Result<tonic::Response<Self::DetectedBlockRangeStream>, Status> {
+        let snapshot = self.storage.latest_snapshot();
+        snapshot
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let DetectedBlockRangeRequest {
+            start_height,
+            end_height,
+            keep_alive,
+            detection_keys,
+            ..
+        } = request.into_inner();
+
+        snapshot
+            .check_history_available(start_height)
+            .await
+            .map_err(|e| tonic::Status::failed_precondition(e.to_string()))?;
+
+        if detection_keys.len() > MAX_DETECTION_KEYS {
+            return Err(tonic::Status::invalid_argument(format!(
+                "at most {MAX_DETECTION_KEYS} detection keys can be used at once"
+            )));
+        }
+        let detection_keys = detection_keys
+            .into_iter()
+            .map(|bytes| {
+                let bytes: [u8; 32] = bytes.as_slice().try_into()?;
+                Ok(DetectionKey::from_bytes(bytes)?)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid detection key: {e}")))?;
+
+        let current_height = snapshot
+            .get_block_height()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting block height: {e}")))?;
+        std::mem::drop(snapshot);
+
+        // As for compact block ranges, an unspecified end height means the current height.
+        let end_height = if end_height == 0 {
+            current_height
+        } else {
+            std::cmp::min(end_height, current_height)
+        };
+
+        let storage = self.storage.clone();
+        let mut rx_state_snapshot = self.storage.subscribe();
+
+        let (tx_blocks, rx_blocks) = mpsc::channel(10);
+        let tx_blocks_err = tx_blocks.clone();
+        let task = async move {
+            // Phase 1: catch up from the start height to the end height.
+            let snapshot = storage.latest_snapshot();
+            for height in start_height..=end_height {
+                let response = detected_block(&snapshot, height, &detection_keys).await?;
+                tx_blocks.send(Ok(response)).await?;
+            }
+            std::mem::drop(snapshot);
+
+            if !keep_alive {
+                return Ok::<(), anyhow::Error>(());
+            }
+
+            // Phase 2: catch up on the blocks created while catching up, then
+            // stream new blocks as they are created.
+            let mut last_sent = end_height;
+            loop {
+                let snapshot = rx_state_snapshot.borrow_and_update().clone();
+                let height = snapshot.version();
+                for height in (last_sent + 1)..=height {
+                    let response = detected_block(&snapshot, height, &detection_keys).await?;
+                    tx_blocks.send(Ok(response)).await?;
+                }
+                last_sent = last_sent.max(height);
+                std::mem::drop(snapshot);
+
+                rx_state_snapshot.changed().await?;
+            }
+        };
+        tokio::spawn(
+            async move {
+                // Notify the client of the error before the task exits.
+                if let Err(e) = task.await {
+                    let _ = tx_blocks_err
+                        .send(Err(tonic::Status::internal(e.to_string())))
+                        .await;
+                }
+            }
+            .instrument(tracing::Span::current()),
+        );
+
+        Ok(tonic::Response::new(
+            tokio_stream::wrappers::ReceiverStream::new(rx_blocks).boxed(),
+        ))
+    }
+}
+
+/// Runs detection over the block at `height`.
+async fn detected_block(
+    snapshot: &Snapshot,
+    height: u64,
+    detection_keys: &[DetectionKey],
+) -> anyhow::Result<DetectedBlockRangeResponse> {
+    let compact_block = snapshot
+        .compact_block(height)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("compact block for height {height} is missing"))?;
+    let transactions = snapshot
+        .transactions_by_height(height)
+        .await?
+        .transactions
+        .into_iter()
+        .map(Transaction::try_from)
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let (compact_block, transactions) =
+        detection::detect_block(detection_keys, compact_block, transactions);
+
+    Ok(DetectedBlockRangeResponse {
+        compact_block: Some(compact_block.into()),
+        transactions: transactions.into_iter().map(Into::into).collect(),
+    })
 }
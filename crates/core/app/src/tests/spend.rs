@@ -8,6 +8,7 @@ use penumbra_component::{ActionHandler as _, Component};
 use penumbra_fee::Fee;
 use penumbra_keys::{test_keys, PayloadKey};
 use penumbra_num::Amount;
+use penumbra_proof_params::BatchVerifier;
 use penumbra_shielded_pool::{component::ShieldedPool, SpendPlan};
 use penumbra_storage::{ArcStateDeltaExt, StateDelta, TempStorage};
 use penumbra_transaction::{AuthorizingData, Transaction, TransactionBody, TransactionParameters};
@@ -57,6 +58,9 @@ async fn spend_happy_path() -> anyhow::Result<()> {
     };
 
     // 3. Simulate execution of the Spend action
+    let mut proofs = BatchVerifier::new();
+    spend.check_stateless_batched(&transaction_context, &mut proofs)?;
+    proofs.verify(&mut rng)?;
     spend.check_stateless(transaction_context).await?;
     spend.check_stateful(state.clone()).await?;
     let mut state_tx = state.try_begin_transaction().unwrap();
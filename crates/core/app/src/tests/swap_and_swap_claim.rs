@@ -9,6 +9,7 @@ use penumbra_component::{ActionHandler, Component};
 use penumbra_fee::Fee;
 use penumbra_keys::{test_keys, Address};
 use penumbra_num::Amount;
+use penumbra_proof_params::BatchVerifier;
 use penumbra_shielded_pool::component::ShieldedPool;
 use penumbra_storage::{ArcStateDeltaExt, StateDelta, TempStorage};
 use penumbra_transaction::Transaction;
@@ -63,6 +64,9 @@ async fn swap_and_swap_claim() -> anyhow::Result<()> {
 
     // 3. Simulate execution of the Swap action
 
+    let mut proofs = BatchVerifier::new();
+    swap.check_stateless_batched(&mut proofs)?;
+    proofs.verify(&mut rng)?;
     swap.check_stateless(()).await?;
     swap.check_stateful(state.clone()).await?;
     let mut state_tx = state.try_begin_transaction().unwrap();
@@ -121,6 +125,9 @@ async fn swap_and_swap_claim() -> anyhow::Result<()> {
     }
     .context();
 
+    let mut proofs = BatchVerifier::new();
+    claim.check_stateless_batched(context, &mut proofs)?;
+    proofs.verify(&mut rng)?;
     claim.check_stateless(context.clone()).await?;
     claim.check_stateful(state.clone()).await?;
     let mut state_tx = state.try_begin_transaction().unwrap();
@@ -177,6 +184,9 @@ async fn swap_claim_duplicate_nullifier_previous_transaction() {
 
     // 3. Simulate execution of the Swap action
 
+    let mut proofs = BatchVerifier::new();
+    swap.check_stateless_batched(&mut proofs).unwrap();
+    proofs.verify(&mut rng).unwrap();
     swap.check_stateless(()).await.unwrap();
     swap.check_stateful(state.clone()).await.unwrap();
     let mut state_tx = state.try_begin_transaction().unwrap();
@@ -233,6 +243,9 @@ async fn swap_claim_duplicate_nullifier_previous_transaction() {
     }
     .context();
 
+    let mut proofs = BatchVerifier::new();
+    claim.check_stateless_batched(context, &mut proofs).unwrap();
+    proofs.verify(&mut rng).unwrap();
     claim.check_stateless(context.clone()).await.unwrap();
     claim.check_stateful(state.clone()).await.unwrap();
     let mut state_tx = state.try_begin_transaction().unwrap();
@@ -295,6 +308,9 @@ async fn swap_with_nonzero_fee() -> anyhow::Result<()> {
 
     // 3. Simulate execution of the Swap action
 
+    let mut proofs = BatchVerifier::new();
+    swap.check_stateless_batched(&mut proofs)?;
+    proofs.verify(&mut rng)?;
     swap.check_stateless(()).await?;
     swap.check_stateful(state.clone()).await?;
     let mut state_tx = state.try_begin_transaction().unwrap();
@@ -129,6 +129,29 @@ pub trait StateReadExt: StateRead {
             .ok_or_else(|| anyhow!("Missing FmdParameters"))
     }
 
+    /// Indicates if the FMD parameters have been updated in this block.
+    fn fmd_parameters_updated(&self) -> bool {
+        self.object_get::<()>(state_key::fmd_parameters_updated())
+            .is_some()
+    }
+
+    /// Gets the number of clues included in the current epoch, up to the previous block.
+    ///
+    /// The count is recorded in the JMT, since the next FMD parameters are derived
+    /// from it, so a node restored from a snapshot mid-epoch must agree on it.
+    async fn get_fmd_clue_count(&self) -> Result<u64> {
+        Ok(self
+            .get_proto(state_key::fmd_clue_count())
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the number of clues included in the current block so far.
+    fn block_fmd_clue_count(&self) -> u64 {
+        self.object_get(state_key::block_fmd_clue_count())
+            .unwrap_or_default()
+    }
+
     /// Get the current epoch.
     async fn epoch(&self) -> Result<Epoch> {
         // Get the height
@@ -151,19 +174,73 @@ pub trait StateReadExt: StateRead {
     /// We look-ahead to the next height because we want to halt the chain immediately after
     /// committing the block.
     async fn is_upgrade_height(&self) -> Result<bool> {
-        let Some(next_upgrade_height) = self
-            .nonverifiable_get_raw(state_key::next_upgrade().as_bytes())
-            .await?
-        else {
+        let Some(next_upgrade_height) = self.next_upgrade_height().await? else {
             return Ok(false);
         };
 
-        let next_upgrade_height = u64::from_be_bytes(next_upgrade_height.as_slice().try_into()?);
-
         let current_height = self.get_block_height().await?;
         Ok(current_height.saturating_add(1) == next_upgrade_height)
     }
 
+    /// Returns the height of the scheduled upgrade, if any.
+    ///
+    /// Upgrades used to be scheduled in nonverifiable storage, so an upgrade scheduled
+    /// before the node was upgraded is still read from there.
+    async fn next_upgrade_height(&self) -> Result<Option<u64>> {
+        if let Some(height) = self.get_proto(state_key::next_upgrade()).await? {
+            return Ok(Some(height));
+        }
+        self.nonverifiable_get_raw(state_key::next_upgrade().as_bytes())
+            .await?
+            .map(|bytes| Ok(u64::from_be_bytes(bytes.as_slice().try_into()?)))
+            .transpose()
+    }
+
+    /// Returns the name of the scheduled upgrade, if any.
+    ///
+    /// Like its height, the name of an upgrade scheduled in nonverifiable storage is
+    /// still read from there.
+    async fn next_upgrade_name(&self) -> Result<Option<String>> {
+        if let Some(name) = self.get_proto(state_key::next_upgrade_name()).await? {
+            return Ok(Some(name));
+        }
+        self.nonverifiable_get_raw(state_key::next_upgrade_name().as_bytes())
+            .await?
+            .map(String::from_utf8)
+            .transpose()
+            .map_err(Into::into)
+    }
+
+    /// Returns the name of the upgrade the chain has halted for, if it is awaiting a migration.
+    async fn upgrade_halt(&self) -> Result<Option<String>> {
+        self.nonverifiable_get_raw(state_key::upgrade_halt().as_bytes())
+            .await?
+            .map(String::from_utf8)
+            .transpose()
+            .map_err(Into::into)
+    }
+
+    /// Returns the height at which the state was restored from a state sync snapshot, if it was.
+    async fn restored_height(&self) -> Result<Option<u64>> {
+        self.nonverifiable_get_raw(state_key::restored_height().as_bytes())
+            .await?
+            .map(|bytes| Ok(u64::from_be_bytes(bytes.as_slice().try_into()?)))
+            .transpose()
+    }
+
+    /// Checks that the history of the block at `height`, such as its compact block, is
+    /// available. A node restored from a snapshot never executed the blocks up to the
+    /// height it was restored at, so it has no history for them.
+    async fn check_history_available(&self, height: u64) -> Result<()> {
+        match self.restored_height().await? {
+            Some(restored_height) if height <= restored_height => Err(anyhow!(
+                "state was restored from a snapshot at height {restored_height}, \
+                 so the history of block {height} is not available"
+            )),
+            _ => Ok(()),
+        }
+    }
+
     async fn epoch_by_height(&self, height: u64) -> Result<Epoch> {
         self.get(&state_key::epoch_by_height(height))
             .await?
@@ -221,6 +298,9 @@ pub trait StateWriteExt: StateWrite {
 
     /// Writes the current FMD parameters to the JMT.
     fn put_current_fmd_parameters(&mut self, params: FmdParameters) {
+        // Note that the FMD parameters have been updated:
+        self.object_put(state_key::fmd_parameters_updated(), ());
+
         self.put(state_key::fmd_parameters_current().into(), params)
     }
 
@@ -229,6 +309,60 @@ pub trait StateWriteExt: StateWrite {
         self.put(state_key::fmd_parameters_previous().into(), params)
     }
 
+    /// Adds to the number of clues included in the current block.
+    fn add_fmd_clue_count(&mut self, count: u64) {
+        let total = self.block_fmd_clue_count().saturating_add(count);
+        self.object_put(state_key::block_fmd_clue_count(), total);
+    }
+
+    /// Adds the clues included in the current block to the count for the epoch.
+    async fn record_block_fmd_clue_count(&mut self) -> Result<()> {
+        let total = self
+            .get_fmd_clue_count()
+            .await?
+            .saturating_add(self.block_fmd_clue_count());
+        self.put_fmd_clue_count(total);
+        Ok(())
+    }
+
+    /// Writes the number of clues included in the current epoch.
+    fn put_fmd_clue_count(&mut self, count: u64) {
+        self.put_proto(state_key::fmd_clue_count().to_string(), count);
+    }
+
+    /// Recomputes the FMD parameters from the clues included in the epoch that
+    /// just ended, and resets the clue count for the next epoch.
+    ///
+    /// If the precision changes, the new parameters become effective at
+    /// `next_height`, and the current ones are kept as the previous parameters,
+    /// so that clues created with them remain valid during the grace period.
+    async fn update_fmd_parameters(&mut self, next_height: u64) -> Result<()> {
+        let chain_params = self.get_chain_params().await?;
+        let current = self.get_current_fmd_parameters().await?;
+        let clue_count = self.get_fmd_clue_count().await?;
+
+        let precision_bits = current.next_precision_bits(
+            clue_count,
+            chain_params.fmd_target_false_positives_per_epoch,
+        );
+        if precision_bits != current.precision_bits {
+            tracing::info!(
+                clue_count,
+                old_precision_bits = current.precision_bits,
+                new_precision_bits = precision_bits,
+                "updating fmd parameters"
+            );
+            self.put_previous_fmd_parameters(current);
+            self.put_current_fmd_parameters(FmdParameters {
+                precision_bits,
+                as_of_block_height: next_height,
+            });
+        }
+
+        self.put_fmd_clue_count(0);
+        Ok(())
+    }
+
     /// Signals to the consensus worker to halt after the next commit.
     async fn signal_halt(&mut self) -> Result<()> {
         let halt_count = self.chain_halt_count().await?;
@@ -244,15 +378,50 @@ pub trait StateWriteExt: StateWrite {
         Ok(())
     }
 
-    /// Record the next upgrade height.
+    /// Record the next upgrade height and the name of the migration to run at that height.
     /// Right after committing the state for this height, the chain will halt and wait for an upgrade.
-    /// It uses the same mechanism as emergency halting to prevent the chain from restarting.
-    async fn signal_upgrade(&mut self, height: u64) -> Result<()> {
+    async fn signal_upgrade(&mut self, height: u64, name: String) -> Result<()> {
+        self.put_proto(state_key::next_upgrade().to_string(), height);
+        self.put_proto(state_key::next_upgrade_name().to_string(), name);
+        self.clear_legacy_upgrade();
+        Ok(())
+    }
+
+    /// Signals that the chain is halting for the scheduled upgrade.
+    ///
+    /// Unlike [`signal_halt`](Self::signal_halt), this does not touch the halt count: the node
+    /// refuses to restart until `pd migrate` has applied the named migration and cleared the
+    /// marker with [`clear_upgrade_halt`](Self::clear_upgrade_halt).
+    async fn signal_upgrade_halt(&mut self) -> Result<()> {
+        let name = self
+            .next_upgrade_name()
+            .await?
+            .ok_or_else(|| anyhow!("missing name for scheduled upgrade"))?;
+        self.nonverifiable_put_raw(state_key::upgrade_halt().into(), name.into_bytes());
+        Ok(())
+    }
+
+    /// Clears the scheduled upgrade and the upgrade halt marker, once the migration has run.
+    fn clear_upgrade_halt(&mut self) {
+        self.delete(state_key::next_upgrade().to_string());
+        self.delete(state_key::next_upgrade_name().to_string());
+        self.clear_legacy_upgrade();
+        self.nonverifiable_delete(state_key::upgrade_halt().into());
+    }
+
+    /// Clears an upgrade scheduled in nonverifiable storage, where upgrades used to be
+    /// scheduled before they were recorded in the JMT.
+    fn clear_legacy_upgrade(&mut self) {
+        self.nonverifiable_delete(state_key::next_upgrade().into());
+        self.nonverifiable_delete(state_key::next_upgrade_name().into());
+    }
+
+    /// Records that the state was restored from a state sync snapshot at `height`.
+    fn put_restored_height(&mut self, height: u64) {
         self.nonverifiable_put_raw(
-            state_key::next_upgrade().into(),
+            state_key::restored_height().into(),
             height.to_be_bytes().to_vec(),
         );
-        Ok(())
     }
 
     // Signals that the epoch should end this block.
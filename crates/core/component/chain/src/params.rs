@@ -19,6 +19,14 @@ use serde::{Deserialize, Serialize};
 pub struct ChainParameters {
     pub chain_id: String,
     pub epoch_duration: u64,
+    /// The number of false-positive detections each client should expect per epoch,
+    /// used to recompute the FMD precision at the end of every epoch. Governance only
+    /// accepts a nonzero target; a zero target, as decoded from parameters that predate
+    /// this field, disables the adjustment.
+    pub fmd_target_false_positives_per_epoch: u64,
+    /// The number of blocks after an FMD parameter change during which clues created
+    /// with the previous parameters are still accepted.
+    pub fmd_grace_period_blocks: u64,
 }
 
 impl DomainType for ChainParameters {
@@ -32,6 +40,8 @@ impl TryFrom<pb_chain::ChainParameters> for ChainParameters {
         Ok(ChainParameters {
             chain_id: msg.chain_id,
             epoch_duration: msg.epoch_duration,
+            fmd_target_false_positives_per_epoch: msg.fmd_target_false_positives_per_epoch,
+            fmd_grace_period_blocks: msg.fmd_grace_period_blocks,
         })
     }
 }
@@ -41,6 +51,8 @@ impl From<ChainParameters> for pb_chain::ChainParameters {
         pb_chain::ChainParameters {
             chain_id: params.chain_id,
             epoch_duration: params.epoch_duration,
+            fmd_target_false_positives_per_epoch: params.fmd_target_false_positives_per_epoch,
+            fmd_grace_period_blocks: params.fmd_grace_period_blocks,
         }
     }
 }
@@ -52,11 +64,13 @@ impl Default for ChainParameters {
         Self {
             chain_id: String::new(),
             epoch_duration: 719,
+            fmd_target_false_positives_per_epoch: 64,
+            fmd_grace_period_blocks: 10,
         }
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(try_from = "pb_chain::FmdParameters", into = "pb_chain::FmdParameters")]
 pub struct FmdParameters {
     /// Bits of precision.
@@ -65,6 +79,31 @@ pub struct FmdParameters {
     pub as_of_block_height: u64,
 }
 
+impl FmdParameters {
+    /// Computes the precision to use for the next epoch, given the number of
+    /// clues observed during the last epoch.
+    ///
+    /// A client examining `clue_count` clues created with `p` bits of precision
+    /// expects `clue_count / 2^p` false positives, so this picks the highest
+    /// precision that still yields at least `target_false_positives` of them,
+    /// capped below [`decaf377_fmd::MAX_PRECISION`], which clues can't be created
+    /// with. A target of zero disables the adjustment, keeping the current precision.
+    pub fn next_precision_bits(&self, clue_count: u64, target_false_positives: u64) -> u8 {
+        if target_false_positives == 0 {
+            return self.precision_bits;
+        }
+
+        let mut precision_bits = 0;
+        while precision_bits + 1 < decaf377_fmd::MAX_PRECISION
+            && (clue_count >> (precision_bits + 1)) >= target_false_positives
+        {
+            precision_bits += 1;
+        }
+
+        precision_bits as u8
+    }
+}
+
 impl DomainType for FmdParameters {
     type Proto = pb_chain::FmdParameters;
 }
@@ -187,3 +226,34 @@ impl From<pb_chain::Ratio> for Ratio {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fmd_precision_targets_false_positives() {
+        let params = FmdParameters::default();
+        // Too few clues to reach the target at any precision.
+        assert_eq!(params.next_precision_bits(0, 64), 0);
+        assert_eq!(params.next_precision_bits(127, 64), 0);
+        // Exactly enough clues for one bit of precision.
+        assert_eq!(params.next_precision_bits(128, 64), 1);
+        assert_eq!(params.next_precision_bits(255, 64), 1);
+        assert_eq!(params.next_precision_bits(64 << 10, 64), 10);
+        // The precision stays below the maximum, so clues can still be created.
+        assert_eq!(
+            params.next_precision_bits(u64::MAX, 1) as usize,
+            decaf377_fmd::MAX_PRECISION - 1
+        );
+    }
+
+    #[test]
+    fn fmd_precision_unchanged_when_disabled() {
+        let params = FmdParameters {
+            precision_bits: 7,
+            as_of_block_height: 1,
+        };
+        assert_eq!(params.next_precision_bits(1 << 20, 0), 7);
+    }
+}
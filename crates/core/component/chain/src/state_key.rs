@@ -20,10 +20,28 @@ pub fn fmd_parameters_previous() -> &'static str {
     "chain/fmd_parameters/previous"
 }
 
+pub fn fmd_clue_count() -> &'static str {
+    "chain/fmd_clue_count"
+}
+
+pub fn block_fmd_clue_count() -> &'static str {
+    "chain/block_fmd_clue_count"
+}
+
 pub fn chain_halt_count() -> &'static str {
     "chain/halt_count"
 }
 
+// The scheduled upgrade used to be kept in the nonverifiable store, under the same keys,
+// where upgrades scheduled by older versions are still read from.
+pub fn next_upgrade() -> &'static str {
+    "chain/next_upgrade"
+}
+
+pub fn next_upgrade_name() -> &'static str {
+    "chain/next_upgrade_name"
+}
+
 // These are used in the nonverifiable store:
 pub fn halted(total_halt_count: u64) -> Vec<u8> {
     let mut key = b"chain/halt/".to_vec();
@@ -31,8 +49,12 @@ pub fn halted(total_halt_count: u64) -> Vec<u8> {
     key
 }
 
-pub fn next_upgrade() -> &'static str {
-    "chain/next_upgrade"
+pub fn upgrade_halt() -> &'static str {
+    "chain/upgrade_halt"
+}
+
+pub fn restored_height() -> &'static str {
+    "chain/restored_height"
 }
 
 // These are used for the object store:
@@ -51,3 +73,7 @@ pub fn end_epoch_early() -> &'static str {
 pub fn chain_params_updated() -> &'static str {
     "chain/chain_params_updated"
 }
+
+pub fn fmd_parameters_updated() -> &'static str {
+    "chain/fmd_parameters_updated"
+}
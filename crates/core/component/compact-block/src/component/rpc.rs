@@ -59,6 +59,11 @@ impl QueryService for Server {
             ..
         } = request.into_inner();
 
+        snapshot
+            .check_history_available(start_height)
+            .await
+            .map_err(|e| tonic::Status::failed_precondition(e.to_string()))?;
+
         let current_height = snapshot
             .get_block_height()
             .await
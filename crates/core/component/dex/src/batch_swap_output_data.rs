@@ -21,8 +21,14 @@ use super::TradingPair;
 #[serde(try_from = "pb::BatchSwapOutputData", into = "pb::BatchSwapOutputData")]
 pub struct BatchSwapOutputData {
     /// The total amount of asset 1 that was input to the batch swap.
+    ///
+    /// This excludes the input of swaps that were refunded because the batch
+    /// couldn't honor their minimum output.
     pub delta_1: Amount,
     /// The total amount of asset 2 that was input to the batch swap.
+    ///
+    /// This excludes the input of swaps that were refunded because the batch
+    /// couldn't honor their minimum output.
     pub delta_2: Amount,
     /// The total amount of asset 1 that was output from the batch swap for 2=>1 trades.
     pub lambda_1: Amount,
@@ -38,6 +44,12 @@ pub struct BatchSwapOutputData {
     pub trading_pair: TradingPair,
     /// The starting block height of the epoch for which the batch swap data is valid.
     pub epoch_starting_height: u64,
+    /// The price (in asset 2 per unit of asset 1) that the batch could offer
+    /// 1=>2 trades: those whose minimum output demanded more were refunded.
+    pub limit_price_1: U128x128,
+    /// The price (in asset 1 per unit of asset 2) that the batch could offer
+    /// 2=>1 trades: those whose minimum output demanded more were refunded.
+    pub limit_price_2: U128x128,
 }
 
 impl BatchSwapOutputData {
@@ -80,6 +92,50 @@ impl BatchSwapOutputData {
                 .expect("rounded amount is integral"),
         )
     }
+
+    /// Returns whether a swap of `(delta_1_i, delta_2_i)` that must output at
+    /// least `min_output` of the other asset was refunded by the batch.
+    ///
+    /// A swap is refunded if the price demanded by its minimum output is above
+    /// the batch's limit price for its direction. Unlimited swaps (with a zero
+    /// minimum output) are never refunded.
+    pub fn is_refunded(
+        &self,
+        (delta_1_i, delta_2_i): (Amount, Amount),
+        min_output: Amount,
+    ) -> bool {
+        // Limited swaps only have input in one direction. When the input is
+        // zero, the outputs are zero whether or not the swap is refunded.
+        let (input, limit_price) = if delta_1_i == Amount::zero() {
+            (delta_2_i, self.limit_price_2)
+        } else {
+            (delta_1_i, self.limit_price_1)
+        };
+        if input == Amount::zero() {
+            return false;
+        }
+        let demanded_price =
+            U128x128::ratio(min_output, input).expect("input is nonzero, so the ratio is defined");
+
+        demanded_price > limit_price
+    }
+
+    /// Given a user's inputs `(delta_1_i, delta_2_i)` and the minimum output
+    /// they committed to, compute their outputs `(lambda_1_i, lambda_2_i)`.
+    ///
+    /// This is the user's pro rata share of the batch output, unless the batch
+    /// couldn't honor their minimum output, in which case it's their input.
+    pub fn swap_outputs(
+        &self,
+        (delta_1_i, delta_2_i): (Amount, Amount),
+        min_output: Amount,
+    ) -> (Amount, Amount) {
+        if self.is_refunded((delta_1_i, delta_2_i), min_output) {
+            (delta_1_i, delta_2_i)
+        } else {
+            self.pro_rata_outputs((delta_1_i, delta_2_i))
+        }
+    }
 }
 
 impl ToConstraintField<Fq> for BatchSwapOutputData {
@@ -131,6 +187,16 @@ impl ToConstraintField<Fq> for BatchSwapOutputData {
                 .to_field_elements()
                 .expect("Fq types are Bls12-377 field members"),
         );
+        public_inputs.extend(
+            self.limit_price_1
+                .to_field_elements()
+                .expect("U128x128 types are Bls12-377 field members"),
+        );
+        public_inputs.extend(
+            self.limit_price_2
+                .to_field_elements()
+                .expect("U128x128 types are Bls12-377 field members"),
+        );
         Some(public_inputs)
     }
 }
@@ -145,6 +211,8 @@ pub struct BatchSwapOutputDataVar {
     pub height: FqVar,
     pub trading_pair: TradingPairVar,
     pub epoch_starting_height: FqVar,
+    pub limit_price_1: U128x128Var,
+    pub limit_price_2: U128x128Var,
 }
 
 impl AllocVar<BatchSwapOutputData, Fq> for BatchSwapOutputDataVar {
@@ -176,10 +244,16 @@ impl AllocVar<BatchSwapOutputData, Fq> for BatchSwapOutputDataVar {
             || Ok(output_data.trading_pair),
             mode,
         )?;
-        let epoch_starting_height =
-            FqVar::new_variable(cs, || Ok(Fq::from(output_data.epoch_starting_height)), mode)?;
+        let epoch_starting_height = FqVar::new_variable(
+            cs.clone(),
+            || Ok(Fq::from(output_data.epoch_starting_height)),
+            mode,
+        )?;
         // Check the epoch starting height is 64 bits
         let _ = bit_constrain(epoch_starting_height.clone(), 64);
+        let limit_price_1 =
+            U128x128Var::new_variable(cs.clone(), || Ok(output_data.limit_price_1), mode)?;
+        let limit_price_2 = U128x128Var::new_variable(cs, || Ok(output_data.limit_price_2), mode)?;
 
         Ok(Self {
             delta_1,
@@ -191,6 +265,8 @@ impl AllocVar<BatchSwapOutputData, Fq> for BatchSwapOutputDataVar {
             trading_pair,
             height,
             epoch_starting_height,
+            limit_price_1,
+            limit_price_2,
         })
     }
 }
@@ -211,6 +287,8 @@ impl From<BatchSwapOutputData> for pb::BatchSwapOutputData {
             height: s.height,
             epoch_starting_height: s.epoch_starting_height,
             trading_pair: Some(s.trading_pair.into()),
+            limit_price_1: s.limit_price_1.to_bytes().to_vec(),
+            limit_price_2: s.limit_price_2.to_bytes().to_vec(),
         }
     }
 }
@@ -263,6 +341,46 @@ impl BatchSwapOutputDataVar {
 
         Ok((lambda_1_i_rounded.into(), lambda_2_i_rounded.into()))
     }
+
+    /// In-circuit version of [`BatchSwapOutputData::swap_outputs`].
+    pub fn swap_outputs(
+        &self,
+        delta_1_i: AmountVar,
+        delta_2_i: AmountVar,
+        min_output: AmountVar,
+        cs: ConstraintSystemRef<Fq>,
+    ) -> Result<(AmountVar, AmountVar), SynthesisError> {
+        let (pro_rata_1_i, pro_rata_2_i) =
+            self.pro_rata_outputs(delta_1_i.clone(), delta_2_i.clone(), cs.clone())?;
+
+        // Limited swaps only have input in one direction.
+        let zero_amount = AmountVar::new_constant(cs.clone(), Amount::zero())?;
+        let delta_1_i_is_zero = delta_1_i.is_eq(&zero_amount)?;
+        let input = AmountVar::conditionally_select(&delta_1_i_is_zero, &delta_2_i, &delta_1_i)?;
+        let limit_price = U128x128Var::conditionally_select(
+            &delta_1_i_is_zero,
+            &self.limit_price_2,
+            &self.limit_price_1,
+        )?;
+
+        // Compute the price demanded by the swap's minimum output, avoiding a
+        // division by zero when the input is zero (in which case the outputs
+        // are zero whether or not the swap is refunded).
+        let zero = U128x128Var::zero();
+        let one = U128x128Var::new_constant(cs.clone(), U128x128::from(1u64))?;
+        let input = U128x128Var::from_amount_var(input)?;
+        let input_is_zero = input.is_eq(&zero)?;
+        let divisor = U128x128Var::conditionally_select(&input_is_zero, &one, &input)?;
+        let demanded_price = U128x128Var::from_amount_var(min_output)?.checked_div(&divisor, cs)?;
+        let refunded = input_is_zero
+            .not()
+            .and(&demanded_price.is_gt(&limit_price)?)?;
+
+        let lambda_1_i = AmountVar::conditionally_select(&refunded, &delta_1_i, &pro_rata_1_i)?;
+        let lambda_2_i = AmountVar::conditionally_select(&refunded, &delta_2_i, &pro_rata_2_i)?;
+
+        Ok((lambda_1_i, lambda_2_i))
+    }
 }
 
 impl From<BatchSwapOutputData> for pb::BatchSwapOutputDataResponse {
@@ -307,10 +425,22 @@ impl TryFrom<pb::BatchSwapOutputData> for BatchSwapOutputData {
                 .ok_or_else(|| anyhow!("Missing trading_pair"))?
                 .try_into()?,
             epoch_starting_height: s.epoch_starting_height,
+            limit_price_1: limit_price_from_bytes(&s.limit_price_1)?,
+            limit_price_2: limit_price_from_bytes(&s.limit_price_2)?,
         })
     }
 }
 
+/// Decodes a limit price, treating an empty encoding as zero.
+fn limit_price_from_bytes(bytes: &[u8]) -> anyhow::Result<U128x128> {
+    if bytes.is_empty() {
+        return Ok(U128x128::default());
+    }
+    Ok(U128x128::from_bytes(bytes.try_into().map_err(|_| {
+        anyhow!("limit price must be 32 bytes, got {}", bytes.len())
+    })?))
+}
+
 impl TryFrom<pb::BatchSwapOutputDataResponse> for BatchSwapOutputData {
     type Error = anyhow::Error;
     fn try_from(value: pb::BatchSwapOutputDataResponse) -> Result<Self, Self::Error> {
@@ -424,6 +554,8 @@ mod tests {
                     height: 1,
                     trading_pair,
                     epoch_starting_height: 1,
+                    limit_price_1: U128x128::default(),
+                    limit_price_2: U128x128::default(),
                 },
             }
         }
@@ -445,6 +577,8 @@ mod tests {
             height: 0u64,
             trading_pair,
             epoch_starting_height: 0u64,
+            limit_price_1: U128x128::default(),
+            limit_price_2: U128x128::default(),
         };
 
         // Now suppose our user's contribution is:
@@ -477,4 +611,120 @@ mod tests {
 
         assert!(proof_result);
     }
+
+    struct SwapOutputCircuit {
+        delta_1_i: Amount,
+        delta_2_i: Amount,
+        min_output: Amount,
+        lambda_1_i: Amount,
+        lambda_2_i: Amount,
+        pub bsod: BatchSwapOutputData,
+    }
+
+    impl ConstraintSynthesizer<Fq> for SwapOutputCircuit {
+        fn generate_constraints(
+            self,
+            cs: ConstraintSystemRef<Fq>,
+        ) -> ark_relations::r1cs::Result<()> {
+            let delta_1_i_var = AmountVar::new_witness(cs.clone(), || Ok(self.delta_1_i))?;
+            let delta_2_i_var = AmountVar::new_witness(cs.clone(), || Ok(self.delta_2_i))?;
+            let min_output_var = AmountVar::new_witness(cs.clone(), || Ok(self.min_output))?;
+            let lambda_1_i_var = AmountVar::new_witness(cs.clone(), || Ok(self.lambda_1_i))?;
+            let lambda_2_i_var = AmountVar::new_witness(cs.clone(), || Ok(self.lambda_2_i))?;
+            let bsod_var = BatchSwapOutputDataVar::new_input(cs.clone(), || Ok(self.bsod))?;
+
+            let (calculated_lambda_1_i_var, calculated_lambda_2_i_var) =
+                bsod_var.swap_outputs(delta_1_i_var, delta_2_i_var, min_output_var, cs.clone())?;
+            calculated_lambda_1_i_var.enforce_equal(&lambda_1_i_var)?;
+            calculated_lambda_2_i_var.enforce_equal(&lambda_2_i_var)?;
+
+            Ok(())
+        }
+    }
+
+    impl DummyWitness for SwapOutputCircuit {
+        fn with_dummy_witness() -> Self {
+            let ProRataOutputCircuit {
+                delta_1_i,
+                delta_2_i,
+                lambda_1_i,
+                lambda_2_i,
+                bsod,
+            } = ProRataOutputCircuit::with_dummy_witness();
+            Self {
+                delta_1_i,
+                delta_2_i,
+                min_output: Amount::from(1u32),
+                lambda_1_i,
+                lambda_2_i,
+                bsod,
+            }
+        }
+    }
+
+    #[test]
+    fn bsod_refunds_swaps_below_min_output() {
+        let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+        let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+        let trading_pair = TradingPair::new(gm.id(), gn.id());
+        // The batch could only offer 1=>2 trades a price of 0.5.
+        let bsod = BatchSwapOutputData {
+            delta_1: Amount::from(200u64),
+            delta_2: Amount::from(0u64),
+            lambda_1: Amount::from(0u64),
+            lambda_2: Amount::from(100u64),
+            unfilled_1: Amount::from(0u64),
+            unfilled_2: Amount::from(0u64),
+            height: 0u64,
+            trading_pair,
+            epoch_starting_height: 0u64,
+            limit_price_1: U128x128::ratio(1u64, 2u64).unwrap(),
+            limit_price_2: U128x128::default(),
+        };
+        let delta_1_i = Amount::from(100u64);
+        let delta_2_i = Amount::from(0u64);
+
+        // A swap demanding a price above the limit price is refunded...
+        assert!(bsod.is_refunded((delta_1_i, delta_2_i), Amount::from(60u64)));
+        assert_eq!(
+            bsod.swap_outputs((delta_1_i, delta_2_i), Amount::from(60u64)),
+            (delta_1_i, delta_2_i)
+        );
+        // ...while one demanding at most the limit price gets its pro rata share.
+        assert!(!bsod.is_refunded((delta_1_i, delta_2_i), Amount::from(50u64)));
+        assert_eq!(
+            bsod.swap_outputs((delta_1_i, delta_2_i), Amount::from(50u64)),
+            bsod.pro_rata_outputs((delta_1_i, delta_2_i))
+        );
+        // Unlimited swaps are never refunded.
+        assert!(!bsod.is_refunded((delta_1_i, delta_2_i), Amount::zero()));
+
+        let mut rng = OsRng;
+        let (pk, vk) = generate_test_parameters::<SwapOutputCircuit>(&mut rng);
+
+        for min_output in [0u64, 50, 60] {
+            let min_output = Amount::from(min_output);
+            let (lambda_1_i, lambda_2_i) = bsod.swap_outputs((delta_1_i, delta_2_i), min_output);
+            let circuit = SwapOutputCircuit {
+                delta_1_i,
+                delta_2_i,
+                min_output,
+                lambda_1_i,
+                lambda_2_i,
+                bsod,
+            };
+
+            let proof = Groth16::<Bls12_377, LibsnarkReduction>::prove(&pk, circuit, &mut rng)
+                .expect("should be able to form proof");
+
+            let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify(
+                &vk,
+                &bsod.to_field_elements().unwrap(),
+                &proof,
+            )
+            .expect("should be able to verify proof");
+
+            assert!(proof_result);
+        }
+    }
 }
@@ -1,34 +1,84 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use ark_ff::Zero;
 use async_trait::async_trait;
+use decaf377::Fr;
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_component::ActionHandler;
+use penumbra_shielded_pool::component::SupplyWrite;
 use penumbra_storage::{StateRead, StateWrite};
 
-use crate::lp::action::PositionRewardClaim;
+use crate::{
+    component::{LpRewardsManager, LpRewardsRead, PositionManager, PositionRead},
+    event,
+    lp::{action::PositionRewardClaim, position},
+};
 
 #[async_trait]
 /// Debits a withdrawn position NFT and credits a claimed position NFT and any liquidity incentives.
 impl ActionHandler for PositionRewardClaim {
     type CheckStatelessContext = ();
     async fn check_stateless(&self, _context: ()) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp rewards not supported yet"))
+        // Nothing to do: the only validation is of the state change,
+        // and that's done by the value balance mechanism.
+        Ok(())
     }
 
-    async fn check_stateful<S: StateRead + 'static>(&self, _state: Arc<S>) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp rewards not supported yet"))
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        // Check that the committed rewards in the action match the state.
+        state
+            .position_by_id(&self.position_id)
+            .await?
+            .ok_or_else(|| anyhow!("claimed rewards of unknown position {}", self.position_id))?;
+
+        let expected_rewards_commitment = Value {
+            amount: state.claimable_rewards(&self.position_id).await?,
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+        .commit(Fr::zero());
+
+        if self.rewards_commitment != expected_rewards_commitment {
+            anyhow::bail!(
+                "rewards commitment {:?} is incorrect, expected {:?}",
+                self.rewards_commitment,
+                expected_rewards_commitment
+            );
+        }
+
+        // As for withdrawals, we don't check that the position state is
+        // Withdrawn here, so that a position can be withdrawn and have its
+        // rewards claimed in one transaction.
+
+        Ok(())
     }
 
-    async fn execute<S: StateWrite>(&self, _state: S) -> Result<()> {
-        // It's important to reject all LP actions for now, to prevent
-        // inflation / minting bugs until we implement all required checks
-        // (e.g., minting tokens by withdrawing reserves we don't check)
-        Err(anyhow::anyhow!("lp rewards not supported yet"))
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // See comment in check_stateful for why we check the position state here.
+        let mut metadata = state
+            .position_by_id(&self.position_id)
+            .await?
+            .ok_or_else(|| anyhow!("claimed rewards of unknown position {}", self.position_id))?;
+
+        if metadata.state != position::State::Withdrawn {
+            anyhow::bail!(
+                "attempted to claim rewards of position {} with state {}, expected Withdrawn",
+                self.position_id,
+                metadata.state
+            );
+        }
+
+        // The rewards are newly issued staking tokens.
+        let rewards = state.take_claimable_rewards(&self.position_id).await?;
+        state
+            .update_token_supply(&STAKING_TOKEN_ASSET_ID, rewards.value() as i128)
+            .await?;
+
+        state.record(event::position_reward_claim(self, rewards));
+
+        metadata.state = position::State::Claimed;
+        state.put_position(metadata).await?;
+
+        Ok(())
     }
 }
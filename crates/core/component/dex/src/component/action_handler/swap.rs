@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
 use penumbra_component::ActionHandler;
-use penumbra_proof_params::SWAP_PROOF_VERIFICATION_KEY;
+use penumbra_num::Amount;
+use penumbra_proof_params::{BatchVerifier, SWAP_PROOF_VERIFICATION_KEY};
 use penumbra_storage::{StateRead, StateWrite};
 
 use crate::{
@@ -12,24 +13,61 @@ use crate::{
     swap::Swap,
 };
 
-#[async_trait]
-impl ActionHandler for Swap {
-    type CheckStatelessContext = ();
-    async fn check_stateless(&self, _context: ()) -> Result<()> {
+impl Swap {
+    /// Checks the swap statelessly, like `check_stateless`, except that its proof is
+    /// queued in `batch` instead of being verified, so that it can be verified along
+    /// with the other proofs of the transaction.
+    pub fn check_stateless_batched(&self, batch: &mut BatchVerifier<'static>) -> Result<()> {
+        self.check_swap_body()?;
+
+        self.proof
+            .queue(
+                batch,
+                &SWAP_PROOF_VERIFICATION_KEY,
+                self.balance_commitment_inner(),
+                self.body.payload.commitment,
+                self.body.fee_commitment,
+                self.body.min_output,
+            )
+            .context("invalid swap proof")
+    }
+
+    fn check_swap_body(&self) -> Result<()> {
         // Check that the trading pair is distinct.
         if self.body.trading_pair.asset_1() == self.body.trading_pair.asset_2() {
             anyhow::bail!("Trading pair must be distinct");
         }
 
-        self.proof.verify(
-            &SWAP_PROOF_VERIFICATION_KEY,
-            self.balance_commitment_inner(),
-            self.body.payload.commitment,
-            self.body.fee_commitment,
-        )?;
+        // Check that a swap with a minimum output has input in exactly one direction,
+        // so that the minimum output is denominated in a single asset.
+        if self.body.min_output > Amount::zero() {
+            let inputs_1 = self.body.delta_1_i > Amount::zero();
+            let inputs_2 = self.body.delta_2_i > Amount::zero();
+            if inputs_1 == inputs_2 {
+                anyhow::bail!("Swap with a minimum output must have input in exactly one asset");
+            }
+        }
 
         Ok(())
     }
+}
+
+#[async_trait]
+impl ActionHandler for Swap {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        self.check_swap_body()?;
+
+        self.proof
+            .verify(
+                &SWAP_PROOF_VERIFICATION_KEY,
+                self.balance_commitment_inner(),
+                self.body.payload.commitment,
+                self.body.fee_commitment,
+                self.body.min_output,
+            )
+            .context("a swap proof did not verify")
+    }
 
     async fn check_stateful<S: StateRead + 'static>(&self, _state: Arc<S>) -> Result<()> {
         Ok(())
@@ -47,6 +85,10 @@ impl ActionHandler for Swap {
         // Add the amount of each asset being swapped to the batch swap flow.
         swap_flow.0 += swap.body.delta_1_i;
         swap_flow.1 += swap.body.delta_2_i;
+        swap_flow.add_limited_swap(
+            (swap.body.delta_1_i, swap.body.delta_2_i),
+            swap.body.min_output,
+        );
 
         // Set the batch swap flow for the trading pair.
         state.put_swap_flow(&swap.body.trading_pair, swap_flow);
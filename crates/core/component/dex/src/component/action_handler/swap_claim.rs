@@ -6,12 +6,36 @@ use penumbra_chain::component::StateReadExt as _;
 use penumbra_chain::TransactionContext;
 use penumbra_component::ActionHandler;
 
-use penumbra_proof_params::SWAPCLAIM_PROOF_VERIFICATION_KEY;
+use penumbra_proof_params::{BatchVerifier, SWAPCLAIM_PROOF_VERIFICATION_KEY};
 use penumbra_shielded_pool::component::{NoteManager, StateReadExt as _};
 use penumbra_storage::{StateRead, StateWrite};
 
 use crate::{component::StateReadExt, event, swap_claim::SwapClaim};
 
+impl SwapClaim {
+    /// Checks the swap claim statelessly, like `check_stateless`, except that its proof is
+    /// queued in `batch` instead of being verified, so that it can be verified along
+    /// with the other proofs of the transaction.
+    pub fn check_stateless_batched(
+        &self,
+        context: &TransactionContext,
+        batch: &mut BatchVerifier<'static>,
+    ) -> Result<()> {
+        self.proof
+            .queue(
+                batch,
+                &SWAPCLAIM_PROOF_VERIFICATION_KEY,
+                context.anchor,
+                self.body.nullifier,
+                self.body.fee.clone(),
+                self.body.output_data,
+                self.body.output_1_commitment,
+                self.body.output_2_commitment,
+            )
+            .context("invalid swap claim proof")
+    }
+}
+
 #[async_trait]
 impl ActionHandler for SwapClaim {
     type CheckStatelessContext = TransactionContext;
@@ -26,9 +50,7 @@ impl ActionHandler for SwapClaim {
                 self.body.output_1_commitment,
                 self.body.output_2_commitment,
             )
-            .context("a swap claim proof did not verify")?;
-
-        Ok(())
+            .context("a swap claim proof did not verify")
     }
 
     async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
@@ -18,16 +18,24 @@ use super::{
 pub trait Arbitrage: StateWrite + Sized {
     /// Attempts to extract as much as possible of the `arb_token` from the available
     /// liquidity positions, and returns the amount of `arb_token` extracted.
-    #[instrument(skip(self, arb_token, fixed_candidates))]
+    ///
+    /// The search routes through the fixed candidates of `routing_params`, with
+    /// one more hop than `routing_params.max_hops` to allow returning to the
+    /// `arb_token`. Its price limit is ignored, since an arb must be profitable.
+    #[instrument(skip(self, arb_token, routing_params))]
     async fn arbitrage(
         self: &mut Arc<Self>,
         arb_token: asset::Id,
-        fixed_candidates: Vec<asset::Id>,
+        routing_params: RoutingParams,
     ) -> Result<Value>
     where
         Self: 'static,
     {
-        tracing::debug!(?arb_token, ?fixed_candidates, "beginning arb search");
+        tracing::debug!(
+            ?arb_token,
+            fixed_candidates = ?routing_params.fixed_candidates,
+            "beginning arb search"
+        );
         let arb_start = std::time::Instant::now();
 
         // Work in a new `StateDelta`, so we can transactionally apply any state
@@ -39,9 +47,9 @@ pub trait Arbitrage: StateWrite + Sized {
         // - both ends of all trading pairs for which there were swaps in the block
         // - both ends of all trading pairs for which positions were opened
         let params = RoutingParams {
-            max_hops: 5,
+            max_hops: routing_params.max_hops + 1,
             price_limit: Some(1u64.into()),
-            fixed_candidates: Arc::new(fixed_candidates),
+            fixed_candidates: routing_params.fixed_candidates,
         };
 
         // Create a flash-loan 2^64 of the arb token to ourselves.
@@ -1,6 +1,6 @@
 use std::{collections::BTreeMap, sync::Arc};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
 use penumbra_asset::{asset, STAKING_TOKEN_ASSET_ID};
 use penumbra_chain::component::StateReadExt as _;
@@ -11,13 +11,13 @@ use tendermint::v0_37::abci;
 use tracing::instrument;
 
 use crate::{
-    component::flow::SwapFlow, state_key, BatchSwapOutputData, DirectedTradingPair, SwapExecution,
-    TradingPair,
+    component::flow::SwapFlow, params::DexParameters, state_key, BatchSwapOutputData,
+    DirectedTradingPair, SwapExecution, TradingPair,
 };
 
 use super::{
     router::{HandleBatchSwaps, RoutingParams},
-    Arbitrage, PositionManager,
+    Arbitrage, LpRewardsManager, PositionManager,
 };
 
 pub struct Dex {}
@@ -43,6 +43,12 @@ impl Component for Dex {
     ) {
         let current_epoch = state.epoch().await.expect("epoch is set");
 
+        let dex_params = state
+            .get_dex_params()
+            .await
+            .expect("dex params are set at genesis");
+        let routing_params = RoutingParams::from(&dex_params);
+
         // For each batch swap during the block, calculate clearing prices and set in the JMT.
         for (trading_pair, swap_flows) in state.swap_flows() {
             let batch_start = std::time::Instant::now();
@@ -56,10 +62,9 @@ impl Component for Dex {
                         .expect("height is part of the end block data"),
                     current_epoch.start_height,
                     // Always include both ends of the target pair as fixed candidates.
-                    RoutingParams::default_with_extra_candidates([
-                        trading_pair.asset_1(),
-                        trading_pair.asset_2(),
-                    ]),
+                    routing_params
+                        .clone()
+                        .with_extra_candidates([trading_pair.asset_1(), trading_pair.asset_2()]),
                 )
                 .await
                 .expect("handling batch swaps is infaillible");
@@ -69,48 +74,27 @@ impl Component for Dex {
             );
         }
 
-        // Then, perform arbitrage:
-        let arb_burn = state
-            .arbitrage(
-                *STAKING_TOKEN_ASSET_ID,
-                vec![
+        // Then, perform arbitrage, if it is enabled:
+        if dex_params.arbitrage_enabled {
+            let arb_burn = state
+                .arbitrage(
                     *STAKING_TOKEN_ASSET_ID,
-                    asset::Cache::with_known_assets()
-                        .get_unit("gm")
-                        .expect("gm is a known asset")
-                        .id(),
-                    asset::Cache::with_known_assets()
-                        .get_unit("gn")
-                        .expect("gn is a known asset")
-                        .id(),
-                    asset::Cache::with_known_assets()
-                        .get_unit("test_usd")
-                        .expect("test_usd is a known asset")
-                        .id(),
-                    asset::Cache::with_known_assets()
-                        .get_unit("test_btc")
-                        .expect("test_btc is a known asset")
-                        .id(),
-                    asset::Cache::with_known_assets()
-                        .get_unit("test_atom")
-                        .expect("test_atom is a known asset")
-                        .id(),
-                    asset::Cache::with_known_assets()
-                        .get_unit("test_osmo")
-                        .expect("test_osmo is a known asset")
-                        .id(),
-                ],
-            )
-            .await
-            .expect("must be able to process arbitrage");
-
-        if arb_burn.amount != 0u64.into() {
-            // TODO: hack to avoid needing an asset cache for nice debug output
-            let unit = asset::Cache::with_known_assets()
-                .get_unit("penumbra")
-                .expect("penumbra is a known asset");
-            let burn = format!("{}{}", unit.format_value(arb_burn.amount), unit);
-            tracing::info!(%burn, "executed arbitrage opportunity");
+                    RoutingParams {
+                        fixed_candidates: Arc::new(dex_params.arbitrage_candidates),
+                        ..routing_params
+                    },
+                )
+                .await
+                .expect("must be able to process arbitrage");
+
+            if arb_burn.amount != 0u64.into() {
+                // TODO: hack to avoid needing an asset cache for nice debug output
+                let unit = asset::Cache::with_known_assets()
+                    .get_unit("penumbra")
+                    .expect("penumbra is a known asset");
+                let burn = format!("{}{}", unit.format_value(arb_burn.amount), unit);
+                tracing::info!(%burn, "executed arbitrage opportunity");
+            }
         }
 
         // Next, close all positions queued for closure at the end of the block.
@@ -121,15 +105,32 @@ impl Component for Dex {
             .await;
     }
 
-    #[instrument(name = "dex", skip(_state))]
-    async fn end_epoch<S: StateWrite + 'static>(mut _state: &mut Arc<S>) -> Result<()> {
-        Ok(())
+    #[instrument(name = "dex", skip(state))]
+    async fn end_epoch<S: StateWrite + 'static>(state: &mut Arc<S>) -> Result<()> {
+        let state = Arc::get_mut(state).context("state should be unique")?;
+        let dex_params = state.get_dex_params().await?;
+        state
+            .distribute_lp_rewards(dex_params.lp_rewards_per_epoch)
+            .await
     }
 }
 
 /// Extension trait providing read access to dex data.
 #[async_trait]
 pub trait StateReadExt: StateRead {
+    /// Gets the DEX parameters from the state.
+    async fn get_dex_params(&self) -> Result<DexParameters> {
+        self.get(state_key::dex_params())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Missing DexParameters"))
+    }
+
+    /// Indicates if the DEX parameters have been updated in this block.
+    fn dex_params_updated(&self) -> bool {
+        self.object_get::<()>(state_key::dex_params_updated())
+            .is_some()
+    }
+
     async fn output_data(
         &self,
         height: u64,
@@ -163,11 +164,18 @@ pub trait StateReadExt: StateRead {
     }
 }
 
-impl<T: StateRead> StateReadExt for T {}
+impl<T: StateRead + ?Sized> StateReadExt for T {}
 
 /// Extension trait providing write access to dex data.
 #[async_trait]
 pub trait StateWriteExt: StateWrite + StateReadExt {
+    /// Set the DEX parameters in the state.
+    fn put_dex_params(&mut self, params: DexParameters) {
+        // Note that the dex params have been updated:
+        self.object_put(state_key::dex_params_updated(), ());
+        self.put(state_key::dex_params().to_string(), params);
+    }
+
     fn set_output_data(
         &mut self,
         output_data: BatchSwapOutputData,
@@ -215,4 +223,4 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
     }
 }
 
-impl<T: StateWrite> StateWriteExt for T {}
+impl<T: StateWrite + ?Sized> StateWriteExt for T {}
@@ -2,22 +2,49 @@ use std::ops::{Deref, DerefMut};
 
 use penumbra_num::Amount;
 
-// Tuple represents:
-// ((amount of asset 1 being exchanged for asset 2),
-//  (amount of asset 2 being exchanged for asset 1))
 #[derive(Default, Clone)]
-pub struct SwapFlow((Amount, Amount));
+pub struct SwapFlow {
+    // Tuple represents:
+    // ((amount of asset 1 being exchanged for asset 2),
+    //  (amount of asset 2 being exchanged for asset 1))
+    flow: (Amount, Amount),
+    /// The `(input, min_output)` of each 1=>2 swap with a minimum output.
+    pub limited_1: Vec<(Amount, Amount)>,
+    /// The `(input, min_output)` of each 2=>1 swap with a minimum output.
+    pub limited_2: Vec<(Amount, Amount)>,
+}
+
+impl SwapFlow {
+    /// Records a swap of `(delta_1_i, delta_2_i)` that must output at least
+    /// `min_output`, so that it can be refunded if the batch can't honor it.
+    ///
+    /// Swaps with a minimum output only have input in one direction.
+    pub fn add_limited_swap(
+        &mut self,
+        (delta_1_i, delta_2_i): (Amount, Amount),
+        min_output: Amount,
+    ) {
+        if min_output == Amount::zero() {
+            return;
+        }
+        if delta_1_i == Amount::zero() {
+            self.limited_2.push((delta_2_i, min_output));
+        } else {
+            self.limited_1.push((delta_1_i, min_output));
+        }
+    }
+}
 
 impl Deref for SwapFlow {
     type Target = (Amount, Amount);
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.flow
     }
 }
 
 impl DerefMut for SwapFlow {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.flow
     }
 }
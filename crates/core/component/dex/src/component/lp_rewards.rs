@@ -0,0 +1,150 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::StreamExt;
+use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_storage::{StateRead, StateWrite};
+
+use crate::{
+    component::PositionRead,
+    lp::position::{self, Position},
+    state_key,
+};
+
+#[async_trait]
+pub trait LpRewardsRead: StateRead {
+    /// The fees earned by a position in the current epoch, valued in the staking token.
+    async fn position_fees(&self, id: &position::Id) -> Result<Amount> {
+        Ok(self
+            .get(&state_key::lp_rewards::fees(id))
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// The fees earned by all positions in the current epoch, valued in the staking token.
+    async fn total_position_fees(&self) -> Result<Amount> {
+        Ok(self
+            .get(state_key::lp_rewards::total_fees())
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// The liquidity incentives accrued to a position in previous epochs.
+    async fn claimable_rewards(&self, id: &position::Id) -> Result<Amount> {
+        Ok(self
+            .get(&state_key::lp_rewards::claimable(id))
+            .await?
+            .unwrap_or_default())
+    }
+}
+
+impl<T: StateRead + ?Sized> LpRewardsRead for T {}
+
+/// Tracks the fees earned by liquidity positions, and distributes the
+/// liquidity incentives issued each epoch among them.
+///
+/// Only the fees earned on the staking token side of a position are counted,
+/// so that positions on different pairs can be compared; positions on pairs
+/// without the staking token don't earn incentives.
+#[async_trait]
+pub trait LpRewardsManager: StateWrite + LpRewardsRead {
+    /// Credits a position with the fees it earned by being filled from `prev` to `new`.
+    async fn record_position_fees(&mut self, prev: &Position, new: &Position) -> Result<()> {
+        // Reserves only change as the result of fills while the position is open.
+        if prev.state != position::State::Opened {
+            return Ok(());
+        }
+        let (Some(prev_reserves), Some(new_reserves)) = (
+            prev.reserves_for(*STAKING_TOKEN_ASSET_ID),
+            new.reserves_for(*STAKING_TOKEN_ASSET_ID),
+        ) else {
+            return Ok(());
+        };
+
+        let volume = if new_reserves > prev_reserves {
+            new_reserves - prev_reserves
+        } else {
+            prev_reserves - new_reserves
+        };
+        let fees =
+            Amount::from(volume.value().saturating_mul(new.phi.component.fee.into()) / 10_000);
+        if fees == Amount::zero() {
+            return Ok(());
+        }
+
+        let id = new.id();
+        let position_fees = self.position_fees(&id).await?.saturating_add(&fees);
+        let total_fees = self.total_position_fees().await?.saturating_add(&fees);
+        tracing::debug!(?id, ?fees, ?position_fees, "recording position fees");
+        self.put(state_key::lp_rewards::fees(&id), position_fees);
+        self.put(state_key::lp_rewards::total_fees().to_string(), total_fees);
+        Ok(())
+    }
+
+    /// Splits `rewards` among the positions in proportion to the fees they
+    /// earned this epoch, then resets the fees for the next epoch.
+    ///
+    /// Positions which already claimed their rewards can't claim any more, so
+    /// they're left out of the split. Each position's rewards are capped at the
+    /// fees it earned, so that trading against one's own position can't earn
+    /// more in rewards than the fees paid into it; the rest is not issued.
+    async fn distribute_lp_rewards(&mut self, rewards: Amount) -> Result<()> {
+        let position_fees = self
+            .prefix(state_key::lp_rewards::fees_prefix())
+            .map(|entry: Result<(String, Amount)>| {
+                let (key, fees) = entry?;
+                let id = key
+                    .strip_prefix(state_key::lp_rewards::fees_prefix())
+                    .ok_or_else(|| anyhow::anyhow!("unexpected position fees key {key}"))?
+                    .parse::<position::Id>()?;
+                Ok((id, fees))
+            })
+            .collect::<Vec<Result<_>>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+        self.delete(state_key::lp_rewards::total_fees().to_string());
+
+        let mut eligible = Vec::with_capacity(position_fees.len());
+        for (id, fees) in position_fees {
+            self.delete(state_key::lp_rewards::fees(&id));
+            let claimed = self
+                .position_by_id(&id)
+                .await?
+                .map_or(true, |position| position.state == position::State::Claimed);
+            if !claimed {
+                eligible.push((id, fees));
+            }
+        }
+
+        let total_fees = eligible.iter().fold(Amount::zero(), |total, (_, fees)| {
+            total.saturating_add(fees)
+        });
+        if rewards == Amount::zero() || total_fees == Amount::zero() {
+            return Ok(());
+        }
+
+        for (id, fees) in eligible {
+            let reward: Amount = U128x128::ratio(fees, total_fees)?
+                .checked_mul(&rewards.into())?
+                .round_down()
+                .try_into()?;
+            let reward = reward.min(fees);
+            let claimable = self.claimable_rewards(&id).await?.saturating_add(&reward);
+            tracing::debug!(?id, ?fees, ?reward, ?claimable, "distributing lp rewards");
+            self.put(state_key::lp_rewards::claimable(&id), claimable);
+        }
+
+        Ok(())
+    }
+
+    /// Removes the claimable rewards of a position, returning them.
+    async fn take_claimable_rewards(&mut self, id: &position::Id) -> Result<Amount> {
+        let claimable = self.claimable_rewards(id).await?;
+        self.delete(state_key::lp_rewards::claimable(id));
+        Ok(claimable)
+    }
+}
+
+impl<T: StateWrite + ?Sized> LpRewardsManager for T {}
@@ -10,12 +10,14 @@ mod action_handler;
 mod arb;
 mod dex;
 mod flow;
+mod lp_rewards;
 mod position_manager;
 mod swap_manager;
 
 pub use self::metrics::register_metrics;
 pub use arb::Arbitrage;
 pub use dex::{Dex, StateReadExt, StateWriteExt};
+pub use lp_rewards::{LpRewardsManager, LpRewardsRead};
 pub use position_manager::{PositionManager, PositionRead};
 pub use swap_manager::SwapManager;
 
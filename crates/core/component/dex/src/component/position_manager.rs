@@ -14,6 +14,7 @@ use penumbra_storage::{EscapedByteSlice, StateRead, StateWrite};
 
 use crate::lp::position::State;
 use crate::{
+    component::LpRewardsManager,
     lp::position::{self, Position},
     state_key, DirectedTradingPair,
 };
@@ -154,10 +155,29 @@ pub trait PositionManager: StateWrite + PositionRead {
         // Update the available liquidity for this position's trading pair.
         self.update_available_liquidity(&position, &prev).await?;
 
+        // Credit the position with any fees it earned, for liquidity incentives.
+        if let Some(prev) = &prev {
+            self.record_position_fees(prev, &position).await?;
+        }
+
         self.put(state_key::position_by_id(&id), position);
         Ok(())
     }
 
+    /// Rebuilds the price and liquidity indexes of the open positions, after the
+    /// verifiable state was restored from a snapshot without them.
+    async fn reindex_positions(&mut self) -> Result<()> {
+        let mut positions = self.all_positions();
+        while let Some(position) = positions.next().await {
+            let position = position?;
+            if position.state == position::State::Opened {
+                self.index_position_by_price(&position);
+                self.update_available_liquidity(&position, &None).await?;
+            }
+        }
+        Ok(())
+    }
+
     /// Handle a limit order, inspecting it previous state to determine if it
     /// has been filled, and if so, marking it as closed. If the position is
     /// not a limit order, or has not been filled, it is returned unchanged.
@@ -3,6 +3,8 @@ use std::sync::Arc;
 use penumbra_asset::asset;
 use penumbra_num::fixpoint::U128x128;
 
+use crate::DexParameters;
+
 #[derive(Debug, Clone)]
 pub struct RoutingParams {
     pub price_limit: Option<U128x128>,
@@ -12,53 +14,25 @@ pub struct RoutingParams {
 
 impl Default for RoutingParams {
     fn default() -> Self {
+        Self::from(&DexParameters::default())
+    }
+}
+
+impl From<&DexParameters> for RoutingParams {
+    fn from(params: &DexParameters) -> Self {
         Self {
             price_limit: None,
-            fixed_candidates: Arc::new(vec![
-                asset::Cache::with_known_assets()
-                    .get_unit("test_usd")
-                    .expect("hardcoded \"test_usd\" denom should be known")
-                    .id(),
-                asset::Cache::with_known_assets()
-                    .get_unit("penumbra")
-                    .expect("hardcoded \"penumbra\" denom should be known")
-                    .id(),
-                asset::Cache::with_known_assets()
-                    .get_unit("gm")
-                    .expect("hardcoded \"gm\" denom should be known")
-                    .id(),
-                asset::Cache::with_known_assets()
-                    .get_unit("gn")
-                    .expect("hardcoded \"gn\" denom should be known")
-                    .id(),
-                asset::Cache::with_known_assets()
-                    .get_unit("test_atom")
-                    .expect("hardcoded \"test_atom\" denom should be known")
-                    .id(),
-                asset::Cache::with_known_assets()
-                    .get_unit("test_osmo")
-                    .expect("hardcoded \"test_osmo\" denom should be known")
-                    .id(),
-                asset::Cache::with_known_assets()
-                    .get_unit("test_btc")
-                    .expect("hardcoded \"test_btc\" denom should be known")
-                    .id(),
-            ]),
-            max_hops: 4,
+            fixed_candidates: Arc::new(params.fixed_candidates.clone()),
+            max_hops: params.max_hops as usize,
         }
     }
 }
 
 impl RoutingParams {
-    /// Like `Default::default()`, but extends the default fixed candidates with the given list.
-    ///
-    /// If you want to _set_ the fixed candidates, just use `..Default::default()`.
-    pub fn default_with_extra_candidates(iter: impl IntoIterator<Item = asset::Id>) -> Self {
-        let mut params = Self::default();
-        Arc::get_mut(&mut params.fixed_candidates)
-            .expect("just created unique ref")
-            .extend(iter);
-        params
+    /// Extends the fixed candidates with the given list.
+    pub fn with_extra_candidates(mut self, iter: impl IntoIterator<Item = asset::Id>) -> Self {
+        Arc::make_mut(&mut self.fixed_candidates).extend(iter);
+        self
     }
 
     /// Clamps the spill price to the price limit and returns whether or not it was clamped.
@@ -3,8 +3,8 @@ use std::sync::Arc;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use penumbra_asset::{asset, Value};
-use penumbra_num::Amount;
-use penumbra_storage::StateWrite;
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_storage::{StateDelta, StateWrite};
 use tracing::instrument;
 
 use crate::{
@@ -47,6 +47,16 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
 
         tracing::debug!(?delta_1, ?delta_2, ?trading_pair, "decrypted batch swaps");
 
+        let (delta_1, limit_price_1) = self
+            .refund_limited_swaps(
+                trading_pair.asset_1(),
+                trading_pair.asset_2(),
+                delta_1,
+                &batch_data.limited_1,
+                params.clone(),
+            )
+            .await?;
+
         let swap_execution_1_for_2 = if delta_1.value() > 0 {
             Some(
                 self.route_and_fill(
@@ -62,6 +72,16 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
             None
         };
 
+        let (delta_2, limit_price_2) = self
+            .refund_limited_swaps(
+                trading_pair.asset_2(),
+                trading_pair.asset_1(),
+                delta_2,
+                &batch_data.limited_2,
+                params.clone(),
+            )
+            .await?;
+
         let swap_execution_2_for_1 = if delta_2.value() > 0 {
             Some(
                 self.route_and_fill(
@@ -101,6 +121,8 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
             lambda_2,
             unfilled_1,
             unfilled_2,
+            limit_price_1,
+            limit_price_2,
         };
 
         // Fetch the swap execution object that should have been modified during the routing and filling.
@@ -115,6 +137,53 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
 
         Ok(())
     }
+
+    /// Decides which of the swaps of `asset_1` for `asset_2` with a minimum
+    /// output should be refunded, returning the input left to route and the
+    /// limit price recorded in the batch swap output data.
+    ///
+    /// The limit price is the price the whole batch would get, found by
+    /// routing `input` on a fork of the state. Swaps demanding a higher price
+    /// are refunded; since a smaller input can only get a better price, the
+    /// remaining swaps are still filled at or above the limit price.
+    async fn refund_limited_swaps(
+        self: &mut Arc<Self>,
+        asset_1: asset::Id,
+        asset_2: asset::Id,
+        input: Amount,
+        limited_swaps: &[(Amount, Amount)],
+        params: RoutingParams,
+    ) -> Result<(Amount, U128x128)>
+    where
+        Self: 'static,
+    {
+        if limited_swaps.is_empty() || input == Amount::zero() {
+            return Ok((input, U128x128::default()));
+        }
+
+        let simulated_execution = {
+            let mut fork = Arc::new(StateDelta::new(self.clone()));
+            fork.route_and_fill(asset_1, asset_2, input, params).await?
+        };
+        let limit_price = U128x128::ratio(simulated_execution.output.amount, input)?;
+
+        let mut remaining_input = input;
+        for (swap_input, min_output) in limited_swaps {
+            if U128x128::ratio(*min_output, *swap_input)? > limit_price {
+                remaining_input = remaining_input
+                    .checked_sub(swap_input)
+                    .expect("refunded input is part of the batch input");
+            }
+        }
+        tracing::debug!(
+            ?input,
+            ?remaining_input,
+            ?limit_price,
+            "refunded swaps with unmet minimum outputs"
+        );
+
+        Ok((remaining_input, limit_price))
+    }
 }
 
 impl<T: PositionManager> HandleBatchSwaps for T {}
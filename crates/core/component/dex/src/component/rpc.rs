@@ -2,7 +2,7 @@ use std::{pin::Pin, sync::Arc};
 
 use async_stream::try_stream;
 use futures::{StreamExt, TryStreamExt};
-use penumbra_asset::{asset, Value};
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_chain::component::StateReadExt as _;
 use penumbra_proto::{
     core::component::dex::v1alpha1::{
@@ -11,6 +11,7 @@ use penumbra_proto::{
         simulation_service_server::SimulationService, ArbExecutionRequest, ArbExecutionResponse,
         ArbExecutionsRequest, ArbExecutionsResponse, BatchSwapOutputDataRequest,
         BatchSwapOutputDataResponse, LiquidityPositionByIdRequest, LiquidityPositionByIdResponse,
+        LiquidityPositionRewardsRequest, LiquidityPositionRewardsResponse,
         LiquidityPositionsByIdRequest, LiquidityPositionsByIdResponse,
         LiquidityPositionsByPriceRequest, LiquidityPositionsByPriceResponse,
         LiquidityPositionsRequest, LiquidityPositionsResponse, SimulateTradeRequest,
@@ -25,7 +26,7 @@ use tracing::instrument;
 
 use super::{
     router::{RouteAndFill, RoutingParams},
-    PositionRead, StateReadExt,
+    LpRewardsRead, PositionRead, StateReadExt,
 };
 use crate::{
     lp::position::{self, Position},
@@ -456,6 +457,44 @@ impl QueryService for Server {
         }))
     }
 
+    #[instrument(skip(self, request))]
+    async fn liquidity_position_rewards(
+        &self,
+        request: tonic::Request<LiquidityPositionRewardsRequest>,
+    ) -> Result<tonic::Response<LiquidityPositionRewardsResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let position_id: position::Id = request
+            .into_inner()
+            .position_id
+            .ok_or_else(|| Status::invalid_argument("empty message"))?
+            .try_into()
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::invalid_argument(format!("error converting position_id: {e}"))
+            })?;
+
+        let rewards = state
+            .claimable_rewards(&position_id)
+            .await
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!("error fetching rewards from storage: {e}"))
+            })?;
+
+        Ok(tonic::Response::new(LiquidityPositionRewardsResponse {
+            rewards: Some(
+                Value {
+                    amount: rewards,
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                }
+                .into(),
+            ),
+        }))
+    }
+
     #[instrument(skip(self, request))]
     async fn liquidity_positions_by_id(
         &self,
@@ -539,15 +578,20 @@ impl SimulationService for Server {
                 tonic::Status::invalid_argument(format!("error parsing output id: {:#}", e))
             })?;
 
+        let state = self.storage.latest_snapshot();
+        let dex_params = state
+            .get_dex_params()
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error getting dex params: {:#}", e)))?;
+
         let routing_params = match routing_strategy {
-            Setting::Default(_) => RoutingParams::default(),
+            Setting::Default(_) => RoutingParams::from(&dex_params),
             Setting::SingleHop(_) => RoutingParams {
                 max_hops: 1,
-                ..RoutingParams::default()
+                ..RoutingParams::from(&dex_params)
             },
         };
 
-        let state = self.storage.latest_snapshot();
         let mut state_tx = Arc::new(StateDelta::new(state));
         let swap_execution = state_tx
             .route_and_fill(input.asset_id, output_id, input.amount, routing_params)
@@ -1,24 +1,29 @@
 use std::sync::Arc;
 
 use anyhow::Ok;
+use ark_ff::Zero;
 use async_trait::async_trait;
+use decaf377::Fr;
 use futures::StreamExt;
-use penumbra_asset::{asset, Value};
-use penumbra_num::Amount;
-use penumbra_storage::{ArcStateDeltaExt, StateDelta, TempStorage};
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_component::ActionHandler;
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_shielded_pool::component::SupplyRead;
+use penumbra_storage::{ArcStateDeltaExt, StateDelta, StateWrite, TempStorage};
 use rand_core::OsRng;
 
 //use crate::TempStorageExt;
 
-use crate::lp::action::PositionOpen;
+use crate::lp::action::{PositionOpen, PositionRewardClaim};
 use crate::{
     component::{
         router::FillRoute,
         router::{limit_buy, limit_sell, HandleBatchSwaps, RoutingParams},
-        Arbitrage, PositionManager, PositionRead, StateReadExt, StateWriteExt,
+        Arbitrage, LpRewardsManager, LpRewardsRead, PositionManager, PositionRead, StateReadExt,
+        StateWriteExt,
     },
     lp::{position::Position, Reserves},
-    BatchSwapOutputData, DirectedTradingPair, DirectedUnitPair,
+    BatchSwapOutputData, DirectedTradingPair, DirectedUnitPair, TradingPair,
 };
 
 // TODO: what's the right way to mock genesis? if component A needs component B,
@@ -712,6 +717,8 @@ async fn swap_execution_tests() -> anyhow::Result<()> {
             unfilled_2: 0u32.into(),
             height: 0,
             epoch_starting_height: 0,
+            limit_price_1: U128x128::default(),
+            limit_price_2: U128x128::default(),
             trading_pair,
         }
     );
@@ -796,7 +803,13 @@ async fn basic_cycle_arb() -> anyhow::Result<()> {
 
     // Now we should be able to arb 10penumbra => 10gn => 20gm => 20penumbra.
     state
-        .arbitrage(penumbra.id(), vec![penumbra.id(), gm.id(), gn.id()])
+        .arbitrage(
+            penumbra.id(),
+            RoutingParams {
+                fixed_candidates: Arc::new(vec![penumbra.id(), gm.id(), gn.id()]),
+                ..RoutingParams::default()
+            },
+        )
         .await?;
 
     let arb_execution = state.arb_execution(0).await?.expect("arb was performed");
@@ -887,7 +900,13 @@ async fn reproduce_arbitrage_loop_testnet_53() -> anyhow::Result<()> {
 
     let arb_profit = tokio::time::timeout(
         tokio::time::Duration::from_secs(2),
-        state.arbitrage(penumbra.id(), vec![penumbra.id(), test_usd.id()]),
+        state.arbitrage(
+            penumbra.id(),
+            RoutingParams {
+                fixed_candidates: Arc::new(vec![penumbra.id(), test_usd.id()]),
+                ..RoutingParams::default()
+            },
+        ),
     )
     .await??;
 
@@ -901,3 +920,313 @@ async fn reproduce_arbitrage_loop_testnet_53() -> anyhow::Result<()> {
     tracing::info!(?arb_execution, "fetched arb execution!");
     Ok(())
 }
+
+/// Fills a position by moving `amount` from its first reserve to its second.
+fn fill_position(mut position: Position, amount: u64) -> Position {
+    position.reserves = Reserves {
+        r1: position.reserves.r1 - amount.into(),
+        r2: position.reserves.r2 + amount.into(),
+    };
+    position
+}
+
+/// Opens two positions charging a 1% fee on the penumbra/gm pair, and fills
+/// them so that they earn 100 and 300 units of fees.
+async fn lp_rewards_positions<S: StateWrite>(
+    state: &mut S,
+) -> anyhow::Result<(Position, Position)> {
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let pair = DirectedTradingPair::new(penumbra.id(), gm.id());
+
+    let reserves = Reserves {
+        r1: 100_000u64.into(),
+        r2: 100_000u64.into(),
+    };
+    let position_1 = Position::new(OsRng, pair, 100, 1u64.into(), 1u64.into(), reserves.clone());
+    let position_2 = Position::new(OsRng, pair, 100, 1u64.into(), 1u64.into(), reserves.clone());
+    state.put_position(position_1.clone()).await?;
+    state.put_position(position_2.clone()).await?;
+
+    state
+        .put_position(fill_position(position_1.clone(), 10_000))
+        .await?;
+    state
+        .put_position(fill_position(position_2.clone(), 30_000))
+        .await?;
+
+    assert_eq!(state.position_fees(&position_1.id()).await?, 100u64.into());
+    assert_eq!(state.position_fees(&position_2.id()).await?, 300u64.into());
+    assert_eq!(state.total_position_fees().await?, 400u64.into());
+
+    Ok((position_1, position_2))
+}
+
+#[tokio::test]
+/// Liquidity incentives are split among positions in proportion to the fees
+/// they earned on the staking token side of their fills.
+async fn lp_rewards_follow_position_fees() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let (position_1, position_2) = lp_rewards_positions(&mut state_tx).await?;
+
+    state_tx.distribute_lp_rewards(200u64.into()).await?;
+    state_tx.apply();
+
+    // The fees are reset for the next epoch.
+    assert_eq!(state.total_position_fees().await?, Amount::zero());
+    assert_eq!(state.position_fees(&position_1.id()).await?, Amount::zero());
+
+    assert_eq!(
+        state.claimable_rewards(&position_1.id()).await?,
+        50u64.into()
+    );
+    assert_eq!(
+        state.claimable_rewards(&position_2.id()).await?,
+        150u64.into()
+    );
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    assert_eq!(
+        state_tx.take_claimable_rewards(&position_1.id()).await?,
+        50u64.into()
+    );
+    assert_eq!(
+        state_tx.claimable_rewards(&position_1.id()).await?,
+        Amount::zero()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Positions which claimed their rewards mid-epoch don't dilute the rewards of
+/// the others, and rewards are capped at the fees a position earned.
+async fn lp_rewards_skip_claimed_positions_and_are_capped_at_fees() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let (position_1, position_2) = lp_rewards_positions(&mut state_tx).await?;
+
+    // The second position is withdrawn and claimed before the end of the epoch.
+    let mut claimed = state_tx
+        .position_by_id(&position_2.id())
+        .await?
+        .expect("position exists");
+    claimed.state = crate::lp::position::State::Claimed;
+    state_tx.put_position(claimed).await?;
+
+    // The first position gets all of the rewards, up to the fees it earned.
+    state_tx.distribute_lp_rewards(60u64.into()).await?;
+    assert_eq!(
+        state_tx.claimable_rewards(&position_1.id()).await?,
+        60u64.into()
+    );
+    assert_eq!(
+        state_tx.claimable_rewards(&position_2.id()).await?,
+        Amount::zero()
+    );
+
+    // Rewards beyond the fees earned are not issued.
+    state_tx
+        .put_position(fill_position(
+            state_tx
+                .position_by_id(&position_1.id())
+                .await?
+                .expect("position exists"),
+            10_000,
+        ))
+        .await?;
+    state_tx.distribute_lp_rewards(1_000u64.into()).await?;
+    state_tx.apply();
+
+    assert_eq!(
+        state.claimable_rewards(&position_1.id()).await?,
+        160u64.into()
+    );
+    assert_eq!(
+        state.claimable_rewards(&position_2.id()).await?,
+        Amount::zero()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+/// A reward claim must commit to exactly the rewards the position can claim.
+async fn reward_claim_rejects_mismatched_commitment() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let (position, _) = lp_rewards_positions(&mut state_tx).await?;
+    state_tx.distribute_lp_rewards(40u64.into()).await?;
+    state_tx.apply();
+    assert_eq!(state.claimable_rewards(&position.id()).await?, 10u64.into());
+
+    let claim = |amount: u64| PositionRewardClaim {
+        position_id: position.id(),
+        rewards_commitment: Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+        .commit(Fr::zero()),
+    };
+    claim(10).check_stateful(state.clone()).await?;
+    assert!(claim(9).check_stateful(state.clone()).await.is_err());
+    assert!(claim(11).check_stateful(state.clone()).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Claiming rewards marks the position as claimed and issues the rewards, so
+/// that they can only be claimed once.
+async fn reward_claim_issues_rewards_once() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let (position, _) = lp_rewards_positions(&mut state_tx).await?;
+    state_tx.distribute_lp_rewards(40u64.into()).await?;
+    let mut withdrawn = state_tx
+        .position_by_id(&position.id())
+        .await?
+        .expect("position exists");
+    withdrawn.state = crate::lp::position::State::Withdrawn;
+    state_tx.put_position(withdrawn).await?;
+    state_tx.apply();
+
+    let supply_before = state
+        .token_supply(&STAKING_TOKEN_ASSET_ID)
+        .await?
+        .unwrap_or_default();
+    let claim = PositionRewardClaim {
+        position_id: position.id(),
+        rewards_commitment: Value {
+            amount: 10u64.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+        .commit(Fr::zero()),
+    };
+    claim.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    claim.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    assert_eq!(
+        state
+            .position_by_id(&position.id())
+            .await?
+            .expect("position exists")
+            .state,
+        crate::lp::position::State::Claimed
+    );
+    assert_eq!(
+        state.claimable_rewards(&position.id()).await?,
+        Amount::zero()
+    );
+    assert_eq!(
+        state.token_supply(&STAKING_TOKEN_ASSET_ID).await?,
+        Some(supply_before + 10u64.into())
+    );
+
+    // The position can't claim again, and no more rewards are issued.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    assert!(claim.execute(&mut state_tx).await.is_err());
+    drop(state_tx);
+    assert_eq!(
+        state.token_supply(&STAKING_TOKEN_ASSET_ID).await?,
+        Some(supply_before + 10u64.into())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Swaps whose minimum output the batch can't honor are refunded, and excluded
+/// from the batch input.
+async fn swap_below_min_output_is_refunded() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+
+    let pair_gn_penumbra = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+
+    // Sell 10 gn at 1 penumbra each.
+    let sell = limit_sell(pair_gn_penumbra.clone(), 10u64.into(), 1u64.into());
+    state_tx.put_position(sell).await.unwrap();
+    state_tx.apply();
+
+    let trading_pair: TradingPair = pair_gn_penumbra.into_directed_trading_pair().into();
+    assert!(trading_pair.asset_1() == penumbra.id());
+
+    let mut swap_flow = state.swap_flow(&trading_pair);
+
+    // A swap of 5penumbra without a minimum output.
+    swap_flow.0 += penumbra.value(5u32.into()).amount;
+    // A swap of 10penumbra for at least 10gn, which the batch can't honor:
+    // the 20penumbra of the whole batch would only get 10gn.
+    let refunded = (penumbra.value(10u32.into()).amount, Amount::zero());
+    let refunded_min_output = gn.value(10u32.into()).amount;
+    swap_flow.0 += refunded.0;
+    swap_flow.add_limited_swap(refunded, refunded_min_output);
+    // A swap of 5penumbra for at least 2gn, which the batch can honor.
+    let honored = (penumbra.value(5u32.into()).amount, Amount::zero());
+    let honored_min_output = gn.value(2u32.into()).amount;
+    swap_flow.0 += honored.0;
+    swap_flow.add_limited_swap(honored, honored_min_output);
+
+    Arc::get_mut(&mut state)
+        .unwrap()
+        .put_swap_flow(&trading_pair, swap_flow.clone());
+    state
+        .handle_batch_swaps(
+            trading_pair,
+            swap_flow,
+            0u32.into(),
+            0,
+            RoutingParams::default(),
+        )
+        .await
+        .expect("unable to process batch swaps");
+
+    let output_data = state.output_data(0, trading_pair).await?.unwrap();
+
+    // The refunded input isn't part of the batch.
+    assert_eq!(output_data.delta_1, penumbra.value(10u32.into()).amount);
+    assert_eq!(output_data.lambda_2, gn.value(10u32.into()).amount);
+    assert_eq!(output_data.unfilled_1, Amount::zero());
+    assert_eq!(
+        output_data.limit_price_1,
+        U128x128::ratio(1u64, 2u64).unwrap()
+    );
+
+    assert!(output_data.is_refunded(refunded, refunded_min_output));
+    assert_eq!(
+        output_data.swap_outputs(refunded, refunded_min_output),
+        refunded
+    );
+    assert!(!output_data.is_refunded(honored, honored_min_output));
+    assert_eq!(
+        output_data.swap_outputs(honored, honored_min_output),
+        (Amount::zero(), gn.value(5u32.into()).amount)
+    );
+
+    Ok(())
+}
@@ -1,8 +1,9 @@
+use penumbra_num::Amount;
 use tendermint::abci::{Event, EventAttributeIndexExt};
 
 use crate::{
     lp::{
-        action::{PositionClose, PositionOpen, PositionWithdraw},
+        action::{PositionClose, PositionOpen, PositionRewardClaim, PositionWithdraw},
         position::Position,
     },
     swap::Swap,
@@ -82,3 +83,14 @@ pub fn position_withdraw(action: &PositionWithdraw, final_position_state: &Posit
         ],
     )
 }
+
+pub fn position_reward_claim(action: &PositionRewardClaim, rewards: Amount) -> Event {
+    Event::new(
+        "action_position_reward_claim",
+        [
+            ("position_id", action.position_id.to_string()).index(),
+            // rewards are only committed to in the action so need to be passed in separately
+            ("rewards", rewards.to_string()).index(),
+        ],
+    )
+}
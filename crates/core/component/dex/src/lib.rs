@@ -5,6 +5,8 @@
 #[cfg(feature = "component")]
 pub mod component;
 pub mod event;
+pub mod genesis;
+pub mod params;
 pub mod state_key;
 
 mod batch_swap_output_data;
@@ -12,6 +14,7 @@ mod swap_execution;
 mod trading_pair;
 
 pub use batch_swap_output_data::BatchSwapOutputData;
+pub use params::DexParameters;
 pub use swap_execution::SwapExecution;
 pub use trading_pair::{DirectedTradingPair, DirectedUnitPair, TradingPair, TradingPairVar};
 
@@ -0,0 +1,114 @@
+use penumbra_asset::{asset, STAKING_TOKEN_ASSET_ID};
+use penumbra_num::Amount;
+use penumbra_proto::core::component::dex::v1alpha1 as pb;
+use penumbra_proto::DomainType;
+use serde::{Deserialize, Serialize};
+
+/// The largest value governance may set [`DexParameters::max_hops`] to.
+///
+/// Routing explores every path of up to `max_hops` hops through the candidate
+/// assets in `end_block`, so its cost grows quickly with the number of hops.
+pub const MAX_HOPS_LIMIT: u32 = 8;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::DexParameters", into = "pb::DexParameters")]
+pub struct DexParameters {
+    /// The assets always considered as intermediate hops when routing trades.
+    pub fixed_candidates: Vec<asset::Id>,
+    /// The maximum number of hops in a route found by the router, at most [`MAX_HOPS_LIMIT`].
+    pub max_hops: u32,
+    /// Whether arbitrage is executed at the end of each block.
+    pub arbitrage_enabled: bool,
+    /// The assets considered as intermediate hops when searching for arbitrage.
+    pub arbitrage_candidates: Vec<asset::Id>,
+    /// The amount of the staking token issued each epoch as liquidity incentives, at most the
+    /// staking issuance per epoch.
+    pub lp_rewards_per_epoch: Amount,
+}
+
+impl DomainType for DexParameters {
+    type Proto = pb::DexParameters;
+}
+
+impl TryFrom<pb::DexParameters> for DexParameters {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::DexParameters) -> anyhow::Result<Self> {
+        Ok(DexParameters {
+            fixed_candidates: msg
+                .fixed_candidates
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            max_hops: msg.max_hops,
+            arbitrage_enabled: msg.arbitrage_enabled,
+            arbitrage_candidates: msg
+                .arbitrage_candidates
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            lp_rewards_per_epoch: msg
+                .lp_rewards_per_epoch
+                .ok_or_else(|| anyhow::anyhow!("missing lp_rewards_per_epoch"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<DexParameters> for pb::DexParameters {
+    fn from(params: DexParameters) -> Self {
+        pb::DexParameters {
+            fixed_candidates: params
+                .fixed_candidates
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            max_hops: params.max_hops,
+            arbitrage_enabled: params.arbitrage_enabled,
+            arbitrage_candidates: params
+                .arbitrage_candidates
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            lp_rewards_per_epoch: Some(params.lp_rewards_per_epoch.into()),
+        }
+    }
+}
+
+/// Looks up the asset ID of a denom known to the default asset cache.
+fn known_asset(denom: &str) -> asset::Id {
+    asset::Cache::with_known_assets()
+        .get_unit(denom)
+        .unwrap_or_else(|| panic!("hardcoded {denom:?} denom should be known"))
+        .id()
+}
+
+// The defaults route through the testnet assets; other deployments should
+// set their own candidates in genesis or with a parameter change proposal.
+impl Default for DexParameters {
+    fn default() -> Self {
+        Self {
+            fixed_candidates: vec![
+                known_asset("test_usd"),
+                known_asset("penumbra"),
+                known_asset("gm"),
+                known_asset("gn"),
+                known_asset("test_atom"),
+                known_asset("test_osmo"),
+                known_asset("test_btc"),
+            ],
+            max_hops: 4,
+            arbitrage_enabled: true,
+            arbitrage_candidates: vec![
+                *STAKING_TOKEN_ASSET_ID,
+                known_asset("gm"),
+                known_asset("gn"),
+                known_asset("test_usd"),
+                known_asset("test_btc"),
+                known_asset("test_atom"),
+                known_asset("test_osmo"),
+            ],
+            lp_rewards_per_epoch: Amount::zero(),
+        }
+    }
+}
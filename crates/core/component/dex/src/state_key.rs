@@ -2,6 +2,14 @@ use std::string::String;
 
 use crate::{lp::position, DirectedTradingPair, TradingPair};
 
+pub fn dex_params() -> &'static str {
+    "dex/params"
+}
+
+pub fn dex_params_updated() -> &'static str {
+    "dex/params_updated"
+}
+
 pub fn positions(trading_pair: &TradingPair, position_id: &str) -> String {
     format!("dex/positions/{trading_pair}/opened/{position_id}")
 }
@@ -59,6 +67,29 @@ pub fn pending_outputs() -> &'static str {
     "dex/pending_outputs"
 }
 
+pub mod lp_rewards {
+    use crate::lp::position;
+
+    /// The fees earned by a position in the current epoch, valued in the staking token.
+    pub fn fees(id: &position::Id) -> String {
+        format!("{}{id}", fees_prefix())
+    }
+
+    pub fn fees_prefix() -> &'static str {
+        "dex/lp_rewards/fees/"
+    }
+
+    /// The fees earned by all positions in the current epoch, valued in the staking token.
+    pub fn total_fees() -> &'static str {
+        "dex/lp_rewards/total_fees"
+    }
+
+    /// The rewards a position can claim once it has been withdrawn.
+    pub fn claimable(id: &position::Id) -> String {
+        format!("dex/lp_rewards/claimable/{id}")
+    }
+}
+
 /// Encompasses non-consensus state keys.
 pub(crate) mod internal {
     use super::*;
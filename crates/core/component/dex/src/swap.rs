@@ -19,9 +19,9 @@ pub use plan::SwapPlan;
 pub use view::SwapView;
 
 // Swap ciphertext byte length.
-pub const SWAP_CIPHERTEXT_BYTES: usize = 272;
+pub const SWAP_CIPHERTEXT_BYTES: usize = 288;
 // Swap plaintext byte length.
-pub const SWAP_LEN_BYTES: usize = 256;
+pub const SWAP_LEN_BYTES: usize = 272;
 
 pub static DOMAIN_SEPARATOR: Lazy<Fq> =
     Lazy::new(|| Fq::from_le_bytes_mod_order(blake2b_simd::blake2b(b"penumbra.swap").as_bytes()));
@@ -79,6 +79,8 @@ pub struct Body {
     pub delta_2_i: Amount,
     pub fee_commitment: balance::Commitment,
     pub payload: SwapPayload,
+    /// The minimum output of the swap, below which the input is refunded.
+    pub min_output: Amount,
 }
 
 impl DomainType for Body {
@@ -93,6 +95,7 @@ impl From<Body> for pb::SwapBody {
             delta_2_i: Some(s.delta_2_i.into()),
             fee_commitment: Some(s.fee_commitment.into()),
             payload: Some(s.payload.into()),
+            min_output: Some(s.min_output.into()),
         }
     }
 }
@@ -123,6 +126,11 @@ impl TryFrom<pb::SwapBody> for Body {
                 .payload
                 .ok_or_else(|| anyhow::anyhow!("missing payload"))?
                 .try_into()?,
+            min_output: s
+                .min_output
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
         })
     }
 }
@@ -11,7 +11,7 @@ use penumbra_proto::{
     core::keys::v1alpha1 as pb_keys, penumbra::core::component::dex::v1alpha1 as pb, DomainType,
 };
 use penumbra_tct::StateCommitment;
-use poseidon377::{hash_1, hash_4, hash_7};
+use poseidon377::{hash_1, hash_5, hash_7};
 use rand_core::{CryptoRng, RngCore};
 
 use decaf377_ka as ka;
@@ -39,6 +39,8 @@ pub struct SwapPlaintext {
     pub claim_address: Address,
     // Swap rseed
     pub rseed: Rseed,
+    // Minimum output of the other asset, below which the input is refunded
+    pub min_output: Amount,
 }
 
 pub static OUTPUT_1_BLINDING_DOMAIN_SEPARATOR: Lazy<Fq> = Lazy::new(|| {
@@ -67,7 +69,7 @@ impl SwapPlaintext {
         let (output_1_rseed, output_2_rseed) = self.output_rseeds();
 
         let (lambda_1_i, lambda_2_i) =
-            batch_data.pro_rata_outputs((self.delta_1_i, self.delta_2_i));
+            batch_data.swap_outputs((self.delta_1_i, self.delta_2_i), self.min_output);
 
         let output_1_note = Note::from_parts(
             self.claim_address,
@@ -107,13 +109,14 @@ impl SwapPlaintext {
                     .vartime_compress_to_field(),
                 *self.claim_address.transmission_key_s(),
                 Fq::from_le_bytes_mod_order(&self.claim_address.clue_key().0[..]),
-                hash_4(
+                hash_5(
                     &DOMAIN_SEPARATOR,
                     (
                         self.trading_pair.asset_1().0,
                         self.trading_pair.asset_2().0,
                         self.delta_1_i.into(),
                         self.delta_2_i.into(),
+                        self.min_output.into(),
                     ),
                 ),
             ),
@@ -163,8 +166,16 @@ impl SwapPlaintext {
             claim_fee,
             claim_address,
             rseed,
+            min_output: Amount::zero(),
         }
     }
+
+    /// Requires the swap to output at least `min_output` of the other asset,
+    /// refunding its input otherwise.
+    pub fn with_min_output(mut self, min_output: Amount) -> Self {
+        self.min_output = min_output;
+        self
+    }
 }
 
 pub struct SwapPlaintextVar {
@@ -174,6 +185,7 @@ pub struct SwapPlaintextVar {
     pub delta_2_i: AmountVar,
     pub claim_address: AddressVar,
     pub rseed: FqVar,
+    pub min_output: AmountVar,
 }
 
 impl SwapPlaintextVar {
@@ -201,7 +213,7 @@ impl SwapPlaintextVar {
             .diversified_generator()
             .compress_to_field()?;
 
-        let inner_hash4 = poseidon377::r1cs::hash_4(
+        let inner_hash5 = poseidon377::r1cs::hash_5(
             cs.clone(),
             &domain_sep,
             (
@@ -209,6 +221,7 @@ impl SwapPlaintextVar {
                 self.trading_pair.asset_2.asset_id.clone(),
                 self.delta_1_i.amount.clone(),
                 self.delta_2_i.amount.clone(),
+                self.min_output.amount.clone(),
             ),
         )?;
 
@@ -222,7 +235,7 @@ impl SwapPlaintextVar {
                 compressed_g_d,
                 self.claim_address.transmission_key().compress_to_field()?,
                 self.claim_address.clue_key(),
-                inner_hash4,
+                inner_hash5,
             ),
         )?;
 
@@ -256,7 +269,7 @@ impl AllocVar<SwapPlaintext, Fq> for SwapPlaintextVar {
         let claim_address =
             AddressVar::new_variable(cs.clone(), || Ok(swap_plaintext.claim_address), mode)?;
         let rseed = FqVar::new_variable(
-            cs,
+            cs.clone(),
             || {
                 Ok(Fq::from_le_bytes_mod_order(
                     &swap_plaintext.rseed.to_bytes()[..],
@@ -264,6 +277,7 @@ impl AllocVar<SwapPlaintext, Fq> for SwapPlaintextVar {
             },
             mode,
         )?;
+        let min_output = AmountVar::new_variable(cs, || Ok(swap_plaintext.min_output), mode)?;
         Ok(Self {
             claim_fee,
             delta_1_i,
@@ -271,6 +285,7 @@ impl AllocVar<SwapPlaintext, Fq> for SwapPlaintextVar {
             delta_2_i,
             claim_address,
             rseed,
+            min_output,
         })
     }
 }
@@ -305,6 +320,11 @@ impl TryFrom<pb::SwapPlaintext> for SwapPlaintext {
                 .ok_or_else(|| anyhow::anyhow!("missing trading pair in SwapPlaintext"))?
                 .try_into()?,
             rseed: Rseed(plaintext.rseed.as_slice().try_into()?),
+            min_output: plaintext
+                .min_output
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
         })
     }
 }
@@ -318,6 +338,7 @@ impl From<SwapPlaintext> for pb::SwapPlaintext {
             claim_address: Some(plaintext.claim_address.into()),
             trading_pair: Some(plaintext.trading_pair.into()),
             rseed: plaintext.rseed.to_bytes().to_vec(),
+            min_output: Some(plaintext.min_output.into()),
         }
     }
 }
@@ -333,6 +354,7 @@ impl From<&SwapPlaintext> for [u8; SWAP_LEN_BYTES] {
         let pb_address = pb_keys::Address::from(swap.claim_address);
         bytes[144..224].copy_from_slice(&pb_address.inner);
         bytes[224..256].copy_from_slice(&swap.rseed.to_bytes());
+        bytes[256..272].copy_from_slice(&swap.min_output.to_le_bytes());
         bytes
     }
 }
@@ -376,6 +398,9 @@ impl TryFrom<&[u8]> for SwapPlaintext {
         let rseed: [u8; 32] = bytes[224..256]
             .try_into()
             .map_err(|_| anyhow!("error fetching rseed bytes"))?;
+        let min_output_bytes: [u8; 16] = bytes[256..272]
+            .try_into()
+            .map_err(|_| anyhow!("error fetching min output bytes"))?;
 
         Ok(SwapPlaintext {
             trading_pair: tp_bytes
@@ -389,6 +414,7 @@ impl TryFrom<&[u8]> for SwapPlaintext {
             }),
             claim_address: pb_address.try_into()?,
             rseed: Rseed(rseed),
+            min_output: Amount::from_le_bytes(min_output_bytes),
         })
     }
 }
@@ -22,6 +22,7 @@ use penumbra_asset::{
     Value,
 };
 use penumbra_keys::{keys::Diversifier, Address};
+use penumbra_num::{Amount, AmountVar};
 use penumbra_shielded_pool::Rseed;
 
 use crate::{
@@ -29,7 +30,7 @@ use crate::{
     TradingPair,
 };
 
-use penumbra_proof_params::{DummyWitness, GROTH16_PROOF_LENGTH_BYTES};
+use penumbra_proof_params::{BatchVerifier, DummyWitness, GROTH16_PROOF_LENGTH_BYTES};
 
 pub struct SwapCircuit {
     /// The swap plaintext.
@@ -42,6 +43,8 @@ pub struct SwapCircuit {
     pub swap_commitment: tct::StateCommitment,
     /// Balance commitment of the fee.
     pub fee_commitment: balance::Commitment,
+    /// The minimum output of the swap.
+    pub min_output: Amount,
 }
 
 impl SwapCircuit {
@@ -51,6 +54,7 @@ impl SwapCircuit {
         balance_commitment: balance::Commitment,
         swap_commitment: tct::StateCommitment,
         fee_commitment: balance::Commitment,
+        min_output: Amount,
     ) -> Self {
         Self {
             swap_plaintext,
@@ -58,6 +62,7 @@ impl SwapCircuit {
             balance_commitment,
             swap_commitment,
             fee_commitment,
+            min_output,
         }
     }
 }
@@ -75,7 +80,8 @@ impl ConstraintSynthesizer<Fq> for SwapCircuit {
         let claimed_swap_commitment =
             StateCommitmentVar::new_input(cs.clone(), || Ok(self.swap_commitment))?;
         let claimed_fee_commitment =
-            BalanceCommitmentVar::new_input(cs, || Ok(self.fee_commitment))?;
+            BalanceCommitmentVar::new_input(cs.clone(), || Ok(self.fee_commitment))?;
+        let claimed_min_output = AmountVar::new_input(cs, || Ok(self.min_output))?;
 
         // Swap commitment integrity check
         let swap_commitment = swap_plaintext_var.commit()?;
@@ -98,6 +104,9 @@ impl ConstraintSynthesizer<Fq> for SwapCircuit {
         // Balance commitment integrity check
         claimed_balance_commitment.enforce_equal(&total_balance_commitment)?;
 
+        // Minimum output integrity check
+        claimed_min_output.enforce_equal(&swap_plaintext_var.min_output)?;
+
         Ok(())
     }
 }
@@ -134,6 +143,7 @@ impl DummyWitness for SwapCircuit {
             }),
             claim_address: address,
             rseed: Rseed([1u8; 32]),
+            min_output: 1u64.into(),
         };
 
         Self {
@@ -142,6 +152,7 @@ impl DummyWitness for SwapCircuit {
             swap_commitment: swap_plaintext.swap_commitment(),
             fee_commitment: balance::Commitment(decaf377::basepoint()),
             balance_commitment: balance::Commitment(decaf377::basepoint()),
+            min_output: swap_plaintext.min_output,
         }
     }
 }
@@ -161,12 +172,14 @@ impl SwapProof {
         swap_commitment: tct::StateCommitment,
         fee_commitment: balance::Commitment,
     ) -> anyhow::Result<Self> {
+        let min_output = swap_plaintext.min_output;
         let circuit = SwapCircuit {
             swap_plaintext,
             fee_blinding,
             balance_commitment,
             swap_commitment,
             fee_commitment,
+            min_output,
         };
         let proof = Groth16::<Bls12_377, LibsnarkReduction>::create_proof_with_reduction(
             circuit, pk, blinding_r, blinding_s,
@@ -183,6 +196,7 @@ impl SwapProof {
     /// * balance commitment,
     /// * swap commitment,
     /// * fee commimtment,
+    /// * minimum output.
     ///
     // Commented out, but this may be useful when debugging proof verification failures,
     // to check that the proof data and verification keys are consistent.
@@ -194,10 +208,59 @@ impl SwapProof {
         balance_commitment: balance::Commitment,
         swap_commitment: tct::StateCommitment,
         fee_commitment: balance::Commitment,
+        min_output: Amount,
     ) -> anyhow::Result<()> {
         let proof =
             Proof::deserialize_compressed_unchecked(&self.0[..]).map_err(|e| anyhow::anyhow!(e))?;
 
+        let public_inputs = Self::public_inputs(
+            balance_commitment,
+            swap_commitment,
+            fee_commitment,
+            min_output,
+        )?;
+
+        tracing::trace!(?public_inputs);
+        let start = std::time::Instant::now();
+        let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
+            vk,
+            public_inputs.as_slice(),
+            &proof,
+        )
+        .map_err(|err| anyhow::anyhow!(err))?;
+        tracing::debug!(?proof_result, elapsed = ?start.elapsed());
+        proof_result
+            .then_some(())
+            .ok_or_else(|| anyhow::anyhow!("a swap proof did not verify"))
+    }
+
+    /// Queues the proof for batch verification with the provided public inputs.
+    pub fn queue<'a>(
+        &self,
+        batch: &mut BatchVerifier<'a>,
+        vk: &'a PreparedVerifyingKey<Bls12_377>,
+        balance_commitment: balance::Commitment,
+        swap_commitment: tct::StateCommitment,
+        fee_commitment: balance::Commitment,
+        min_output: Amount,
+    ) -> anyhow::Result<()> {
+        let proof = Proof::deserialize_compressed(&self.0[..]).map_err(|e| anyhow::anyhow!(e))?;
+        let public_inputs = Self::public_inputs(
+            balance_commitment,
+            swap_commitment,
+            fee_commitment,
+            min_output,
+        )?;
+        batch.queue(vk, "swap proof", proof, public_inputs);
+        Ok(())
+    }
+
+    fn public_inputs(
+        balance_commitment: balance::Commitment,
+        swap_commitment: tct::StateCommitment,
+        fee_commitment: balance::Commitment,
+        min_output: Amount,
+    ) -> anyhow::Result<Vec<Fq>> {
         let mut public_inputs = Vec::new();
         public_inputs.extend(
             balance_commitment
@@ -217,19 +280,13 @@ impl SwapProof {
                 .to_field_elements()
                 .context("fee_commitment should be a Bls12-377 field member")?,
         );
+        public_inputs.extend(
+            min_output
+                .to_field_elements()
+                .context("min_output should be a Bls12-377 field member")?,
+        );
 
-        tracing::trace!(?public_inputs);
-        let start = std::time::Instant::now();
-        let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
-            vk,
-            public_inputs.as_slice(),
-            &proof,
-        )
-        .map_err(|err| anyhow::anyhow!(err))?;
-        tracing::debug!(?proof_result, elapsed = ?start.elapsed());
-        proof_result
-            .then_some(())
-            .ok_or_else(|| anyhow::anyhow!("a swap proof did not verify"))
+        Ok(public_inputs)
     }
 }
 
@@ -259,7 +316,6 @@ mod tests {
     use ark_ff::{PrimeField, UniformRand};
     use penumbra_asset::{Balance, Value};
     use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
-    use penumbra_num::Amount;
     use penumbra_proof_params::generate_prepared_test_parameters;
     use proptest::prelude::*;
     use rand_core::OsRng;
@@ -292,8 +348,11 @@ mod tests {
         let delta_2 = Amount::from(0u64);
         let fee = Fee::default();
 
+        let min_output = Amount::from(1u64);
+
         let swap_plaintext =
-        SwapPlaintext::new(&mut rng, trading_pair, delta_1, delta_2, fee, claim_address);
+        SwapPlaintext::new(&mut rng, trading_pair, delta_1, delta_2, fee, claim_address)
+            .with_min_output(min_output);
         let fee_commitment = swap_plaintext.claim_fee.commit(fee_blinding);
         let swap_commitment = swap_plaintext.swap_commitment();
 
@@ -330,7 +389,13 @@ mod tests {
         )
         .expect("can create proof");
 
-        let proof_result = proof.verify(&vk, balance_commitment, swap_commitment, fee_commitment);
+        let proof_result = proof.verify(
+            &vk,
+            balance_commitment,
+            swap_commitment,
+            fee_commitment,
+            min_output,
+        );
 
         assert!(proof_result.is_ok());
         }
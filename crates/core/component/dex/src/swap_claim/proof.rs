@@ -17,7 +17,7 @@ use penumbra_asset::{
     Value, ValueVar,
 };
 use penumbra_keys::keys::{Bip44Path, NullifierKey, NullifierKeyVar, SeedPhrase, SpendKey};
-use penumbra_num::{Amount, AmountVar};
+use penumbra_num::{fixpoint::U128x128, Amount, AmountVar};
 use penumbra_sct::{Nullifier, NullifierVar};
 use penumbra_shielded_pool::{
     note::{self, NoteVar},
@@ -30,7 +30,7 @@ use crate::{
     BatchSwapOutputData, TradingPair,
 };
 
-use penumbra_proof_params::{DummyWitness, GROTH16_PROOF_LENGTH_BYTES};
+use penumbra_proof_params::{BatchVerifier, DummyWitness, GROTH16_PROOF_LENGTH_BYTES};
 
 /// SwapClaim consumes an existing Swap NFT so they are most similar to Spend operations,
 /// however the note commitment proof needs to be for a specific block due to clearing prices
@@ -168,9 +168,10 @@ impl ConstraintSynthesizer<Fq> for SwapClaimCircuit {
             .enforce_equal(&swap_plaintext_var.trading_pair)?;
 
         // Output amounts integrity
-        let (computed_lambda_1_i, computed_lambda_2_i) = output_data_var.pro_rata_outputs(
+        let (computed_lambda_1_i, computed_lambda_2_i) = output_data_var.swap_outputs(
             swap_plaintext_var.delta_1_i,
             swap_plaintext_var.delta_2_i,
+            swap_plaintext_var.min_output,
             cs,
         )?;
         computed_lambda_1_i.enforce_equal(&lambda_1_i_var)?;
@@ -238,6 +239,7 @@ impl DummyWitness for SwapClaimCircuit {
             }),
             claim_address: address,
             rseed: Rseed([1u8; 32]),
+            min_output: 1u64.into(),
         };
         let mut sct = tct::Tree::new();
         let swap_commitment = swap_plaintext.swap_commitment();
@@ -259,12 +261,15 @@ impl DummyWitness for SwapClaimCircuit {
             height: 0,
             trading_pair: swap_plaintext.trading_pair,
             epoch_starting_height: 0,
+            limit_price_1: U128x128::default(),
+            limit_price_2: U128x128::default(),
         };
         let note_blinding_1 = Fq::from(1);
         let note_blinding_2 = Fq::from(1);
         let note_commitment_1 = tct::StateCommitment(Fq::from(1));
         let note_commitment_2 = tct::StateCommitment(Fq::from(2));
-        let (lambda_1, lambda_2) = output_data.pro_rata_outputs((delta_1_i, delta_2_i));
+        let (lambda_1, lambda_2) =
+            output_data.swap_outputs((delta_1_i, delta_2_i), swap_plaintext.min_output);
 
         Self {
             swap_plaintext,
@@ -350,6 +355,62 @@ impl SwapClaimProof {
         let proof =
             Proof::deserialize_compressed_unchecked(&self.0[..]).map_err(|e| anyhow::anyhow!(e))?;
 
+        let public_inputs = Self::public_inputs(
+            anchor,
+            nullifier,
+            fee,
+            output_data,
+            note_commitment_1,
+            note_commitment_2,
+        )?;
+
+        tracing::trace!(?public_inputs);
+        let start = std::time::Instant::now();
+        let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
+            vk,
+            public_inputs.as_slice(),
+            &proof,
+        )
+        .map_err(|err| anyhow::anyhow!(err))?;
+        tracing::debug!(?proof_result, elapsed = ?start.elapsed());
+        proof_result
+            .then_some(())
+            .ok_or_else(|| anyhow::anyhow!("swapclaim proof did not verify"))
+    }
+
+    /// Queues the proof for batch verification with the provided public inputs.
+    pub fn queue<'a>(
+        &self,
+        batch: &mut BatchVerifier<'a>,
+        vk: &'a PreparedVerifyingKey<Bls12_377>,
+        anchor: tct::Root,
+        nullifier: Nullifier,
+        fee: Fee,
+        output_data: BatchSwapOutputData,
+        note_commitment_1: tct::StateCommitment,
+        note_commitment_2: tct::StateCommitment,
+    ) -> anyhow::Result<()> {
+        let proof = Proof::deserialize_compressed(&self.0[..]).map_err(|e| anyhow::anyhow!(e))?;
+        let public_inputs = Self::public_inputs(
+            anchor,
+            nullifier,
+            fee,
+            output_data,
+            note_commitment_1,
+            note_commitment_2,
+        )?;
+        batch.queue(vk, "swap claim proof", proof, public_inputs);
+        Ok(())
+    }
+
+    fn public_inputs(
+        anchor: tct::Root,
+        nullifier: Nullifier,
+        fee: Fee,
+        output_data: BatchSwapOutputData,
+        note_commitment_1: tct::StateCommitment,
+        note_commitment_2: tct::StateCommitment,
+    ) -> anyhow::Result<Vec<Fq>> {
         let mut public_inputs = Vec::new();
         public_inputs.extend(
             Fq::from(anchor.0)
@@ -392,18 +453,7 @@ impl SwapClaimProof {
                 .expect("note_commitment_2 is a Bls12-377 field member"),
         );
 
-        tracing::trace!(?public_inputs);
-        let start = std::time::Instant::now();
-        let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
-            vk,
-            public_inputs.as_slice(),
-            &proof,
-        )
-        .map_err(|err| anyhow::anyhow!(err))?;
-        tracing::debug!(?proof_result, elapsed = ?start.elapsed());
-        proof_result
-            .then_some(())
-            .ok_or_else(|| anyhow::anyhow!("swapclaim proof did not verify"))
+        Ok(public_inputs)
     }
 }
 
@@ -530,8 +580,11 @@ mod tests {
             height: height.into(),
             trading_pair: swap_plaintext.trading_pair,
             epoch_starting_height: (epoch_duration * position.epoch()).into(),
+            limit_price_1: U128x128::default(),
+            limit_price_2: U128x128::default(),
         };
-        let (lambda_1, lambda_2) = output_data.pro_rata_outputs((delta_1_i, delta_2_i));
+        let (lambda_1, lambda_2) =
+            output_data.swap_outputs((delta_1_i, delta_2_i), swap_plaintext.min_output);
 
         let (output_rseed_1, output_rseed_2) = swap_plaintext.output_rseeds();
         let note_blinding_1 = output_rseed_1.derive_note_blinding();
@@ -649,8 +702,11 @@ mod tests {
                 height: height.into(),
                 trading_pair: swap_plaintext.trading_pair,
                 epoch_starting_height: (epoch_duration * position.epoch()).into(),
+                limit_price_1: U128x128::default(),
+                limit_price_2: U128x128::default(),
             };
-            let (lambda_1, lambda_2) = output_data.pro_rata_outputs((delta_1_i, delta_2_i));
+            let (lambda_1, lambda_2) =
+                output_data.swap_outputs((delta_1_i, delta_2_i), swap_plaintext.min_output);
 
             let (output_rseed_1, output_rseed_2) = swap_plaintext.output_rseeds();
             let note_blinding_1 = output_rseed_1.derive_note_blinding();
@@ -39,12 +39,35 @@ impl Component for Fee {
         state.object_delete(state_key::gas_prices_changed());
     }
 
-    #[instrument(name = "staking", skip(_state, _end_block))]
+    #[instrument(name = "staking", skip(state, _end_block))]
     async fn end_block<S: StateWrite + 'static>(
-        _state: &mut Arc<S>,
+        state: &mut Arc<S>,
         _end_block: &abci::request::EndBlock,
     ) {
-        // TODO: update gas prices here eventually
+        let state = Arc::get_mut(state).expect("state should be unique");
+
+        // Adjust the gas prices based on how much gas the transactions in this block used.
+        let fee_params = state
+            .get_fee_params()
+            .await
+            .expect("fee params must be set");
+        let gas_prices = state
+            .get_gas_prices()
+            .await
+            .expect("gas prices must be set");
+        let gas_used = state.gas_used();
+
+        let next_gas_prices = fee_params.next_gas_prices(&gas_prices, &gas_used);
+        if next_gas_prices != gas_prices {
+            tracing::debug!(
+                ?gas_used,
+                ?gas_prices,
+                ?next_gas_prices,
+                "adjusting gas prices"
+            );
+            // This marks the gas prices as changed, so they're included in the compact block.
+            state.put_gas_prices(next_gas_prices);
+        }
     }
 
     #[instrument(name = "staking", skip(_state))]
@@ -1,9 +1,10 @@
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
+use penumbra_asset::{asset, STAKING_TOKEN_ASSET_ID};
 use penumbra_proto::{StateReadProto, StateWriteProto};
 use penumbra_storage::{StateRead, StateWrite};
 
-use crate::{params::FeeParameters, state_key, GasPrices};
+use crate::{params::FeeParameters, state_key, Gas, GasPrices};
 
 /// This trait provides read access to fee-related parts of the Penumbra
 /// state store.
@@ -33,11 +34,32 @@ pub trait StateReadExt: StateRead {
             .ok_or_else(|| anyhow!("Missing GasPrices"))
     }
 
+    /// Gets the gas prices for the given fee asset, or `None` if the asset is
+    /// neither the staking token nor an alternative fee asset approved by governance.
+    async fn get_gas_prices_for_asset(&self, asset_id: asset::Id) -> Result<Option<GasPrices>> {
+        if asset_id == *STAKING_TOKEN_ASSET_ID {
+            return Ok(Some(self.get_gas_prices().await?));
+        }
+
+        Ok(self
+            .get_fee_params()
+            .await?
+            .alt_gas_prices
+            .into_iter()
+            .find(|prices| prices.asset_id == asset_id))
+    }
+
     /// Returns true if the gas prices have been changed in this block.
     fn gas_prices_changed(&self) -> bool {
         self.object_get::<()>(state_key::gas_prices_changed())
             .is_some()
     }
+
+    /// Returns the total gas used by the transactions executed so far in this block.
+    fn gas_used(&self) -> Gas {
+        self.object_get(state_key::gas_used())
+            .unwrap_or_else(Gas::zero)
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
@@ -67,6 +89,13 @@ pub trait StateWriteExt: StateWrite {
         // Mark that they've changed
         self.object_put(state_key::gas_prices_changed(), ());
     }
+
+    /// Records gas used by a transaction executed in this block, which is used
+    /// to adjust the gas prices at the end of the block.
+    fn add_gas_used(&mut self, gas: Gas) {
+        let gas_used = self.gas_used() + gas;
+        self.object_put(state_key::gas_used(), gas_used);
+    }
 }
 
 impl<T: StateWrite + ?Sized> StateWriteExt for T {}
@@ -1,11 +1,13 @@
 use std::{iter::Sum, ops::Add};
 
+use penumbra_asset::{asset, STAKING_TOKEN_ASSET_ID};
 use penumbra_num::Amount;
 use penumbra_proto::{core::component::fee::v1alpha1 as pb, DomainType};
 
 /// Represents the different resources that a transaction can consume,
 /// for purposes of calculating multidimensional fees based on real
 /// transaction resource consumption.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct Gas {
     pub block_space: u64,
     pub compact_block_space: u64,
@@ -43,9 +45,12 @@ impl Sum for Gas {
     }
 }
 
-/// Expresses the price of each unit of gas in terms of the staking token.
+/// Expresses the price of each unit of gas in terms of a fee token, which is
+/// either the staking token or one of the alternative fee assets approved by
+/// governance.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct GasPrices {
+    pub asset_id: asset::Id,
     pub block_space_price: u64,
     pub compact_block_space_price: u64,
     pub verification_price: u64,
@@ -55,6 +60,7 @@ pub struct GasPrices {
 impl GasPrices {
     pub fn zero() -> Self {
         Self {
+            asset_id: *STAKING_TOKEN_ASSET_ID,
             block_space_price: 0,
             compact_block_space_price: 0,
             verification_price: 0,
@@ -81,6 +87,12 @@ impl DomainType for GasPrices {
 impl From<GasPrices> for pb::GasPrices {
     fn from(prices: GasPrices) -> Self {
         pb::GasPrices {
+            // Omit the asset ID for the staking token, as in [`Fee`](crate::Fee).
+            asset_id: if prices.asset_id == *STAKING_TOKEN_ASSET_ID {
+                None
+            } else {
+                Some(prices.asset_id.into())
+            },
             block_space_price: prices.block_space_price,
             compact_block_space_price: prices.compact_block_space_price,
             verification_price: prices.verification_price,
@@ -94,6 +106,11 @@ impl TryFrom<pb::GasPrices> for GasPrices {
 
     fn try_from(proto: pb::GasPrices) -> Result<Self, Self::Error> {
         Ok(GasPrices {
+            asset_id: proto
+                .asset_id
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or(*STAKING_TOKEN_ASSET_ID),
             block_space_price: proto.block_space_price,
             compact_block_space_price: proto.compact_block_space_price,
             verification_price: proto.verification_price,
@@ -47,12 +47,7 @@ impl Default for Content {
     fn default() -> Self {
         Self {
             fee_params: FeeParameters::default(),
-            gas_prices: GasPrices {
-                block_space_price: 0,
-                compact_block_space_price: 0,
-                verification_price: 0,
-                execution_price: 0,
-            },
+            gas_prices: GasPrices::zero(),
         }
     }
 }
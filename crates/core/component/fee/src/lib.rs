@@ -15,4 +15,4 @@ pub mod params;
 
 pub use fee::Fee;
 pub use gas::{Gas, GasPrices};
-pub use params::FeeParameters;
+pub use params::{FeeParameters, GasPriceAdjustment};
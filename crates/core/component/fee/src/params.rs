@@ -3,9 +3,47 @@ use penumbra_proto::penumbra::core::component::fee::v1alpha1 as pb;
 use penumbra_proto::DomainType;
 use serde::{Deserialize, Serialize};
 
+use crate::{Gas, GasPrices};
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(try_from = "pb::FeeParameters", into = "pb::FeeParameters")]
-pub struct FeeParameters {}
+pub struct FeeParameters {
+    /// The adjustment parameters for the block space gas price.
+    pub block_space: GasPriceAdjustment,
+    /// The adjustment parameters for the compact block space gas price.
+    pub compact_block_space: GasPriceAdjustment,
+    /// The adjustment parameters for the verification gas price.
+    pub verification: GasPriceAdjustment,
+    /// The adjustment parameters for the execution gas price.
+    pub execution: GasPriceAdjustment,
+    /// The gas prices of the alternative fee assets approved by governance.
+    pub alt_gas_prices: Vec<GasPrices>,
+}
+
+impl FeeParameters {
+    /// Computes the gas prices for the next block, given the current prices and
+    /// the gas used by the transactions in the current block.
+    ///
+    /// Each dimension is adjusted independently of the others.
+    pub fn next_gas_prices(&self, prices: &GasPrices, gas_used: &Gas) -> GasPrices {
+        GasPrices {
+            asset_id: prices.asset_id,
+            block_space_price: self
+                .block_space
+                .next_price(prices.block_space_price, gas_used.block_space),
+            compact_block_space_price: self.compact_block_space.next_price(
+                prices.compact_block_space_price,
+                gas_used.compact_block_space,
+            ),
+            verification_price: self
+                .verification
+                .next_price(prices.verification_price, gas_used.verification),
+            execution_price: self
+                .execution
+                .next_price(prices.execution_price, gas_used.execution),
+        }
+    }
+}
 
 impl DomainType for FeeParameters {
     type Proto = pb::FeeParameters;
@@ -14,13 +52,199 @@ impl DomainType for FeeParameters {
 impl TryFrom<pb::FeeParameters> for FeeParameters {
     type Error = anyhow::Error;
 
-    fn try_from(_msg: pb::FeeParameters) -> anyhow::Result<Self> {
-        Ok(FeeParameters {})
+    fn try_from(msg: pb::FeeParameters) -> anyhow::Result<Self> {
+        Ok(FeeParameters {
+            block_space: msg.block_space.unwrap_or_default().try_into()?,
+            compact_block_space: msg.compact_block_space.unwrap_or_default().try_into()?,
+            verification: msg.verification.unwrap_or_default().try_into()?,
+            execution: msg.execution.unwrap_or_default().try_into()?,
+            alt_gas_prices: msg
+                .alt_gas_prices
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+        })
     }
 }
 
 impl From<FeeParameters> for pb::FeeParameters {
-    fn from(_params: FeeParameters) -> Self {
-        pb::FeeParameters {}
+    fn from(params: FeeParameters) -> Self {
+        pb::FeeParameters {
+            block_space: Some(params.block_space.into()),
+            compact_block_space: Some(params.compact_block_space.into()),
+            verification: Some(params.verification.into()),
+            execution: Some(params.execution.into()),
+            alt_gas_prices: params.alt_gas_prices.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Controls how the gas price of a single resource dimension moves from block to block.
+///
+/// This is an EIP-1559-style controller: at the end of every block, the price is
+/// raised if the gas used in the block exceeded `target_per_block`, and lowered if
+/// it fell short, by at most `1/adjustment_denominator` of the current price. The
+/// result is then clamped to `[min_price, max_price]`.
+///
+/// A `target_per_block` of zero disables the adjustment, leaving the price unchanged.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(try_from = "pb::GasPriceAdjustment", into = "pb::GasPriceAdjustment")]
+pub struct GasPriceAdjustment {
+    pub target_per_block: u64,
+    pub min_price: u64,
+    pub max_price: u64,
+    pub adjustment_denominator: u64,
+}
+
+impl GasPriceAdjustment {
+    /// Returns true if the adjustment is enabled, i.e. if it has a non-zero target.
+    pub fn is_enabled(&self) -> bool {
+        self.target_per_block != 0
+    }
+
+    /// Computes the price for the next block, given the current price and the
+    /// gas used in the current block.
+    pub fn next_price(&self, price: u64, gas_used: u64) -> u64 {
+        if !self.is_enabled() {
+            return price;
+        }
+
+        let target = self.target_per_block as u128;
+        // Usage beyond twice the target counts as twice the target, so that a single
+        // block can't move the price by more than `1/adjustment_denominator`.
+        let used = (gas_used as u128).min(2 * target);
+        let denominator = self.adjustment_denominator.max(1) as u128;
+        let price = price as u128;
+
+        let next = if used > target {
+            // Always increase by at least one unit, so that a zero price can recover.
+            price + (price * (used - target) / target / denominator).max(1)
+        } else {
+            price - price * (target - used) / target / denominator
+        };
+
+        u64::try_from(next)
+            .unwrap_or(u64::MAX)
+            .max(self.min_price)
+            .min(self.max_price)
+    }
+}
+
+impl DomainType for GasPriceAdjustment {
+    type Proto = pb::GasPriceAdjustment;
+}
+
+impl TryFrom<pb::GasPriceAdjustment> for GasPriceAdjustment {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::GasPriceAdjustment) -> anyhow::Result<Self> {
+        Ok(GasPriceAdjustment {
+            target_per_block: msg.target_per_block,
+            min_price: msg.min_price,
+            max_price: msg.max_price,
+            adjustment_denominator: msg.adjustment_denominator,
+        })
+    }
+}
+
+impl From<GasPriceAdjustment> for pb::GasPriceAdjustment {
+    fn from(adjustment: GasPriceAdjustment) -> Self {
+        pb::GasPriceAdjustment {
+            target_per_block: adjustment.target_per_block,
+            min_price: adjustment.min_price,
+            max_price: adjustment.max_price,
+            adjustment_denominator: adjustment.adjustment_denominator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+
+    use super::*;
+
+    fn adjustment() -> GasPriceAdjustment {
+        GasPriceAdjustment {
+            target_per_block: 1_000,
+            min_price: 10,
+            max_price: 10_000,
+            adjustment_denominator: 8,
+        }
+    }
+
+    #[test]
+    fn disabled_adjustment_keeps_price() {
+        let disabled = GasPriceAdjustment::default();
+        assert_eq!(disabled.next_price(1234, 0), 1234);
+        assert_eq!(disabled.next_price(1234, u64::MAX), 1234);
+    }
+
+    #[test]
+    fn price_moves_towards_target() {
+        let adjustment = adjustment();
+        // At target, the price is unchanged.
+        assert_eq!(adjustment.next_price(800, 1_000), 800);
+        // Full blocks raise the price by at most 1/8th.
+        assert_eq!(adjustment.next_price(800, 2_000), 900);
+        assert_eq!(adjustment.next_price(800, u64::MAX), 900);
+        // Empty blocks lower the price by 1/8th.
+        assert_eq!(adjustment.next_price(800, 0), 700);
+        // Partial deviations move the price proportionally.
+        assert_eq!(adjustment.next_price(800, 1_500), 850);
+        assert_eq!(adjustment.next_price(800, 500), 750);
+    }
+
+    #[test]
+    fn price_stays_within_bounds() {
+        let adjustment = adjustment();
+        assert_eq!(adjustment.next_price(10, 0), 10);
+        assert_eq!(adjustment.next_price(10_000, 2_000), 10_000);
+        // Prices outside of the bounds are brought back into them.
+        assert_eq!(adjustment.next_price(0, 1_000), 10);
+        assert_eq!(adjustment.next_price(50_000, 1_000), 10_000);
+    }
+
+    #[test]
+    fn zero_price_can_increase() {
+        let adjustment = GasPriceAdjustment {
+            min_price: 0,
+            ..adjustment()
+        };
+        assert_eq!(adjustment.next_price(0, 1_001), 1);
+    }
+
+    #[test]
+    fn dimensions_adjust_independently() {
+        let params = FeeParameters {
+            block_space: adjustment(),
+            compact_block_space: adjustment(),
+            verification: GasPriceAdjustment::default(),
+            execution: adjustment(),
+            alt_gas_prices: Vec::new(),
+        };
+        let prices = GasPrices {
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+            block_space_price: 800,
+            compact_block_space_price: 800,
+            verification_price: 800,
+            execution_price: 800,
+        };
+        let gas_used = Gas {
+            block_space: 2_000,
+            compact_block_space: 0,
+            verification: 2_000,
+            execution: 1_000,
+        };
+        assert_eq!(
+            params.next_gas_prices(&prices, &gas_used),
+            GasPrices {
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+                block_space_price: 900,
+                compact_block_space_price: 700,
+                verification_price: 800,
+                execution_price: 800,
+            }
+        );
     }
 }
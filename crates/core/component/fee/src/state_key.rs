@@ -10,6 +10,10 @@ pub fn gas_prices_changed() -> &'static str {
     "fee/gas_prices_changed"
 }
 
+pub fn gas_used() -> &'static str {
+    "fee/gas_used"
+}
+
 pub fn fee_params_updated() -> &'static str {
     "fee/fee_params_updated"
 }
@@ -5,7 +5,7 @@ use ark_ff::Zero;
 use async_trait::async_trait;
 use decaf377::Fr;
 use penumbra_chain::TransactionContext;
-use penumbra_proof_params::DELEGATOR_VOTE_PROOF_VERIFICATION_KEY;
+use penumbra_proof_params::{BatchVerifier, DELEGATOR_VOTE_PROOF_VERIFICATION_KEY};
 use penumbra_storage::{StateRead, StateWrite};
 
 use crate::{
@@ -14,6 +14,34 @@ use crate::{
 };
 use penumbra_component::ActionHandler;
 
+impl DelegatorVote {
+    /// Checks the delegator vote statelessly, like `check_stateless`, except that its
+    /// proof is queued in `batch` instead of being verified, so that it can be verified
+    /// along with the other proofs of the transaction.
+    pub fn check_stateless_batched(
+        &self,
+        context: &TransactionContext,
+        batch: &mut BatchVerifier<'static>,
+    ) -> Result<()> {
+        let body = &self.body;
+        body.rk
+            .verify(context.effect_hash.as_ref(), &self.auth_sig)
+            .context("delegator vote auth signature failed to verify")?;
+
+        self.proof
+            .queue(
+                batch,
+                &DELEGATOR_VOTE_PROOF_VERIFICATION_KEY,
+                context.anchor,
+                body.value.commit(Fr::zero()),
+                body.nullifier,
+                body.rk,
+                body.start_position,
+            )
+            .context("invalid delegator vote proof")
+    }
+}
+
 #[async_trait]
 impl ActionHandler for DelegatorVote {
     type CheckStatelessContext = TransactionContext;
@@ -49,9 +77,7 @@ impl ActionHandler for DelegatorVote {
                 *rk,
                 *start_position,
             )
-            .context("a delegator vote proof did not verify")?;
-
-        Ok(())
+            .context("a delegator vote proof did not verify")
     }
 
     async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
@@ -99,7 +99,7 @@ impl ActionHandler for ValidatorVote {
                 .total_voting_power_at_proposal_start(*proposal)
                 .await?;
             let governance_params = state.get_governance_params().await?;
-            if tally.emergency_pass(total_voting_power, &governance_params) {
+            if tally.emergency_pass(total_voting_power, &governance_params.emergency) {
                 // If the emergency pass condition is met, enact the proposal
                 tracing::debug!(proposal = %proposal, "emergency pass condition met, trying to enact proposal");
                 // Try to enact the proposal based on its payload
@@ -104,12 +104,18 @@ pub async fn enact_all_passed_proposals<S: StateWrite>(mut state: S) -> Result<(
         let outcome = match current_state {
             ProposalState::Voting => {
                 // If the proposal is still in the voting state, tally and conclude it (this will
-                // automatically remove it from the list of unfinished proposals)
+                // automatically remove it from the list of unfinished proposals), using the
+                // parameters for its kind of proposal
+                let kind = state
+                    .proposal_payload(proposal_id)
+                    .await?
+                    .context("proposal has payload")?
+                    .kind();
                 let outcome = state.current_tally(proposal_id).await?.outcome(
                     state
                         .total_voting_power_at_proposal_start(proposal_id)
                         .await?,
-                    &state.get_governance_params().await?,
+                    state.get_governance_params().await?.for_kind(kind),
                 );
 
                 // If the proposal passes, enact it now (or try to: if the proposal can't be
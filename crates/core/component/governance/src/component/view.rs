@@ -8,6 +8,7 @@ use async_trait::async_trait;
 use futures::StreamExt;
 use penumbra_asset::{asset, Value, STAKING_TOKEN_DENOM};
 use penumbra_chain::component::{StateReadExt as _, StateWriteExt as _};
+use penumbra_ibc::component::client_recovery::ClientRecoveryWrite as _;
 use penumbra_num::Amount;
 use penumbra_proto::{StateReadProto, StateWriteProto};
 use penumbra_sct::Nullifier;
@@ -22,7 +23,9 @@ use penumbra_stake::{rate::RateData, validator, StateReadExt as _};
 
 use crate::{
     params::GovernanceParameters,
-    proposal::{ChangedAppParameters, ChangedAppParametersSet, Proposal, ProposalPayload},
+    proposal::{
+        ChangedAppParameters, ChangedAppParametersSet, Proposal, ProposalKind, ProposalPayload,
+    },
     proposal_state::State as ProposalState,
     validator_vote::action::ValidatorVoteReason,
     vote::Vote,
@@ -295,9 +298,17 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
         Ok(())
     }
 
-    async fn check_height_in_future_of_voting_end(&self, height: u64) -> Result<()> {
+    async fn check_height_in_future_of_voting_end(
+        &self,
+        kind: ProposalKind,
+        height: u64,
+    ) -> Result<()> {
         let block_height = self.get_block_height().await?;
-        let voting_blocks = self.get_governance_params().await?.proposal_voting_blocks;
+        let voting_blocks = self
+            .get_governance_params()
+            .await?
+            .for_kind(kind)
+            .voting_blocks;
         let voting_end_height = block_height + voting_blocks;
 
         if height < voting_end_height {
@@ -871,9 +882,28 @@ pub trait StateWriteExt: StateWrite {
                 // be slotted in at the end of the block:
                 self.deliver_dao_transaction(proposal_id).await?;
             }
-            ProposalPayload::UpgradePlan { height } => {
-                tracing::info!(target_height = height, "upgrade plan proposal passed");
-                self.signal_upgrade(*height).await?;
+            ProposalPayload::UpgradePlan { height, name } => {
+                tracing::info!(target_height = height, %name, "upgrade plan proposal passed");
+                self.signal_upgrade(*height, name.clone()).await?;
+            }
+            ProposalPayload::IbcClientRecovery {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                tracing::info!(
+                    %subject_client_id,
+                    %substitute_client_id,
+                    "ibc client recovery proposal passed, attempting to recover client"
+                );
+
+                // Either client may have changed since the proposal was submitted, so the recovery
+                // can fail; if it does, nothing is written and the proposal is not enacted.
+                if let Err(e) = self
+                    .recover_client(subject_client_id, substitute_client_id)
+                    .await
+                {
+                    return Ok(Err(e));
+                }
             }
         }
 
@@ -24,7 +24,9 @@ use penumbra_keys::keys::{
     AuthorizationKeyVar, Bip44Path, IncomingViewingKeyVar, NullifierKey, NullifierKeyVar,
     RandomizedVerificationKey, SeedPhrase, SpendAuthRandomizerVar, SpendKey,
 };
-use penumbra_proof_params::{DummyWitness, VerifyingKeyExt, GROTH16_PROOF_LENGTH_BYTES};
+use penumbra_proof_params::{
+    BatchVerifier, DummyWitness, VerifyingKeyExt, GROTH16_PROOF_LENGTH_BYTES,
+};
 use penumbra_sct::{Nullifier, NullifierVar};
 use penumbra_shielded_pool::{note, Note, Rseed};
 
@@ -305,6 +307,48 @@ impl DelegatorVoteProof {
         let proof =
             Proof::deserialize_compressed_unchecked(&self.0[..]).map_err(|e| anyhow::anyhow!(e))?;
 
+        let public_inputs =
+            Self::public_inputs(anchor, balance_commitment, nullifier, rk, start_position)?;
+
+        tracing::trace!(?public_inputs);
+        let start = std::time::Instant::now();
+        let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
+            vk,
+            public_inputs.as_slice(),
+            &proof,
+        )
+        .map_err(|err| anyhow::anyhow!(err))?;
+        tracing::debug!(?proof_result, elapsed = ?start.elapsed());
+        proof_result
+            .then_some(())
+            .ok_or_else(|| anyhow::anyhow!("delegator vote proof did not verify"))
+    }
+
+    /// Queues the proof for batch verification with the provided public inputs.
+    pub fn queue<'a>(
+        &self,
+        batch: &mut BatchVerifier<'a>,
+        vk: &'a PreparedVerifyingKey<Bls12_377>,
+        anchor: tct::Root,
+        balance_commitment: balance::Commitment,
+        nullifier: Nullifier,
+        rk: VerificationKey<SpendAuth>,
+        start_position: tct::Position,
+    ) -> anyhow::Result<()> {
+        let proof = Proof::deserialize_compressed(&self.0[..]).map_err(|e| anyhow::anyhow!(e))?;
+        let public_inputs =
+            Self::public_inputs(anchor, balance_commitment, nullifier, rk, start_position)?;
+        batch.queue(vk, "delegator vote proof", proof, public_inputs);
+        Ok(())
+    }
+
+    fn public_inputs(
+        anchor: tct::Root,
+        balance_commitment: balance::Commitment,
+        nullifier: Nullifier,
+        rk: VerificationKey<SpendAuth>,
+        start_position: tct::Position,
+    ) -> anyhow::Result<Vec<Fq>> {
         let mut public_inputs = Vec::new();
         public_inputs.extend(
             Fq::from(anchor.0)
@@ -333,18 +377,7 @@ impl DelegatorVoteProof {
                 .expect("valid field element"),
         );
 
-        tracing::trace!(?public_inputs);
-        let start = std::time::Instant::now();
-        let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
-            vk,
-            public_inputs.as_slice(),
-            &proof,
-        )
-        .map_err(|err| anyhow::anyhow!(err))?;
-        tracing::debug!(?proof_result, elapsed = ?start.elapsed());
-        proof_result
-            .then_some(())
-            .ok_or_else(|| anyhow::anyhow!("delegator vote proof did not verify"))
+        Ok(public_inputs)
     }
 }
 
@@ -5,78 +5,263 @@ use penumbra_proto::core::component::governance::v1alpha1 as pb;
 use penumbra_proto::DomainType;
 use serde::{Deserialize, Serialize};
 
+use crate::proposal::ProposalKind;
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(
     try_from = "pb::GovernanceParameters",
     into = "pb::GovernanceParameters"
 )]
 pub struct GovernanceParameters {
+    /// The parameters for signaling proposals.
+    pub signaling: ProposalParameters,
+    /// The parameters for emergency proposals.
+    pub emergency: ProposalParameters,
+    /// The parameters for parameter change proposals.
+    pub parameter_change: ProposalParameters,
+    /// The parameters for DAO spend proposals.
+    pub dao_spend: ProposalParameters,
+    /// The parameters for upgrade plan proposals.
+    pub upgrade_plan: ProposalParameters,
+    /// The parameters for IBC client recovery proposals.
+    pub ibc_client_recovery: ProposalParameters,
+}
+
+impl GovernanceParameters {
+    /// The parameters governing proposals of the given kind.
+    pub fn for_kind(&self, kind: ProposalKind) -> &ProposalParameters {
+        match kind {
+            ProposalKind::Signaling => &self.signaling,
+            ProposalKind::Emergency => &self.emergency,
+            ProposalKind::ParameterChange => &self.parameter_change,
+            ProposalKind::DaoSpend => &self.dao_spend,
+            ProposalKind::UpgradePlan => &self.upgrade_plan,
+            ProposalKind::IbcClientRecovery => &self.ibc_client_recovery,
+        }
+    }
+
+    /// Mutable access to the parameters governing proposals of the given kind.
+    pub fn for_kind_mut(&mut self, kind: ProposalKind) -> &mut ProposalParameters {
+        match kind {
+            ProposalKind::Signaling => &mut self.signaling,
+            ProposalKind::Emergency => &mut self.emergency,
+            ProposalKind::ParameterChange => &mut self.parameter_change,
+            ProposalKind::DaoSpend => &mut self.dao_spend,
+            ProposalKind::UpgradePlan => &mut self.upgrade_plan,
+            ProposalKind::IbcClientRecovery => &mut self.ibc_client_recovery,
+        }
+    }
+}
+
+impl DomainType for GovernanceParameters {
+    type Proto = pb::GovernanceParameters;
+}
+
+impl TryFrom<pb::GovernanceParameters> for GovernanceParameters {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::GovernanceParameters) -> anyhow::Result<Self> {
+        // Parameters stored before the per-kind parameters were introduced only have the
+        // legacy fields, which then apply to every kind of proposal.
+        let legacy = msg
+            .proposal_deposit_amount
+            .map(|deposit_amount| pb::ProposalParameters {
+                voting_blocks: msg.proposal_voting_blocks,
+                deposit_amount: Some(deposit_amount),
+                valid_quorum: msg.proposal_valid_quorum,
+                pass_threshold: msg.proposal_pass_threshold,
+                slash_threshold: msg.proposal_slash_threshold,
+            });
+        let for_kind = |params, name| proposal_parameters(params, legacy.clone(), name);
+
+        Ok(GovernanceParameters {
+            signaling: for_kind(msg.signaling_params, "signaling_params")?,
+            emergency: for_kind(msg.emergency_params, "emergency_params")?,
+            parameter_change: for_kind(msg.parameter_change_params, "parameter_change_params")?,
+            dao_spend: for_kind(msg.dao_spend_params, "dao_spend_params")?,
+            upgrade_plan: for_kind(msg.upgrade_plan_params, "upgrade_plan_params")?,
+            ibc_client_recovery: for_kind(
+                msg.ibc_client_recovery_params,
+                "ibc_client_recovery_params",
+            )?,
+        })
+    }
+}
+
+/// Decodes the parameters for one kind of proposal, falling back to the legacy parameters
+/// shared by every kind if they're absent.
+fn proposal_parameters(
+    params: Option<pb::ProposalParameters>,
+    legacy: Option<pb::ProposalParameters>,
+    name: &str,
+) -> anyhow::Result<ProposalParameters> {
+    params
+        .or(legacy)
+        .ok_or_else(|| anyhow::anyhow!("missing {name}"))?
+        .try_into()
+        .with_context(|| format!("couldn't parse {name}"))
+}
+
+impl From<GovernanceParameters> for pb::GovernanceParameters {
+    fn from(params: GovernanceParameters) -> Self {
+        pb::GovernanceParameters {
+            signaling_params: Some(params.signaling.into()),
+            emergency_params: Some(params.emergency.into()),
+            parameter_change_params: Some(params.parameter_change.into()),
+            dao_spend_params: Some(params.dao_spend.into()),
+            upgrade_plan_params: Some(params.upgrade_plan.into()),
+            ibc_client_recovery_params: Some(params.ibc_client_recovery.into()),
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for GovernanceParameters {
+    fn default() -> Self {
+        let defaults = ProposalParameters::default();
+        Self {
+            signaling: defaults.clone(),
+            // Emergency proposals can pass early with 2/3 of all voting power, and otherwise
+            // conclude after a shorter voting period.
+            emergency: ProposalParameters {
+                voting_blocks: 4_320, // 6 hours, at a 5 second block time
+                ..defaults.clone()
+            },
+            parameter_change: defaults.clone(),
+            dao_spend: defaults.clone(),
+            ibc_client_recovery: defaults.clone(),
+            // Upgrades require coordination from every node operator, so they need a supermajority.
+            upgrade_plan: ProposalParameters {
+                pass_threshold: Ratio::new(2, 3),
+                ..defaults
+            },
+        }
+    }
+}
+
+/// The governance parameters for a single kind of proposal.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::ProposalParameters", into = "pb::ProposalParameters")]
+pub struct ProposalParameters {
     /// The number of blocks during which a proposal is voted on.
-    pub proposal_voting_blocks: u64,
+    pub voting_blocks: u64,
     /// The deposit required to create a proposal.
-    pub proposal_deposit_amount: Amount,
+    pub deposit_amount: Amount,
     /// The quorum required for a proposal to be considered valid, as a fraction of the total stake
     /// weight of the network.
-    pub proposal_valid_quorum: Ratio,
+    pub valid_quorum: Ratio,
     /// The threshold for a proposal to pass voting, as a ratio of "yes" votes over "no" votes.
-    pub proposal_pass_threshold: Ratio,
+    pub pass_threshold: Ratio,
     /// The threshold for a proposal to be slashed, as a ratio of "no" votes over all total votes.
-    pub proposal_slash_threshold: Ratio,
+    pub slash_threshold: Ratio,
 }
 
-impl DomainType for GovernanceParameters {
-    type Proto = pb::GovernanceParameters;
+impl DomainType for ProposalParameters {
+    type Proto = pb::ProposalParameters;
 }
 
-impl TryFrom<pb::GovernanceParameters> for GovernanceParameters {
+impl TryFrom<pb::ProposalParameters> for ProposalParameters {
     type Error = anyhow::Error;
 
-    fn try_from(msg: pb::GovernanceParameters) -> anyhow::Result<Self> {
-        Ok(GovernanceParameters {
-            proposal_voting_blocks: msg.proposal_voting_blocks,
-            proposal_deposit_amount: msg
-                .proposal_deposit_amount
-                .ok_or_else(|| anyhow::anyhow!("missing proposal_deposit_amount"))?
+    fn try_from(msg: pb::ProposalParameters) -> anyhow::Result<Self> {
+        Ok(ProposalParameters {
+            voting_blocks: msg.voting_blocks,
+            deposit_amount: msg
+                .deposit_amount
+                .ok_or_else(|| anyhow::anyhow!("missing deposit_amount"))?
                 .try_into()?,
-            proposal_valid_quorum: msg
-                .proposal_valid_quorum
+            valid_quorum: msg
+                .valid_quorum
                 .parse()
-                .context("couldn't parse proposal_valid_quorum")?,
-            proposal_pass_threshold: msg
-                .proposal_pass_threshold
+                .context("couldn't parse valid_quorum")?,
+            pass_threshold: msg
+                .pass_threshold
                 .parse()
-                .context("couldn't parse proposal_pass_threshold")?,
-            proposal_slash_threshold: msg
-                .proposal_slash_threshold
+                .context("couldn't parse pass_threshold")?,
+            slash_threshold: msg
+                .slash_threshold
                 .parse()
-                .context("couldn't parse proposal_slash_threshold")?,
+                .context("couldn't parse slash_threshold")?,
         })
     }
 }
 
-impl From<GovernanceParameters> for pb::GovernanceParameters {
-    fn from(params: GovernanceParameters) -> Self {
-        pb::GovernanceParameters {
-            proposal_voting_blocks: params.proposal_voting_blocks,
-            proposal_deposit_amount: Some(params.proposal_deposit_amount.into()),
-            proposal_valid_quorum: params.proposal_valid_quorum.to_string(),
-            proposal_pass_threshold: params.proposal_pass_threshold.to_string(),
-            proposal_slash_threshold: params.proposal_slash_threshold.to_string(),
+impl From<ProposalParameters> for pb::ProposalParameters {
+    fn from(params: ProposalParameters) -> Self {
+        pb::ProposalParameters {
+            voting_blocks: params.voting_blocks,
+            deposit_amount: Some(params.deposit_amount.into()),
+            valid_quorum: params.valid_quorum.to_string(),
+            pass_threshold: params.pass_threshold.to_string(),
+            slash_threshold: params.slash_threshold.to_string(),
         }
     }
 }
 
-impl Default for GovernanceParameters {
+impl Default for ProposalParameters {
     fn default() -> Self {
         Self {
-            // governance
-            proposal_voting_blocks: 17_280, // 24 hours, at a 5 second block time
-            proposal_deposit_amount: 10_000_000u64.into(), // 10,000,000 upenumbra = 10 penumbra
+            voting_blocks: 17_280,                // 24 hours, at a 5 second block time
+            deposit_amount: 10_000_000u64.into(), // 10,000,000 upenumbra = 10 penumbra
             // governance parameters copied from cosmos hub
-            proposal_valid_quorum: Ratio::new(40, 100),
-            proposal_pass_threshold: Ratio::new(50, 100),
+            valid_quorum: Ratio::new(40, 100),
+            pass_threshold: Ratio::new(50, 100),
             // slash threshold means if (no / no + yes + abstain) > slash_threshold, then proposal is slashed
-            proposal_slash_threshold: Ratio::new(80, 100),
+            slash_threshold: Ratio::new(80, 100),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_parameters_apply_to_every_kind() {
+        let legacy = ProposalParameters {
+            voting_blocks: 1_000,
+            deposit_amount: 5_000_000u64.into(),
+            valid_quorum: Ratio::new(1, 3),
+            pass_threshold: Ratio::new(1, 2),
+            slash_threshold: Ratio::new(4, 5),
+        };
+        let msg = pb::GovernanceParameters {
+            proposal_voting_blocks: legacy.voting_blocks,
+            proposal_deposit_amount: Some(legacy.deposit_amount.into()),
+            proposal_valid_quorum: legacy.valid_quorum.to_string(),
+            proposal_pass_threshold: legacy.pass_threshold.to_string(),
+            proposal_slash_threshold: legacy.slash_threshold.to_string(),
+            ..Default::default()
+        };
+
+        let params = GovernanceParameters::try_from(msg).expect("legacy parameters decode");
+        for kind in [
+            ProposalKind::Signaling,
+            ProposalKind::Emergency,
+            ProposalKind::ParameterChange,
+            ProposalKind::DaoSpend,
+            ProposalKind::UpgradePlan,
+            ProposalKind::IbcClientRecovery,
+        ] {
+            assert_eq!(params.for_kind(kind), &legacy);
+        }
+    }
+
+    #[test]
+    fn per_kind_parameters_take_precedence() {
+        let params = GovernanceParameters::default();
+        let mut msg = pb::GovernanceParameters::from(params.clone());
+        msg.proposal_voting_blocks = 1;
+        msg.proposal_deposit_amount = Some(Amount::from(1u64).into());
+        msg.proposal_valid_quorum = Ratio::new(1, 1).to_string();
+        msg.proposal_pass_threshold = Ratio::new(1, 1).to_string();
+        msg.proposal_slash_threshold = Ratio::new(1, 1).to_string();
+
+        assert_eq!(GovernanceParameters::try_from(msg).unwrap(), params);
+
+        // Without either, the parameters don't decode.
+        let mut msg = pb::GovernanceParameters::from(params);
+        msg.dao_spend_params = None;
+        assert!(GovernanceParameters::try_from(msg).is_err());
+    }
+}
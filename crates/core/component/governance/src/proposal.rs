@@ -1,11 +1,13 @@
 use anyhow::Context;
 use bytes::Bytes;
+use ibc_types::core::client::ClientId;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 use crate::params::GovernanceParameters;
 use penumbra_chain::params::ChainParameters;
 use penumbra_dao::params::DaoParameters;
+use penumbra_dex::DexParameters;
 use penumbra_distributions::params::DistributionsParameters;
 use penumbra_fee::params::FeeParameters;
 use penumbra_ibc::params::IBCParameters;
@@ -67,8 +69,17 @@ impl From<Proposal> for pb::Proposal {
                     }),
                 });
             }
-            ProposalPayload::UpgradePlan { height } => {
-                proposal.upgrade_plan = Some(pb::proposal::UpgradePlan { height });
+            ProposalPayload::UpgradePlan { height, name } => {
+                proposal.upgrade_plan = Some(pb::proposal::UpgradePlan { height, name });
+            }
+            ProposalPayload::IbcClientRecovery {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                proposal.ibc_client_recovery = Some(pb::proposal::IbcClientRecovery {
+                    subject_client_id: subject_client_id.to_string(),
+                    substitute_client_id: substitute_client_id.to_string(),
+                });
             }
         }
         proposal
@@ -128,6 +139,16 @@ impl TryFrom<pb::Proposal> for Proposal {
             } else if let Some(upgrade_plan) = inner.upgrade_plan {
                 ProposalPayload::UpgradePlan {
                     height: upgrade_plan.height,
+                    name: upgrade_plan.name,
+                }
+            } else if let Some(ibc_client_recovery) = inner.ibc_client_recovery {
+                ProposalPayload::IbcClientRecovery {
+                    subject_client_id: ClientId::from_str(&ibc_client_recovery.subject_client_id)
+                        .context("invalid subject client id")?,
+                    substitute_client_id: ClientId::from_str(
+                        &ibc_client_recovery.substitute_client_id,
+                    )
+                    .context("invalid substitute client id")?,
                 }
             } else {
                 anyhow::bail!("missing proposal payload or unknown proposal type");
@@ -175,7 +196,7 @@ impl TryFrom<ProposalToml> for Proposal {
 }
 
 /// The specific kind of a proposal.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "clap", derive(clap::Subcommand))]
 pub enum ProposalKind {
     /// A signaling proposal.
@@ -193,6 +214,9 @@ pub enum ProposalKind {
     /// An upgrade proposal.
     #[cfg_attr(feature = "clap", clap(display_order = 500))]
     UpgradePlan,
+    /// An IBC client recovery proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 600))]
+    IbcClientRecovery,
 }
 
 impl FromStr for ProposalKind {
@@ -204,22 +228,42 @@ impl FromStr for ProposalKind {
             "emergency" => Ok(ProposalKind::Emergency),
             "parameterchange" => Ok(ProposalKind::ParameterChange),
             "daospend" => Ok(ProposalKind::DaoSpend),
-            "upgrade_plan" => Ok(ProposalKind::UpgradePlan),
+            "upgradeplan" => Ok(ProposalKind::UpgradePlan),
+            "ibcclientrecovery" => Ok(ProposalKind::IbcClientRecovery),
             _ => Err(anyhow::anyhow!("invalid proposal kind: {}", s)),
         }
     }
 }
 
+impl std::fmt::Display for ProposalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProposalKind::Signaling => write!(f, "signaling"),
+            ProposalKind::Emergency => write!(f, "emergency"),
+            ProposalKind::ParameterChange => write!(f, "parameter change"),
+            ProposalKind::DaoSpend => write!(f, "DAO spend"),
+            ProposalKind::UpgradePlan => write!(f, "upgrade plan"),
+            ProposalKind::IbcClientRecovery => write!(f, "IBC client recovery"),
+        }
+    }
+}
+
+impl ProposalKind {
+    /// Every kind of proposal.
+    pub const ALL: [ProposalKind; 6] = [
+        ProposalKind::Signaling,
+        ProposalKind::Emergency,
+        ProposalKind::ParameterChange,
+        ProposalKind::DaoSpend,
+        ProposalKind::UpgradePlan,
+        ProposalKind::IbcClientRecovery,
+    ];
+}
+
 impl Proposal {
     /// Get the kind of a proposal.
     pub fn kind(&self) -> ProposalKind {
-        match self.payload {
-            ProposalPayload::Signaling { .. } => ProposalKind::Signaling,
-            ProposalPayload::Emergency { .. } => ProposalKind::Emergency,
-            ProposalPayload::ParameterChange { .. } => ProposalKind::ParameterChange,
-            ProposalPayload::DaoSpend { .. } => ProposalKind::DaoSpend,
-            ProposalPayload::UpgradePlan { .. } => ProposalKind::UpgradePlan,
-        }
+        self.payload.kind()
     }
 }
 
@@ -266,7 +310,20 @@ pub enum ProposalPayload {
     },
     /// An upgrade plan proposal describes a planned upgrade to the chain. If ratified, the chain
     /// will halt at the specified height, trigger an epoch transition, and halt the chain.
-    UpgradePlan { height: u64 },
+    ///
+    /// The `name` selects the migration `pd migrate` applies to the halted chain state.
+    UpgradePlan { height: u64, name: String },
+    /// An IBC client recovery proposal replaces the state of a frozen or expired IBC client with
+    /// that of an active client tracking the same counterparty chain, following the semantics of
+    /// ibc-go's `MsgRecoverClient`.
+    ///
+    /// If ratified, connections and channels built on the subject client can resume using it.
+    IbcClientRecovery {
+        /// The client to be recovered, which must be frozen or expired.
+        subject_client_id: ClientId,
+        /// The client whose state is substituted into the subject, which must be active.
+        substitute_client_id: ClientId,
+    },
 }
 
 /// A TOML-serializable version of `ProposalPayload`, meant for human consumption.
@@ -288,6 +345,11 @@ pub enum ProposalPayloadToml {
     },
     UpgradePlan {
         height: u64,
+        name: String,
+    },
+    IbcClientRecovery {
+        subject_client_id: String,
+        substitute_client_id: String,
     },
 }
 
@@ -310,7 +372,18 @@ impl TryFrom<ProposalPayloadToml> for ProposalPayload {
                 )
                 .to_vec(),
             },
-            ProposalPayloadToml::UpgradePlan { height } => ProposalPayload::UpgradePlan { height },
+            ProposalPayloadToml::UpgradePlan { height, name } => {
+                ProposalPayload::UpgradePlan { height, name }
+            }
+            ProposalPayloadToml::IbcClientRecovery {
+                subject_client_id,
+                substitute_client_id,
+            } => ProposalPayload::IbcClientRecovery {
+                subject_client_id: ClientId::from_str(&subject_client_id)
+                    .context("invalid subject client id")?,
+                substitute_client_id: ClientId::from_str(&substitute_client_id)
+                    .context("invalid substitute client id")?,
+            },
         })
     }
 }
@@ -331,12 +404,33 @@ impl From<ProposalPayload> for ProposalPayloadToml {
                     transaction_plan,
                 ),
             },
-            ProposalPayload::UpgradePlan { height } => ProposalPayloadToml::UpgradePlan { height },
+            ProposalPayload::UpgradePlan { height, name } => {
+                ProposalPayloadToml::UpgradePlan { height, name }
+            }
+            ProposalPayload::IbcClientRecovery {
+                subject_client_id,
+                substitute_client_id,
+            } => ProposalPayloadToml::IbcClientRecovery {
+                subject_client_id: subject_client_id.to_string(),
+                substitute_client_id: substitute_client_id.to_string(),
+            },
         }
     }
 }
 
 impl ProposalPayload {
+    /// Get the kind of a proposal payload.
+    pub fn kind(&self) -> ProposalKind {
+        match self {
+            ProposalPayload::Signaling { .. } => ProposalKind::Signaling,
+            ProposalPayload::Emergency { .. } => ProposalKind::Emergency,
+            ProposalPayload::ParameterChange { .. } => ProposalKind::ParameterChange,
+            ProposalPayload::DaoSpend { .. } => ProposalKind::DaoSpend,
+            ProposalPayload::UpgradePlan { .. } => ProposalKind::UpgradePlan,
+            ProposalPayload::IbcClientRecovery { .. } => ProposalKind::IbcClientRecovery,
+        }
+    }
+
     pub fn is_signaling(&self) -> bool {
         matches!(self, ProposalPayload::Signaling { .. })
     }
@@ -352,6 +446,10 @@ impl ProposalPayload {
     pub fn is_dao_spend(&self) -> bool {
         matches!(self, ProposalPayload::DaoSpend { .. })
     }
+
+    pub fn is_ibc_client_recovery(&self) -> bool {
+        matches!(self, ProposalPayload::IbcClientRecovery { .. })
+    }
 }
 
 /// Indicates which app parameters have changed during the
@@ -367,6 +465,7 @@ impl ProposalPayload {
 pub struct ChangedAppParameters {
     pub chain_params: Option<ChainParameters>,
     pub dao_params: Option<DaoParameters>,
+    pub dex_params: Option<DexParameters>,
     pub distributions_params: Option<DistributionsParameters>,
     pub ibc_params: Option<IBCParameters>,
     pub stake_params: Option<StakeParameters>,
@@ -385,6 +484,7 @@ impl TryFrom<pb::ChangedAppParameters> for ChangedAppParameters {
         Ok(ChangedAppParameters {
             chain_params: msg.chain_params.map(TryInto::try_into).transpose()?,
             dao_params: msg.dao_params.map(TryInto::try_into).transpose()?,
+            dex_params: msg.dex_params.map(TryInto::try_into).transpose()?,
             distributions_params: msg
                 .distributions_params
                 .map(TryInto::try_into)
@@ -402,6 +502,7 @@ impl From<ChangedAppParameters> for pb::ChangedAppParameters {
         pb::ChangedAppParameters {
             chain_params: params.chain_params.map(Into::into),
             dao_params: params.dao_params.map(Into::into),
+            dex_params: params.dex_params.map(Into::into),
             distributions_params: params.distributions_params.map(Into::into),
             fee_params: params.fee_params.map(Into::into),
             governance_params: params.governance_params.map(Into::into),
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use penumbra_proto::{penumbra::core::component::governance::v1alpha1 as pb, DomainType};
 
 use crate::{
-    params::GovernanceParameters,
+    params::ProposalParameters,
     proposal_state::{Outcome as StateOutcome, Withdrawn},
     vote::Vote,
 };
@@ -135,12 +135,12 @@ impl<T> From<Outcome> for StateOutcome<T> {
 }
 
 impl Tally {
-    fn meets_quorum(&self, total_voting_power: u64, params: &GovernanceParameters) -> bool {
-        Ratio::new(self.total(), total_voting_power) >= params.proposal_valid_quorum
+    fn meets_quorum(&self, total_voting_power: u64, params: &ProposalParameters) -> bool {
+        Ratio::new(self.total(), total_voting_power) >= params.valid_quorum
     }
 
-    fn slashed(&self, params: &GovernanceParameters) -> bool {
-        Ratio::new(self.no, self.total()) > params.proposal_slash_threshold
+    fn slashed(&self, params: &ProposalParameters) -> bool {
+        Ratio::new(self.no, self.total()) > params.slash_threshold
     }
 
     fn yes_ratio(&self) -> Ratio {
@@ -150,7 +150,7 @@ impl Tally {
         // desired in that situation
     }
 
-    pub fn outcome(self, total_voting_power: u64, params: &GovernanceParameters) -> Outcome {
+    pub fn outcome(self, total_voting_power: u64, params: &ProposalParameters) -> Outcome {
         use Outcome::*;
 
         // Check to see if we've met quorum
@@ -164,14 +164,14 @@ impl Tally {
         }
 
         // Now that we've checked for slash and quorum, we can just check to see if it should pass
-        if self.yes_ratio() > params.proposal_pass_threshold {
+        if self.yes_ratio() > params.pass_threshold {
             Pass
         } else {
             Fail
         }
     }
 
-    pub fn emergency_pass(self, total_voting_power: u64, params: &GovernanceParameters) -> bool {
+    pub fn emergency_pass(self, total_voting_power: u64, params: &ProposalParameters) -> bool {
         // Check to see if we've met quorum
         if !self.meets_quorum(total_voting_power, params) {
             return false;
@@ -187,3 +187,27 @@ impl Tally {
         Ratio::new(self.yes, total_voting_power) > Ratio::new(2, 3)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{params::GovernanceParameters, ProposalKind};
+
+    #[test]
+    fn outcome_uses_parameters_for_kind() {
+        let mut params = GovernanceParameters::default();
+        params.signaling.valid_quorum = Ratio::new(1, 5);
+        // 30% turnout, all yes: enough for the lowered signaling quorum, but not for the
+        // default quorum of upgrade plans.
+        let tally = Tally::from((Vote::Yes, 30));
+
+        assert_eq!(
+            tally.outcome(100, params.for_kind(ProposalKind::Signaling)),
+            Outcome::Pass
+        );
+        assert_eq!(
+            tally.outcome(100, params.for_kind(ProposalKind::UpgradePlan)),
+            Outcome::Fail
+        );
+    }
+}
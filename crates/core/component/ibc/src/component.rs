@@ -15,8 +15,10 @@ mod proof_verification;
 mod view;
 
 pub mod app_handler;
+pub mod client_recovery;
 pub mod ibc_action_with_handler;
 pub mod packet;
+pub mod rate_limit;
 pub mod state_key;
 
 use msg_handler::MsgHandler;
@@ -0,0 +1,461 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use ibc_types::core::client::ClientId;
+use ibc_types::lightclients::tendermint::{
+    client_state::ClientState as TendermintClientState,
+    consensus_state::ConsensusState as TendermintConsensusState,
+};
+use ibc_types::path::ClientConsensusStatePath;
+use penumbra_chain::component::StateReadExt as _;
+use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_storage::{StateRead, StateWrite};
+
+use crate::component::client::{StateReadExt as _, StateWriteExt as _};
+use crate::component::client_counter::VerifiedHeights;
+use crate::prefix::MerklePrefixExt;
+use crate::IBC_COMMITMENT_PREFIX;
+
+use super::state_key;
+
+/// The status of an IBC client, as relevant to its recovery.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClientStatus {
+    /// The client can be updated and used to verify proofs.
+    Active,
+    /// The client was frozen after evidence of misbehaviour was submitted.
+    Frozen,
+    /// The client's latest consensus state is older than its trusting period.
+    Expired,
+}
+
+impl std::fmt::Display for ClientStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientStatus::Active => write!(f, "active"),
+            ClientStatus::Frozen => write!(f, "frozen"),
+            ClientStatus::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+/// A validated recovery of a subject client from a substitute client, ready to be written.
+#[derive(Clone, Debug)]
+pub struct ClientRecovery {
+    /// The client being recovered.
+    pub subject_client_id: ClientId,
+    /// The client whose state is substituted into the subject.
+    pub substitute_client_id: ClientId,
+    /// The recovered client state of the subject.
+    pub client_state: TendermintClientState,
+    /// The substitute's consensus state at its latest height.
+    pub consensus_state: TendermintConsensusState,
+}
+
+#[async_trait]
+pub trait ClientRecoveryRead: StateRead {
+    /// Returns the status of the client with the given id.
+    async fn client_status(&self, client_id: &ClientId) -> Result<ClientStatus> {
+        let client_state = self.get_client_state(client_id).await?;
+        if client_state.is_frozen() {
+            return Ok(ClientStatus::Frozen);
+        }
+
+        let latest_consensus_state = self
+            .get_verified_consensus_state(client_state.latest_height(), client_id.clone())
+            .await?;
+        let now = self.get_block_timestamp().await?;
+        // A consensus state from the future has not yet expired.
+        let time_elapsed = now
+            .duration_since(latest_consensus_state.timestamp)
+            .unwrap_or_default();
+
+        if client_state.expired(time_elapsed) {
+            Ok(ClientStatus::Expired)
+        } else {
+            Ok(ClientStatus::Active)
+        }
+    }
+
+    /// Checks that the subject client can be recovered from the substitute client, following the
+    /// semantics of ibc-go's `MsgRecoverClient`, returning the recovery to be written.
+    ///
+    /// The subject must be frozen or expired and the substitute must be active. Both must track
+    /// the same counterparty chain, so their client states may differ only in their latest
+    /// height, frozen height, trusting period and chain id, and the substitute must be ahead of
+    /// the subject.
+    async fn check_client_recovery(
+        &self,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+    ) -> Result<ClientRecovery> {
+        anyhow::ensure!(
+            subject_client_id != substitute_client_id,
+            "subject and substitute clients must differ, but both are {subject_client_id}"
+        );
+
+        let subject_type = self.get_client_type(subject_client_id).await?;
+        let substitute_type = self.get_client_type(substitute_client_id).await?;
+        anyhow::ensure!(
+            subject_type == substitute_type,
+            "subject client type {} does not match substitute client type {}",
+            subject_type,
+            substitute_type,
+        );
+
+        let subject_status = self.client_status(subject_client_id).await?;
+        anyhow::ensure!(
+            subject_status != ClientStatus::Active,
+            "subject client {subject_client_id} is active, and only frozen or expired clients can be recovered"
+        );
+        let substitute_status = self.client_status(substitute_client_id).await?;
+        anyhow::ensure!(
+            substitute_status == ClientStatus::Active,
+            "substitute client {substitute_client_id} is {substitute_status}, but must be active"
+        );
+
+        let subject = self.get_client_state(subject_client_id).await?;
+        let substitute = self.get_client_state(substitute_client_id).await?;
+
+        // Ignoring the fields a recovery is allowed to change, the substitute must be identical to
+        // the subject. `allow_update` is ignored too, as ibc-go no longer uses it.
+        let mut normalized_substitute = substitute.clone();
+        normalized_substitute.latest_height = subject.latest_height;
+        normalized_substitute.frozen_height = subject.frozen_height;
+        normalized_substitute.trusting_period = subject.trusting_period;
+        normalized_substitute.chain_id = subject.chain_id.clone();
+        normalized_substitute.allow_update = subject.allow_update;
+        anyhow::ensure!(
+            normalized_substitute == subject,
+            "substitute client {substitute_client_id} does not match subject client {subject_client_id}"
+        );
+
+        anyhow::ensure!(
+            substitute.latest_height > subject.latest_height,
+            "substitute client height {} must be greater than subject client height {}",
+            substitute.latest_height,
+            subject.latest_height,
+        );
+
+        let consensus_state = self
+            .get_verified_consensus_state(substitute.latest_height, substitute_client_id.clone())
+            .await?;
+
+        let mut client_state = subject;
+        client_state.latest_height = substitute.latest_height;
+        client_state.chain_id = substitute.chain_id;
+        client_state.trusting_period = substitute.trusting_period;
+        client_state.frozen_height = None;
+
+        Ok(ClientRecovery {
+            subject_client_id: subject_client_id.clone(),
+            substitute_client_id: substitute_client_id.clone(),
+            client_state,
+            consensus_state,
+        })
+    }
+}
+
+impl<T: StateRead + ?Sized> ClientRecoveryRead for T {}
+
+#[async_trait]
+pub trait ClientRecoveryWrite: StateWrite {
+    /// Recovers the subject client from the substitute client, as checked by
+    /// `check_client_recovery`.
+    ///
+    /// The subject takes on the substitute's consensus state at its latest height, along with
+    /// the height and time at which the substitute processed it, and is unfrozen. Nothing is
+    /// written if the recovery is invalid.
+    async fn recover_client(
+        &mut self,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+    ) -> Result<()> {
+        let ClientRecovery {
+            subject_client_id,
+            substitute_client_id,
+            client_state,
+            consensus_state,
+        } = self
+            .check_client_recovery(subject_client_id, substitute_client_id)
+            .await?;
+
+        let height = client_state.latest_height;
+        let processed_time = self
+            .get_proto::<u64>(&state_key::client_processed_times(
+                &substitute_client_id,
+                &height,
+            ))
+            .await?
+            .context("missing processed time of substitute consensus state")?;
+        let processed_height = self
+            .get_client_update_height(&substitute_client_id, &height)
+            .await?;
+
+        self.put(
+            IBC_COMMITMENT_PREFIX.apply_string(
+                ClientConsensusStatePath::new(&subject_client_id, &height).to_string(),
+            ),
+            consensus_state,
+        );
+        self.put_proto::<u64>(
+            state_key::client_processed_times(&subject_client_id, &height),
+            processed_time,
+        );
+        self.put(
+            state_key::client_processed_heights(&subject_client_id, &height),
+            processed_height,
+        );
+
+        let mut verified_heights = self
+            .get_verified_heights(&subject_client_id)
+            .await?
+            .unwrap_or(VerifiedHeights {
+                heights: Vec::new(),
+            });
+        verified_heights.heights.push(height);
+        self.put_verified_heights(&subject_client_id, verified_heights);
+
+        self.put_client(&subject_client_id, client_state);
+
+        tracing::info!(
+            %subject_client_id,
+            %substitute_client_id,
+            %height,
+            "recovered ibc client"
+        );
+
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> ClientRecoveryWrite for T {}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use ibc_types::core::client::{msgs::MsgCreateClient, Height};
+    use ibc_types::DomainType;
+    use penumbra_chain::component::StateWriteExt as _;
+    use penumbra_storage::{Snapshot, StateDelta, TempStorage};
+    use tendermint::Time;
+
+    use super::*;
+    use crate::component::client_counter::ics02_validation;
+
+    const SUBJECT: &str = "07-tendermint-0";
+    const SUBSTITUTE: &str = "07-tendermint-1";
+
+    fn now() -> Time {
+        Time::parse_from_rfc3339("2022-02-11T17:30:50.425417198Z").unwrap()
+    }
+
+    /// The client and consensus states of the Stargaze light client used in the IBC component
+    /// tests, with the consensus state's timestamp set to the current block time.
+    fn stargaze_client() -> (TendermintClientState, TendermintConsensusState) {
+        let msg_create_client_raw =
+            base64::decode(include_str!("./test/create_client.msg").replace('\n', "")).unwrap();
+        let msg_create_client = MsgCreateClient::decode(msg_create_client_raw.as_slice()).unwrap();
+        let client_state =
+            ics02_validation::get_tendermint_client_state(msg_create_client.client_state).unwrap();
+        let mut consensus_state =
+            ics02_validation::get_tendermint_consensus_state(msg_create_client.consensus_state)
+                .unwrap();
+        consensus_state.timestamp = now();
+        (client_state, consensus_state)
+    }
+
+    async fn put_client<S: StateWrite>(
+        state: &mut S,
+        client_id: &ClientId,
+        client_state: TendermintClientState,
+        consensus_state: TendermintConsensusState,
+    ) -> Result<()> {
+        let height = client_state.latest_height;
+        state.put_client(client_id, client_state);
+        state
+            .put_verified_consensus_state(height, client_id.clone(), consensus_state)
+            .await
+    }
+
+    /// A state with a subject client modified by `subject`, and an active substitute client
+    /// ahead of it.
+    async fn recovery_state(
+        storage: &TempStorage,
+        subject: impl FnOnce(&mut TendermintClientState, &mut TendermintConsensusState),
+    ) -> Result<StateDelta<Snapshot>> {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(1);
+        state.put_block_timestamp(now());
+
+        let (mut subject_state, mut subject_consensus_state) = stargaze_client();
+        subject(&mut subject_state, &mut subject_consensus_state);
+        put_client(
+            &mut state,
+            &ClientId::from_str(SUBJECT)?,
+            subject_state,
+            subject_consensus_state,
+        )
+        .await?;
+
+        let (mut substitute_state, substitute_consensus_state) = stargaze_client();
+        substitute_state.latest_height = Height::new(
+            substitute_state.latest_height.revision_number(),
+            substitute_state.latest_height.revision_height() + 10,
+        )?;
+        put_client(
+            &mut state,
+            &ClientId::from_str(SUBSTITUTE)?,
+            substitute_state,
+            substitute_consensus_state,
+        )
+        .await?;
+
+        Ok(state)
+    }
+
+    fn expire(
+        client_state: &mut TendermintClientState,
+        consensus_state: &mut TendermintConsensusState,
+    ) {
+        consensus_state.timestamp = now()
+            .checked_sub(client_state.trusting_period + Duration::from_secs(1))
+            .unwrap();
+    }
+
+    async fn assert_recovered(storage: &TempStorage) -> Result<()> {
+        let state = storage.latest_snapshot();
+        let subject_client_id = ClientId::from_str(SUBJECT)?;
+        let substitute_client_id = ClientId::from_str(SUBSTITUTE)?;
+
+        let subject = state.get_client_state(&subject_client_id).await?;
+        let substitute = state.get_client_state(&substitute_client_id).await?;
+        assert_eq!(subject, substitute);
+        assert_eq!(
+            state.client_status(&subject_client_id).await?,
+            ClientStatus::Active
+        );
+
+        let height = substitute.latest_height;
+        assert_eq!(
+            state
+                .get_verified_consensus_state(height, subject_client_id.clone())
+                .await?,
+            state
+                .get_verified_consensus_state(height, substitute_client_id.clone())
+                .await?,
+        );
+        assert_eq!(
+            state
+                .get_client_update_height(&subject_client_id, &height)
+                .await?,
+            state
+                .get_client_update_height(&substitute_client_id, &height)
+                .await?,
+        );
+        assert!(state
+            .get_verified_heights(&subject_client_id)
+            .await?
+            .unwrap()
+            .heights
+            .contains(&height));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn expired_client_is_recovered() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = recovery_state(&storage, expire).await?;
+        let subject_client_id = ClientId::from_str(SUBJECT)?;
+        assert_eq!(
+            state.client_status(&subject_client_id).await?,
+            ClientStatus::Expired
+        );
+
+        state
+            .recover_client(&subject_client_id, &ClientId::from_str(SUBSTITUTE)?)
+            .await?;
+        storage.commit(state).await?;
+
+        assert_recovered(&storage).await
+    }
+
+    #[tokio::test]
+    async fn frozen_client_is_recovered() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = recovery_state(&storage, |client_state, _| {
+            *client_state = client_state
+                .clone()
+                .with_frozen_height(client_state.latest_height);
+        })
+        .await?;
+        let subject_client_id = ClientId::from_str(SUBJECT)?;
+        assert_eq!(
+            state.client_status(&subject_client_id).await?,
+            ClientStatus::Frozen
+        );
+
+        state
+            .recover_client(&subject_client_id, &ClientId::from_str(SUBSTITUTE)?)
+            .await?;
+        storage.commit(state).await?;
+
+        assert_recovered(&storage).await
+    }
+
+    #[tokio::test]
+    async fn active_client_is_not_recovered() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = recovery_state(&storage, |_, _| {}).await?;
+        let subject_client_id = ClientId::from_str(SUBJECT)?;
+        let before = state.get_client_state(&subject_client_id).await?;
+
+        assert!(state
+            .recover_client(&subject_client_id, &ClientId::from_str(SUBSTITUTE)?)
+            .await
+            .is_err());
+        assert_eq!(state.get_client_state(&subject_client_id).await?, before);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mismatched_substitute_is_rejected() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = recovery_state(&storage, |client_state, consensus_state| {
+            expire(client_state, consensus_state);
+            client_state.unbonding_period += Duration::from_secs(1);
+        })
+        .await?;
+        let subject_client_id = ClientId::from_str(SUBJECT)?;
+        let substitute_client_id = ClientId::from_str(SUBSTITUTE)?;
+
+        assert!(state
+            .recover_client(&subject_client_id, &substitute_client_id)
+            .await
+            .is_err());
+        // A client can't be recovered from itself, either.
+        assert!(state
+            .recover_client(&subject_client_id, &subject_client_id)
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn substitute_must_be_ahead_of_subject() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = recovery_state(&storage, expire).await?;
+
+        // Recovering the substitute from the (now expired) subject fails both because the
+        // substitute is active and because it is ahead of the subject.
+        assert!(state
+            .recover_client(
+                &ClientId::from_str(SUBSTITUTE)?,
+                &ClientId::from_str(SUBJECT)?
+            )
+            .await
+            .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,363 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::TryStreamExt;
+use ibc_types::core::channel::ChannelId;
+use penumbra_asset::asset;
+use penumbra_chain::component::StateReadExt as _;
+use penumbra_num::Amount;
+use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_storage::{StateRead, StateWrite};
+
+use crate::{Ics20FlowUsage, Ics20RateLimit};
+
+use super::{state_key, StateReadExt as _};
+
+/// The direction of an ICS-20 transfer, relative to Penumbra.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Flow {
+    /// Tokens received over a channel.
+    Inflow,
+    /// Tokens sent over a channel.
+    Outflow,
+}
+
+#[async_trait]
+pub trait Ics20RateLimitRead: StateRead {
+    /// Returns the rate limit on the flow of `asset_id` over `channel_id`, along with its usage
+    /// in the current window, if there is one.
+    ///
+    /// The usage is `None` if nothing has flowed within the current window.
+    async fn ics20_rate_limit_usage(
+        &self,
+        channel_id: &ChannelId,
+        asset_id: &asset::Id,
+    ) -> Result<Option<(Ics20RateLimit, Option<Ics20FlowUsage>)>> {
+        let Some(limit) = self
+            .get_ibc_params()
+            .await?
+            .ics20_rate_limit(channel_id, asset_id)
+            .cloned()
+        else {
+            return Ok(None);
+        };
+        let usage = self.current_flow_usage(&limit).await?;
+        Ok(Some((limit, usage)))
+    }
+
+    /// Returns the first height of the current window of `limit`, which spans the last
+    /// `window_blocks` blocks, up to and including the current one.
+    async fn current_flow_window_start(&self, limit: &Ics20RateLimit) -> Result<u64> {
+        let height = self.get_block_height().await?;
+        Ok(height.saturating_add(1).saturating_sub(limit.window_blocks))
+    }
+
+    /// Returns the per-block flows of the asset over the channel of `limit`, keyed by their
+    /// state key and ordered by height, for every block that may still be within a window.
+    ///
+    /// Each bucket is the usage of a window of a single block, starting at its height. Buckets
+    /// that have slid out of the window are pruned when the next flow is recorded, so the result
+    /// may include some of those.
+    async fn ics20_flow_buckets(
+        &self,
+        limit: &Ics20RateLimit,
+    ) -> Result<Vec<(String, Ics20FlowUsage)>> {
+        self.prefix(&state_key::ics20_flow_buckets(
+            &limit.channel_id,
+            &limit.asset_id,
+        ))
+        .try_collect()
+        .await
+    }
+
+    /// Returns the usage of `limit` in its current window, summing the flows of every block
+    /// within it, if anything has flowed within it.
+    async fn current_flow_usage(&self, limit: &Ics20RateLimit) -> Result<Option<Ics20FlowUsage>> {
+        let window_start_height = self.current_flow_window_start(limit).await?;
+        let mut usage: Option<Ics20FlowUsage> = None;
+        for (_, bucket) in self.ics20_flow_buckets(limit).await? {
+            if bucket.window_start_height < window_start_height {
+                continue;
+            }
+            let usage = usage.get_or_insert_with(|| Ics20FlowUsage::new(window_start_height));
+            usage.inflow = usage.inflow.saturating_add(&bucket.inflow);
+            usage.outflow = usage.outflow.saturating_add(&bucket.outflow);
+        }
+        Ok(usage)
+    }
+
+    /// Checks that a flow of `amount` of `asset_id` over `channel_id` would not take the net flow
+    /// in that direction over the channel's rate limit, returning the usage of the limit
+    /// including the flow.
+    ///
+    /// The window slides with every block: it always covers the last `window_blocks` blocks, so
+    /// the net flow over any `window_blocks` consecutive blocks stays within the limit.
+    async fn check_ics20_flow(
+        &self,
+        channel_id: &ChannelId,
+        asset_id: &asset::Id,
+        flow: Flow,
+        amount: Amount,
+    ) -> Result<Option<Ics20FlowUsage>> {
+        let Some((limit, usage)) = self.ics20_rate_limit_usage(channel_id, asset_id).await? else {
+            return Ok(None);
+        };
+        let mut usage = match usage {
+            Some(usage) => usage,
+            None => Ics20FlowUsage::new(self.current_flow_window_start(&limit).await?),
+        };
+
+        match flow {
+            Flow::Inflow => {
+                usage.inflow = usage.inflow.saturating_add(&amount);
+                if usage.net_inflow() > limit.max_net_inflow {
+                    anyhow::bail!(
+                        "ics20 rate limit exceeded: net inflow of {} over {} would be {}, above the limit of {}",
+                        asset_id,
+                        channel_id,
+                        usage.net_inflow(),
+                        limit.max_net_inflow
+                    );
+                }
+            }
+            Flow::Outflow => {
+                usage.outflow = usage.outflow.saturating_add(&amount);
+                if usage.net_outflow() > limit.max_net_outflow {
+                    anyhow::bail!(
+                        "ics20 rate limit exceeded: net outflow of {} over {} would be {}, above the limit of {}",
+                        asset_id,
+                        channel_id,
+                        usage.net_outflow(),
+                        limit.max_net_outflow
+                    );
+                }
+            }
+        }
+
+        Ok(Some(usage))
+    }
+}
+
+impl<T: StateRead + ?Sized> Ics20RateLimitRead for T {}
+
+#[async_trait]
+pub trait Ics20RateLimitWrite: StateWrite {
+    /// Records a flow of `amount` of `asset_id` over `channel_id`, failing if it would take the
+    /// net flow in that direction over the channel's rate limit.
+    ///
+    /// The flow is added to the bucket of the current block, and the buckets of blocks that have
+    /// slid out of the window are pruned.
+    async fn record_ics20_flow(
+        &mut self,
+        channel_id: &ChannelId,
+        asset_id: &asset::Id,
+        flow: Flow,
+        amount: Amount,
+    ) -> Result<()> {
+        if self
+            .check_ics20_flow(channel_id, asset_id, flow, amount)
+            .await?
+            .is_none()
+        {
+            return Ok(());
+        }
+        let Some(limit) = self
+            .get_ibc_params()
+            .await?
+            .ics20_rate_limit(channel_id, asset_id)
+            .cloned()
+        else {
+            return Ok(());
+        };
+
+        let height = self.get_block_height().await?;
+        let window_start_height = self.current_flow_window_start(&limit).await?;
+        let mut bucket = Ics20FlowUsage::new(height);
+        for (key, existing) in self.ics20_flow_buckets(&limit).await? {
+            if existing.window_start_height < window_start_height {
+                self.delete(key);
+            } else if existing.window_start_height == height {
+                bucket = existing;
+            }
+        }
+
+        match flow {
+            Flow::Inflow => bucket.inflow = bucket.inflow.saturating_add(&amount),
+            Flow::Outflow => bucket.outflow = bucket.outflow.saturating_add(&amount),
+        }
+        self.put(
+            state_key::ics20_flow_bucket(channel_id, asset_id, height),
+            bucket,
+        );
+        Ok(())
+    }
+
+    /// Reverses a flow recorded with `record_ics20_flow`, for a transfer that was refunded.
+    ///
+    /// The flow is deducted from the buckets within the current window, most recent first and
+    /// saturating at zero, so that the usage of the window drops by at most the flow.
+    async fn revert_ics20_flow(
+        &mut self,
+        channel_id: &ChannelId,
+        asset_id: &asset::Id,
+        flow: Flow,
+        amount: Amount,
+    ) -> Result<()> {
+        let Some(limit) = self
+            .get_ibc_params()
+            .await?
+            .ics20_rate_limit(channel_id, asset_id)
+            .cloned()
+        else {
+            return Ok(());
+        };
+
+        let window_start_height = self.current_flow_window_start(&limit).await?;
+        let mut remaining = amount;
+        for (key, mut bucket) in self.ics20_flow_buckets(&limit).await?.into_iter().rev() {
+            if remaining == Amount::zero() || bucket.window_start_height < window_start_height {
+                break;
+            }
+            let recorded = match flow {
+                Flow::Inflow => &mut bucket.inflow,
+                Flow::Outflow => &mut bucket.outflow,
+            };
+            let deducted = std::cmp::min(*recorded, remaining);
+            *recorded = recorded.saturating_sub(&deducted);
+            remaining = remaining.saturating_sub(&deducted);
+            self.put(key, bucket);
+        }
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> Ics20RateLimitWrite for T {}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_chain::component::StateWriteExt as _;
+    use penumbra_storage::StateDelta;
+
+    use super::*;
+    use crate::{component::StateWriteExt as _, params::IBCParameters};
+
+    fn channel() -> ChannelId {
+        ChannelId::new(0)
+    }
+
+    fn asset_id() -> asset::Id {
+        *STAKING_TOKEN_ASSET_ID
+    }
+
+    /// A state limiting the net flow of upenumbra over the channel to 150 per 10 blocks.
+    fn rate_limited_state() -> StateDelta<()> {
+        let mut state = StateDelta::new(());
+        state.put_ibc_params(IBCParameters {
+            ics20_rate_limits: vec![Ics20RateLimit {
+                channel_id: channel(),
+                asset_id: asset_id(),
+                max_net_inflow: 150u64.into(),
+                max_net_outflow: 150u64.into(),
+                window_blocks: 10,
+            }],
+            ..Default::default()
+        });
+        state
+    }
+
+    async fn inflow_at(state: &mut StateDelta<()>, height: u64, amount: u64) -> Result<()> {
+        state.put_block_height(height);
+        state
+            .record_ics20_flow(&channel(), &asset_id(), Flow::Inflow, amount.into())
+            .await
+    }
+
+    async fn usage(state: &StateDelta<()>) -> Option<Ics20FlowUsage> {
+        state
+            .ics20_rate_limit_usage(&channel(), &asset_id())
+            .await
+            .expect("can read rate limit usage")
+            .expect("the channel is rate limited")
+            .1
+    }
+
+    #[tokio::test]
+    async fn window_slides_with_every_block() -> Result<()> {
+        let mut state = rate_limited_state();
+
+        // A small flow, then a large one at the end of the first ten blocks.
+        inflow_at(&mut state, 1, 10).await?;
+        inflow_at(&mut state, 10, 140).await?;
+
+        // A window starting at height 11 would allow another full 150 right away, moving twice
+        // the limit across its boundary. The sliding window still holds the flow at height 10.
+        assert!(inflow_at(&mut state, 11, 150).await.is_err());
+        inflow_at(&mut state, 11, 10).await?;
+        let usage = usage(&state).await.expect("flows are within the window");
+        assert_eq!(usage.window_start_height, 2);
+        assert_eq!(usage.inflow, 150u64.into());
+
+        // Once the large flow has slid out of the window, there is room again.
+        assert!(inflow_at(&mut state, 19, 1).await.is_err());
+        inflow_at(&mut state, 20, 140).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn old_buckets_are_pruned() -> Result<()> {
+        let mut state = rate_limited_state();
+        inflow_at(&mut state, 1, 100).await?;
+        inflow_at(&mut state, 5, 50).await?;
+
+        // Nothing has flowed within the last ten blocks.
+        state.put_block_height(15);
+        assert_eq!(usage(&state).await, None);
+
+        inflow_at(&mut state, 15, 10).await?;
+        let limit = state
+            .get_ibc_params()
+            .await?
+            .ics20_rate_limit(&channel(), &asset_id())
+            .cloned()
+            .expect("the channel is rate limited");
+        let buckets = state.ics20_flow_buckets(&limit).await?;
+        assert_eq!(buckets.len(), 1);
+        assert_eq!(buckets[0].1.window_start_height, 15);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reverted_flows_are_deducted_from_the_window() -> Result<()> {
+        let mut state = rate_limited_state();
+        inflow_at(&mut state, 1, 100).await?;
+        inflow_at(&mut state, 2, 30).await?;
+
+        // The refund spans both buckets, most recent first.
+        state
+            .revert_ics20_flow(&channel(), &asset_id(), Flow::Inflow, 50u64.into())
+            .await?;
+        assert_eq!(
+            usage(&state)
+                .await
+                .expect("flows are within the window")
+                .inflow,
+            80u64.into()
+        );
+
+        // Refunds never take the usage below zero.
+        state
+            .revert_ics20_flow(&channel(), &asset_id(), Flow::Inflow, 500u64.into())
+            .await?;
+        assert_eq!(
+            usage(&state)
+                .await
+                .expect("flows are within the window")
+                .inflow,
+            Amount::zero()
+        );
+
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ use tonic::transport::server::Routes;
 mod client_query;
 mod connection_query;
 mod consensus_query;
+mod rate_limit_query;
 
 // TODO: hide and replace with a routes() constructor that
 // bundles up all the internal services
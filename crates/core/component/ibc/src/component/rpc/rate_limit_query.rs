@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+
+use ibc_types::core::channel::ChannelId;
+use penumbra_chain::component::StateReadExt as _;
+use penumbra_proto::core::component::ibc::v1alpha1::{
+    ics20_rate_limits_response::RateLimitUsage, query_service_server::QueryService,
+    Ics20RateLimitsRequest, Ics20RateLimitsResponse,
+};
+use std::str::FromStr;
+use tonic::{Response, Status};
+
+use crate::component::rate_limit::Ics20RateLimitRead;
+use crate::component::StateReadExt as _;
+
+use super::IbcQuery;
+
+#[async_trait]
+impl QueryService for IbcQuery {
+    /// Queries the configured ICS-20 rate limits, along with their usage in the current window.
+    async fn ics20_rate_limits(
+        &self,
+        request: tonic::Request<Ics20RateLimitsRequest>,
+    ) -> std::result::Result<Response<Ics20RateLimitsResponse>, Status> {
+        let snapshot = self.0.latest_snapshot();
+        snapshot
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let channel_id = match request.get_ref().channel_id.as_str() {
+            "" => None,
+            channel_id => Some(
+                ChannelId::from_str(channel_id)
+                    .map_err(|e| Status::invalid_argument(format!("invalid channel id: {e}")))?,
+            ),
+        };
+
+        let limits = snapshot
+            .get_ibc_params()
+            .await
+            .map_err(|e| Status::aborted(format!("couldn't get ibc params: {e}")))?
+            .ics20_rate_limits;
+
+        let mut rate_limits = Vec::new();
+        for limit in limits.into_iter().filter(|limit| match &channel_id {
+            Some(channel_id) => limit.channel_id == *channel_id,
+            None => true,
+        }) {
+            let usage = snapshot
+                .current_flow_usage(&limit)
+                .await
+                .map_err(|e| Status::aborted(format!("couldn't get rate limit usage: {e}")))?;
+            rate_limits.push(RateLimitUsage {
+                rate_limit: Some(limit.into()),
+                usage: usage.map(Into::into),
+            });
+        }
+
+        Ok(Response::new(Ics20RateLimitsResponse { rate_limits }))
+    }
+}
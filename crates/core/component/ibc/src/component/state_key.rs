@@ -26,3 +26,10 @@ pub fn counter() -> &'static str {
 pub fn ics20_value_balance(channel_id: &ChannelId, asset_id: &asset::Id) -> String {
     format!("ibc/ics20-value-balance/{channel_id}/{asset_id}")
 }
+pub fn ics20_flow_buckets(channel_id: &ChannelId, asset_id: &asset::Id) -> String {
+    format!("ibc/ics20-flow-usage/{channel_id}/{asset_id}/")
+}
+pub fn ics20_flow_bucket(channel_id: &ChannelId, asset_id: &asset::Id, height: u64) -> String {
+    // Heights are zero-padded, so that buckets are ordered by height.
+    format!("ibc/ics20-flow-usage/{channel_id}/{asset_id}/{height:020}")
+}
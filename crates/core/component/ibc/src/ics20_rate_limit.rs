@@ -0,0 +1,142 @@
+use std::str::FromStr;
+
+use ibc_types::core::channel::ChannelId;
+use penumbra_asset::asset;
+use penumbra_num::Amount;
+use penumbra_proto::core::component::ibc::v1alpha1 as pb;
+use penumbra_proto::DomainType;
+use serde::{Deserialize, Serialize};
+
+/// The longest window, in blocks, that governance may set for an [`Ics20RateLimit`].
+///
+/// Flows are recorded per block and summed over the window on every transfer, so the cost of
+/// checking a limit grows with its window.
+pub const MAX_ICS20_RATE_LIMIT_WINDOW_BLOCKS: u64 = 17_280;
+
+/// A limit on the net flow of an asset over an ICS-20 channel, within a sliding window of blocks.
+///
+/// Inflows and outflows offset each other: over any `window_blocks` consecutive blocks, the amount
+/// received over the channel may exceed the amount sent over it by at most `max_net_inflow`, and
+/// vice versa.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::Ics20RateLimit", into = "pb::Ics20RateLimit")]
+pub struct Ics20RateLimit {
+    /// The channel the limit applies to.
+    pub channel_id: ChannelId,
+    /// The asset the limit applies to, in its denomination on Penumbra.
+    pub asset_id: asset::Id,
+    /// The most that inflows may exceed outflows by within a window.
+    pub max_net_inflow: Amount,
+    /// The most that outflows may exceed inflows by within a window.
+    pub max_net_outflow: Amount,
+    /// The length of the sliding window, in blocks, at most
+    /// [`MAX_ICS20_RATE_LIMIT_WINDOW_BLOCKS`].
+    pub window_blocks: u64,
+}
+
+impl DomainType for Ics20RateLimit {
+    type Proto = pb::Ics20RateLimit;
+}
+
+impl TryFrom<pb::Ics20RateLimit> for Ics20RateLimit {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::Ics20RateLimit) -> anyhow::Result<Self> {
+        Ok(Ics20RateLimit {
+            channel_id: ChannelId::from_str(&msg.channel_id)?,
+            asset_id: msg
+                .asset_id
+                .ok_or_else(|| anyhow::anyhow!("missing asset_id"))?
+                .try_into()?,
+            max_net_inflow: msg
+                .max_net_inflow
+                .ok_or_else(|| anyhow::anyhow!("missing max_net_inflow"))?
+                .try_into()?,
+            max_net_outflow: msg
+                .max_net_outflow
+                .ok_or_else(|| anyhow::anyhow!("missing max_net_outflow"))?
+                .try_into()?,
+            window_blocks: msg.window_blocks,
+        })
+    }
+}
+
+impl From<Ics20RateLimit> for pb::Ics20RateLimit {
+    fn from(limit: Ics20RateLimit) -> Self {
+        pb::Ics20RateLimit {
+            channel_id: limit.channel_id.to_string(),
+            asset_id: Some(limit.asset_id.into()),
+            max_net_inflow: Some(limit.max_net_inflow.into()),
+            max_net_outflow: Some(limit.max_net_outflow.into()),
+            window_blocks: limit.window_blocks,
+        }
+    }
+}
+
+/// The flow of an asset over an ICS-20 channel within a window of its rate limit.
+///
+/// The flows of each block are recorded as the usage of a window of that single block, and summed
+/// over the blocks of the current window.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::Ics20FlowUsage", into = "pb::Ics20FlowUsage")]
+pub struct Ics20FlowUsage {
+    /// The first height of the window.
+    pub window_start_height: u64,
+    /// The amount received over the channel within the window.
+    pub inflow: Amount,
+    /// The amount sent over the channel within the window.
+    pub outflow: Amount,
+}
+
+impl Ics20FlowUsage {
+    /// A window starting at the given height, with nothing having flowed in either direction.
+    pub fn new(window_start_height: u64) -> Self {
+        Self {
+            window_start_height,
+            inflow: Amount::zero(),
+            outflow: Amount::zero(),
+        }
+    }
+
+    /// The amount by which inflows exceed outflows, if any.
+    pub fn net_inflow(&self) -> Amount {
+        self.inflow.saturating_sub(&self.outflow)
+    }
+
+    /// The amount by which outflows exceed inflows, if any.
+    pub fn net_outflow(&self) -> Amount {
+        self.outflow.saturating_sub(&self.inflow)
+    }
+}
+
+impl DomainType for Ics20FlowUsage {
+    type Proto = pb::Ics20FlowUsage;
+}
+
+impl TryFrom<pb::Ics20FlowUsage> for Ics20FlowUsage {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::Ics20FlowUsage) -> anyhow::Result<Self> {
+        Ok(Ics20FlowUsage {
+            window_start_height: msg.window_start_height,
+            inflow: msg
+                .inflow
+                .ok_or_else(|| anyhow::anyhow!("missing inflow"))?
+                .try_into()?,
+            outflow: msg
+                .outflow
+                .ok_or_else(|| anyhow::anyhow!("missing outflow"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Ics20FlowUsage> for pb::Ics20FlowUsage {
+    fn from(usage: Ics20FlowUsage) -> Self {
+        pb::Ics20FlowUsage {
+            window_start_height: usage.window_start_height,
+            inflow: Some(usage.inflow.into()),
+            outflow: Some(usage.outflow.into()),
+        }
+    }
+}
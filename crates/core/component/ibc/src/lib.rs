@@ -14,6 +14,7 @@ pub use component::ibc_action_with_handler::IbcActionWithHandler;
 pub mod genesis;
 mod ibc_action;
 mod ibc_token;
+mod ics20_rate_limit;
 pub mod params;
 mod version;
 
@@ -22,6 +23,7 @@ pub use prefix::{IBC_COMMITMENT_PREFIX, IBC_PROOF_SPECS, IBC_SUBSTORE_PREFIX};
 
 pub use ibc_action::IbcRelay;
 pub use ibc_token::IbcToken;
+pub use ics20_rate_limit::{Ics20FlowUsage, Ics20RateLimit, MAX_ICS20_RATE_LIMIT_WINDOW_BLOCKS};
 
 #[cfg_attr(docsrs, doc(cfg(feature = "component")))]
 #[cfg(feature = "component")]
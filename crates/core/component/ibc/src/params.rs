@@ -1,7 +1,11 @@
+use ibc_types::core::channel::ChannelId;
+use penumbra_asset::asset;
 use penumbra_proto::core::component::ibc::v1alpha1 as pb;
 use penumbra_proto::DomainType;
 use serde::{Deserialize, Serialize};
 
+use crate::Ics20RateLimit;
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(try_from = "pb::IbcParameters", into = "pb::IbcParameters")]
 pub struct IBCParameters {
@@ -11,6 +15,21 @@ pub struct IBCParameters {
     pub inbound_ics20_transfers_enabled: bool,
     /// Whether outbound ICS-20 transfers are enabled
     pub outbound_ics20_transfers_enabled: bool,
+    /// Limits on the net flow of assets over ICS-20 channels.
+    pub ics20_rate_limits: Vec<Ics20RateLimit>,
+}
+
+impl IBCParameters {
+    /// Returns the rate limit on the flow of `asset_id` over `channel_id`, if there is one.
+    pub fn ics20_rate_limit(
+        &self,
+        channel_id: &ChannelId,
+        asset_id: &asset::Id,
+    ) -> Option<&Ics20RateLimit> {
+        self.ics20_rate_limits
+            .iter()
+            .find(|limit| limit.channel_id == *channel_id && limit.asset_id == *asset_id)
+    }
 }
 
 impl DomainType for IBCParameters {
@@ -25,6 +44,11 @@ impl TryFrom<pb::IbcParameters> for IBCParameters {
             ibc_enabled: msg.ibc_enabled,
             inbound_ics20_transfers_enabled: msg.inbound_ics20_transfers_enabled,
             outbound_ics20_transfers_enabled: msg.outbound_ics20_transfers_enabled,
+            ics20_rate_limits: msg
+                .ics20_rate_limits
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
         })
     }
 }
@@ -35,6 +59,11 @@ impl From<IBCParameters> for pb::IbcParameters {
             ibc_enabled: params.ibc_enabled,
             inbound_ics20_transfers_enabled: params.inbound_ics20_transfers_enabled,
             outbound_ics20_transfers_enabled: params.outbound_ics20_transfers_enabled,
+            ics20_rate_limits: params
+                .ics20_rate_limits
+                .into_iter()
+                .map(Into::into)
+                .collect(),
         }
     }
 }
@@ -45,6 +74,7 @@ impl Default for IBCParameters {
             ibc_enabled: true,
             inbound_ics20_transfers_enabled: true,
             outbound_ics20_transfers_enabled: true,
+            ics20_rate_limits: Vec::new(),
         }
     }
 }
@@ -113,6 +113,32 @@ pub trait SctManager: StateWrite {
 
         Ok((block_root, epoch_root))
     }
+
+    /// Restores the state commitment tree of a state restored from a snapshot.
+    ///
+    /// The tree is received from an untrusted peer, so it is only accepted if it
+    /// is well-formed and its root is the anchor recorded for the current height.
+    async fn restore_state_commitment_tree(&mut self, tree: tct::Tree) -> Result<()> {
+        let height = self.get_block_height().await?;
+        tct::validate::index(&tree)?;
+        tct::validate::cached_hashes(&tree)?;
+
+        let anchor = self
+            .anchor_by_height(height)
+            .await?
+            .ok_or_else(|| anyhow!("missing anchor for height {height}"))?;
+        if tree.root() != anchor {
+            return Err(anyhow!(
+                "state commitment tree root {} does not match the anchor {} at height {height}",
+                tree.root(),
+                anchor
+            ));
+        }
+
+        self.put_state_commitment_tree(tree);
+        self.write_state_commitment_tree().await;
+        Ok(())
+    }
 }
 
 impl<T: StateWrite + ?Sized> SctManager for T {}
@@ -3,6 +3,7 @@
 mod action_handler;
 mod metrics;
 mod note_manager;
+mod packet_forward;
 mod shielded_pool;
 mod supply;
 mod transfer;
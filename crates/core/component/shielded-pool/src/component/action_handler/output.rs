@@ -1,27 +1,44 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
 use penumbra_component::ActionHandler;
-use penumbra_proof_params::OUTPUT_PROOF_VERIFICATION_KEY;
+use penumbra_proof_params::{BatchVerifier, OUTPUT_PROOF_VERIFICATION_KEY};
 use penumbra_proto::StateWriteProto as _;
 use penumbra_storage::{StateRead, StateWrite};
 
 use crate::{component::NoteManager, event, Output};
 
+impl Output {
+    /// Checks the output statelessly, like `check_stateless`, except that its proof is
+    /// queued in `batch` instead of being verified, so that it can be verified along
+    /// with the other proofs of the transaction.
+    pub fn check_stateless_batched(&self, batch: &mut BatchVerifier<'static>) -> Result<()> {
+        self.proof
+            .queue(
+                batch,
+                &OUTPUT_PROOF_VERIFICATION_KEY,
+                self.body.balance_commitment,
+                self.body.note_payload.note_commitment,
+            )
+            .context("invalid output proof")
+    }
+}
+
 #[async_trait]
 impl ActionHandler for Output {
     type CheckStatelessContext = ();
     async fn check_stateless(&self, _context: ()) -> Result<()> {
         let output = self;
 
-        output.proof.verify(
-            &OUTPUT_PROOF_VERIFICATION_KEY,
-            output.body.balance_commitment,
-            output.body.note_payload.note_commitment,
-        )?;
-
-        Ok(())
+        output
+            .proof
+            .verify(
+                &OUTPUT_PROOF_VERIFICATION_KEY,
+                output.body.balance_commitment,
+                output.body.note_payload.note_commitment,
+            )
+            .context("an output proof did not verify")
     }
 
     async fn check_stateful<S: StateRead + 'static>(&self, _state: Arc<S>) -> Result<()> {
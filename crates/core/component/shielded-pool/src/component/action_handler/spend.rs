@@ -4,7 +4,7 @@ use anyhow::{Context, Result};
 use async_trait::async_trait;
 use penumbra_chain::TransactionContext;
 use penumbra_component::ActionHandler;
-use penumbra_proof_params::SPEND_PROOF_VERIFICATION_KEY;
+use penumbra_proof_params::{BatchVerifier, SPEND_PROOF_VERIFICATION_KEY};
 use penumbra_proto::StateWriteProto as _;
 use penumbra_storage::{StateRead, StateWrite};
 
@@ -13,17 +13,45 @@ use crate::{
     event, Spend,
 };
 
+impl Spend {
+    /// Checks the spend statelessly, like `check_stateless`, except that its proof is
+    /// queued in `batch` instead of being verified, so that it can be verified along
+    /// with the other proofs of the transaction.
+    pub fn check_stateless_batched(
+        &self,
+        context: &TransactionContext,
+        batch: &mut BatchVerifier<'static>,
+    ) -> Result<()> {
+        self.check_auth_sig(context)?;
+
+        self.proof
+            .queue(
+                batch,
+                &SPEND_PROOF_VERIFICATION_KEY,
+                context.anchor,
+                self.body.balance_commitment,
+                self.body.nullifier,
+                self.body.rk,
+            )
+            .context("invalid spend proof")
+    }
+
+    fn check_auth_sig(&self, context: &TransactionContext) -> Result<()> {
+        // Check spend auth signature using provided spend auth key.
+        self.body
+            .rk
+            .verify(context.effect_hash.as_ref(), &self.auth_sig)
+            .context("spend auth signature failed to verify")
+    }
+}
+
 #[async_trait]
 impl ActionHandler for Spend {
     type CheckStatelessContext = TransactionContext;
     async fn check_stateless(&self, context: TransactionContext) -> Result<()> {
         let spend = self;
         // 2. Check spend auth signature using provided spend auth key.
-        spend
-            .body
-            .rk
-            .verify(context.effect_hash.as_ref(), &spend.auth_sig)
-            .context("spend auth signature failed to verify")?;
+        spend.check_auth_sig(&context)?;
 
         // 3. Check that the proof verifies.
         spend
@@ -35,9 +63,7 @@ impl ActionHandler for Spend {
                 spend.body.nullifier,
                 spend.body.rk,
             )
-            .context("a spend proof did not verify")?;
-
-        Ok(())
+            .context("a spend proof did not verify")
     }
 
     async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
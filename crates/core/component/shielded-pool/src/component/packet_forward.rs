@@ -0,0 +1,174 @@
+//! Parsing of packet-forward-middleware memos on inbound ICS-20 transfers.
+//!
+//! A transfer whose memo carries a `forward` object is not credited to its receiver, but sent on
+//! over another channel, following the format used by the Cosmos packet-forward-middleware:
+//!
+//! ```json
+//! {"forward": {"receiver": "noble1...", "port": "transfer", "channel": "channel-1"}}
+//! ```
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use ibc_types::core::channel::{ChannelId, PortId};
+use serde::{Deserialize, Deserializer};
+
+/// How long a forwarded packet has to reach the next chain, if the memo doesn't say.
+pub const DEFAULT_FORWARD_TIMEOUT_NANOS: u64 = 10 * 60 * 1_000_000_000; // 10 minutes
+
+/// How far past the counterparty's latest known height a forwarded packet's timeout height is
+/// set: forwarded packets time out by timestamp, so this only needs to be out of the way.
+pub const FORWARD_TIMEOUT_HEIGHT_OFFSET: u64 = 1_000_000;
+
+/// Instructions for forwarding an inbound transfer over another channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardMetadata {
+    /// The receiver of the forwarded transfer on the next chain.
+    pub receiver: String,
+    /// The port to forward the transfer over.
+    pub port: PortId,
+    /// The channel to forward the transfer over.
+    pub channel: ChannelId,
+    /// How long, in nanoseconds, the forwarded packet has to be received before it times out.
+    pub timeout_nanos: u64,
+    /// The memo to attach to the forwarded transfer, which may itself contain forwarding
+    /// instructions for the next chain.
+    pub next_memo: String,
+}
+
+impl ForwardMetadata {
+    /// Parses forwarding instructions out of a transfer memo.
+    ///
+    /// Returns `None` if the memo doesn't request forwarding, and an error if it does but the
+    /// instructions are malformed.
+    pub fn parse(memo: &str) -> Result<Option<Self>> {
+        let Ok(memo) = serde_json::from_str::<serde_json::Value>(memo) else {
+            return Ok(None);
+        };
+        let Some(forward) = memo.get("forward") else {
+            return Ok(None);
+        };
+
+        let forward = ForwardJson::deserialize(forward).context("invalid forward memo")?;
+        let next_memo = match forward.next {
+            None | Some(serde_json::Value::Null) => String::new(),
+            Some(serde_json::Value::String(memo)) => memo,
+            Some(next) => next.to_string(),
+        };
+
+        Ok(Some(Self {
+            receiver: forward.receiver,
+            port: PortId::from_str(&forward.port).context("invalid forward port")?,
+            channel: ChannelId::from_str(&forward.channel).context("invalid forward channel")?,
+            timeout_nanos: forward.timeout.unwrap_or(DEFAULT_FORWARD_TIMEOUT_NANOS),
+            next_memo,
+        }))
+    }
+}
+
+#[derive(Deserialize)]
+struct ForwardJson {
+    receiver: String,
+    port: String,
+    channel: String,
+    #[serde(default, deserialize_with = "deserialize_timeout")]
+    timeout: Option<u64>,
+    #[serde(default)]
+    next: Option<serde_json::Value>,
+}
+
+/// Timeouts are given either in nanoseconds, or as a duration like `"10m"`.
+fn deserialize_timeout<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<u64>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Timeout {
+        Nanos(u64),
+        Duration(String),
+    }
+
+    let nanos = match Timeout::deserialize(deserializer)? {
+        Timeout::Nanos(nanos) => nanos,
+        Timeout::Duration(duration) => {
+            parse_duration_nanos(&duration).map_err(serde::de::Error::custom)?
+        }
+    };
+    Ok(Some(nanos))
+}
+
+fn parse_duration_nanos(duration: &str) -> Result<u64> {
+    let unit_start = duration
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(|| anyhow::anyhow!("duration {duration:?} is missing a unit"))?;
+    let (count, unit) = duration.split_at(unit_start);
+    let count: u64 = count
+        .parse()
+        .with_context(|| format!("invalid duration {duration:?}"))?;
+    let unit_nanos: u64 = match unit {
+        "ns" => 1,
+        "us" => 1_000,
+        "ms" => 1_000_000,
+        "s" => 1_000_000_000,
+        "m" => 60 * 1_000_000_000,
+        "h" => 60 * 60 * 1_000_000_000,
+        _ => anyhow::bail!("unknown unit in duration {duration:?}"),
+    };
+    count
+        .checked_mul(unit_nanos)
+        .ok_or_else(|| anyhow::anyhow!("duration {duration:?} is too long"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memos_without_forward_are_ignored() {
+        assert_eq!(ForwardMetadata::parse("").unwrap(), None);
+        assert_eq!(
+            ForwardMetadata::parse("thanks for the tokens").unwrap(),
+            None
+        );
+        assert_eq!(ForwardMetadata::parse(r#"{"wasm": {}}"#).unwrap(), None);
+    }
+
+    #[test]
+    fn forward_memo_is_parsed() {
+        let memo = r#"{
+            "forward": {
+                "receiver": "noble1receiver",
+                "port": "transfer",
+                "channel": "channel-1",
+                "timeout": "5m",
+                "retries": 2,
+                "next": {"forward": {"receiver": "osmo1receiver", "port": "transfer", "channel": "channel-7"}}
+            }
+        }"#;
+        let forward = ForwardMetadata::parse(memo).unwrap().unwrap();
+
+        assert_eq!(forward.receiver, "noble1receiver");
+        assert_eq!(forward.port, PortId::transfer());
+        assert_eq!(forward.channel, ChannelId::new(1));
+        assert_eq!(forward.timeout_nanos, 5 * 60 * 1_000_000_000);
+
+        // The rest of the route is passed on to the next chain.
+        let next = ForwardMetadata::parse(&forward.next_memo).unwrap().unwrap();
+        assert_eq!(next.receiver, "osmo1receiver");
+        assert_eq!(next.channel, ChannelId::new(7));
+        assert_eq!(next.timeout_nanos, DEFAULT_FORWARD_TIMEOUT_NANOS);
+        assert_eq!(next.next_memo, "");
+    }
+
+    #[test]
+    fn malformed_forward_memo_is_rejected() {
+        assert!(ForwardMetadata::parse(r#"{"forward": {"receiver": "noble1receiver"}}"#).is_err());
+        assert!(ForwardMetadata::parse(
+            r#"{"forward": {"receiver": "r", "port": "transfer", "channel": "channel 1"}}"#
+        )
+        .is_err());
+        assert!(ForwardMetadata::parse(
+            r#"{"forward": {"receiver": "r", "port": "transfer", "channel": "channel-1", "timeout": "5 fortnights"}}"#
+        )
+        .is_err());
+    }
+}
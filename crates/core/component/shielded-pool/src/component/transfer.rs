@@ -1,7 +1,10 @@
 use std::str::FromStr;
 
 use crate::{
-    component::{NoteManager, SupplyWrite},
+    component::{
+        packet_forward::{ForwardMetadata, FORWARD_TIMEOUT_HEIGHT_OFFSET},
+        NoteManager, SupplyWrite,
+    },
     Ics20Withdrawal,
 };
 use anyhow::{Context, Result};
@@ -14,26 +17,28 @@ use ibc_types::{
             MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket,
             MsgTimeout,
         },
-        ChannelId, PortId, Version,
+        ChannelId, Packet, PortId, Version,
     },
+    core::client::Height as IbcHeight,
     transfer::acknowledgement::TokenTransferAcknowledgement,
 };
 use penumbra_asset::{asset, asset::DenomMetadata, Value};
+use penumbra_chain::component::StateReadExt as _;
 use penumbra_keys::Address;
 use penumbra_num::Amount;
 use penumbra_proto::{
     penumbra::core::component::ibc::v1alpha1::FungibleTokenPacketData, StateReadProto,
     StateWriteProto,
 };
-use penumbra_storage::{StateRead, StateWrite};
-use prost::Message;
+use penumbra_storage::{StateDelta, StateRead, StateWrite};
 
 use penumbra_ibc::component::{
     app_handler::{AppHandler, AppHandlerCheck, AppHandlerExecute},
     packet::{
         IBCPacket, SendPacketRead as _, SendPacketWrite as _, Unchecked, WriteAcknowledgement as _,
     },
-    state_key,
+    rate_limit::{Flow, Ics20RateLimitRead as _, Ics20RateLimitWrite as _},
+    state_key, ChannelStateReadExt as _, ClientStateReadExt as _, ConnectionStateReadExt as _,
 };
 
 // returns a bool indicating if the provided denom was issued locally or if it was bridged in.
@@ -63,6 +68,290 @@ fn is_source(
     }
 }
 
+// returns whether an ICS20 acknowledgement (`{"result": ...}` or `{"error": ...}`, as JSON)
+// reports success.
+fn is_success_acknowledgement(acknowledgement: &[u8]) -> bool {
+    serde_json::from_slice::<serde_json::Value>(acknowledgement)
+        .map(|ack| ack.get("result").is_some())
+        .unwrap_or(false)
+}
+
+async fn increase_value_balance<S: StateWrite + ?Sized>(
+    state: &mut S,
+    channel: &ChannelId,
+    asset_id: &asset::Id,
+    amount: Amount,
+) -> Result<()> {
+    let value_balance: Amount = state
+        .get(&state_key::ics20_value_balance(channel, asset_id))
+        .await?
+        .unwrap_or_else(Amount::zero);
+    state.put(
+        state_key::ics20_value_balance(channel, asset_id),
+        value_balance.saturating_add(&amount),
+    );
+    Ok(())
+}
+
+async fn decrease_value_balance<S: StateWrite + ?Sized>(
+    state: &mut S,
+    channel: &ChannelId,
+    asset_id: &asset::Id,
+    amount: Amount,
+) -> Result<()> {
+    let value_balance: Amount = state
+        .get(&state_key::ics20_value_balance(channel, asset_id))
+        .await?
+        .unwrap_or_else(Amount::zero);
+    let new_value_balance = value_balance.checked_sub(&amount).ok_or_else(|| {
+        anyhow::anyhow!("insufficient value balance on {channel} for {amount} of {asset_id}")
+    })?;
+    state.put(
+        state_key::ics20_value_balance(channel, asset_id),
+        new_value_balance,
+    );
+    Ok(())
+}
+
+// accounts for tokens leaving Penumbra over `channel`. tokens we are the source of are escrowed,
+// while tokens that came in over `channel` are returned through it. returns `true` in the latter
+// case, where the tokens leave our supply.
+async fn send_tokens<S: StateWrite + ?Sized>(
+    state: &mut S,
+    channel: &ChannelId,
+    denom: &DenomMetadata,
+    amount: Amount,
+) -> Result<bool> {
+    state
+        .record_ics20_flow(channel, &denom.id(), Flow::Outflow, amount)
+        .await?;
+
+    let prefix = format!("transfer/{}/", channel);
+    if !denom.starts_with(&prefix) {
+        // we are the source. add the value balance to the escrow channel.
+        increase_value_balance(state, channel, &denom.id(), amount).await?;
+        Ok(false)
+    } else {
+        // receiver is the source.
+        //
+        // for assets not originating from Penumbra, never transfer out more tokens than were
+        // transferred in. (Our counterparties should be checking this anyways, since if we
+        // were Byzantine we could lie to them).
+        decrease_value_balance(state, channel, &denom.id(), amount)
+            .await
+            .context("insufficient balance to withdraw tokens")?;
+        Ok(true)
+    }
+}
+
+// reverses `send_tokens` for a packet we sent that was not received, returning the tokens to
+// refund.
+async fn refund_tokens<S: StateWrite + ?Sized>(
+    state: &mut S,
+    packet: &Packet,
+    packet_data: &FungibleTokenPacketData,
+) -> Result<(DenomMetadata, Amount)> {
+    let denom: DenomMetadata = packet_data
+        .denom
+        .as_str()
+        .try_into()
+        .context("couldn't decode denom in ics20 refund")?;
+    let amount: Amount = packet_data
+        .amount
+        .clone()
+        .try_into()
+        .context("couldn't decode amount in ics20 refund")?;
+
+    state
+        .revert_ics20_flow(&packet.chan_on_a, &denom.id(), Flow::Outflow, amount)
+        .await?;
+
+    if is_source(&packet.port_on_a, &packet.chan_on_a, &denom, true) {
+        // sender was source chain, unescrow tokens back to sender
+        decrease_value_balance(state, &packet.chan_on_a, &denom.id(), amount)
+            .await
+            .context("couldn't return coins in refund: not enough value balance")?;
+    } else {
+        // the tokens were returned through the channel they came in over, so they count towards
+        // its value balance again.
+        increase_value_balance(state, &packet.chan_on_a, &denom.id(), amount).await?;
+    }
+
+    Ok((denom, amount))
+}
+
+async fn refund_check<S: StateRead>(state: &S, packet: &Packet) -> Result<()> {
+    let packet_data: FungibleTokenPacketData = serde_json::from_slice(packet.data.as_slice())?;
+    let denom: asset::DenomMetadata = packet_data.denom.as_str().try_into()?;
+
+    if is_source(&packet.port_on_a, &packet.chan_on_a, &denom, true) {
+        // check if we have enough balance to refund tokens to sender
+        let value_balance: Amount = state
+            .get(&state_key::ics20_value_balance(
+                &packet.chan_on_a,
+                &denom.id(),
+            ))
+            .await?
+            .unwrap_or_else(Amount::zero);
+
+        let amount_penumbra: Amount = packet_data.amount.try_into()?;
+        if value_balance < amount_penumbra {
+            anyhow::bail!("insufficient balance to refund tokens to sender");
+        }
+    }
+
+    Ok(())
+}
+
+// accounts for tokens arriving over the receiving channel of `packet`, returning their local
+// denomination and amount.
+//
+// see this part of the spec for this logic:
+//
+// https://github.com/cosmos/ibc/tree/main/spec/app/ics-020-fungible-token-transfer (onRecvPacket)
+async fn receive_tokens<S: StateWrite + ?Sized>(
+    state: &mut S,
+    packet: &Packet,
+    packet_data: &FungibleTokenPacketData,
+) -> Result<(DenomMetadata, Amount)> {
+    let (denom, returning) = received_denom(packet, packet_data)?;
+    let amount: Amount = packet_data
+        .amount
+        .clone()
+        .try_into()
+        .context("couldnt decode amount in ICS20 transfer")?;
+
+    state
+        .record_ics20_flow(&packet.chan_on_b, &denom.id(), Flow::Inflow, amount)
+        .await?;
+
+    if returning {
+        // we are the source of the tokens, so they are unescrowed. error text here is from the
+        // ics20 spec.
+        decrease_value_balance(state, &packet.chan_on_b, &denom.id(), amount)
+            .await
+            .context("transfer coins failed")?;
+    } else {
+        // the tokens are new to Penumbra, so we track them as a prefixed denom.
+        state
+            .register_denom(&denom)
+            .await
+            .context("unable to register denom in ics20 transfer")?;
+        increase_value_balance(state, &packet.chan_on_b, &denom.id(), amount).await?;
+    }
+
+    Ok((denom, amount))
+}
+
+// reverses `receive_tokens`, for a received packet whose tokens never reached their receiver.
+async fn unreceive_tokens<S: StateWrite + ?Sized>(
+    state: &mut S,
+    packet: &Packet,
+    packet_data: &FungibleTokenPacketData,
+) -> Result<()> {
+    let (denom, returning) = received_denom(packet, packet_data)?;
+    let amount: Amount = packet_data
+        .amount
+        .clone()
+        .try_into()
+        .context("couldnt decode amount in ICS20 transfer")?;
+
+    state
+        .revert_ics20_flow(&packet.chan_on_b, &denom.id(), Flow::Inflow, amount)
+        .await?;
+
+    if returning {
+        increase_value_balance(state, &packet.chan_on_b, &denom.id(), amount).await
+    } else {
+        decrease_value_balance(state, &packet.chan_on_b, &denom.id(), amount).await
+    }
+}
+
+// returns the local denomination of the tokens in a received packet, and whether we are the
+// source of those tokens (so they are returning to Penumbra, rather than arriving for the first
+// time).
+fn received_denom(
+    packet: &Packet,
+    packet_data: &FungibleTokenPacketData,
+) -> Result<(DenomMetadata, bool)> {
+    let denom: asset::DenomMetadata = packet_data
+        .denom
+        .as_str()
+        .try_into()
+        .context("couldnt decode denom in ICS20 transfer")?;
+
+    // NOTE: here we assume we are chain A.
+    if is_source(&packet.port_on_a, &packet.chan_on_a, &denom, false) {
+        // the denom is sent with the source removed, since we're the source
+        let prefix = format!(
+            "{source_port}/{source_chan}/",
+            source_port = packet.port_on_a,
+            source_chan = packet.chan_on_a
+        );
+        let unprefixed_denom: asset::DenomMetadata = packet_data
+            .denom
+            .replace(&prefix, "")
+            .as_str()
+            .try_into()
+            .context("couldnt decode denom in ICS20 transfer")?;
+        Ok((unprefixed_denom, true))
+    } else {
+        // prefix = "{packet.destPort}/{packet.destChannel}/"
+        // prefixedDenomination = prefix + data.denom
+        let prefixed_denomination = format!(
+            "{}/{}/{}",
+            packet.port_on_b, packet.chan_on_b, packet_data.denom
+        );
+        let prefixed_denom: asset::DenomMetadata = prefixed_denomination
+            .as_str()
+            .try_into()
+            .context("unable to parse denom in ics20 transfer as DenomMetadata")?;
+        Ok((prefixed_denom, false))
+    }
+}
+
+// removes and returns the inbound packet that `packet` was forwarded on behalf of, if any.
+async fn take_forwarded_packet<S: StateWrite + ?Sized>(
+    state: &mut S,
+    packet: &Packet,
+) -> Result<Option<Packet>> {
+    let key = crate::state_key::forwarded_packet(&packet.chan_on_a, packet.sequence.into());
+    let inbound: Option<Packet> = state.get(&key).await?;
+    if inbound.is_some() {
+        state.delete(key);
+    }
+    Ok(inbound)
+}
+
+// forwarded packets time out by timestamp; their timeout height is set well past the latest
+// height known for the counterparty, so that it doesn't come into play.
+async fn forward_timeout_height<S: StateRead + ?Sized>(
+    state: &S,
+    channel_id: &ChannelId,
+) -> Result<IbcHeight> {
+    let channel = state
+        .get_channel(channel_id, &PortId::transfer())
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("channel {} does not exist", channel_id))?;
+    let connection = state
+        .get_connection(&channel.connection_hops[0])
+        .await?
+        .ok_or_else(|| {
+            anyhow::anyhow!("connection {} does not exist", channel.connection_hops[0])
+        })?;
+    let latest_height = state
+        .get_client_state(&connection.client_id)
+        .await?
+        .latest_height();
+
+    Ok(IbcHeight::new(
+        latest_height.revision_number(),
+        latest_height
+            .revision_height()
+            .saturating_add(FORWARD_TIMEOUT_HEIGHT_OFFSET),
+    )?)
+}
+
 #[derive(Clone)]
 pub struct Ics20Transfer {}
 
@@ -75,6 +364,14 @@ pub trait Ics20TransferReadExt: StateRead {
         // send packet
         self.send_packet_check(packet).await?;
 
+        self.check_ics20_flow(
+            &withdrawal.source_channel,
+            &withdrawal.denom.id(),
+            Flow::Outflow,
+            withdrawal.amount,
+        )
+        .await?;
+
         Ok(())
     }
 }
@@ -87,55 +384,16 @@ pub trait Ics20TransferWriteExt: StateWrite {
         // create packet, assume it's already checked since the component caller contract calls `check` before `execute`
         let checked_packet = IBCPacket::<Unchecked>::from(withdrawal.clone()).assume_checked();
 
-        let prefix = format!("transfer/{}/", &withdrawal.source_channel);
-        if !withdrawal.denom.starts_with(&prefix) {
-            // we are the source. add the value balance to the escrow channel.
-            let existing_value_balance: Amount = self
-                .get(&state_key::ics20_value_balance(
-                    &withdrawal.source_channel,
-                    &withdrawal.denom.id(),
-                ))
-                .await
-                .expect("able to retrieve value balance in ics20 withdrawal! (execute)")
-                .unwrap_or_else(Amount::zero);
+        let returned_to_source = send_tokens(
+            self,
+            &withdrawal.source_channel,
+            &withdrawal.denom,
+            withdrawal.amount,
+        )
+        .await?;
 
-            let new_value_balance = existing_value_balance + withdrawal.amount;
-            self.put(
-                state_key::ics20_value_balance(&withdrawal.source_channel, &withdrawal.denom.id()),
-                new_value_balance,
-            );
-        } else {
-            // receiver is the source, burn utxos
-
-            // double check the value balance here.
-            //
-            // for assets not originating from Penumbra, never transfer out more tokens than were
-            // transferred in. (Our counterparties should be checking this anyways, since if we
-            // were Byzantine we could lie to them).
-            let value_balance: Amount = self
-                .get(&state_key::ics20_value_balance(
-                    &withdrawal.source_channel,
-                    &withdrawal.denom.id(),
-                ))
-                .await?
-                .unwrap_or_else(Amount::zero);
-
-            if value_balance < withdrawal.amount {
-                anyhow::bail!("insufficient balance to withdraw tokens");
-            }
-
-            let new_value_balance =
-                value_balance
-                    .checked_sub(&withdrawal.amount)
-                    .ok_or_else(|| {
-                        anyhow::anyhow!("underflow subtracting value balance in ics20 withdrawal")
-                    })?;
-            self.put(
-                state_key::ics20_value_balance(&withdrawal.source_channel, &withdrawal.denom.id()),
-                new_value_balance,
-            );
-
-            // update supply tracking of burned note
+        if returned_to_source {
+            // receiver is the source, so the tokens leave our supply.
             self.update_token_supply(&withdrawal.denom.id(), -(withdrawal.amount.value() as i128))
                 .await
                 .expect("couldn't update token supply in ics20 withdrawal!");
@@ -216,153 +474,47 @@ impl AppHandlerCheck for Ics20Transfer {
     }
 
     async fn timeout_packet_check<S: StateRead>(state: S, msg: &MsgTimeout) -> Result<()> {
-        let packet_data = FungibleTokenPacketData::decode(msg.packet.data.as_slice())?;
-        let denom: asset::DenomMetadata = packet_data.denom.as_str().try_into()?;
-
-        if is_source(&msg.packet.port_on_a, &msg.packet.chan_on_a, &denom, true) {
-            // check if we have enough balance to refund tokens to sender
-            let value_balance: Amount = state
-                .get(&state_key::ics20_value_balance(
-                    &msg.packet.chan_on_a,
-                    &denom.id(),
-                ))
-                .await?
-                .unwrap_or_else(Amount::zero);
-
-            let amount_penumbra: Amount = packet_data.amount.try_into()?;
-            if value_balance < amount_penumbra {
-                anyhow::bail!("insufficient balance to refund tokens to sender");
-            }
-        }
-
-        Ok(())
+        refund_check(&state, &msg.packet).await
     }
 
     async fn acknowledge_packet_check<S: StateRead>(
-        _state: S,
-        _msg: &MsgAcknowledgement,
+        state: S,
+        msg: &MsgAcknowledgement,
     ) -> Result<()> {
-        Ok(())
+        if is_success_acknowledgement(&msg.acknowledgement) {
+            return Ok(());
+        }
+        refund_check(&state, &msg.packet).await
     }
 }
 
+/// What became of a received transfer packet.
+enum Received {
+    /// The tokens were minted to the receiver, and the packet can be acknowledged.
+    Credited,
+    /// The tokens were forwarded over another channel, and the packet will be acknowledged once
+    /// the forwarded packet is.
+    Forwarded,
+}
+
 // the main entry point for ICS20 transfer packet handling
 async fn recv_transfer_packet_inner<S: StateWrite>(
     mut state: S,
-    msg: &MsgRecvPacket,
-) -> Result<()> {
-    // parse if we are source or dest, and mint or burn accordingly
-    //
-    // see this part of the spec for this logic:
-    //
-    // https://github.com/cosmos/ibc/tree/main/spec/app/ics-020-fungible-token-transfer (onRecvPacket)
-    //
+    packet: &Packet,
+) -> Result<Received> {
     // NOTE: spec says proto but thsi is actualy JSON according to the ibc-go implementation
-    let packet_data: FungibleTokenPacketData = serde_json::from_slice(msg.packet.data.as_slice())
+    let packet_data: FungibleTokenPacketData = serde_json::from_slice(packet.data.as_slice())
         .with_context(|| "failed to decode FTPD packet")?;
-    let denom: asset::DenomMetadata = packet_data
-        .denom
-        .as_str()
-        .try_into()
-        .context("couldnt decode denom in ICS20 transfer")?;
-    let receiver_amount: Amount = packet_data
-        .amount
-        .try_into()
-        .context("couldnt decode amount in ICS20 transfer")?;
-    let receiver_address = Address::from_str(&packet_data.receiver)?;
-
-    // NOTE: here we assume we are chain A.
-
-    // 2. check if we are the source chain for the denom.
-    if is_source(&msg.packet.port_on_a, &msg.packet.chan_on_a, &denom, false) {
-        // mint tokens to receiver in the amount of packet_data.amount in the denom of denom (with
-        // the source removed, since we're the source)
-        let prefix = format!(
-            "{source_port}/{source_chan}/",
-            source_port = msg.packet.port_on_a,
-            source_chan = msg.packet.chan_on_a
-        );
-
-        let unprefixed_denom: asset::DenomMetadata = packet_data
-            .denom
-            .replace(&prefix, "")
-            .as_str()
-            .try_into()
-            .context("couldnt decode denom in ICS20 transfer")?;
-
-        let value: Value = Value {
-            amount: receiver_amount,
-            asset_id: unprefixed_denom.id(),
-        };
-
-        // assume AppHandlerCheck has already been called, and we have enough balance to mint tokens to receiver
-        // check if we have enough balance to unescrow tokens to receiver
-        let value_balance: Amount = state
-            .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_b,
-                &unprefixed_denom.id(),
-            ))
-            .await?
-            .unwrap_or_else(Amount::zero);
-
-        if value_balance < receiver_amount {
-            // error text here is from the ics20 spec
-            anyhow::bail!("transfer coins failed");
-        }
-
-        state
-            .mint_note(
-                value,
-                &receiver_address,
-                penumbra_chain::NoteSource::Ics20Transfer, // TODO
-            )
-            .await
-            .context("unable to mint note when receiving ics20 transfer packet")?;
+    let forward = ForwardMetadata::parse(&packet_data.memo)?;
 
-        // update the value balance
-        let value_balance: Amount = state
-            .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_b,
-                &unprefixed_denom.id(),
-            ))
-            .await?
-            .unwrap_or_else(Amount::zero);
-
-        // note: this arithmetic was checked above, but we do it again anyway.
-        let new_value_balance = value_balance
-            .checked_sub(&receiver_amount)
-            .context("underflow subtracing value balance in ics20 transfer")?;
-        state.put(
-            state_key::ics20_value_balance(&msg.packet.chan_on_b, &denom.id()),
-            new_value_balance,
-        );
-    } else {
-        // create new denom:
-        //
-        // prefix = "{packet.destPort}/{packet.destChannel}/"
-        // prefixedDenomination = prefix + data.denom
-        //
-        // then mint that denom to packet_data.receiver in packet_data.amount
-        // no value balance to update here since this is an exogenous denom
-        let prefixed_denomination = format!(
-            "{}/{}/{}",
-            msg.packet.port_on_b, msg.packet.chan_on_b, packet_data.denom
-        );
-
-        let denom: asset::DenomMetadata = prefixed_denomination
-            .as_str()
-            .try_into()
-            .context("unable to parse denom in ics20 transfer as DenomMetadata")?;
-        state
-            .register_denom(&denom)
-            .await
-            .context("unable to register denom in ics20 transfer")?;
+    let (denom, amount) = receive_tokens(&mut state, packet, &packet_data).await?;
 
+    let Some(forward) = forward else {
+        let receiver_address = Address::from_str(&packet_data.receiver)?;
         let value = Value {
-            amount: receiver_amount,
+            amount,
             asset_id: denom.id(),
         };
-
         state
             .mint_note(
                 value,
@@ -370,90 +522,147 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
                 penumbra_chain::NoteSource::Ics20Transfer,
             )
             .await
-            .context("failed to mint notes in ibc transfer")?;
-
-        // update the value balance
-        let value_balance: Amount = state
-            .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_b,
-                &denom.id(),
-            ))
-            .await?
-            .unwrap_or_else(Amount::zero);
+            .context("unable to mint note when receiving ics20 transfer packet")?;
+        return Ok(Received::Credited);
+    };
 
-        let new_value_balance = value_balance.saturating_add(&value.amount);
-        state.put(
-            state_key::ics20_value_balance(&msg.packet.chan_on_b, &denom.id()),
-            new_value_balance,
-        );
+    // The tokens never reach a note on Penumbra: they are sent straight back out, with the
+    // receiver of the inbound transfer acting as the sender of the forwarded one.
+    if forward.port != PortId::transfer() {
+        anyhow::bail!("can only forward transfers over the transfer port");
     }
-
-    Ok(())
+    send_tokens(&mut state, &forward.channel, &denom, amount).await?;
+
+    let forwarded_data = FungibleTokenPacketData {
+        denom: denom.to_string(),
+        amount: amount.to_string(),
+        sender: packet_data.receiver,
+        receiver: forward.receiver,
+        memo: forward.next_memo,
+    };
+    let timeout_height = forward_timeout_height(&state, &forward.channel).await?;
+    let timeout_time = (state.get_block_timestamp().await?.unix_timestamp_nanos() as u64)
+        .saturating_add(forward.timeout_nanos);
+    let sequence = state
+        .get_send_sequence(&forward.channel, &forward.port)
+        .await?;
+
+    let forwarded_packet = state
+        .send_packet_check(IBCPacket::new(
+            forward.port,
+            forward.channel.clone(),
+            timeout_height,
+            timeout_time,
+            serde_json::to_vec(&forwarded_data)
+                .context("can serialize FungibleTokenPacketData as JSON")?,
+        ))
+        .await
+        .context("unable to forward ics20 transfer")?;
+    state.send_packet_execute(forwarded_packet).await;
+
+    // Hold on to the inbound packet, so that we can acknowledge it with the outcome of the
+    // forwarded one.
+    state.put(
+        crate::state_key::forwarded_packet(&forward.channel, sequence),
+        packet.clone(),
+    );
+
+    Ok(Received::Forwarded)
 }
 
 // see: https://github.com/cosmos/ibc/blob/8326e26e7e1188b95c32481ff00348a705b23700/spec/app/ics-020-fungible-token-transfer/README.md?plain=1#L297
-async fn timeout_packet_inner<S: StateWrite>(mut state: S, msg: &MsgTimeout) -> Result<()> {
-    let packet_data = FungibleTokenPacketData::decode(msg.packet.data.as_slice())?;
-    let denom: asset::DenomMetadata = packet_data // CRITICAL: verify that this denom is validated in upstream timeout handling
-        .denom
-        .as_str()
-        .try_into()
-        .context("couldn't decode denom in ics20 transfer timeout")?;
-    // receiver was source chain, mint vouchers back to sender
-    let amount: Amount = packet_data
-        .amount
-        .try_into()
-        .context("couldn't decode amount in ics20 transfer timeout")?;
-
-    let receiver = Address::from_str(&packet_data.receiver)
-        .context("couldn't decode receiver address in ics20 timeout")?;
-
-    let value: Value = Value {
-        amount,
-        asset_id: denom.id(),
-    };
+//
+// Called when a packet we sent times out, or is acknowledged with an error.
+async fn refund_packet_inner<S: StateWrite>(
+    mut state: S,
+    packet: &Packet,
+    reason: String,
+) -> Result<()> {
+    let packet_data: FungibleTokenPacketData = serde_json::from_slice(packet.data.as_slice())
+        .context("couldn't decode FTPD packet in ics20 refund")?;
+    let (denom, amount) = refund_tokens(&mut state, packet, &packet_data).await?;
+
+    match take_forwarded_packet(&mut state, packet).await? {
+        None => {
+            let sender = Address::from_str(&packet_data.sender)
+                .context("couldn't decode sender address in ics20 refund")?;
+            let value = Value {
+                amount,
+                asset_id: denom.id(),
+            };
+            state
+                .mint_note(value, &sender, penumbra_chain::NoteSource::Ics20Transfer) // NOTE: should this be Ics20TransferTimeout?
+                .await
+                .context("failed to mint refund note in ics20 refund")?;
+        }
+        Some(inbound) => {
+            // The tokens were forwarded on behalf of another chain: undo our receipt of them, and
+            // fail the inbound packet, so that they are refunded to the original sender.
+            let inbound_data: FungibleTokenPacketData =
+                serde_json::from_slice(inbound.data.as_slice())
+                    .context("couldn't decode forwarded FTPD packet in ics20 refund")?;
+            unreceive_tokens(&mut state, &inbound, &inbound_data).await?;
+            state
+                .write_acknowledgement(
+                    &inbound,
+                    &Vec::<u8>::from(TokenTransferAcknowledgement::Error(reason)),
+                )
+                .await?;
+        }
+    }
 
-    if is_source(&msg.packet.port_on_a, &msg.packet.chan_on_a, &denom, true) {
-        // sender was source chain, unescrow tokens back to sender
-        let value_balance: Amount = state
-            .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_a,
-                &denom.id(),
-            ))
-            .await?
-            .unwrap_or_else(Amount::zero);
+    Ok(())
+}
 
-        if value_balance < amount {
-            anyhow::bail!("couldn't return coins in timeout: not enough value balance");
+async fn recv_packet<S: StateWrite>(mut state: S, packet: &Packet) {
+    // Receiving happens in its own state fork, so that a failure leaves no partial changes behind.
+    let mut fork = StateDelta::new(&mut state);
+    let ack: Vec<u8> = match recv_transfer_packet_inner(&mut fork, packet).await {
+        Ok(received) => {
+            let (state, events) = fork.apply();
+            for event in events {
+                state.record(event);
+            }
+            match received {
+                // record packet acknowledgement without error
+                Received::Credited => TokenTransferAcknowledgement::success().into(),
+                // the acknowledgement is written once the forwarded packet is resolved
+                Received::Forwarded => return,
+            }
+        }
+        Err(e) => {
+            // discard any partial changes
+            drop(fork);
+            tracing::debug!("couldnt execute transfer: {:#}", e);
+            // record packet acknowledgement with error
+            TokenTransferAcknowledgement::Error(e.to_string()).into()
         }
+    };
 
-        state
-            .mint_note(value, &receiver, penumbra_chain::NoteSource::Ics20Transfer)
-            .await
-            .context("couldn't mint note in timeout_packet_inner")?;
+    state
+        .write_acknowledgement(packet, &ack)
+        .await
+        .expect("able to write acknowledgement");
+}
 
-        // update the value balance
-        let value_balance: Amount = state
-            .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_a,
-                &denom.id(),
-            ))
-            .await?
-            .unwrap_or_else(Amount::zero);
+async fn acknowledge_packet_inner<S: StateWrite>(
+    mut state: S,
+    packet: &Packet,
+    acknowledgement: &[u8],
+) -> Result<()> {
+    if !is_success_acknowledgement(acknowledgement) {
+        let reason = String::from_utf8_lossy(acknowledgement).into_owned();
+        return refund_packet_inner(&mut state, packet, reason).await;
+    }
 
-        // note: this arithmetic was checked above, but we do it again anyway.
-        let new_value_balance = value_balance
-            .checked_sub(&amount)
-            .context("underflow in ics20 timeout packet value balance subtraction")?;
-        state.put(
-            state_key::ics20_value_balance(&msg.packet.chan_on_a, &denom.id()),
-            new_value_balance,
-        );
-    } else {
+    // If the packet was forwarded on behalf of another chain, the forward succeeded.
+    if let Some(inbound) = take_forwarded_packet(&mut state, packet).await? {
         state
-            .mint_note(value, &receiver, penumbra_chain::NoteSource::Ics20Transfer) // NOTE: should this be Ics20TransferTimeout?
-            .await
-            .context("failed to mint return voucher in ics20 transfer timeout")?;
+            .write_acknowledgement(
+                &inbound,
+                &Vec::<u8>::from(TokenTransferAcknowledgement::success()),
+            )
+            .await?;
     }
 
     Ok(())
@@ -468,34 +677,346 @@ impl AppHandlerExecute for Ics20Transfer {
     async fn chan_open_confirm_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenConfirm) {}
     async fn chan_close_confirm_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseConfirm) {}
     async fn chan_close_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseInit) {}
-    async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) {
+    async fn recv_packet_execute<S: StateWrite>(state: S, msg: &MsgRecvPacket) {
         // recv packet should never fail a transaction, but it should record a failure acknowledgement.
-        let ack: Vec<u8> = match recv_transfer_packet_inner(&mut state, msg).await {
-            Ok(_) => {
-                // record packet acknowledgement without error
-                TokenTransferAcknowledgement::success().into()
-            }
-            Err(e) => {
-                tracing::debug!("couldnt execute transfer: {:#}", e);
-                // record packet acknowledgement with error
-                TokenTransferAcknowledgement::Error(e.to_string()).into()
-            }
-        };
-
-        state
-            .write_acknowledgement(&msg.packet, &ack)
-            .await
-            .expect("able to write acknowledgement");
+        recv_packet(state, &msg.packet).await
     }
 
     async fn timeout_packet_execute<S: StateWrite>(mut state: S, msg: &MsgTimeout) {
         // timeouts should never fail
-        timeout_packet_inner(&mut state, msg)
+        refund_packet_inner(&mut state, &msg.packet, "packet timed out".to_string())
             .await
             .expect("able to timeout packet");
     }
 
-    async fn acknowledge_packet_execute<S: StateWrite>(_state: S, _msg: &MsgAcknowledgement) {}
+    async fn acknowledge_packet_execute<S: StateWrite>(mut state: S, msg: &MsgAcknowledgement) {
+        // acknowledgements should never fail
+        acknowledge_packet_inner(&mut state, &msg.packet, &msg.acknowledgement)
+            .await
+            .expect("able to acknowledge packet");
+    }
 }
 
 impl AppHandler for Ics20Transfer {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ibc_types::{
+        core::{
+            channel::{channel::State as ChannelState, ChannelEnd, Counterparty},
+            client::{msgs::MsgCreateClient, ClientId},
+            connection::{
+                ConnectionEnd, ConnectionId, Counterparty as ConnectionCounterparty,
+                State as ConnectionState, Version as ConnectionVersion,
+            },
+        },
+        lightclients::tendermint::client_state::ClientState as TendermintClientState,
+        path::{ChannelEndPath, ClientStatePath, ConnectionPath},
+        timestamp::Timestamp,
+        DomainType as _,
+    };
+    use penumbra_chain::component::StateWriteExt as _;
+    use penumbra_ibc::{
+        params::IBCParameters, Ics20RateLimit, StateWriteExt as _, IBC_COMMITMENT_PREFIX,
+        IBC_SUBSTORE_PREFIX,
+    };
+    use tendermint::Time;
+
+    // the channel transfers arrive over, and the channel they are forwarded over.
+    const INBOUND_CHANNEL: u64 = 0;
+    const FORWARD_CHANNEL: u64 = 1;
+
+    fn ibc_key(path: impl std::fmt::Display) -> String {
+        format!("{IBC_SUBSTORE_PREFIX}/{path}")
+    }
+
+    /// Sets up a light client, a connection over it, and two open transfer channels on that
+    /// connection: channel-0 (to a counterparty's channel-5) and channel-1 (to channel-9).
+    fn ibc_state() -> StateDelta<()> {
+        let mut state = StateDelta::new(());
+        state.put_chain_params(Default::default());
+        state.put_ibc_params(Default::default());
+        state.put_block_height(1);
+        state.put_block_timestamp(
+            Time::parse_from_rfc3339("2022-02-11T17:30:50.425417198Z").unwrap(),
+        );
+
+        // the Stargaze light client used in the IBC component tests.
+        let msg_create_client_raw = base64::decode(
+            include_str!("../../../ibc/src/component/test/create_client.msg").replace('\n', ""),
+        )
+        .unwrap();
+        let msg_create_client = MsgCreateClient::decode(msg_create_client_raw.as_slice()).unwrap();
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+        state.put(
+            ibc_key(ClientStatePath(client_id.clone())),
+            TendermintClientState::try_from(msg_create_client.client_state).unwrap(),
+        );
+
+        let connection_id = ConnectionId::new(0);
+        state.put(
+            ibc_key(ConnectionPath::new(&connection_id)),
+            ConnectionEnd {
+                state: ConnectionState::Open,
+                client_id: client_id.clone(),
+                counterparty: ConnectionCounterparty {
+                    client_id,
+                    connection_id: Some(ConnectionId::new(0)),
+                    prefix: IBC_COMMITMENT_PREFIX.clone(),
+                },
+                versions: vec![ConnectionVersion::default()],
+                delay_period: std::time::Duration::from_secs(0),
+            },
+        );
+
+        for (channel, counterparty_channel) in [(INBOUND_CHANNEL, 5), (FORWARD_CHANNEL, 9)] {
+            state.put(
+                ibc_key(ChannelEndPath::new(
+                    &PortId::transfer(),
+                    &ChannelId::new(channel),
+                )),
+                ChannelEnd {
+                    state: ChannelState::Open,
+                    ordering: ChannelOrder::Unordered,
+                    remote: Counterparty::new(
+                        PortId::transfer(),
+                        Some(ChannelId::new(counterparty_channel)),
+                    ),
+                    connection_hops: vec![connection_id.clone()],
+                    version: Version::new("ics20-1".to_string()),
+                },
+            );
+        }
+
+        state
+    }
+
+    /// A transfer of 100 uosmo into Penumbra, over channel-0.
+    fn inbound_packet(memo: &str) -> Packet {
+        let packet_data = FungibleTokenPacketData {
+            denom: "uosmo".to_string(),
+            amount: "100".to_string(),
+            sender: "osmo1sender".to_string(),
+            receiver: "penumbra1receiver".to_string(),
+            memo: memo.to_string(),
+        };
+        Packet {
+            sequence: 1u64.into(),
+            port_on_a: PortId::transfer(),
+            chan_on_a: ChannelId::new(5),
+            port_on_b: PortId::transfer(),
+            chan_on_b: ChannelId::new(INBOUND_CHANNEL),
+            data: serde_json::to_vec(&packet_data).unwrap(),
+            timeout_height_on_b: IbcHeight::new(0, 1_000_000).unwrap().into(),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(0).unwrap(),
+        }
+    }
+
+    /// The packet an `inbound_packet` is forwarded as, over channel-1.
+    fn forwarded_packet() -> Packet {
+        let packet_data = FungibleTokenPacketData {
+            denom: format!("transfer/channel-{INBOUND_CHANNEL}/uosmo"),
+            amount: "100".to_string(),
+            sender: "penumbra1receiver".to_string(),
+            receiver: "noble1receiver".to_string(),
+            memo: String::new(),
+        };
+        Packet {
+            sequence: 0u64.into(),
+            port_on_a: PortId::transfer(),
+            chan_on_a: ChannelId::new(FORWARD_CHANNEL),
+            port_on_b: PortId::transfer(),
+            chan_on_b: ChannelId::new(9),
+            data: serde_json::to_vec(&packet_data).unwrap(),
+            timeout_height_on_b: IbcHeight::new(0, 1_000_000).unwrap().into(),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(0).unwrap(),
+        }
+    }
+
+    const FORWARD_MEMO: &str = r#"{"forward": {"receiver": "noble1receiver", "port": "transfer", "channel": "channel-1"}}"#;
+
+    /// The asset ID of uosmo received over channel-0.
+    fn forwarded_denom_id() -> asset::Id {
+        let denom: DenomMetadata = format!("transfer/channel-{INBOUND_CHANNEL}/uosmo")
+            .as_str()
+            .try_into()
+            .unwrap();
+        denom.id()
+    }
+
+    async fn value_balance(state: &StateDelta<()>, channel: u64) -> Amount {
+        state
+            .get(&state_key::ics20_value_balance(
+                &ChannelId::new(channel),
+                &forwarded_denom_id(),
+            ))
+            .await
+            .unwrap()
+            .unwrap_or_else(Amount::zero)
+    }
+
+    async fn inbound_acknowledgement(state: &StateDelta<()>) -> Option<Vec<u8>> {
+        state
+            .get_packet_acknowledgement(&PortId::transfer(), &ChannelId::new(INBOUND_CHANNEL), 1)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn forwarded_transfer_is_acknowledged_once_delivered() -> anyhow::Result<()> {
+        let mut state = ibc_state();
+
+        recv_packet(&mut state, &inbound_packet(FORWARD_MEMO)).await;
+
+        // the tokens are sent straight on, and the inbound packet waits for the outcome.
+        assert!(state
+            .get_packet_commitment_by_id(&ChannelId::new(FORWARD_CHANNEL), &PortId::transfer(), 0)
+            .await?
+            .is_some());
+        assert_eq!(inbound_acknowledgement(&state).await, None);
+        assert_eq!(value_balance(&state, INBOUND_CHANNEL).await, 100u64.into());
+        assert_eq!(value_balance(&state, FORWARD_CHANNEL).await, 100u64.into());
+
+        let success: Vec<u8> = TokenTransferAcknowledgement::success().into();
+        acknowledge_packet_inner(&mut state, &forwarded_packet(), &success).await?;
+
+        assert!(inbound_acknowledgement(&state).await.is_some());
+        assert_eq!(value_balance(&state, INBOUND_CHANNEL).await, 100u64.into());
+        assert_eq!(value_balance(&state, FORWARD_CHANNEL).await, 100u64.into());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_forward_is_refunded_to_origin() -> anyhow::Result<()> {
+        let mut state = ibc_state();
+
+        recv_packet(&mut state, &inbound_packet(FORWARD_MEMO)).await;
+        let error: Vec<u8> =
+            TokenTransferAcknowledgement::Error("receiver rejected".to_string()).into();
+        acknowledge_packet_inner(&mut state, &forwarded_packet(), &error).await?;
+
+        // both legs are unwound, and the inbound packet fails so the origin chain refunds.
+        assert!(inbound_acknowledgement(&state).await.is_some());
+        assert_eq!(value_balance(&state, INBOUND_CHANNEL).await, Amount::zero());
+        assert_eq!(value_balance(&state, FORWARD_CHANNEL).await, Amount::zero());
+
+        // a second acknowledgement for the same packet has nothing left to unwind.
+        assert!(take_forwarded_packet(&mut state, &forwarded_packet())
+            .await?
+            .is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn timed_out_forward_is_refunded_to_origin() -> anyhow::Result<()> {
+        let mut state = ibc_state();
+
+        recv_packet(&mut state, &inbound_packet(FORWARD_MEMO)).await;
+        refund_packet_inner(
+            &mut state,
+            &forwarded_packet(),
+            "packet timed out".to_string(),
+        )
+        .await?;
+
+        assert!(inbound_acknowledgement(&state).await.is_some());
+        assert_eq!(value_balance(&state, INBOUND_CHANNEL).await, Amount::zero());
+        assert_eq!(value_balance(&state, FORWARD_CHANNEL).await, Amount::zero());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn forward_over_unknown_channel_fails_immediately() -> anyhow::Result<()> {
+        let mut state = ibc_state();
+
+        let memo = r#"{"forward": {"receiver": "noble1receiver", "port": "transfer", "channel": "channel-7"}}"#;
+        recv_packet(&mut state, &inbound_packet(memo)).await;
+
+        // the inbound packet is failed right away, leaving no trace of the transfer behind.
+        assert!(inbound_acknowledgement(&state).await.is_some());
+        assert_eq!(value_balance(&state, INBOUND_CHANNEL).await, Amount::zero());
+
+        Ok(())
+    }
+
+    /// Limits the net flow of forwarded uosmo over both channels to 150 per 10 blocks.
+    fn rate_limit(state: &mut StateDelta<()>) {
+        let limit = |channel| Ics20RateLimit {
+            channel_id: ChannelId::new(channel),
+            asset_id: forwarded_denom_id(),
+            max_net_inflow: 150u64.into(),
+            max_net_outflow: 150u64.into(),
+            window_blocks: 10,
+        };
+        state.put_ibc_params(IBCParameters {
+            ics20_rate_limits: vec![limit(INBOUND_CHANNEL), limit(FORWARD_CHANNEL)],
+            ..Default::default()
+        });
+    }
+
+    #[tokio::test]
+    async fn transfers_over_the_rate_limit_are_rejected() -> anyhow::Result<()> {
+        let mut state = ibc_state();
+        rate_limit(&mut state);
+
+        recv_packet(&mut state, &inbound_packet(FORWARD_MEMO)).await;
+        let mut second_packet = inbound_packet(FORWARD_MEMO);
+        second_packet.sequence = 2u64.into();
+        recv_packet(&mut state, &second_packet).await;
+
+        // the first transfer fits within the limit, but the second one would exceed it, so it is
+        // failed right away.
+        let (_, usage) = state
+            .ics20_rate_limit_usage(&ChannelId::new(INBOUND_CHANNEL), &forwarded_denom_id())
+            .await?
+            .unwrap();
+        assert_eq!(usage.unwrap().inflow, 100u64.into());
+        assert_eq!(value_balance(&state, INBOUND_CHANNEL).await, 100u64.into());
+        assert!(state
+            .get_packet_acknowledgement(&PortId::transfer(), &ChannelId::new(INBOUND_CHANNEL), 2)
+            .await?
+            .is_some());
+
+        // once the window has passed, there is room for more.
+        state.put_block_height(11);
+        recv_packet(&mut state, &{
+            let mut packet = inbound_packet(FORWARD_MEMO);
+            packet.sequence = 3u64.into();
+            packet
+        })
+        .await;
+        assert_eq!(value_balance(&state, INBOUND_CHANNEL).await, 200u64.into());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refunded_transfers_are_removed_from_the_rate_limit() -> anyhow::Result<()> {
+        let mut state = ibc_state();
+        rate_limit(&mut state);
+
+        recv_packet(&mut state, &inbound_packet(FORWARD_MEMO)).await;
+        refund_packet_inner(
+            &mut state,
+            &forwarded_packet(),
+            "packet timed out".to_string(),
+        )
+        .await?;
+
+        for channel in [INBOUND_CHANNEL, FORWARD_CHANNEL] {
+            let (_, usage) = state
+                .ics20_rate_limit_usage(&ChannelId::new(channel), &forwarded_denom_id())
+                .await?
+                .unwrap();
+            let usage = usage.unwrap();
+            assert_eq!(usage.inflow, Amount::zero());
+            assert_eq!(usage.outflow, Amount::zero());
+        }
+
+        Ok(())
+    }
+}
@@ -36,8 +36,15 @@ pub struct Ics20Withdrawal {
     pub timeout_time: u64,
     // the source channel used for the withdrawal
     pub source_channel: ChannelId,
+    // an optional memo to attach to the transfer packet (empty if unused), which may carry
+    // instructions for the destination chain, such as forwarding the transfer
+    pub memo: String,
 }
 
+/// The maximum length of an ICS-20 withdrawal memo, matching the limit ibc-go enforces on
+/// inbound transfers.
+pub const ICS20_WITHDRAWAL_MEMO_LIMIT: usize = 32_768;
+
 #[cfg(feature = "component")]
 impl From<Ics20Withdrawal> for IBCPacket<Unchecked> {
     fn from(withdrawal: Ics20Withdrawal) -> Self {
@@ -76,6 +83,10 @@ impl Ics20Withdrawal {
             anyhow::bail!("timeout time must be non-zero");
         }
 
+        if self.memo.len() > ICS20_WITHDRAWAL_MEMO_LIMIT {
+            anyhow::bail!("memo must fit within {ICS20_WITHDRAWAL_MEMO_LIMIT} bytes");
+        }
+
         // NOTE: we could validate the destination chain address as bech32 to prevent mistyped
         // addresses, but this would preclude sending to chains that don't use bech32 addresses.
 
@@ -97,6 +108,7 @@ impl From<Ics20Withdrawal> for pb::Ics20Withdrawal {
             timeout_height: Some(w.timeout_height.into()),
             timeout_time: w.timeout_time,
             source_channel: w.source_channel.to_string(),
+            memo: w.memo,
         }
     }
 }
@@ -126,6 +138,7 @@ impl TryFrom<pb::Ics20Withdrawal> for Ics20Withdrawal {
                 .try_into()?,
             timeout_time: s.timeout_time,
             source_channel: ChannelId::from_str(&s.source_channel)?,
+            memo: s.memo,
         })
     }
 }
@@ -137,6 +150,7 @@ impl From<Ics20Withdrawal> for pb::FungibleTokenPacketData {
             denom: w.denom.to_string(),
             receiver: w.destination_chain_address,
             sender: w.return_address.to_string(),
+            memo: w.memo,
         }
     }
 }
@@ -24,7 +24,9 @@ use penumbra_asset::{
     balance::{commitment::BalanceCommitmentVar, BalanceVar},
     Value,
 };
-use penumbra_proof_params::{DummyWitness, VerifyingKeyExt, GROTH16_PROOF_LENGTH_BYTES};
+use penumbra_proof_params::{
+    BatchVerifier, DummyWitness, VerifyingKeyExt, GROTH16_PROOF_LENGTH_BYTES,
+};
 
 /// Public:
 /// * vcm (value commitment)
@@ -169,18 +171,7 @@ impl OutputProof {
         let proof =
             Proof::deserialize_compressed_unchecked(&self.0[..]).map_err(|e| anyhow::anyhow!(e))?;
 
-        let mut public_inputs = Vec::new();
-        public_inputs.extend(
-            note_commitment
-                .0
-                .to_field_elements()
-                .ok_or_else(|| anyhow::anyhow!("note commitment is not a valid field element"))?,
-        );
-        public_inputs.extend(
-            balance_commitment.0.to_field_elements().ok_or_else(|| {
-                anyhow::anyhow!("balance commitment is not a valid field element")
-            })?,
-        );
+        let public_inputs = Self::public_inputs(balance_commitment, note_commitment)?;
 
         tracing::trace!(?public_inputs);
         let start = std::time::Instant::now();
@@ -195,6 +186,40 @@ impl OutputProof {
             .then_some(())
             .ok_or_else(|| anyhow::anyhow!("output proof did not verify"))
     }
+
+    /// Queues the proof for batch verification with the provided public inputs.
+    pub fn queue<'a>(
+        &self,
+        batch: &mut BatchVerifier<'a>,
+        vk: &'a PreparedVerifyingKey<Bls12_377>,
+        balance_commitment: balance::Commitment,
+        note_commitment: note::StateCommitment,
+    ) -> anyhow::Result<()> {
+        let proof = Proof::deserialize_compressed(&self.0[..]).map_err(|e| anyhow::anyhow!(e))?;
+        let public_inputs = Self::public_inputs(balance_commitment, note_commitment)?;
+        batch.queue(vk, "output proof", proof, public_inputs);
+        Ok(())
+    }
+
+    fn public_inputs(
+        balance_commitment: balance::Commitment,
+        note_commitment: note::StateCommitment,
+    ) -> anyhow::Result<Vec<Fq>> {
+        let mut public_inputs = Vec::new();
+        public_inputs.extend(
+            note_commitment
+                .0
+                .to_field_elements()
+                .ok_or_else(|| anyhow::anyhow!("note commitment is not a valid field element"))?,
+        );
+        public_inputs.extend(
+            balance_commitment.0.to_field_elements().ok_or_else(|| {
+                anyhow::anyhow!("balance commitment is not a valid field element")
+            })?,
+        );
+
+        Ok(public_inputs)
+    }
 }
 
 impl DomainType for OutputProof {
@@ -381,4 +406,70 @@ mod tests {
         assert!(proof_result.is_err());
     }
         }
+
+    #[test]
+    fn output_proof_batch_verification() {
+        let mut rng = OsRng;
+        let (pk, vk) = generate_prepared_test_parameters::<OutputCircuit>(&mut rng);
+
+        let sk_recipient =
+            SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(rng), &Bip44Path::new(0));
+        let (dest, _dtk_d) = sk_recipient
+            .full_viewing_key()
+            .incoming()
+            .payment_address(0u32.into());
+
+        let mut outputs = Vec::new();
+        for amount in 1..=3u64 {
+            let value_to_send = Value {
+                amount: amount.into(),
+                asset_id: asset::Cache::with_known_assets()
+                    .get_unit("upenumbra")
+                    .unwrap()
+                    .id(),
+            };
+            let note = Note::generate(&mut rng, &dest, value_to_send);
+            let note_commitment = note.commit();
+            let v_blinding = Fr::rand(&mut rng);
+            let balance_commitment = (-Balance::from(value_to_send)).commit(v_blinding);
+            let proof = OutputProof::prove(
+                Fq::rand(&mut rng),
+                Fq::rand(&mut rng),
+                &pk,
+                note,
+                v_blinding,
+                balance_commitment,
+                note_commitment,
+            )
+            .expect("can create proof");
+            outputs.push((proof, balance_commitment, note_commitment));
+        }
+
+        let mut batch = BatchVerifier::new();
+        for (proof, balance_commitment, note_commitment) in &outputs {
+            proof
+                .queue(&mut batch, &vk, *balance_commitment, *note_commitment)
+                .unwrap();
+        }
+        assert_eq!(batch.len(), 3);
+        batch
+            .verify(&mut rng)
+            .expect("valid proofs verify in a batch");
+
+        // Swapping the public inputs of two proofs makes both invalid, and the
+        // first invalid proof is reported.
+        let mut batch = BatchVerifier::new();
+        for (i, (proof, balance_commitment, note_commitment)) in outputs.iter().enumerate() {
+            let note_commitment = match i {
+                1 => outputs[2].2,
+                2 => outputs[1].2,
+                _ => *note_commitment,
+            };
+            proof
+                .queue(&mut batch, &vk, *balance_commitment, note_commitment)
+                .unwrap();
+        }
+        let err = batch.verify(&mut rng).unwrap_err();
+        assert_eq!(err.to_string(), "output proof 2 of 3 did not verify");
+    }
 }
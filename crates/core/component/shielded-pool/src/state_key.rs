@@ -1,3 +1,4 @@
+use ibc_types::core::channel::ChannelId;
 use penumbra_asset::asset;
 use penumbra_sct::Nullifier;
 use std::string::String;
@@ -18,6 +19,12 @@ pub fn spent_nullifier_lookup(nullifier: &Nullifier) -> String {
     format!("shielded_pool/spent_nullifiers/{nullifier}")
 }
 
+/// An inbound ICS-20 packet, awaiting the outcome of the packet it was forwarded as, which was
+/// sent over `channel_id` with the given sequence number.
+pub fn forwarded_packet(channel_id: &ChannelId, sequence: u64) -> String {
+    format!("shielded_pool/ics20/forwarded_packets/{channel_id}/{sequence}")
+}
+
 // State keys used to temporarily store payloads and nullifiers to be inserted into the compact
 // block
 
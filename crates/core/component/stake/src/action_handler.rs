@@ -1,6 +1,9 @@
 use penumbra_component::ActionHandler;
 
 mod delegate;
+mod redelegate;
+mod redelegate_claim;
 mod undelegate;
 mod undelegate_claim;
 mod validator_definition;
+mod validator_unjail;
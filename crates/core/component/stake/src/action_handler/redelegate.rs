@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use async_trait::async_trait;
+use penumbra_storage::{StateRead, StateWrite};
+
+use crate::{
+    action_handler::ActionHandler, component::StateWriteExt as _, event, validator, Redelegate,
+    StateReadExt as _,
+};
+
+#[async_trait]
+impl ActionHandler for Redelegate {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        ensure!(
+            self.from_validator_identity != self.to_validator_identity,
+            "cannot redelegate from validator {} to itself",
+            self.from_validator_identity,
+        );
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        let r = self;
+        let from_rate_data = state
+            .current_validator_rate(&r.from_validator_identity)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("unknown validator identity {}", r.from_validator_identity)
+            })?;
+        let to_rate_data = state
+            .current_validator_rate(&r.to_validator_identity)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("unknown validator identity {}", r.to_validator_identity)
+            })?;
+
+        // Check whether the epoch is correct first, to give a more helpful
+        // error message if it's wrong.
+        if r.epoch_index != from_rate_data.epoch_index {
+            anyhow::bail!(
+                "redelegation was prepared for epoch {} but the next epoch is {}",
+                r.epoch_index,
+                from_rate_data.epoch_index
+            );
+        }
+
+        // The destination validator must accept delegations, just as for a
+        // `Delegate` action.
+        let validator = state
+            .validator(&r.to_validator_identity)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("missing definition for validator"))?;
+        let validator_state = state
+            .validator_state(&r.to_validator_identity)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("missing state for validator"))?;
+
+        use validator::State::*;
+        if !validator.enabled {
+            anyhow::bail!(
+                "redelegations are only allowed to enabled validators, but {} is disabled",
+                r.to_validator_identity,
+            );
+        }
+        if !matches!(validator_state, Inactive | Active) {
+            anyhow::bail!(
+                "redelegations are only allowed to active or inactive validators, but {} is in state {:?}",
+                r.to_validator_identity,
+                validator_state,
+            );
+        }
+
+        // As for an undelegation, the unbonded amount is computed from the
+        // source delegation amount, and as for a delegation, the destination
+        // delegation amount is computed from the unbonded amount.  See the
+        // `Undelegate` and `Delegate` handlers for why the direction matters.
+        let expected_unbonded_amount =
+            from_rate_data.unbonded_amount(r.from_delegation_amount.value());
+        ensure!(
+            r.unbonded_amount.value() == expected_unbonded_amount,
+            "redelegation amount {} does not match expected amount {}",
+            r.unbonded_amount,
+            expected_unbonded_amount,
+        );
+
+        let expected_to_delegation_amount =
+            to_rate_data.delegation_amount(r.unbonded_amount.value());
+        ensure!(
+            r.to_delegation_amount.value() == expected_to_delegation_amount,
+            "given {} unbonded stake, expected {} delegation tokens but description produces {}",
+            r.unbonded_amount,
+            expected_to_delegation_amount,
+            r.to_delegation_amount,
+        );
+
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        tracing::debug!(?self, "queuing redelegation for next epoch");
+        // The redelegated stake remains liable for slashing of the source
+        // validator until its original unbonding period ends, which is applied
+        // when the escrowed redelegation tokens are claimed.
+        state.stub_push_redelegation(self.clone());
+
+        state.record(event::redelegate(self));
+
+        Ok(())
+    }
+}
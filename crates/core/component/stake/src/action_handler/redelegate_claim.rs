@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use async_trait::async_trait;
+use penumbra_chain::component::StateReadExt;
+use penumbra_shielded_pool::component::SupplyWrite;
+use penumbra_storage::{StateRead, StateWrite};
+
+use crate::RedelegateClaim;
+use crate::{action_handler::ActionHandler, StateReadExt as _};
+
+#[async_trait]
+impl ActionHandler for RedelegateClaim {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        ensure!(
+            self.from_validator_identity != self.to_validator_identity,
+            "cannot claim a redelegation from validator {} to itself",
+            self.from_validator_identity,
+        );
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        // As for an undelegate claim, we need to check two things:
+
+        // 1. That we're past the unbonding end epoch of the source validator.
+
+        let current_epoch = state.epoch().await?;
+        let end_epoch_index = state
+            .unbonding_end_epoch_for(&self.from_validator_identity, self.start_epoch_index)
+            .await?;
+        ensure!(
+            current_epoch.index >= end_epoch_index,
+            "cannot claim redelegation tokens before the end epoch"
+        );
+
+        // 2. That the penalty of the source validator is correct.
+        let expected_penalty = state
+            .compounded_penalty_over_range(
+                &self.from_validator_identity,
+                self.start_epoch_index,
+                end_epoch_index,
+            )
+            .await?;
+        ensure!(
+            self.penalty == expected_penalty,
+            "penalty does not match expected penalty"
+        );
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // The escrowed delegation tokens were counted in the destination
+        // validator's supply when the redelegation took effect, so the ones
+        // forfeited to the source validator's penalty are burned here.
+        let slashed_amount = self.redelegation_amount - self.delegation_amount();
+        if slashed_amount.value() > 0 {
+            tracing::debug!(
+                ?self,
+                %slashed_amount,
+                "burning redelegated delegation tokens slashed by the source validator"
+            );
+            state
+                .update_token_supply(
+                    &self.delegation_token().id(),
+                    -(slashed_amount.value() as i128),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,14 +1,34 @@
 use std::sync::Arc;
 
-use anyhow::{ensure, Result};
+use anyhow::{ensure, Context, Result};
 use async_trait::async_trait;
 use penumbra_chain::component::StateReadExt;
-use penumbra_proof_params::UNDELEGATECLAIM_PROOF_VERIFICATION_KEY;
+use penumbra_proof_params::{BatchVerifier, UNDELEGATECLAIM_PROOF_VERIFICATION_KEY};
 use penumbra_storage::{StateRead, StateWrite};
 
 use crate::UndelegateClaim;
 use crate::{action_handler::ActionHandler, StateReadExt as _, UnbondingToken};
 
+impl UndelegateClaim {
+    /// Checks the undelegate claim statelessly, like `check_stateless`, except that its proof is
+    /// queued in `batch` instead of being verified, so that it can be verified along
+    /// with the other proofs of the transaction.
+    pub fn check_stateless_batched(&self, batch: &mut BatchVerifier<'static>) -> Result<()> {
+        let unbonding_id =
+            UnbondingToken::new(self.body.validator_identity, self.body.start_epoch_index).id();
+
+        self.proof
+            .queue(
+                batch,
+                &UNDELEGATECLAIM_PROOF_VERIFICATION_KEY,
+                self.body.balance_commitment,
+                unbonding_id,
+                self.body.penalty,
+            )
+            .context("invalid undelegate claim proof")
+    }
+}
+
 #[async_trait]
 impl ActionHandler for UndelegateClaim {
     type CheckStatelessContext = ();
@@ -16,14 +36,14 @@ impl ActionHandler for UndelegateClaim {
         let unbonding_id =
             UnbondingToken::new(self.body.validator_identity, self.body.start_epoch_index).id();
 
-        self.proof.verify(
-            &UNDELEGATECLAIM_PROOF_VERIFICATION_KEY,
-            self.body.balance_commitment,
-            unbonding_id,
-            self.body.penalty,
-        )?;
-
-        Ok(())
+        self.proof
+            .verify(
+                &UNDELEGATECLAIM_PROOF_VERIFICATION_KEY,
+                self.body.balance_commitment,
+                unbonding_id,
+                self.body.penalty,
+            )
+            .context("an undelegate claim proof did not verify")
     }
 
     async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
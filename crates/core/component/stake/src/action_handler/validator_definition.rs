@@ -8,8 +8,10 @@ use std::sync::Arc;
 use penumbra_proto::DomainType;
 
 use crate::{
-    action_handler::ActionHandler, component::StakingImpl as _, rate::RateData, validator,
-    StateReadExt as _,
+    action_handler::ActionHandler,
+    component::{StakingImpl as _, StateWriteExt as _},
+    rate::RateData,
+    validator, StateReadExt as _,
 };
 
 #[async_trait]
@@ -26,12 +28,7 @@ impl ActionHandler for validator::Definition {
 
         // TODO(hdevalence) -- is this duplicated by the check during parsing?
         // Check that the funding streams do not exceed 100% commission (10000bps)
-        let total_funding_bps = self
-            .validator
-            .funding_streams
-            .iter()
-            .map(|fs| fs.rate_bps() as u64)
-            .sum::<u64>();
+        let total_funding_bps = self.validator.funding_streams.commission_bps();
 
         if total_funding_bps > 10000 {
             anyhow::bail!(
@@ -58,6 +55,26 @@ impl ActionHandler for validator::Definition {
                     current_seq
                 );
             }
+
+            // Check that the commission does not move further from its value at
+            // the start of the epoch than the chain allows.
+            let max_change = state
+                .get_stake_params()
+                .await?
+                .max_commission_change_bps_per_epoch;
+            let epoch_start_commission = state
+                .epoch_start_commission(&v.validator.identity_key)
+                .await?
+                .unwrap_or_else(|| existing_v.funding_streams.commission_bps());
+            let new_commission = v.validator.funding_streams.commission_bps();
+            let change = new_commission.abs_diff(epoch_start_commission);
+            if change > max_change {
+                anyhow::bail!(
+                    "validator commission would change by {}bps this epoch, greater than the maximum of {}bps",
+                    change,
+                    max_change
+                );
+            }
         }
 
         // Check whether the consensus key has already been used by another validator.
@@ -98,16 +115,44 @@ impl ActionHandler for validator::Definition {
             .await
             .context("should be able to get current epoch during validator definition execution")?;
 
-        if state
+        if let Some(existing_v) = state
             .validator(&v.validator.identity_key)
             .await
             .context("should be able to fetch validator during validator definition execution")?
-            .is_some()
         {
             // This is an existing validator definition.
-            state.update_validator(v.validator.clone()).await.context(
-                "should be able to update validator during validator definition execution",
-            )?;
+            let notice_epochs = state
+                .get_stake_params()
+                .await?
+                .commission_increase_notice_epochs;
+            let current_commission = existing_v.funding_streams.commission_bps();
+            let new_commission = v.validator.funding_streams.commission_bps();
+
+            if new_commission > current_commission && notice_epochs > 0 {
+                // Commission increases are scheduled, giving delegators at
+                // least `notice_epochs` full epochs to react before the new
+                // funding streams take effect. Any earlier pending funding
+                // streams are replaced.
+                state.put_pending_validator_definition(validator::PendingDefinition {
+                    identity_key: v.validator.identity_key,
+                    funding_streams: v.validator.funding_streams.clone(),
+                    effective_epoch_index: cur_epoch.index + notice_epochs + 1,
+                });
+
+                // The rest of the definition takes effect immediately.
+                let mut validator = v.validator.clone();
+                validator.funding_streams = existing_v.funding_streams;
+                state.update_validator(validator).await.context(
+                    "should be able to update validator during validator definition execution",
+                )?;
+            } else {
+                // Any other update takes effect immediately, and supersedes
+                // a pending commission increase.
+                state.delete_pending_validator_definition(&v.validator.identity_key);
+                state.update_validator(v.validator.clone()).await.context(
+                    "should be able to update validator during validator definition execution",
+                )?;
+            }
         } else {
             // This is a new validator definition.
             // Set the default rates and state.
@@ -0,0 +1,110 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use penumbra_chain::component::StateReadExt as _;
+use penumbra_proto::DomainType;
+use penumbra_storage::{StateRead, StateWrite};
+
+use std::sync::Arc;
+
+use crate::{
+    action_handler::ActionHandler,
+    component::StakingImpl as _,
+    validator::{self, Unjail, UnjailBody},
+    StateReadExt as _,
+};
+
+#[async_trait]
+impl ActionHandler for Unjail {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        let Unjail { body, auth_sig } = self;
+
+        // Check the signature using the key named in the body, which is checked
+        // against the validator's definition during stateful checks.
+        let body_bytes = body.encode_to_vec();
+        body.governance_key
+            .0
+            .verify(&body_bytes, auth_sig)
+            .context("validator unjail signature failed to verify")?;
+
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        let UnjailBody {
+            identity_key,
+            governance_key,
+            jail_height,
+        } = &self.body;
+
+        let validator = state
+            .validator(identity_key)
+            .await?
+            .with_context(|| format!("validator {identity_key} does not exist"))?;
+
+        // Either the validator's governance key or its identity key may
+        // authorize the request.
+        if validator.governance_key != *governance_key && governance_key.0 != identity_key.0 {
+            anyhow::bail!(
+                "key {} is neither the governance key nor the identity key of validator {}",
+                governance_key,
+                identity_key
+            );
+        }
+
+        let validator_state = state
+            .validator_state(identity_key)
+            .await?
+            .with_context(|| format!("validator {identity_key} has no state"))?;
+        if validator_state != validator::State::Jailed {
+            anyhow::bail!("validator {identity_key} is {validator_state}, not jailed");
+        }
+
+        // Validators jailed before jail heights were recorded can be released
+        // immediately.
+        let recorded_jail_height = state
+            .validator_jail_height(identity_key)
+            .await?
+            .unwrap_or(0);
+        if *jail_height != recorded_jail_height {
+            anyhow::bail!(
+                "validator {identity_key} was jailed at height {recorded_jail_height}, not {jail_height}"
+            );
+        }
+
+        let jail_duration = state.get_stake_params().await?.jail_duration_blocks;
+        let release_height = recorded_jail_height.saturating_add(jail_duration);
+        let current_height = state.get_block_height().await?;
+        if current_height < release_height {
+            anyhow::bail!(
+                "validator {identity_key} can't be unjailed until height {release_height} (current height is {current_height})"
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let identity_key = &self.body.identity_key;
+
+        state
+            .set_validator_state(identity_key, validator::State::Inactive)
+            .await
+            .context("should be able to release validator from jail")?;
+
+        // The operator may have disabled the validator while it was in jail.
+        let enabled = state
+            .validator(identity_key)
+            .await?
+            .context("unjailed validator should exist")?
+            .enabled;
+        if !enabled {
+            state
+                .set_validator_state(identity_key, validator::State::Disabled)
+                .await
+                .context("should be able to disable unjailed validator")?;
+        }
+
+        Ok(())
+    }
+}
@@ -10,6 +10,9 @@ pub mod metrics;
 pub mod rpc;
 pub use self::metrics::register_metrics;
 
+#[cfg(test)]
+mod tests;
+
 // TODO: move into leaf submodules under component/ and re-export
 
 use anyhow::{anyhow, Context, Result};
@@ -55,7 +58,7 @@ use crate::{
     validator::{self, Validator},
     CurrentConsensusKeys, DelegationChanges, Penalty, Uptime, {DelegationToken, IdentityKey},
 };
-use crate::{Delegate, Undelegate};
+use crate::{Delegate, Redelegate, Undelegate};
 
 // Max validator power is 1152921504606846975 (i64::MAX / 8)
 // https://github.com/tendermint/tendermint/blob/master/types/validator_set.go#L25
@@ -221,9 +224,21 @@ pub(crate) trait StakingImpl: StateWriteExt {
                 Ok(())
             }
             (Jailed, Inactive) => {
-                // We don't really have to do anything here; the validator was already
-                // slashed, and we're just allowing it to return to society.
+                // The validator was already slashed, and we're just allowing it
+                // to return to society.
                 tracing::debug!("releasing validator from jail");
+                self.delete(state_key::jail_height_by_validator(identity_key));
+
+                // Reset the validator's uptime, so that the blocks it missed
+                // before it was jailed don't count against it.
+                self.set_validator_uptime(
+                    identity_key,
+                    Uptime::new(
+                        self.get_block_height().await?,
+                        self.signed_blocks_window_len().await? as usize,
+                    ),
+                );
+
                 self.put(state_key, Inactive);
 
                 Ok(())
@@ -264,6 +279,11 @@ pub(crate) trait StakingImpl: StateWriteExt {
                 )
                 .await;
 
+                // Record when the validator was jailed, so we know when it
+                // may be released.
+                let height = self.get_block_height().await?;
+                self.put_proto(state_key::jail_height_by_validator(identity_key), height);
+
                 // Finally, set the validator to be jailed.
                 self.put(state_key, Jailed);
 
@@ -305,6 +325,7 @@ pub(crate) trait StakingImpl: StateWriteExt {
         // Collect all the delegation changes that occurred in the epoch we are ending.
         let mut delegations_by_validator = BTreeMap::<IdentityKey, Vec<Delegate>>::new();
         let mut undelegations_by_validator = BTreeMap::<IdentityKey, Vec<Undelegate>>::new();
+        let mut redelegations = Vec::<Redelegate>::new();
 
         let end_height = self.get_block_height().await?;
 
@@ -328,6 +349,24 @@ pub(crate) trait StakingImpl: StateWriteExt {
                     .or_default()
                     .push(u);
             }
+            redelegations.extend(changes.redelegations);
+        }
+
+        // Redelegations move delegation tokens between validators' pools
+        // without changing the staking token supply, so we track them
+        // separately from delegations and undelegations.  The destination
+        // validator's delegation tokens count towards its pool right away,
+        // even though they're held in escrow as redelegation tokens until the
+        // source validator's unbonding period ends.
+        let mut redelegated_in_by_validator = BTreeMap::<IdentityKey, u128>::new();
+        let mut redelegated_out_by_validator = BTreeMap::<IdentityKey, u128>::new();
+        for r in &redelegations {
+            *redelegated_in_by_validator
+                .entry(r.to_validator_identity.clone())
+                .or_default() += r.to_delegation_amount.value();
+            *redelegated_out_by_validator
+                .entry(r.from_validator_identity.clone())
+                .or_default() += r.from_delegation_amount.value();
         }
 
         tracing::debug!(
@@ -335,6 +374,7 @@ pub(crate) trait StakingImpl: StateWriteExt {
                 .sum::<usize>(),
             total_undelegations = ?undelegations_by_validator.values().map(|v| v.len())
                 .sum::<usize>(),
+            total_redelegations = ?redelegations.len(),
                 epoch_start_height = epoch_to_end.start_height,
                 epoch_end_height = end_height,
                 "calculated delegation changes for epoch"
@@ -432,12 +472,25 @@ pub(crate) trait StakingImpl: StateWriteExt {
                 .flat_map(|us| us.iter().map(|u| u.delegation_amount.value()))
                 .sum::<u128>();
             let delegation_delta = (total_delegations as i128) - (total_undelegations as i128);
+            let total_redelegated_in = redelegated_in_by_validator
+                .get(&validator.identity_key)
+                .copied()
+                .unwrap_or_default();
+            let total_redelegated_out = redelegated_out_by_validator
+                .get(&validator.identity_key)
+                .copied()
+                .unwrap_or_default();
+            let redelegation_delta =
+                (total_redelegated_in as i128) - (total_redelegated_out as i128);
 
             tracing::debug!(
                 validator = ?validator.identity_key,
                 total_delegations,
                 total_undelegations,
-                delegation_delta
+                delegation_delta,
+                total_redelegated_in,
+                total_redelegated_out,
+                redelegation_delta,
             );
 
             // Delegations and undelegations created in the previous epoch were created
@@ -457,7 +510,7 @@ pub(crate) trait StakingImpl: StateWriteExt {
             // update the delegation token supply in the JMT
             self.update_token_supply(
                 &DelegationToken::from(validator.identity_key).id(),
-                delegation_delta,
+                delegation_delta + redelegation_delta,
             )
             .await?;
             // update the staking token supply in the JMT
@@ -528,6 +581,11 @@ pub(crate) trait StakingImpl: StateWriteExt {
             tracing::debug!(?delegation_denom);
         }
 
+        // Commission increases which have waited out their notice period take
+        // effect at the start of the next epoch.
+        self.apply_pending_validator_definitions(epoch_to_end.index + 1)
+            .await?;
+
         // Now that all the voting power has been calculated for the upcoming epoch,
         // we can determine which validators are Active for the next epoch.
         self.process_validator_unbondings().await?;
@@ -614,6 +672,35 @@ pub(crate) trait StakingImpl: StateWriteExt {
         Ok(())
     }
 
+    /// Applies the pending funding streams that take effect in the epoch with
+    /// the given index, and records each validator's commission at the start
+    /// of that epoch.
+    async fn apply_pending_validator_definitions(&mut self, epoch_index: u64) -> Result<()> {
+        for pending in self.pending_validator_definitions().await? {
+            if pending.effective_epoch_index > epoch_index {
+                continue;
+            }
+            self.delete_pending_validator_definition(&pending.identity_key);
+
+            let mut validator = self
+                .validator(&pending.identity_key)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("pending funding streams for unknown validator"))?;
+            tracing::debug!(identity_key = ?pending.identity_key, "applying pending funding streams");
+            validator.funding_streams = pending.funding_streams;
+            self.update_validator(validator).await?;
+        }
+
+        for validator in self.validator_list().await? {
+            self.set_epoch_start_commission(
+                &validator.identity_key,
+                validator.funding_streams.commission_bps(),
+            );
+        }
+
+        Ok(())
+    }
+
     /// Materializes the entire current validator set as a Tendermint update.
     ///
     /// This re-defines all validators every time, to simplify the code compared to
@@ -878,11 +965,12 @@ pub(crate) trait StakingImpl: StateWriteExt {
                 // The operator has enabled their validator, so set it to Inactive.
                 self.set_validator_state(id, Inactive).await?;
             }
-            (Jailed, true) => {
-                // Treat updates to jailed validators as unjail requests.
-                self.set_validator_state(id, Inactive).await?;
+            (Jailed, _) => {
+                // Jailed validators can only be released by a `ValidatorUnjail`
+                // action, once their jail duration has elapsed, so updates don't
+                // affect their state.
             }
-            (Active | Inactive | Jailed | Disabled, false) => {
+            (Active | Inactive | Disabled, false) => {
                 // The operator has disabled their validator.
                 self.set_validator_state(id, Disabled).await?;
             }
@@ -1126,10 +1214,48 @@ pub trait StateReadExt: StateRead {
         self.get_proto(&state_key::power_by_validator(identity_key))
     }
 
+    /// The height at which the validator was jailed, if it is jailed.
+    fn validator_jail_height(
+        &self,
+        identity_key: &IdentityKey,
+    ) -> ProtoFuture<u64, Self::GetRawFut> {
+        self.get_proto(&state_key::jail_height_by_validator(identity_key))
+    }
+
     async fn validator(&self, identity_key: &IdentityKey) -> Result<Option<Validator>> {
         self.get(&state_key::validators::by_id(identity_key)).await
     }
 
+    /// The validator's funding streams waiting out the commission increase
+    /// notice period, if there are any.
+    async fn pending_validator_definition(
+        &self,
+        identity_key: &IdentityKey,
+    ) -> Result<Option<validator::PendingDefinition>> {
+        self.get(&state_key::pending_definition_by_validator(identity_key))
+            .await
+    }
+
+    /// Returns all funding streams waiting out the commission increase notice
+    /// period.
+    async fn pending_validator_definitions(&self) -> Result<Vec<validator::PendingDefinition>> {
+        self.prefix(state_key::pending_definitions())
+            .map_ok(|(_key, pending)| pending)
+            .try_collect()
+            .await
+    }
+
+    /// The validator's commission at the start of the current epoch, in basis
+    /// points, if the validator existed then.
+    fn epoch_start_commission(
+        &self,
+        identity_key: &IdentityKey,
+    ) -> ProtoFuture<u64, Self::GetRawFut> {
+        self.get_proto(&state_key::epoch_start_commission_by_validator(
+            identity_key,
+        ))
+    }
+
     fn validator_consensus_key(
         &self,
         identity_key: &IdentityKey,
@@ -1349,12 +1475,36 @@ pub trait StateWriteExt: StateWrite {
         self.put_stub_delegation_changes(changes);
     }
 
+    fn stub_push_redelegation(&mut self, redelegation: Redelegate) {
+        let mut changes = self.stub_delegation_changes();
+        changes.redelegations.push(redelegation);
+        self.put_stub_delegation_changes(changes);
+    }
+
     fn stub_push_undelegation(&mut self, undelegation: Undelegate) {
         let mut changes = self.stub_delegation_changes();
         changes.undelegations.push(undelegation);
         self.put_stub_delegation_changes(changes);
     }
 
+    fn put_pending_validator_definition(&mut self, pending: validator::PendingDefinition) {
+        self.put(
+            state_key::pending_definition_by_validator(&pending.identity_key),
+            pending,
+        );
+    }
+
+    fn delete_pending_validator_definition(&mut self, identity_key: &IdentityKey) {
+        self.delete(state_key::pending_definition_by_validator(identity_key));
+    }
+
+    fn set_epoch_start_commission(&mut self, identity_key: &IdentityKey, commission_bps: u64) {
+        self.put_proto(
+            state_key::epoch_start_commission_by_validator(identity_key),
+            commission_bps,
+        );
+    }
+
     #[instrument(skip(self))]
     fn set_base_rate(&mut self, current: BaseRateData) {
         tracing::debug!("setting base rate");
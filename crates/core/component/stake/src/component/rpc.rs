@@ -6,7 +6,8 @@ use penumbra_chain::component::StateReadExt as _;
 use penumbra_proto::{
     core::component::stake::v1alpha1::{
         query_service_server::QueryService, CurrentValidatorRateRequest,
-        CurrentValidatorRateResponse, ValidatorInfoRequest, ValidatorInfoResponse,
+        CurrentValidatorRateResponse, PendingValidatorDefinitionsRequest,
+        PendingValidatorDefinitionsResponse, ValidatorInfoRequest, ValidatorInfoResponse,
         ValidatorPenaltyRequest, ValidatorPenaltyResponse, ValidatorStatusRequest,
         ValidatorStatusResponse,
     },
@@ -34,6 +35,12 @@ impl Server {
 impl QueryService for Server {
     type ValidatorInfoStream =
         Pin<Box<dyn futures::Stream<Item = Result<ValidatorInfoResponse, tonic::Status>> + Send>>;
+    type PendingValidatorDefinitionsStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<PendingValidatorDefinitionsResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
 
     #[instrument(skip(self, request), fields(show_inactive = request.get_ref().show_inactive))]
     async fn validator_info(
@@ -82,6 +89,31 @@ impl QueryService for Server {
         ))
     }
 
+    #[instrument(skip(self, request))]
+    async fn pending_validator_definitions(
+        &self,
+        request: tonic::Request<PendingValidatorDefinitionsRequest>,
+    ) -> Result<tonic::Response<Self::PendingValidatorDefinitionsStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let pending = state.pending_validator_definitions().await.map_err(|e| {
+            tonic::Status::unavailable(format!("error listing pending validator definitions: {e}"))
+        })?;
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(pending.into_iter().map(|p| {
+                Ok(PendingValidatorDefinitionsResponse {
+                    pending_definition: Some(p.into()),
+                })
+            }))
+            .boxed(),
+        ))
+    }
+
     #[instrument(skip(self, request))]
     async fn validator_status(
         &self,
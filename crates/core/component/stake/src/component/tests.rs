@@ -0,0 +1,449 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use decaf377_rdsa::{SigningKey, SpendAuth};
+use penumbra_chain::component::StateWriteExt as _;
+use penumbra_component::ActionHandler;
+use penumbra_num::Amount;
+use penumbra_proto::DomainType;
+use penumbra_shielded_pool::component::{SupplyRead as _, SupplyWrite as _};
+use penumbra_storage::{ArcStateDeltaExt, StateDelta, StateWrite, TempStorage};
+use rand_core::OsRng;
+
+use crate::{
+    component::{StakingImpl as _, StateReadExt as _, StateWriteExt as _},
+    params::StakeParameters,
+    rate::BaseRateData,
+    validator::{self, Unjail, UnjailBody, Validator},
+    DelegationToken, FundingStream, FundingStreams, GovernanceKey, IdentityKey, Penalty,
+    RedelegateClaim,
+};
+
+const JAIL_DURATION: u64 = 10;
+
+fn put_height<S: StateWrite>(state: &mut S, height: u64) {
+    state.put_block_height(height);
+    state.put_epoch_by_height(
+        height,
+        penumbra_chain::Epoch {
+            index: 0,
+            start_height: 0,
+        },
+    );
+}
+
+fn test_validator(identity_key: IdentityKey) -> Validator {
+    let consensus_key = ed25519_consensus::SigningKey::new(OsRng);
+    Validator {
+        identity_key: identity_key.clone(),
+        governance_key: GovernanceKey(identity_key.0),
+        consensus_key: tendermint::PublicKey::from_raw_ed25519(
+            consensus_key.verification_key().as_bytes(),
+        )
+        .expect("ed25519 verification key is valid"),
+        name: "test validator".to_string(),
+        website: String::new(),
+        description: String::new(),
+        enabled: true,
+        funding_streams: FundingStreams::new(),
+        sequence_number: 0,
+    }
+}
+
+fn unjail(
+    signing_key: &SigningKey<SpendAuth>,
+    identity_key: IdentityKey,
+    jail_height: u64,
+) -> Unjail {
+    let body = UnjailBody {
+        identity_key,
+        governance_key: GovernanceKey(signing_key.into()),
+        jail_height,
+    };
+    let auth_sig = signing_key.sign(OsRng, &body.encode_to_vec());
+    Unjail { body, auth_sig }
+}
+
+#[tokio::test]
+/// Test that a jailed validator can only be unjailed once its jail duration
+/// has elapsed, and only once per jailing.
+async fn unjail_after_jail_duration() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+    let identity_key = IdentityKey((&signing_key).into());
+
+    // Start with an active genesis validator.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    put_height(&mut state_tx, 0);
+    state_tx.put_stake_params(StakeParameters {
+        signed_blocks_window_len: 10,
+        missed_blocks_maximum: 5,
+        jail_duration_blocks: JAIL_DURATION,
+        ..Default::default()
+    });
+    state_tx
+        .add_genesis_validator(
+            &BTreeMap::new(),
+            &BaseRateData {
+                epoch_index: 0,
+                base_reward_rate: 0,
+                base_exchange_rate: 1_0000_0000,
+            },
+            test_validator(identity_key.clone()),
+        )
+        .await?;
+    state_tx.apply();
+
+    // Jail the validator at height 5.
+    let jail_height = 5;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    put_height(&mut state_tx, jail_height);
+    state_tx
+        .set_validator_state(&identity_key, validator::State::Jailed)
+        .await?;
+    state_tx.apply();
+    assert_eq!(
+        state.validator_jail_height(&identity_key).await?,
+        Some(jail_height)
+    );
+
+    let action = unjail(&signing_key, identity_key.clone(), jail_height);
+    action.check_stateless(()).await?;
+
+    // A request signed by an unrelated key is rejected.
+    let other_key = SigningKey::<SpendAuth>::new(OsRng);
+    let forged = unjail(&other_key, identity_key.clone(), jail_height);
+    forged.check_stateless(()).await?;
+    assert!(forged.check_stateful(state.clone()).await.is_err());
+
+    // Definition updates don't release the validator from jail.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    let mut update = test_validator(identity_key.clone());
+    update.sequence_number = 1;
+    state_tx.update_validator(update).await?;
+    state_tx.apply();
+    assert_eq!(
+        state.validator_state(&identity_key).await?,
+        Some(validator::State::Jailed)
+    );
+
+    // Unjailing before the jail duration has elapsed is rejected.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    put_height(&mut state_tx, jail_height + JAIL_DURATION - 1);
+    state_tx.apply();
+    assert!(action.check_stateful(state.clone()).await.is_err());
+
+    // Once it has elapsed, a request for the wrong jailing is still rejected...
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    let release_height = jail_height + JAIL_DURATION;
+    put_height(&mut state_tx, release_height);
+    state_tx.apply();
+    let wrong_height = unjail(&signing_key, identity_key.clone(), jail_height - 1);
+    assert!(wrong_height.check_stateful(state.clone()).await.is_err());
+
+    // ... but the request for this jailing is accepted.
+    action.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    action.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    assert_eq!(
+        state.validator_state(&identity_key).await?,
+        Some(validator::State::Inactive)
+    );
+    assert_eq!(state.validator_jail_height(&identity_key).await?, None);
+    let uptime = state
+        .validator_uptime(&identity_key)
+        .await?
+        .expect("unjailed validator has an uptime");
+    assert_eq!(uptime.num_missed_blocks(), 0);
+
+    // Unjailing again is rejected, since the validator is no longer jailed.
+    assert!(action.check_stateful(state.clone()).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that a validator that was disabled while in jail remains disabled
+/// once it is unjailed.
+async fn unjail_disabled_validator() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+    let identity_key = IdentityKey((&signing_key).into());
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    put_height(&mut state_tx, 0);
+    state_tx.put_stake_params(StakeParameters {
+        jail_duration_blocks: JAIL_DURATION,
+        ..Default::default()
+    });
+    state_tx
+        .add_genesis_validator(
+            &BTreeMap::new(),
+            &BaseRateData {
+                epoch_index: 0,
+                base_reward_rate: 0,
+                base_exchange_rate: 1_0000_0000,
+            },
+            test_validator(identity_key.clone()),
+        )
+        .await?;
+    state_tx
+        .set_validator_state(&identity_key, validator::State::Jailed)
+        .await?;
+
+    // The operator disables the validator while it is jailed.
+    let mut update = test_validator(identity_key.clone());
+    update.enabled = false;
+    update.sequence_number = 1;
+    state_tx.update_validator(update).await?;
+    assert_eq!(
+        state_tx.validator_state(&identity_key).await?,
+        Some(validator::State::Jailed)
+    );
+
+    put_height(&mut state_tx, JAIL_DURATION);
+    state_tx.apply();
+
+    let action = unjail(&signing_key, identity_key.clone(), 0);
+    action.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    action.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    assert_eq!(
+        state.validator_state(&identity_key).await?,
+        Some(validator::State::Disabled)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that stake redelegated away from a validator is held in escrow until
+/// the end of its original unbonding period, and that the validator's penalties
+/// over that period only slash the redelegated stake.
+async fn redelegation_escrows_slashable_stake() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let from = IdentityKey((&SigningKey::<SpendAuth>::new(OsRng)).into());
+    let to = IdentityKey((&SigningKey::<SpendAuth>::new(OsRng)).into());
+    let base_rate = BaseRateData {
+        epoch_index: 0,
+        base_reward_rate: 0,
+        base_exchange_rate: 1_0000_0000,
+    };
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    put_height(&mut state_tx, 0);
+    state_tx.put_stake_params(StakeParameters {
+        unbonding_epochs: 2,
+        ..Default::default()
+    });
+    for identity_key in [&from, &to] {
+        state_tx
+            .add_genesis_validator(
+                &BTreeMap::new(),
+                &base_rate,
+                test_validator(identity_key.clone()),
+            )
+            .await?;
+    }
+    state_tx.apply();
+
+    let from_rate = state.current_validator_rate(&from).await?.unwrap();
+    let to_rate = state.current_validator_rate(&to).await?.unwrap();
+    let redelegation = from_rate.build_redelegate(&to_rate, 100_000u64.into());
+
+    // Redelegating to the same validator is rejected.
+    let circular = from_rate.build_redelegate(&from_rate, 100_000u64.into());
+    assert!(circular.check_stateless(()).await.is_err());
+
+    // So is a redelegation producing the wrong amount of delegation tokens.
+    let mut inflated = redelegation.clone();
+    inflated.to_delegation_amount = 100_001u64.into();
+    assert!(inflated.check_stateful(state.clone()).await.is_err());
+
+    redelegation.check_stateless(()).await?;
+    redelegation.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    redelegation.execute(&mut state_tx).await?;
+    assert_eq!(state_tx.stub_delegation_changes().redelegations.len(), 1);
+
+    // The destination pool holds 1_000_000 delegation tokens of other
+    // delegators, and the redelegated ones once the redelegation takes effect.
+    let to_id = DelegationToken::from(&to).id();
+    state_tx.update_token_supply(&to_id, 1_100_000).await?;
+
+    // Slash the source validator by 10% in the epoch of the redelegation.
+    let penalty = Penalty::from_percent(10);
+    state_tx.record_slashing_penalty(&from, penalty).await?;
+    state_tx.apply();
+
+    // The destination validator isn't penalized for it.
+    assert!(state.penalty_in_epoch(&to, 0).await?.is_none());
+
+    // The escrowed delegation tokens can't be claimed before the end of the
+    // unbonding period.
+    let claim = RedelegateClaim {
+        from_validator_identity: from.clone(),
+        to_validator_identity: to.clone(),
+        start_epoch_index: 0,
+        penalty,
+        redelegation_amount: redelegation.to_delegation_amount,
+    };
+    assert!(claim.check_stateful(state.clone()).await.is_err());
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(1);
+    state_tx.put_epoch_by_height(
+        1,
+        penumbra_chain::Epoch {
+            index: 2,
+            start_height: 1,
+        },
+    );
+    state_tx.apply();
+
+    // Afterwards, they can only be claimed with the source validator's penalty.
+    let mut unslashed = claim.clone();
+    unslashed.penalty = Penalty::from_percent(0);
+    assert!(unslashed.check_stateful(state.clone()).await.is_err());
+
+    claim.check_stateless(()).await?;
+    claim.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    claim.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    // Only the slashed part of the redelegated stake is burned.
+    let claimed = penalty.apply_to_amount(100_000u64.into());
+    assert_eq!(claim.delegation_amount(), claimed);
+    assert_eq!(
+        state.token_supply(&to_id).await?,
+        Some(Amount::from(1_000_000u64) + claimed)
+    );
+
+    Ok(())
+}
+
+fn define(signing_key: &SigningKey<SpendAuth>, validator: Validator) -> validator::Definition {
+    let auth_sig = signing_key.sign(OsRng, &validator.encode_to_vec());
+    validator::Definition {
+        validator,
+        auth_sig,
+    }
+}
+
+fn with_commission(validator: &Validator, rate_bps: u16, sequence_number: u32) -> Validator {
+    let mut validator = validator.clone();
+    validator.funding_streams = vec![FundingStream::ToDao { rate_bps }]
+        .try_into()
+        .expect("commission is at most 100%");
+    validator.sequence_number = sequence_number;
+    validator
+}
+
+#[tokio::test]
+/// Test that commission changes are limited per epoch, and that commission
+/// increases only take effect after the notice period.
+async fn commission_increase_is_limited_and_scheduled() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+    let identity_key = IdentityKey((&signing_key).into());
+    let genesis_validator = test_validator(identity_key.clone());
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    put_height(&mut state_tx, 0);
+    state_tx.put_stake_params(StakeParameters {
+        max_commission_change_bps_per_epoch: 500,
+        commission_increase_notice_epochs: 2,
+        ..Default::default()
+    });
+    state_tx
+        .add_genesis_validator(
+            &BTreeMap::new(),
+            &BaseRateData {
+                epoch_index: 0,
+                base_reward_rate: 0,
+                base_exchange_rate: 1_0000_0000,
+            },
+            genesis_validator.clone(),
+        )
+        .await?;
+    state_tx.apply();
+
+    // Raising the commission by more than the per-epoch limit is rejected.
+    let too_large = define(&signing_key, with_commission(&genesis_validator, 600, 1));
+    too_large.check_stateless(()).await?;
+    assert!(too_large.check_stateful(state.clone()).await.is_err());
+
+    // An increase within the limit only schedules the new funding streams,
+    // and applies the rest of the definition immediately.
+    let mut increased = with_commission(&genesis_validator, 400, 1);
+    increased.name = "renamed".to_string();
+    let increase = define(&signing_key, increased);
+    increase.check_stateless(()).await?;
+    increase.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    increase.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    let current = state.validator(&identity_key).await?.unwrap();
+    assert_eq!(current.funding_streams.commission_bps(), 0);
+    assert_eq!(current.name, "renamed");
+    assert_eq!(current.sequence_number, 1);
+    let pending = state
+        .pending_validator_definition(&identity_key)
+        .await?
+        .expect("increase is pending");
+    assert_eq!(pending.effective_epoch_index, 3);
+    assert_eq!(pending.funding_streams, increase.validator.funding_streams);
+
+    // A further definition must have a higher sequence number than the one
+    // that was applied.
+    let stale = define(&signing_key, with_commission(&genesis_validator, 0, 1));
+    assert!(stale.check_stateful(state.clone()).await.is_err());
+
+    // The pending funding streams only take effect once the notice period is
+    // over.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.apply_pending_validator_definitions(2).await?;
+    state_tx.apply();
+    let current = state.validator(&identity_key).await?.unwrap();
+    assert_eq!(current.funding_streams.commission_bps(), 0);
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.apply_pending_validator_definitions(3).await?;
+    state_tx.apply();
+    let current = state.validator(&identity_key).await?.unwrap();
+    assert_eq!(current.funding_streams.commission_bps(), 400);
+    assert_eq!(current.name, "renamed");
+    assert_eq!(
+        state.pending_validator_definition(&identity_key).await?,
+        None
+    );
+    assert_eq!(
+        state.epoch_start_commission(&identity_key).await?,
+        Some(400)
+    );
+
+    // Decreases take effect immediately.
+    let decrease = define(&signing_key, with_commission(&genesis_validator, 100, 2));
+    decrease.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    decrease.execute(&mut state_tx).await?;
+    state_tx.apply();
+    let current = state.validator(&identity_key).await?.unwrap();
+    assert_eq!(current.funding_streams.commission_bps(), 100);
+
+    Ok(())
+}
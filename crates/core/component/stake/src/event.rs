@@ -1,4 +1,4 @@
-use crate::{Delegate, Undelegate};
+use crate::{Delegate, Redelegate, Undelegate};
 use tendermint::abci::{Event, EventAttributeIndexExt};
 
 pub fn delegate(delegate: &Delegate) -> Event {
@@ -20,3 +20,18 @@ pub fn undelegate(undelegate: &Undelegate) -> Event {
         ],
     )
 }
+
+pub fn redelegate(redelegate: &Redelegate) -> Event {
+    Event::new(
+        "action_redelegate",
+        [
+            (
+                "from_validator",
+                redelegate.from_validator_identity.to_string(),
+            )
+                .index(),
+            ("to_validator", redelegate.to_validator_identity.to_string()).index(),
+            ("amount", redelegate.unbonded_amount.to_string()).no_index(),
+        ],
+    )
+}
@@ -150,6 +150,14 @@ impl FundingStreams {
     pub fn iter(&self) -> impl Iterator<Item = &FundingStream> {
         self.funding_streams.iter()
     }
+
+    /// The validator's total commission, in basis points of its staking rewards.
+    pub fn commission_bps(&self) -> u64 {
+        self.funding_streams
+            .iter()
+            .map(|fs| fs.rate_bps() as u64)
+            .sum()
+    }
 }
 
 impl TryFrom<Vec<FundingStream>> for FundingStreams {
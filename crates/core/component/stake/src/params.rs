@@ -18,6 +18,12 @@ pub struct StakeParameters {
     pub signed_blocks_window_len: u64,
     /// The maximum number of blocks in the window each validator can miss signing without slashing.
     pub missed_blocks_maximum: u64,
+    /// The number of blocks a jailed validator must wait before it can be unjailed.
+    pub jail_duration_blocks: u64,
+    /// The maximum change in a validator's total commission within an epoch, in basis points.
+    pub max_commission_change_bps_per_epoch: u64,
+    /// The number of epochs a validator must wait before an increase of its commission takes effect.
+    pub commission_increase_notice_epochs: u64,
 }
 
 impl DomainType for StakeParameters {
@@ -36,6 +42,9 @@ impl TryFrom<pb::StakeParameters> for StakeParameters {
             base_reward_rate: msg.base_reward_rate,
             missed_blocks_maximum: msg.missed_blocks_maximum,
             signed_blocks_window_len: msg.signed_blocks_window_len,
+            jail_duration_blocks: msg.jail_duration_blocks,
+            max_commission_change_bps_per_epoch: msg.max_commission_change_bps_per_epoch,
+            commission_increase_notice_epochs: msg.commission_increase_notice_epochs,
         })
     }
 }
@@ -50,6 +59,9 @@ impl From<StakeParameters> for pb::StakeParameters {
             slashing_penalty_downtime: params.slashing_penalty_downtime,
             slashing_penalty_misbehavior: params.slashing_penalty_misbehavior,
             base_reward_rate: params.base_reward_rate,
+            jail_duration_blocks: params.jail_duration_blocks,
+            max_commission_change_bps_per_epoch: params.max_commission_change_bps_per_epoch,
+            commission_increase_notice_epochs: params.commission_increase_notice_epochs,
         }
     }
 }
@@ -64,6 +76,11 @@ impl Default for StakeParameters {
             // copied from cosmos hub
             signed_blocks_window_len: 10000,
             missed_blocks_maximum: 9500,
+            // roughly one day at 5s blocks
+            jail_duration_blocks: 17280,
+            // 500 basis points = 5%
+            max_commission_change_bps_per_epoch: 500,
+            commission_increase_notice_epochs: 2,
             // 1000 basis points = 10%
             slashing_penalty_misbehavior: 1000_0000,
             // 1 basis point = 0.01%
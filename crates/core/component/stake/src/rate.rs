@@ -6,7 +6,7 @@ use penumbra_proto::{penumbra::core::component::stake::v1alpha1 as pb, DomainTyp
 use serde::{Deserialize, Serialize};
 
 use crate::{validator::State, FundingStream, IdentityKey};
-use crate::{Delegate, Penalty, Undelegate};
+use crate::{Delegate, Penalty, Redelegate, Undelegate};
 
 /// Describes a validator's reward rate and voting power in some epoch.
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
@@ -157,6 +157,21 @@ impl RateData {
             validator_identity: self.identity_key.clone(),
         }
     }
+
+    /// Uses this `RateData` and the destination validator's `RateData` to
+    /// build a `Redelegate` transaction action that moves `delegation_amount`
+    /// of this validator's delegation tokens to the destination validator.
+    pub fn build_redelegate(&self, to: &RateData, delegation_amount: Amount) -> Redelegate {
+        let unbonded_amount = self.unbonded_amount(delegation_amount.into());
+        Redelegate {
+            from_validator_identity: self.identity_key.clone(),
+            to_validator_identity: to.identity_key.clone(),
+            epoch_index: self.epoch_index,
+            unbonded_amount: unbonded_amount.into(),
+            from_delegation_amount: delegation_amount,
+            to_delegation_amount: to.delegation_amount(unbonded_amount).into(),
+        }
+    }
 }
 
 /// Describes the base reward and exchange rates in some epoch.
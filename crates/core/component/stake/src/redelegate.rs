@@ -0,0 +1,121 @@
+use penumbra_asset::{Balance, Value};
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::stake::v1alpha1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::{DelegationToken, IdentityKey, RedelegationToken};
+
+/// A transaction action moving stake from one validator's delegation pool to
+/// another's, without waiting for the unbonding period.
+///
+/// The redelegated stake remains liable for slashing of the source validator
+/// until the unbonding period that an undelegation started in the same epoch
+/// would have had ends.  Until then, the destination validator's delegation
+/// tokens are held in escrow as [`RedelegationToken`]s, which can be claimed
+/// with a [`RedelegateClaim`](crate::RedelegateClaim) after the source
+/// validator's penalties over that period are applied.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::Redelegate", into = "pb::Redelegate")]
+pub struct Redelegate {
+    /// The identity key of the validator to redelegate from.
+    pub from_validator_identity: IdentityKey,
+    /// The identity key of the validator to redelegate to.
+    pub to_validator_identity: IdentityKey,
+    /// The index of the epoch in which this redelegation was performed.
+    /// The redelegation takes effect in the next epoch.
+    pub epoch_index: u64,
+    /// The amount to redelegate, in units of unbonded stake.
+    pub unbonded_amount: Amount,
+    /// The amount of the source validator's delegation tokens consumed by this action.
+    pub from_delegation_amount: Amount,
+    /// The amount of the destination validator's delegation tokens produced by this action,
+    /// held in escrow as redelegation tokens.
+    ///
+    /// Both delegation amounts are implied by the validators' exchange rates in the
+    /// specified epoch (and should be checked in transaction validation!), but including
+    /// them allows stateless verification that the transaction is internally consistent.
+    pub to_delegation_amount: Amount,
+}
+
+impl Redelegate {
+    /// Return the balance after consuming the source validator's delegation
+    /// tokens, and producing redelegation tokens for the destination validator's
+    /// delegation tokens.
+    pub fn balance(&self) -> Balance {
+        let from_delegation = Balance::from(Value {
+            amount: self.from_delegation_amount,
+            asset_id: self.from_delegation_token().id(),
+        });
+
+        let redelegation = Balance::from(Value {
+            amount: self.to_delegation_amount,
+            asset_id: self.redelegation_token().id(),
+        });
+
+        // We consume the source delegation tokens and produce the escrowed
+        // destination delegation tokens.
+        redelegation - from_delegation
+    }
+
+    pub fn from_delegation_token(&self) -> DelegationToken {
+        DelegationToken::new(self.from_validator_identity.clone())
+    }
+
+    pub fn to_delegation_token(&self) -> DelegationToken {
+        DelegationToken::new(self.to_validator_identity.clone())
+    }
+
+    pub fn redelegation_token(&self) -> RedelegationToken {
+        RedelegationToken::new(
+            self.from_validator_identity.clone(),
+            self.to_validator_identity.clone(),
+            self.epoch_index,
+        )
+    }
+}
+
+impl DomainType for Redelegate {
+    type Proto = pb::Redelegate;
+}
+
+impl From<Redelegate> for pb::Redelegate {
+    fn from(r: Redelegate) -> Self {
+        pb::Redelegate {
+            from_validator_identity: Some(r.from_validator_identity.into()),
+            to_validator_identity: Some(r.to_validator_identity.into()),
+            epoch_index: r.epoch_index,
+            unbonded_amount: Some(r.unbonded_amount.into()),
+            from_delegation_amount: Some(r.from_delegation_amount.into()),
+            to_delegation_amount: Some(r.to_delegation_amount.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::Redelegate> for Redelegate {
+    type Error = anyhow::Error;
+    fn try_from(r: pb::Redelegate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            from_validator_identity: r
+                .from_validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing source validator identity"))?
+                .try_into()?,
+            to_validator_identity: r
+                .to_validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing destination validator identity"))?
+                .try_into()?,
+            epoch_index: r.epoch_index,
+            unbonded_amount: r
+                .unbonded_amount
+                .ok_or_else(|| anyhow::anyhow!("missing unbonded amount"))?
+                .try_into()?,
+            from_delegation_amount: r
+                .from_delegation_amount
+                .ok_or_else(|| anyhow::anyhow!("missing source delegation amount"))?
+                .try_into()?,
+            to_delegation_amount: r
+                .to_delegation_amount
+                .ok_or_else(|| anyhow::anyhow!("missing destination delegation amount"))?
+                .try_into()?,
+        })
+    }
+}
@@ -0,0 +1,104 @@
+use penumbra_asset::{Balance, Value};
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::stake::v1alpha1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::{DelegationToken, IdentityKey, Penalty, RedelegationToken};
+
+/// A transaction action finishing a redelegation, converting (slashable)
+/// redelegation tokens to delegation tokens of the destination validator.
+///
+/// The claimed delegation tokens are reduced by the source validator's
+/// penalties over the unbonding period of the redelegation, so that slashing
+/// of the source validator only affects the redelegated stake.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::RedelegateClaim", into = "pb::RedelegateClaim")]
+pub struct RedelegateClaim {
+    /// The identity key of the validator the stake was redelegated from.
+    pub from_validator_identity: IdentityKey,
+    /// The identity key of the validator the stake was redelegated to.
+    pub to_validator_identity: IdentityKey,
+    /// The epoch in which the redelegation happened, used to verify the penalty.
+    pub start_epoch_index: u64,
+    /// The penalty of the source validator over the unbonding period, in bps^2.
+    pub penalty: Penalty,
+    /// The amount of redelegation tokens consumed by this action.
+    pub redelegation_amount: Amount,
+}
+
+impl RedelegateClaim {
+    /// The amount of the destination validator's delegation tokens produced by this action.
+    pub fn delegation_amount(&self) -> Amount {
+        self.penalty.apply_to_amount(self.redelegation_amount)
+    }
+
+    /// Return the balance after consuming the redelegation tokens, and
+    /// producing the destination validator's delegation tokens.
+    pub fn balance(&self) -> Balance {
+        let redelegation = Balance::from(Value {
+            amount: self.redelegation_amount,
+            asset_id: self.redelegation_token().id(),
+        });
+
+        let delegation = Balance::from(Value {
+            amount: self.delegation_amount(),
+            asset_id: self.delegation_token().id(),
+        });
+
+        // We consume the redelegation tokens and produce the delegation tokens.
+        delegation - redelegation
+    }
+
+    pub fn redelegation_token(&self) -> RedelegationToken {
+        RedelegationToken::new(
+            self.from_validator_identity.clone(),
+            self.to_validator_identity.clone(),
+            self.start_epoch_index,
+        )
+    }
+
+    pub fn delegation_token(&self) -> DelegationToken {
+        DelegationToken::new(self.to_validator_identity.clone())
+    }
+}
+
+impl DomainType for RedelegateClaim {
+    type Proto = pb::RedelegateClaim;
+}
+
+impl From<RedelegateClaim> for pb::RedelegateClaim {
+    fn from(c: RedelegateClaim) -> Self {
+        pb::RedelegateClaim {
+            from_validator_identity: Some(c.from_validator_identity.into()),
+            to_validator_identity: Some(c.to_validator_identity.into()),
+            start_epoch_index: c.start_epoch_index,
+            penalty: Some(c.penalty.into()),
+            redelegation_amount: Some(c.redelegation_amount.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::RedelegateClaim> for RedelegateClaim {
+    type Error = anyhow::Error;
+    fn try_from(c: pb::RedelegateClaim) -> Result<Self, Self::Error> {
+        Ok(Self {
+            from_validator_identity: c
+                .from_validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing source validator identity"))?
+                .try_into()?,
+            to_validator_identity: c
+                .to_validator_identity
+                .ok_or_else(|| anyhow::anyhow!("missing destination validator identity"))?
+                .try_into()?,
+            start_epoch_index: c.start_epoch_index,
+            penalty: c
+                .penalty
+                .ok_or_else(|| anyhow::anyhow!("missing penalty"))?
+                .try_into()?,
+            redelegation_amount: c
+                .redelegation_amount
+                .ok_or_else(|| anyhow::anyhow!("missing redelegation amount"))?
+                .try_into()?,
+        })
+    }
+}
@@ -66,6 +66,22 @@ pub fn uptime_by_validator(id: &IdentityKey) -> String {
     format!("staking/validator_uptime/{id}")
 }
 
+pub fn jail_height_by_validator(id: &IdentityKey) -> String {
+    format!("staking/validator_jail_height/{id}")
+}
+
+pub fn pending_definition_by_validator(id: &IdentityKey) -> String {
+    format!("staking/validator_pending_definition/{id}")
+}
+
+pub fn pending_definitions() -> &'static str {
+    "staking/validator_pending_definition/"
+}
+
+pub fn epoch_start_commission_by_validator(id: &IdentityKey) -> String {
+    format!("staking/validator_epoch_start_commission/{id}")
+}
+
 pub fn slashed_validators(height: u64) -> String {
     format!("staking/slashed_validators/{height}")
 }
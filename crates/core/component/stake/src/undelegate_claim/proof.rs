@@ -16,7 +16,9 @@ use penumbra_asset::{
     STAKING_TOKEN_ASSET_ID,
 };
 use penumbra_num::{Amount, AmountVar};
-use penumbra_proof_params::{DummyWitness, VerifyingKeyExt, GROTH16_PROOF_LENGTH_BYTES};
+use penumbra_proof_params::{
+    BatchVerifier, DummyWitness, VerifyingKeyExt, GROTH16_PROOF_LENGTH_BYTES,
+};
 
 use crate::{Penalty, PenaltyVar};
 
@@ -137,6 +139,42 @@ impl UndelegateClaimProof {
         let proof =
             Proof::deserialize_compressed_unchecked(&self.0[..]).map_err(|e| anyhow::anyhow!(e))?;
 
+        let public_inputs = Self::public_inputs(balance_commitment, unbonding_id, penalty)?;
+
+        tracing::trace!(?public_inputs);
+        let start = std::time::Instant::now();
+        let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
+            vk,
+            public_inputs.as_slice(),
+            &proof,
+        )
+        .map_err(|err| anyhow::anyhow!(err))?;
+        tracing::debug!(?proof_result, elapsed = ?start.elapsed());
+        proof_result
+            .then_some(())
+            .ok_or_else(|| anyhow::anyhow!("undelegate claim proof did not verify"))
+    }
+
+    /// Queues the proof for batch verification with the provided public inputs.
+    pub fn queue<'a>(
+        &self,
+        batch: &mut BatchVerifier<'a>,
+        vk: &'a PreparedVerifyingKey<Bls12_377>,
+        balance_commitment: balance::Commitment,
+        unbonding_id: asset::Id,
+        penalty: Penalty,
+    ) -> anyhow::Result<()> {
+        let proof = Proof::deserialize_compressed(&self.0[..]).map_err(|e| anyhow::anyhow!(e))?;
+        let public_inputs = Self::public_inputs(balance_commitment, unbonding_id, penalty)?;
+        batch.queue(vk, "undelegate claim proof", proof, public_inputs);
+        Ok(())
+    }
+
+    fn public_inputs(
+        balance_commitment: balance::Commitment,
+        unbonding_id: asset::Id,
+        penalty: Penalty,
+    ) -> anyhow::Result<Vec<Fq>> {
         let mut public_inputs = Vec::new();
         public_inputs.extend(balance_commitment.0.to_field_elements().ok_or_else(|| {
             anyhow::anyhow!("could not convert balance commitment to field elements")
@@ -152,18 +190,7 @@ impl UndelegateClaimProof {
                 .ok_or_else(|| anyhow::anyhow!("could not convert penalty to field elements"))?,
         );
 
-        tracing::trace!(?public_inputs);
-        let start = std::time::Instant::now();
-        let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
-            vk,
-            public_inputs.as_slice(),
-            &proof,
-        )
-        .map_err(|err| anyhow::anyhow!(err))?;
-        tracing::debug!(?proof_result, elapsed = ?start.elapsed());
-        proof_result
-            .then_some(())
-            .ok_or_else(|| anyhow::anyhow!("undelegate claim proof did not verify"))
+        Ok(public_inputs)
     }
 }
 
@@ -11,14 +11,18 @@ use crate::{FundingStream, FundingStreams, GovernanceKey, IdentityKey};
 mod bonding;
 mod definition;
 mod info;
+mod pending;
 mod state;
 mod status;
+mod unjail;
 
 pub use bonding::State as BondingState;
 pub use definition::Definition;
 pub use info::Info;
+pub use pending::PendingDefinition;
 pub use state::State;
 pub use status::Status;
+pub use unjail::{Unjail, UnjailBody};
 
 /// Describes a Penumbra validator's configuration data.
 ///
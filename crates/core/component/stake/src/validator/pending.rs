@@ -0,0 +1,57 @@
+use penumbra_proto::{penumbra::core::component::stake::v1alpha1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::{FundingStream, FundingStreams, IdentityKey};
+
+/// The funding streams of a validator definition increasing the validator's
+/// commission, which are waiting out the commission increase notice period
+/// before taking effect.
+///
+/// The rest of the definition takes effect as soon as it is accepted.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::PendingValidatorDefinition",
+    into = "pb::PendingValidatorDefinition"
+)]
+pub struct PendingDefinition {
+    /// The identity key of the validator whose funding streams are pending.
+    pub identity_key: IdentityKey,
+    /// The pending funding streams.
+    pub funding_streams: FundingStreams,
+    /// The index of the first epoch whose rewards use the pending funding
+    /// streams.
+    pub effective_epoch_index: u64,
+}
+
+impl DomainType for PendingDefinition {
+    type Proto = pb::PendingValidatorDefinition;
+}
+
+impl From<PendingDefinition> for pb::PendingValidatorDefinition {
+    fn from(p: PendingDefinition) -> Self {
+        pb::PendingValidatorDefinition {
+            identity_key: Some(p.identity_key.into()),
+            funding_streams: p.funding_streams.into_iter().map(Into::into).collect(),
+            effective_epoch_index: p.effective_epoch_index,
+        }
+    }
+}
+
+impl TryFrom<pb::PendingValidatorDefinition> for PendingDefinition {
+    type Error = anyhow::Error;
+    fn try_from(p: pb::PendingValidatorDefinition) -> Result<Self, Self::Error> {
+        Ok(PendingDefinition {
+            identity_key: p
+                .identity_key
+                .ok_or_else(|| anyhow::anyhow!("missing identity key"))?
+                .try_into()?,
+            funding_streams: p
+                .funding_streams
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<FundingStream>, _>>()?
+                .try_into()?,
+            effective_epoch_index: p.effective_epoch_index,
+        })
+    }
+}
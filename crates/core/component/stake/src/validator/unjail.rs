@@ -0,0 +1,93 @@
+use decaf377_rdsa::{Signature, SpendAuth};
+use penumbra_proto::{penumbra::core::component::stake::v1alpha1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::{GovernanceKey, IdentityKey};
+
+/// A request to release a jailed validator from jail.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::ValidatorUnjail", into = "pb::ValidatorUnjail")]
+pub struct Unjail {
+    /// The body of the unjail request.
+    pub body: UnjailBody,
+    /// The signature authorizing the request (signed with the governance key over the body).
+    pub auth_sig: Signature<SpendAuth>,
+}
+
+impl DomainType for Unjail {
+    type Proto = pb::ValidatorUnjail;
+}
+
+impl From<Unjail> for pb::ValidatorUnjail {
+    fn from(msg: Unjail) -> Self {
+        Self {
+            body: Some(msg.body.into()),
+            auth_sig: Some(msg.auth_sig.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::ValidatorUnjail> for Unjail {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ValidatorUnjail) -> Result<Self, Self::Error> {
+        Ok(Self {
+            body: msg
+                .body
+                .ok_or_else(|| anyhow::anyhow!("missing validator unjail body"))?
+                .try_into()?,
+            auth_sig: msg
+                .auth_sig
+                .ok_or_else(|| anyhow::anyhow!("missing validator unjail auth sig"))?
+                .try_into()?,
+        })
+    }
+}
+
+/// The effecting data of an unjail request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::ValidatorUnjailBody", into = "pb::ValidatorUnjailBody")]
+pub struct UnjailBody {
+    /// The identity of the jailed validator.
+    pub identity_key: IdentityKey,
+    /// The key authorizing the request: either the validator's governance key,
+    /// or its identity key.
+    pub governance_key: GovernanceKey,
+    /// The height at which the validator was jailed, so that the request can't
+    /// be replayed to release the validator from a later jailing.
+    pub jail_height: u64,
+}
+
+impl DomainType for UnjailBody {
+    type Proto = pb::ValidatorUnjailBody;
+}
+
+impl From<UnjailBody> for pb::ValidatorUnjailBody {
+    fn from(value: UnjailBody) -> Self {
+        pb::ValidatorUnjailBody {
+            identity_key: Some(value.identity_key.into()),
+            governance_key: Some(value.governance_key.into()),
+            jail_height: value.jail_height,
+        }
+    }
+}
+
+impl TryFrom<pb::ValidatorUnjailBody> for UnjailBody {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ValidatorUnjailBody) -> Result<Self, Self::Error> {
+        Ok(UnjailBody {
+            identity_key: msg
+                .identity_key
+                .ok_or_else(|| anyhow::anyhow!("missing validator identity in `ValidatorUnjail`"))?
+                .try_into()?,
+            governance_key: msg
+                .governance_key
+                .ok_or_else(|| {
+                    anyhow::anyhow!("missing validator governance key in `ValidatorUnjail`")
+                })?
+                .try_into()?,
+            jail_height: msg.jail_height,
+        })
+    }
+}
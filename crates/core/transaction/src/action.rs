@@ -31,7 +31,10 @@ pub enum Action {
 
     Delegate(penumbra_stake::Delegate),
     Undelegate(penumbra_stake::Undelegate),
+    Redelegate(penumbra_stake::Redelegate),
+    RedelegateClaim(penumbra_stake::RedelegateClaim),
     UndelegateClaim(penumbra_stake::UndelegateClaim),
+    ValidatorUnjail(penumbra_stake::validator::Unjail),
 
     Ics20Withdrawal(penumbra_shielded_pool::Ics20Withdrawal),
 
@@ -51,6 +54,7 @@ impl Action {
             Action::ValidatorDefinition(_) => {
                 tracing::info_span!("ValidatorDefinition", ?idx)
             }
+            Action::ValidatorUnjail(_) => tracing::info_span!("ValidatorUnjail", ?idx),
             Action::IbcRelay(msg) => {
                 // Construct a nested span, identifying the IbcAction within
                 // the transaction but also the message within the IbcAction.
@@ -78,6 +82,8 @@ impl Action {
             }
             Action::Delegate(_) => tracing::info_span!("Delegate", ?idx),
             Action::Undelegate(_) => tracing::info_span!("Undelegate", ?idx),
+            Action::Redelegate(_) => tracing::info_span!("Redelegate", ?idx),
+            Action::RedelegateClaim(_) => tracing::info_span!("RedelegateClaim", ?idx),
             Action::UndelegateClaim(_) => tracing::info_span!("UndelegateClaim", ?idx),
             Action::Ics20Withdrawal(_) => tracing::info_span!("Ics20Withdrawal", ?idx),
             Action::DaoDeposit(_) => tracing::info_span!("DaoDeposit", ?idx),
@@ -94,6 +100,8 @@ impl IsAction for Action {
             Action::Spend(spend) => spend.balance_commitment(),
             Action::Delegate(delegate) => delegate.balance_commitment(),
             Action::Undelegate(undelegate) => undelegate.balance_commitment(),
+            Action::Redelegate(redelegate) => redelegate.balance_commitment(),
+            Action::RedelegateClaim(redelegate_claim) => redelegate_claim.balance_commitment(),
             Action::UndelegateClaim(undelegate_claim) => undelegate_claim.balance_commitment(),
             Action::Swap(swap) => swap.balance_commitment(),
             Action::SwapClaim(swap_claim) => swap_claim.balance_commitment(),
@@ -114,6 +122,7 @@ impl IsAction for Action {
             // value balance unchanged.
             Action::IbcRelay(x) => x.balance_commitment(),
             Action::ValidatorDefinition(_) => balance::Commitment::default(),
+            Action::ValidatorUnjail(x) => x.balance_commitment(),
         }
     }
 
@@ -125,11 +134,14 @@ impl IsAction for Action {
             Action::Spend(x) => x.view_from_perspective(txp),
             Action::Delegate(x) => x.view_from_perspective(txp),
             Action::Undelegate(x) => x.view_from_perspective(txp),
+            Action::Redelegate(x) => x.view_from_perspective(txp),
+            Action::RedelegateClaim(x) => x.view_from_perspective(txp),
             Action::UndelegateClaim(x) => x.view_from_perspective(txp),
             Action::ProposalSubmit(x) => x.view_from_perspective(txp),
             Action::ProposalWithdraw(x) => x.view_from_perspective(txp),
             Action::DelegatorVote(x) => x.view_from_perspective(txp),
             Action::ValidatorVote(x) => x.view_from_perspective(txp),
+            Action::ValidatorUnjail(x) => x.view_from_perspective(txp),
             Action::ProposalDepositClaim(x) => x.view_from_perspective(txp),
             Action::PositionOpen(x) => x.view_from_perspective(txp),
             Action::PositionClose(x) => x.view_from_perspective(txp),
@@ -165,12 +177,21 @@ impl From<Action> for pb::Action {
             Action::Undelegate(inner) => pb::Action {
                 action: Some(pb::action::Action::Undelegate(inner.into())),
             },
+            Action::Redelegate(inner) => pb::Action {
+                action: Some(pb::action::Action::Redelegate(inner.into())),
+            },
+            Action::RedelegateClaim(inner) => pb::Action {
+                action: Some(pb::action::Action::RedelegateClaim(inner.into())),
+            },
             Action::UndelegateClaim(inner) => pb::Action {
                 action: Some(pb::action::Action::UndelegateClaim(inner.into())),
             },
             Action::ValidatorDefinition(inner) => pb::Action {
                 action: Some(pb::action::Action::ValidatorDefinition(inner.into())),
             },
+            Action::ValidatorUnjail(inner) => pb::Action {
+                action: Some(pb::action::Action::ValidatorUnjail(inner.into())),
+            },
             Action::SwapClaim(inner) => pb::Action {
                 action: Some(pb::action::Action::SwapClaim(inner.into())),
             },
@@ -237,12 +258,19 @@ impl TryFrom<pb::Action> for Action {
             pb::action::Action::Spend(inner) => Ok(Action::Spend(inner.try_into()?)),
             pb::action::Action::Delegate(inner) => Ok(Action::Delegate(inner.try_into()?)),
             pb::action::Action::Undelegate(inner) => Ok(Action::Undelegate(inner.try_into()?)),
+            pb::action::Action::Redelegate(inner) => Ok(Action::Redelegate(inner.try_into()?)),
+            pb::action::Action::RedelegateClaim(inner) => {
+                Ok(Action::RedelegateClaim(inner.try_into()?))
+            }
             pb::action::Action::UndelegateClaim(inner) => {
                 Ok(Action::UndelegateClaim(inner.try_into()?))
             }
             pb::action::Action::ValidatorDefinition(inner) => {
                 Ok(Action::ValidatorDefinition(inner.try_into()?))
             }
+            pb::action::Action::ValidatorUnjail(inner) => {
+                Ok(Action::ValidatorUnjail(inner.try_into()?))
+            }
             pb::action::Action::SwapClaim(inner) => Ok(Action::SwapClaim(inner.try_into()?)),
             pb::action::Action::Swap(inner) => Ok(Action::Swap(inner.try_into()?)),
             pb::action::Action::IbcRelayAction(inner) => Ok(Action::IbcRelay(inner.try_into()?)),
@@ -19,7 +19,9 @@ use penumbra_proto::{
     core::transaction::v1alpha1 as pbt, crypto::decaf377_fmd::v1alpha1 as pb_fmd, Message, Name,
 };
 use penumbra_shielded_pool::{output, spend, Ics20Withdrawal};
-use penumbra_stake::{validator, Delegate, Undelegate, UndelegateClaimBody};
+use penumbra_stake::{
+    validator, Delegate, Redelegate, RedelegateClaim, Undelegate, UndelegateClaimBody,
+};
 
 use crate::{
     memo::MemoCiphertext, plan::TransactionPlan, transaction::DetectionData, Action, Transaction,
@@ -163,6 +165,8 @@ impl EffectingData for Action {
             Action::Spend(spend) => spend.body.effect_hash(),
             Action::Delegate(delegate) => delegate.effect_hash(),
             Action::Undelegate(undelegate) => undelegate.effect_hash(),
+            Action::Redelegate(redelegate) => redelegate.effect_hash(),
+            Action::RedelegateClaim(claim) => claim.effect_hash(),
             Action::UndelegateClaim(claim) => claim.body.effect_hash(),
             Action::ProposalSubmit(submit) => submit.effect_hash(),
             Action::ProposalWithdraw(withdraw) => withdraw.effect_hash(),
@@ -172,6 +176,7 @@ impl EffectingData for Action {
             Action::SwapClaim(swap_claim) => swap_claim.body.effect_hash(),
             Action::Swap(swap) => swap.body.effect_hash(),
             Action::ValidatorDefinition(defn) => defn.effect_hash(),
+            Action::ValidatorUnjail(unjail) => unjail.effect_hash(),
             Action::IbcRelay(payload) => payload.effect_hash(),
             Action::PositionOpen(p) => p.effect_hash(),
             Action::PositionClose(p) => p.effect_hash(),
@@ -215,6 +220,19 @@ impl EffectingData for validator::Definition {
     }
 }
 
+impl EffectingData for validator::Unjail {
+    fn effect_hash(&self) -> EffectHash {
+        self.body.effect_hash()
+    }
+}
+
+impl EffectingData for validator::UnjailBody {
+    fn effect_hash(&self) -> EffectHash {
+        let effecting_data: pbs::ValidatorUnjailBody = self.clone().into();
+        hash_proto_effecting_data(&pbs::ValidatorUnjailBody::type_url(), &effecting_data)
+    }
+}
+
 impl EffectingData for IbcRelay {
     fn effect_hash(&self) -> EffectHash {
         let effecting_data: pbi::IbcRelay = self.clone().into();
@@ -302,6 +320,22 @@ impl EffectingData for Undelegate {
     }
 }
 
+impl EffectingData for Redelegate {
+    fn effect_hash(&self) -> EffectHash {
+        // For redelegations, the entire action is considered effecting data.
+        let effecting_data: pbs::Redelegate = self.clone().into();
+        hash_proto_effecting_data(&pbs::Redelegate::type_url(), &effecting_data)
+    }
+}
+
+impl EffectingData for RedelegateClaim {
+    fn effect_hash(&self) -> EffectHash {
+        // For redelegation claims, the entire action is considered effecting data.
+        let effecting_data: pbs::RedelegateClaim = self.clone().into();
+        hash_proto_effecting_data(&pbs::RedelegateClaim::type_url(), &effecting_data)
+    }
+}
+
 impl EffectingData for UndelegateClaimBody {
     fn effect_hash(&self) -> EffectHash {
         // The effecting data is in the body of the undelegate claim, so we can
@@ -10,7 +10,8 @@ use penumbra_ibc::IbcRelay;
 use penumbra_sct::Nullifier;
 use penumbra_shielded_pool::{Ics20Withdrawal, Output, Spend};
 use penumbra_stake::{
-    validator::Definition as ValidatorDefinition, Delegate, Undelegate, UndelegateClaim,
+    validator::{Definition as ValidatorDefinition, Unjail as ValidatorUnjail},
+    Delegate, Redelegate, RedelegateClaim, Undelegate, UndelegateClaim,
 };
 
 use penumbra_governance::{
@@ -96,6 +97,40 @@ fn undelegate_gas_cost() -> Gas {
     }
 }
 
+fn redelegate_gas_cost() -> Gas {
+    Gas {
+        // Each [`Action`] has a `0` `block_space` cost, since the [`Transaction`] itself
+        // will use the encoded size of the complete transaction to calculate the block space.
+        block_space: 0,
+        // The compact block space cost is based on the byte size of the data the [`Action`] adds
+        // to the compact block.
+        // For a Redelegate, nothing is added to the compact block directly. The associated [`Action::Spend`]
+        // and [`Action::Output`] actions will add their costs, but there's nothing to add here.
+        compact_block_space: 0u64,
+        // Does not include a zk-SNARK proof, so there's no verification cost.
+        verification: 0,
+        // Execution cost is currently hardcoded at 10 for all Action variants.
+        execution: 10,
+    }
+}
+
+fn redelegate_claim_gas_cost() -> Gas {
+    Gas {
+        // Each [`Action`] has a `0` `block_space` cost, since the [`Transaction`] itself
+        // will use the encoded size of the complete transaction to calculate the block space.
+        block_space: 0,
+        // The compact block space cost is based on the byte size of the data the [`Action`] adds
+        // to the compact block.
+        // For a RedelegateClaim, nothing is added to the compact block directly. The associated [`Action::Spend`]
+        // and [`Action::Output`] actions will add their costs, but there's nothing to add here.
+        compact_block_space: 0u64,
+        // Does not include a zk-SNARK proof, so there's no verification cost.
+        verification: 0,
+        // Execution cost is currently hardcoded at 10 for all Action variants.
+        execution: 10,
+    }
+}
+
 fn undelegate_claim_gas_cost() -> Gas {
     Gas {
         // Each [`Action`] has a `0` `block_space` cost, since the [`Transaction`] itself
@@ -244,8 +279,11 @@ impl GasCost for ActionPlan {
             ActionPlan::Output(_) => output_gas_cost(),
             ActionPlan::Delegate(d) => d.gas_cost(),
             ActionPlan::Undelegate(u) => u.gas_cost(),
+            ActionPlan::Redelegate(r) => r.gas_cost(),
+            ActionPlan::RedelegateClaim(c) => c.gas_cost(),
             ActionPlan::UndelegateClaim(_) => undelegate_claim_gas_cost(),
             ActionPlan::ValidatorDefinition(vd) => vd.gas_cost(),
+            ActionPlan::ValidatorUnjail(u) => u.gas_cost(),
             ActionPlan::Swap(_) => swap_gas_cost(),
             ActionPlan::SwapClaim(_) => swap_claim_gas_cost(),
             ActionPlan::IbcAction(i) => i.gas_cost(),
@@ -273,6 +311,8 @@ impl GasCost for Action {
             Action::Spend(spend) => spend.gas_cost(),
             Action::Delegate(delegate) => delegate.gas_cost(),
             Action::Undelegate(undelegate) => undelegate.gas_cost(),
+            Action::Redelegate(redelegate) => redelegate.gas_cost(),
+            Action::RedelegateClaim(redelegate_claim) => redelegate_claim.gas_cost(),
             Action::UndelegateClaim(undelegate_claim) => undelegate_claim.gas_cost(),
             Action::Swap(swap) => swap.gas_cost(),
             Action::SwapClaim(swap_claim) => swap_claim.gas_cost(),
@@ -291,6 +331,7 @@ impl GasCost for Action {
             Action::DaoOutput(output) => output.gas_cost(),
             Action::IbcRelay(x) => x.gas_cost(),
             Action::ValidatorDefinition(x) => x.gas_cost(),
+            Action::ValidatorUnjail(x) => x.gas_cost(),
         }
     }
 }
@@ -319,6 +360,18 @@ impl GasCost for Undelegate {
     }
 }
 
+impl GasCost for Redelegate {
+    fn gas_cost(&self) -> Gas {
+        redelegate_gas_cost()
+    }
+}
+
+impl GasCost for RedelegateClaim {
+    fn gas_cost(&self) -> Gas {
+        redelegate_claim_gas_cost()
+    }
+}
+
 impl GasCost for UndelegateClaim {
     fn gas_cost(&self) -> Gas {
         undelegate_claim_gas_cost()
@@ -569,3 +622,21 @@ impl GasCost for ValidatorDefinition {
         validator_definition_gas_cost()
     }
 }
+
+impl GasCost for ValidatorUnjail {
+    fn gas_cost(&self) -> Gas {
+        Gas {
+            // Each [`Action`] has a `0` `block_space` cost, since the [`Transaction`] itself
+            // will use the encoded size of the complete transaction to calculate the block space.
+            block_space: 0,
+            // The compact block space cost is based on the byte size of the data the [`Action`] adds
+            // to the compact block.
+            // For a ValidatorUnjail the compact block is not modified.
+            compact_block_space: 0u64,
+            // Includes a signature verification, so we include a small constant verification cost.
+            verification: 200,
+            // Execution cost is currently hardcoded at 10 for all Action variants.
+            execution: 10,
+        }
+    }
+}
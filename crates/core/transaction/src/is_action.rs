@@ -16,7 +16,9 @@ use penumbra_governance::{
 };
 use penumbra_ibc::IbcRelay;
 use penumbra_shielded_pool::{Ics20Withdrawal, Note, Output, OutputView, Spend, SpendView};
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaim};
+use penumbra_stake::{
+    validator, Delegate, Redelegate, RedelegateClaim, Undelegate, UndelegateClaim,
+};
 
 use crate::{Action, ActionView, TransactionPerspective};
 
@@ -101,6 +103,16 @@ impl IsAction for ValidatorVote {
     }
 }
 
+impl IsAction for validator::Unjail {
+    fn balance_commitment(&self) -> balance::Commitment {
+        Default::default()
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::ValidatorUnjail(self.to_owned())
+    }
+}
+
 impl IsAction for Output {
     fn balance_commitment(&self) -> balance::Commitment {
         self.body.balance_commitment
@@ -185,6 +197,26 @@ impl IsAction for Undelegate {
     }
 }
 
+impl IsAction for Redelegate {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::Redelegate(self.to_owned())
+    }
+}
+
+impl IsAction for RedelegateClaim {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::RedelegateClaim(self.to_owned())
+    }
+}
+
 impl IsAction for UndelegateClaim {
     fn balance_commitment(&self) -> balance::Commitment {
         self.body.balance_commitment
@@ -295,9 +327,15 @@ impl IsAction for PositionRewardClaim {
             asset_id: LpNft::new(self.position_id, position::State::Withdrawn).asset_id(),
         }
         .commit(Fr::zero());
+        let claimed_position_nft = Value {
+            amount: 1u64.into(),
+            asset_id: LpNft::new(self.position_id, position::State::Claimed).asset_id(),
+        }
+        .commit(Fr::zero());
 
-        // The action consumes a closed position and produces the position's reserves.
-        self.rewards_commitment - withdrawn_position_nft
+        // The action consumes a withdrawn position and produces a claimed position
+        // and the position's rewards.
+        self.rewards_commitment - withdrawn_position_nft + claimed_position_nft
     }
 
     fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
@@ -27,6 +27,7 @@ mod witness_data;
 pub mod action;
 pub mod gas;
 pub mod memo;
+pub mod payment_request;
 pub mod plan;
 pub mod view;
 
@@ -37,8 +38,9 @@ pub use effect_hash::EffectingData;
 pub use error::Error;
 pub use id::Id;
 pub use is_action::IsAction;
+pub use payment_request::PaymentRequest;
 pub use plan::ActionPlan;
-pub use transaction::{Transaction, TransactionBody, TransactionParameters};
+pub use transaction::{DetectionData, Transaction, TransactionBody, TransactionParameters};
 pub use view::{ActionView, MemoPlaintextView, MemoView, TransactionPerspective, TransactionView};
 pub use witness_data::WitnessData;
 
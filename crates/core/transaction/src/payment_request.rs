@@ -0,0 +1,430 @@
+//! Payment requests, which a payee shares with a payer to ask to be paid.
+
+use anyhow::Context;
+use decaf377::Fr;
+use decaf377_rdsa::{Signature, SpendAuth, VerificationKey};
+use penumbra_asset::Value;
+use penumbra_keys::{keys::SpendKey, prf, Address, FullViewingKey};
+use penumbra_proto::{core::transaction::v1alpha1 as pb, serializers::bech32str, DomainType};
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+use crate::memo::{MemoPlaintext, MEMO_LEN_BYTES};
+
+/// The length of the text of a memo plaintext, which follows its 80-byte return address.
+const MEMO_TEXT_LEN_BYTES: usize = MEMO_LEN_BYTES - 80;
+
+/// The start of the line that ends the memo of a payment, followed by the hex-encoded id of the
+/// payment request it pays.
+pub const MEMO_REFERENCE_PREFIX: &str = "payment request ";
+
+/// A request for payment, shared by the payee with the payer out of band.
+///
+/// Paying the request sends each of the requested values to its address, with a memo ending in a
+/// reference to the request (see [`PaymentRequest::memo_reference`]), so that the payee can match
+/// the notes it receives to the request.
+///
+/// A payment request is encoded as a Bech32m string, starting with `penumbrapaymentrequest1`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "pb::PaymentRequest", into = "pb::PaymentRequest")]
+pub struct PaymentRequest {
+    /// The address to pay.
+    pub address: Address,
+    /// The values requested.
+    pub values: Vec<Value>,
+    /// Text to include in the memo of the payment, before the reference to the request.
+    pub memo: String,
+    /// The height after which the request can no longer be paid, if any.
+    pub expiry_height: Option<u64>,
+    /// The invoice id of the request, signed by the payee, if any.
+    pub invoice: Option<SignedInvoice>,
+}
+
+/// An invoice id chosen by the payee, signed with the spend authorization key of its wallet,
+/// randomized for the address of the request (see [`invoice_randomizer`]).
+#[derive(Clone, Debug)]
+pub struct SignedInvoice {
+    /// The invoice id.
+    pub id: String,
+    /// The payee's spend verification key, randomized for the address of the request.
+    pub verification_key: VerificationKey<SpendAuth>,
+    /// The payee's signature over the rest of the payment request and the invoice id.
+    pub signature: Signature<SpendAuth>,
+}
+
+impl PaymentRequest {
+    /// Creates an unsigned payment request, checking that it can be paid.
+    pub fn new(
+        address: Address,
+        values: Vec<Value>,
+        memo: String,
+        expiry_height: Option<u64>,
+    ) -> anyhow::Result<Self> {
+        let request = Self {
+            address,
+            values,
+            memo,
+            expiry_height,
+            invoice: None,
+        };
+        request.check()?;
+        Ok(request)
+    }
+
+    /// Checks that the request asks for something, and that the memo of its payment would fit.
+    fn check(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            !self.values.is_empty(),
+            "payment request must request at least one value"
+        );
+        let memo_text_len = self.memo_text().len();
+        anyhow::ensure!(
+            memo_text_len <= MEMO_TEXT_LEN_BYTES,
+            "payment request memo is too long: the memo of its payment would be {memo_text_len} bytes, above the maximum of {MEMO_TEXT_LEN_BYTES}"
+        );
+        Ok(())
+    }
+
+    /// The bytes committing to the request and the given invoice id, regardless of any signature.
+    fn hash_state(&self, personalization: &[u8], invoice_id: &str) -> blake2b_simd::State {
+        let mut unsigned = self.to_proto();
+        unsigned.invoice = None;
+        let unsigned = penumbra_proto::Message::encode_to_vec(&unsigned);
+
+        let mut state = blake2b_simd::Params::new()
+            .personal(personalization)
+            .hash_length(32)
+            .to_state();
+        state.update(&(unsigned.len() as u64).to_le_bytes());
+        state.update(&unsigned);
+        state.update(invoice_id.as_bytes());
+        state
+    }
+
+    /// The id of the request, which the memo of its payment refers to.
+    ///
+    /// The id commits to the contents of the request and its invoice id, but not to the signature
+    /// over the invoice id.
+    pub fn id(&self) -> [u8; 32] {
+        let invoice_id = self
+            .invoice
+            .as_ref()
+            .map(|invoice| invoice.id.as_str())
+            .unwrap_or_default();
+        self.hash_state(b"Penumbra_PayReqI", invoice_id)
+            .finalize()
+            .as_bytes()
+            .try_into()
+            .expect("hash is 32 bytes")
+    }
+
+    /// Signs the given invoice id, along with the rest of the request, replacing any previous
+    /// invoice.
+    ///
+    /// The invoice is signed with the spend authorization key randomized for the address of the
+    /// request, so that it does not reveal the wallet's spend verification key.
+    pub fn sign_invoice<R: RngCore + CryptoRng>(
+        mut self,
+        rng: R,
+        invoice_id: String,
+        spend_key: &SpendKey,
+    ) -> Self {
+        let randomizer = invoice_randomizer(spend_key.full_viewing_key(), &self.address);
+        let signing_key = spend_key.spend_auth_key().randomize(&randomizer);
+        let message = self.hash_state(b"Penumbra_PayReqS", &invoice_id).finalize();
+        self.invoice = Some(SignedInvoice {
+            signature: signing_key.sign(rng, message.as_bytes()),
+            verification_key: (&signing_key).into(),
+            id: invoice_id,
+        });
+        self
+    }
+
+    /// Verifies the signature over the invoice id of the request, if it has one.
+    pub fn verify_invoice(&self) -> anyhow::Result<()> {
+        let Some(invoice) = &self.invoice else {
+            return Ok(());
+        };
+        let message = self.hash_state(b"Penumbra_PayReqS", &invoice.id).finalize();
+        invoice
+            .verification_key
+            .verify(message.as_bytes(), &invoice.signature)
+            .context("invalid signature over payment request invoice id")
+    }
+
+    /// Returns whether the invoice of the request, if it has one, was signed by the wallet of the
+    /// given full viewing key.
+    pub fn is_signed_by(&self, fvk: &FullViewingKey) -> bool {
+        let Some(invoice) = &self.invoice else {
+            return false;
+        };
+        let randomizer = invoice_randomizer(fvk, &self.address);
+        invoice.verification_key == fvk.spend_verification_key().randomize(&randomizer)
+    }
+
+    /// Returns whether the request can no longer be paid at the given height.
+    pub fn is_expired(&self, height: u64) -> bool {
+        self.expiry_height
+            .map_or(false, |expiry_height| height > expiry_height)
+    }
+
+    /// The line that ends the memo of a payment of the request.
+    pub fn memo_reference(&self) -> String {
+        format!("{MEMO_REFERENCE_PREFIX}{}", hex::encode(self.id()))
+    }
+
+    /// The text of the memo of a payment of the request.
+    pub fn memo_text(&self) -> String {
+        if self.memo.is_empty() {
+            self.memo_reference()
+        } else {
+            format!("{}\n{}", self.memo, self.memo_reference())
+        }
+    }
+
+    /// The memo of a payment of the request, with the given return address.
+    pub fn memo_plaintext(&self, return_address: Address) -> MemoPlaintext {
+        MemoPlaintext {
+            return_address,
+            text: self.memo_text(),
+        }
+    }
+
+    /// Returns whether the given memo is that of a payment of the request.
+    pub fn is_paid_by(&self, memo: &MemoPlaintext) -> bool {
+        let reference = self.memo_reference();
+        memo.text
+            .strip_suffix(&reference)
+            .map_or(false, |rest| rest.is_empty() || rest.ends_with('\n'))
+    }
+}
+
+/// The randomizer of the spend authorization key that signs the invoices of payment requests to
+/// `address`.
+///
+/// It is derived from the outgoing viewing key of the wallet and the address, so that the invoices
+/// of requests to different addresses can't be linked to each other or to the wallet, while the
+/// wallet can still recognize them as its own.
+pub fn invoice_randomizer(fvk: &FullViewingKey, address: &Address) -> Fr {
+    prf::expand_ff(
+        b"Penumbra_PayReqR",
+        &fvk.outgoing().to_bytes(),
+        &address.to_vec(),
+    )
+}
+
+impl DomainType for PaymentRequest {
+    type Proto = pb::PaymentRequest;
+}
+
+impl From<PaymentRequest> for pb::PaymentRequest {
+    fn from(request: PaymentRequest) -> Self {
+        pb::PaymentRequest {
+            address: Some(request.address.into()),
+            values: request.values.into_iter().map(Into::into).collect(),
+            memo: request.memo,
+            expiry_height: request.expiry_height.unwrap_or(0),
+            invoice: request
+                .invoice
+                .map(|invoice| pb::payment_request::SignedInvoice {
+                    id: invoice.id,
+                    verification_key: Some(invoice.verification_key.into()),
+                    signature: Some(invoice.signature.into()),
+                }),
+        }
+    }
+}
+
+impl TryFrom<pb::PaymentRequest> for PaymentRequest {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::PaymentRequest) -> Result<Self, Self::Error> {
+        let request = PaymentRequest {
+            address: msg
+                .address
+                .ok_or_else(|| anyhow::anyhow!("missing address"))?
+                .try_into()?,
+            values: msg
+                .values
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+            memo: msg.memo,
+            expiry_height: match msg.expiry_height {
+                0 => None,
+                expiry_height => Some(expiry_height),
+            },
+            invoice: msg
+                .invoice
+                .map(|invoice| -> anyhow::Result<_> {
+                    Ok(SignedInvoice {
+                        id: invoice.id,
+                        verification_key: invoice
+                            .verification_key
+                            .ok_or_else(|| anyhow::anyhow!("missing invoice verification key"))?
+                            .try_into()?,
+                        signature: invoice
+                            .signature
+                            .ok_or_else(|| anyhow::anyhow!("missing invoice signature"))?
+                            .try_into()?,
+                    })
+                })
+                .transpose()?,
+        };
+        request.check()?;
+        Ok(request)
+    }
+}
+
+impl std::fmt::Display for PaymentRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&bech32str::encode(
+            &self.encode_to_vec(),
+            bech32str::payment_request::BECH32_PREFIX,
+            bech32str::Bech32m,
+        ))
+    }
+}
+
+impl std::str::FromStr for PaymentRequest {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PaymentRequest::decode(
+            bech32str::decode(
+                s,
+                bech32str::payment_request::BECH32_PREFIX,
+                bech32str::Bech32m,
+            )?
+            .as_slice(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+    use penumbra_asset::asset;
+    use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+
+    fn payment_request(spend_key: &SpendKey, memo: &str) -> PaymentRequest {
+        let (address, _dtk) = spend_key
+            .full_viewing_key()
+            .ephemeral_address(OsRng, 0u32.into());
+        let value = Value {
+            amount: 1_000u64.into(),
+            asset_id: asset::Cache::with_known_assets()
+                .get_unit("upenumbra")
+                .expect("upenumbra is a known asset")
+                .id(),
+        };
+        PaymentRequest::new(address, vec![value], memo.to_string(), Some(100))
+            .expect("payment request is valid")
+    }
+
+    fn spend_key() -> SpendKey {
+        SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(OsRng), &Bip44Path::new(0))
+    }
+
+    #[test]
+    fn payment_request_string_round_trip() {
+        let spend_key = spend_key();
+        let request = payment_request(&spend_key, "coffee").sign_invoice(
+            OsRng,
+            "invoice-1".to_string(),
+            &spend_key,
+        );
+
+        let encoded = request.to_string();
+        assert!(encoded.starts_with("penumbrapaymentrequest1"));
+        let decoded: PaymentRequest = encoded.parse().expect("can parse payment request");
+        assert_eq!(decoded.encode_to_vec(), request.encode_to_vec());
+        assert_eq!(decoded.id(), request.id());
+        decoded
+            .verify_invoice()
+            .expect("invoice signature is valid");
+    }
+
+    #[test]
+    fn tampered_invoice_is_rejected() {
+        let spend_key = spend_key();
+        let mut request = payment_request(&spend_key, "coffee").sign_invoice(
+            OsRng,
+            "invoice-1".to_string(),
+            &spend_key,
+        );
+        request
+            .verify_invoice()
+            .expect("invoice signature is valid");
+
+        request.values[0].amount = 1u64.into();
+        assert!(request.verify_invoice().is_err());
+    }
+
+    #[test]
+    fn memo_refers_to_request() {
+        let spend_key = spend_key();
+        let request = payment_request(&spend_key, "coffee");
+        let other = payment_request(&spend_key, "coffee");
+        let return_address = spend_key.full_viewing_key().payment_address(0u32.into()).0;
+
+        let memo = request.memo_plaintext(return_address);
+        assert!(memo.text.starts_with("coffee\n"));
+        assert!(request.is_paid_by(&memo));
+        assert!(!other.is_paid_by(&memo));
+
+        // Signing an invoice id changes which payments match the request.
+        let signed = request
+            .clone()
+            .sign_invoice(OsRng, "invoice-1".to_string(), &spend_key);
+        assert!(!signed.is_paid_by(&memo));
+    }
+
+    #[test]
+    fn payment_request_memo_must_fit() {
+        let spend_key = spend_key();
+        let (address, _dtk) = spend_key.full_viewing_key().payment_address(0u32.into());
+        let value = Value {
+            amount: 1u64.into(),
+            asset_id: asset::Id(decaf377::Fq::from(1u64)),
+        };
+
+        assert!(PaymentRequest::new(address, vec![value], "a".repeat(400), None).is_err());
+        assert!(PaymentRequest::new(address, vec![], String::new(), None).is_err());
+    }
+
+    #[test]
+    fn invoices_do_not_reveal_the_spend_verification_key() {
+        let spend_key = spend_key();
+        let fvk = spend_key.full_viewing_key();
+        let sign = |request: PaymentRequest| {
+            request.sign_invoice(OsRng, "invoice-1".to_string(), &spend_key)
+        };
+
+        let request = sign(payment_request(&spend_key, "coffee"));
+        let other = sign(payment_request(&spend_key, "coffee"));
+        let invoice_key = |request: &PaymentRequest| {
+            request
+                .invoice
+                .as_ref()
+                .expect("request has an invoice")
+                .verification_key
+        };
+
+        // Requests to different addresses are signed with unrelated keys...
+        assert_ne!(invoice_key(&request), *fvk.spend_verification_key());
+        assert_ne!(invoice_key(&request), invoice_key(&other));
+
+        // ...which the wallet still recognizes as its own.
+        assert!(request.is_signed_by(fvk));
+        assert!(other.is_signed_by(fvk));
+        assert!(!request.is_signed_by(self::spend_key().full_viewing_key()));
+
+        // Requests to the same address share a key.
+        let mut again = payment_request(&spend_key, "tea");
+        again.address = request.address;
+        assert_eq!(invoice_key(&sign(again)), invoice_key(&request));
+    }
+}
@@ -17,7 +17,7 @@ use penumbra_ibc::IbcRelay;
 use penumbra_keys::{Address, PayloadKey};
 use penumbra_proto::{core::transaction::v1alpha1 as pb, DomainType};
 use penumbra_shielded_pool::{Ics20Withdrawal, OutputPlan, SpendPlan};
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaimPlan};
+use penumbra_stake::{Delegate, Redelegate, RedelegateClaim, Undelegate, UndelegateClaimPlan};
 use rand::{CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
 
@@ -90,6 +90,26 @@ impl TransactionPlan {
         })
     }
 
+    pub fn redelegations(&self) -> impl Iterator<Item = &Redelegate> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::Redelegate(r) = action {
+                Some(r)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn redelegate_claims(&self) -> impl Iterator<Item = &RedelegateClaim> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::RedelegateClaim(c) = action {
+                Some(c)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn undelegate_claim_plans(&self) -> impl Iterator<Item = &UndelegateClaimPlan> {
         self.actions.iter().filter_map(|action| {
             if let ActionPlan::UndelegateClaim(d) = action {
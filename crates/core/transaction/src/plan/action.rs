@@ -25,7 +25,7 @@ use penumbra_ibc::IbcRelay;
 use penumbra_keys::{symmetric::PayloadKey, FullViewingKey};
 use penumbra_proto::{core::transaction::v1alpha1 as pb_t, DomainType};
 use penumbra_shielded_pool::{Ics20Withdrawal, OutputPlan, SpendPlan};
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaimPlan};
+use penumbra_stake::{Delegate, Redelegate, RedelegateClaim, Undelegate, UndelegateClaimPlan};
 use serde::{Deserialize, Serialize};
 
 /// A declaration of a planned [`Action`], for use in transaction creation.
@@ -47,8 +47,15 @@ pub enum ActionPlan {
     /// We don't need any extra information (yet) to understand undelegations,
     /// because we don't yet use flow encryption.
     Undelegate(Undelegate),
+    /// We don't need any extra information (yet) to understand redelegations,
+    /// because we don't yet use flow encryption.
+    Redelegate(Redelegate),
+    /// Redelegation claims are transparent, so they need no extra information.
+    RedelegateClaim(RedelegateClaim),
     UndelegateClaim(UndelegateClaimPlan),
     ValidatorDefinition(penumbra_stake::validator::Definition),
+    /// Releases a jailed validator from jail.
+    ValidatorUnjail(penumbra_stake::validator::Unjail),
     /// Describes a proposed swap.
     Swap(SwapPlan),
     /// Describes a swap claim.
@@ -134,8 +141,11 @@ impl ActionPlan {
             }
             Delegate(plan) => Action::Delegate(plan.clone()),
             Undelegate(plan) => Action::Undelegate(plan.clone()),
+            Redelegate(plan) => Action::Redelegate(plan.clone()),
+            RedelegateClaim(plan) => Action::RedelegateClaim(plan.clone()),
             UndelegateClaim(plan) => Action::UndelegateClaim(plan.undelegate_claim()),
             ValidatorDefinition(plan) => Action::ValidatorDefinition(plan.clone()),
+            ValidatorUnjail(plan) => Action::ValidatorUnjail(plan.clone()),
             // Fixme: action name
             IbcAction(plan) => Action::IbcRelay(plan.clone()),
             ProposalSubmit(plan) => Action::ProposalSubmit(plan.clone()),
@@ -153,9 +163,7 @@ impl ActionPlan {
             PositionOpen(plan) => Action::PositionOpen(plan.clone()),
             PositionClose(plan) => Action::PositionClose(plan.clone()),
             PositionWithdraw(plan) => Action::PositionWithdraw(plan.position_withdraw()),
-            PositionRewardClaim(_plan) => unimplemented!(
-                "this api is wrong and needs to be fixed, but we don't do reward claims anyways"
-            ),
+            PositionRewardClaim(plan) => Action::PositionRewardClaim(plan.position_reward_claim()),
             DaoSpend(plan) => Action::DaoSpend(plan.clone()),
             DaoOutput(plan) => Action::DaoOutput(plan.clone()),
             DaoDeposit(plan) => Action::DaoDeposit(plan.clone()),
@@ -172,6 +180,8 @@ impl ActionPlan {
             Output(output) => output.balance(),
             Delegate(delegate) => delegate.balance(),
             Undelegate(undelegate) => undelegate.balance(),
+            Redelegate(redelegate) => redelegate.balance(),
+            RedelegateClaim(redelegate_claim) => redelegate_claim.balance(),
             UndelegateClaim(undelegate_claim) => undelegate_claim.balance(),
             Swap(swap) => swap.balance(),
             SwapClaim(swap_claim) => swap_claim.balance(),
@@ -188,7 +198,9 @@ impl ActionPlan {
             PositionRewardClaim(position_reward_claim) => position_reward_claim.balance(),
             Withdrawal(withdrawal) => withdrawal.balance(),
             // None of these contribute to transaction balance:
-            IbcAction(_) | ValidatorDefinition(_) | ValidatorVote(_) => Balance::default(),
+            IbcAction(_) | ValidatorDefinition(_) | ValidatorUnjail(_) | ValidatorVote(_) => {
+                Balance::default()
+            }
         }
     }
 
@@ -200,8 +212,11 @@ impl ActionPlan {
             Output(output) => output.value_blinding,
             Delegate(_) => Fr::zero(),
             Undelegate(_) => Fr::zero(),
+            Redelegate(_) => Fr::zero(),
+            RedelegateClaim(_) => Fr::zero(),
             UndelegateClaim(undelegate_claim) => undelegate_claim.balance_blinding,
             ValidatorDefinition(_) => Fr::zero(),
+            ValidatorUnjail(_) => Fr::zero(),
             Swap(swap) => swap.fee_blinding,
             SwapClaim(_) => Fr::zero(),
             IbcAction(_) => Fr::zero(),
@@ -230,8 +245,11 @@ impl ActionPlan {
             Output(plan) => plan.output_body(fvk.outgoing(), memo_key).effect_hash(),
             Delegate(plan) => plan.effect_hash(),
             Undelegate(plan) => plan.effect_hash(),
+            Redelegate(plan) => plan.effect_hash(),
+            RedelegateClaim(plan) => plan.effect_hash(),
             UndelegateClaim(plan) => plan.undelegate_claim_body().effect_hash(),
             ValidatorDefinition(plan) => plan.effect_hash(),
+            ValidatorUnjail(plan) => plan.effect_hash(),
             Swap(plan) => plan.swap_body(fvk).effect_hash(),
             SwapClaim(plan) => plan.swap_claim_body(fvk).effect_hash(),
             IbcAction(plan) => plan.effect_hash(),
@@ -243,7 +261,7 @@ impl ActionPlan {
             PositionOpen(plan) => plan.effect_hash(),
             PositionClose(plan) => plan.effect_hash(),
             PositionWithdraw(plan) => plan.position_withdraw().effect_hash(),
-            PositionRewardClaim(_plan) => todo!("position reward claim plan is not implemented"),
+            PositionRewardClaim(plan) => plan.position_reward_claim().effect_hash(),
             DaoSpend(plan) => plan.effect_hash(),
             DaoOutput(plan) => plan.effect_hash(),
             DaoDeposit(plan) => plan.effect_hash(),
@@ -296,6 +314,24 @@ impl From<penumbra_stake::validator::Definition> for ActionPlan {
     }
 }
 
+impl From<Redelegate> for ActionPlan {
+    fn from(inner: Redelegate) -> ActionPlan {
+        ActionPlan::Redelegate(inner)
+    }
+}
+
+impl From<RedelegateClaim> for ActionPlan {
+    fn from(inner: RedelegateClaim) -> ActionPlan {
+        ActionPlan::RedelegateClaim(inner)
+    }
+}
+
+impl From<penumbra_stake::validator::Unjail> for ActionPlan {
+    fn from(inner: penumbra_stake::validator::Unjail) -> ActionPlan {
+        ActionPlan::ValidatorUnjail(inner)
+    }
+}
+
 impl From<IbcRelay> for ActionPlan {
     fn from(inner: IbcRelay) -> ActionPlan {
         ActionPlan::IbcAction(inner)
@@ -369,12 +405,21 @@ impl From<ActionPlan> for pb_t::ActionPlan {
             ActionPlan::Undelegate(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::Undelegate(inner.into())),
             },
+            ActionPlan::Redelegate(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::Redelegate(inner.into())),
+            },
+            ActionPlan::RedelegateClaim(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::RedelegateClaim(inner.into())),
+            },
             ActionPlan::UndelegateClaim(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::UndelegateClaim(inner.into())),
             },
             ActionPlan::ValidatorDefinition(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::ValidatorDefinition(inner.into())),
             },
+            ActionPlan::ValidatorUnjail(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::ValidatorUnjail(inner.into())),
+            },
             ActionPlan::SwapClaim(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::SwapClaim(inner.into())),
             },
@@ -453,12 +498,21 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
             pb_t::action_plan::Action::Undelegate(inner) => {
                 Ok(ActionPlan::Undelegate(inner.try_into()?))
             }
+            pb_t::action_plan::Action::Redelegate(inner) => {
+                Ok(ActionPlan::Redelegate(inner.try_into()?))
+            }
+            pb_t::action_plan::Action::RedelegateClaim(inner) => {
+                Ok(ActionPlan::RedelegateClaim(inner.try_into()?))
+            }
             pb_t::action_plan::Action::UndelegateClaim(inner) => {
                 Ok(ActionPlan::UndelegateClaim(inner.try_into()?))
             }
             pb_t::action_plan::Action::ValidatorDefinition(inner) => {
                 Ok(ActionPlan::ValidatorDefinition(inner.try_into()?))
             }
+            pb_t::action_plan::Action::ValidatorUnjail(inner) => {
+                Ok(ActionPlan::ValidatorUnjail(inner.try_into()?))
+            }
             pb_t::action_plan::Action::Swap(inner) => Ok(ActionPlan::Swap(inner.try_into()?)),
             pb_t::action_plan::Action::SwapClaim(inner) => {
                 Ok(ActionPlan::SwapClaim(inner.try_into()?))
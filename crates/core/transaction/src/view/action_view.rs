@@ -8,7 +8,7 @@ use penumbra_governance::{ProposalDepositClaim, ProposalSubmit, ProposalWithdraw
 use penumbra_ibc::IbcRelay;
 use penumbra_proto::{core::transaction::v1alpha1 as pbt, DomainType};
 use penumbra_shielded_pool::Ics20Withdrawal;
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaim};
+use penumbra_stake::{Delegate, Redelegate, RedelegateClaim, Undelegate, UndelegateClaim};
 use serde::{Deserialize, Serialize};
 
 pub use penumbra_governance::DelegatorVoteView;
@@ -29,6 +29,7 @@ pub enum ActionView {
     DelegatorVote(DelegatorVoteView),
     // Action types with transparent contents
     ValidatorDefinition(penumbra_stake::validator::Definition),
+    ValidatorUnjail(penumbra_stake::validator::Unjail),
     IbcRelay(IbcRelay),
     ProposalSubmit(ProposalSubmit),
     ProposalWithdraw(ProposalWithdraw),
@@ -40,6 +41,8 @@ pub enum ActionView {
     PositionRewardClaim(PositionRewardClaim),
     Delegate(Delegate),
     Undelegate(Undelegate),
+    Redelegate(Redelegate),
+    RedelegateClaim(RedelegateClaim),
     UndelegateClaim(UndelegateClaim),
     Ics20Withdrawal(Ics20Withdrawal),
     DaoDeposit(DaoDeposit),
@@ -65,10 +68,13 @@ impl TryFrom<pbt::ActionView> for ActionView {
                 AV::Spend(x) => ActionView::Spend(x.try_into()?),
                 AV::Output(x) => ActionView::Output(x.try_into()?),
                 AV::Undelegate(x) => ActionView::Undelegate(x.try_into()?),
+                AV::Redelegate(x) => ActionView::Redelegate(x.try_into()?),
+                AV::RedelegateClaim(x) => ActionView::RedelegateClaim(x.try_into()?),
                 AV::UndelegateClaim(x) => ActionView::UndelegateClaim(x.try_into()?),
                 AV::Swap(x) => ActionView::Swap(x.try_into()?),
                 AV::SwapClaim(x) => ActionView::SwapClaim(x.try_into()?),
                 AV::ValidatorDefinition(x) => ActionView::ValidatorDefinition(x.try_into()?),
+                AV::ValidatorUnjail(x) => ActionView::ValidatorUnjail(x.try_into()?),
                 AV::IbcRelayAction(x) => ActionView::IbcRelay(x.try_into()?),
                 AV::ProposalSubmit(x) => ActionView::ProposalSubmit(x.try_into()?),
                 AV::ProposalWithdraw(x) => ActionView::ProposalWithdraw(x.try_into()?),
@@ -99,8 +105,11 @@ impl From<ActionView> for pbt::ActionView {
                 ActionView::Spend(x) => AV::Spend(x.into()),
                 ActionView::Delegate(x) => AV::Delegate(x.into()),
                 ActionView::Undelegate(x) => AV::Undelegate(x.into()),
+                ActionView::Redelegate(x) => AV::Redelegate(x.into()),
+                ActionView::RedelegateClaim(x) => AV::RedelegateClaim(x.into()),
                 ActionView::UndelegateClaim(x) => AV::UndelegateClaim(x.into()),
                 ActionView::ValidatorDefinition(x) => AV::ValidatorDefinition(x.into()),
+                ActionView::ValidatorUnjail(x) => AV::ValidatorUnjail(x.into()),
                 ActionView::IbcRelay(x) => AV::IbcRelayAction(x.into()),
                 ActionView::ProposalSubmit(x) => AV::ProposalSubmit(x.into()),
                 ActionView::ProposalWithdraw(x) => AV::ProposalWithdraw(x.into()),
@@ -129,8 +138,11 @@ impl From<ActionView> for Action {
             ActionView::Spend(x) => Action::Spend(x.into()),
             ActionView::Delegate(x) => Action::Delegate(x),
             ActionView::Undelegate(x) => Action::Undelegate(x),
+            ActionView::Redelegate(x) => Action::Redelegate(x),
+            ActionView::RedelegateClaim(x) => Action::RedelegateClaim(x),
             ActionView::UndelegateClaim(x) => Action::UndelegateClaim(x),
             ActionView::ValidatorDefinition(x) => Action::ValidatorDefinition(x),
+            ActionView::ValidatorUnjail(x) => Action::ValidatorUnjail(x),
             ActionView::IbcRelay(x) => Action::IbcRelay(x),
             ActionView::ProposalSubmit(x) => Action::ProposalSubmit(x),
             ActionView::ProposalWithdraw(x) => Action::ProposalWithdraw(x),
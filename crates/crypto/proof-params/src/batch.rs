@@ -0,0 +1,355 @@
+use anyhow::{anyhow, Result};
+use ark_ec::{pairing::Pairing, CurveGroup};
+use ark_ff::{Field, PrimeField, Zero};
+use ark_groth16::{r1cs_to_qap::LibsnarkReduction, Groth16, PreparedVerifyingKey, Proof};
+use ark_snark::SNARK;
+use ark_std::UniformRand;
+use decaf377::{Bls12_377, Fq};
+use rand_core::CryptoRngCore;
+
+type G1 = <Bls12_377 as Pairing>::G1;
+type G1Prepared = <Bls12_377 as Pairing>::G1Prepared;
+type G2Prepared = <Bls12_377 as Pairing>::G2Prepared;
+
+/// A proof queued for batch verification, along with its public inputs.
+struct Item {
+    proof: Proof<Bls12_377>,
+    public_inputs: Vec<Fq>,
+}
+
+/// The proofs queued for a single circuit.
+struct Batch<'a> {
+    vk: &'a PreparedVerifyingKey<Bls12_377>,
+    label: &'static str,
+    items: Vec<Item>,
+}
+
+/// Verifies many Groth16 proofs at once.
+///
+/// Proofs are grouped by verifying key, and the proofs for each circuit are
+/// checked together with a single randomized multi-pairing, which costs one
+/// Miller loop per proof and one final exponentiation per circuit, rather than
+/// a full pairing check per proof.
+///
+/// If a batch fails to verify, its proofs are verified one by one, to report
+/// which proof is invalid.
+#[derive(Default)]
+pub struct BatchVerifier<'a> {
+    batches: Vec<Batch<'a>>,
+}
+
+impl<'a> BatchVerifier<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a proof for verification against the verifying key `vk`.
+    ///
+    /// The `label` names the kind of proof in error messages, e.g. `"spend proof"`.
+    /// The proof must have been deserialized with subgroup checks, since the batch
+    /// combines it with the other proofs before pairing.
+    pub fn queue(
+        &mut self,
+        vk: &'a PreparedVerifyingKey<Bls12_377>,
+        label: &'static str,
+        proof: Proof<Bls12_377>,
+        public_inputs: Vec<Fq>,
+    ) {
+        let item = Item {
+            proof,
+            public_inputs,
+        };
+        match self
+            .batches
+            .iter_mut()
+            .find(|batch| std::ptr::eq(batch.vk, vk))
+        {
+            Some(batch) => batch.items.push(item),
+            None => self.batches.push(Batch {
+                vk,
+                label,
+                items: vec![item],
+            }),
+        }
+    }
+
+    /// The number of queued proofs.
+    pub fn len(&self) -> usize {
+        self.batches.iter().map(|batch| batch.items.len()).sum()
+    }
+
+    /// Returns true if no proofs are queued.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Verifies all the queued proofs.
+    #[tracing::instrument(skip_all, fields(proofs = self.len()))]
+    pub fn verify(self, rng: &mut impl CryptoRngCore) -> Result<()> {
+        for batch in self.batches {
+            let start = std::time::Instant::now();
+            let batch_result = batch.verify(rng);
+            tracing::debug!(
+                label = batch.label,
+                proofs = batch.items.len(),
+                ?batch_result,
+                elapsed = ?start.elapsed(),
+                "verified proof batch"
+            );
+            if !batch_result {
+                batch.find_invalid()?;
+                // Every proof verified on its own, so the batch should have too.
+                return Err(anyhow!(
+                    "batch of {} {}s did not verify",
+                    batch.items.len(),
+                    batch.label
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Batch<'_> {
+    /// Checks the randomized batch equation
+    ///
+    /// ```text
+    /// ∏ e(r_i A_i, B_i) · e(-∑ r_i IC_i, γ) · e(-∑ r_i C_i, δ) = e(α, β)^(∑ r_i)
+    /// ```
+    ///
+    /// where `IC_i` is the linear combination of the public inputs of the `i`-th
+    /// proof, and the `r_i` are random scalars preventing invalid proofs from
+    /// canceling each other out.
+    fn verify(&self, rng: &mut impl CryptoRngCore) -> bool {
+        if let [item] = self.items.as_slice() {
+            return verify_single(self.vk, item);
+        }
+
+        let mut g1: Vec<G1Prepared> = Vec::with_capacity(self.items.len() + 2);
+        let mut g2: Vec<G2Prepared> = Vec::with_capacity(self.items.len() + 2);
+        let mut inputs_sum = G1::zero();
+        let mut c_sum = G1::zero();
+        let mut r_sum = Fq::zero();
+
+        for item in &self.items {
+            let prepared_inputs = match Groth16::<Bls12_377, LibsnarkReduction>::prepare_inputs(
+                self.vk,
+                &item.public_inputs,
+            ) {
+                Ok(prepared_inputs) => prepared_inputs,
+                Err(_) => return false,
+            };
+            let r = Fq::rand(rng);
+            inputs_sum += prepared_inputs * r;
+            c_sum += item.proof.c * r;
+            r_sum += r;
+            g1.push((item.proof.a * r).into_affine().into());
+            g2.push(item.proof.b.into());
+        }
+        g1.push(inputs_sum.into_affine().into());
+        g2.push(self.vk.gamma_g2_neg_pc.clone());
+        g1.push(c_sum.into_affine().into());
+        g2.push(self.vk.delta_g2_neg_pc.clone());
+
+        let qap = Bls12_377::multi_miller_loop(g1, g2);
+        match Bls12_377::final_exponentiation(qap) {
+            Some(test) => test.0 == self.vk.alpha_g1_beta_g2.pow(r_sum.into_bigint()),
+            None => false,
+        }
+    }
+
+    /// Verifies the proofs one by one, returning an error for the first invalid one.
+    fn find_invalid(&self) -> Result<()> {
+        for (i, item) in self.items.iter().enumerate() {
+            if !verify_single(self.vk, item) {
+                return Err(anyhow!(
+                    "{} {} of {} did not verify",
+                    self.label,
+                    i + 1,
+                    self.items.len()
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn verify_single(vk: &PreparedVerifyingKey<Bls12_377>, item: &Item) -> bool {
+    Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
+        vk,
+        &item.public_inputs,
+        &item.proof,
+    )
+    .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_groth16::ProvingKey;
+    use ark_relations::{
+        lc,
+        r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError},
+    };
+    use ark_snark::{CircuitSpecificSetupSNARK, SNARK};
+    use rand_core::{CryptoRng, OsRng, RngCore};
+
+    use super::*;
+
+    /// Proves knowledge of `w` such that `w^exponent = x`, for a public `x`.
+    #[derive(Clone)]
+    struct PowerCircuit {
+        exponent: u64,
+        w: Fq,
+    }
+
+    impl ConstraintSynthesizer<Fq> for PowerCircuit {
+        fn generate_constraints(self, cs: ConstraintSystemRef<Fq>) -> Result<(), SynthesisError> {
+            let x = cs.new_input_variable(|| Ok(self.w.pow([self.exponent])))?;
+            let w = cs.new_witness_variable(|| Ok(self.w))?;
+            let mut acc = w;
+            let mut value = self.w;
+            for i in 1..self.exponent {
+                value *= self.w;
+                let next = if i + 1 == self.exponent {
+                    x
+                } else {
+                    cs.new_witness_variable(|| Ok(value))?
+                };
+                cs.enforce_constraint(lc!() + acc, lc!() + w, lc!() + next)?;
+                acc = next;
+            }
+            Ok(())
+        }
+    }
+
+    struct Circuit {
+        exponent: u64,
+        pk: ProvingKey<Bls12_377>,
+        vk: PreparedVerifyingKey<Bls12_377>,
+    }
+
+    impl Circuit {
+        fn setup(exponent: u64) -> Self {
+            let circuit = PowerCircuit {
+                exponent,
+                w: Fq::from(1u64),
+            };
+            let (pk, vk) = Groth16::<Bls12_377, LibsnarkReduction>::setup(circuit, &mut OsRng)
+                .expect("can generate parameters");
+            Self {
+                exponent,
+                pk,
+                vk: Groth16::<Bls12_377, LibsnarkReduction>::process_vk(&vk)
+                    .expect("can process vk"),
+            }
+        }
+
+        /// Returns a proof for `w`, along with its public input.
+        fn prove(&self, w: u64) -> (Proof<Bls12_377>, Vec<Fq>) {
+            let w = Fq::from(w);
+            let circuit = PowerCircuit {
+                exponent: self.exponent,
+                w,
+            };
+            let proof =
+                Groth16::<Bls12_377, LibsnarkReduction>::prove(&self.pk, circuit, &mut OsRng)
+                    .expect("can create proof");
+            (proof, vec![w.pow([self.exponent])])
+        }
+    }
+
+    /// An RNG that always returns the same bytes, so every proof in a batch gets
+    /// the same random scalar.
+    struct FixedRng;
+
+    impl RngCore for FixedRng {
+        fn next_u32(&mut self) -> u32 {
+            0x0101_0101
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            0x0101_0101_0101_0101
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            dest.fill(1);
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for FixedRng {}
+
+    #[test]
+    fn batches_proofs_across_circuits() {
+        let square = Circuit::setup(2);
+        let cube = Circuit::setup(3);
+
+        let mut batch = BatchVerifier::new();
+        for w in 1..=3 {
+            let (proof, public_inputs) = square.prove(w);
+            batch.queue(&square.vk, "square proof", proof, public_inputs);
+            let (proof, public_inputs) = cube.prove(w);
+            batch.queue(&cube.vk, "cube proof", proof, public_inputs);
+        }
+        assert_eq!(batch.len(), 6);
+        assert_eq!(batch.batches.len(), 2);
+        batch
+            .verify(&mut OsRng)
+            .expect("valid proofs verify in a batch");
+
+        // An invalid proof for one circuit fails the whole batch, even though the
+        // proofs for the other circuit are valid.
+        let mut batch = BatchVerifier::new();
+        for w in 1..=3 {
+            let (proof, public_inputs) = square.prove(w);
+            batch.queue(&square.vk, "square proof", proof, public_inputs);
+            let (proof, mut public_inputs) = cube.prove(w);
+            if w == 2 {
+                public_inputs[0] += Fq::from(1u64);
+            }
+            batch.queue(&cube.vk, "cube proof", proof, public_inputs);
+        }
+        let err = batch
+            .verify(&mut OsRng)
+            .expect_err("invalid proof is rejected");
+        assert_eq!(err.to_string(), "cube proof 2 of 3 did not verify");
+
+        // A proof checked against the verifying key of another circuit is invalid.
+        let mut batch = BatchVerifier::new();
+        let (proof, public_inputs) = square.prove(2);
+        batch.queue(&square.vk, "square proof", proof, public_inputs);
+        let (proof, public_inputs) = square.prove(3);
+        batch.queue(&cube.vk, "cube proof", proof, public_inputs);
+        let err = batch
+            .verify(&mut OsRng)
+            .expect_err("invalid proof is rejected");
+        assert_eq!(err.to_string(), "cube proof 1 of 1 did not verify");
+    }
+
+    #[test]
+    fn invalid_proofs_cancel_without_random_scalars() {
+        let square = Circuit::setup(2);
+        let (proof_2, inputs_2) = square.prove(2);
+        let (proof_3, inputs_3) = square.prove(3);
+
+        // Swapping the public inputs of two proofs makes both invalid, but the
+        // sum of their public inputs is unchanged.
+        let mut batch = BatchVerifier::new();
+        batch.queue(&square.vk, "square proof", proof_2, inputs_3);
+        batch.queue(&square.vk, "square proof", proof_3, inputs_2);
+
+        // With the same scalar for every proof, the errors cancel out and the
+        // batch equation holds...
+        assert!(batch.batches[0].verify(&mut FixedRng));
+        // ...which random scalars prevent.
+        assert!(!batch.batches[0].verify(&mut OsRng));
+        let err = batch
+            .verify(&mut OsRng)
+            .expect_err("invalid proof is rejected");
+        assert_eq!(err.to_string(), "square proof 1 of 2 did not verify");
+    }
+}
@@ -1,3 +1,3 @@
 
-pub const PROVING_KEY_ID: &'static str = "groth16pk1hqv7y72vx5hgzfkzcajf5mg6plc3hvnhjqg8zaclymv4m9lfj99sn7w8wv";
-pub const VERIFICATION_KEY_ID: &'static str = "groth16vk1knhcr7yduj48sdrx4htfrth45ptqmjwtsyywwg5vl4pucrwm368qeqzj84";
+pub const PROVING_KEY_ID: &'static str = "groth16pk1xk3rwzf8pat56r8cq0uykha4r3q7p05fj03eqhvu0yj9afrfv33sz06698";
+pub const VERIFICATION_KEY_ID: &'static str = "groth16vk158yj9me2lsl4catalusha8hmhwn42pnfq6aw5fdz0v7m62pfudqsz8w9t7";
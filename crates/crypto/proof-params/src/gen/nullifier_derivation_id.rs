@@ -1,3 +1,3 @@
 
-pub const PROVING_KEY_ID: &'static str = "groth16pk1706hzsn4sn3ewwhnavgyql7pa289qwkze3eq8533wwdcqfzh5lrsz79klh";
-pub const VERIFICATION_KEY_ID: &'static str = "groth16vk1us3xkxnj8eks0mkk72z5h42mxhs4du5knm3m3llcghv635pn0qequt4yc2";
+pub const PROVING_KEY_ID: &'static str = "groth16pk13ddqu4ujnuxfpsau0ghfxdq5kup983ynkg234nvnqjnyvrwttvtqunfeh6";
+pub const VERIFICATION_KEY_ID: &'static str = "groth16vk10lurhmqasp4mwdempx72xzq0h3jkacmmfgl0s392l0swyjf205fsgtcm93";
@@ -1,3 +1,3 @@
 
-pub const PROVING_KEY_ID: &'static str = "groth16pk1cjr04fvt8n59fzwdlmqrs9e9v7j8syfenvjax2krqjjr3ky0jlfqxalshd";
-pub const VERIFICATION_KEY_ID: &'static str = "groth16vk15hyzxz5zkc5zszg3n0g0247jp3h87w2dgu8zhe5k89wqesy7hxfqntspmu";
+pub const PROVING_KEY_ID: &'static str = "groth16pk10vm8r6ja338j5u5qx24tjh6tzqs9yva8d3krgmk02jttcg3hhacq072g50";
+pub const VERIFICATION_KEY_ID: &'static str = "groth16vk13tvmcv3te5kz82g6ty67aypuyqsa492uyl0ghn6v93w8utea400q8de5p4";
@@ -1,3 +1,3 @@
 
-pub const PROVING_KEY_ID: &'static str = "groth16pk1fcytclqvsqmleer6w87cnyvfw9gvksydren89r4hejhkmq8njl8qanww5n";
-pub const VERIFICATION_KEY_ID: &'static str = "groth16vk1xtreus06n3ewt2q3wt3jy79umjpytr7c9z9pfggahhzdl848299qxajy7v";
+pub const PROVING_KEY_ID: &'static str = "groth16pk1vx9rrh4gw5v9zwy0pa92armddhauq98709acgr2td0r5uarhlgfqkgy9jf";
+pub const VERIFICATION_KEY_ID: &'static str = "groth16vk1733pamwugg2ywls52v6xckks9e8tqpcjhux7nf8ychkkrdapgq8sje0xlj";
@@ -1,3 +1,3 @@
 
-pub const PROVING_KEY_ID: &'static str = "groth16pk1u5xk2s2uxnz7370mt5f84yvcc0qdz42v6lsdjy3w6vyv6gaympms22kn8r";
-pub const VERIFICATION_KEY_ID: &'static str = "groth16vk1qsujtjs2pgumus0sx5unmygv0uq78t8x2eht4ry89qempx8wh4gs4gaped";
+pub const PROVING_KEY_ID: &'static str = "groth16pk15d4tafq98wkha40yjgzwqg4v7fhjzew2jcsf4svw46lhq3k462hqt03hze";
+pub const VERIFICATION_KEY_ID: &'static str = "groth16vk165twq2964v9plfmum7z7c9r9220gt23ma6xdfcwheg9ndy9vj6wsvu6fgj";
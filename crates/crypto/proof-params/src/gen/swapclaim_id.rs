@@ -1,3 +1,3 @@
 
-pub const PROVING_KEY_ID: &'static str = "groth16pk1jxrg7qd577py094z6430e5y8864ya5dwevh879aj6qk2qq3r7exq49ftnq";
-pub const VERIFICATION_KEY_ID: &'static str = "groth16vk1rg87uv9a6qphylmk6n66llaj954zq0jpwkx5cw5sfwpgaw0ym4mqkrn2ct";
+pub const PROVING_KEY_ID: &'static str = "groth16pk1r58f52q7wfc24mjnn2z6mflymtsvr0jwclmfg9k48kkexjrel7rq7jfhsw";
+pub const VERIFICATION_KEY_ID: &'static str = "groth16vk1x7qyxsvytstf0nej6w994j793d3ku2jxdxk383ljxx7zvz7fx9gsc74pe9";
@@ -1,3 +1,3 @@
 
-pub const PROVING_KEY_ID: &'static str = "groth16pk1kz7ck7fsulndvp23zf6knnt7gvkh3pycryj9q95k4vlc4fmv2ptqq4pw65";
-pub const VERIFICATION_KEY_ID: &'static str = "groth16vk1edaemnjzh22hmkms4mqw9mhh9kt79qjvdgtr5gurn4k8yc7860xqx5ecxk";
+pub const PROVING_KEY_ID: &'static str = "groth16pk19pr2l7e453s347mtndjcpmd032v9fulk59vka3wdf57g9qca7f0qw2z6ly";
+pub const VERIFICATION_KEY_ID: &'static str = "groth16vk1ll6698quukyqt5fw6w9a88dkkkz0htnrr2xs6fuue24c3hh9pz2skxvqlj";
@@ -9,8 +9,10 @@ use std::ops::Deref;
 /// The length of our Groth16 proofs in bytes.
 pub const GROTH16_PROOF_LENGTH_BYTES: usize = 192;
 
+mod batch;
 mod traits;
 
+pub use batch::BatchVerifier;
 pub use traits::{
     generate_constraint_matrices, generate_prepared_test_parameters, generate_test_parameters,
     DummyWitness, ProvingKeyExt, VerifyingKeyExt,
@@ -19,4 +19,4 @@ pub mod threshold;
 
 pub use client::CustodyClient;
 pub use pre_auth::PreAuthorization;
-pub use request::AuthorizeRequest;
+pub use request::{AuthorizePaymentRequest, AuthorizeRequest};
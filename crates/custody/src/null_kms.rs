@@ -38,4 +38,13 @@ impl pb::custody_protocol_service_server::CustodyProtocolService for NullKms {
             "Got authorization request in view-only mode to null KMS.",
         ))
     }
+
+    async fn authorize_payment_request(
+        &self,
+        _request: Request<pb::AuthorizePaymentRequestRequest>,
+    ) -> Result<Response<pb::AuthorizePaymentRequestResponse>, Status> {
+        Err(tonic::Status::failed_precondition(
+            "Got authorization request in view-only mode to null KMS.",
+        ))
+    }
 }
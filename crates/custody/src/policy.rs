@@ -1,17 +1,46 @@
 //! A set of basic spend authorization policies.
 
-use std::collections::HashSet;
+use std::{
+    collections::{BTreeMap, HashSet},
+    time::{Duration, SystemTime},
+};
 
-use penumbra_keys::Address;
-use penumbra_transaction::plan::ActionPlan;
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_keys::{Address, FullViewingKey};
+use penumbra_num::Amount;
+use penumbra_transaction::plan::{ActionPlan, TransactionPlan};
 use serde::{Deserialize, Serialize};
+use serde_with::DisplayFromStr;
 
-use crate::{AuthorizeRequest, PreAuthorization};
+use crate::{pre_auth, AuthorizePaymentRequest, AuthorizeRequest, PreAuthorization};
+
+mod history;
+
+pub use history::SpendHistory;
 
 /// A trait for checking whether a transaction plan is allowed by a policy.
 pub trait Policy {
     /// Checks whether the proposed transaction plan is allowed by this policy.
-    fn check(&self, request: &AuthorizeRequest) -> anyhow::Result<()>;
+    fn check(&self, request: &AuthorizeRequest, context: &PolicyContext) -> anyhow::Result<()>;
+
+    /// Checks whether signing the invoice id of the proposed payment request is allowed by this
+    /// policy.
+    fn check_payment_request(
+        &self,
+        request: &AuthorizePaymentRequest,
+        context: &PolicyContext,
+    ) -> anyhow::Result<()>;
+}
+
+/// What policies know about the custodian when checking a request.
+#[derive(Clone, Copy, Debug)]
+pub struct PolicyContext<'a> {
+    /// The full viewing key of the account being spent from.
+    pub fvk: &'a FullViewingKey,
+    /// The value sent out of the account by previously authorized transactions.
+    pub history: &'a SpendHistory,
+    /// The time at which the request is being checked.
+    pub now: SystemTime,
 }
 
 /// A set of basic spend authorization policies.
@@ -19,6 +48,7 @@ pub trait Policy {
 /// These policies are intended to be simple enough that they can be written by
 /// hand in a config file.  More complex policy logic than than should be
 /// implemented by a custom implementation of the [`Policy`] trait.
+#[serde_as]
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 #[serde(tag = "type")]
 pub enum AuthPolicy {
@@ -36,6 +66,200 @@ pub enum AuthPolicy {
     OnlyIbcRelay,
     /// Require specific pre-authorizations for submitted [`TransactionPlan`](penumbra_transaction::plan::TransactionPlan)s.
     PreAuthorization(PreAuthorizationPolicy),
+    /// Only allow transactions made up of the allowed kinds of actions.
+    AllowedActions { allowed_actions: Vec<ActionKind> },
+    /// Limit the amount of an asset a single transaction can send out of the
+    /// account, as computed by [`outflows`].
+    SpendLimit {
+        #[serde_as(as = "DisplayFromStr")]
+        asset_id: asset::Id,
+        #[serde_as(as = "DisplayFromStr")]
+        amount: u128,
+    },
+    /// Limit the amount of an asset sent out of the account, as computed by
+    /// [`outflows`], by all the transactions authorized over a rolling window
+    /// of time, including the one being authorized.
+    ///
+    /// Transactions count towards the limit from the time they're authorized,
+    /// even if they're never broadcast.
+    RollingSpendLimit {
+        #[serde_as(as = "DisplayFromStr")]
+        asset_id: asset::Id,
+        #[serde_as(as = "DisplayFromStr")]
+        amount: u128,
+        window_seconds: u64,
+    },
+    /// Only allow transactions paying a fee of at most `amount` of the given
+    /// asset, which defaults to the staking token.
+    ///
+    /// Fees paid in any other asset are rejected, so this policy should be
+    /// combined with `AnyOf` to allow several fee assets.
+    MaxFee {
+        #[serde_as(as = "DisplayFromStr")]
+        amount: u128,
+        #[serde_as(as = "Option<DisplayFromStr>")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        asset_id: Option<asset::Id>,
+    },
+    /// Only allow ICS-20 withdrawals over the allowed channels, to the allowed receivers.
+    IbcWithdrawalAllowList { allowed_routes: Vec<IbcRoute> },
+    /// Only allow transactions allowed by all of the policies.
+    AllOf { policies: Vec<AuthPolicy> },
+    /// Only allow transactions allowed by at least one of the policies.
+    AnyOf { policies: Vec<AuthPolicy> },
+}
+
+/// The kinds of actions a transaction plan can contain.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ActionKind {
+    Spend,
+    Output,
+    Delegate,
+    Undelegate,
+    Redelegate,
+    RedelegateClaim,
+    UndelegateClaim,
+    ValidatorDefinition,
+    ValidatorUnjail,
+    Swap,
+    SwapClaim,
+    IbcAction,
+    ProposalSubmit,
+    ProposalWithdraw,
+    DelegatorVote,
+    ValidatorVote,
+    ProposalDepositClaim,
+    PositionOpen,
+    PositionClose,
+    PositionWithdraw,
+    PositionRewardClaim,
+    DaoSpend,
+    DaoOutput,
+    DaoDeposit,
+    Withdrawal,
+}
+
+impl From<&ActionPlan> for ActionKind {
+    fn from(action: &ActionPlan) -> Self {
+        match action {
+            ActionPlan::Spend(_) => ActionKind::Spend,
+            ActionPlan::Output(_) => ActionKind::Output,
+            ActionPlan::Delegate(_) => ActionKind::Delegate,
+            ActionPlan::Undelegate(_) => ActionKind::Undelegate,
+            ActionPlan::Redelegate(_) => ActionKind::Redelegate,
+            ActionPlan::RedelegateClaim(_) => ActionKind::RedelegateClaim,
+            ActionPlan::UndelegateClaim(_) => ActionKind::UndelegateClaim,
+            ActionPlan::ValidatorDefinition(_) => ActionKind::ValidatorDefinition,
+            ActionPlan::ValidatorUnjail(_) => ActionKind::ValidatorUnjail,
+            ActionPlan::Swap(_) => ActionKind::Swap,
+            ActionPlan::SwapClaim(_) => ActionKind::SwapClaim,
+            ActionPlan::IbcAction(_) => ActionKind::IbcAction,
+            ActionPlan::ProposalSubmit(_) => ActionKind::ProposalSubmit,
+            ActionPlan::ProposalWithdraw(_) => ActionKind::ProposalWithdraw,
+            ActionPlan::DelegatorVote(_) => ActionKind::DelegatorVote,
+            ActionPlan::ValidatorVote(_) => ActionKind::ValidatorVote,
+            ActionPlan::ProposalDepositClaim(_) => ActionKind::ProposalDepositClaim,
+            ActionPlan::PositionOpen(_) => ActionKind::PositionOpen,
+            ActionPlan::PositionClose(_) => ActionKind::PositionClose,
+            ActionPlan::PositionWithdraw(_) => ActionKind::PositionWithdraw,
+            ActionPlan::PositionRewardClaim(_) => ActionKind::PositionRewardClaim,
+            ActionPlan::DaoSpend(_) => ActionKind::DaoSpend,
+            ActionPlan::DaoOutput(_) => ActionKind::DaoOutput,
+            ActionPlan::DaoDeposit(_) => ActionKind::DaoDeposit,
+            ActionPlan::Withdrawal(_) => ActionKind::Withdrawal,
+        }
+    }
+}
+
+/// An IBC channel that ICS-20 withdrawals are allowed to use.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct IbcRoute {
+    /// The source channel of the withdrawal, e.g. `channel-0`.
+    pub channel: String,
+    /// The addresses on the counterparty chain allowed to receive withdrawals.
+    ///
+    /// If empty, any receiver is allowed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_receivers: Vec<String>,
+}
+
+impl IbcRoute {
+    fn allows(&self, channel: &str, receiver: &str) -> bool {
+        self.channel == channel
+            && (self.allowed_receivers.is_empty()
+                || self.allowed_receivers.iter().any(|r| r == receiver))
+    }
+}
+
+/// Computes the value a transaction plan sends out of the account of `fvk`, by asset.
+///
+/// This counts outputs to addresses outside the account, swaps whose outputs
+/// are claimed outside the account, the reserves of opened liquidity positions,
+/// ICS-20 withdrawals, and DAO deposits.
+/// Fees aren't counted, and are left to the [`AuthPolicy::MaxFee`] policy.
+pub fn outflows(plan: &TransactionPlan, fvk: &FullViewingKey) -> BTreeMap<asset::Id, Amount> {
+    let is_external = |address: &Address| fvk.address_index(address).is_none();
+
+    let mut values = Vec::new();
+    for action in &plan.actions {
+        match action {
+            ActionPlan::Output(output) if is_external(&output.dest_address) => {
+                values.push(output.value);
+            }
+            ActionPlan::Swap(swap) if is_external(&swap.swap_plaintext.claim_address) => {
+                let plaintext = &swap.swap_plaintext;
+                values.push(Value {
+                    amount: plaintext.delta_1_i,
+                    asset_id: plaintext.trading_pair.asset_1(),
+                });
+                values.push(Value {
+                    amount: plaintext.delta_2_i,
+                    asset_id: plaintext.trading_pair.asset_2(),
+                });
+            }
+            // The reserves of a position can be traded away by anyone, so they count
+            // as leaving the account even though the position is owned by it.
+            ActionPlan::PositionOpen(position_open) => {
+                let position = &position_open.position;
+                values.push(Value {
+                    amount: position.reserves.r1,
+                    asset_id: position.phi.pair.asset_1(),
+                });
+                values.push(Value {
+                    amount: position.reserves.r2,
+                    asset_id: position.phi.pair.asset_2(),
+                });
+            }
+            ActionPlan::Withdrawal(withdrawal) => values.push(withdrawal.value()),
+            ActionPlan::DaoDeposit(deposit) => values.push(deposit.value),
+            _ => {}
+        }
+    }
+
+    let mut outflows = BTreeMap::new();
+    for value in values {
+        if value.amount != Amount::zero() {
+            *outflows.entry(value.asset_id).or_insert_with(Amount::zero) += value.amount;
+        }
+    }
+    outflows
+}
+
+impl AuthPolicy {
+    /// The longest window of time over which this policy needs the spend history.
+    pub fn history_window(&self) -> Duration {
+        match self {
+            AuthPolicy::RollingSpendLimit { window_seconds, .. } => {
+                Duration::from_secs(*window_seconds)
+            }
+            AuthPolicy::AllOf { policies } | AuthPolicy::AnyOf { policies } => policies
+                .iter()
+                .map(AuthPolicy::history_window)
+                .max()
+                .unwrap_or_default(),
+            _ => Duration::ZERO,
+        }
+    }
 }
 
 /// A set of pre-authorization policies.
@@ -126,7 +350,7 @@ mod ed25519_vec_base64 {
 }
 
 impl Policy for AuthPolicy {
-    fn check(&self, request: &AuthorizeRequest) -> anyhow::Result<()> {
+    fn check(&self, request: &AuthorizeRequest, context: &PolicyContext) -> anyhow::Result<()> {
         let plan = &request.plan;
         match self {
             AuthPolicy::DestinationAllowList {
@@ -157,13 +381,166 @@ impl Policy for AuthPolicy {
                 }
                 Ok(())
             }
-            AuthPolicy::PreAuthorization(policy) => policy.check(request),
+            AuthPolicy::PreAuthorization(policy) => policy.check(request, context),
+            AuthPolicy::AllowedActions { allowed_actions } => {
+                for action in &plan.actions {
+                    let kind = ActionKind::from(action);
+                    if !allowed_actions.contains(&kind) {
+                        anyhow::bail!(
+                            "{:?} actions are not allowed by AllowedActions policy",
+                            kind
+                        );
+                    }
+                }
+                Ok(())
+            }
+            AuthPolicy::SpendLimit { asset_id, amount } => {
+                let spent = outflows(plan, context.fvk)
+                    .remove(asset_id)
+                    .unwrap_or_default();
+                if spent > Amount::from(*amount) {
+                    anyhow::bail!(
+                        "transaction sends {} of {}, over the limit of {}",
+                        spent,
+                        asset_id,
+                        amount
+                    );
+                }
+                Ok(())
+            }
+            AuthPolicy::RollingSpendLimit {
+                asset_id,
+                amount,
+                window_seconds,
+            } => {
+                let window = Duration::from_secs(*window_seconds);
+                let since = context
+                    .now
+                    .checked_sub(window)
+                    .unwrap_or(SystemTime::UNIX_EPOCH);
+                let spent = context.history.spent_since(asset_id, since)
+                    + outflows(plan, context.fvk)
+                        .remove(asset_id)
+                        .unwrap_or_default();
+                if spent > Amount::from(*amount) {
+                    anyhow::bail!(
+                        "transaction would bring the amount of {} sent in the last {}s to {}, over the limit of {}",
+                        asset_id,
+                        window_seconds,
+                        spent,
+                        amount
+                    );
+                }
+                Ok(())
+            }
+            AuthPolicy::MaxFee { amount, asset_id } => {
+                let fee_asset_id = asset_id.unwrap_or(*STAKING_TOKEN_ASSET_ID);
+                if plan.fee.asset_id() != fee_asset_id {
+                    anyhow::bail!(
+                        "fee paid in {}, but MaxFee policy only allows {}",
+                        plan.fee.asset_id(),
+                        fee_asset_id
+                    );
+                }
+                if plan.fee.amount() > Amount::from(*amount) {
+                    anyhow::bail!(
+                        "fee of {} is over the limit of {}",
+                        plan.fee.amount(),
+                        amount
+                    );
+                }
+                Ok(())
+            }
+            AuthPolicy::IbcWithdrawalAllowList { allowed_routes } => {
+                for action in &plan.actions {
+                    if let ActionPlan::Withdrawal(withdrawal) = action {
+                        let channel = withdrawal.source_channel.to_string();
+                        let receiver = &withdrawal.destination_chain_address;
+                        if !allowed_routes
+                            .iter()
+                            .any(|route| route.allows(&channel, receiver))
+                        {
+                            anyhow::bail!(
+                                "withdrawal to {} over {} not in allow list",
+                                receiver,
+                                channel
+                            );
+                        }
+                    }
+                }
+                Ok(())
+            }
+            AuthPolicy::AllOf { policies } => {
+                for policy in policies {
+                    policy.check(request, context)?;
+                }
+                Ok(())
+            }
+            AuthPolicy::AnyOf { policies } => {
+                let mut errors = Vec::new();
+                for policy in policies {
+                    match policy.check(request, context) {
+                        Ok(()) => return Ok(()),
+                        Err(e) => errors.push(e.to_string()),
+                    }
+                }
+                anyhow::bail!(
+                    "no policy in AnyOf allowed the transaction: {}",
+                    errors.join("; ")
+                )
+            }
+        }
+    }
+
+    fn check_payment_request(
+        &self,
+        request: &AuthorizePaymentRequest,
+        context: &PolicyContext,
+    ) -> anyhow::Result<()> {
+        match self {
+            // Signing an invoice sends nothing out of the account, pays no fee, and adds no
+            // actions to any transaction, so it is within any of these limits.
+            AuthPolicy::DestinationAllowList { .. }
+            | AuthPolicy::AllowedActions { .. }
+            | AuthPolicy::SpendLimit { .. }
+            | AuthPolicy::RollingSpendLimit { .. }
+            | AuthPolicy::MaxFee { .. }
+            | AuthPolicy::IbcWithdrawalAllowList { .. } => Ok(()),
+            AuthPolicy::OnlyIbcRelay => {
+                anyhow::bail!("payment requests are not allowed by OnlyRelay policy")
+            }
+            AuthPolicy::PreAuthorization(policy) => policy.check_payment_request(request, context),
+            AuthPolicy::AllOf { policies } => {
+                for policy in policies {
+                    policy.check_payment_request(request, context)?;
+                }
+                Ok(())
+            }
+            AuthPolicy::AnyOf { policies } => {
+                let mut errors = Vec::new();
+                for policy in policies {
+                    match policy.check_payment_request(request, context) {
+                        Ok(()) => return Ok(()),
+                        Err(e) => errors.push(e.to_string()),
+                    }
+                }
+                anyhow::bail!(
+                    "no policy in AnyOf allowed the payment request: {}",
+                    errors.join("; ")
+                )
+            }
         }
     }
 }
 
-impl Policy for PreAuthorizationPolicy {
-    fn check(&self, request: &AuthorizeRequest) -> anyhow::Result<()> {
+impl PreAuthorizationPolicy {
+    /// Checks that enough of the allowed signers pre-authorized a request, using `verify` to check
+    /// each of their signatures over it.
+    fn check_pre_authorizations(
+        &self,
+        pre_authorizations: &[PreAuthorization],
+        verify: impl Fn(&pre_auth::Ed25519) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
         match self {
             PreAuthorizationPolicy::Ed25519 {
                 required_signatures,
@@ -171,8 +548,7 @@ impl Policy for PreAuthorizationPolicy {
             } => {
                 #[allow(clippy::unnecessary_filter_map)]
                 let ed25519_pre_auths =
-                    request
-                        .pre_authorizations
+                    pre_authorizations
                         .iter()
                         .filter_map(|pre_auth| match pre_auth {
                             PreAuthorization::Ed25519(pre_auth) => Some(pre_auth),
@@ -186,7 +562,7 @@ impl Policy for PreAuthorizationPolicy {
                     // Remove the signer from the allowed signers set, so that
                     // each signer can only submit one pre-authorization.
                     if let Some(signer) = allowed_signers.take(&pre_auth.vk) {
-                        pre_auth.verify_plan(&request.plan)?;
+                        verify(pre_auth)?;
                         seen_signers.insert(signer);
                     }
                 }
@@ -203,3 +579,401 @@ impl Policy for PreAuthorizationPolicy {
         }
     }
 }
+
+impl Policy for PreAuthorizationPolicy {
+    fn check(&self, request: &AuthorizeRequest, _context: &PolicyContext) -> anyhow::Result<()> {
+        self.check_pre_authorizations(&request.pre_authorizations, |pre_auth| {
+            pre_auth.verify_plan(&request.plan)
+        })
+    }
+
+    fn check_payment_request(
+        &self,
+        request: &AuthorizePaymentRequest,
+        _context: &PolicyContext,
+    ) -> anyhow::Result<()> {
+        self.check_pre_authorizations(&request.pre_authorizations, |pre_auth| {
+            pre_auth.verify_payment_request(request)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use ibc_types::core::{channel::ChannelId, client::Height as IbcHeight};
+    use penumbra_asset::STAKING_TOKEN_DENOM;
+    use penumbra_dex::{
+        lp::{position::Position, Reserves},
+        DirectedTradingPair, PositionOpen,
+    };
+    use penumbra_fee::Fee;
+    use penumbra_keys::{
+        keys::{Bip44Path, SeedPhrase, SpendKey},
+        test_keys,
+    };
+    use penumbra_shielded_pool::{Ics20Withdrawal, OutputPlan};
+    use penumbra_transaction::PaymentRequest;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn external_address() -> Address {
+        let spend_key =
+            SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(OsRng), &Bip44Path::new(0));
+        spend_key.full_viewing_key().payment_address(0.into()).0
+    }
+
+    fn staking_tokens(amount: u64) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+    }
+
+    fn output(amount: u64, dest_address: Address) -> ActionPlan {
+        ActionPlan::Output(OutputPlan::new(
+            &mut OsRng,
+            staking_tokens(amount),
+            dest_address,
+        ))
+    }
+
+    fn withdrawal(channel: &str, receiver: &str) -> ActionPlan {
+        ActionPlan::Withdrawal(Ics20Withdrawal {
+            amount: 10u64.into(),
+            denom: STAKING_TOKEN_DENOM.clone(),
+            destination_chain_address: receiver.to_string(),
+            return_address: *test_keys::ADDRESS_0,
+            timeout_height: IbcHeight::new(1, 1000).unwrap(),
+            timeout_time: 0,
+            source_channel: ChannelId::from_str(channel).unwrap(),
+            memo: String::new(),
+        })
+    }
+
+    fn request(actions: Vec<ActionPlan>) -> AuthorizeRequest {
+        AuthorizeRequest {
+            plan: TransactionPlan {
+                actions,
+                ..Default::default()
+            },
+            pre_authorizations: Vec::new(),
+        }
+    }
+
+    fn check(policy: &AuthPolicy, request: &AuthorizeRequest) -> anyhow::Result<()> {
+        let history = SpendHistory::default();
+        let context = PolicyContext {
+            fvk: &test_keys::FULL_VIEWING_KEY,
+            history: &history,
+            now: SystemTime::now(),
+        };
+        policy.check(request, &context)
+    }
+
+    #[test]
+    fn outflows_ignore_change() {
+        let req = request(vec![
+            output(5, external_address()),
+            output(100, *test_keys::ADDRESS_1),
+            withdrawal("channel-0", "osmo1receiver"),
+        ]);
+        let outflows = outflows(&req.plan, &test_keys::FULL_VIEWING_KEY);
+        assert_eq!(outflows.len(), 1);
+        assert_eq!(outflows[&*STAKING_TOKEN_ASSET_ID], 15u64.into());
+    }
+
+    #[test]
+    fn outflows_count_position_reserves() {
+        let gm = asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .unwrap()
+            .id();
+        let position = Position::new(
+            OsRng,
+            DirectedTradingPair::new(*STAKING_TOKEN_ASSET_ID, gm),
+            0,
+            1u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: 20u64.into(),
+                r2: 30u64.into(),
+            },
+        );
+        let req = request(vec![ActionPlan::PositionOpen(PositionOpen { position })]);
+
+        let outflows = outflows(&req.plan, &test_keys::FULL_VIEWING_KEY);
+        assert_eq!(outflows.len(), 2);
+        assert_eq!(outflows[&*STAKING_TOKEN_ASSET_ID], 20u64.into());
+        assert_eq!(outflows[&gm], 30u64.into());
+    }
+
+    #[test]
+    fn spend_limit() {
+        let policy = AuthPolicy::SpendLimit {
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+            amount: 10,
+        };
+        let external = external_address();
+
+        assert!(check(&policy, &request(vec![output(10, external)])).is_ok());
+        assert!(check(&policy, &request(vec![output(11, external)])).is_err());
+        assert!(check(
+            &policy,
+            &request(vec![output(6, external), output(5, external)])
+        )
+        .is_err());
+        // Sending to ourselves isn't limited.
+        assert!(check(&policy, &request(vec![output(1000, *test_keys::ADDRESS_0)])).is_ok());
+    }
+
+    #[test]
+    fn rolling_spend_limit() {
+        let policy = AuthPolicy::RollingSpendLimit {
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+            amount: 10,
+            window_seconds: 3600,
+        };
+        let req = request(vec![output(4, external_address())]);
+        let fvk = &test_keys::FULL_VIEWING_KEY;
+
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let mut history = SpendHistory::default();
+        for minutes in [0, 10] {
+            let now = start + Duration::from_secs(minutes * 60);
+            let context = PolicyContext {
+                fvk,
+                history: &history,
+                now,
+            };
+            policy.check(&req, &context).unwrap();
+            history.record(now, &outflows(&req.plan, fvk));
+        }
+
+        // A third transaction within the hour goes over the limit...
+        let context = PolicyContext {
+            fvk,
+            history: &history,
+            now: start + Duration::from_secs(30 * 60),
+        };
+        assert!(policy.check(&req, &context).is_err());
+
+        // ...but is allowed once the first one is out of the window.
+        let now = start + Duration::from_secs(61 * 60);
+        history.prune(now - policy.history_window());
+        let context = PolicyContext {
+            fvk,
+            history: &history,
+            now,
+        };
+        assert!(policy.check(&req, &context).is_ok());
+    }
+
+    #[test]
+    fn spend_history_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "penumbra-custody-spend-history-{}.json",
+            std::process::id()
+        ));
+        let now = SystemTime::now();
+
+        assert_eq!(SpendHistory::load(&path).unwrap(), SpendHistory::default());
+
+        let mut history = SpendHistory::default();
+        history.record(
+            now,
+            &BTreeMap::from([(*STAKING_TOKEN_ASSET_ID, 7u64.into())]),
+        );
+        history.save(&path).unwrap();
+
+        let loaded = SpendHistory::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, history);
+        assert_eq!(
+            loaded.spent_since(&STAKING_TOKEN_ASSET_ID, now - Duration::from_secs(1)),
+            7u64.into()
+        );
+    }
+
+    #[test]
+    fn allowed_actions() {
+        let policy = AuthPolicy::AllowedActions {
+            allowed_actions: vec![ActionKind::Spend, ActionKind::Output],
+        };
+        assert!(check(&policy, &request(vec![output(1, external_address())])).is_ok());
+        assert!(check(
+            &policy,
+            &request(vec![withdrawal("channel-0", "osmo1receiver")])
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn max_fee() {
+        let policy = AuthPolicy::MaxFee {
+            amount: 100,
+            asset_id: None,
+        };
+        let mut req = request(vec![]);
+
+        req.plan.fee = Fee::from_staking_token_amount(100u64.into());
+        assert!(check(&policy, &req).is_ok());
+        req.plan.fee = Fee::from_staking_token_amount(101u64.into());
+        assert!(check(&policy, &req).is_err());
+
+        let other_asset = asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .unwrap()
+            .id();
+        req.plan.fee = Fee(Value {
+            amount: 1u64.into(),
+            asset_id: other_asset,
+        });
+        assert!(check(&policy, &req).is_err());
+    }
+
+    #[test]
+    fn ibc_withdrawal_allow_list() {
+        let policy = AuthPolicy::IbcWithdrawalAllowList {
+            allowed_routes: vec![
+                IbcRoute {
+                    channel: "channel-0".to_string(),
+                    allowed_receivers: vec!["osmo1receiver".to_string()],
+                },
+                IbcRoute {
+                    channel: "channel-1".to_string(),
+                    allowed_receivers: vec![],
+                },
+            ],
+        };
+        let allowed =
+            |channel, receiver| check(&policy, &request(vec![withdrawal(channel, receiver)]));
+
+        assert!(allowed("channel-0", "osmo1receiver").is_ok());
+        assert!(allowed("channel-0", "osmo1other").is_err());
+        assert!(allowed("channel-1", "cosmos1anyone").is_ok());
+        assert!(allowed("channel-2", "osmo1receiver").is_err());
+    }
+
+    #[test]
+    fn combinators() {
+        let small_fee = AuthPolicy::MaxFee {
+            amount: 10,
+            asset_id: None,
+        };
+        let only_outputs = AuthPolicy::AllowedActions {
+            allowed_actions: vec![ActionKind::Output],
+        };
+        let mut req = request(vec![output(1, external_address())]);
+        req.plan.fee = Fee::from_staking_token_amount(50u64.into());
+
+        let all_of = AuthPolicy::AllOf {
+            policies: vec![small_fee.clone(), only_outputs.clone()],
+        };
+        let any_of = AuthPolicy::AnyOf {
+            policies: vec![small_fee, only_outputs],
+        };
+        assert!(check(&all_of, &req).is_err());
+        assert!(check(&any_of, &req).is_ok());
+        assert!(check(&AuthPolicy::AnyOf { policies: vec![] }, &req).is_err());
+        assert!(check(&AuthPolicy::AllOf { policies: vec![] }, &req).is_ok());
+    }
+
+    #[test]
+    fn payment_requests() {
+        let pak = ed25519_consensus::SigningKey::new(OsRng);
+        let pre_auth_policy = AuthPolicy::PreAuthorization(PreAuthorizationPolicy::Ed25519 {
+            required_signatures: 1,
+            allowed_signers: vec![pak.verification_key()],
+        });
+        let mut request = AuthorizePaymentRequest {
+            payment_request: PaymentRequest::new(
+                *test_keys::ADDRESS_0,
+                vec![staking_tokens(10)],
+                String::new(),
+                None,
+            )
+            .unwrap(),
+            invoice_id: "invoice-1".to_string(),
+            pre_authorizations: Vec::new(),
+        };
+        let check = |policy: &AuthPolicy, request: &AuthorizePaymentRequest| {
+            let history = SpendHistory::default();
+            let context = PolicyContext {
+                fvk: &test_keys::FULL_VIEWING_KEY,
+                history: &history,
+                now: SystemTime::now(),
+            };
+            policy.check_payment_request(request, &context)
+        };
+
+        // Signing an invoice spends nothing, but relayers shouldn't sign invoices.
+        let spend_limit = AuthPolicy::SpendLimit {
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+            amount: 0,
+        };
+        assert!(check(&spend_limit, &request).is_ok());
+        assert!(check(&AuthPolicy::OnlyIbcRelay, &request).is_err());
+
+        // Pre-authorizations are required just as for transactions.
+        assert!(check(&pre_auth_policy, &request).is_err());
+        let sig = pak.sign(&request.signed_bytes());
+        request.pre_authorizations = vec![PreAuthorization::Ed25519(pre_auth::Ed25519 {
+            vk: pak.verification_key(),
+            sig,
+        })];
+        assert!(check(&pre_auth_policy, &request).is_ok());
+        assert!(check(
+            &AuthPolicy::AllOf {
+                policies: vec![spend_limit, pre_auth_policy.clone()]
+            },
+            &request
+        )
+        .is_ok());
+
+        // A pre-authorization only covers the invoice id it signed.
+        request.invoice_id = "invoice-2".to_string();
+        assert!(check(&pre_auth_policy, &request).is_err());
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        #[derive(Serialize, Deserialize)]
+        struct Wrapper {
+            auth_policy: Vec<AuthPolicy>,
+        }
+
+        let wrapper = Wrapper {
+            auth_policy: vec![
+                AuthPolicy::RollingSpendLimit {
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                    amount: 1_000_000,
+                    window_seconds: 86400,
+                },
+                AuthPolicy::AnyOf {
+                    policies: vec![
+                        AuthPolicy::MaxFee {
+                            amount: 100,
+                            asset_id: None,
+                        },
+                        AuthPolicy::AllowedActions {
+                            allowed_actions: vec![ActionKind::Spend, ActionKind::Withdrawal],
+                        },
+                    ],
+                },
+                AuthPolicy::IbcWithdrawalAllowList {
+                    allowed_routes: vec![IbcRoute {
+                        channel: "channel-0".to_string(),
+                        allowed_receivers: vec![],
+                    }],
+                },
+            ],
+        };
+        let encoded = toml::to_string_pretty(&wrapper).unwrap();
+        println!("{encoded}");
+        let decoded: Wrapper = toml::from_str(&encoded).unwrap();
+        assert_eq!(decoded.auth_policy, wrapper.auth_policy);
+    }
+}
@@ -0,0 +1,93 @@
+use std::{
+    collections::BTreeMap,
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::Context;
+use penumbra_asset::asset;
+use penumbra_num::Amount;
+use serde::{Deserialize, Serialize};
+use serde_with::DisplayFromStr;
+
+/// A record of the value sent out of an account by authorized transactions,
+/// used by policies limiting spending over a window of time.
+///
+/// The history can be saved to and loaded from a file, so that limits keep
+/// applying across restarts of the custodian.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct SpendHistory {
+    #[serde(default)]
+    entries: Vec<SpendEntry>,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+struct SpendEntry {
+    /// Seconds since the Unix epoch at which the transaction was authorized.
+    timestamp: u64,
+    #[serde_as(as = "DisplayFromStr")]
+    asset_id: asset::Id,
+    #[serde_as(as = "DisplayFromStr")]
+    amount: u128,
+}
+
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_secs()
+}
+
+impl SpendHistory {
+    /// Loads the history from `path`, or returns an empty history if the file doesn't exist.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read spend history from {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("failed to parse spend history in {}", path.display()))
+    }
+
+    /// Saves the history to `path`, replacing the previous contents atomically.
+    pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        let tmp_path = path.with_extension("tmp");
+        std::fs::write(&tmp_path, serde_json::to_vec(self)?)
+            .with_context(|| format!("failed to write spend history to {}", tmp_path.display()))?;
+        std::fs::rename(&tmp_path, path)
+            .with_context(|| format!("failed to write spend history to {}", path.display()))?;
+        Ok(())
+    }
+
+    /// The total amount of `asset_id` sent at or after `since`.
+    pub fn spent_since(&self, asset_id: &asset::Id, since: SystemTime) -> Amount {
+        let since = unix_seconds(since);
+        self.entries
+            .iter()
+            .filter(|entry| entry.timestamp >= since && &entry.asset_id == asset_id)
+            .fold(Amount::zero(), |total, entry| {
+                total + Amount::from(entry.amount)
+            })
+    }
+
+    /// Records the value sent by a transaction authorized at `now`.
+    pub fn record(&mut self, now: SystemTime, outflows: &BTreeMap<asset::Id, Amount>) {
+        let timestamp = unix_seconds(now);
+        for (asset_id, amount) in outflows {
+            self.entries.push(SpendEntry {
+                timestamp,
+                asset_id: *asset_id,
+                amount: amount.value(),
+            });
+        }
+    }
+
+    /// Forgets the value sent before `before`.
+    pub fn prune(&mut self, before: SystemTime) {
+        let before = unix_seconds(before);
+        self.entries.retain(|entry| entry.timestamp >= before);
+    }
+}
@@ -2,6 +2,8 @@ use penumbra_proto::{custody::v1alpha1 as pb, DomainType};
 use penumbra_transaction::plan::TransactionPlan;
 use serde::{Deserialize, Serialize};
 
+use crate::AuthorizePaymentRequest;
+
 /// A pre-authorization packet.  This allows a custodian to delegate (partial)
 /// signing authority to other authorization mechanisms.  Details of how a
 /// custodian manages those keys are out-of-scope for the custody protocol and
@@ -32,6 +34,13 @@ impl Ed25519 {
         let plan_bytes = plan.encode_to_vec();
         self.vk.verify(&self.sig, &plan_bytes).map_err(Into::into)
     }
+
+    /// Verifies the provided request to sign the invoice id of a payment request.
+    pub fn verify_payment_request(&self, request: &AuthorizePaymentRequest) -> anyhow::Result<()> {
+        self.vk
+            .verify(&self.sig, &request.signed_bytes())
+            .map_err(Into::into)
+    }
 }
 
 impl DomainType for PreAuthorization {
@@ -1,5 +1,5 @@
-use penumbra_proto::{custody::v1alpha1 as pb, DomainType};
-use penumbra_transaction::plan::TransactionPlan;
+use penumbra_proto::{custody::v1alpha1 as pb, DomainType, Message};
+use penumbra_transaction::{plan::TransactionPlan, PaymentRequest};
 
 use crate::PreAuthorization;
 
@@ -45,3 +45,60 @@ impl From<AuthorizeRequest> for pb::AuthorizeRequest {
         }
     }
 }
+
+/// A request to sign the invoice id of a payment request, submitted to a custody service for
+/// approval.
+#[derive(Debug, Clone)]
+pub struct AuthorizePaymentRequest {
+    /// The payment request to sign.
+    pub payment_request: PaymentRequest,
+    /// The invoice id to sign.
+    pub invoice_id: String,
+    /// Optionally, pre-authorization data, if required by the custodian.
+    pub pre_authorizations: Vec<PreAuthorization>,
+}
+
+impl AuthorizePaymentRequest {
+    /// The bytes that pre-authorizations of the request sign.
+    pub fn signed_bytes(&self) -> Vec<u8> {
+        let mut unsigned = self.to_proto();
+        unsigned.pre_authorizations.clear();
+        unsigned.encode_to_vec()
+    }
+}
+
+impl DomainType for AuthorizePaymentRequest {
+    type Proto = pb::AuthorizePaymentRequestRequest;
+}
+
+impl TryFrom<pb::AuthorizePaymentRequestRequest> for AuthorizePaymentRequest {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::AuthorizePaymentRequestRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            payment_request: value
+                .payment_request
+                .ok_or_else(|| anyhow::anyhow!("missing payment request"))?
+                .try_into()?,
+            invoice_id: value.invoice_id,
+            pre_authorizations: value
+                .pre_authorizations
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl From<AuthorizePaymentRequest> for pb::AuthorizePaymentRequestRequest {
+    fn from(value: AuthorizePaymentRequest) -> pb::AuthorizePaymentRequestRequest {
+        Self {
+            payment_request: Some(value.payment_request.into()),
+            invoice_id: value.invoice_id,
+            pre_authorizations: value
+                .pre_authorizations
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
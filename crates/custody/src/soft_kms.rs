@@ -1,39 +1,214 @@
 //! A basic software key management system that stores keys in memory but
 //! presents as an asynchronous signer.
 
+use std::{
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
+
+use penumbra_keys::keys::SpendKey;
 use penumbra_proto::custody::v1alpha1::{self as pb, AuthorizeResponse};
-use penumbra_transaction::AuthorizationData;
+use penumbra_transaction::{AuthorizationData, PaymentRequest};
 use rand_core::OsRng;
+use tokio::sync::Mutex;
 use tonic::{async_trait, Request, Response, Status};
+use zeroize::Zeroizing;
 
-use crate::{policy::Policy, AuthorizeRequest};
+use crate::{
+    policy::{self, Policy, PolicyContext, SpendHistory},
+    AuthorizePaymentRequest, AuthorizeRequest,
+};
 
 mod config;
+mod keystore;
+mod passphrase;
 
 pub use config::Config;
+pub use keystore::{EncryptedSpendKey, KdfParams, SpendKeyStore};
+pub use passphrase::{encrypt_spend_key, prompt_new_passphrase, prompt_passphrase};
+
+/// A source of the passphrase used to unlock an encrypted spend key, such as a
+/// terminal prompt.
+pub type PassphraseSource = Arc<dyn Fn() -> anyhow::Result<Zeroizing<String>> + Send + Sync>;
+
+/// The conventional name of the file keeping the spend history of a [`SoftKms`],
+/// relative to the home directory of the binary running it.
+pub const SPEND_HISTORY_FILE_NAME: &str = "spend-history.json";
 
 /// A basic software key management system that stores keys in memory but
 /// presents as an asynchronous signer.
+///
+/// If the spend key is encrypted, it's unlocked on demand when a request needs
+/// it, using the configured [`PassphraseSource`], and kept unlocked in memory
+/// until the unlock timeout (if any) expires.
+///
+/// Policies limiting spending over time check requests against the history of
+/// previously authorized transactions, which is only kept in memory unless a
+/// file is configured with [`SoftKms::with_spend_history`]. Transactions are
+/// recorded when they're signed, not when they're broadcast: the custodian never
+/// learns whether or when a signed transaction is broadcast, and the signature is
+/// what lets its funds be spent, so the limits bound what's authorized.
 pub struct SoftKms {
     config: Config,
+    passphrase: Option<PassphraseSource>,
+    unlock_timeout: Option<Duration>,
+    unlocked: Arc<Mutex<Option<(SpendKey, Instant)>>>,
+    history: Mutex<SpendHistory>,
+    history_path: Option<PathBuf>,
 }
 
 impl SoftKms {
     /// Initialize with the given [`Config`].
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self {
+            config,
+            passphrase: None,
+            unlock_timeout: None,
+            unlocked: Arc::new(Mutex::new(None)),
+            history: Mutex::new(SpendHistory::default()),
+            history_path: None,
+        }
+    }
+
+    /// Keep the history of authorized spending in the file at `path`, so that
+    /// spending limits keep applying across restarts.
+    pub fn with_spend_history(mut self, path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+        self.history = Mutex::new(SpendHistory::load(&path)?);
+        self.history_path = Some(path);
+        Ok(self)
+    }
+
+    /// Use the given [`PassphraseSource`] to unlock an encrypted spend key.
+    pub fn with_passphrase(mut self, passphrase: PassphraseSource) -> Self {
+        self.passphrase = Some(passphrase);
+        self
+    }
+
+    /// Lock an encrypted spend key again once `timeout` has elapsed since it was unlocked.
+    pub fn with_unlock_timeout(mut self, timeout: Duration) -> Self {
+        self.unlock_timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the spend key, unlocking it if needed.
+    async fn spend_key(&self) -> anyhow::Result<SpendKey> {
+        if let SpendKeyStore::Plaintext(spend_key) = &self.config.spend_key {
+            return Ok(spend_key.clone());
+        }
+
+        let mut unlocked = self.unlocked.lock().await;
+        if let Some((spend_key, unlocked_at)) = unlocked.as_ref() {
+            if self
+                .unlock_timeout
+                .map_or(true, |timeout| unlocked_at.elapsed() < timeout)
+            {
+                return Ok(spend_key.clone());
+            }
+        }
+        *unlocked = None;
+
+        let passphrase = self.passphrase.clone().ok_or_else(|| {
+            anyhow::anyhow!("spend key is encrypted, but no passphrase source is configured")
+        })?;
+        // Unlocking may wait on a prompt and runs a memory-hard KDF, so run it on
+        // the blocking thread pool rather than stalling the tasks of the runtime.
+        let spend_key_store = self.config.spend_key.clone();
+        let spend_key =
+            tokio::task::spawn_blocking(move || spend_key_store.unlock(|| passphrase())).await??;
+        let unlocked_at = Instant::now();
+        *unlocked = Some((spend_key.clone(), unlocked_at));
+        tracing::debug!("unlocked spend key");
+
+        // Drop the unlocked key from memory once the timeout expires, rather than
+        // waiting for the next request to notice it.
+        if let Some(timeout) = self.unlock_timeout {
+            let unlocked = self.unlocked.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(timeout).await;
+                let mut unlocked = unlocked.lock().await;
+                if matches!(unlocked.as_ref(), Some((_, at)) if *at == unlocked_at) {
+                    *unlocked = None;
+                    tracing::debug!("unlock timeout expired, locked spend key");
+                }
+            });
+        }
+
+        Ok(spend_key)
+    }
+
+    /// Sign the invoice id of the requested [`PaymentRequest`], which must be to one of our
+    /// addresses, if the configured policies allow it.
+    #[tracing::instrument(skip(self, request), name = "softhsm_sign_payment_request")]
+    pub async fn sign_payment_request(
+        &self,
+        request: &AuthorizePaymentRequest,
+    ) -> anyhow::Result<PaymentRequest> {
+        let fvk = self.config.spend_key.full_viewing_key();
+        anyhow::ensure!(
+            fvk.address_index(&request.payment_request.address)
+                .is_some(),
+            "payment request is not to an address controlled by this custodian"
+        );
+
+        // Signing an invoice sends nothing, so it isn't recorded in the history.
+        let history = self.history.lock().await;
+        let context = PolicyContext {
+            fvk,
+            history: &history,
+            now: SystemTime::now(),
+        };
+        for policy in &self.config.auth_policy {
+            policy.check_payment_request(request, &context)?;
+        }
+        drop(history);
+
+        let spend_key = self.spend_key().await?;
+        Ok(request.payment_request.clone().sign_invoice(
+            OsRng,
+            request.invoice_id.clone(),
+            &spend_key,
+        ))
     }
 
     /// Attempt to authorize the requested [`TransactionPlan`](penumbra_transaction::plan::TransactionPlan).
     #[tracing::instrument(skip(self, request), name = "softhsm_sign")]
-    pub fn sign(&self, request: &AuthorizeRequest) -> anyhow::Result<AuthorizationData> {
+    pub async fn sign(&self, request: &AuthorizeRequest) -> anyhow::Result<AuthorizationData> {
         tracing::debug!(?request.plan);
 
+        // Hold the history lock until the spending is recorded, so that
+        // concurrent requests can't both fit under the same limit. The spending
+        // is recorded as soon as it's authorized, whether or not it's broadcast.
+        let mut history = self.history.lock().await;
+        let fvk = self.config.spend_key.full_viewing_key();
+        let now = SystemTime::now();
+        let context = PolicyContext {
+            fvk,
+            history: &history,
+            now,
+        };
         for policy in &self.config.auth_policy {
-            policy.check(request)?;
+            policy.check(request, &context)?;
+        }
+
+        let spend_key = self.spend_key().await?;
+        let authorization_data = request.plan.authorize(OsRng, &spend_key);
+
+        let window = self
+            .config
+            .auth_policy
+            .iter()
+            .map(|policy| policy.history_window())
+            .max()
+            .unwrap_or_default();
+        history.record(now, &policy::outflows(&request.plan, fvk));
+        history.prune(now.checked_sub(window).unwrap_or(SystemTime::UNIX_EPOCH));
+        if let Some(path) = &self.history_path {
+            history.save(path)?;
         }
 
-        Ok(request.plan.authorize(OsRng, &self.config.spend_key))
+        Ok(authorization_data)
     }
 }
 
@@ -50,6 +225,7 @@ impl pb::custody_protocol_service_server::CustodyProtocolService for SoftKms {
 
         let authorization_data = self
             .sign(&request)
+            .await
             .map_err(|e| Status::unauthenticated(format!("{e:#}")))?;
 
         let authorization_response = AuthorizeResponse {
@@ -95,4 +271,23 @@ impl pb::custody_protocol_service_server::CustodyProtocolService for SoftKms {
             address: Some(address.into()),
         }))
     }
+
+    async fn authorize_payment_request(
+        &self,
+        request: Request<pb::AuthorizePaymentRequestRequest>,
+    ) -> Result<Response<pb::AuthorizePaymentRequestResponse>, Status> {
+        let request = request
+            .into_inner()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(format!("{e:#}")))?;
+
+        let payment_request = self
+            .sign_payment_request(&request)
+            .await
+            .map_err(|e| Status::unauthenticated(format!("{e:#}")))?;
+
+        Ok(Response::new(pb::AuthorizePaymentRequestResponse {
+            payment_request: Some(payment_request.into()),
+        }))
+    }
 }
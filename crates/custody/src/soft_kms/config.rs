@@ -1,23 +1,28 @@
 use crate::policy::AuthPolicy;
 use penumbra_keys::keys::SpendKey;
 use serde::{Deserialize, Serialize};
-use serde_with::DisplayFromStr;
+
+use super::SpendKeyStore;
 
 /// Configuration data for the [`SoftKms`](super::SoftKms).
 ///
 /// Only the `spend_key` field is required; leaving the other fields
 /// empty/default provides blind signing.
-#[serde_as]
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct Config {
-    #[serde_as(as = "DisplayFromStr")]
-    pub spend_key: SpendKey,
+    pub spend_key: SpendKeyStore,
     #[serde(default, skip_serializing_if = "is_default")]
     pub auth_policy: Vec<AuthPolicy>,
 }
 
 impl From<SpendKey> for Config {
     fn from(spend_key: SpendKey) -> Self {
+        SpendKeyStore::from(spend_key).into()
+    }
+}
+
+impl From<SpendKeyStore> for Config {
+    fn from(spend_key: SpendKeyStore) -> Self {
         Self {
             spend_key,
             auth_policy: Default::default(),
@@ -69,7 +74,7 @@ mod tests {
         ];
 
         let example = Config {
-            spend_key: spend_key.clone(),
+            spend_key: spend_key.clone().into(),
             auth_policy,
         };
 
@@ -0,0 +1,262 @@
+use anyhow::{anyhow, Result};
+use chacha20poly1305::{
+    aead::{Aead, NewAead, Payload},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use penumbra_keys::{
+    keys::{SpendKey, SpendKeyBytes},
+    FullViewingKey,
+};
+use penumbra_proto::DomainType;
+use rand_core::CryptoRngCore;
+use serde::{Deserialize, Serialize};
+use serde_with::{hex::Hex, DisplayFromStr};
+use zeroize::Zeroizing;
+
+/// The storage format of the spend key used by the [`SoftKms`](super::SoftKms).
+///
+/// In a config file, a plaintext spend key is a bech32 string, while an
+/// encrypted spend key is a table, so existing configs keep working unchanged.
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum SpendKeyStore {
+    /// A spend key stored in the clear.
+    Plaintext(#[serde_as(as = "DisplayFromStr")] SpendKey),
+    /// A spend key sealed with a passphrase.
+    Encrypted(EncryptedSpendKey),
+}
+
+impl SpendKeyStore {
+    /// Returns true if the spend key is encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        matches!(self, SpendKeyStore::Encrypted(_))
+    }
+
+    /// The full viewing key of the spend key, which is available without unlocking it.
+    pub fn full_viewing_key(&self) -> &FullViewingKey {
+        match self {
+            SpendKeyStore::Plaintext(spend_key) => spend_key.full_viewing_key(),
+            SpendKeyStore::Encrypted(encrypted) => &encrypted.full_viewing_key,
+        }
+    }
+
+    /// Returns the spend key, calling `passphrase` to unlock it if it's encrypted.
+    pub fn unlock(
+        &self,
+        passphrase: impl FnOnce() -> Result<Zeroizing<String>>,
+    ) -> Result<SpendKey> {
+        match self {
+            SpendKeyStore::Plaintext(spend_key) => Ok(spend_key.clone()),
+            SpendKeyStore::Encrypted(encrypted) => encrypted.open(&passphrase()?),
+        }
+    }
+}
+
+impl From<SpendKey> for SpendKeyStore {
+    fn from(spend_key: SpendKey) -> Self {
+        SpendKeyStore::Plaintext(spend_key)
+    }
+}
+
+impl From<EncryptedSpendKey> for SpendKeyStore {
+    fn from(encrypted: EncryptedSpendKey) -> Self {
+        SpendKeyStore::Encrypted(encrypted)
+    }
+}
+
+/// The parameters of the Argon2id key derivation used to turn a passphrase into
+/// an encryption key.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KdfParams {
+    /// The memory cost, in KiB.
+    pub m_cost: u32,
+    /// The number of iterations.
+    pub t_cost: u32,
+    /// The degree of parallelism.
+    pub p_cost: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            m_cost: 64 * 1024,
+            t_cost: 3,
+            p_cost: 1,
+        }
+    }
+}
+
+impl KdfParams {
+    fn derive_key(&self, passphrase: &str, salt: &[u8]) -> Result<Zeroizing<[u8; 32]>> {
+        let params = argon2::Params::new(self.m_cost, self.t_cost, self.p_cost, Some(32))
+            .map_err(|e| anyhow!("invalid key derivation parameters: {e}"))?;
+        let argon2 =
+            argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params);
+
+        let mut key = Zeroizing::new([0u8; 32]);
+        argon2
+            .hash_password_into(passphrase.as_bytes(), salt, key.as_mut())
+            .map_err(|e| anyhow!("failed to derive key from passphrase: {e}"))?;
+        Ok(key)
+    }
+}
+
+/// A spend key sealed with a passphrase.
+///
+/// The passphrase is stretched into a key with Argon2id, which encrypts the
+/// spend key with ChaCha20-Poly1305. The full viewing key is kept in the clear,
+/// and authenticated as associated data.
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct EncryptedSpendKey {
+    #[serde_as(as = "DisplayFromStr")]
+    pub full_viewing_key: FullViewingKey,
+    pub kdf: KdfParams,
+    #[serde_as(as = "Hex")]
+    pub salt: Vec<u8>,
+    #[serde_as(as = "Hex")]
+    pub nonce: Vec<u8>,
+    #[serde_as(as = "Hex")]
+    pub ciphertext: Vec<u8>,
+}
+
+impl EncryptedSpendKey {
+    /// Seals the spend key with the passphrase, using the default [`KdfParams`].
+    pub fn seal(spend_key: &SpendKey, passphrase: &str, rng: impl CryptoRngCore) -> Result<Self> {
+        Self::seal_with_params(spend_key, passphrase, KdfParams::default(), rng)
+    }
+
+    /// Seals the spend key with the passphrase, using the given [`KdfParams`].
+    pub fn seal_with_params(
+        spend_key: &SpendKey,
+        passphrase: &str,
+        kdf: KdfParams,
+        mut rng: impl CryptoRngCore,
+    ) -> Result<Self> {
+        let mut salt = vec![0u8; 16];
+        rng.fill_bytes(&mut salt);
+        let mut nonce = vec![0u8; 12];
+        rng.fill_bytes(&mut nonce);
+
+        let full_viewing_key = spend_key.full_viewing_key().clone();
+        let aad = full_viewing_key.encode_to_vec();
+
+        let key = kdf.derive_key(passphrase, &salt)?;
+        let plaintext = Zeroizing::new(spend_key.to_bytes().0);
+        let ciphertext = ChaCha20Poly1305::new(Key::from_slice(key.as_ref())).encrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: plaintext.as_ref(),
+                aad: &aad,
+            },
+        );
+
+        Ok(Self {
+            full_viewing_key,
+            kdf,
+            salt,
+            nonce,
+            ciphertext: ciphertext.map_err(|_| anyhow!("failed to encrypt spend key"))?,
+        })
+    }
+
+    /// Opens the sealed spend key with the passphrase.
+    pub fn open(&self, passphrase: &str) -> Result<SpendKey> {
+        if self.nonce.len() != 12 {
+            anyhow::bail!("invalid nonce length {}", self.nonce.len());
+        }
+        let aad = self.full_viewing_key.encode_to_vec();
+
+        let key = self.kdf.derive_key(passphrase, &self.salt)?;
+        let plaintext = ChaCha20Poly1305::new(Key::from_slice(key.as_ref()))
+            .decrypt(
+                Nonce::from_slice(&self.nonce),
+                Payload {
+                    msg: &self.ciphertext,
+                    aad: &aad,
+                },
+            )
+            .map(Zeroizing::new)
+            .map_err(|_| anyhow!("failed to decrypt spend key: wrong passphrase?"))?;
+        let spend_key = SpendKey::from(SpendKeyBytes::try_from(plaintext.as_slice())?);
+
+        if spend_key.full_viewing_key() != &self.full_viewing_key {
+            anyhow::bail!("decrypted spend key does not match the full viewing key");
+        }
+        Ok(spend_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_keys::keys::{Bip44Path, SeedPhrase};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    // Cheap parameters, to keep the tests fast.
+    const TEST_KDF: KdfParams = KdfParams {
+        m_cost: 64,
+        t_cost: 1,
+        p_cost: 1,
+    };
+
+    fn spend_key() -> SpendKey {
+        let seed_phrase = SeedPhrase::generate(OsRng);
+        SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0))
+    }
+
+    #[test]
+    fn seal_open_round_trip() {
+        let spend_key = spend_key();
+        let sealed =
+            EncryptedSpendKey::seal_with_params(&spend_key, "hunter2", TEST_KDF, OsRng).unwrap();
+
+        assert_eq!(&sealed.full_viewing_key, spend_key.full_viewing_key());
+        assert_eq!(sealed.open("hunter2").unwrap(), spend_key);
+        assert!(sealed.open("hunter3").is_err());
+    }
+
+    #[test]
+    fn tampered_viewing_key_is_rejected() {
+        let sealed =
+            EncryptedSpendKey::seal_with_params(&spend_key(), "hunter2", TEST_KDF, OsRng).unwrap();
+        let tampered = EncryptedSpendKey {
+            full_viewing_key: spend_key().full_viewing_key().clone(),
+            ..sealed
+        };
+        assert!(tampered.open("hunter2").is_err());
+    }
+
+    #[test]
+    fn toml_plaintext_and_encrypted() {
+        #[derive(Serialize, Deserialize)]
+        struct Wrapper {
+            spend_key: SpendKeyStore,
+        }
+
+        let spend_key = spend_key();
+        let plaintext = SpendKeyStore::from(spend_key.clone());
+        let encrypted = SpendKeyStore::from(
+            EncryptedSpendKey::seal_with_params(&spend_key, "hunter2", TEST_KDF, OsRng).unwrap(),
+        );
+
+        for store in [plaintext, encrypted] {
+            let encoded = toml::to_string_pretty(&Wrapper {
+                spend_key: store.clone(),
+            })
+            .unwrap();
+            let decoded: Wrapper = toml::from_str(&encoded).unwrap();
+            assert_eq!(decoded.spend_key, store);
+            assert_eq!(
+                decoded
+                    .spend_key
+                    .unlock(|| Ok(Zeroizing::new("hunter2".to_string())))
+                    .unwrap(),
+                spend_key
+            );
+        }
+    }
+}
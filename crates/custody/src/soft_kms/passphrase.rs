@@ -0,0 +1,40 @@
+use anyhow::Result;
+use rand_core::OsRng;
+use zeroize::Zeroizing;
+
+use super::{EncryptedSpendKey, SpendKeyStore};
+
+/// Prompts on the terminal for the passphrase unlocking an encrypted spend key.
+pub fn prompt_passphrase(prompt: &str) -> Result<Zeroizing<String>> {
+    Ok(Zeroizing::new(rpassword::prompt_password(prompt)?))
+}
+
+/// Prompts on the terminal for a new passphrase to encrypt a spend key with,
+/// asking twice to catch typos.
+pub fn prompt_new_passphrase() -> Result<Zeroizing<String>> {
+    let passphrase = Zeroizing::new(rpassword::prompt_password("Enter new passphrase: ")?);
+    if passphrase.is_empty() {
+        anyhow::bail!("passphrase must not be empty");
+    }
+    if *Zeroizing::new(rpassword::prompt_password("Confirm passphrase: ")?) != *passphrase {
+        anyhow::bail!("passphrases do not match");
+    }
+    Ok(passphrase)
+}
+
+/// Encrypts the plaintext spend key of `spend_key` in place with a new passphrase,
+/// prompted for on the terminal.
+pub fn encrypt_spend_key(spend_key: &mut SpendKeyStore) -> Result<()> {
+    let SpendKeyStore::Plaintext(plaintext) = &*spend_key else {
+        anyhow::bail!("the spend key is already encrypted");
+    };
+
+    let passphrase = prompt_new_passphrase()?;
+    let encrypted = EncryptedSpendKey::seal(plaintext, &passphrase, OsRng)?;
+    // Check that the key can be unlocked before throwing away the plaintext.
+    if &encrypted.open(&passphrase)? != plaintext {
+        anyhow::bail!("encrypted spend key does not match the original");
+    }
+    *spend_key = encrypted.into();
+    Ok(())
+}
@@ -12,9 +12,11 @@ use tonic::{async_trait, Request, Response, Status};
 use crate::AuthorizeRequest;
 
 pub use self::config::Config;
+pub use self::relay::{NetworkTerminal, Relay};
 
 mod config;
 mod dkg;
+mod relay;
 mod sign;
 
 fn to_json<T>(data: &T) -> Result<String>
@@ -40,7 +42,17 @@ where
 /// This is mainly used to accomodate the kind of interaction we have with the CLI
 /// interface, but it can also be plugged in with more general backends.
 #[async_trait]
-pub trait Terminal {
+pub trait Terminal: Sync {
+    /// Prepare for a new run of a protocol.
+    ///
+    /// This is called before any message of the run is exchanged, allowing
+    /// backends which keep track of the protocol to start from scratch.
+    ///
+    /// By default, this does nothing.
+    async fn start(&self) -> Result<()> {
+        Ok(())
+    }
+
     /// Have a user confirm that they want to sign this transaction.
     ///
     /// In an actual terminal, this should display the transaction in a human readable
@@ -70,6 +82,7 @@ pub trait Terminal {
 /// All this function does is produce side effects on the terminal, potentially returning
 /// early if the user on the other end did not want to sign the transaction.
 pub async fn follow(config: &Config, terminal: &impl Terminal) -> Result<()> {
+    terminal.start().await?;
     // Round 1
     terminal
         .explain("Paste the coordinator's first message:")
@@ -118,6 +131,7 @@ pub async fn follow(config: &Config, terminal: &impl Terminal) -> Result<()> {
 /// This takes in a terminal, because it requires interacting with the other participants.
 pub async fn dkg(t: u16, n: u16, terminal: &impl Terminal) -> Result<Config> {
     let expected_responses = n.saturating_sub(1) as usize;
+    terminal.start().await?;
     // Round 1 top
     let (round1_message, state) = dkg::round1(&mut OsRng, t, n)?;
     terminal
@@ -189,6 +203,7 @@ impl<T: Terminal> Threshold<T> {
     /// Try and create the necessary signatures to authorize the transaction plan.
     async fn authorize(&self, request: AuthorizeRequest) -> Result<AuthorizationData> {
         let plan = request.plan;
+        self.terminal.start().await?;
 
         // Round 1
         let (round1_message, state1) = sign::coordinator_round1(&mut OsRng, &self.config, plan)?;
@@ -304,6 +319,15 @@ impl<T: Terminal + Sync + Send + 'static>
             address: Some(address.into()),
         }))
     }
+
+    async fn authorize_payment_request(
+        &self,
+        _request: Request<pb::AuthorizePaymentRequestRequest>,
+    ) -> Result<Response<pb::AuthorizePaymentRequestResponse>, Status> {
+        Err(Status::unimplemented(
+            "threshold custody does not support signing payment requests",
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -314,6 +338,8 @@ mod test {
 
     use super::*;
 
+    const TEST_PLAN: &'static str = r#"{"actions":[{"output":{"value":{"amount":{"lo":"1000000000"},"assetId":{"inner":"KeqcLzNx9qSH5+lcJHBB9KNW+YPrBk5dKzvPMiypahA="}},"destAddress":{"inner":"UuFEV0VoZNxNTttsJVJzRqEzW4bm0z2RCxhUneve0KTvDjQipeg/1zx0ftbDjgr6uPiSA70yJIdlpFyxeLyXfAAtmSy6BCpR3YjEkf1bI5Q="},"rseed":"4m4bxumA0sHuonPjr12UnI4CWKj1wuq4y6rrMRb0nw0=","valueBlinding":"HHS7tY19JuWMwdKJvtKs8AmhMVa7osSpZ+CCBszu/AE=","proofBlindingR":"FmbXZoh5Pd2mEtiAEkkAZpllWo9pdwTPlXeODBXHUxA=","proofBlindingS":"0x96kUchW8jFfnxglAoMtvzPT5/RLg2RvfkRKjlU8BA="}},{"spend":{"note":{"value":{"amount":{"lo":"1000000000000"},"assetId":{"inner":"KeqcLzNx9qSH5+lcJHBB9KNW+YPrBk5dKzvPMiypahA="}},"rseed":"3svSxWREwvvVzb2upQuu3Cyr56O2kRbo0nuX4+OWcdc=","address":{"inner":"6146pY5upA9bQa4tag+6hXpMXa2kO5fcicSJGVEUP4HhZt7m4FpwAJ3+qwr5gpbHUON7DigyEJRpeV31FATGdfJhHBzGDWC+CIvi8dyIzGo="}},"position":"90","randomizer":"dJvg8FGvw5rJAvtSQvlQ4imLXahVXn419+xroVMLSwA=","valueBlinding":"Ce1/hBKLEMB/bjEA06b4zUJVEstNUjkDBWM3WrVu+QM=","proofBlindingR":"gXA7M4VR48IoxKrf4w4jGae2O7OGlTecU/RBXd4g6QI=","proofBlindingS":"7+Rhrve7mdgsKbkfFq41yfq9+Mx2qRAZDtwP3VUDAAs="}},{"output":{"value":{"amount":{"lo":"999000000000"},"assetId":{"inner":"KeqcLzNx9qSH5+lcJHBB9KNW+YPrBk5dKzvPMiypahA="}},"destAddress":{"inner":"6146pY5upA9bQa4tag+6hXpMXa2kO5fcicSJGVEUP4HhZt7m4FpwAJ3+qwr5gpbHUON7DigyEJRpeV31FATGdfJhHBzGDWC+CIvi8dyIzGo="},"rseed":"rCTbPc6xWyEcDV73Pl+W6XXbACShVOM+8/vdc7RSLlo=","valueBlinding":"DP0FN5CV4g9xZN6u2W6/4o6I/Zwr38n81q4YnJ6COAA=","proofBlindingR":"KV3u8Dc+cZo0HFUIn7n95UkQVXWeYp+3vAVuIpCIZRI=","proofBlindingS":"i00KyJVklWXUhVRy37N3p9szFIvo7383to/qxBexnBE="}}],"chainId":"penumbra-testnet-rhea-8b2dfc5c","fee":{"amount":{}},"cluePlans":[{"address":{"inner":"UuFEV0VoZNxNTttsJVJzRqEzW4bm0z2RCxhUneve0KTvDjQipeg/1zx0ftbDjgr6uPiSA70yJIdlpFyxeLyXfAAtmSy6BCpR3YjEkf1bI5Q="},"rseed":"1Li0Qx05txsyOrx2pfO9kD5rDSUMy9e+j/hHmucqARI="},{"address":{"inner":"6146pY5upA9bQa4tag+6hXpMXa2kO5fcicSJGVEUP4HhZt7m4FpwAJ3+qwr5gpbHUON7DigyEJRpeV31FATGdfJhHBzGDWC+CIvi8dyIzGo="},"rseed":"ePtCm9/tFcpLBdlgyu8bYRKV5CHbqd823UGDhG1LsGY="}],"memoPlan":{"plaintext":{"returnAddress":{"inner":"OB8AEHEehWo0o0/Dn7JtNmgdDX1VRPaDgn6MLl6n41hVjI3llljrTDCFRRjN5mkNwVwsAyJ/UdfjNIFzbGV62YVXfBJ/IMVTq2CNAHwR8Qo="}},"key":"3plOcPZzKKj8KT3sVdKnblUUFDRzCmMWYtgwB3BqfXQ="}}"#;
+
     struct FollowerTerminal {
         incoming: sync::Mutex<sync::mpsc::Receiver<String>>,
         outgoing: sync::mpsc::Sender<String>,
@@ -460,7 +486,6 @@ mod test {
 
     #[tokio::test]
     async fn test_transaction_signing() -> Result<()> {
-        const TEST_PLAN: &'static str = r#"{"actions":[{"output":{"value":{"amount":{"lo":"1000000000"},"assetId":{"inner":"KeqcLzNx9qSH5+lcJHBB9KNW+YPrBk5dKzvPMiypahA="}},"destAddress":{"inner":"UuFEV0VoZNxNTttsJVJzRqEzW4bm0z2RCxhUneve0KTvDjQipeg/1zx0ftbDjgr6uPiSA70yJIdlpFyxeLyXfAAtmSy6BCpR3YjEkf1bI5Q="},"rseed":"4m4bxumA0sHuonPjr12UnI4CWKj1wuq4y6rrMRb0nw0=","valueBlinding":"HHS7tY19JuWMwdKJvtKs8AmhMVa7osSpZ+CCBszu/AE=","proofBlindingR":"FmbXZoh5Pd2mEtiAEkkAZpllWo9pdwTPlXeODBXHUxA=","proofBlindingS":"0x96kUchW8jFfnxglAoMtvzPT5/RLg2RvfkRKjlU8BA="}},{"spend":{"note":{"value":{"amount":{"lo":"1000000000000"},"assetId":{"inner":"KeqcLzNx9qSH5+lcJHBB9KNW+YPrBk5dKzvPMiypahA="}},"rseed":"3svSxWREwvvVzb2upQuu3Cyr56O2kRbo0nuX4+OWcdc=","address":{"inner":"6146pY5upA9bQa4tag+6hXpMXa2kO5fcicSJGVEUP4HhZt7m4FpwAJ3+qwr5gpbHUON7DigyEJRpeV31FATGdfJhHBzGDWC+CIvi8dyIzGo="}},"position":"90","randomizer":"dJvg8FGvw5rJAvtSQvlQ4imLXahVXn419+xroVMLSwA=","valueBlinding":"Ce1/hBKLEMB/bjEA06b4zUJVEstNUjkDBWM3WrVu+QM=","proofBlindingR":"gXA7M4VR48IoxKrf4w4jGae2O7OGlTecU/RBXd4g6QI=","proofBlindingS":"7+Rhrve7mdgsKbkfFq41yfq9+Mx2qRAZDtwP3VUDAAs="}},{"output":{"value":{"amount":{"lo":"999000000000"},"assetId":{"inner":"KeqcLzNx9qSH5+lcJHBB9KNW+YPrBk5dKzvPMiypahA="}},"destAddress":{"inner":"6146pY5upA9bQa4tag+6hXpMXa2kO5fcicSJGVEUP4HhZt7m4FpwAJ3+qwr5gpbHUON7DigyEJRpeV31FATGdfJhHBzGDWC+CIvi8dyIzGo="},"rseed":"rCTbPc6xWyEcDV73Pl+W6XXbACShVOM+8/vdc7RSLlo=","valueBlinding":"DP0FN5CV4g9xZN6u2W6/4o6I/Zwr38n81q4YnJ6COAA=","proofBlindingR":"KV3u8Dc+cZo0HFUIn7n95UkQVXWeYp+3vAVuIpCIZRI=","proofBlindingS":"i00KyJVklWXUhVRy37N3p9szFIvo7383to/qxBexnBE="}}],"chainId":"penumbra-testnet-rhea-8b2dfc5c","fee":{"amount":{}},"cluePlans":[{"address":{"inner":"UuFEV0VoZNxNTttsJVJzRqEzW4bm0z2RCxhUneve0KTvDjQipeg/1zx0ftbDjgr6uPiSA70yJIdlpFyxeLyXfAAtmSy6BCpR3YjEkf1bI5Q="},"rseed":"1Li0Qx05txsyOrx2pfO9kD5rDSUMy9e+j/hHmucqARI="},{"address":{"inner":"6146pY5upA9bQa4tag+6hXpMXa2kO5fcicSJGVEUP4HhZt7m4FpwAJ3+qwr5gpbHUON7DigyEJRpeV31FATGdfJhHBzGDWC+CIvi8dyIzGo="},"rseed":"ePtCm9/tFcpLBdlgyu8bYRKV5CHbqd823UGDhG1LsGY="}],"memoPlan":{"plaintext":{"returnAddress":{"inner":"OB8AEHEehWo0o0/Dn7JtNmgdDX1VRPaDgn6MLl6n41hVjI3llljrTDCFRRjN5mkNwVwsAyJ/UdfjNIFzbGV62YVXfBJ/IMVTq2CNAHwR8Qo="}},"key":"3plOcPZzKKj8KT3sVdKnblUUFDRzCmMWYtgwB3BqfXQ="}}"#;
         const T: u16 = 3;
         const N: u16 = 3;
 
@@ -497,4 +522,119 @@ mod test {
         }
         Ok(())
     }
+
+    /// A terminal which only confirms transactions, for use behind a [`NetworkTerminal`].
+    struct LocalTerminal {
+        approve: bool,
+    }
+
+    #[async_trait]
+    impl Terminal for LocalTerminal {
+        async fn confirm_transaction(&self, _transaction: &TransactionPlan) -> Result<bool> {
+            Ok(self.approve)
+        }
+
+        async fn explain(&self, _msg: &str) -> Result<()> {
+            Ok(())
+        }
+
+        async fn broadcast(&self, _data: &str) -> Result<()> {
+            anyhow::bail!("local terminals don't send messages")
+        }
+
+        async fn next_response(&self) -> Result<Option<String>> {
+            anyhow::bail!("local terminals don't receive messages")
+        }
+    }
+
+    const RELAY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+    /// Run a relay in the background, returning a channel to connect to it.
+    async fn spawn_relay() -> Result<tonic::transport::Channel> {
+        use penumbra_proto::custody::threshold::v1alpha1::threshold_relay_service_server::ThresholdRelayServiceServer;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let incoming = futures::stream::unfold(listener, |listener| async move {
+            Some((listener.accept().await.map(|(stream, _)| stream), listener))
+        });
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(ThresholdRelayServiceServer::new(Relay::new()))
+                .serve_with_incoming(incoming),
+        );
+        Ok(tonic::transport::Endpoint::from_shared(format!("http://{addr}"))?.connect_lazy())
+    }
+
+    #[tokio::test]
+    async fn test_dkg_through_relay() -> Result<()> {
+        const T: u16 = 2;
+        const N: u16 = 3;
+        let channel = spawn_relay().await?;
+        let mut handles = Vec::new();
+        for _ in 0..N {
+            let terminal =
+                NetworkTerminal::dkg(channel.clone(), "test-dkg", LocalTerminal { approve: true })
+                    .with_timeout(RELAY_TIMEOUT);
+            handles.push(tokio::spawn(async move { dkg(T, N, &terminal).await }));
+        }
+        let mut configs = Vec::new();
+        for handle in handles {
+            configs.push(handle.await??);
+        }
+        let first_config = configs.pop().unwrap();
+        for config in configs {
+            assert_eq!(first_config.fvk(), config.fvk());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transaction_signing_through_relay() -> Result<()> {
+        const T: u16 = 3;
+        const N: u16 = 5;
+        let channel = spawn_relay().await?;
+        let (coordinator_config, follower_configs) = {
+            let mut configs = Config::deal(&mut OsRng, T, N)?;
+            (configs.pop().unwrap(), configs)
+        };
+
+        // One of the followers refuses to sign, but there are still enough of the others.
+        for (i, config) in follower_configs.into_iter().enumerate() {
+            let terminal = NetworkTerminal::follower(
+                channel.clone(),
+                &config,
+                LocalTerminal { approve: i != 0 },
+            )
+            .with_timeout(RELAY_TIMEOUT);
+            tokio::spawn(async move { follow(&config, &terminal).await });
+        }
+
+        let plan = serde_json::from_str::<TransactionPlan>(TEST_PLAN)?;
+        let fvk = coordinator_config.fvk().clone();
+        let terminal = NetworkTerminal::coordinator(
+            channel,
+            &coordinator_config,
+            LocalTerminal { approve: true },
+        )
+        .with_timeout(RELAY_TIMEOUT);
+        let authorization_data = Threshold::new(coordinator_config, terminal)
+            .authorize(AuthorizeRequest {
+                plan: plan.clone(),
+                pre_authorizations: Vec::new(),
+            })
+            .await?;
+        assert_eq!(plan.effect_hash(&fvk), authorization_data.effect_hash);
+        for (randomizer, sig) in plan
+            .spend_plans()
+            .into_iter()
+            .map(|x| x.randomizer)
+            .zip(authorization_data.spend_auths)
+        {
+            fvk.spend_verification_key()
+                .randomize(&randomizer)
+                .verify(authorization_data.effect_hash.as_bytes(), &sig)?;
+        }
+        Ok(())
+    }
 }
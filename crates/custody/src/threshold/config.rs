@@ -259,6 +259,31 @@ impl Config {
     pub fn verification_keys(&self) -> HashSet<VerificationKey> {
         self.verifying_shares.keys().cloned().collect()
     }
+
+    /// A short digest of the public parts of this config.
+    ///
+    /// The participants of a DKG end up with the same fingerprint only if they
+    /// agree on the threshold, on who the participants are, and on the
+    /// resulting key, so comparing fingerprints out of band catches anyone
+    /// tampering with the messages of the protocol.
+    pub fn fingerprint(&self) -> String {
+        let mut keys = self
+            .verification_keys()
+            .into_iter()
+            .map(|vk| vk.to_bytes())
+            .collect::<Vec<_>>();
+        keys.sort();
+        let mut state = blake2b_simd::Params::new()
+            .personal(b"threshold-config")
+            .hash_length(16)
+            .to_state();
+        state.update(&self.threshold.to_le_bytes());
+        state.update(self.fvk.to_string().as_bytes());
+        for key in &keys {
+            state.update(key);
+        }
+        hex::encode(state.finalize().as_bytes())
+    }
 }
 
 #[cfg(test)]
@@ -280,4 +305,15 @@ mod test {
         assert_eq!(config.verifying_shares, config2.verifying_shares);
         Ok(())
     }
+
+    #[test]
+    fn test_fingerprint_is_shared_by_participants() -> Result<()> {
+        let configs = Config::deal(&mut OsRng, 2, 3)?;
+        let fingerprint = configs[0].fingerprint();
+        assert!(configs.iter().all(|c| c.fingerprint() == fingerprint));
+
+        let other = Config::deal(&mut OsRng, 2, 3)?.pop().unwrap();
+        assert_ne!(other.fingerprint(), fingerprint);
+        Ok(())
+    }
 }
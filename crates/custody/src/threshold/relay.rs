@@ -0,0 +1,905 @@
+//! Running the threshold protocols over the network.
+//!
+//! Rather than having participants copy messages between their terminals by
+//! hand, a [`Relay`] groups participants into rooms, and forwards the messages
+//! sent in a room to the other participants subscribed to it. Participants use
+//! a [`NetworkTerminal`] to run the protocols through a relay.
+//!
+//! Every message is signed by its sender, and checked by its recipients against
+//! the keys in their threshold config, so the relay only needs to be trusted to
+//! deliver messages. It does get to see them, including the plans being signed.
+//!
+//! The participants of a DKG don't have a config yet, so nothing stops the relay,
+//! or anyone else who knows the session name, from taking part in their place.
+//! The participants need to compare the [`Config::fingerprint`] they end up with
+//! out of band before using the config.
+
+use std::{
+    collections::{BTreeMap, HashSet, VecDeque},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Result};
+use ed25519_consensus::{Signature, SigningKey, VerificationKey};
+use penumbra_proto::{custody::threshold::v1alpha1 as pb, Message};
+use penumbra_transaction::plan::TransactionPlan;
+use rand_core::{OsRng, RngCore};
+use tokio::sync::mpsc;
+use tonic::{async_trait, transport::Channel, Request, Response, Status, Streaming};
+
+use super::{Config, Terminal};
+
+/// The domain separator for the signatures on relayed messages.
+const ENVELOPE_DOMAIN: &[u8] = b"penumbra.custody.threshold.relay.envelope";
+/// The domain separator for the signatures on subscriptions.
+const SUBSCRIBE_DOMAIN: &[u8] = b"penumbra.custody.threshold.relay.subscribe";
+
+/// How far the timestamp of a subscription can be from the relay's clock.
+const MAX_CLOCK_SKEW: Duration = Duration::from_secs(5 * 60);
+/// How long a relay keeps messages around for participants joining late, by default.
+const DEFAULT_BACKLOG_TTL: Duration = Duration::from_secs(10 * 60);
+/// The maximum number of messages a relay keeps around in each room.
+const MAX_BACKLOG: usize = 256;
+/// The number of messages that can be waiting to be streamed to a subscriber.
+const SUBSCRIBER_BUFFER: usize = 64;
+/// The maximum number of messages a terminal keeps for later rounds or sessions.
+const MAX_BUFFERED: usize = 256;
+
+fn vk_to_pb(vk: &VerificationKey) -> pb::VerificationKey {
+    pb::VerificationKey {
+        inner: vk.to_bytes().to_vec(),
+    }
+}
+
+fn vk_from_pb(vk: Option<pb::VerificationKey>) -> Result<VerificationKey> {
+    Ok(vk
+        .ok_or(anyhow!("missing verification key"))?
+        .inner
+        .as_slice()
+        .try_into()?)
+}
+
+fn sig_from_pb(sig: Option<pb::Signature>) -> Result<Signature> {
+    Ok(sig
+        .ok_or(anyhow!("missing signature"))?
+        .inner
+        .as_slice()
+        .try_into()?)
+}
+
+fn signed_bytes(domain: &[u8], inner: &impl Message) -> Vec<u8> {
+    [domain, inner.encode_to_vec().as_slice()].concat()
+}
+
+/// Hash some data into a 32 byte identifier, for rooms and sessions.
+fn identifier(personal: &[u8], data: &[&[u8]]) -> Vec<u8> {
+    let mut state = blake2b_simd::Params::new()
+        .personal(personal)
+        .hash_length(32)
+        .to_state();
+    for chunk in data {
+        state.update(chunk);
+    }
+    state.finalize().as_bytes().to_vec()
+}
+
+/// A protocol message, as relayed between participants.
+#[derive(Debug, Clone)]
+struct Envelope {
+    room: Vec<u8>,
+    session: Vec<u8>,
+    round: u32,
+    recipient: Option<VerificationKey>,
+    payload: String,
+    sender: VerificationKey,
+    sig: Signature,
+}
+
+impl Envelope {
+    fn inner(
+        room: &[u8],
+        session: &[u8],
+        round: u32,
+        recipient: Option<&VerificationKey>,
+        payload: &str,
+    ) -> pb::relay_envelope::Inner {
+        pb::relay_envelope::Inner {
+            room: room.to_vec(),
+            session: session.to_vec(),
+            round,
+            recipient: recipient.map(vk_to_pb),
+            payload: payload.to_owned(),
+        }
+    }
+
+    // Make an envelope, automatically signing the right bytes
+    fn make(
+        signing_key: &SigningKey,
+        room: Vec<u8>,
+        session: Vec<u8>,
+        round: u32,
+        recipient: Option<VerificationKey>,
+        payload: String,
+    ) -> Self {
+        let inner = Self::inner(&room, &session, round, recipient.as_ref(), &payload);
+        Self {
+            room,
+            session,
+            round,
+            recipient,
+            payload,
+            sender: signing_key.verification_key(),
+            sig: signing_key.sign(&signed_bytes(ENVELOPE_DOMAIN, &inner)),
+        }
+    }
+
+    // Check that the envelope was signed by its sender
+    fn check(&self) -> Result<()> {
+        let inner = Self::inner(
+            &self.room,
+            &self.session,
+            self.round,
+            self.recipient.as_ref(),
+            &self.payload,
+        );
+        self.sender
+            .verify(&self.sig, &signed_bytes(ENVELOPE_DOMAIN, &inner))?;
+        Ok(())
+    }
+
+    /// Whether or not this envelope should be delivered to a given participant.
+    fn is_for(&self, pk: &VerificationKey) -> bool {
+        &self.sender != pk && self.recipient.as_ref().map_or(true, |r| r == pk)
+    }
+}
+
+impl From<Envelope> for pb::RelayEnvelope {
+    fn from(value: Envelope) -> Self {
+        Self {
+            inner: Some(Envelope::inner(
+                &value.room,
+                &value.session,
+                value.round,
+                value.recipient.as_ref(),
+                &value.payload,
+            )),
+            sender: Some(vk_to_pb(&value.sender)),
+            sig: Some(pb::Signature {
+                inner: value.sig.to_bytes().to_vec(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<pb::RelayEnvelope> for Envelope {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::RelayEnvelope) -> Result<Self, Self::Error> {
+        let inner = value.inner.ok_or(anyhow!("missing inner"))?;
+        Ok(Self {
+            room: inner.room,
+            session: inner.session,
+            round: inner.round,
+            recipient: inner.recipient.map(|x| vk_from_pb(Some(x))).transpose()?,
+            payload: inner.payload,
+            sender: vk_from_pb(value.sender)?,
+            sig: sig_from_pb(value.sig)?,
+        })
+    }
+}
+
+/// A request to join a room, signed by the participant.
+#[derive(Debug, Clone)]
+struct Registration {
+    room: Vec<u8>,
+    timestamp: u64,
+    pk: VerificationKey,
+    sig: Signature,
+}
+
+impl Registration {
+    fn make(signing_key: &SigningKey, room: Vec<u8>, now: SystemTime) -> Result<Self> {
+        let timestamp = now.duration_since(UNIX_EPOCH)?.as_secs();
+        let inner = pb::subscribe_request::Inner {
+            room: room.clone(),
+            timestamp,
+        };
+        Ok(Self {
+            room,
+            timestamp,
+            pk: signing_key.verification_key(),
+            sig: signing_key.sign(&signed_bytes(SUBSCRIBE_DOMAIN, &inner)),
+        })
+    }
+
+    // Check the signature, and that the registration is recent
+    fn check(&self, now: SystemTime) -> Result<()> {
+        let inner = pb::subscribe_request::Inner {
+            room: self.room.clone(),
+            timestamp: self.timestamp,
+        };
+        self.pk
+            .verify(&self.sig, &signed_bytes(SUBSCRIBE_DOMAIN, &inner))?;
+        let now = now.duration_since(UNIX_EPOCH)?.as_secs();
+        if now.abs_diff(self.timestamp) > MAX_CLOCK_SKEW.as_secs() {
+            anyhow::bail!("subscription timestamp is too far from the current time");
+        }
+        Ok(())
+    }
+}
+
+impl From<Registration> for pb::SubscribeRequest {
+    fn from(value: Registration) -> Self {
+        Self {
+            inner: Some(pb::subscribe_request::Inner {
+                room: value.room,
+                timestamp: value.timestamp,
+            }),
+            pk: Some(vk_to_pb(&value.pk)),
+            sig: Some(pb::Signature {
+                inner: value.sig.to_bytes().to_vec(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<pb::SubscribeRequest> for Registration {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::SubscribeRequest) -> Result<Self, Self::Error> {
+        let inner = value.inner.ok_or(anyhow!("missing inner"))?;
+        Ok(Self {
+            room: inner.room,
+            timestamp: inner.timestamp,
+            pk: vk_from_pb(value.pk)?,
+            sig: sig_from_pb(value.sig)?,
+        })
+    }
+}
+
+type Outgoing = mpsc::Sender<Result<pb::SubscribeResponse, Status>>;
+
+#[derive(Default)]
+struct Room {
+    subscribers: Vec<(VerificationKey, Outgoing)>,
+    backlog: VecDeque<(Instant, Envelope)>,
+}
+
+impl Room {
+    fn prune(&mut self, backlog_ttl: Duration) {
+        self.subscribers.retain(|(_, tx)| !tx.is_closed());
+        while let Some((at, _)) = self.backlog.front() {
+            if at.elapsed() <= backlog_ttl && self.backlog.len() <= MAX_BACKLOG {
+                break;
+            }
+            self.backlog.pop_front();
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.subscribers.is_empty() && self.backlog.is_empty()
+    }
+}
+
+/// A relay forwarding the messages of the threshold protocols between participants.
+///
+/// Participants subscribe to a room, proving that they own their signing key,
+/// and every message sent in a room is delivered to the other participants
+/// subscribed to it. Messages are kept around for a while, so that participants
+/// joining late can catch up.
+#[derive(Clone)]
+pub struct Relay {
+    participants: Option<Arc<HashSet<VerificationKey>>>,
+    backlog_ttl: Duration,
+    rooms: Arc<Mutex<BTreeMap<Vec<u8>, Room>>>,
+}
+
+impl Default for Relay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Relay {
+    pub fn new() -> Self {
+        Self {
+            participants: None,
+            backlog_ttl: DEFAULT_BACKLOG_TTL,
+            rooms: Default::default(),
+        }
+    }
+
+    /// Only allow the participants with these verification keys to use the relay.
+    pub fn with_participants(
+        mut self,
+        participants: impl IntoIterator<Item = VerificationKey>,
+    ) -> Self {
+        self.participants = Some(Arc::new(participants.into_iter().collect()));
+        self
+    }
+
+    /// Set how long messages are kept around for participants joining late.
+    pub fn with_backlog_ttl(mut self, backlog_ttl: Duration) -> Self {
+        self.backlog_ttl = backlog_ttl;
+        self
+    }
+
+    fn check_participant(&self, pk: &VerificationKey) -> Result<(), Status> {
+        match &self.participants {
+            Some(participants) if !participants.contains(pk) => {
+                Err(Status::permission_denied(format!(
+                    "{} is not allowed to use this relay",
+                    hex::encode(pk.as_bytes())
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn rooms(&self) -> Result<std::sync::MutexGuard<'_, BTreeMap<Vec<u8>, Room>>, Status> {
+        let mut rooms = self
+            .rooms
+            .lock()
+            .map_err(|_| Status::internal("relay state lock poisoned"))?;
+        rooms.retain(|_, room| {
+            room.prune(self.backlog_ttl);
+            !room.is_empty()
+        });
+        Ok(rooms)
+    }
+
+    /// Add a participant to a room, returning the stream of messages sent to them.
+    fn register(
+        &self,
+        registration: Registration,
+    ) -> Result<mpsc::Receiver<Result<pb::SubscribeResponse, Status>>, Status> {
+        registration
+            .check(SystemTime::now())
+            .map_err(|e| Status::unauthenticated(format!("invalid subscription: {e}")))?;
+        self.check_participant(&registration.pk)?;
+
+        // Make room for the whole backlog, so that catching up can't fail.
+        let (tx, rx) = mpsc::channel(SUBSCRIBER_BUFFER + MAX_BACKLOG + 1);
+        let mut rooms = self.rooms()?;
+        let room = rooms.entry(registration.room).or_default();
+        for (_, envelope) in room
+            .backlog
+            .iter()
+            .filter(|(_, envelope)| envelope.is_for(&registration.pk))
+        {
+            tx.try_send(Ok(pb::SubscribeResponse {
+                envelope: Some(envelope.clone().into()),
+            }))
+            .map_err(|e| Status::internal(format!("failed to send backlog: {e}")))?;
+        }
+        room.subscribers.push((registration.pk, tx));
+        Ok(rx)
+    }
+
+    /// Deliver a message to the other participants in its room, returning the number of recipients.
+    fn relay(&self, envelope: Envelope) -> Result<u32, Status> {
+        envelope
+            .check()
+            .map_err(|e| Status::unauthenticated(format!("invalid message signature: {e}")))?;
+        self.check_participant(&envelope.sender)?;
+
+        let mut rooms = self.rooms()?;
+        let room = rooms
+            .get_mut(&envelope.room)
+            .filter(|room| {
+                room.subscribers
+                    .iter()
+                    .any(|(pk, _)| pk == &envelope.sender)
+            })
+            .ok_or_else(|| {
+                Status::failed_precondition(
+                    "must subscribe to a room before sending messages to it",
+                )
+            })?;
+
+        let mut recipients = 0;
+        room.subscribers.retain(|(pk, tx)| {
+            if !envelope.is_for(pk) {
+                return true;
+            }
+            let response = pb::SubscribeResponse {
+                envelope: Some(envelope.clone().into()),
+            };
+            match tx.try_send(Ok(response)) {
+                Ok(()) => {
+                    recipients += 1;
+                    true
+                }
+                Err(e) => {
+                    // Dropping the subscriber ends their stream, so they'll know to subscribe again.
+                    tracing::warn!(pk = %hex::encode(pk.as_bytes()), %e, "dropping subscriber");
+                    false
+                }
+            }
+        });
+        room.backlog.push_back((Instant::now(), envelope));
+        room.prune(self.backlog_ttl);
+
+        Ok(recipients)
+    }
+}
+
+#[async_trait]
+impl pb::threshold_relay_service_server::ThresholdRelayService for Relay {
+    type SubscribeStream = Pin<
+        Box<dyn futures::Stream<Item = Result<pb::SubscribeResponse, Status>> + Send + 'static>,
+    >;
+
+    async fn subscribe(
+        &self,
+        request: Request<pb::SubscribeRequest>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        let registration = request
+            .into_inner()
+            .try_into()
+            .map_err(|e| Status::invalid_argument(format!("{e}")))?;
+        let rx = self.register(registration)?;
+        let stream = futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|item| (item, rx))
+        });
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    async fn broadcast(
+        &self,
+        request: Request<pb::BroadcastRequest>,
+    ) -> Result<Response<pb::BroadcastResponse>, Status> {
+        let envelope = request
+            .into_inner()
+            .envelope
+            .ok_or(anyhow!("BroadcastRequest missing envelope"))
+            .and_then(|x| x.try_into())
+            .map_err(|e| Status::invalid_argument(format!("{e}")))?;
+        let recipients = self.relay(envelope)?;
+        Ok(Response::new(pb::BroadcastResponse { recipients }))
+    }
+}
+
+/// How a [`NetworkTerminal`] takes part in the protocols.
+#[derive(Debug, Clone)]
+enum Role {
+    /// Send messages to everyone in the room, and gather their replies.
+    ///
+    /// This is how the coordinator of a signature and the participants of a DKG behave.
+    /// Without a fixed session, a new one is created each time the protocol is run.
+    Lead { session: Option<Vec<u8>> },
+    /// Wait for a message starting a session, and then only reply to its sender.
+    Follow,
+}
+
+/// The state of the current run of a protocol.
+#[derive(Default)]
+struct Session {
+    id: Option<Vec<u8>>,
+    /// The number of messages we've sent so far, which is also our current round.
+    sent: u32,
+    /// When following, the participant who started the session.
+    leader: Option<VerificationKey>,
+    /// The participants we've accepted a message from in the current round.
+    heard: HashSet<VerificationKey>,
+    /// The participants we accepted messages from in the first round.
+    ///
+    /// Later rounds only accept messages from these participants.
+    locked: Option<HashSet<VerificationKey>>,
+}
+
+#[derive(Default)]
+struct State {
+    incoming: Option<Streaming<pb::SubscribeResponse>>,
+    session: Session,
+    /// Messages for later rounds or sessions.
+    buffered: VecDeque<Envelope>,
+    /// The sessions we've already taken part in.
+    finished: HashSet<Vec<u8>>,
+}
+
+enum Disposition {
+    Accept,
+    Keep,
+    Discard,
+}
+
+/// A [`Terminal`] running the protocols through a [`Relay`].
+///
+/// The messages of the protocols are sent through the relay automatically,
+/// while confirming transactions is left to a local terminal, so that a person
+/// can still decide whether or not to sign.
+pub struct NetworkTerminal<T> {
+    local: T,
+    client: pb::threshold_relay_service_client::ThresholdRelayServiceClient<Channel>,
+    signing_key: SigningKey,
+    room: Vec<u8>,
+    peers: Option<HashSet<VerificationKey>>,
+    role: Role,
+    timeout: Option<Duration>,
+    state: tokio::sync::Mutex<State>,
+}
+
+impl<T> NetworkTerminal<T> {
+    fn new(
+        channel: Channel,
+        local: T,
+        signing_key: SigningKey,
+        room: Vec<u8>,
+        peers: Option<HashSet<VerificationKey>>,
+        role: Role,
+    ) -> Self {
+        Self {
+            local,
+            client: pb::threshold_relay_service_client::ThresholdRelayServiceClient::new(channel),
+            signing_key,
+            room,
+            peers,
+            role,
+            timeout: None,
+            state: Default::default(),
+        }
+    }
+
+    /// The room shared by all the signers of a config.
+    fn signers_room(config: &Config) -> Vec<u8> {
+        let mut keys = config
+            .verification_keys()
+            .into_iter()
+            .map(|vk| vk.to_bytes())
+            .collect::<Vec<_>>();
+        keys.sort();
+        identifier(
+            b"threshold-room",
+            &keys.iter().map(|x| x.as_slice()).collect::<Vec<_>>(),
+        )
+    }
+
+    /// The other signers of a config.
+    fn other_signers(config: &Config) -> HashSet<VerificationKey> {
+        let me = config.signing_key().verification_key();
+        config
+            .verification_keys()
+            .into_iter()
+            .filter(|vk| vk != &me)
+            .collect()
+    }
+
+    /// Create a terminal for the coordinator of the signing protocol.
+    pub fn coordinator(channel: Channel, config: &Config, local: T) -> Self {
+        Self::new(
+            channel,
+            local,
+            config.signing_key().clone(),
+            Self::signers_room(config),
+            Some(Self::other_signers(config)),
+            Role::Lead { session: None },
+        )
+    }
+
+    /// Create a terminal for a follower in the signing protocol.
+    pub fn follower(channel: Channel, config: &Config, local: T) -> Self {
+        Self::new(
+            channel,
+            local,
+            config.signing_key().clone(),
+            Self::signers_room(config),
+            Some(Self::other_signers(config)),
+            Role::Follow,
+        )
+    }
+
+    /// Create a terminal for a participant in the DKG protocol.
+    ///
+    /// Since the participants don't have any keys yet, they find each other
+    /// by agreeing on the name of the session beforehand. Nothing authenticates
+    /// the other participants, so the resulting config must not be used until
+    /// everyone has checked that they got the same [`Config::fingerprint`].
+    pub fn dkg(channel: Channel, session: &str, local: T) -> Self {
+        let room = identifier(b"threshold-dkg", &[session.as_bytes()]);
+        Self::new(
+            channel,
+            local,
+            SigningKey::new(OsRng),
+            room.clone(),
+            None,
+            Role::Lead {
+                session: Some(room),
+            },
+        )
+    }
+
+    /// Fail when waiting longer than `timeout` for a message from the other participants.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    fn classify(&self, state: &State, envelope: &Envelope) -> Disposition {
+        let me = self.signing_key.verification_key();
+        if envelope.room != self.room || !envelope.is_for(&me) {
+            return Disposition::Discard;
+        }
+        if let Some(peers) = &self.peers {
+            if !peers.contains(&envelope.sender) {
+                tracing::warn!(
+                    sender = %hex::encode(envelope.sender.as_bytes()),
+                    "ignoring message from unknown participant"
+                );
+                return Disposition::Discard;
+            }
+        }
+
+        let session = &state.session;
+        let same_session = session.id.as_ref() == Some(&envelope.session);
+        let expected = match (&self.role, &session.leader) {
+            (Role::Follow, None) if state.finished.contains(&envelope.session) => {
+                return Disposition::Discard
+            }
+            (Role::Follow, None) if envelope.round == 1 => return Disposition::Accept,
+            (Role::Follow, None) => return Disposition::Keep,
+            (Role::Follow, Some(_)) if !same_session => {
+                return if state.finished.contains(&envelope.session) {
+                    Disposition::Discard
+                } else {
+                    Disposition::Keep
+                };
+            }
+            (Role::Follow, Some(leader)) if leader != &envelope.sender => {
+                return Disposition::Discard
+            }
+            // We reply to the leader's messages, so their next message is one round ahead.
+            (Role::Follow, Some(_)) => session.sent + 1,
+            (Role::Lead { .. }, _) if !same_session => return Disposition::Discard,
+            (Role::Lead { .. }, _)
+                if session
+                    .locked
+                    .as_ref()
+                    .map_or(false, |locked| !locked.contains(&envelope.sender)) =>
+            {
+                return Disposition::Discard
+            }
+            // Everyone replies to our messages, so their messages are in the same round.
+            (Role::Lead { .. }, _) => session.sent,
+        };
+
+        if envelope.round < expected || session.heard.contains(&envelope.sender) {
+            Disposition::Discard
+        } else if envelope.round > expected {
+            Disposition::Keep
+        } else {
+            Disposition::Accept
+        }
+    }
+
+    fn accept(state: &mut State, envelope: Envelope) -> String {
+        let session = &mut state.session;
+        if session.id.is_none() {
+            session.id = Some(envelope.session);
+            session.leader = Some(envelope.sender);
+        }
+        session.heard.insert(envelope.sender);
+        envelope.payload
+    }
+}
+
+#[async_trait]
+impl<T: Terminal + Sync + Send> Terminal for NetworkTerminal<T> {
+    async fn start(&self) -> Result<()> {
+        let registration =
+            Registration::make(&self.signing_key, self.room.clone(), SystemTime::now())?;
+        let incoming = self
+            .client
+            .clone()
+            .subscribe(pb::SubscribeRequest::from(registration))
+            .await?
+            .into_inner();
+
+        let mut state = self.state.lock().await;
+        if let Some(id) = state.session.id.take() {
+            state.finished.insert(id);
+        }
+        let id = match &self.role {
+            Role::Lead { session: Some(id) } => Some(id.clone()),
+            Role::Lead { session: None } => {
+                let mut id = vec![0u8; 32];
+                OsRng.fill_bytes(&mut id);
+                Some(id)
+            }
+            Role::Follow => None,
+        };
+        if let Role::Lead { .. } = &self.role {
+            state.buffered.clear();
+        }
+        state.session = Session {
+            id,
+            ..Default::default()
+        };
+        state.incoming = Some(incoming);
+        Ok(())
+    }
+
+    async fn confirm_transaction(&self, transaction: &TransactionPlan) -> Result<bool> {
+        let leader = self.state.lock().await.session.leader;
+        if let Some(leader) = leader {
+            self.local
+                .explain(&format!(
+                    "Signature requested by {}",
+                    hex::encode(leader.as_bytes())
+                ))
+                .await?;
+        }
+        self.local.confirm_transaction(transaction).await
+    }
+
+    async fn explain(&self, msg: &str) -> Result<()> {
+        // The explanations are instructions for relaying messages by hand, which we do for the user.
+        tracing::debug!(msg);
+        Ok(())
+    }
+
+    async fn broadcast(&self, data: &str) -> Result<()> {
+        let envelope = {
+            let mut state = self.state.lock().await;
+            let session = &mut state.session;
+            let id = session
+                .id
+                .clone()
+                .ok_or(anyhow!("no session to send messages in"))?;
+            let recipient = match &self.role {
+                Role::Lead { .. } => None,
+                Role::Follow => Some(session.leader.ok_or(anyhow!("no leader to reply to"))?),
+            };
+            let heard = std::mem::take(&mut session.heard);
+            if session.sent == 1 && session.locked.is_none() {
+                session.locked = Some(heard);
+            }
+            session.sent += 1;
+            Envelope::make(
+                &self.signing_key,
+                self.room.clone(),
+                id,
+                session.sent,
+                recipient,
+                data.to_owned(),
+            )
+        };
+        let round = envelope.round;
+        let recipients = self
+            .client
+            .clone()
+            .broadcast(pb::BroadcastRequest {
+                envelope: Some(envelope.into()),
+            })
+            .await?
+            .into_inner()
+            .recipients;
+        tracing::debug!(round, recipients, "relayed message");
+        Ok(())
+    }
+
+    async fn next_response(&self) -> Result<Option<String>> {
+        let mut state = self.state.lock().await;
+
+        // First, look through the messages we kept from before.
+        let mut i = 0;
+        while i < state.buffered.len() {
+            match self.classify(&state, &state.buffered[i]) {
+                Disposition::Accept => {
+                    let envelope = state
+                        .buffered
+                        .remove(i)
+                        .ok_or(anyhow!("buffered message should exist"))?;
+                    return Ok(Some(Self::accept(&mut state, envelope)));
+                }
+                Disposition::Keep => i += 1,
+                Disposition::Discard => {
+                    state.buffered.remove(i);
+                }
+            }
+        }
+
+        loop {
+            let incoming = state
+                .incoming
+                .as_mut()
+                .ok_or(anyhow!("not subscribed to the relay"))?;
+            let response = match self.timeout {
+                Some(timeout) => tokio::time::timeout(timeout, incoming.message())
+                    .await
+                    .map_err(|_| anyhow!("timed out waiting for the other participants"))?,
+                None => incoming.message().await,
+            }?
+            .ok_or(anyhow!("the relay closed the connection"))?;
+
+            let envelope = match response
+                .envelope
+                .ok_or(anyhow!("missing envelope"))
+                .and_then(Envelope::try_from)
+                .and_then(|envelope| envelope.check().map(|_| envelope))
+            {
+                Ok(envelope) => envelope,
+                Err(e) => {
+                    tracing::warn!(%e, "ignoring invalid message from the relay");
+                    continue;
+                }
+            };
+            match self.classify(&state, &envelope) {
+                Disposition::Accept => return Ok(Some(Self::accept(&mut state, envelope))),
+                Disposition::Keep => {
+                    state.buffered.push_back(envelope);
+                    if state.buffered.len() > MAX_BUFFERED {
+                        state.buffered.pop_front();
+                    }
+                }
+                Disposition::Discard => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_registration_is_checked() -> Result<()> {
+        let signing_key = SigningKey::new(OsRng);
+        let now = SystemTime::now();
+        let registration = Registration::make(&signing_key, vec![1; 32], now)?;
+        registration.check(now)?;
+
+        let stale = now - 2 * MAX_CLOCK_SKEW;
+        assert!(registration.check(stale).is_err());
+        let wrong_room = Registration {
+            room: vec![2; 32],
+            ..registration
+        };
+        assert!(wrong_room.check(now).is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_relay_only_forwards_authentic_messages() -> Result<()> {
+        let relay = Relay::new();
+        let (alice, bob, eve) = (
+            SigningKey::new(OsRng),
+            SigningKey::new(OsRng),
+            SigningKey::new(OsRng),
+        );
+        let room = vec![1; 32];
+        let mut alice_rx =
+            relay.register(Registration::make(&alice, room.clone(), SystemTime::now())?)?;
+        let _bob_rx = relay.register(Registration::make(&bob, room.clone(), SystemTime::now())?)?;
+
+        let message = Envelope::make(&bob, room.clone(), vec![0; 32], 1, None, "hi".to_owned());
+        assert_eq!(relay.relay(message.clone())?, 1);
+        let received: Envelope = alice_rx
+            .recv()
+            .await
+            .ok_or(anyhow!("expected a message"))??
+            .envelope
+            .ok_or(anyhow!("missing envelope"))?
+            .try_into()?;
+        assert_eq!(received.payload, "hi");
+
+        // Tampering with a message invalidates it.
+        let forged = Envelope {
+            payload: "bye".to_owned(),
+            ..message
+        };
+        assert!(relay.relay(forged).is_err());
+        // Participants need to subscribe before sending messages.
+        let eve_message = Envelope::make(&eve, room, vec![0; 32], 1, None, "hi".to_owned());
+        assert!(relay.relay(eve_message).is_err());
+
+        // Only allowed participants can subscribe.
+        let relay = relay.with_participants([alice.verification_key()]);
+        assert!(relay
+            .register(Registration::make(&bob, vec![1; 32], SystemTime::now())?)
+            .is_err());
+        Ok(())
+    }
+}
@@ -36,6 +36,57 @@ impl ::prost::Name for TransactionsByHeightResponse {
         ::prost::alloc::format!("penumbra.core.app.v1alpha1.{}", Self::NAME)
     }
 }
+/// Requests a range of compact blocks, filtered with fuzzy message detection.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DetectedBlockRangeRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// The start height of the range.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+    /// The end height of the range, defaults to the latest block height.
+    #[prost(uint64, tag = "3")]
+    pub end_height: u64,
+    /// If set, keeps the connection alive past `end_height`,
+    /// streaming new blocks as they are created.
+    #[prost(bool, tag = "4")]
+    pub keep_alive: bool,
+    /// The 32-byte encodings of the detection keys used to examine transaction clues.
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub detection_keys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+impl ::prost::Name for DetectedBlockRangeRequest {
+    const NAME: &'static str = "DetectedBlockRangeRequest";
+    const PACKAGE: &'static str = "penumbra.core.app.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1alpha1.{}", Self::NAME)
+    }
+}
+/// A compact block filtered with fuzzy message detection.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DetectedBlockRangeResponse {
+    /// The compact block, where the note and swap payloads of transactions that were
+    /// not detected are rolled up into their state commitments.
+    #[prost(message, optional, tag = "1")]
+    pub compact_block: ::core::option::Option<
+        super::super::component::compact_block::v1alpha1::CompactBlock,
+    >,
+    /// The transactions in the block with at least one clue matching a detection key.
+    #[prost(message, repeated, tag = "2")]
+    pub transactions: ::prost::alloc::vec::Vec<
+        super::super::transaction::v1alpha1::Transaction,
+    >,
+}
+impl ::prost::Name for DetectedBlockRangeResponse {
+    const NAME: &'static str = "DetectedBlockRangeResponse";
+    const PACKAGE: &'static str = "penumbra.core.app.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1alpha1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AppParameters {
@@ -74,6 +125,11 @@ pub struct AppParameters {
     pub distributions_params: ::core::option::Option<
         super::super::component::distributions::v1alpha1::DistributionsParameters,
     >,
+    /// Dex module parameters.
+    #[prost(message, optional, tag = "8")]
+    pub dex_params: ::core::option::Option<
+        super::super::component::dex::v1alpha1::DexParameters,
+    >,
 }
 impl ::prost::Name for AppParameters {
     const NAME: &'static str = "AppParameters";
@@ -177,6 +233,11 @@ pub struct GenesisContent {
     pub distributions_content: ::core::option::Option<
         super::super::component::distributions::v1alpha1::GenesisContent,
     >,
+    /// Dex module genesis state.
+    #[prost(message, optional, tag = "9")]
+    pub dex_content: ::core::option::Option<
+        super::super::component::dex::v1alpha1::GenesisContent,
+    >,
 }
 impl ::prost::Name for GenesisContent {
     const NAME: &'static str = "GenesisContent";
@@ -334,6 +395,38 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Returns a stream of compact blocks filtered with fuzzy message detection,
+        /// along with the transactions detected in each of them.
+        pub async fn detected_block_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DetectedBlockRangeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::DetectedBlockRangeResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.app.v1alpha1.QueryService/DetectedBlockRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.app.v1alpha1.QueryService",
+                        "DetectedBlockRange",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -360,6 +453,24 @@ pub mod query_service_server {
             tonic::Response<super::TransactionsByHeightResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the DetectedBlockRange method.
+        type DetectedBlockRangeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::DetectedBlockRangeResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Returns a stream of compact blocks filtered with fuzzy message detection,
+        /// along with the transactions detected in each of them.
+        async fn detected_block_range(
+            &self,
+            request: tonic::Request<super::DetectedBlockRangeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::DetectedBlockRangeStream>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the overall Penumbra application.
     #[derive(Debug)]
@@ -534,6 +645,55 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.app.v1alpha1.QueryService/DetectedBlockRange" => {
+                    #[allow(non_camel_case_types)]
+                    struct DetectedBlockRangeSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::DetectedBlockRangeRequest,
+                    > for DetectedBlockRangeSvc<T> {
+                        type Response = super::DetectedBlockRangeResponse;
+                        type ResponseStream = T::DetectedBlockRangeStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DetectedBlockRangeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::detected_block_range(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DetectedBlockRangeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -27,6 +27,9 @@ impl serde::Serialize for AppParameters {
         if self.distributions_params.is_some() {
             len += 1;
         }
+        if self.dex_params.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1alpha1.AppParameters", len)?;
         if let Some(v) = self.chain_params.as_ref() {
             struct_ser.serialize_field("chainParams", v)?;
@@ -49,6 +52,9 @@ impl serde::Serialize for AppParameters {
         if let Some(v) = self.distributions_params.as_ref() {
             struct_ser.serialize_field("distributionsParams", v)?;
         }
+        if let Some(v) = self.dex_params.as_ref() {
+            struct_ser.serialize_field("dexParams", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -73,6 +79,8 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
             "feeParams",
             "distributions_params",
             "distributionsParams",
+            "dex_params",
+            "dexParams",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -84,6 +92,7 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
             StakeParams,
             FeeParams,
             DistributionsParams,
+            DexParams,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -112,6 +121,7 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
                             "stakeParams" | "stake_params" => Ok(GeneratedField::StakeParams),
                             "feeParams" | "fee_params" => Ok(GeneratedField::FeeParams),
                             "distributionsParams" | "distributions_params" => Ok(GeneratedField::DistributionsParams),
+                            "dexParams" | "dex_params" => Ok(GeneratedField::DexParams),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -138,6 +148,7 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
                 let mut stake_params__ = None;
                 let mut fee_params__ = None;
                 let mut distributions_params__ = None;
+                let mut dex_params__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ChainParams => {
@@ -182,6 +193,12 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
                             }
                             distributions_params__ = map_.next_value()?;
                         }
+                        GeneratedField::DexParams => {
+                            if dex_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexParams"));
+                            }
+                            dex_params__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(AppParameters {
@@ -192,6 +209,7 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
                     stake_params: stake_params__,
                     fee_params: fee_params__,
                     distributions_params: distributions_params__,
+                    dex_params: dex_params__,
                 })
             }
         }
@@ -382,6 +400,288 @@ impl<'de> serde::Deserialize<'de> for AppParametersResponse {
         deserializer.deserialize_struct("penumbra.core.app.v1alpha1.AppParametersResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for DetectedBlockRangeRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        if self.keep_alive {
+            len += 1;
+        }
+        if !self.detection_keys.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1alpha1.DetectedBlockRangeRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        if self.keep_alive {
+            struct_ser.serialize_field("keepAlive", &self.keep_alive)?;
+        }
+        if !self.detection_keys.is_empty() {
+            struct_ser.serialize_field("detectionKeys", &self.detection_keys.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DetectedBlockRangeRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+            "keep_alive",
+            "keepAlive",
+            "detection_keys",
+            "detectionKeys",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            StartHeight,
+            EndHeight,
+            KeepAlive,
+            DetectionKeys,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "keepAlive" | "keep_alive" => Ok(GeneratedField::KeepAlive),
+                            "detectionKeys" | "detection_keys" => Ok(GeneratedField::DetectionKeys),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DetectedBlockRangeRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1alpha1.DetectedBlockRangeRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DetectedBlockRangeRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                let mut keep_alive__ = None;
+                let mut detection_keys__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::KeepAlive => {
+                            if keep_alive__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keepAlive"));
+                            }
+                            keep_alive__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DetectionKeys => {
+                            if detection_keys__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("detectionKeys"));
+                            }
+                            detection_keys__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                    }
+                }
+                Ok(DetectedBlockRangeRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                    keep_alive: keep_alive__.unwrap_or_default(),
+                    detection_keys: detection_keys__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1alpha1.DetectedBlockRangeRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DetectedBlockRangeResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.compact_block.is_some() {
+            len += 1;
+        }
+        if !self.transactions.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1alpha1.DetectedBlockRangeResponse", len)?;
+        if let Some(v) = self.compact_block.as_ref() {
+            struct_ser.serialize_field("compactBlock", v)?;
+        }
+        if !self.transactions.is_empty() {
+            struct_ser.serialize_field("transactions", &self.transactions)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DetectedBlockRangeResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "compact_block",
+            "compactBlock",
+            "transactions",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CompactBlock,
+            Transactions,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "compactBlock" | "compact_block" => Ok(GeneratedField::CompactBlock),
+                            "transactions" => Ok(GeneratedField::Transactions),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DetectedBlockRangeResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1alpha1.DetectedBlockRangeResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DetectedBlockRangeResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut compact_block__ = None;
+                let mut transactions__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CompactBlock => {
+                            if compact_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("compactBlock"));
+                            }
+                            compact_block__ = map_.next_value()?;
+                        }
+                        GeneratedField::Transactions => {
+                            if transactions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactions"));
+                            }
+                            transactions__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(DetectedBlockRangeResponse {
+                    compact_block: compact_block__,
+                    transactions: transactions__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1alpha1.DetectedBlockRangeResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GenesisAppState {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -525,6 +825,9 @@ impl serde::Serialize for GenesisContent {
         if self.distributions_content.is_some() {
             len += 1;
         }
+        if self.dex_content.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1alpha1.GenesisContent", len)?;
         if let Some(v) = self.stake_content.as_ref() {
             struct_ser.serialize_field("stakeContent", v)?;
@@ -550,6 +853,9 @@ impl serde::Serialize for GenesisContent {
         if let Some(v) = self.distributions_content.as_ref() {
             struct_ser.serialize_field("distributionsContent", v)?;
         }
+        if let Some(v) = self.dex_content.as_ref() {
+            struct_ser.serialize_field("dexContent", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -576,6 +882,8 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
             "feeContent",
             "distributions_content",
             "distributionsContent",
+            "dex_content",
+            "dexContent",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -588,6 +896,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
             DaoContent,
             FeeContent,
             DistributionsContent,
+            DexContent,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -617,6 +926,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                             "daoContent" | "dao_content" => Ok(GeneratedField::DaoContent),
                             "feeContent" | "fee_content" => Ok(GeneratedField::FeeContent),
                             "distributionsContent" | "distributions_content" => Ok(GeneratedField::DistributionsContent),
+                            "dexContent" | "dex_content" => Ok(GeneratedField::DexContent),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -644,6 +954,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                 let mut dao_content__ = None;
                 let mut fee_content__ = None;
                 let mut distributions_content__ = None;
+                let mut dex_content__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::StakeContent => {
@@ -694,6 +1005,12 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                             }
                             distributions_content__ = map_.next_value()?;
                         }
+                        GeneratedField::DexContent => {
+                            if dex_content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexContent"));
+                            }
+                            dex_content__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(GenesisContent {
@@ -705,6 +1022,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                     dao_content: dao_content__,
                     fee_content: fee_content__,
                     distributions_content: distributions_content__,
+                    dex_content: dex_content__,
                 })
             }
         }
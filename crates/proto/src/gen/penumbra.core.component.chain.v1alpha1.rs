@@ -8,6 +8,17 @@ pub struct ChainParameters {
     /// The duration of each epoch, in number of blocks.
     #[prost(uint64, tag = "2")]
     pub epoch_duration: u64,
+    /// The number of false-positive detections each client should expect per epoch.
+    ///
+    /// The FMD precision is recomputed at the end of every epoch from the number of
+    /// clues observed during the epoch, so as to approach this target. Zero disables
+    /// the adjustment.
+    #[prost(uint64, tag = "3")]
+    pub fmd_target_false_positives_per_epoch: u64,
+    /// The number of blocks after an FMD parameter change during which clues created
+    /// with the previous parameters are still accepted.
+    #[prost(uint64, tag = "4")]
+    pub fmd_grace_period_blocks: u64,
 }
 impl ::prost::Name for ChainParameters {
     const NAME: &'static str = "ChainParameters";
@@ -38,8 +49,10 @@ impl ::prost::Name for Ratio {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FmdParameters {
+    /// Bits of precision of the clues.
     #[prost(uint32, tag = "1")]
     pub precision_bits: u32,
+    /// The block height at which these parameters became effective.
     #[prost(uint64, tag = "2")]
     pub as_of_block_height: u64,
 }
@@ -12,6 +12,12 @@ impl serde::Serialize for ChainParameters {
         if self.epoch_duration != 0 {
             len += 1;
         }
+        if self.fmd_target_false_positives_per_epoch != 0 {
+            len += 1;
+        }
+        if self.fmd_grace_period_blocks != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.chain.v1alpha1.ChainParameters", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -20,6 +26,14 @@ impl serde::Serialize for ChainParameters {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("epochDuration", ToString::to_string(&self.epoch_duration).as_str())?;
         }
+        if self.fmd_target_false_positives_per_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fmdTargetFalsePositivesPerEpoch", ToString::to_string(&self.fmd_target_false_positives_per_epoch).as_str())?;
+        }
+        if self.fmd_grace_period_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fmdGracePeriodBlocks", ToString::to_string(&self.fmd_grace_period_blocks).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -34,12 +48,18 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
             "chainId",
             "epoch_duration",
             "epochDuration",
+            "fmd_target_false_positives_per_epoch",
+            "fmdTargetFalsePositivesPerEpoch",
+            "fmd_grace_period_blocks",
+            "fmdGracePeriodBlocks",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
             EpochDuration,
+            FmdTargetFalsePositivesPerEpoch,
+            FmdGracePeriodBlocks,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -63,6 +83,8 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
                             "epochDuration" | "epoch_duration" => Ok(GeneratedField::EpochDuration),
+                            "fmdTargetFalsePositivesPerEpoch" | "fmd_target_false_positives_per_epoch" => Ok(GeneratedField::FmdTargetFalsePositivesPerEpoch),
+                            "fmdGracePeriodBlocks" | "fmd_grace_period_blocks" => Ok(GeneratedField::FmdGracePeriodBlocks),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -84,6 +106,8 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
             {
                 let mut chain_id__ = None;
                 let mut epoch_duration__ = None;
+                let mut fmd_target_false_positives_per_epoch__ = None;
+                let mut fmd_grace_period_blocks__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -100,11 +124,29 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::FmdTargetFalsePositivesPerEpoch => {
+                            if fmd_target_false_positives_per_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fmdTargetFalsePositivesPerEpoch"));
+                            }
+                            fmd_target_false_positives_per_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FmdGracePeriodBlocks => {
+                            if fmd_grace_period_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fmdGracePeriodBlocks"));
+                            }
+                            fmd_grace_period_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(ChainParameters {
                     chain_id: chain_id__.unwrap_or_default(),
                     epoch_duration: epoch_duration__.unwrap_or_default(),
+                    fmd_target_false_positives_per_epoch: fmd_target_false_positives_per_epoch__.unwrap_or_default(),
+                    fmd_grace_period_blocks: fmd_grace_period_blocks__.unwrap_or_default(),
                 })
             }
         }
@@ -123,6 +123,12 @@ pub struct SwapBody {
     /// The swap commitment and encryption of the swap data.
     #[prost(message, optional, tag = "5")]
     pub payload: ::core::option::Option<SwapPayload>,
+    /// The minimum amount of the other asset the swap must output.
+    ///
+    /// If the batch would output less than this, the swap's input is refunded
+    /// at claim time instead.
+    #[prost(message, optional, tag = "6")]
+    pub min_output: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
 }
 impl ::prost::Name for SwapBody {
     const NAME: &'static str = "SwapBody";
@@ -171,6 +177,9 @@ pub struct SwapPlaintext {
     /// Swap rseed (blinding factors are derived from this)
     #[prost(bytes = "vec", tag = "6")]
     pub rseed: ::prost::alloc::vec::Vec<u8>,
+    /// The minimum amount of the other asset the swap must output, or zero if unlimited.
+    #[prost(message, optional, tag = "7")]
+    pub min_output: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
 }
 impl ::prost::Name for SwapPlaintext {
     const NAME: &'static str = "SwapPlaintext";
@@ -422,6 +431,18 @@ pub struct BatchSwapOutputData {
     /// The starting block height of the epoch for which the batch swap data is valid.
     #[prost(uint64, tag = "9")]
     pub epoch_starting_height: u64,
+    /// The highest price, in asset 2 per unit of asset 1, demanded by the minimum output of a
+    /// 1=>2 trade that was honored. Trades demanding more had their input refunded.
+    ///
+    /// Encoded as a 32-byte big-endian U128x128 fixed-point number; empty means zero.
+    #[prost(bytes = "vec", tag = "10")]
+    pub limit_price_1: ::prost::alloc::vec::Vec<u8>,
+    /// The highest price, in asset 1 per unit of asset 2, demanded by the minimum output of a
+    /// 2=>1 trade that was honored. Trades demanding more had their input refunded.
+    ///
+    /// Encoded as a 32-byte big-endian U128x128 fixed-point number; empty means zero.
+    #[prost(bytes = "vec", tag = "11")]
+    pub limit_price_2: ::prost::alloc::vec::Vec<u8>,
 }
 impl ::prost::Name for BatchSwapOutputData {
     const NAME: &'static str = "BatchSwapOutputData";
@@ -804,8 +825,12 @@ impl ::prost::Name for PositionWithdrawPlan {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PositionRewardClaimPlan {
-    #[prost(message, optional, tag = "1")]
-    pub reserves: ::core::option::Option<Reserves>,
+    /// The position to claim rewards for.
+    #[prost(message, optional, tag = "2")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// The rewards accrued to the position, in the staking token.
+    #[prost(message, optional, tag = "3")]
+    pub rewards: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
 }
 impl ::prost::Name for PositionRewardClaimPlan {
     const NAME: &'static str = "PositionRewardClaimPlan";
@@ -1044,6 +1069,36 @@ impl ::prost::Name for LiquidityPositionByIdResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityPositionRewardsRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub position_id: ::core::option::Option<PositionId>,
+}
+impl ::prost::Name for LiquidityPositionRewardsRequest {
+    const NAME: &'static str = "LiquidityPositionRewardsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityPositionRewardsResponse {
+    /// The rewards the position can claim once it has been withdrawn.
+    #[prost(message, optional, tag = "1")]
+    pub rewards: ::core::option::Option<super::super::super::asset::v1alpha1::Value>,
+}
+impl ::prost::Name for LiquidityPositionRewardsResponse {
+    const NAME: &'static str = "LiquidityPositionRewardsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LiquidityPositionsByIdRequest {
     /// The expected chain id (empty string if no expectation).
     #[prost(string, tag = "1")]
@@ -1229,6 +1284,53 @@ impl ::prost::Name for SimulateTradeResponse {
         ::prost::alloc::format!("penumbra.core.component.dex.v1alpha1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DexParameters {
+    /// The assets always considered as intermediate hops when routing trades.
+    #[prost(message, repeated, tag = "1")]
+    pub fixed_candidates: ::prost::alloc::vec::Vec<
+        super::super::super::asset::v1alpha1::AssetId,
+    >,
+    /// The maximum number of hops in a route found by the router.
+    #[prost(uint32, tag = "2")]
+    pub max_hops: u32,
+    /// Whether arbitrage is executed at the end of each block.
+    #[prost(bool, tag = "3")]
+    pub arbitrage_enabled: bool,
+    /// The assets considered as intermediate hops when searching for arbitrage.
+    #[prost(message, repeated, tag = "4")]
+    pub arbitrage_candidates: ::prost::alloc::vec::Vec<
+        super::super::super::asset::v1alpha1::AssetId,
+    >,
+    /// The amount of the staking token issued each epoch as liquidity incentives,
+    /// split among positions in proportion to the fees they earned in that epoch.
+    #[prost(message, optional, tag = "5")]
+    pub lp_rewards_per_epoch: ::core::option::Option<
+        super::super::super::num::v1alpha1::Amount,
+    >,
+}
+impl ::prost::Name for DexParameters {
+    const NAME: &'static str = "DexParameters";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1alpha1.{}", Self::NAME)
+    }
+}
+/// Genesis data for the dex module.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenesisContent {
+    #[prost(message, optional, tag = "1")]
+    pub dex_params: ::core::option::Option<DexParameters>,
+}
+impl ::prost::Name for GenesisContent {
+    const NAME: &'static str = "GenesisContent";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1alpha1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -1601,6 +1703,37 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Query the liquidity incentive rewards a position can claim.
+        pub async fn liquidity_position_rewards(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LiquidityPositionRewardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LiquidityPositionRewardsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1alpha1.QueryService/LiquidityPositionRewards",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1alpha1.QueryService",
+                        "LiquidityPositionRewards",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// Get the current (direct) spread on a trading pair.
         ///
         /// This method doesn't do simulation, so actually executing might result in a
@@ -1879,6 +2012,14 @@ pub mod query_service_server {
             tonic::Response<Self::LiquidityPositionsByPriceStream>,
             tonic::Status,
         >;
+        /// Query the liquidity incentive rewards a position can claim.
+        async fn liquidity_position_rewards(
+            &self,
+            request: tonic::Request<super::LiquidityPositionRewardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LiquidityPositionRewardsResponse>,
+            tonic::Status,
+        >;
         /// Get the current (direct) spread on a trading pair.
         ///
         /// This method doesn't do simulation, so actually executing might result in a
@@ -2406,6 +2547,58 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.dex.v1alpha1.QueryService/LiquidityPositionRewards" => {
+                    #[allow(non_camel_case_types)]
+                    struct LiquidityPositionRewardsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::LiquidityPositionRewardsRequest>
+                    for LiquidityPositionRewardsSvc<T> {
+                        type Response = super::LiquidityPositionRewardsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::LiquidityPositionRewardsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::liquidity_position_rewards(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = LiquidityPositionRewardsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.core.component.dex.v1alpha1.QueryService/Spread" => {
                     #[allow(non_camel_case_types)]
                     struct SpreadSvc<T: QueryService>(pub Arc<T>);
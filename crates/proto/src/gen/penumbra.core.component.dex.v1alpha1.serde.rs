@@ -630,6 +630,12 @@ impl serde::Serialize for BatchSwapOutputData {
         if self.epoch_starting_height != 0 {
             len += 1;
         }
+        if !self.limit_price_1.is_empty() {
+            len += 1;
+        }
+        if !self.limit_price_2.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1alpha1.BatchSwapOutputData", len)?;
         if let Some(v) = self.delta_1.as_ref() {
             struct_ser.serialize_field("delta1", v)?;
@@ -660,6 +666,14 @@ impl serde::Serialize for BatchSwapOutputData {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("epochStartingHeight", ToString::to_string(&self.epoch_starting_height).as_str())?;
         }
+        if !self.limit_price_1.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("limitPrice1", pbjson::private::base64::encode(&self.limit_price_1).as_str())?;
+        }
+        if !self.limit_price_2.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("limitPrice2", pbjson::private::base64::encode(&self.limit_price_2).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -687,6 +701,10 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
             "tradingPair",
             "epoch_starting_height",
             "epochStartingHeight",
+            "limit_price_1",
+            "limitPrice1",
+            "limit_price_2",
+            "limitPrice2",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -700,6 +718,8 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
             Height,
             TradingPair,
             EpochStartingHeight,
+            LimitPrice1,
+            LimitPrice2,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -730,6 +750,8 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
                             "height" => Ok(GeneratedField::Height),
                             "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             "epochStartingHeight" | "epoch_starting_height" => Ok(GeneratedField::EpochStartingHeight),
+                            "limitPrice1" | "limit_price_1" => Ok(GeneratedField::LimitPrice1),
+                            "limitPrice2" | "limit_price_2" => Ok(GeneratedField::LimitPrice2),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -758,6 +780,8 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
                 let mut height__ = None;
                 let mut trading_pair__ = None;
                 let mut epoch_starting_height__ = None;
+                let mut limit_price_1__ = None;
+                let mut limit_price_2__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Delta1 => {
@@ -818,6 +842,22 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::LimitPrice1 => {
+                            if limit_price_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limitPrice1"));
+                            }
+                            limit_price_1__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LimitPrice2 => {
+                            if limit_price_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limitPrice2"));
+                            }
+                            limit_price_2__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(BatchSwapOutputData {
@@ -830,6 +870,8 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputData {
                     height: height__.unwrap_or_default(),
                     trading_pair: trading_pair__,
                     epoch_starting_height: epoch_starting_height__.unwrap_or_default(),
+                    limit_price_1: limit_price_1__.unwrap_or_default(),
+                    limit_price_2: limit_price_2__.unwrap_or_default(),
                 })
             }
         }
@@ -1057,6 +1099,172 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputDataResponse {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1alpha1.BatchSwapOutputDataResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for DexParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.fixed_candidates.is_empty() {
+            len += 1;
+        }
+        if self.max_hops != 0 {
+            len += 1;
+        }
+        if self.arbitrage_enabled {
+            len += 1;
+        }
+        if !self.arbitrage_candidates.is_empty() {
+            len += 1;
+        }
+        if self.lp_rewards_per_epoch.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1alpha1.DexParameters", len)?;
+        if !self.fixed_candidates.is_empty() {
+            struct_ser.serialize_field("fixedCandidates", &self.fixed_candidates)?;
+        }
+        if self.max_hops != 0 {
+            struct_ser.serialize_field("maxHops", &self.max_hops)?;
+        }
+        if self.arbitrage_enabled {
+            struct_ser.serialize_field("arbitrageEnabled", &self.arbitrage_enabled)?;
+        }
+        if !self.arbitrage_candidates.is_empty() {
+            struct_ser.serialize_field("arbitrageCandidates", &self.arbitrage_candidates)?;
+        }
+        if let Some(v) = self.lp_rewards_per_epoch.as_ref() {
+            struct_ser.serialize_field("lpRewardsPerEpoch", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DexParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "fixed_candidates",
+            "fixedCandidates",
+            "max_hops",
+            "maxHops",
+            "arbitrage_enabled",
+            "arbitrageEnabled",
+            "arbitrage_candidates",
+            "arbitrageCandidates",
+            "lp_rewards_per_epoch",
+            "lpRewardsPerEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FixedCandidates,
+            MaxHops,
+            ArbitrageEnabled,
+            ArbitrageCandidates,
+            LpRewardsPerEpoch,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fixedCandidates" | "fixed_candidates" => Ok(GeneratedField::FixedCandidates),
+                            "maxHops" | "max_hops" => Ok(GeneratedField::MaxHops),
+                            "arbitrageEnabled" | "arbitrage_enabled" => Ok(GeneratedField::ArbitrageEnabled),
+                            "arbitrageCandidates" | "arbitrage_candidates" => Ok(GeneratedField::ArbitrageCandidates),
+                            "lpRewardsPerEpoch" | "lp_rewards_per_epoch" => Ok(GeneratedField::LpRewardsPerEpoch),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DexParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1alpha1.DexParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DexParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut fixed_candidates__ = None;
+                let mut max_hops__ = None;
+                let mut arbitrage_enabled__ = None;
+                let mut arbitrage_candidates__ = None;
+                let mut lp_rewards_per_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FixedCandidates => {
+                            if fixed_candidates__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fixedCandidates"));
+                            }
+                            fixed_candidates__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MaxHops => {
+                            if max_hops__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxHops"));
+                            }
+                            max_hops__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ArbitrageEnabled => {
+                            if arbitrage_enabled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("arbitrageEnabled"));
+                            }
+                            arbitrage_enabled__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ArbitrageCandidates => {
+                            if arbitrage_candidates__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("arbitrageCandidates"));
+                            }
+                            arbitrage_candidates__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LpRewardsPerEpoch => {
+                            if lp_rewards_per_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lpRewardsPerEpoch"));
+                            }
+                            lp_rewards_per_epoch__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(DexParameters {
+                    fixed_candidates: fixed_candidates__.unwrap_or_default(),
+                    max_hops: max_hops__.unwrap_or_default(),
+                    arbitrage_enabled: arbitrage_enabled__.unwrap_or_default(),
+                    arbitrage_candidates: arbitrage_candidates__.unwrap_or_default(),
+                    lp_rewards_per_epoch: lp_rewards_per_epoch__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1alpha1.DexParameters", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for DirectedTradingPair {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1165,6 +1373,98 @@ impl<'de> serde::Deserialize<'de> for DirectedTradingPair {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1alpha1.DirectedTradingPair", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for GenesisContent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.dex_params.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1alpha1.GenesisContent", len)?;
+        if let Some(v) = self.dex_params.as_ref() {
+            struct_ser.serialize_field("dexParams", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GenesisContent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "dex_params",
+            "dexParams",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DexParams,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "dexParams" | "dex_params" => Ok(GeneratedField::DexParams),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GenesisContent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1alpha1.GenesisContent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut dex_params__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::DexParams => {
+                            if dex_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexParams"));
+                            }
+                            dex_params__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(GenesisContent {
+                    dex_params: dex_params__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1alpha1.GenesisContent", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for LiquidityPositionByIdRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1366,6 +1666,207 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1alpha1.LiquidityPositionByIdResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for LiquidityPositionRewardsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1alpha1.LiquidityPositionRewardsRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionRewardsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            PositionId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionRewardsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1alpha1.LiquidityPositionRewardsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionRewardsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionRewardsRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1alpha1.LiquidityPositionRewardsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionRewardsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rewards.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1alpha1.LiquidityPositionRewardsResponse", len)?;
+        if let Some(v) = self.rewards.as_ref() {
+            struct_ser.serialize_field("rewards", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionRewardsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rewards",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Rewards,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rewards" => Ok(GeneratedField::Rewards),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionRewardsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1alpha1.LiquidityPositionRewardsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionRewardsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rewards__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionRewardsResponse {
+                    rewards: rewards__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1alpha1.LiquidityPositionRewardsResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for LiquidityPositionsByIdRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2674,12 +3175,18 @@ impl serde::Serialize for PositionRewardClaimPlan {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.reserves.is_some() {
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.rewards.is_some() {
             len += 1;
         }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1alpha1.PositionRewardClaimPlan", len)?;
-        if let Some(v) = self.reserves.as_ref() {
-            struct_ser.serialize_field("reserves", v)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.rewards.as_ref() {
+            struct_ser.serialize_field("rewards", v)?;
         }
         struct_ser.end()
     }
@@ -2691,12 +3198,15 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "reserves",
+            "position_id",
+            "positionId",
+            "rewards",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Reserves,
+            PositionId,
+            Rewards,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2718,7 +3228,8 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
                         E: serde::de::Error,
                     {
                         match value {
-                            "reserves" => Ok(GeneratedField::Reserves),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "rewards" => Ok(GeneratedField::Rewards),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2738,19 +3249,27 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut reserves__ = None;
+                let mut position_id__ = None;
+                let mut rewards__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            reserves__ = map_.next_value()?;
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = map_.next_value()?;
                         }
                     }
                 }
                 Ok(PositionRewardClaimPlan {
-                    reserves: reserves__,
+                    position_id: position_id__,
+                    rewards: rewards__,
                 })
             }
         }
@@ -4133,6 +4652,9 @@ impl serde::Serialize for SwapBody {
         if self.payload.is_some() {
             len += 1;
         }
+        if self.min_output.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1alpha1.SwapBody", len)?;
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
@@ -4149,6 +4671,9 @@ impl serde::Serialize for SwapBody {
         if let Some(v) = self.payload.as_ref() {
             struct_ser.serialize_field("payload", v)?;
         }
+        if let Some(v) = self.min_output.as_ref() {
+            struct_ser.serialize_field("minOutput", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -4168,6 +4693,8 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
             "fee_commitment",
             "feeCommitment",
             "payload",
+            "min_output",
+            "minOutput",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -4177,6 +4704,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
             Delta2I,
             FeeCommitment,
             Payload,
+            MinOutput,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4203,6 +4731,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                             "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
                             "feeCommitment" | "fee_commitment" => Ok(GeneratedField::FeeCommitment),
                             "payload" => Ok(GeneratedField::Payload),
+                            "minOutput" | "min_output" => Ok(GeneratedField::MinOutput),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4227,6 +4756,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                 let mut delta_2_i__ = None;
                 let mut fee_commitment__ = None;
                 let mut payload__ = None;
+                let mut min_output__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::TradingPair => {
@@ -4259,6 +4789,12 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                             }
                             payload__ = map_.next_value()?;
                         }
+                        GeneratedField::MinOutput => {
+                            if min_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minOutput"));
+                            }
+                            min_output__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(SwapBody {
@@ -4267,6 +4803,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                     delta_2_i: delta_2_i__,
                     fee_commitment: fee_commitment__,
                     payload: payload__,
+                    min_output: min_output__,
                 })
             }
         }
@@ -5944,6 +6481,9 @@ impl serde::Serialize for SwapPlaintext {
         if !self.rseed.is_empty() {
             len += 1;
         }
+        if self.min_output.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1alpha1.SwapPlaintext", len)?;
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
@@ -5964,6 +6504,9 @@ impl serde::Serialize for SwapPlaintext {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("rseed", pbjson::private::base64::encode(&self.rseed).as_str())?;
         }
+        if let Some(v) = self.min_output.as_ref() {
+            struct_ser.serialize_field("minOutput", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -5985,6 +6528,8 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
             "claim_address",
             "claimAddress",
             "rseed",
+            "min_output",
+            "minOutput",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -5995,6 +6540,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
             ClaimFee,
             ClaimAddress,
             Rseed,
+            MinOutput,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -6022,6 +6568,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
                             "claimFee" | "claim_fee" => Ok(GeneratedField::ClaimFee),
                             "claimAddress" | "claim_address" => Ok(GeneratedField::ClaimAddress),
                             "rseed" => Ok(GeneratedField::Rseed),
+                            "minOutput" | "min_output" => Ok(GeneratedField::MinOutput),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -6047,6 +6594,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
                 let mut claim_fee__ = None;
                 let mut claim_address__ = None;
                 let mut rseed__ = None;
+                let mut min_output__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::TradingPair => {
@@ -6087,6 +6635,12 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
                                 Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::MinOutput => {
+                            if min_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minOutput"));
+                            }
+                            min_output__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(SwapPlaintext {
@@ -6096,6 +6650,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlaintext {
                     claim_fee: claim_fee__,
                     claim_address: claim_address__,
                     rseed: rseed__.unwrap_or_default(),
+                    min_output: min_output__,
                 })
             }
         }
@@ -20,16 +20,20 @@ impl ::prost::Name for Fee {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GasPrices {
-    /// The price per unit block space in terms of the staking token, with an implicit 1,000 denominator.
+    /// The asset ID of the fee token these prices are denominated in.
+    /// If absent, specifies the staking token implicitly.
+    #[prost(message, optional, tag = "5")]
+    pub asset_id: ::core::option::Option<super::super::super::asset::v1alpha1::AssetId>,
+    /// The price per unit block space in terms of the fee token, with an implicit 1,000 denominator.
     #[prost(uint64, tag = "1")]
     pub block_space_price: u64,
-    /// The price per unit compact block space in terms of the staking token, with an implicit 1,000 denominator.
+    /// The price per unit compact block space in terms of the fee token, with an implicit 1,000 denominator.
     #[prost(uint64, tag = "2")]
     pub compact_block_space_price: u64,
-    /// The price per unit verification cost in terms of the staking token, with an implicit 1,000 denominator.
+    /// The price per unit verification cost in terms of the fee token, with an implicit 1,000 denominator.
     #[prost(uint64, tag = "3")]
     pub verification_price: u64,
-    /// The price per unit execution cost in terms of the staking token, with an implicit 1,000 denominator.
+    /// The price per unit execution cost in terms of the fee token, with an implicit 1,000 denominator.
     #[prost(uint64, tag = "4")]
     pub execution_price: u64,
 }
@@ -43,7 +47,26 @@ impl ::prost::Name for GasPrices {
 /// Fee component configuration data.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct FeeParameters {}
+pub struct FeeParameters {
+    /// The adjustment parameters for the block space gas price.
+    #[prost(message, optional, tag = "1")]
+    pub block_space: ::core::option::Option<GasPriceAdjustment>,
+    /// The adjustment parameters for the compact block space gas price.
+    #[prost(message, optional, tag = "2")]
+    pub compact_block_space: ::core::option::Option<GasPriceAdjustment>,
+    /// The adjustment parameters for the verification gas price.
+    #[prost(message, optional, tag = "3")]
+    pub verification: ::core::option::Option<GasPriceAdjustment>,
+    /// The adjustment parameters for the execution gas price.
+    #[prost(message, optional, tag = "4")]
+    pub execution: ::core::option::Option<GasPriceAdjustment>,
+    /// The gas prices of the alternative fee assets approved by governance, which
+    /// transactions can use to pay fees instead of the staking token.
+    ///
+    /// Unlike the staking token gas prices, these are fixed until governance changes them.
+    #[prost(message, repeated, tag = "5")]
+    pub alt_gas_prices: ::prost::alloc::vec::Vec<GasPrices>,
+}
 impl ::prost::Name for FeeParameters {
     const NAME: &'static str = "FeeParameters";
     const PACKAGE: &'static str = "penumbra.core.component.fee.v1alpha1";
@@ -51,6 +74,34 @@ impl ::prost::Name for FeeParameters {
         ::prost::alloc::format!("penumbra.core.component.fee.v1alpha1.{}", Self::NAME)
     }
 }
+/// Controls how the gas price of a single resource dimension moves from block to block.
+///
+/// At the end of every block, the price is moved towards the value that would have
+/// made the gas used in that block equal to the target, by at most 1/`adjustment_denominator`
+/// of the current price, and then clamped to `\[min_price, max_price\]`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GasPriceAdjustment {
+    /// The target amount of gas used per block. A target of 0 disables adjustment of this price.
+    #[prost(uint64, tag = "1")]
+    pub target_per_block: u64,
+    /// The lowest price the adjustment can reach, with an implicit 1,000 denominator.
+    #[prost(uint64, tag = "2")]
+    pub min_price: u64,
+    /// The highest price the adjustment can reach, with an implicit 1,000 denominator.
+    #[prost(uint64, tag = "3")]
+    pub max_price: u64,
+    /// The inverse of the maximum fractional change of the price in a single block.
+    #[prost(uint64, tag = "4")]
+    pub adjustment_denominator: u64,
+}
+impl ::prost::Name for GasPriceAdjustment {
+    const NAME: &'static str = "GasPriceAdjustment";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1alpha1.{}", Self::NAME)
+    }
+}
 /// Fee-specific genesis content.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
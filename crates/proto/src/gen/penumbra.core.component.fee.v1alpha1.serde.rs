@@ -114,8 +114,38 @@ impl serde::Serialize for FeeParameters {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1alpha1.FeeParameters", len)?;
+        let mut len = 0;
+        if self.block_space.is_some() {
+            len += 1;
+        }
+        if self.compact_block_space.is_some() {
+            len += 1;
+        }
+        if self.verification.is_some() {
+            len += 1;
+        }
+        if self.execution.is_some() {
+            len += 1;
+        }
+        if !self.alt_gas_prices.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1alpha1.FeeParameters", len)?;
+        if let Some(v) = self.block_space.as_ref() {
+            struct_ser.serialize_field("blockSpace", v)?;
+        }
+        if let Some(v) = self.compact_block_space.as_ref() {
+            struct_ser.serialize_field("compactBlockSpace", v)?;
+        }
+        if let Some(v) = self.verification.as_ref() {
+            struct_ser.serialize_field("verification", v)?;
+        }
+        if let Some(v) = self.execution.as_ref() {
+            struct_ser.serialize_field("execution", v)?;
+        }
+        if !self.alt_gas_prices.is_empty() {
+            struct_ser.serialize_field("altGasPrices", &self.alt_gas_prices)?;
+        }
         struct_ser.end()
     }
 }
@@ -126,10 +156,23 @@ impl<'de> serde::Deserialize<'de> for FeeParameters {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "block_space",
+            "blockSpace",
+            "compact_block_space",
+            "compactBlockSpace",
+            "verification",
+            "execution",
+            "alt_gas_prices",
+            "altGasPrices",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            BlockSpace,
+            CompactBlockSpace,
+            Verification,
+            Execution,
+            AltGasPrices,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -150,7 +193,14 @@ impl<'de> serde::Deserialize<'de> for FeeParameters {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "blockSpace" | "block_space" => Ok(GeneratedField::BlockSpace),
+                            "compactBlockSpace" | "compact_block_space" => Ok(GeneratedField::CompactBlockSpace),
+                            "verification" => Ok(GeneratedField::Verification),
+                            "execution" => Ok(GeneratedField::Execution),
+                            "altGasPrices" | "alt_gas_prices" => Ok(GeneratedField::AltGasPrices),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -168,16 +218,215 @@ impl<'de> serde::Deserialize<'de> for FeeParameters {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut block_space__ = None;
+                let mut compact_block_space__ = None;
+                let mut verification__ = None;
+                let mut execution__ = None;
+                let mut alt_gas_prices__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BlockSpace => {
+                            if block_space__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockSpace"));
+                            }
+                            block_space__ = map_.next_value()?;
+                        }
+                        GeneratedField::CompactBlockSpace => {
+                            if compact_block_space__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("compactBlockSpace"));
+                            }
+                            compact_block_space__ = map_.next_value()?;
+                        }
+                        GeneratedField::Verification => {
+                            if verification__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("verification"));
+                            }
+                            verification__ = map_.next_value()?;
+                        }
+                        GeneratedField::Execution => {
+                            if execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("execution"));
+                            }
+                            execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::AltGasPrices => {
+                            if alt_gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("altGasPrices"));
+                            }
+                            alt_gas_prices__ = Some(map_.next_value()?);
+                        }
+                    }
                 }
                 Ok(FeeParameters {
+                    block_space: block_space__,
+                    compact_block_space: compact_block_space__,
+                    verification: verification__,
+                    execution: execution__,
+                    alt_gas_prices: alt_gas_prices__.unwrap_or_default(),
                 })
             }
         }
         deserializer.deserialize_struct("penumbra.core.component.fee.v1alpha1.FeeParameters", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for GasPriceAdjustment {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.target_per_block != 0 {
+            len += 1;
+        }
+        if self.min_price != 0 {
+            len += 1;
+        }
+        if self.max_price != 0 {
+            len += 1;
+        }
+        if self.adjustment_denominator != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1alpha1.GasPriceAdjustment", len)?;
+        if self.target_per_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("targetPerBlock", ToString::to_string(&self.target_per_block).as_str())?;
+        }
+        if self.min_price != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("minPrice", ToString::to_string(&self.min_price).as_str())?;
+        }
+        if self.max_price != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("maxPrice", ToString::to_string(&self.max_price).as_str())?;
+        }
+        if self.adjustment_denominator != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("adjustmentDenominator", ToString::to_string(&self.adjustment_denominator).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GasPriceAdjustment {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "target_per_block",
+            "targetPerBlock",
+            "min_price",
+            "minPrice",
+            "max_price",
+            "maxPrice",
+            "adjustment_denominator",
+            "adjustmentDenominator",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TargetPerBlock,
+            MinPrice,
+            MaxPrice,
+            AdjustmentDenominator,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "targetPerBlock" | "target_per_block" => Ok(GeneratedField::TargetPerBlock),
+                            "minPrice" | "min_price" => Ok(GeneratedField::MinPrice),
+                            "maxPrice" | "max_price" => Ok(GeneratedField::MaxPrice),
+                            "adjustmentDenominator" | "adjustment_denominator" => Ok(GeneratedField::AdjustmentDenominator),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GasPriceAdjustment;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1alpha1.GasPriceAdjustment")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GasPriceAdjustment, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut target_per_block__ = None;
+                let mut min_price__ = None;
+                let mut max_price__ = None;
+                let mut adjustment_denominator__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TargetPerBlock => {
+                            if target_per_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("targetPerBlock"));
+                            }
+                            target_per_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MinPrice => {
+                            if min_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minPrice"));
+                            }
+                            min_price__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxPrice => {
+                            if max_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxPrice"));
+                            }
+                            max_price__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AdjustmentDenominator => {
+                            if adjustment_denominator__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("adjustmentDenominator"));
+                            }
+                            adjustment_denominator__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(GasPriceAdjustment {
+                    target_per_block: target_per_block__.unwrap_or_default(),
+                    min_price: min_price__.unwrap_or_default(),
+                    max_price: max_price__.unwrap_or_default(),
+                    adjustment_denominator: adjustment_denominator__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1alpha1.GasPriceAdjustment", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GasPrices {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -186,6 +435,9 @@ impl serde::Serialize for GasPrices {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
+        if self.asset_id.is_some() {
+            len += 1;
+        }
         if self.block_space_price != 0 {
             len += 1;
         }
@@ -199,6 +451,9 @@ impl serde::Serialize for GasPrices {
             len += 1;
         }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1alpha1.GasPrices", len)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
         if self.block_space_price != 0 {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("blockSpacePrice", ToString::to_string(&self.block_space_price).as_str())?;
@@ -225,6 +480,8 @@ impl<'de> serde::Deserialize<'de> for GasPrices {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "asset_id",
+            "assetId",
             "block_space_price",
             "blockSpacePrice",
             "compact_block_space_price",
@@ -237,6 +494,7 @@ impl<'de> serde::Deserialize<'de> for GasPrices {
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            AssetId,
             BlockSpacePrice,
             CompactBlockSpacePrice,
             VerificationPrice,
@@ -262,6 +520,7 @@ impl<'de> serde::Deserialize<'de> for GasPrices {
                         E: serde::de::Error,
                     {
                         match value {
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
                             "blockSpacePrice" | "block_space_price" => Ok(GeneratedField::BlockSpacePrice),
                             "compactBlockSpacePrice" | "compact_block_space_price" => Ok(GeneratedField::CompactBlockSpacePrice),
                             "verificationPrice" | "verification_price" => Ok(GeneratedField::VerificationPrice),
@@ -285,12 +544,19 @@ impl<'de> serde::Deserialize<'de> for GasPrices {
                 where
                     V: serde::de::MapAccess<'de>,
             {
+                let mut asset_id__ = None;
                 let mut block_space_price__ = None;
                 let mut compact_block_space_price__ = None;
                 let mut verification_price__ = None;
                 let mut execution_price__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
                         GeneratedField::BlockSpacePrice => {
                             if block_space_price__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("blockSpacePrice"));
@@ -326,6 +592,7 @@ impl<'de> serde::Deserialize<'de> for GasPrices {
                     }
                 }
                 Ok(GasPrices {
+                    asset_id: asset_id__,
                     block_space_price: block_space_price__.unwrap_or_default(),
                     compact_block_space_price: compact_block_space_price__.unwrap_or_default(),
                     verification_price: verification_price__.unwrap_or_default(),
@@ -667,6 +667,8 @@ pub struct Proposal {
     pub dao_spend: ::core::option::Option<proposal::DaoSpend>,
     #[prost(message, optional, tag = "9")]
     pub upgrade_plan: ::core::option::Option<proposal::UpgradePlan>,
+    #[prost(message, optional, tag = "10")]
+    pub ibc_client_recovery: ::core::option::Option<proposal::IbcClientRecovery>,
 }
 /// Nested message and enum types in `Proposal`.
 pub mod proposal {
@@ -764,6 +766,9 @@ pub mod proposal {
     pub struct UpgradePlan {
         #[prost(uint64, tag = "1")]
         pub height: u64,
+        /// The name of the migration to apply to the chain state once it has halted.
+        #[prost(string, tag = "2")]
+        pub name: ::prost::alloc::string::String,
     }
     impl ::prost::Name for UpgradePlan {
         const NAME: &'static str = "UpgradePlan";
@@ -774,6 +779,28 @@ pub mod proposal {
             )
         }
     }
+    /// An IBC client recovery proposal replaces the state of a frozen or expired IBC client (the
+    /// subject) with that of an active client tracking the same counterparty chain (the substitute),
+    /// following the semantics of ibc-go's `MsgRecoverClient`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct IbcClientRecovery {
+        /// The client to be recovered, which must be frozen or expired.
+        #[prost(string, tag = "1")]
+        pub subject_client_id: ::prost::alloc::string::String,
+        /// The client whose state is substituted into the subject, which must be active.
+        #[prost(string, tag = "2")]
+        pub substitute_client_id: ::prost::alloc::string::String,
+    }
+    impl ::prost::Name for IbcClientRecovery {
+        const NAME: &'static str = "IbcClientRecovery";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.governance.v1alpha1.Proposal.{}", Self::NAME
+            )
+        }
+    }
 }
 impl ::prost::Name for Proposal {
     const NAME: &'static str = "Proposal";
@@ -1007,25 +1034,44 @@ impl ::prost::Name for ValidatorVotesResponse {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GovernanceParameters {
-    /// The number of blocks during which a proposal is voted on.
+    /// DEPRECATED: use the per-kind parameters instead.
+    ///
+    /// These fields are only read when the per-kind parameters are absent, so that
+    /// parameters stored before they were introduced apply to every kind of proposal.
     #[prost(uint64, tag = "1")]
     pub proposal_voting_blocks: u64,
-    /// The deposit required to create a proposal.
+    /// DEPRECATED: use the per-kind parameters instead.
     #[prost(message, optional, tag = "2")]
     pub proposal_deposit_amount: ::core::option::Option<
         super::super::super::num::v1alpha1::Amount,
     >,
-    /// The quorum required for a proposal to be considered valid, as a fraction of the total stake
-    /// weight of the network.
+    /// DEPRECATED: use the per-kind parameters instead.
     #[prost(string, tag = "3")]
     pub proposal_valid_quorum: ::prost::alloc::string::String,
-    /// The threshold for a proposal to pass voting, as a ratio of "yes" votes over "no" votes.
+    /// DEPRECATED: use the per-kind parameters instead.
     #[prost(string, tag = "4")]
     pub proposal_pass_threshold: ::prost::alloc::string::String,
-    /// The threshold for a proposal to be slashed, regardless of whether the "yes" and "no" votes
-    /// would have passed it, as a ratio of "no" votes over all total votes.
+    /// DEPRECATED: use the per-kind parameters instead.
     #[prost(string, tag = "5")]
     pub proposal_slash_threshold: ::prost::alloc::string::String,
+    /// The parameters for signaling proposals.
+    #[prost(message, optional, tag = "6")]
+    pub signaling_params: ::core::option::Option<ProposalParameters>,
+    /// The parameters for emergency proposals.
+    #[prost(message, optional, tag = "7")]
+    pub emergency_params: ::core::option::Option<ProposalParameters>,
+    /// The parameters for parameter change proposals.
+    #[prost(message, optional, tag = "8")]
+    pub parameter_change_params: ::core::option::Option<ProposalParameters>,
+    /// The parameters for DAO spend proposals.
+    #[prost(message, optional, tag = "9")]
+    pub dao_spend_params: ::core::option::Option<ProposalParameters>,
+    /// The parameters for upgrade plan proposals.
+    #[prost(message, optional, tag = "10")]
+    pub upgrade_plan_params: ::core::option::Option<ProposalParameters>,
+    /// The parameters for IBC client recovery proposals.
+    #[prost(message, optional, tag = "11")]
+    pub ibc_client_recovery_params: ::core::option::Option<ProposalParameters>,
 }
 impl ::prost::Name for GovernanceParameters {
     const NAME: &'static str = "GovernanceParameters";
@@ -1036,6 +1082,39 @@ impl ::prost::Name for GovernanceParameters {
         )
     }
 }
+/// The governance parameters for a single kind of proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalParameters {
+    /// The number of blocks during which a proposal is voted on.
+    #[prost(uint64, tag = "1")]
+    pub voting_blocks: u64,
+    /// The deposit required to create a proposal.
+    #[prost(message, optional, tag = "2")]
+    pub deposit_amount: ::core::option::Option<
+        super::super::super::num::v1alpha1::Amount,
+    >,
+    /// The quorum required for a proposal to be considered valid, as a fraction of the total stake
+    /// weight of the network.
+    #[prost(string, tag = "3")]
+    pub valid_quorum: ::prost::alloc::string::String,
+    /// The threshold for a proposal to pass voting, as a ratio of "yes" votes over "no" votes.
+    #[prost(string, tag = "4")]
+    pub pass_threshold: ::prost::alloc::string::String,
+    /// The threshold for a proposal to be slashed, regardless of whether the "yes" and "no" votes
+    /// would have passed it, as a ratio of "no" votes over all total votes.
+    #[prost(string, tag = "5")]
+    pub slash_threshold: ::prost::alloc::string::String,
+}
+impl ::prost::Name for ProposalParameters {
+    const NAME: &'static str = "ProposalParameters";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.governance.v1alpha1.{}", Self::NAME
+        )
+    }
+}
 /// Governance genesis state.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1085,6 +1164,9 @@ pub struct ChangedAppParameters {
     pub distributions_params: ::core::option::Option<
         super::super::distributions::v1alpha1::DistributionsParameters,
     >,
+    /// Dex module parameters.
+    #[prost(message, optional, tag = "8")]
+    pub dex_params: ::core::option::Option<super::super::dex::v1alpha1::DexParameters>,
 }
 impl ::prost::Name for ChangedAppParameters {
     const NAME: &'static str = "ChangedAppParameters";
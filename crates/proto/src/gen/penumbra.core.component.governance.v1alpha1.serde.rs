@@ -249,6 +249,9 @@ impl serde::Serialize for ChangedAppParameters {
         if self.distributions_params.is_some() {
             len += 1;
         }
+        if self.dex_params.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.ChangedAppParameters", len)?;
         if let Some(v) = self.chain_params.as_ref() {
             struct_ser.serialize_field("chainParams", v)?;
@@ -271,6 +274,9 @@ impl serde::Serialize for ChangedAppParameters {
         if let Some(v) = self.distributions_params.as_ref() {
             struct_ser.serialize_field("distributionsParams", v)?;
         }
+        if let Some(v) = self.dex_params.as_ref() {
+            struct_ser.serialize_field("dexParams", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -295,6 +301,8 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
             "feeParams",
             "distributions_params",
             "distributionsParams",
+            "dex_params",
+            "dexParams",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -306,6 +314,7 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
             StakeParams,
             FeeParams,
             DistributionsParams,
+            DexParams,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -334,6 +343,7 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
                             "stakeParams" | "stake_params" => Ok(GeneratedField::StakeParams),
                             "feeParams" | "fee_params" => Ok(GeneratedField::FeeParams),
                             "distributionsParams" | "distributions_params" => Ok(GeneratedField::DistributionsParams),
+                            "dexParams" | "dex_params" => Ok(GeneratedField::DexParams),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -360,6 +370,7 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
                 let mut stake_params__ = None;
                 let mut fee_params__ = None;
                 let mut distributions_params__ = None;
+                let mut dex_params__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ChainParams => {
@@ -404,6 +415,12 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
                             }
                             distributions_params__ = map_.next_value()?;
                         }
+                        GeneratedField::DexParams => {
+                            if dex_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexParams"));
+                            }
+                            dex_params__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(ChangedAppParameters {
@@ -414,6 +431,7 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
                     stake_params: stake_params__,
                     fee_params: fee_params__,
                     distributions_params: distributions_params__,
+                    dex_params: dex_params__,
                 })
             }
         }
@@ -1827,6 +1845,24 @@ impl serde::Serialize for GovernanceParameters {
         if !self.proposal_slash_threshold.is_empty() {
             len += 1;
         }
+        if self.signaling_params.is_some() {
+            len += 1;
+        }
+        if self.emergency_params.is_some() {
+            len += 1;
+        }
+        if self.parameter_change_params.is_some() {
+            len += 1;
+        }
+        if self.dao_spend_params.is_some() {
+            len += 1;
+        }
+        if self.upgrade_plan_params.is_some() {
+            len += 1;
+        }
+        if self.ibc_client_recovery_params.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.GovernanceParameters", len)?;
         if self.proposal_voting_blocks != 0 {
             #[allow(clippy::needless_borrow)]
@@ -1844,6 +1880,24 @@ impl serde::Serialize for GovernanceParameters {
         if !self.proposal_slash_threshold.is_empty() {
             struct_ser.serialize_field("proposalSlashThreshold", &self.proposal_slash_threshold)?;
         }
+        if let Some(v) = self.signaling_params.as_ref() {
+            struct_ser.serialize_field("signalingParams", v)?;
+        }
+        if let Some(v) = self.emergency_params.as_ref() {
+            struct_ser.serialize_field("emergencyParams", v)?;
+        }
+        if let Some(v) = self.parameter_change_params.as_ref() {
+            struct_ser.serialize_field("parameterChangeParams", v)?;
+        }
+        if let Some(v) = self.dao_spend_params.as_ref() {
+            struct_ser.serialize_field("daoSpendParams", v)?;
+        }
+        if let Some(v) = self.upgrade_plan_params.as_ref() {
+            struct_ser.serialize_field("upgradePlanParams", v)?;
+        }
+        if let Some(v) = self.ibc_client_recovery_params.as_ref() {
+            struct_ser.serialize_field("ibcClientRecoveryParams", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1864,6 +1918,18 @@ impl<'de> serde::Deserialize<'de> for GovernanceParameters {
             "proposalPassThreshold",
             "proposal_slash_threshold",
             "proposalSlashThreshold",
+            "signaling_params",
+            "signalingParams",
+            "emergency_params",
+            "emergencyParams",
+            "parameter_change_params",
+            "parameterChangeParams",
+            "dao_spend_params",
+            "daoSpendParams",
+            "upgrade_plan_params",
+            "upgradePlanParams",
+            "ibc_client_recovery_params",
+            "ibcClientRecoveryParams",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1873,6 +1939,12 @@ impl<'de> serde::Deserialize<'de> for GovernanceParameters {
             ProposalValidQuorum,
             ProposalPassThreshold,
             ProposalSlashThreshold,
+            SignalingParams,
+            EmergencyParams,
+            ParameterChangeParams,
+            DaoSpendParams,
+            UpgradePlanParams,
+            IbcClientRecoveryParams,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1899,6 +1971,12 @@ impl<'de> serde::Deserialize<'de> for GovernanceParameters {
                             "proposalValidQuorum" | "proposal_valid_quorum" => Ok(GeneratedField::ProposalValidQuorum),
                             "proposalPassThreshold" | "proposal_pass_threshold" => Ok(GeneratedField::ProposalPassThreshold),
                             "proposalSlashThreshold" | "proposal_slash_threshold" => Ok(GeneratedField::ProposalSlashThreshold),
+                            "signalingParams" | "signaling_params" => Ok(GeneratedField::SignalingParams),
+                            "emergencyParams" | "emergency_params" => Ok(GeneratedField::EmergencyParams),
+                            "parameterChangeParams" | "parameter_change_params" => Ok(GeneratedField::ParameterChangeParams),
+                            "daoSpendParams" | "dao_spend_params" => Ok(GeneratedField::DaoSpendParams),
+                            "upgradePlanParams" | "upgrade_plan_params" => Ok(GeneratedField::UpgradePlanParams),
+                            "ibcClientRecoveryParams" | "ibc_client_recovery_params" => Ok(GeneratedField::IbcClientRecoveryParams),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1923,6 +2001,12 @@ impl<'de> serde::Deserialize<'de> for GovernanceParameters {
                 let mut proposal_valid_quorum__ = None;
                 let mut proposal_pass_threshold__ = None;
                 let mut proposal_slash_threshold__ = None;
+                let mut signaling_params__ = None;
+                let mut emergency_params__ = None;
+                let mut parameter_change_params__ = None;
+                let mut dao_spend_params__ = None;
+                let mut upgrade_plan_params__ = None;
+                let mut ibc_client_recovery_params__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ProposalVotingBlocks => {
@@ -1957,6 +2041,42 @@ impl<'de> serde::Deserialize<'de> for GovernanceParameters {
                             }
                             proposal_slash_threshold__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::SignalingParams => {
+                            if signaling_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signalingParams"));
+                            }
+                            signaling_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::EmergencyParams => {
+                            if emergency_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("emergencyParams"));
+                            }
+                            emergency_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::ParameterChangeParams => {
+                            if parameter_change_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parameterChangeParams"));
+                            }
+                            parameter_change_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::DaoSpendParams => {
+                            if dao_spend_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("daoSpendParams"));
+                            }
+                            dao_spend_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::UpgradePlanParams => {
+                            if upgrade_plan_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upgradePlanParams"));
+                            }
+                            upgrade_plan_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::IbcClientRecoveryParams => {
+                            if ibc_client_recovery_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcClientRecoveryParams"));
+                            }
+                            ibc_client_recovery_params__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(GovernanceParameters {
@@ -1965,6 +2085,12 @@ impl<'de> serde::Deserialize<'de> for GovernanceParameters {
                     proposal_valid_quorum: proposal_valid_quorum__.unwrap_or_default(),
                     proposal_pass_threshold: proposal_pass_threshold__.unwrap_or_default(),
                     proposal_slash_threshold: proposal_slash_threshold__.unwrap_or_default(),
+                    signaling_params: signaling_params__,
+                    emergency_params: emergency_params__,
+                    parameter_change_params: parameter_change_params__,
+                    dao_spend_params: dao_spend_params__,
+                    upgrade_plan_params: upgrade_plan_params__,
+                    ibc_client_recovery_params: ibc_client_recovery_params__,
                 })
             }
         }
@@ -2190,6 +2316,9 @@ impl serde::Serialize for Proposal {
         if self.upgrade_plan.is_some() {
             len += 1;
         }
+        if self.ibc_client_recovery.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal", len)?;
         if self.id != 0 {
             #[allow(clippy::needless_borrow)]
@@ -2216,6 +2345,9 @@ impl serde::Serialize for Proposal {
         if let Some(v) = self.upgrade_plan.as_ref() {
             struct_ser.serialize_field("upgradePlan", v)?;
         }
+        if let Some(v) = self.ibc_client_recovery.as_ref() {
+            struct_ser.serialize_field("ibcClientRecovery", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -2237,6 +2369,8 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             "daoSpend",
             "upgrade_plan",
             "upgradePlan",
+            "ibc_client_recovery",
+            "ibcClientRecovery",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -2249,6 +2383,7 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             ParameterChange,
             DaoSpend,
             UpgradePlan,
+            IbcClientRecovery,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2278,6 +2413,7 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                             "parameterChange" | "parameter_change" => Ok(GeneratedField::ParameterChange),
                             "daoSpend" | "dao_spend" => Ok(GeneratedField::DaoSpend),
                             "upgradePlan" | "upgrade_plan" => Ok(GeneratedField::UpgradePlan),
+                            "ibcClientRecovery" | "ibc_client_recovery" => Ok(GeneratedField::IbcClientRecovery),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2305,6 +2441,7 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                 let mut parameter_change__ = None;
                 let mut dao_spend__ = None;
                 let mut upgrade_plan__ = None;
+                let mut ibc_client_recovery__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -2357,6 +2494,12 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                             }
                             upgrade_plan__ = map_.next_value()?;
                         }
+                        GeneratedField::IbcClientRecovery => {
+                            if ibc_client_recovery__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcClientRecovery"));
+                            }
+                            ibc_client_recovery__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(Proposal {
@@ -2368,6 +2511,7 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                     parameter_change: parameter_change__,
                     dao_spend: dao_spend__,
                     upgrade_plan: upgrade_plan__,
+                    ibc_client_recovery: ibc_client_recovery__,
                 })
             }
         }
@@ -2558,6 +2702,116 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.Emergency", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for proposal::IbcClientRecovery {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.subject_client_id.is_empty() {
+            len += 1;
+        }
+        if !self.substitute_client_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.IbcClientRecovery", len)?;
+        if !self.subject_client_id.is_empty() {
+            struct_ser.serialize_field("subjectClientId", &self.subject_client_id)?;
+        }
+        if !self.substitute_client_id.is_empty() {
+            struct_ser.serialize_field("substituteClientId", &self.substitute_client_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::IbcClientRecovery {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "subject_client_id",
+            "subjectClientId",
+            "substitute_client_id",
+            "substituteClientId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SubjectClientId,
+            SubstituteClientId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "subjectClientId" | "subject_client_id" => Ok(GeneratedField::SubjectClientId),
+                            "substituteClientId" | "substitute_client_id" => Ok(GeneratedField::SubstituteClientId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::IbcClientRecovery;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.Proposal.IbcClientRecovery")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::IbcClientRecovery, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut subject_client_id__ = None;
+                let mut substitute_client_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SubjectClientId => {
+                            if subject_client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("subjectClientId"));
+                            }
+                            subject_client_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SubstituteClientId => {
+                            if substitute_client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("substituteClientId"));
+                            }
+                            substitute_client_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(proposal::IbcClientRecovery {
+                    subject_client_id: subject_client_id__.unwrap_or_default(),
+                    substitute_client_id: substitute_client_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.IbcClientRecovery", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for proposal::ParameterChange {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2770,11 +3024,17 @@ impl serde::Serialize for proposal::UpgradePlan {
         if self.height != 0 {
             len += 1;
         }
+        if !self.name.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.Proposal.UpgradePlan", len)?;
         if self.height != 0 {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
         struct_ser.end()
     }
 }
@@ -2786,11 +3046,13 @@ impl<'de> serde::Deserialize<'de> for proposal::UpgradePlan {
     {
         const FIELDS: &[&str] = &[
             "height",
+            "name",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Height,
+            Name,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2813,6 +3075,7 @@ impl<'de> serde::Deserialize<'de> for proposal::UpgradePlan {
                     {
                         match value {
                             "height" => Ok(GeneratedField::Height),
+                            "name" => Ok(GeneratedField::Name),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2833,6 +3096,7 @@ impl<'de> serde::Deserialize<'de> for proposal::UpgradePlan {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut height__ = None;
+                let mut name__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Height => {
@@ -2843,10 +3107,17 @@ impl<'de> serde::Deserialize<'de> for proposal::UpgradePlan {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(proposal::UpgradePlan {
                     height: height__.unwrap_or_default(),
+                    name: name__.unwrap_or_default(),
                 })
             }
         }
@@ -4259,6 +4530,173 @@ impl<'de> serde::Deserialize<'de> for proposal_outcome::Withdrawn {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.ProposalOutcome.Withdrawn", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ProposalParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.voting_blocks != 0 {
+            len += 1;
+        }
+        if self.deposit_amount.is_some() {
+            len += 1;
+        }
+        if !self.valid_quorum.is_empty() {
+            len += 1;
+        }
+        if !self.pass_threshold.is_empty() {
+            len += 1;
+        }
+        if !self.slash_threshold.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1alpha1.ProposalParameters", len)?;
+        if self.voting_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("votingBlocks", ToString::to_string(&self.voting_blocks).as_str())?;
+        }
+        if let Some(v) = self.deposit_amount.as_ref() {
+            struct_ser.serialize_field("depositAmount", v)?;
+        }
+        if !self.valid_quorum.is_empty() {
+            struct_ser.serialize_field("validQuorum", &self.valid_quorum)?;
+        }
+        if !self.pass_threshold.is_empty() {
+            struct_ser.serialize_field("passThreshold", &self.pass_threshold)?;
+        }
+        if !self.slash_threshold.is_empty() {
+            struct_ser.serialize_field("slashThreshold", &self.slash_threshold)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "voting_blocks",
+            "votingBlocks",
+            "deposit_amount",
+            "depositAmount",
+            "valid_quorum",
+            "validQuorum",
+            "pass_threshold",
+            "passThreshold",
+            "slash_threshold",
+            "slashThreshold",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            VotingBlocks,
+            DepositAmount,
+            ValidQuorum,
+            PassThreshold,
+            SlashThreshold,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "votingBlocks" | "voting_blocks" => Ok(GeneratedField::VotingBlocks),
+                            "depositAmount" | "deposit_amount" => Ok(GeneratedField::DepositAmount),
+                            "validQuorum" | "valid_quorum" => Ok(GeneratedField::ValidQuorum),
+                            "passThreshold" | "pass_threshold" => Ok(GeneratedField::PassThreshold),
+                            "slashThreshold" | "slash_threshold" => Ok(GeneratedField::SlashThreshold),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1alpha1.ProposalParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut voting_blocks__ = None;
+                let mut deposit_amount__ = None;
+                let mut valid_quorum__ = None;
+                let mut pass_threshold__ = None;
+                let mut slash_threshold__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::VotingBlocks => {
+                            if voting_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votingBlocks"));
+                            }
+                            voting_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DepositAmount => {
+                            if deposit_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depositAmount"));
+                            }
+                            deposit_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::ValidQuorum => {
+                            if valid_quorum__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validQuorum"));
+                            }
+                            valid_quorum__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PassThreshold => {
+                            if pass_threshold__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("passThreshold"));
+                            }
+                            pass_threshold__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SlashThreshold => {
+                            if slash_threshold__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slashThreshold"));
+                            }
+                            slash_threshold__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ProposalParameters {
+                    voting_blocks: voting_blocks__.unwrap_or_default(),
+                    deposit_amount: deposit_amount__,
+                    valid_quorum: valid_quorum__.unwrap_or_default(),
+                    pass_threshold: pass_threshold__.unwrap_or_default(),
+                    slash_threshold: slash_threshold__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1alpha1.ProposalParameters", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ProposalRateDataRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
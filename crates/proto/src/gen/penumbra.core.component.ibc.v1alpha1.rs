@@ -29,6 +29,10 @@ pub struct FungibleTokenPacketData {
     /// the recipient address on the destination chain
     #[prost(string, tag = "4")]
     pub receiver: ::prost::alloc::string::String,
+    /// an optional memo, which may carry instructions for the destination chain,
+    /// such as forwarding the transfer over another channel
+    #[prost(string, tag = "5")]
+    pub memo: ::prost::alloc::string::String,
 }
 impl ::prost::Name for FungibleTokenPacketData {
     const NAME: &'static str = "FungibleTokenPacketData";
@@ -66,6 +70,10 @@ pub struct Ics20Withdrawal {
     /// the source channel used for the withdrawal
     #[prost(string, tag = "7")]
     pub source_channel: ::prost::alloc::string::String,
+    /// an optional memo to attach to the transfer packet, e.g. to instruct the
+    /// destination chain to forward the transfer
+    #[prost(string, tag = "8")]
+    pub memo: ::prost::alloc::string::String,
 }
 impl ::prost::Name for Ics20Withdrawal {
     const NAME: &'static str = "Ics20Withdrawal";
@@ -172,6 +180,9 @@ pub struct IbcParameters {
     /// Whether outbound ICS-20 transfers are enabled
     #[prost(bool, tag = "3")]
     pub outbound_ics20_transfers_enabled: bool,
+    /// Limits on the net flow of assets over ICS-20 channels.
+    #[prost(message, repeated, tag = "4")]
+    pub ics20_rate_limits: ::prost::alloc::vec::Vec<Ics20RateLimit>,
 }
 impl ::prost::Name for IbcParameters {
     const NAME: &'static str = "IbcParameters";
@@ -180,6 +191,58 @@ impl ::prost::Name for IbcParameters {
         ::prost::alloc::format!("penumbra.core.component.ibc.v1alpha1.{}", Self::NAME)
     }
 }
+/// A limit on the net flow of an asset over an ICS-20 channel, within a sliding window of blocks.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimit {
+    /// The channel the limit applies to.
+    #[prost(string, tag = "1")]
+    pub channel_id: ::prost::alloc::string::String,
+    /// The asset the limit applies to, in its denomination on Penumbra.
+    #[prost(message, optional, tag = "2")]
+    pub asset_id: ::core::option::Option<super::super::super::asset::v1alpha1::AssetId>,
+    /// The most that inflows may exceed outflows by within a window.
+    #[prost(message, optional, tag = "3")]
+    pub max_net_inflow: ::core::option::Option<
+        super::super::super::num::v1alpha1::Amount,
+    >,
+    /// The most that outflows may exceed inflows by within a window.
+    #[prost(message, optional, tag = "4")]
+    pub max_net_outflow: ::core::option::Option<
+        super::super::super::num::v1alpha1::Amount,
+    >,
+    /// The length of the sliding window, in blocks.
+    #[prost(uint64, tag = "5")]
+    pub window_blocks: u64,
+}
+impl ::prost::Name for Ics20RateLimit {
+    const NAME: &'static str = "Ics20RateLimit";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1alpha1.{}", Self::NAME)
+    }
+}
+/// The flow of an asset over an ICS-20 channel within a rate limit window.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20FlowUsage {
+    /// The first height of the window.
+    #[prost(uint64, tag = "1")]
+    pub window_start_height: u64,
+    /// The amount received over the channel within the window.
+    #[prost(message, optional, tag = "2")]
+    pub inflow: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
+    /// The amount sent over the channel within the window.
+    #[prost(message, optional, tag = "3")]
+    pub outflow: ::core::option::Option<super::super::super::num::v1alpha1::Amount>,
+}
+impl ::prost::Name for Ics20FlowUsage {
+    const NAME: &'static str = "Ics20FlowUsage";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1alpha1.{}", Self::NAME)
+    }
+}
 /// IBC genesis state.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -195,3 +258,364 @@ impl ::prost::Name for GenesisContent {
         ::prost::alloc::format!("penumbra.core.component.ibc.v1alpha1.{}", Self::NAME)
     }
 }
+/// Requests the configured ICS-20 rate limits and their current usage.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimitsRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// (Optional): Only return the rate limits for this channel.
+    #[prost(string, tag = "2")]
+    pub channel_id: ::prost::alloc::string::String,
+}
+impl ::prost::Name for Ics20RateLimitsRequest {
+    const NAME: &'static str = "Ics20RateLimitsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimitsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub rate_limits: ::prost::alloc::vec::Vec<
+        ics20_rate_limits_response::RateLimitUsage,
+    >,
+}
+/// Nested message and enum types in `Ics20RateLimitsResponse`.
+pub mod ics20_rate_limits_response {
+    /// An ICS-20 rate limit, along with its usage in the current window.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RateLimitUsage {
+        #[prost(message, optional, tag = "1")]
+        pub rate_limit: ::core::option::Option<super::Ics20RateLimit>,
+        /// The usage of the rate limit, if anything has flowed in its current window.
+        #[prost(message, optional, tag = "2")]
+        pub usage: ::core::option::Option<super::Ics20FlowUsage>,
+    }
+    impl ::prost::Name for RateLimitUsage {
+        const NAME: &'static str = "RateLimitUsage";
+        const PACKAGE: &'static str = "penumbra.core.component.ibc.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.ibc.v1alpha1.Ics20RateLimitsResponse.{}",
+                Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for Ics20RateLimitsResponse {
+    const NAME: &'static str = "Ics20RateLimitsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1alpha1.{}", Self::NAME)
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "rpc")]
+pub mod query_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Query operations for the IBC component.
+    #[derive(Debug, Clone)]
+    pub struct QueryServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl QueryServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            QueryServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Queries the configured ICS-20 rate limits, along with their current usage.
+        pub async fn ics20_rate_limits(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Ics20RateLimitsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::Ics20RateLimitsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.ibc.v1alpha1.QueryService/Ics20RateLimits",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.ibc.v1alpha1.QueryService",
+                        "Ics20RateLimits",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "rpc")]
+pub mod query_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServiceServer.
+    #[async_trait]
+    pub trait QueryService: Send + Sync + 'static {
+        /// Queries the configured ICS-20 rate limits, along with their current usage.
+        async fn ics20_rate_limits(
+            &self,
+            request: tonic::Request<super::Ics20RateLimitsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::Ics20RateLimitsResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query operations for the IBC component.
+    #[derive(Debug)]
+    pub struct QueryServiceServer<T: QueryService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: QueryService> QueryServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServiceServer<T>
+    where
+        T: QueryService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/penumbra.core.component.ibc.v1alpha1.QueryService/Ics20RateLimits" => {
+                    #[allow(non_camel_case_types)]
+                    struct Ics20RateLimitsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::Ics20RateLimitsRequest>
+                    for Ics20RateLimitsSvc<T> {
+                        type Response = super::Ics20RateLimitsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::Ics20RateLimitsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::ics20_rate_limits(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = Ics20RateLimitsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: QueryService> Clone for QueryServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: QueryService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: QueryService> tonic::server::NamedService for QueryServiceServer<T> {
+        const NAME: &'static str = "penumbra.core.component.ibc.v1alpha1.QueryService";
+    }
+}
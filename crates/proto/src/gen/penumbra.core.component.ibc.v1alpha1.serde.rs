@@ -538,6 +538,9 @@ impl serde::Serialize for FungibleTokenPacketData {
         if !self.receiver.is_empty() {
             len += 1;
         }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1alpha1.FungibleTokenPacketData", len)?;
         if !self.denom.is_empty() {
             struct_ser.serialize_field("denom", &self.denom)?;
@@ -551,6 +554,9 @@ impl serde::Serialize for FungibleTokenPacketData {
         if !self.receiver.is_empty() {
             struct_ser.serialize_field("receiver", &self.receiver)?;
         }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
         struct_ser.end()
     }
 }
@@ -565,6 +571,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
             "amount",
             "sender",
             "receiver",
+            "memo",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -573,6 +580,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
             Amount,
             Sender,
             Receiver,
+            Memo,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -598,6 +606,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
                             "amount" => Ok(GeneratedField::Amount),
                             "sender" => Ok(GeneratedField::Sender),
                             "receiver" => Ok(GeneratedField::Receiver),
+                            "memo" => Ok(GeneratedField::Memo),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -621,6 +630,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
                 let mut amount__ = None;
                 let mut sender__ = None;
                 let mut receiver__ = None;
+                let mut memo__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Denom => {
@@ -647,6 +657,12 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
                             }
                             receiver__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(FungibleTokenPacketData {
@@ -654,6 +670,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
                     amount: amount__.unwrap_or_default(),
                     sender: sender__.unwrap_or_default(),
                     receiver: receiver__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
                 })
             }
         }
@@ -769,6 +786,9 @@ impl serde::Serialize for IbcParameters {
         if self.outbound_ics20_transfers_enabled {
             len += 1;
         }
+        if !self.ics20_rate_limits.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1alpha1.IbcParameters", len)?;
         if self.ibc_enabled {
             struct_ser.serialize_field("ibcEnabled", &self.ibc_enabled)?;
@@ -779,6 +799,9 @@ impl serde::Serialize for IbcParameters {
         if self.outbound_ics20_transfers_enabled {
             struct_ser.serialize_field("outboundIcs20TransfersEnabled", &self.outbound_ics20_transfers_enabled)?;
         }
+        if !self.ics20_rate_limits.is_empty() {
+            struct_ser.serialize_field("ics20RateLimits", &self.ics20_rate_limits)?;
+        }
         struct_ser.end()
     }
 }
@@ -795,6 +818,8 @@ impl<'de> serde::Deserialize<'de> for IbcParameters {
             "inboundIcs20TransfersEnabled",
             "outbound_ics20_transfers_enabled",
             "outboundIcs20TransfersEnabled",
+            "ics20_rate_limits",
+            "ics20RateLimits",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -802,6 +827,7 @@ impl<'de> serde::Deserialize<'de> for IbcParameters {
             IbcEnabled,
             InboundIcs20TransfersEnabled,
             OutboundIcs20TransfersEnabled,
+            Ics20RateLimits,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -826,6 +852,7 @@ impl<'de> serde::Deserialize<'de> for IbcParameters {
                             "ibcEnabled" | "ibc_enabled" => Ok(GeneratedField::IbcEnabled),
                             "inboundIcs20TransfersEnabled" | "inbound_ics20_transfers_enabled" => Ok(GeneratedField::InboundIcs20TransfersEnabled),
                             "outboundIcs20TransfersEnabled" | "outbound_ics20_transfers_enabled" => Ok(GeneratedField::OutboundIcs20TransfersEnabled),
+                            "ics20RateLimits" | "ics20_rate_limits" => Ok(GeneratedField::Ics20RateLimits),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -848,6 +875,7 @@ impl<'de> serde::Deserialize<'de> for IbcParameters {
                 let mut ibc_enabled__ = None;
                 let mut inbound_ics20_transfers_enabled__ = None;
                 let mut outbound_ics20_transfers_enabled__ = None;
+                let mut ics20_rate_limits__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::IbcEnabled => {
@@ -868,12 +896,19 @@ impl<'de> serde::Deserialize<'de> for IbcParameters {
                             }
                             outbound_ics20_transfers_enabled__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Ics20RateLimits => {
+                            if ics20_rate_limits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20RateLimits"));
+                            }
+                            ics20_rate_limits__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(IbcParameters {
                     ibc_enabled: ibc_enabled__.unwrap_or_default(),
                     inbound_ics20_transfers_enabled: inbound_ics20_transfers_enabled__.unwrap_or_default(),
                     outbound_ics20_transfers_enabled: outbound_ics20_transfers_enabled__.unwrap_or_default(),
+                    ics20_rate_limits: ics20_rate_limits__.unwrap_or_default(),
                 })
             }
         }
@@ -972,6 +1007,613 @@ impl<'de> serde::Deserialize<'de> for IbcRelay {
         deserializer.deserialize_struct("penumbra.core.component.ibc.v1alpha1.IbcRelay", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for Ics20FlowUsage {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.window_start_height != 0 {
+            len += 1;
+        }
+        if self.inflow.is_some() {
+            len += 1;
+        }
+        if self.outflow.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20FlowUsage", len)?;
+        if self.window_start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("windowStartHeight", ToString::to_string(&self.window_start_height).as_str())?;
+        }
+        if let Some(v) = self.inflow.as_ref() {
+            struct_ser.serialize_field("inflow", v)?;
+        }
+        if let Some(v) = self.outflow.as_ref() {
+            struct_ser.serialize_field("outflow", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20FlowUsage {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "window_start_height",
+            "windowStartHeight",
+            "inflow",
+            "outflow",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WindowStartHeight,
+            Inflow,
+            Outflow,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "windowStartHeight" | "window_start_height" => Ok(GeneratedField::WindowStartHeight),
+                            "inflow" => Ok(GeneratedField::Inflow),
+                            "outflow" => Ok(GeneratedField::Outflow),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20FlowUsage;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1alpha1.Ics20FlowUsage")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20FlowUsage, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut window_start_height__ = None;
+                let mut inflow__ = None;
+                let mut outflow__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WindowStartHeight => {
+                            if window_start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("windowStartHeight"));
+                            }
+                            window_start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Inflow => {
+                            if inflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inflow"));
+                            }
+                            inflow__ = map_.next_value()?;
+                        }
+                        GeneratedField::Outflow => {
+                            if outflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outflow"));
+                            }
+                            outflow__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(Ics20FlowUsage {
+                    window_start_height: window_start_height__.unwrap_or_default(),
+                    inflow: inflow__,
+                    outflow: outflow__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20FlowUsage", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20RateLimit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.channel_id.is_empty() {
+            len += 1;
+        }
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.max_net_inflow.is_some() {
+            len += 1;
+        }
+        if self.max_net_outflow.is_some() {
+            len += 1;
+        }
+        if self.window_blocks != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20RateLimit", len)?;
+        if !self.channel_id.is_empty() {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
+        }
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.max_net_inflow.as_ref() {
+            struct_ser.serialize_field("maxNetInflow", v)?;
+        }
+        if let Some(v) = self.max_net_outflow.as_ref() {
+            struct_ser.serialize_field("maxNetOutflow", v)?;
+        }
+        if self.window_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("windowBlocks", ToString::to_string(&self.window_blocks).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20RateLimit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "channel_id",
+            "channelId",
+            "asset_id",
+            "assetId",
+            "max_net_inflow",
+            "maxNetInflow",
+            "max_net_outflow",
+            "maxNetOutflow",
+            "window_blocks",
+            "windowBlocks",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChannelId,
+            AssetId,
+            MaxNetInflow,
+            MaxNetOutflow,
+            WindowBlocks,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "maxNetInflow" | "max_net_inflow" => Ok(GeneratedField::MaxNetInflow),
+                            "maxNetOutflow" | "max_net_outflow" => Ok(GeneratedField::MaxNetOutflow),
+                            "windowBlocks" | "window_blocks" => Ok(GeneratedField::WindowBlocks),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20RateLimit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1alpha1.Ics20RateLimit")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20RateLimit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut channel_id__ = None;
+                let mut asset_id__ = None;
+                let mut max_net_inflow__ = None;
+                let mut max_net_outflow__ = None;
+                let mut window_blocks__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
+                            }
+                            channel_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::MaxNetInflow => {
+                            if max_net_inflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxNetInflow"));
+                            }
+                            max_net_inflow__ = map_.next_value()?;
+                        }
+                        GeneratedField::MaxNetOutflow => {
+                            if max_net_outflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxNetOutflow"));
+                            }
+                            max_net_outflow__ = map_.next_value()?;
+                        }
+                        GeneratedField::WindowBlocks => {
+                            if window_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("windowBlocks"));
+                            }
+                            window_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Ics20RateLimit {
+                    channel_id: channel_id__.unwrap_or_default(),
+                    asset_id: asset_id__,
+                    max_net_inflow: max_net_inflow__,
+                    max_net_outflow: max_net_outflow__,
+                    window_blocks: window_blocks__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20RateLimit", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20RateLimitsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if !self.channel_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20RateLimitsRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if !self.channel_id.is_empty() {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20RateLimitsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "channel_id",
+            "channelId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            ChannelId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20RateLimitsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1alpha1.Ics20RateLimitsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20RateLimitsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut channel_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
+                            }
+                            channel_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Ics20RateLimitsRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    channel_id: channel_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20RateLimitsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20RateLimitsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.rate_limits.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20RateLimitsResponse", len)?;
+        if !self.rate_limits.is_empty() {
+            struct_ser.serialize_field("rateLimits", &self.rate_limits)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20RateLimitsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rate_limits",
+            "rateLimits",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RateLimits,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rateLimits" | "rate_limits" => Ok(GeneratedField::RateLimits),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20RateLimitsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1alpha1.Ics20RateLimitsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20RateLimitsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rate_limits__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RateLimits => {
+                            if rate_limits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateLimits"));
+                            }
+                            rate_limits__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Ics20RateLimitsResponse {
+                    rate_limits: rate_limits__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20RateLimitsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ics20_rate_limits_response::RateLimitUsage {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rate_limit.is_some() {
+            len += 1;
+        }
+        if self.usage.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20RateLimitsResponse.RateLimitUsage", len)?;
+        if let Some(v) = self.rate_limit.as_ref() {
+            struct_ser.serialize_field("rateLimit", v)?;
+        }
+        if let Some(v) = self.usage.as_ref() {
+            struct_ser.serialize_field("usage", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ics20_rate_limits_response::RateLimitUsage {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rate_limit",
+            "rateLimit",
+            "usage",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RateLimit,
+            Usage,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rateLimit" | "rate_limit" => Ok(GeneratedField::RateLimit),
+                            "usage" => Ok(GeneratedField::Usage),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ics20_rate_limits_response::RateLimitUsage;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.ibc.v1alpha1.Ics20RateLimitsResponse.RateLimitUsage")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ics20_rate_limits_response::RateLimitUsage, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rate_limit__ = None;
+                let mut usage__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RateLimit => {
+                            if rate_limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateLimit"));
+                            }
+                            rate_limit__ = map_.next_value()?;
+                        }
+                        GeneratedField::Usage => {
+                            if usage__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("usage"));
+                            }
+                            usage__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ics20_rate_limits_response::RateLimitUsage {
+                    rate_limit: rate_limit__,
+                    usage: usage__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20RateLimitsResponse.RateLimitUsage", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Ics20Withdrawal {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1001,6 +1643,9 @@ impl serde::Serialize for Ics20Withdrawal {
         if !self.source_channel.is_empty() {
             len += 1;
         }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.ibc.v1alpha1.Ics20Withdrawal", len)?;
         if let Some(v) = self.amount.as_ref() {
             struct_ser.serialize_field("amount", v)?;
@@ -1024,6 +1669,9 @@ impl serde::Serialize for Ics20Withdrawal {
         if !self.source_channel.is_empty() {
             struct_ser.serialize_field("sourceChannel", &self.source_channel)?;
         }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
         struct_ser.end()
     }
 }
@@ -1046,6 +1694,7 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
             "timeoutTime",
             "source_channel",
             "sourceChannel",
+            "memo",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1057,6 +1706,7 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
             TimeoutHeight,
             TimeoutTime,
             SourceChannel,
+            Memo,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1085,6 +1735,7 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
                             "timeoutHeight" | "timeout_height" => Ok(GeneratedField::TimeoutHeight),
                             "timeoutTime" | "timeout_time" => Ok(GeneratedField::TimeoutTime),
                             "sourceChannel" | "source_channel" => Ok(GeneratedField::SourceChannel),
+                            "memo" => Ok(GeneratedField::Memo),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1111,6 +1762,7 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
                 let mut timeout_height__ = None;
                 let mut timeout_time__ = None;
                 let mut source_channel__ = None;
+                let mut memo__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Amount => {
@@ -1157,6 +1809,12 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
                             }
                             source_channel__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(Ics20Withdrawal {
@@ -1167,6 +1825,7 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
                     timeout_height: timeout_height__,
                     timeout_time: timeout_time__.unwrap_or_default(),
                     source_channel: source_channel__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
                 })
             }
         }
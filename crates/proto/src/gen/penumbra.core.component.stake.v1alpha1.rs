@@ -363,6 +363,79 @@ impl ::prost::Name for ValidatorDefinition {
         ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
     }
 }
+/// A transaction action releasing a validator from jail.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorUnjail {
+    /// The effecting data for the unjail request.
+    #[prost(message, optional, tag = "1")]
+    pub body: ::core::option::Option<ValidatorUnjailBody>,
+    /// The unjail authorization signature is authorizing data.
+    #[prost(message, optional, tag = "2")]
+    pub auth_sig: ::core::option::Option<
+        super::super::super::super::crypto::decaf377_rdsa::v1alpha1::SpendAuthSignature,
+    >,
+}
+impl ::prost::Name for ValidatorUnjail {
+    const NAME: &'static str = "ValidatorUnjail";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorUnjailBody {
+    /// The identity key of the jailed validator.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<
+        super::super::super::keys::v1alpha1::IdentityKey,
+    >,
+    /// The key authorizing the request: either the validator's governance key,
+    /// or its identity key.
+    #[prost(message, optional, tag = "2")]
+    pub governance_key: ::core::option::Option<
+        super::super::super::keys::v1alpha1::GovernanceKey,
+    >,
+    /// The height at which the validator was jailed.
+    ///
+    /// This binds the request to a single jailing, so that it can't be replayed
+    /// to release the validator from a later one.
+    #[prost(uint64, tag = "3")]
+    pub jail_height: u64,
+}
+impl ::prost::Name for ValidatorUnjailBody {
+    const NAME: &'static str = "ValidatorUnjailBody";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
+    }
+}
+/// The funding streams of a validator definition increasing the validator's
+/// commission, which are waiting out the commission increase notice period
+/// before taking effect. The rest of the definition takes effect immediately.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingValidatorDefinition {
+    /// The identity key of the validator whose funding streams are pending.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<
+        super::super::super::keys::v1alpha1::IdentityKey,
+    >,
+    /// The pending funding streams.
+    #[prost(message, repeated, tag = "2")]
+    pub funding_streams: ::prost::alloc::vec::Vec<FundingStream>,
+    /// The index of the first epoch whose rewards use the pending funding streams.
+    #[prost(uint64, tag = "3")]
+    pub effective_epoch_index: u64,
+}
+impl ::prost::Name for PendingValidatorDefinition {
+    const NAME: &'static str = "PendingValidatorDefinition";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
+    }
+}
 /// A transaction action adding stake to a validator's delegation pool.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -433,6 +506,93 @@ impl ::prost::Name for Undelegate {
         ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
     }
 }
+/// A transaction action moving stake from one validator's delegation pool to
+/// another's, without waiting for the unbonding period.
+///
+/// The redelegated stake remains liable for slashing of the source validator
+/// until the unbonding period that an undelegation started in the same epoch
+/// would have had ends.  Until then, the destination validator's delegation
+/// tokens are held in escrow as "redelegation tokens".
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Redelegate {
+    /// The identity key of the validator to redelegate from.
+    #[prost(message, optional, tag = "1")]
+    pub from_validator_identity: ::core::option::Option<
+        super::super::super::keys::v1alpha1::IdentityKey,
+    >,
+    /// The identity key of the validator to redelegate to.
+    #[prost(message, optional, tag = "2")]
+    pub to_validator_identity: ::core::option::Option<
+        super::super::super::keys::v1alpha1::IdentityKey,
+    >,
+    /// The index of the epoch in which this redelegation was performed.
+    #[prost(uint64, tag = "3")]
+    pub epoch_index: u64,
+    /// The amount to redelegate, in units of unbonded stake.
+    #[prost(message, optional, tag = "4")]
+    pub unbonded_amount: ::core::option::Option<
+        super::super::super::num::v1alpha1::Amount,
+    >,
+    /// The amount of delegation tokens of the source validator consumed by this action.
+    #[prost(message, optional, tag = "5")]
+    pub from_delegation_amount: ::core::option::Option<
+        super::super::super::num::v1alpha1::Amount,
+    >,
+    /// The amount of delegation tokens of the destination validator produced by this action,
+    /// held in escrow as redelegation tokens.
+    ///
+    /// Both delegation amounts are implied by the validators' exchange rates in the
+    /// specified epoch (and are checked in transaction validation), but including them
+    /// allows stateless verification that the transaction is internally consistent.
+    #[prost(message, optional, tag = "6")]
+    pub to_delegation_amount: ::core::option::Option<
+        super::super::super::num::v1alpha1::Amount,
+    >,
+}
+impl ::prost::Name for Redelegate {
+    const NAME: &'static str = "Redelegate";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
+    }
+}
+/// A transaction action finishing a redelegation, converting (slashable)
+/// "redelegation tokens" to delegation tokens of the destination validator.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RedelegateClaim {
+    /// The identity key of the validator the stake was redelegated from.
+    #[prost(message, optional, tag = "1")]
+    pub from_validator_identity: ::core::option::Option<
+        super::super::super::keys::v1alpha1::IdentityKey,
+    >,
+    /// The identity key of the validator the stake was redelegated to.
+    #[prost(message, optional, tag = "2")]
+    pub to_validator_identity: ::core::option::Option<
+        super::super::super::keys::v1alpha1::IdentityKey,
+    >,
+    /// The epoch in which the redelegation happened, used to verify the penalty.
+    #[prost(uint64, tag = "3")]
+    pub start_epoch_index: u64,
+    /// The penalty of the source validator over the unbonding period, in bps^2 (10e-8).
+    /// In the happy path (no slashing), this is 0.
+    #[prost(message, optional, tag = "4")]
+    pub penalty: ::core::option::Option<Penalty>,
+    /// The amount of redelegation tokens to claim.
+    /// This is a bare number because its denom is determined by the preceding data.
+    #[prost(message, optional, tag = "5")]
+    pub redelegation_amount: ::core::option::Option<
+        super::super::super::num::v1alpha1::Amount,
+    >,
+}
+impl ::prost::Name for RedelegateClaim {
+    const NAME: &'static str = "RedelegateClaim";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
+    }
+}
 /// A transaction action finishing an undelegation, converting (slashable)
 /// "unbonding tokens" to (unslashable) staking tokens.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -524,6 +684,8 @@ pub struct DelegationChanges {
     pub delegations: ::prost::alloc::vec::Vec<Delegate>,
     #[prost(message, repeated, tag = "2")]
     pub undelegations: ::prost::alloc::vec::Vec<Undelegate>,
+    #[prost(message, repeated, tag = "3")]
+    pub redelegations: ::prost::alloc::vec::Vec<Redelegate>,
 }
 impl ::prost::Name for DelegationChanges {
     const NAME: &'static str = "DelegationChanges";
@@ -710,6 +872,33 @@ impl ::prost::Name for CurrentValidatorRateResponse {
         ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingValidatorDefinitionsRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+impl ::prost::Name for PendingValidatorDefinitionsRequest {
+    const NAME: &'static str = "PendingValidatorDefinitionsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingValidatorDefinitionsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub pending_definition: ::core::option::Option<PendingValidatorDefinition>,
+}
+impl ::prost::Name for PendingValidatorDefinitionsResponse {
+    const NAME: &'static str = "PendingValidatorDefinitionsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1alpha1.{}", Self::NAME)
+    }
+}
 /// Staking configuration data.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -735,6 +924,15 @@ pub struct StakeParameters {
     /// The maximum number of blocks in the window each validator can miss signing without slashing.
     #[prost(uint64, tag = "7")]
     pub missed_blocks_maximum: u64,
+    /// The number of blocks a jailed validator must wait before it can be unjailed.
+    #[prost(uint64, tag = "8")]
+    pub jail_duration_blocks: u64,
+    /// The maximum change in a validator's total commission within an epoch, in basis points.
+    #[prost(uint64, tag = "9")]
+    pub max_commission_change_bps_per_epoch: u64,
+    /// The number of epochs a validator must wait before an increase of its commission takes effect.
+    #[prost(uint64, tag = "10")]
+    pub commission_increase_notice_epochs: u64,
 }
 impl ::prost::Name for StakeParameters {
     const NAME: &'static str = "StakeParameters";
@@ -969,6 +1167,39 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Queries the validator definitions whose commission increases are waiting out their notice period.
+        pub async fn pending_validator_definitions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PendingValidatorDefinitionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::PendingValidatorDefinitionsResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.stake.v1alpha1.QueryService/PendingValidatorDefinitions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.stake.v1alpha1.QueryService",
+                        "PendingValidatorDefinitions",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -1014,6 +1245,23 @@ pub mod query_service_server {
             tonic::Response<super::CurrentValidatorRateResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the PendingValidatorDefinitions method.
+        type PendingValidatorDefinitionsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::PendingValidatorDefinitionsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Queries the validator definitions whose commission increases are waiting out their notice period.
+        async fn pending_validator_definitions(
+            &self,
+            request: tonic::Request<super::PendingValidatorDefinitionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::PendingValidatorDefinitionsStream>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the staking component.
     #[derive(Debug)]
@@ -1282,6 +1530,60 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.stake.v1alpha1.QueryService/PendingValidatorDefinitions" => {
+                    #[allow(non_camel_case_types)]
+                    struct PendingValidatorDefinitionsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::PendingValidatorDefinitionsRequest,
+                    > for PendingValidatorDefinitionsSvc<T> {
+                        type Response = super::PendingValidatorDefinitionsResponse;
+                        type ResponseStream = T::PendingValidatorDefinitionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::PendingValidatorDefinitionsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::pending_validator_definitions(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PendingValidatorDefinitionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
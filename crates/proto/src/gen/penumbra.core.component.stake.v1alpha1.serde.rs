@@ -782,6 +782,9 @@ impl serde::Serialize for DelegationChanges {
         if !self.undelegations.is_empty() {
             len += 1;
         }
+        if !self.redelegations.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.DelegationChanges", len)?;
         if !self.delegations.is_empty() {
             struct_ser.serialize_field("delegations", &self.delegations)?;
@@ -789,6 +792,9 @@ impl serde::Serialize for DelegationChanges {
         if !self.undelegations.is_empty() {
             struct_ser.serialize_field("undelegations", &self.undelegations)?;
         }
+        if !self.redelegations.is_empty() {
+            struct_ser.serialize_field("redelegations", &self.redelegations)?;
+        }
         struct_ser.end()
     }
 }
@@ -801,12 +807,14 @@ impl<'de> serde::Deserialize<'de> for DelegationChanges {
         const FIELDS: &[&str] = &[
             "delegations",
             "undelegations",
+            "redelegations",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Delegations,
             Undelegations,
+            Redelegations,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -830,6 +838,7 @@ impl<'de> serde::Deserialize<'de> for DelegationChanges {
                         match value {
                             "delegations" => Ok(GeneratedField::Delegations),
                             "undelegations" => Ok(GeneratedField::Undelegations),
+                            "redelegations" => Ok(GeneratedField::Redelegations),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -851,6 +860,7 @@ impl<'de> serde::Deserialize<'de> for DelegationChanges {
             {
                 let mut delegations__ = None;
                 let mut undelegations__ = None;
+                let mut redelegations__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Delegations => {
@@ -865,11 +875,18 @@ impl<'de> serde::Deserialize<'de> for DelegationChanges {
                             }
                             undelegations__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Redelegations => {
+                            if redelegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegations"));
+                            }
+                            redelegations__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(DelegationChanges {
                     delegations: delegations__.unwrap_or_default(),
                     undelegations: undelegations__.unwrap_or_default(),
+                    redelegations: redelegations__.unwrap_or_default(),
                 })
             }
         }
@@ -1395,7 +1412,7 @@ impl<'de> serde::Deserialize<'de> for Penalty {
         deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.Penalty", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for RateData {
+impl serde::Serialize for PendingValidatorDefinition {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1406,35 +1423,27 @@ impl serde::Serialize for RateData {
         if self.identity_key.is_some() {
             len += 1;
         }
-        if self.epoch_index != 0 {
-            len += 1;
-        }
-        if self.validator_reward_rate != 0 {
+        if !self.funding_streams.is_empty() {
             len += 1;
         }
-        if self.validator_exchange_rate != 0 {
+        if self.effective_epoch_index != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.RateData", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.PendingValidatorDefinition", len)?;
         if let Some(v) = self.identity_key.as_ref() {
             struct_ser.serialize_field("identityKey", v)?;
         }
-        if self.epoch_index != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
-        }
-        if self.validator_reward_rate != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("validatorRewardRate", ToString::to_string(&self.validator_reward_rate).as_str())?;
+        if !self.funding_streams.is_empty() {
+            struct_ser.serialize_field("fundingStreams", &self.funding_streams)?;
         }
-        if self.validator_exchange_rate != 0 {
+        if self.effective_epoch_index != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("validatorExchangeRate", ToString::to_string(&self.validator_exchange_rate).as_str())?;
+            struct_ser.serialize_field("effectiveEpochIndex", ToString::to_string(&self.effective_epoch_index).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for RateData {
+impl<'de> serde::Deserialize<'de> for PendingValidatorDefinition {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1443,20 +1452,17 @@ impl<'de> serde::Deserialize<'de> for RateData {
         const FIELDS: &[&str] = &[
             "identity_key",
             "identityKey",
-            "epoch_index",
-            "epochIndex",
-            "validator_reward_rate",
-            "validatorRewardRate",
-            "validator_exchange_rate",
-            "validatorExchangeRate",
+            "funding_streams",
+            "fundingStreams",
+            "effective_epoch_index",
+            "effectiveEpochIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             IdentityKey,
-            EpochIndex,
-            ValidatorRewardRate,
-            ValidatorExchangeRate,
+            FundingStreams,
+            EffectiveEpochIndex,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1479,9 +1485,8 @@ impl<'de> serde::Deserialize<'de> for RateData {
                     {
                         match value {
                             "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
-                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
-                            "validatorRewardRate" | "validator_reward_rate" => Ok(GeneratedField::ValidatorRewardRate),
-                            "validatorExchangeRate" | "validator_exchange_rate" => Ok(GeneratedField::ValidatorExchangeRate),
+                            "fundingStreams" | "funding_streams" => Ok(GeneratedField::FundingStreams),
+                            "effectiveEpochIndex" | "effective_epoch_index" => Ok(GeneratedField::EffectiveEpochIndex),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1491,20 +1496,19 @@ impl<'de> serde::Deserialize<'de> for RateData {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = RateData;
+            type Value = PendingValidatorDefinition;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.stake.v1alpha1.RateData")
+                formatter.write_str("struct penumbra.core.component.stake.v1alpha1.PendingValidatorDefinition")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RateData, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingValidatorDefinition, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut identity_key__ = None;
-                let mut epoch_index__ = None;
-                let mut validator_reward_rate__ = None;
-                let mut validator_exchange_rate__ = None;
+                let mut funding_streams__ = None;
+                let mut effective_epoch_index__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::IdentityKey => {
@@ -1513,41 +1517,720 @@ impl<'de> serde::Deserialize<'de> for RateData {
                             }
                             identity_key__ = map_.next_value()?;
                         }
-                        GeneratedField::EpochIndex => {
-                            if epoch_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                        GeneratedField::FundingStreams => {
+                            if funding_streams__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fundingStreams"));
+                            }
+                            funding_streams__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EffectiveEpochIndex => {
+                            if effective_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("effectiveEpochIndex"));
+                            }
+                            effective_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PendingValidatorDefinition {
+                    identity_key: identity_key__,
+                    funding_streams: funding_streams__.unwrap_or_default(),
+                    effective_epoch_index: effective_epoch_index__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.PendingValidatorDefinition", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PendingValidatorDefinitionsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.PendingValidatorDefinitionsRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PendingValidatorDefinitionsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PendingValidatorDefinitionsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1alpha1.PendingValidatorDefinitionsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingValidatorDefinitionsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(PendingValidatorDefinitionsRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.PendingValidatorDefinitionsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PendingValidatorDefinitionsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.pending_definition.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.PendingValidatorDefinitionsResponse", len)?;
+        if let Some(v) = self.pending_definition.as_ref() {
+            struct_ser.serialize_field("pendingDefinition", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PendingValidatorDefinitionsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "pending_definition",
+            "pendingDefinition",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PendingDefinition,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pendingDefinition" | "pending_definition" => Ok(GeneratedField::PendingDefinition),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PendingValidatorDefinitionsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1alpha1.PendingValidatorDefinitionsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingValidatorDefinitionsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut pending_definition__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PendingDefinition => {
+                            if pending_definition__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pendingDefinition"));
+                            }
+                            pending_definition__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(PendingValidatorDefinitionsResponse {
+                    pending_definition: pending_definition__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.PendingValidatorDefinitionsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RateData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.epoch_index != 0 {
+            len += 1;
+        }
+        if self.validator_reward_rate != 0 {
+            len += 1;
+        }
+        if self.validator_exchange_rate != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.RateData", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if self.epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+        }
+        if self.validator_reward_rate != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("validatorRewardRate", ToString::to_string(&self.validator_reward_rate).as_str())?;
+        }
+        if self.validator_exchange_rate != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("validatorExchangeRate", ToString::to_string(&self.validator_exchange_rate).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RateData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "epoch_index",
+            "epochIndex",
+            "validator_reward_rate",
+            "validatorRewardRate",
+            "validator_exchange_rate",
+            "validatorExchangeRate",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            EpochIndex,
+            ValidatorRewardRate,
+            ValidatorExchangeRate,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "validatorRewardRate" | "validator_reward_rate" => Ok(GeneratedField::ValidatorRewardRate),
+                            "validatorExchangeRate" | "validator_exchange_rate" => Ok(GeneratedField::ValidatorExchangeRate),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RateData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1alpha1.RateData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RateData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut epoch_index__ = None;
+                let mut validator_reward_rate__ = None;
+                let mut validator_exchange_rate__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                            }
+                            epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ValidatorRewardRate => {
+                            if validator_reward_rate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorRewardRate"));
+                            }
+                            validator_reward_rate__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ValidatorExchangeRate => {
+                            if validator_exchange_rate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorExchangeRate"));
+                            }
+                            validator_exchange_rate__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(RateData {
+                    identity_key: identity_key__,
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    validator_reward_rate: validator_reward_rate__.unwrap_or_default(),
+                    validator_exchange_rate: validator_exchange_rate__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.RateData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Redelegate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.from_validator_identity.is_some() {
+            len += 1;
+        }
+        if self.to_validator_identity.is_some() {
+            len += 1;
+        }
+        if self.epoch_index != 0 {
+            len += 1;
+        }
+        if self.unbonded_amount.is_some() {
+            len += 1;
+        }
+        if self.from_delegation_amount.is_some() {
+            len += 1;
+        }
+        if self.to_delegation_amount.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.Redelegate", len)?;
+        if let Some(v) = self.from_validator_identity.as_ref() {
+            struct_ser.serialize_field("fromValidatorIdentity", v)?;
+        }
+        if let Some(v) = self.to_validator_identity.as_ref() {
+            struct_ser.serialize_field("toValidatorIdentity", v)?;
+        }
+        if self.epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+        }
+        if let Some(v) = self.unbonded_amount.as_ref() {
+            struct_ser.serialize_field("unbondedAmount", v)?;
+        }
+        if let Some(v) = self.from_delegation_amount.as_ref() {
+            struct_ser.serialize_field("fromDelegationAmount", v)?;
+        }
+        if let Some(v) = self.to_delegation_amount.as_ref() {
+            struct_ser.serialize_field("toDelegationAmount", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Redelegate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "from_validator_identity",
+            "fromValidatorIdentity",
+            "to_validator_identity",
+            "toValidatorIdentity",
+            "epoch_index",
+            "epochIndex",
+            "unbonded_amount",
+            "unbondedAmount",
+            "from_delegation_amount",
+            "fromDelegationAmount",
+            "to_delegation_amount",
+            "toDelegationAmount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FromValidatorIdentity,
+            ToValidatorIdentity,
+            EpochIndex,
+            UnbondedAmount,
+            FromDelegationAmount,
+            ToDelegationAmount,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fromValidatorIdentity" | "from_validator_identity" => Ok(GeneratedField::FromValidatorIdentity),
+                            "toValidatorIdentity" | "to_validator_identity" => Ok(GeneratedField::ToValidatorIdentity),
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
+                            "fromDelegationAmount" | "from_delegation_amount" => Ok(GeneratedField::FromDelegationAmount),
+                            "toDelegationAmount" | "to_delegation_amount" => Ok(GeneratedField::ToDelegationAmount),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Redelegate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1alpha1.Redelegate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Redelegate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut from_validator_identity__ = None;
+                let mut to_validator_identity__ = None;
+                let mut epoch_index__ = None;
+                let mut unbonded_amount__ = None;
+                let mut from_delegation_amount__ = None;
+                let mut to_delegation_amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FromValidatorIdentity => {
+                            if from_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromValidatorIdentity"));
+                            }
+                            from_validator_identity__ = map_.next_value()?;
+                        }
+                        GeneratedField::ToValidatorIdentity => {
+                            if to_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toValidatorIdentity"));
+                            }
+                            to_validator_identity__ = map_.next_value()?;
+                        }
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                            }
+                            epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::UnbondedAmount => {
+                            if unbonded_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondedAmount"));
+                            }
+                            unbonded_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::FromDelegationAmount => {
+                            if from_delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromDelegationAmount"));
+                            }
+                            from_delegation_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::ToDelegationAmount => {
+                            if to_delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toDelegationAmount"));
+                            }
+                            to_delegation_amount__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(Redelegate {
+                    from_validator_identity: from_validator_identity__,
+                    to_validator_identity: to_validator_identity__,
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    unbonded_amount: unbonded_amount__,
+                    from_delegation_amount: from_delegation_amount__,
+                    to_delegation_amount: to_delegation_amount__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.Redelegate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RedelegateClaim {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.from_validator_identity.is_some() {
+            len += 1;
+        }
+        if self.to_validator_identity.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.penalty.is_some() {
+            len += 1;
+        }
+        if self.redelegation_amount.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.RedelegateClaim", len)?;
+        if let Some(v) = self.from_validator_identity.as_ref() {
+            struct_ser.serialize_field("fromValidatorIdentity", v)?;
+        }
+        if let Some(v) = self.to_validator_identity.as_ref() {
+            struct_ser.serialize_field("toValidatorIdentity", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
+        }
+        if let Some(v) = self.redelegation_amount.as_ref() {
+            struct_ser.serialize_field("redelegationAmount", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RedelegateClaim {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "from_validator_identity",
+            "fromValidatorIdentity",
+            "to_validator_identity",
+            "toValidatorIdentity",
+            "start_epoch_index",
+            "startEpochIndex",
+            "penalty",
+            "redelegation_amount",
+            "redelegationAmount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FromValidatorIdentity,
+            ToValidatorIdentity,
+            StartEpochIndex,
+            Penalty,
+            RedelegationAmount,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fromValidatorIdentity" | "from_validator_identity" => Ok(GeneratedField::FromValidatorIdentity),
+                            "toValidatorIdentity" | "to_validator_identity" => Ok(GeneratedField::ToValidatorIdentity),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            "redelegationAmount" | "redelegation_amount" => Ok(GeneratedField::RedelegationAmount),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RedelegateClaim;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1alpha1.RedelegateClaim")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RedelegateClaim, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut from_validator_identity__ = None;
+                let mut to_validator_identity__ = None;
+                let mut start_epoch_index__ = None;
+                let mut penalty__ = None;
+                let mut redelegation_amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FromValidatorIdentity => {
+                            if from_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromValidatorIdentity"));
+                            }
+                            from_validator_identity__ = map_.next_value()?;
+                        }
+                        GeneratedField::ToValidatorIdentity => {
+                            if to_validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toValidatorIdentity"));
+                            }
+                            to_validator_identity__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
                             }
-                            epoch_index__ = 
+                            start_epoch_index__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::ValidatorRewardRate => {
-                            if validator_reward_rate__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validatorRewardRate"));
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
                             }
-                            validator_reward_rate__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            penalty__ = map_.next_value()?;
                         }
-                        GeneratedField::ValidatorExchangeRate => {
-                            if validator_exchange_rate__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validatorExchangeRate"));
+                        GeneratedField::RedelegationAmount => {
+                            if redelegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegationAmount"));
                             }
-                            validator_exchange_rate__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            redelegation_amount__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(RateData {
-                    identity_key: identity_key__,
-                    epoch_index: epoch_index__.unwrap_or_default(),
-                    validator_reward_rate: validator_reward_rate__.unwrap_or_default(),
-                    validator_exchange_rate: validator_exchange_rate__.unwrap_or_default(),
+                Ok(RedelegateClaim {
+                    from_validator_identity: from_validator_identity__,
+                    to_validator_identity: to_validator_identity__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    penalty: penalty__,
+                    redelegation_amount: redelegation_amount__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.RateData", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.RedelegateClaim", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for StakeParameters {
@@ -1579,6 +2262,15 @@ impl serde::Serialize for StakeParameters {
         if self.missed_blocks_maximum != 0 {
             len += 1;
         }
+        if self.jail_duration_blocks != 0 {
+            len += 1;
+        }
+        if self.max_commission_change_bps_per_epoch != 0 {
+            len += 1;
+        }
+        if self.commission_increase_notice_epochs != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.StakeParameters", len)?;
         if self.unbonding_epochs != 0 {
             #[allow(clippy::needless_borrow)]
@@ -1608,6 +2300,18 @@ impl serde::Serialize for StakeParameters {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("missedBlocksMaximum", ToString::to_string(&self.missed_blocks_maximum).as_str())?;
         }
+        if self.jail_duration_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("jailDurationBlocks", ToString::to_string(&self.jail_duration_blocks).as_str())?;
+        }
+        if self.max_commission_change_bps_per_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("maxCommissionChangeBpsPerEpoch", ToString::to_string(&self.max_commission_change_bps_per_epoch).as_str())?;
+        }
+        if self.commission_increase_notice_epochs != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("commissionIncreaseNoticeEpochs", ToString::to_string(&self.commission_increase_notice_epochs).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -1632,6 +2336,12 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
             "signedBlocksWindowLen",
             "missed_blocks_maximum",
             "missedBlocksMaximum",
+            "jail_duration_blocks",
+            "jailDurationBlocks",
+            "max_commission_change_bps_per_epoch",
+            "maxCommissionChangeBpsPerEpoch",
+            "commission_increase_notice_epochs",
+            "commissionIncreaseNoticeEpochs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1643,6 +2353,9 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
             SlashingPenaltyDowntime,
             SignedBlocksWindowLen,
             MissedBlocksMaximum,
+            JailDurationBlocks,
+            MaxCommissionChangeBpsPerEpoch,
+            CommissionIncreaseNoticeEpochs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1671,6 +2384,9 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
                             "slashingPenaltyDowntime" | "slashing_penalty_downtime" => Ok(GeneratedField::SlashingPenaltyDowntime),
                             "signedBlocksWindowLen" | "signed_blocks_window_len" => Ok(GeneratedField::SignedBlocksWindowLen),
                             "missedBlocksMaximum" | "missed_blocks_maximum" => Ok(GeneratedField::MissedBlocksMaximum),
+                            "jailDurationBlocks" | "jail_duration_blocks" => Ok(GeneratedField::JailDurationBlocks),
+                            "maxCommissionChangeBpsPerEpoch" | "max_commission_change_bps_per_epoch" => Ok(GeneratedField::MaxCommissionChangeBpsPerEpoch),
+                            "commissionIncreaseNoticeEpochs" | "commission_increase_notice_epochs" => Ok(GeneratedField::CommissionIncreaseNoticeEpochs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1697,6 +2413,9 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
                 let mut slashing_penalty_downtime__ = None;
                 let mut signed_blocks_window_len__ = None;
                 let mut missed_blocks_maximum__ = None;
+                let mut jail_duration_blocks__ = None;
+                let mut max_commission_change_bps_per_epoch__ = None;
+                let mut commission_increase_notice_epochs__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::UnbondingEpochs => {
@@ -1755,6 +2474,30 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::JailDurationBlocks => {
+                            if jail_duration_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("jailDurationBlocks"));
+                            }
+                            jail_duration_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxCommissionChangeBpsPerEpoch => {
+                            if max_commission_change_bps_per_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxCommissionChangeBpsPerEpoch"));
+                            }
+                            max_commission_change_bps_per_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CommissionIncreaseNoticeEpochs => {
+                            if commission_increase_notice_epochs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commissionIncreaseNoticeEpochs"));
+                            }
+                            commission_increase_notice_epochs__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(StakeParameters {
@@ -1765,6 +2508,9 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
                     slashing_penalty_downtime: slashing_penalty_downtime__.unwrap_or_default(),
                     signed_blocks_window_len: signed_blocks_window_len__.unwrap_or_default(),
                     missed_blocks_maximum: missed_blocks_maximum__.unwrap_or_default(),
+                    jail_duration_blocks: jail_duration_blocks__.unwrap_or_default(),
+                    max_commission_change_bps_per_epoch: max_commission_change_bps_per_epoch__.unwrap_or_default(),
+                    commission_increase_notice_epochs: commission_increase_notice_epochs__.unwrap_or_default(),
                 })
             }
         }
@@ -4062,6 +4808,246 @@ impl<'de> serde::Deserialize<'de> for ValidatorStatusResponse {
         deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.ValidatorStatusResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ValidatorUnjail {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.body.is_some() {
+            len += 1;
+        }
+        if self.auth_sig.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.ValidatorUnjail", len)?;
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
+        }
+        if let Some(v) = self.auth_sig.as_ref() {
+            struct_ser.serialize_field("authSig", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorUnjail {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "body",
+            "auth_sig",
+            "authSig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Body,
+            AuthSig,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "body" => Ok(GeneratedField::Body),
+                            "authSig" | "auth_sig" => Ok(GeneratedField::AuthSig),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorUnjail;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1alpha1.ValidatorUnjail")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorUnjail, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut body__ = None;
+                let mut auth_sig__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
+                            }
+                            body__ = map_.next_value()?;
+                        }
+                        GeneratedField::AuthSig => {
+                            if auth_sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authSig"));
+                            }
+                            auth_sig__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ValidatorUnjail {
+                    body: body__,
+                    auth_sig: auth_sig__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.ValidatorUnjail", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorUnjailBody {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.governance_key.is_some() {
+            len += 1;
+        }
+        if self.jail_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1alpha1.ValidatorUnjailBody", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if let Some(v) = self.governance_key.as_ref() {
+            struct_ser.serialize_field("governanceKey", v)?;
+        }
+        if self.jail_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("jailHeight", ToString::to_string(&self.jail_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorUnjailBody {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "governance_key",
+            "governanceKey",
+            "jail_height",
+            "jailHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            GovernanceKey,
+            JailHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "governanceKey" | "governance_key" => Ok(GeneratedField::GovernanceKey),
+                            "jailHeight" | "jail_height" => Ok(GeneratedField::JailHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorUnjailBody;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1alpha1.ValidatorUnjailBody")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorUnjailBody, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut governance_key__ = None;
+                let mut jail_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::GovernanceKey => {
+                            if governance_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governanceKey"));
+                            }
+                            governance_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::JailHeight => {
+                            if jail_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("jailHeight"));
+                            }
+                            jail_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ValidatorUnjailBody {
+                    identity_key: identity_key__,
+                    governance_key: governance_key__,
+                    jail_height: jail_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1alpha1.ValidatorUnjailBody", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ZkUndelegateClaimProof {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
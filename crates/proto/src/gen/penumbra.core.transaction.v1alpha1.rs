@@ -122,7 +122,7 @@ impl ::prost::Name for DetectionData {
 pub struct Action {
     #[prost(
         oneof = "action::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52, 200"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 43, 44, 45, 50, 51, 52, 200"
     )]
     pub action: ::core::option::Option<action::Action>,
 }
@@ -189,6 +189,16 @@ pub mod action {
         UndelegateClaim(
             super::super::super::component::stake::v1alpha1::UndelegateClaim,
         ),
+        #[prost(message, tag = "43")]
+        ValidatorUnjail(
+            super::super::super::component::stake::v1alpha1::ValidatorUnjail,
+        ),
+        #[prost(message, tag = "44")]
+        Redelegate(super::super::super::component::stake::v1alpha1::Redelegate),
+        #[prost(message, tag = "45")]
+        RedelegateClaim(
+            super::super::super::component::stake::v1alpha1::RedelegateClaim,
+        ),
         /// DAO
         #[prost(message, tag = "50")]
         DaoSpend(super::super::super::component::governance::v1alpha1::DaoSpend),
@@ -335,7 +345,7 @@ impl ::prost::Name for TransactionBodyView {
 pub struct ActionView {
     #[prost(
         oneof = "action_view::ActionView",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 41, 42, 50, 51, 52, 43, 200"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 41, 42, 44, 45, 46, 50, 51, 52, 43, 200"
     )]
     pub action_view: ::core::option::Option<action_view::ActionView>,
 }
@@ -397,6 +407,16 @@ pub mod action_view {
         Delegate(super::super::super::component::stake::v1alpha1::Delegate),
         #[prost(message, tag = "42")]
         Undelegate(super::super::super::component::stake::v1alpha1::Undelegate),
+        #[prost(message, tag = "44")]
+        ValidatorUnjail(
+            super::super::super::component::stake::v1alpha1::ValidatorUnjail,
+        ),
+        #[prost(message, tag = "45")]
+        Redelegate(super::super::super::component::stake::v1alpha1::Redelegate),
+        #[prost(message, tag = "46")]
+        RedelegateClaim(
+            super::super::super::component::stake::v1alpha1::RedelegateClaim,
+        ),
         /// DAO
         #[prost(message, tag = "50")]
         DaoSpend(super::super::super::component::governance::v1alpha1::DaoSpend),
@@ -525,7 +545,7 @@ impl ::prost::Name for TransactionPlan {
 pub struct ActionPlan {
     #[prost(
         oneof = "action_plan::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 23, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 23, 30, 31, 32, 34, 40, 41, 42, 43, 44, 45, 50, 51, 52"
     )]
     pub action: ::core::option::Option<action_plan::Action>,
 }
@@ -598,6 +618,20 @@ pub mod action_plan {
         UndelegateClaim(
             super::super::super::component::stake::v1alpha1::UndelegateClaimPlan,
         ),
+        /// This is just a message relayed to the chain.
+        #[prost(message, tag = "43")]
+        ValidatorUnjail(
+            super::super::super::component::stake::v1alpha1::ValidatorUnjail,
+        ),
+        /// We don't need any extra information (yet) to understand redelegations,
+        /// because we don't yet use flow encryption.
+        #[prost(message, tag = "44")]
+        Redelegate(super::super::super::component::stake::v1alpha1::Redelegate),
+        /// Redelegation claims are transparent, so they need no extra information.
+        #[prost(message, tag = "45")]
+        RedelegateClaim(
+            super::super::super::component::stake::v1alpha1::RedelegateClaim,
+        ),
         /// DAO
         #[prost(message, tag = "50")]
         DaoSpend(super::super::super::component::governance::v1alpha1::DaoSpend),
@@ -681,6 +715,67 @@ impl ::prost::Name for MemoPlaintext {
         ::prost::alloc::format!("penumbra.core.transaction.v1alpha1.{}", Self::NAME)
     }
 }
+/// A request for payment, shared by the payee with the payer out of band.
+///
+/// Paying the request sends each of the requested values to its address, with a memo ending in a
+/// reference to the request, so that the payee can match the notes it receives to the request.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaymentRequest {
+    /// The address to pay.
+    #[prost(message, optional, tag = "1")]
+    pub address: ::core::option::Option<super::super::keys::v1alpha1::Address>,
+    /// The values requested.
+    #[prost(message, repeated, tag = "2")]
+    pub values: ::prost::alloc::vec::Vec<super::super::asset::v1alpha1::Value>,
+    /// Text to include in the memo of the payment.
+    #[prost(string, tag = "3")]
+    pub memo: ::prost::alloc::string::String,
+    /// If nonzero, the height after which the request can no longer be paid.
+    #[prost(uint64, tag = "4")]
+    pub expiry_height: u64,
+    /// The invoice id of the request, signed by the payee, if any.
+    #[prost(message, optional, tag = "5")]
+    pub invoice: ::core::option::Option<payment_request::SignedInvoice>,
+}
+/// Nested message and enum types in `PaymentRequest`.
+pub mod payment_request {
+    /// An invoice id chosen by the payee, signed with the spend authorization key of its wallet,
+    /// randomized for the address of the request.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SignedInvoice {
+        /// The invoice id.
+        #[prost(string, tag = "1")]
+        pub id: ::prost::alloc::string::String,
+        /// The payee's spend verification key, randomized for the address of the request.
+        #[prost(message, optional, tag = "2")]
+        pub verification_key: ::core::option::Option<
+            super::super::super::keys::v1alpha1::SpendVerificationKey,
+        >,
+        /// The payee's signature over the rest of the payment request and the invoice id.
+        #[prost(message, optional, tag = "3")]
+        pub signature: ::core::option::Option<
+            super::super::super::super::crypto::decaf377_rdsa::v1alpha1::SpendAuthSignature,
+        >,
+    }
+    impl ::prost::Name for SignedInvoice {
+        const NAME: &'static str = "SignedInvoice";
+        const PACKAGE: &'static str = "penumbra.core.transaction.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.transaction.v1alpha1.PaymentRequest.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for PaymentRequest {
+    const NAME: &'static str = "PaymentRequest";
+    const PACKAGE: &'static str = "penumbra.core.transaction.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.transaction.v1alpha1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MemoPlaintextView {
@@ -66,6 +66,15 @@ impl serde::Serialize for Action {
                 action::Action::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
+                action::Action::ValidatorUnjail(v) => {
+                    struct_ser.serialize_field("validatorUnjail", v)?;
+                }
+                action::Action::Redelegate(v) => {
+                    struct_ser.serialize_field("redelegate", v)?;
+                }
+                action::Action::RedelegateClaim(v) => {
+                    struct_ser.serialize_field("redelegateClaim", v)?;
+                }
                 action::Action::DaoSpend(v) => {
                     struct_ser.serialize_field("daoSpend", v)?;
                 }
@@ -121,6 +130,11 @@ impl<'de> serde::Deserialize<'de> for Action {
             "undelegate",
             "undelegate_claim",
             "undelegateClaim",
+            "validator_unjail",
+            "validatorUnjail",
+            "redelegate",
+            "redelegate_claim",
+            "redelegateClaim",
             "dao_spend",
             "daoSpend",
             "dao_output",
@@ -151,6 +165,9 @@ impl<'de> serde::Deserialize<'de> for Action {
             Delegate,
             Undelegate,
             UndelegateClaim,
+            ValidatorUnjail,
+            Redelegate,
+            RedelegateClaim,
             DaoSpend,
             DaoOutput,
             DaoDeposit,
@@ -194,6 +211,9 @@ impl<'de> serde::Deserialize<'de> for Action {
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
+                            "validatorUnjail" | "validator_unjail" => Ok(GeneratedField::ValidatorUnjail),
+                            "redelegate" => Ok(GeneratedField::Redelegate),
+                            "redelegateClaim" | "redelegate_claim" => Ok(GeneratedField::RedelegateClaim),
                             "daoSpend" | "dao_spend" => Ok(GeneratedField::DaoSpend),
                             "daoOutput" | "dao_output" => Ok(GeneratedField::DaoOutput),
                             "daoDeposit" | "dao_deposit" => Ok(GeneratedField::DaoDeposit),
@@ -344,6 +364,27 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("undelegateClaim"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::UndelegateClaim)
+;
+                        }
+                        GeneratedField::ValidatorUnjail => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorUnjail"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::ValidatorUnjail)
+;
+                        }
+                        GeneratedField::Redelegate => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegate"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::Redelegate)
+;
+                        }
+                        GeneratedField::RedelegateClaim => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegateClaim"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::RedelegateClaim)
 ;
                         }
                         GeneratedField::DaoSpend => {
@@ -455,6 +496,15 @@ impl serde::Serialize for ActionPlan {
                 action_plan::Action::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
+                action_plan::Action::ValidatorUnjail(v) => {
+                    struct_ser.serialize_field("validatorUnjail", v)?;
+                }
+                action_plan::Action::Redelegate(v) => {
+                    struct_ser.serialize_field("redelegate", v)?;
+                }
+                action_plan::Action::RedelegateClaim(v) => {
+                    struct_ser.serialize_field("redelegateClaim", v)?;
+                }
                 action_plan::Action::DaoSpend(v) => {
                     struct_ser.serialize_field("daoSpend", v)?;
                 }
@@ -508,6 +558,11 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             "undelegate",
             "undelegate_claim",
             "undelegateClaim",
+            "validator_unjail",
+            "validatorUnjail",
+            "redelegate",
+            "redelegate_claim",
+            "redelegateClaim",
             "dao_spend",
             "daoSpend",
             "dao_output",
@@ -537,6 +592,9 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             Delegate,
             Undelegate,
             UndelegateClaim,
+            ValidatorUnjail,
+            Redelegate,
+            RedelegateClaim,
             DaoSpend,
             DaoOutput,
             DaoDeposit,
@@ -580,6 +638,9 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
+                            "validatorUnjail" | "validator_unjail" => Ok(GeneratedField::ValidatorUnjail),
+                            "redelegate" => Ok(GeneratedField::Redelegate),
+                            "redelegateClaim" | "redelegate_claim" => Ok(GeneratedField::RedelegateClaim),
                             "daoSpend" | "dao_spend" => Ok(GeneratedField::DaoSpend),
                             "daoOutput" | "dao_output" => Ok(GeneratedField::DaoOutput),
                             "daoDeposit" | "dao_deposit" => Ok(GeneratedField::DaoDeposit),
@@ -736,6 +797,27 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                                 return Err(serde::de::Error::duplicate_field("undelegateClaim"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::UndelegateClaim)
+;
+                        }
+                        GeneratedField::ValidatorUnjail => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorUnjail"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::ValidatorUnjail)
+;
+                        }
+                        GeneratedField::Redelegate => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegate"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::Redelegate)
+;
+                        }
+                        GeneratedField::RedelegateClaim => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegateClaim"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::RedelegateClaim)
 ;
                         }
                         GeneratedField::DaoSpend => {
@@ -834,6 +916,15 @@ impl serde::Serialize for ActionView {
                 action_view::ActionView::Undelegate(v) => {
                     struct_ser.serialize_field("undelegate", v)?;
                 }
+                action_view::ActionView::ValidatorUnjail(v) => {
+                    struct_ser.serialize_field("validatorUnjail", v)?;
+                }
+                action_view::ActionView::Redelegate(v) => {
+                    struct_ser.serialize_field("redelegate", v)?;
+                }
+                action_view::ActionView::RedelegateClaim(v) => {
+                    struct_ser.serialize_field("redelegateClaim", v)?;
+                }
                 action_view::ActionView::DaoSpend(v) => {
                     struct_ser.serialize_field("daoSpend", v)?;
                 }
@@ -890,6 +981,11 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             "positionRewardClaim",
             "delegate",
             "undelegate",
+            "validator_unjail",
+            "validatorUnjail",
+            "redelegate",
+            "redelegate_claim",
+            "redelegateClaim",
             "dao_spend",
             "daoSpend",
             "dao_output",
@@ -921,6 +1017,9 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             PositionRewardClaim,
             Delegate,
             Undelegate,
+            ValidatorUnjail,
+            Redelegate,
+            RedelegateClaim,
             DaoSpend,
             DaoOutput,
             DaoDeposit,
@@ -964,6 +1063,9 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                             "positionRewardClaim" | "position_reward_claim" => Ok(GeneratedField::PositionRewardClaim),
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
+                            "validatorUnjail" | "validator_unjail" => Ok(GeneratedField::ValidatorUnjail),
+                            "redelegate" => Ok(GeneratedField::Redelegate),
+                            "redelegateClaim" | "redelegate_claim" => Ok(GeneratedField::RedelegateClaim),
                             "daoSpend" | "dao_spend" => Ok(GeneratedField::DaoSpend),
                             "daoOutput" | "dao_output" => Ok(GeneratedField::DaoOutput),
                             "daoDeposit" | "dao_deposit" => Ok(GeneratedField::DaoDeposit),
@@ -1108,6 +1210,27 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                                 return Err(serde::de::Error::duplicate_field("undelegate"));
                             }
                             action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::Undelegate)
+;
+                        }
+                        GeneratedField::ValidatorUnjail => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorUnjail"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::ValidatorUnjail)
+;
+                        }
+                        GeneratedField::Redelegate => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegate"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::Redelegate)
+;
+                        }
+                        GeneratedField::RedelegateClaim => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegateClaim"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::RedelegateClaim)
 ;
                         }
                         GeneratedField::DaoSpend => {
@@ -2738,6 +2861,295 @@ impl<'de> serde::Deserialize<'de> for PayloadKeyWithCommitment {
         deserializer.deserialize_struct("penumbra.core.transaction.v1alpha1.PayloadKeyWithCommitment", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for PaymentRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.address.is_some() {
+            len += 1;
+        }
+        if !self.values.is_empty() {
+            len += 1;
+        }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
+        if self.expiry_height != 0 {
+            len += 1;
+        }
+        if self.invoice.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.transaction.v1alpha1.PaymentRequest", len)?;
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
+        }
+        if !self.values.is_empty() {
+            struct_ser.serialize_field("values", &self.values)?;
+        }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
+        if self.expiry_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expiryHeight", ToString::to_string(&self.expiry_height).as_str())?;
+        }
+        if let Some(v) = self.invoice.as_ref() {
+            struct_ser.serialize_field("invoice", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PaymentRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "address",
+            "values",
+            "memo",
+            "expiry_height",
+            "expiryHeight",
+            "invoice",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Address,
+            Values,
+            Memo,
+            ExpiryHeight,
+            Invoice,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "address" => Ok(GeneratedField::Address),
+                            "values" => Ok(GeneratedField::Values),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "expiryHeight" | "expiry_height" => Ok(GeneratedField::ExpiryHeight),
+                            "invoice" => Ok(GeneratedField::Invoice),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PaymentRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.transaction.v1alpha1.PaymentRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PaymentRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut address__ = None;
+                let mut values__ = None;
+                let mut memo__ = None;
+                let mut expiry_height__ = None;
+                let mut invoice__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = map_.next_value()?;
+                        }
+                        GeneratedField::Values => {
+                            if values__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("values"));
+                            }
+                            values__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ExpiryHeight => {
+                            if expiry_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiryHeight"));
+                            }
+                            expiry_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Invoice => {
+                            if invoice__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("invoice"));
+                            }
+                            invoice__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(PaymentRequest {
+                    address: address__,
+                    values: values__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
+                    expiry_height: expiry_height__.unwrap_or_default(),
+                    invoice: invoice__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.transaction.v1alpha1.PaymentRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for payment_request::SignedInvoice {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.verification_key.is_some() {
+            len += 1;
+        }
+        if self.signature.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.transaction.v1alpha1.PaymentRequest.SignedInvoice", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if let Some(v) = self.verification_key.as_ref() {
+            struct_ser.serialize_field("verificationKey", v)?;
+        }
+        if let Some(v) = self.signature.as_ref() {
+            struct_ser.serialize_field("signature", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for payment_request::SignedInvoice {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "verification_key",
+            "verificationKey",
+            "signature",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            VerificationKey,
+            Signature,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "verificationKey" | "verification_key" => Ok(GeneratedField::VerificationKey),
+                            "signature" => Ok(GeneratedField::Signature),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = payment_request::SignedInvoice;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.transaction.v1alpha1.PaymentRequest.SignedInvoice")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<payment_request::SignedInvoice, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut verification_key__ = None;
+                let mut signature__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::VerificationKey => {
+                            if verification_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("verificationKey"));
+                            }
+                            verification_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(payment_request::SignedInvoice {
+                    id: id__.unwrap_or_default(),
+                    verification_key: verification_key__,
+                    signature: signature__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.transaction.v1alpha1.PaymentRequest.SignedInvoice", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Transaction {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -280,3 +280,541 @@ impl ::prost::Name for DkgRound2 {
         ::prost::alloc::format!("penumbra.custody.threshold.v1alpha1.{}", Self::NAME)
     }
 }
+/// A message relayed between the participants of a threshold protocol.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RelayEnvelope {
+    #[prost(message, optional, tag = "1")]
+    pub inner: ::core::option::Option<relay_envelope::Inner>,
+    /// The verification key identifying the sender.
+    #[prost(message, optional, tag = "2")]
+    pub sender: ::core::option::Option<VerificationKey>,
+    /// A signature over the domain-separated, proto-encoded bytes of inner.
+    #[prost(message, optional, tag = "3")]
+    pub sig: ::core::option::Option<Signature>,
+}
+/// Nested message and enum types in `RelayEnvelope`.
+pub mod relay_envelope {
+    /// The inner message that will be signed by the sender.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Inner {
+        /// The room the message is sent in.
+        #[prost(bytes = "vec", tag = "1")]
+        pub room: ::prost::alloc::vec::Vec<u8>,
+        /// An identifier for the run of the protocol the message belongs to.
+        #[prost(bytes = "vec", tag = "2")]
+        pub session: ::prost::alloc::vec::Vec<u8>,
+        /// The round of the protocol the message belongs to, starting at 1.
+        #[prost(uint32, tag = "3")]
+        pub round: u32,
+        /// If set, the only participant the message is intended for.
+        #[prost(message, optional, tag = "4")]
+        pub recipient: ::core::option::Option<super::VerificationKey>,
+        /// The protocol message itself.
+        #[prost(string, tag = "5")]
+        pub payload: ::prost::alloc::string::String,
+    }
+    impl ::prost::Name for Inner {
+        const NAME: &'static str = "Inner";
+        const PACKAGE: &'static str = "penumbra.custody.threshold.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.threshold.v1alpha1.RelayEnvelope.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for RelayEnvelope {
+    const NAME: &'static str = "RelayEnvelope";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1alpha1.{}", Self::NAME)
+    }
+}
+/// Registers a participant in a room of the relay.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeRequest {
+    #[prost(message, optional, tag = "1")]
+    pub inner: ::core::option::Option<subscribe_request::Inner>,
+    /// The verification key identifying the participant.
+    #[prost(message, optional, tag = "2")]
+    pub pk: ::core::option::Option<VerificationKey>,
+    /// A signature over the domain-separated, proto-encoded bytes of inner.
+    #[prost(message, optional, tag = "3")]
+    pub sig: ::core::option::Option<Signature>,
+}
+/// Nested message and enum types in `SubscribeRequest`.
+pub mod subscribe_request {
+    /// The inner message that will be signed by the participant.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Inner {
+        /// The room to join.
+        #[prost(bytes = "vec", tag = "1")]
+        pub room: ::prost::alloc::vec::Vec<u8>,
+        /// The time of the request, in seconds since the Unix epoch.
+        #[prost(uint64, tag = "2")]
+        pub timestamp: u64,
+    }
+    impl ::prost::Name for Inner {
+        const NAME: &'static str = "Inner";
+        const PACKAGE: &'static str = "penumbra.custody.threshold.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.threshold.v1alpha1.SubscribeRequest.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for SubscribeRequest {
+    const NAME: &'static str = "SubscribeRequest";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeResponse {
+    #[prost(message, optional, tag = "1")]
+    pub envelope: ::core::option::Option<RelayEnvelope>,
+}
+impl ::prost::Name for SubscribeResponse {
+    const NAME: &'static str = "SubscribeResponse";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BroadcastRequest {
+    #[prost(message, optional, tag = "1")]
+    pub envelope: ::core::option::Option<RelayEnvelope>,
+}
+impl ::prost::Name for BroadcastRequest {
+    const NAME: &'static str = "BroadcastRequest";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BroadcastResponse {
+    /// The number of participants the message was delivered to.
+    #[prost(uint32, tag = "1")]
+    pub recipients: u32,
+}
+impl ::prost::Name for BroadcastResponse {
+    const NAME: &'static str = "BroadcastResponse";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1alpha1.{}", Self::NAME)
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "rpc")]
+pub mod threshold_relay_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Relays the messages of the threshold protocols between participants over the network.
+    ///
+    /// The relay is not trusted: every message is signed by its sender, and checked
+    /// by its recipients, so the relay can only delay or drop messages.
+    #[derive(Debug, Clone)]
+    pub struct ThresholdRelayServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ThresholdRelayServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ThresholdRelayServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> ThresholdRelayServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            ThresholdRelayServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Register as a participant in a room, and stream the messages sent to it.
+        pub async fn subscribe(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::SubscribeResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.threshold.v1alpha1.ThresholdRelayService/Subscribe",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.custody.threshold.v1alpha1.ThresholdRelayService",
+                        "Subscribe",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Send a message to the other participants in a room.
+        pub async fn broadcast(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BroadcastRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BroadcastResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.threshold.v1alpha1.ThresholdRelayService/Broadcast",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.custody.threshold.v1alpha1.ThresholdRelayService",
+                        "Broadcast",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "rpc")]
+pub mod threshold_relay_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ThresholdRelayServiceServer.
+    #[async_trait]
+    pub trait ThresholdRelayService: Send + Sync + 'static {
+        /// Server streaming response type for the Subscribe method.
+        type SubscribeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::SubscribeResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Register as a participant in a room, and stream the messages sent to it.
+        async fn subscribe(
+            &self,
+            request: tonic::Request<super::SubscribeRequest>,
+        ) -> std::result::Result<tonic::Response<Self::SubscribeStream>, tonic::Status>;
+        /// Send a message to the other participants in a room.
+        async fn broadcast(
+            &self,
+            request: tonic::Request<super::BroadcastRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BroadcastResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Relays the messages of the threshold protocols between participants over the network.
+    ///
+    /// The relay is not trusted: every message is signed by its sender, and checked
+    /// by its recipients, so the relay can only delay or drop messages.
+    #[derive(Debug)]
+    pub struct ThresholdRelayServiceServer<T: ThresholdRelayService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: ThresholdRelayService> ThresholdRelayServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>>
+    for ThresholdRelayServiceServer<T>
+    where
+        T: ThresholdRelayService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/penumbra.custody.threshold.v1alpha1.ThresholdRelayService/Subscribe" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeSvc<T: ThresholdRelayService>(pub Arc<T>);
+                    impl<
+                        T: ThresholdRelayService,
+                    > tonic::server::ServerStreamingService<super::SubscribeRequest>
+                    for SubscribeSvc<T> {
+                        type Response = super::SubscribeResponse;
+                        type ResponseStream = T::SubscribeStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubscribeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ThresholdRelayService>::subscribe(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SubscribeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.custody.threshold.v1alpha1.ThresholdRelayService/Broadcast" => {
+                    #[allow(non_camel_case_types)]
+                    struct BroadcastSvc<T: ThresholdRelayService>(pub Arc<T>);
+                    impl<
+                        T: ThresholdRelayService,
+                    > tonic::server::UnaryService<super::BroadcastRequest>
+                    for BroadcastSvc<T> {
+                        type Response = super::BroadcastResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BroadcastRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ThresholdRelayService>::broadcast(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BroadcastSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: ThresholdRelayService> Clone for ThresholdRelayServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: ThresholdRelayService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: ThresholdRelayService> tonic::server::NamedService
+    for ThresholdRelayServiceServer<T> {
+        const NAME: &'static str = "penumbra.custody.threshold.v1alpha1.ThresholdRelayService";
+    }
+}
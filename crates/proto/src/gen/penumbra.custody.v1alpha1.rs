@@ -138,6 +138,47 @@ impl ::prost::Name for ConfirmAddressResponse {
         ::prost::alloc::format!("penumbra.custody.v1alpha1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizePaymentRequestRequest {
+    /// The payment request to sign, without an invoice.
+    #[prost(message, optional, tag = "1")]
+    pub payment_request: ::core::option::Option<
+        super::super::core::transaction::v1alpha1::PaymentRequest,
+    >,
+    /// The invoice id to sign.
+    #[prost(string, tag = "2")]
+    pub invoice_id: ::prost::alloc::string::String,
+    /// Optionally, pre-authorization data, if required by the custodian.
+    ///
+    /// Each pre-authorization signs the encoding of this request, without its
+    /// pre-authorizations.
+    #[prost(message, repeated, tag = "3")]
+    pub pre_authorizations: ::prost::alloc::vec::Vec<PreAuthorization>,
+}
+impl ::prost::Name for AuthorizePaymentRequestRequest {
+    const NAME: &'static str = "AuthorizePaymentRequestRequest";
+    const PACKAGE: &'static str = "penumbra.custody.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizePaymentRequestResponse {
+    /// The payment request, with its signed invoice.
+    #[prost(message, optional, tag = "1")]
+    pub payment_request: ::core::option::Option<
+        super::super::core::transaction::v1alpha1::PaymentRequest,
+    >,
+}
+impl ::prost::Name for AuthorizePaymentRequestResponse {
+    const NAME: &'static str = "AuthorizePaymentRequestResponse";
+    const PACKAGE: &'static str = "penumbra.custody.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1alpha1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod custody_protocol_service_client {
@@ -338,6 +379,41 @@ pub mod custody_protocol_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Requests a signature over the invoice id of a payment request.
+        ///
+        /// Custody backends should apply the same authorization policies as for
+        /// `Authorize`. Custody backends that can't sign on behalf of the wallet should
+        /// return an error.
+        pub async fn authorize_payment_request(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AuthorizePaymentRequestRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AuthorizePaymentRequestResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.v1alpha1.CustodyProtocolService/AuthorizePaymentRequest",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.custody.v1alpha1.CustodyProtocolService",
+                        "AuthorizePaymentRequest",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -380,6 +456,18 @@ pub mod custody_protocol_service_server {
             tonic::Response<super::ConfirmAddressResponse>,
             tonic::Status,
         >;
+        /// Requests a signature over the invoice id of a payment request.
+        ///
+        /// Custody backends should apply the same authorization policies as for
+        /// `Authorize`. Custody backends that can't sign on behalf of the wallet should
+        /// return an error.
+        async fn authorize_payment_request(
+            &self,
+            request: tonic::Request<super::AuthorizePaymentRequestRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AuthorizePaymentRequestResponse>,
+            tonic::Status,
+        >;
     }
     /// The custody protocol is used by a wallet client to request authorization for
     /// a transaction they've constructed.
@@ -621,6 +709,60 @@ pub mod custody_protocol_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.custody.v1alpha1.CustodyProtocolService/AuthorizePaymentRequest" => {
+                    #[allow(non_camel_case_types)]
+                    struct AuthorizePaymentRequestSvc<T: CustodyProtocolService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: CustodyProtocolService,
+                    > tonic::server::UnaryService<super::AuthorizePaymentRequestRequest>
+                    for AuthorizePaymentRequestSvc<T> {
+                        type Response = super::AuthorizePaymentRequestResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::AuthorizePaymentRequestRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CustodyProtocolService>::authorize_payment_request(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AuthorizePaymentRequestSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -1,3 +1,223 @@
+impl serde::Serialize for AuthorizePaymentRequestRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.payment_request.is_some() {
+            len += 1;
+        }
+        if !self.invoice_id.is_empty() {
+            len += 1;
+        }
+        if !self.pre_authorizations.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1alpha1.AuthorizePaymentRequestRequest", len)?;
+        if let Some(v) = self.payment_request.as_ref() {
+            struct_ser.serialize_field("paymentRequest", v)?;
+        }
+        if !self.invoice_id.is_empty() {
+            struct_ser.serialize_field("invoiceId", &self.invoice_id)?;
+        }
+        if !self.pre_authorizations.is_empty() {
+            struct_ser.serialize_field("preAuthorizations", &self.pre_authorizations)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AuthorizePaymentRequestRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "payment_request",
+            "paymentRequest",
+            "invoice_id",
+            "invoiceId",
+            "pre_authorizations",
+            "preAuthorizations",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PaymentRequest,
+            InvoiceId,
+            PreAuthorizations,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "paymentRequest" | "payment_request" => Ok(GeneratedField::PaymentRequest),
+                            "invoiceId" | "invoice_id" => Ok(GeneratedField::InvoiceId),
+                            "preAuthorizations" | "pre_authorizations" => Ok(GeneratedField::PreAuthorizations),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AuthorizePaymentRequestRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1alpha1.AuthorizePaymentRequestRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuthorizePaymentRequestRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut payment_request__ = None;
+                let mut invoice_id__ = None;
+                let mut pre_authorizations__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PaymentRequest => {
+                            if payment_request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("paymentRequest"));
+                            }
+                            payment_request__ = map_.next_value()?;
+                        }
+                        GeneratedField::InvoiceId => {
+                            if invoice_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("invoiceId"));
+                            }
+                            invoice_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PreAuthorizations => {
+                            if pre_authorizations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("preAuthorizations"));
+                            }
+                            pre_authorizations__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(AuthorizePaymentRequestRequest {
+                    payment_request: payment_request__,
+                    invoice_id: invoice_id__.unwrap_or_default(),
+                    pre_authorizations: pre_authorizations__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1alpha1.AuthorizePaymentRequestRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AuthorizePaymentRequestResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.payment_request.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1alpha1.AuthorizePaymentRequestResponse", len)?;
+        if let Some(v) = self.payment_request.as_ref() {
+            struct_ser.serialize_field("paymentRequest", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AuthorizePaymentRequestResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "payment_request",
+            "paymentRequest",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PaymentRequest,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "paymentRequest" | "payment_request" => Ok(GeneratedField::PaymentRequest),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AuthorizePaymentRequestResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1alpha1.AuthorizePaymentRequestResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuthorizePaymentRequestResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut payment_request__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PaymentRequest => {
+                            if payment_request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("paymentRequest"));
+                            }
+                            payment_request__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(AuthorizePaymentRequestResponse {
+                    payment_request: payment_request__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1alpha1.AuthorizePaymentRequestResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for AuthorizeRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
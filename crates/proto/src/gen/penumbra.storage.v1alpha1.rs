@@ -13,6 +13,11 @@ pub struct KeyValueRequest {
     /// whether to return a proof
     #[prost(bool, tag = "3")]
     pub proof: bool,
+    /// If set, the height of the state to query, rather than the latest.
+    ///
+    /// Only recent heights are retained; queries for older heights fail.
+    #[prost(uint64, optional, tag = "4")]
+    pub height: ::core::option::Option<u64>,
 }
 impl ::prost::Name for KeyValueRequest {
     const NAME: &'static str = "KeyValueRequest";
@@ -68,6 +73,17 @@ pub struct PrefixValueRequest {
     /// The prefix to fetch subkeys from storage.
     #[prost(string, tag = "2")]
     pub prefix: ::prost::alloc::string::String,
+    /// If set, the height of the state to query, rather than the latest.
+    #[prost(uint64, optional, tag = "3")]
+    pub height: ::core::option::Option<u64>,
+    /// If set, only keys strictly greater than this key are returned.
+    ///
+    /// To page through a prefix, set this to the last key of the previous page.
+    #[prost(string, tag = "4")]
+    pub start_after: ::prost::alloc::string::String,
+    /// If nonzero, the maximum number of entries to return.
+    #[prost(uint32, tag = "5")]
+    pub limit: u32,
 }
 impl ::prost::Name for PrefixValueRequest {
     const NAME: &'static str = "PrefixValueRequest";
@@ -91,6 +107,55 @@ impl ::prost::Name for PrefixValueResponse {
         ::prost::alloc::format!("penumbra.storage.v1alpha1.{}", Self::NAME)
     }
 }
+/// Performs a prefix or range query on the non-verifiable key-value store.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NonVerifiableRangeRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// If set, the height of the state to query, rather than the latest.
+    #[prost(uint64, optional, tag = "2")]
+    pub height: ::core::option::Option<u64>,
+    /// The prefix of the keys to fetch.
+    #[prost(bytes = "vec", tag = "3")]
+    pub prefix: ::prost::alloc::vec::Vec<u8>,
+    /// If set, only keys greater than or equal to `prefix || start` are returned.
+    ///
+    /// To page through a range, set this to the suffix of the last key of the
+    /// previous page, followed by a zero byte.
+    #[prost(bytes = "vec", tag = "4")]
+    pub start: ::prost::alloc::vec::Vec<u8>,
+    /// If set, only keys strictly less than `prefix || end` are returned.
+    #[prost(bytes = "vec", tag = "5")]
+    pub end: ::prost::alloc::vec::Vec<u8>,
+    /// If nonzero, the maximum number of entries to return.
+    #[prost(uint32, tag = "6")]
+    pub limit: u32,
+}
+impl ::prost::Name for NonVerifiableRangeRequest {
+    const NAME: &'static str = "NonVerifiableRangeRequest";
+    const PACKAGE: &'static str = "penumbra.storage.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.storage.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NonVerifiableRangeResponse {
+    /// The full key, including the prefix.
+    #[prost(bytes = "vec", tag = "1")]
+    pub key: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for NonVerifiableRangeResponse {
+    const NAME: &'static str = "NonVerifiableRangeResponse";
+    const PACKAGE: &'static str = "penumbra.storage.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.storage.v1alpha1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -239,6 +304,39 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// General-purpose query API for the non-verifiable key-value store, that
+        /// can be used to query arbitrary prefixes or ranges of raw keys.
+        /// Returns a stream of `NonVerifiableRangeResponse`s.
+        pub async fn non_verifiable_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::NonVerifiableRangeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::NonVerifiableRangeResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.storage.v1alpha1.QueryService/NonVerifiableRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.storage.v1alpha1.QueryService",
+                        "NonVerifiableRange",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -274,6 +372,25 @@ pub mod query_service_server {
             tonic::Response<Self::PrefixValueStream>,
             tonic::Status,
         >;
+        /// Server streaming response type for the NonVerifiableRange method.
+        type NonVerifiableRangeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::NonVerifiableRangeResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// General-purpose query API for the non-verifiable key-value store, that
+        /// can be used to query arbitrary prefixes or ranges of raw keys.
+        /// Returns a stream of `NonVerifiableRangeResponse`s.
+        async fn non_verifiable_range(
+            &self,
+            request: tonic::Request<super::NonVerifiableRangeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::NonVerifiableRangeStream>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct QueryServiceServer<T: QueryService> {
@@ -447,6 +564,55 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.storage.v1alpha1.QueryService/NonVerifiableRange" => {
+                    #[allow(non_camel_case_types)]
+                    struct NonVerifiableRangeSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::NonVerifiableRangeRequest,
+                    > for NonVerifiableRangeSvc<T> {
+                        type Response = super::NonVerifiableRangeResponse;
+                        type ResponseStream = T::NonVerifiableRangeStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::NonVerifiableRangeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::non_verifiable_range(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NonVerifiableRangeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -90,6 +90,19 @@ pub struct TransactionPlannerRequest {
     /// If present, only spends funds from the given account.
     #[prost(message, optional, tag = "4")]
     pub source: ::core::option::Option<super::super::core::keys::v1alpha1::AddressIndex>,
+    /// If present, pays the fee in the given asset, which must be the staking token
+    /// or one of the alternative fee assets approved by governance.
+    /// If absent, the fee asset is chosen based on the balances of the source account.
+    #[prost(message, optional, tag = "5")]
+    pub fee_asset_id: ::core::option::Option<
+        super::super::core::asset::v1alpha1::AssetId,
+    >,
+    /// If present, pays the payment request, which sets the outputs, memo and expiry height it
+    /// requires. The memo must be unspecified if this is present.
+    #[prost(message, optional, tag = "6")]
+    pub payment_request: ::core::option::Option<
+        super::super::core::transaction::v1alpha1::PaymentRequest,
+    >,
     /// Optionally identifies the wallet id to query.
     #[prost(message, optional, tag = "14")]
     pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
@@ -104,6 +117,8 @@ pub struct TransactionPlannerRequest {
     pub delegations: ::prost::alloc::vec::Vec<transaction_planner_request::Delegate>,
     #[prost(message, repeated, tag = "50")]
     pub undelegations: ::prost::alloc::vec::Vec<transaction_planner_request::Undelegate>,
+    #[prost(message, repeated, tag = "51")]
+    pub redelegations: ::prost::alloc::vec::Vec<transaction_planner_request::Redelegate>,
     #[prost(message, repeated, tag = "60")]
     pub ibc_relay_actions: ::prost::alloc::vec::Vec<
         super::super::core::component::ibc::v1alpha1::IbcRelay,
@@ -124,6 +139,10 @@ pub struct TransactionPlannerRequest {
     pub position_withdraws: ::prost::alloc::vec::Vec<
         transaction_planner_request::PositionWithdraw,
     >,
+    #[prost(message, repeated, tag = "73")]
+    pub position_reward_claims: ::prost::alloc::vec::Vec<
+        transaction_planner_request::PositionRewardClaim,
+    >,
 }
 /// Nested message and enum types in `TransactionPlannerRequest`.
 pub mod transaction_planner_request {
@@ -174,6 +193,12 @@ pub mod transaction_planner_request {
         pub claim_address: ::core::option::Option<
             super::super::super::core::keys::v1alpha1::Address,
         >,
+        /// The minimum amount of the target asset the swap must output.
+        /// If the batch clears at a worse price, the input is refunded when the swap is claimed.
+        #[prost(message, optional, tag = "5")]
+        pub min_output: ::core::option::Option<
+            super::super::super::core::num::v1alpha1::Amount,
+        >,
     }
     impl ::prost::Name for Swap {
         const NAME: &'static str = "Swap";
@@ -248,6 +273,34 @@ pub mod transaction_planner_request {
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Redelegate {
+        /// The delegation tokens of the source validator to redelegate.
+        #[prost(message, optional, tag = "1")]
+        pub value: ::core::option::Option<
+            super::super::super::core::asset::v1alpha1::Value,
+        >,
+        /// The current rate data of the source validator.
+        #[prost(message, optional, tag = "2")]
+        pub from_rate_data: ::core::option::Option<
+            super::super::super::core::component::stake::v1alpha1::RateData,
+        >,
+        /// The current rate data of the destination validator.
+        #[prost(message, optional, tag = "3")]
+        pub to_rate_data: ::core::option::Option<
+            super::super::super::core::component::stake::v1alpha1::RateData,
+        >,
+    }
+    impl ::prost::Name for Redelegate {
+        const NAME: &'static str = "Redelegate";
+        const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.view.v1alpha1.TransactionPlannerRequest.{}", Self::NAME
+            )
+        }
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct PositionOpen {
         /// Contains the data defining the position, sufficient to compute its `PositionId`.
         ///
@@ -313,6 +366,29 @@ pub mod transaction_planner_request {
             )
         }
     }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PositionRewardClaim {
+        /// The position to claim rewards for.
+        #[prost(message, optional, tag = "1")]
+        pub position_id: ::core::option::Option<
+            super::super::super::core::component::dex::v1alpha1::PositionId,
+        >,
+        /// The rewards accrued to the position, in the staking token.
+        #[prost(message, optional, tag = "2")]
+        pub rewards: ::core::option::Option<
+            super::super::super::core::num::v1alpha1::Amount,
+        >,
+    }
+    impl ::prost::Name for PositionRewardClaim {
+        const NAME: &'static str = "PositionRewardClaim";
+        const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.view.v1alpha1.TransactionPlannerRequest.{}", Self::NAME
+            )
+        }
+    }
 }
 impl ::prost::Name for TransactionPlannerRequest {
     const NAME: &'static str = "TransactionPlannerRequest";
@@ -655,6 +731,26 @@ impl ::prost::Name for NotesForVotingRequest {
         ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
     }
 }
+/// A query for the notes received in payment of a payment request.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NotesForPaymentRequestRequest {
+    /// The payment request.
+    #[prost(message, optional, tag = "1")]
+    pub payment_request: ::core::option::Option<
+        super::super::core::transaction::v1alpha1::PaymentRequest,
+    >,
+    /// Identifies the wallet id to query.
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
+}
+impl ::prost::Name for NotesForPaymentRequestRequest {
+    const NAME: &'static str = "NotesForPaymentRequestRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WitnessRequest {
@@ -1077,6 +1173,134 @@ impl ::prost::Name for TransactionInfoByHashResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionLedgerRequest {
+    /// If present, return only entries of transactions at or after this height.
+    #[prost(uint64, tag = "1")]
+    pub start_height: u64,
+    /// If present, return only entries of transactions at or before this height.
+    #[prost(uint64, tag = "2")]
+    pub end_height: u64,
+    /// If present, return only entries for this account.
+    #[prost(message, optional, tag = "3")]
+    pub account_filter: ::core::option::Option<
+        super::super::core::keys::v1alpha1::AddressIndex,
+    >,
+    /// Identifies the wallet id to query.
+    #[prost(message, optional, tag = "14")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1alpha1::WalletId>,
+}
+impl ::prost::Name for TransactionLedgerRequest {
+    const NAME: &'static str = "TransactionLedgerRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
+/// The effect of a transaction on one account of the wallet.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LedgerEntry {
+    /// The height the transaction was included in a block.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The hash of the transaction.
+    #[prost(message, optional, tag = "2")]
+    pub transaction_id: ::core::option::Option<
+        super::super::core::transaction::v1alpha1::Id,
+    >,
+    /// The account the entry is for.
+    #[prost(uint32, tag = "3")]
+    pub account: u32,
+    /// The net value the account received in the transaction, per asset.
+    #[prost(message, repeated, tag = "4")]
+    pub inflows: ::prost::alloc::vec::Vec<super::super::core::asset::v1alpha1::Value>,
+    /// The net value the account sent in the transaction, per asset, including any fee.
+    #[prost(message, repeated, tag = "5")]
+    pub outflows: ::prost::alloc::vec::Vec<super::super::core::asset::v1alpha1::Value>,
+    /// The fee of the transaction, if the account paid it.
+    #[prost(message, optional, tag = "6")]
+    pub fee: ::core::option::Option<super::super::core::component::fee::v1alpha1::Fee>,
+    /// The swaps of the transaction claimed by the account.
+    #[prost(message, repeated, tag = "7")]
+    pub swap_fills: ::prost::alloc::vec::Vec<ledger_entry::SwapFill>,
+    /// The delegations, undelegations and redelegations of the transaction, if the account funded it.
+    #[prost(message, repeated, tag = "8")]
+    pub delegation_conversions: ::prost::alloc::vec::Vec<
+        ledger_entry::DelegationConversion,
+    >,
+    /// The text of the transaction's memo, if visible.
+    #[prost(string, tag = "9")]
+    pub memo: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `LedgerEntry`.
+pub mod ledger_entry {
+    /// A swap of the transaction, and how it was filled by its batch.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SwapFill {
+        /// The values put into the swap.
+        #[prost(message, repeated, tag = "1")]
+        pub inputs: ::prost::alloc::vec::Vec<
+            super::super::super::core::asset::v1alpha1::Value,
+        >,
+        /// The values the swap's batch paid out for them.
+        #[prost(message, repeated, tag = "2")]
+        pub outputs: ::prost::alloc::vec::Vec<
+            super::super::super::core::asset::v1alpha1::Value,
+        >,
+    }
+    impl ::prost::Name for SwapFill {
+        const NAME: &'static str = "SwapFill";
+        const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.view.v1alpha1.LedgerEntry.{}", Self::NAME)
+        }
+    }
+    /// A conversion between staking, delegation and unbonding tokens at a validator's rate.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DelegationConversion {
+        /// The value converted.
+        #[prost(message, optional, tag = "1")]
+        pub input: ::core::option::Option<
+            super::super::super::core::asset::v1alpha1::Value,
+        >,
+        /// The value it was converted into.
+        #[prost(message, optional, tag = "2")]
+        pub output: ::core::option::Option<
+            super::super::super::core::asset::v1alpha1::Value,
+        >,
+    }
+    impl ::prost::Name for DelegationConversion {
+        const NAME: &'static str = "DelegationConversion";
+        const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!("penumbra.view.v1alpha1.LedgerEntry.{}", Self::NAME)
+        }
+    }
+}
+impl ::prost::Name for LedgerEntry {
+    const NAME: &'static str = "LedgerEntry";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionLedgerResponse {
+    #[prost(message, optional, tag = "1")]
+    pub entry: ::core::option::Option<LedgerEntry>,
+}
+impl ::prost::Name for TransactionLedgerResponse {
+    const NAME: &'static str = "TransactionLedgerResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NotesResponse {
     #[prost(message, optional, tag = "1")]
     pub note_record: ::core::option::Option<SpendableNoteRecord>,
@@ -1105,6 +1329,19 @@ impl ::prost::Name for NotesForVotingResponse {
         ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NotesForPaymentRequestResponse {
+    #[prost(message, optional, tag = "1")]
+    pub note_record: ::core::option::Option<SpendableNoteRecord>,
+}
+impl ::prost::Name for NotesForPaymentRequestResponse {
+    const NAME: &'static str = "NotesForPaymentRequestResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
 /// A note plaintext with associated metadata about its status.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1222,6 +1459,40 @@ impl ::prost::Name for OwnedPositionIdsResponse {
         ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionRewardsRequest {
+    /// If present, return only positions for this trading pair.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<
+        super::super::core::component::dex::v1alpha1::TradingPair,
+    >,
+}
+impl ::prost::Name for PositionRewardsRequest {
+    const NAME: &'static str = "PositionRewardsRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionRewardsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<
+        super::super::core::component::dex::v1alpha1::PositionId,
+    >,
+    /// The rewards the position can claim.
+    #[prost(message, optional, tag = "2")]
+    pub rewards: ::core::option::Option<super::super::core::asset::v1alpha1::Value>,
+}
+impl ::prost::Name for PositionRewardsResponse {
+    const NAME: &'static str = "PositionRewardsResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1alpha1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod view_protocol_service_client {
@@ -1438,6 +1709,39 @@ pub mod view_protocol_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Returns a stream of `NotesForPaymentRequestResponse`s.
+        pub async fn notes_for_payment_request(
+            &mut self,
+            request: impl tonic::IntoRequest<super::NotesForPaymentRequestRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::NotesForPaymentRequestResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.ViewProtocolService/NotesForPaymentRequest",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "NotesForPaymentRequest",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
         /// Returns authentication paths for the given note commitments.
         ///
         /// This method takes a batch of input commitments, rather than just one, so
@@ -1972,6 +2276,39 @@ pub mod view_protocol_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Query for the wallet's transaction history in the given range of blocks, as a ledger of
+        /// per-account entries.
+        /// Returns a stream of `TransactionLedgerResponse`s.
+        pub async fn transaction_ledger(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionLedgerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::TransactionLedgerResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.ViewProtocolService/TransactionLedger",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "TransactionLedger",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
         /// Query for a transaction plan
         pub async fn transaction_planner(
             &mut self,
@@ -2065,6 +2402,37 @@ pub mod view_protocol_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Query for the liquidity incentive rewards claimable by owned, withdrawn positions.
+        pub async fn position_rewards(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PositionRewardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::PositionRewardsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.ViewProtocolService/PositionRewards",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1alpha1.ViewProtocolService",
+                        "PositionRewards",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
         /// Authorize a transaction plan and build the transaction.
         pub async fn authorize_and_build(
             &mut self,
@@ -2267,6 +2635,23 @@ pub mod view_protocol_service_server {
             tonic::Response<Self::NotesForVotingStream>,
             tonic::Status,
         >;
+        /// Server streaming response type for the NotesForPaymentRequest method.
+        type NotesForPaymentRequestStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::NotesForPaymentRequestResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Returns a stream of `NotesForPaymentRequestResponse`s.
+        async fn notes_for_payment_request(
+            &self,
+            request: tonic::Request<super::NotesForPaymentRequestRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::NotesForPaymentRequestStream>,
+            tonic::Status,
+        >;
         /// Returns authentication paths for the given note commitments.
         ///
         /// This method takes a batch of input commitments, rather than just one, so
@@ -2425,6 +2810,25 @@ pub mod view_protocol_service_server {
             tonic::Response<Self::TransactionInfoStream>,
             tonic::Status,
         >;
+        /// Server streaming response type for the TransactionLedger method.
+        type TransactionLedgerStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::TransactionLedgerResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Query for the wallet's transaction history in the given range of blocks, as a ledger of
+        /// per-account entries.
+        /// Returns a stream of `TransactionLedgerResponse`s.
+        async fn transaction_ledger(
+            &self,
+            request: tonic::Request<super::TransactionLedgerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::TransactionLedgerStream>,
+            tonic::Status,
+        >;
         /// Query for a transaction plan
         async fn transaction_planner(
             &self,
@@ -2458,6 +2862,20 @@ pub mod view_protocol_service_server {
             tonic::Response<Self::OwnedPositionIdsStream>,
             tonic::Status,
         >;
+        /// Server streaming response type for the PositionRewards method.
+        type PositionRewardsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::PositionRewardsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Query for the liquidity incentive rewards claimable by owned, withdrawn positions.
+        async fn position_rewards(
+            &self,
+            request: tonic::Request<super::PositionRewardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::PositionRewardsStream>,
+            tonic::Status,
+        >;
         /// Authorize a transaction plan and build the transaction.
         async fn authorize_and_build(
             &self,
@@ -2745,6 +3163,58 @@ pub mod view_protocol_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1alpha1.ViewProtocolService/NotesForPaymentRequest" => {
+                    #[allow(non_camel_case_types)]
+                    struct NotesForPaymentRequestSvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::ServerStreamingService<
+                        super::NotesForPaymentRequestRequest,
+                    > for NotesForPaymentRequestSvc<T> {
+                        type Response = super::NotesForPaymentRequestResponse;
+                        type ResponseStream = T::NotesForPaymentRequestStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::NotesForPaymentRequestRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewProtocolService>::notes_for_payment_request(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NotesForPaymentRequestSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.view.v1alpha1.ViewProtocolService/Witness" => {
                     #[allow(non_camel_case_types)]
                     struct WitnessSvc<T: ViewProtocolService>(pub Arc<T>);
@@ -3572,6 +4042,58 @@ pub mod view_protocol_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1alpha1.ViewProtocolService/TransactionLedger" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionLedgerSvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::ServerStreamingService<
+                        super::TransactionLedgerRequest,
+                    > for TransactionLedgerSvc<T> {
+                        type Response = super::TransactionLedgerResponse;
+                        type ResponseStream = T::TransactionLedgerStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionLedgerRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewProtocolService>::transaction_ledger(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = TransactionLedgerSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.view.v1alpha1.ViewProtocolService/TransactionPlanner" => {
                     #[allow(non_camel_case_types)]
                     struct TransactionPlannerSvc<T: ViewProtocolService>(pub Arc<T>);
@@ -3724,6 +4246,58 @@ pub mod view_protocol_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1alpha1.ViewProtocolService/PositionRewards" => {
+                    #[allow(non_camel_case_types)]
+                    struct PositionRewardsSvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::ServerStreamingService<
+                        super::PositionRewardsRequest,
+                    > for PositionRewardsSvc<T> {
+                        type Response = super::PositionRewardsResponse;
+                        type ResponseStream = T::PositionRewardsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PositionRewardsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewProtocolService>::position_rewards(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PositionRewardsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.view.v1alpha1.ViewProtocolService/AuthorizeAndBuild" => {
                     #[allow(non_camel_case_types)]
                     struct AuthorizeAndBuildSvc<T: ViewProtocolService>(pub Arc<T>);
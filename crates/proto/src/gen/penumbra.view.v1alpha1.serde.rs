@@ -1983,6 +1983,457 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
         deserializer.deserialize_struct("penumbra.view.v1alpha1.IndexByAddressResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for LedgerEntry {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.transaction_id.is_some() {
+            len += 1;
+        }
+        if self.account != 0 {
+            len += 1;
+        }
+        if !self.inflows.is_empty() {
+            len += 1;
+        }
+        if !self.outflows.is_empty() {
+            len += 1;
+        }
+        if self.fee.is_some() {
+            len += 1;
+        }
+        if !self.swap_fills.is_empty() {
+            len += 1;
+        }
+        if !self.delegation_conversions.is_empty() {
+            len += 1;
+        }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.LedgerEntry", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.transaction_id.as_ref() {
+            struct_ser.serialize_field("transactionId", v)?;
+        }
+        if self.account != 0 {
+            struct_ser.serialize_field("account", &self.account)?;
+        }
+        if !self.inflows.is_empty() {
+            struct_ser.serialize_field("inflows", &self.inflows)?;
+        }
+        if !self.outflows.is_empty() {
+            struct_ser.serialize_field("outflows", &self.outflows)?;
+        }
+        if let Some(v) = self.fee.as_ref() {
+            struct_ser.serialize_field("fee", v)?;
+        }
+        if !self.swap_fills.is_empty() {
+            struct_ser.serialize_field("swapFills", &self.swap_fills)?;
+        }
+        if !self.delegation_conversions.is_empty() {
+            struct_ser.serialize_field("delegationConversions", &self.delegation_conversions)?;
+        }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LedgerEntry {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "transaction_id",
+            "transactionId",
+            "account",
+            "inflows",
+            "outflows",
+            "fee",
+            "swap_fills",
+            "swapFills",
+            "delegation_conversions",
+            "delegationConversions",
+            "memo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            TransactionId,
+            Account,
+            Inflows,
+            Outflows,
+            Fee,
+            SwapFills,
+            DelegationConversions,
+            Memo,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "transactionId" | "transaction_id" => Ok(GeneratedField::TransactionId),
+                            "account" => Ok(GeneratedField::Account),
+                            "inflows" => Ok(GeneratedField::Inflows),
+                            "outflows" => Ok(GeneratedField::Outflows),
+                            "fee" => Ok(GeneratedField::Fee),
+                            "swapFills" | "swap_fills" => Ok(GeneratedField::SwapFills),
+                            "delegationConversions" | "delegation_conversions" => Ok(GeneratedField::DelegationConversions),
+                            "memo" => Ok(GeneratedField::Memo),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LedgerEntry;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.LedgerEntry")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LedgerEntry, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut transaction_id__ = None;
+                let mut account__ = None;
+                let mut inflows__ = None;
+                let mut outflows__ = None;
+                let mut fee__ = None;
+                let mut swap_fills__ = None;
+                let mut delegation_conversions__ = None;
+                let mut memo__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TransactionId => {
+                            if transaction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionId"));
+                            }
+                            transaction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Account => {
+                            if account__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("account"));
+                            }
+                            account__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Inflows => {
+                            if inflows__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inflows"));
+                            }
+                            inflows__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Outflows => {
+                            if outflows__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outflows"));
+                            }
+                            outflows__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
+                            }
+                            fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::SwapFills => {
+                            if swap_fills__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapFills"));
+                            }
+                            swap_fills__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DelegationConversions => {
+                            if delegation_conversions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegationConversions"));
+                            }
+                            delegation_conversions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(LedgerEntry {
+                    height: height__.unwrap_or_default(),
+                    transaction_id: transaction_id__,
+                    account: account__.unwrap_or_default(),
+                    inflows: inflows__.unwrap_or_default(),
+                    outflows: outflows__.unwrap_or_default(),
+                    fee: fee__,
+                    swap_fills: swap_fills__.unwrap_or_default(),
+                    delegation_conversions: delegation_conversions__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.LedgerEntry", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ledger_entry::DelegationConversion {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.output.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.LedgerEntry.DelegationConversion", len)?;
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.output.as_ref() {
+            struct_ser.serialize_field("output", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ledger_entry::DelegationConversion {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "input",
+            "output",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Input,
+            Output,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "input" => Ok(GeneratedField::Input),
+                            "output" => Ok(GeneratedField::Output),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ledger_entry::DelegationConversion;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.LedgerEntry.DelegationConversion")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ledger_entry::DelegationConversion, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut input__ = None;
+                let mut output__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output => {
+                            if output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output"));
+                            }
+                            output__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ledger_entry::DelegationConversion {
+                    input: input__,
+                    output: output__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.LedgerEntry.DelegationConversion", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ledger_entry::SwapFill {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.inputs.is_empty() {
+            len += 1;
+        }
+        if !self.outputs.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.LedgerEntry.SwapFill", len)?;
+        if !self.inputs.is_empty() {
+            struct_ser.serialize_field("inputs", &self.inputs)?;
+        }
+        if !self.outputs.is_empty() {
+            struct_ser.serialize_field("outputs", &self.outputs)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ledger_entry::SwapFill {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "inputs",
+            "outputs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Inputs,
+            Outputs,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "inputs" => Ok(GeneratedField::Inputs),
+                            "outputs" => Ok(GeneratedField::Outputs),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ledger_entry::SwapFill;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.LedgerEntry.SwapFill")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ledger_entry::SwapFill, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut inputs__ = None;
+                let mut outputs__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Inputs => {
+                            if inputs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inputs"));
+                            }
+                            inputs__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Outputs => {
+                            if outputs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputs"));
+                            }
+                            outputs__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ledger_entry::SwapFill {
+                    inputs: inputs__.unwrap_or_default(),
+                    outputs: outputs__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.LedgerEntry.SwapFill", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for NoteByCommitmentRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2054,8 +2505,218 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
-                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
+                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NoteByCommitmentRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.NoteByCommitmentRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut note_commitment__ = None;
+                let mut await_detection__ = None;
+                let mut wallet_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NoteCommitment => {
+                            if note_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
+                            }
+                            note_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::AwaitDetection => {
+                            if await_detection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                            }
+                            await_detection__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(NoteByCommitmentRequest {
+                    note_commitment: note_commitment__,
+                    await_detection: await_detection__.unwrap_or_default(),
+                    wallet_id: wallet_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NoteByCommitmentRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NoteByCommitmentResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.spendable_note.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NoteByCommitmentResponse", len)?;
+        if let Some(v) = self.spendable_note.as_ref() {
+            struct_ser.serialize_field("spendableNote", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "spendable_note",
+            "spendableNote",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SpendableNote,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "spendableNote" | "spendable_note" => Ok(GeneratedField::SpendableNote),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NoteByCommitmentResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.NoteByCommitmentResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut spendable_note__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SpendableNote => {
+                            if spendable_note__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spendableNote"));
+                            }
+                            spendable_note__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(NoteByCommitmentResponse {
+                    spendable_note: spendable_note__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NoteByCommitmentResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NotesForPaymentRequestRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.payment_request.is_some() {
+            len += 1;
+        }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NotesForPaymentRequestRequest", len)?;
+        if let Some(v) = self.payment_request.as_ref() {
+            struct_ser.serialize_field("paymentRequest", v)?;
+        }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NotesForPaymentRequestRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "payment_request",
+            "paymentRequest",
+            "wallet_id",
+            "walletId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PaymentRequest,
+            WalletId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "paymentRequest" | "payment_request" => Ok(GeneratedField::PaymentRequest),
                             "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
@@ -2066,32 +2727,25 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NoteByCommitmentRequest;
+            type Value = NotesForPaymentRequestRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.NoteByCommitmentRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.NotesForPaymentRequestRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesForPaymentRequestRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_commitment__ = None;
-                let mut await_detection__ = None;
+                let mut payment_request__ = None;
                 let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::NoteCommitment => {
-                            if note_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
-                            }
-                            note_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::AwaitDetection => {
-                            if await_detection__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                        GeneratedField::PaymentRequest => {
+                            if payment_request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("paymentRequest"));
                             }
-                            await_detection__ = Some(map_.next_value()?);
+                            payment_request__ = map_.next_value()?;
                         }
                         GeneratedField::WalletId => {
                             if wallet_id__.is_some() {
@@ -2101,17 +2755,16 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
                         }
                     }
                 }
-                Ok(NoteByCommitmentRequest {
-                    note_commitment: note_commitment__,
-                    await_detection: await_detection__.unwrap_or_default(),
+                Ok(NotesForPaymentRequestRequest {
+                    payment_request: payment_request__,
                     wallet_id: wallet_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.NoteByCommitmentRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NotesForPaymentRequestRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NoteByCommitmentResponse {
+impl serde::Serialize for NotesForPaymentRequestResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2119,30 +2772,30 @@ impl serde::Serialize for NoteByCommitmentResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.spendable_note.is_some() {
+        if self.note_record.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NoteByCommitmentResponse", len)?;
-        if let Some(v) = self.spendable_note.as_ref() {
-            struct_ser.serialize_field("spendableNote", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NotesForPaymentRequestResponse", len)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
+impl<'de> serde::Deserialize<'de> for NotesForPaymentRequestResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "spendable_note",
-            "spendableNote",
+            "note_record",
+            "noteRecord",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SpendableNote,
+            NoteRecord,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2164,7 +2817,7 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "spendableNote" | "spendable_note" => Ok(GeneratedField::SpendableNote),
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2174,33 +2827,33 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NoteByCommitmentResponse;
+            type Value = NotesForPaymentRequestResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.NoteByCommitmentResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.NotesForPaymentRequestResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesForPaymentRequestResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut spendable_note__ = None;
+                let mut note_record__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SpendableNote => {
-                            if spendable_note__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("spendableNote"));
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
                             }
-                            spendable_note__ = map_.next_value()?;
+                            note_record__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(NoteByCommitmentResponse {
-                    spendable_note: spendable_note__,
+                Ok(NotesForPaymentRequestResponse {
+                    note_record: note_record__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.NoteByCommitmentResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NotesForPaymentRequestResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for NotesForVotingRequest {
@@ -2926,38 +3579,231 @@ impl serde::Serialize for OwnedPositionIdsRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_state.is_some() {
-            len += 1;
-        }
+        if self.position_state.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsRequest", len)?;
+        if let Some(v) = self.position_state.as_ref() {
+            struct_ser.serialize_field("positionState", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_state",
+            "positionState",
+            "trading_pair",
+            "tradingPair",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionState,
+            TradingPair,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionState" | "position_state" => Ok(GeneratedField::PositionState),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OwnedPositionIdsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_state__ = None;
+                let mut trading_pair__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionState => {
+                            if position_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionState"));
+                            }
+                            position_state__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(OwnedPositionIdsRequest {
+                    position_state: position_state__,
+                    trading_pair: trading_pair__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OwnedPositionIdsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OwnedPositionIdsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(OwnedPositionIdsResponse {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionRewardsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
         if self.trading_pair.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsRequest", len)?;
-        if let Some(v) = self.position_state.as_ref() {
-            struct_ser.serialize_field("positionState", v)?;
-        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.PositionRewardsRequest", len)?;
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
+impl<'de> serde::Deserialize<'de> for PositionRewardsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_state",
-            "positionState",
             "trading_pair",
             "tradingPair",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionState,
             TradingPair,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2980,7 +3826,6 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionState" | "position_state" => Ok(GeneratedField::PositionState),
                             "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
@@ -2991,26 +3836,19 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = OwnedPositionIdsRequest;
+            type Value = PositionRewardsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.PositionRewardsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_state__ = None;
                 let mut trading_pair__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionState => {
-                            if position_state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionState"));
-                            }
-                            position_state__ = map_.next_value()?;
-                        }
                         GeneratedField::TradingPair => {
                             if trading_pair__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("tradingPair"));
@@ -3019,16 +3857,15 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
                         }
                     }
                 }
-                Ok(OwnedPositionIdsRequest {
-                    position_state: position_state__,
+                Ok(PositionRewardsRequest {
                     trading_pair: trading_pair__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.PositionRewardsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for OwnedPositionIdsResponse {
+impl serde::Serialize for PositionRewardsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3039,14 +3876,20 @@ impl serde::Serialize for OwnedPositionIdsResponse {
         if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", len)?;
+        if self.rewards.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.PositionRewardsResponse", len)?;
         if let Some(v) = self.position_id.as_ref() {
             struct_ser.serialize_field("positionId", v)?;
         }
+        if let Some(v) = self.rewards.as_ref() {
+            struct_ser.serialize_field("rewards", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
+impl<'de> serde::Deserialize<'de> for PositionRewardsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -3055,11 +3898,13 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
         const FIELDS: &[&str] = &[
             "position_id",
             "positionId",
+            "rewards",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PositionId,
+            Rewards,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3082,6 +3927,7 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
                     {
                         match value {
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "rewards" => Ok(GeneratedField::Rewards),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3091,17 +3937,18 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = OwnedPositionIdsResponse;
+            type Value = PositionRewardsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.PositionRewardsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut position_id__ = None;
+                let mut rewards__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PositionId => {
@@ -3110,14 +3957,21 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
                             }
                             position_id__ = map_.next_value()?;
                         }
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = map_.next_value()?;
+                        }
                     }
                 }
-                Ok(OwnedPositionIdsResponse {
+                Ok(PositionRewardsResponse {
                     position_id: position_id__,
+                    rewards: rewards__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.PositionRewardsResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for SpendableNoteRecord {
@@ -4639,18 +5493,248 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionInfoRequest;
+            type Value = TransactionInfoRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.TransactionInfoRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TransactionInfoRequest {
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionInfoRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionInfoResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.tx_info.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionInfoResponse", len)?;
+        if let Some(v) = self.tx_info.as_ref() {
+            struct_ser.serialize_field("txInfo", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionInfoResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "tx_info",
+            "txInfo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TxInfo,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "txInfo" | "tx_info" => Ok(GeneratedField::TxInfo),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionInfoResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.TransactionInfoResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut tx_info__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TxInfo => {
+                            if tx_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txInfo"));
+                            }
+                            tx_info__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(TransactionInfoResponse {
+                    tx_info: tx_info__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionInfoResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionLedgerRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        if self.account_filter.is_some() {
+            len += 1;
+        }
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionLedgerRequest", len)?;
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        if let Some(v) = self.account_filter.as_ref() {
+            struct_ser.serialize_field("accountFilter", v)?;
+        }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionLedgerRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+            "account_filter",
+            "accountFilter",
+            "wallet_id",
+            "walletId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartHeight,
+            EndHeight,
+            AccountFilter,
+            WalletId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "accountFilter" | "account_filter" => Ok(GeneratedField::AccountFilter),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionLedgerRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.TransactionInfoRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.TransactionLedgerRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionLedgerRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut start_height__ = None;
                 let mut end_height__ = None;
+                let mut account_filter__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::StartHeight => {
@@ -4669,18 +5753,32 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::AccountFilter => {
+                            if account_filter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountFilter"));
+                            }
+                            account_filter__ = map_.next_value()?;
+                        }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
                     }
                 }
-                Ok(TransactionInfoRequest {
+                Ok(TransactionLedgerRequest {
                     start_height: start_height__.unwrap_or_default(),
                     end_height: end_height__.unwrap_or_default(),
+                    account_filter: account_filter__,
+                    wallet_id: wallet_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionInfoRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionLedgerRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionInfoResponse {
+impl serde::Serialize for TransactionLedgerResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4688,30 +5786,29 @@ impl serde::Serialize for TransactionInfoResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.tx_info.is_some() {
+        if self.entry.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionInfoResponse", len)?;
-        if let Some(v) = self.tx_info.as_ref() {
-            struct_ser.serialize_field("txInfo", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionLedgerResponse", len)?;
+        if let Some(v) = self.entry.as_ref() {
+            struct_ser.serialize_field("entry", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionInfoResponse {
+impl<'de> serde::Deserialize<'de> for TransactionLedgerResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "tx_info",
-            "txInfo",
+            "entry",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TxInfo,
+            Entry,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4733,7 +5830,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "txInfo" | "tx_info" => Ok(GeneratedField::TxInfo),
+                            "entry" => Ok(GeneratedField::Entry),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4743,33 +5840,33 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionInfoResponse;
+            type Value = TransactionLedgerResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.TransactionInfoResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.TransactionLedgerResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionLedgerResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut tx_info__ = None;
+                let mut entry__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TxInfo => {
-                            if tx_info__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("txInfo"));
+                        GeneratedField::Entry => {
+                            if entry__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entry"));
                             }
-                            tx_info__ = map_.next_value()?;
+                            entry__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(TransactionInfoResponse {
-                    tx_info: tx_info__,
+                Ok(TransactionLedgerResponse {
+                    entry: entry__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionInfoResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionLedgerResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for TransactionPlannerRequest {
@@ -4792,6 +5889,12 @@ impl serde::Serialize for TransactionPlannerRequest {
         if self.source.is_some() {
             len += 1;
         }
+        if self.fee_asset_id.is_some() {
+            len += 1;
+        }
+        if self.payment_request.is_some() {
+            len += 1;
+        }
         if self.wallet_id.is_some() {
             len += 1;
         }
@@ -4810,6 +5913,9 @@ impl serde::Serialize for TransactionPlannerRequest {
         if !self.undelegations.is_empty() {
             len += 1;
         }
+        if !self.redelegations.is_empty() {
+            len += 1;
+        }
         if !self.ibc_relay_actions.is_empty() {
             len += 1;
         }
@@ -4825,6 +5931,9 @@ impl serde::Serialize for TransactionPlannerRequest {
         if !self.position_withdraws.is_empty() {
             len += 1;
         }
+        if !self.position_reward_claims.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest", len)?;
         if self.expiry_height != 0 {
             #[allow(clippy::needless_borrow)]
@@ -4839,6 +5948,12 @@ impl serde::Serialize for TransactionPlannerRequest {
         if let Some(v) = self.source.as_ref() {
             struct_ser.serialize_field("source", v)?;
         }
+        if let Some(v) = self.fee_asset_id.as_ref() {
+            struct_ser.serialize_field("feeAssetId", v)?;
+        }
+        if let Some(v) = self.payment_request.as_ref() {
+            struct_ser.serialize_field("paymentRequest", v)?;
+        }
         if let Some(v) = self.wallet_id.as_ref() {
             struct_ser.serialize_field("walletId", v)?;
         }
@@ -4857,6 +5972,9 @@ impl serde::Serialize for TransactionPlannerRequest {
         if !self.undelegations.is_empty() {
             struct_ser.serialize_field("undelegations", &self.undelegations)?;
         }
+        if !self.redelegations.is_empty() {
+            struct_ser.serialize_field("redelegations", &self.redelegations)?;
+        }
         if !self.ibc_relay_actions.is_empty() {
             struct_ser.serialize_field("ibcRelayActions", &self.ibc_relay_actions)?;
         }
@@ -4872,6 +5990,9 @@ impl serde::Serialize for TransactionPlannerRequest {
         if !self.position_withdraws.is_empty() {
             struct_ser.serialize_field("positionWithdraws", &self.position_withdraws)?;
         }
+        if !self.position_reward_claims.is_empty() {
+            struct_ser.serialize_field("positionRewardClaims", &self.position_reward_claims)?;
+        }
         struct_ser.end()
     }
 }
@@ -4887,6 +6008,10 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
             "fee",
             "memo",
             "source",
+            "fee_asset_id",
+            "feeAssetId",
+            "payment_request",
+            "paymentRequest",
             "wallet_id",
             "walletId",
             "outputs",
@@ -4895,6 +6020,7 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
             "swapClaims",
             "delegations",
             "undelegations",
+            "redelegations",
             "ibc_relay_actions",
             "ibcRelayActions",
             "ics20_withdrawals",
@@ -4905,6 +6031,8 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
             "positionCloses",
             "position_withdraws",
             "positionWithdraws",
+            "position_reward_claims",
+            "positionRewardClaims",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -4913,17 +6041,21 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
             Fee,
             Memo,
             Source,
+            FeeAssetId,
+            PaymentRequest,
             WalletId,
             Outputs,
             Swaps,
             SwapClaims,
             Delegations,
             Undelegations,
+            Redelegations,
             IbcRelayActions,
             Ics20Withdrawals,
             PositionOpens,
             PositionCloses,
             PositionWithdraws,
+            PositionRewardClaims,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4949,17 +6081,21 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                             "fee" => Ok(GeneratedField::Fee),
                             "memo" => Ok(GeneratedField::Memo),
                             "source" => Ok(GeneratedField::Source),
+                            "feeAssetId" | "fee_asset_id" => Ok(GeneratedField::FeeAssetId),
+                            "paymentRequest" | "payment_request" => Ok(GeneratedField::PaymentRequest),
                             "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             "outputs" => Ok(GeneratedField::Outputs),
                             "swaps" => Ok(GeneratedField::Swaps),
                             "swapClaims" | "swap_claims" => Ok(GeneratedField::SwapClaims),
                             "delegations" => Ok(GeneratedField::Delegations),
                             "undelegations" => Ok(GeneratedField::Undelegations),
+                            "redelegations" => Ok(GeneratedField::Redelegations),
                             "ibcRelayActions" | "ibc_relay_actions" => Ok(GeneratedField::IbcRelayActions),
                             "ics20Withdrawals" | "ics20_withdrawals" => Ok(GeneratedField::Ics20Withdrawals),
                             "positionOpens" | "position_opens" => Ok(GeneratedField::PositionOpens),
                             "positionCloses" | "position_closes" => Ok(GeneratedField::PositionCloses),
                             "positionWithdraws" | "position_withdraws" => Ok(GeneratedField::PositionWithdraws),
+                            "positionRewardClaims" | "position_reward_claims" => Ok(GeneratedField::PositionRewardClaims),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4983,17 +6119,21 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                 let mut fee__ = None;
                 let mut memo__ = None;
                 let mut source__ = None;
+                let mut fee_asset_id__ = None;
+                let mut payment_request__ = None;
                 let mut wallet_id__ = None;
                 let mut outputs__ = None;
                 let mut swaps__ = None;
                 let mut swap_claims__ = None;
                 let mut delegations__ = None;
                 let mut undelegations__ = None;
+                let mut redelegations__ = None;
                 let mut ibc_relay_actions__ = None;
                 let mut ics20_withdrawals__ = None;
                 let mut position_opens__ = None;
                 let mut position_closes__ = None;
                 let mut position_withdraws__ = None;
+                let mut position_reward_claims__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ExpiryHeight => {
@@ -5022,6 +6162,18 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                             }
                             source__ = map_.next_value()?;
                         }
+                        GeneratedField::FeeAssetId => {
+                            if fee_asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAssetId"));
+                            }
+                            fee_asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::PaymentRequest => {
+                            if payment_request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("paymentRequest"));
+                            }
+                            payment_request__ = map_.next_value()?;
+                        }
                         GeneratedField::WalletId => {
                             if wallet_id__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("walletId"));
@@ -5058,6 +6210,12 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                             }
                             undelegations__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Redelegations => {
+                            if redelegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegations"));
+                            }
+                            redelegations__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::IbcRelayActions => {
                             if ibc_relay_actions__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("ibcRelayActions"));
@@ -5088,6 +6246,12 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                             }
                             position_withdraws__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::PositionRewardClaims => {
+                            if position_reward_claims__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionRewardClaims"));
+                            }
+                            position_reward_claims__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(TransactionPlannerRequest {
@@ -5095,17 +6259,21 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                     fee: fee__,
                     memo: memo__,
                     source: source__,
+                    fee_asset_id: fee_asset_id__,
+                    payment_request: payment_request__,
                     wallet_id: wallet_id__,
                     outputs: outputs__.unwrap_or_default(),
                     swaps: swaps__.unwrap_or_default(),
                     swap_claims: swap_claims__.unwrap_or_default(),
                     delegations: delegations__.unwrap_or_default(),
                     undelegations: undelegations__.unwrap_or_default(),
+                    redelegations: redelegations__.unwrap_or_default(),
                     ibc_relay_actions: ibc_relay_actions__.unwrap_or_default(),
                     ics20_withdrawals: ics20_withdrawals__.unwrap_or_default(),
                     position_opens: position_opens__.unwrap_or_default(),
                     position_closes: position_closes__.unwrap_or_default(),
                     position_withdraws: position_withdraws__.unwrap_or_default(),
+                    position_reward_claims: position_reward_claims__.unwrap_or_default(),
                 })
             }
         }
@@ -5512,6 +6680,115 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionOpen
         deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest.PositionOpen", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for transaction_planner_request::PositionRewardClaim {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.rewards.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest.PositionRewardClaim", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.rewards.as_ref() {
+            struct_ser.serialize_field("rewards", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionRewardClaim {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "rewards",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            Rewards,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "rewards" => Ok(GeneratedField::Rewards),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::PositionRewardClaim;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.TransactionPlannerRequest.PositionRewardClaim")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::PositionRewardClaim, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut rewards__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(transaction_planner_request::PositionRewardClaim {
+                    position_id: position_id__,
+                    rewards: rewards__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest.PositionRewardClaim", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for transaction_planner_request::PositionWithdraw {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -5639,6 +6916,133 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithd
         deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest.PositionWithdraw", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for transaction_planner_request::Redelegate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        if self.from_rate_data.is_some() {
+            len += 1;
+        }
+        if self.to_rate_data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest.Redelegate", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if let Some(v) = self.from_rate_data.as_ref() {
+            struct_ser.serialize_field("fromRateData", v)?;
+        }
+        if let Some(v) = self.to_rate_data.as_ref() {
+            struct_ser.serialize_field("toRateData", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::Redelegate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "value",
+            "from_rate_data",
+            "fromRateData",
+            "to_rate_data",
+            "toRateData",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Value,
+            FromRateData,
+            ToRateData,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "value" => Ok(GeneratedField::Value),
+                            "fromRateData" | "from_rate_data" => Ok(GeneratedField::FromRateData),
+                            "toRateData" | "to_rate_data" => Ok(GeneratedField::ToRateData),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::Redelegate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.TransactionPlannerRequest.Redelegate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Redelegate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                let mut from_rate_data__ = None;
+                let mut to_rate_data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map_.next_value()?;
+                        }
+                        GeneratedField::FromRateData => {
+                            if from_rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromRateData"));
+                            }
+                            from_rate_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::ToRateData => {
+                            if to_rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toRateData"));
+                            }
+                            to_rate_data__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(transaction_planner_request::Redelegate {
+                    value: value__,
+                    from_rate_data: from_rate_data__,
+                    to_rate_data: to_rate_data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest.Redelegate", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for transaction_planner_request::Swap {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -5659,6 +7063,9 @@ impl serde::Serialize for transaction_planner_request::Swap {
         if self.claim_address.is_some() {
             len += 1;
         }
+        if self.min_output.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest.Swap", len)?;
         if let Some(v) = self.value.as_ref() {
             struct_ser.serialize_field("value", v)?;
@@ -5672,6 +7079,9 @@ impl serde::Serialize for transaction_planner_request::Swap {
         if let Some(v) = self.claim_address.as_ref() {
             struct_ser.serialize_field("claimAddress", v)?;
         }
+        if let Some(v) = self.min_output.as_ref() {
+            struct_ser.serialize_field("minOutput", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -5688,6 +7098,8 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
             "fee",
             "claim_address",
             "claimAddress",
+            "min_output",
+            "minOutput",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -5696,6 +7108,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
             TargetAsset,
             Fee,
             ClaimAddress,
+            MinOutput,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5721,6 +7134,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
                             "targetAsset" | "target_asset" => Ok(GeneratedField::TargetAsset),
                             "fee" => Ok(GeneratedField::Fee),
                             "claimAddress" | "claim_address" => Ok(GeneratedField::ClaimAddress),
+                            "minOutput" | "min_output" => Ok(GeneratedField::MinOutput),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -5744,6 +7158,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
                 let mut target_asset__ = None;
                 let mut fee__ = None;
                 let mut claim_address__ = None;
+                let mut min_output__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Value => {
@@ -5770,6 +7185,12 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
                             }
                             claim_address__ = map_.next_value()?;
                         }
+                        GeneratedField::MinOutput => {
+                            if min_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minOutput"));
+                            }
+                            min_output__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(transaction_planner_request::Swap {
@@ -5777,6 +7198,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
                     target_asset: target_asset__,
                     fee: fee__,
                     claim_address: claim_address__,
+                    min_output: min_output__,
                 })
             }
         }
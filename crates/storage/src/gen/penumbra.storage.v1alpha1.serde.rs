@@ -15,6 +15,9 @@ impl serde::Serialize for KeyValueRequest {
         if self.proof {
             len += 1;
         }
+        if self.height.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.storage.v1alpha1.KeyValueRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -25,6 +28,10 @@ impl serde::Serialize for KeyValueRequest {
         if self.proof {
             struct_ser.serialize_field("proof", &self.proof)?;
         }
+        if let Some(v) = self.height.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&v).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -39,6 +46,7 @@ impl<'de> serde::Deserialize<'de> for KeyValueRequest {
             "chainId",
             "key",
             "proof",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -46,6 +54,7 @@ impl<'de> serde::Deserialize<'de> for KeyValueRequest {
             ChainId,
             Key,
             Proof,
+            Height,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -70,6 +79,7 @@ impl<'de> serde::Deserialize<'de> for KeyValueRequest {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
                             "key" => Ok(GeneratedField::Key),
                             "proof" => Ok(GeneratedField::Proof),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -92,6 +102,7 @@ impl<'de> serde::Deserialize<'de> for KeyValueRequest {
                 let mut chain_id__ = None;
                 let mut key__ = None;
                 let mut proof__ = None;
+                let mut height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -112,12 +123,21 @@ impl<'de> serde::Deserialize<'de> for KeyValueRequest {
                             }
                             proof__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
                     }
                 }
                 Ok(KeyValueRequest {
                     chain_id: chain_id__.unwrap_or_default(),
                     key: key__.unwrap_or_default(),
                     proof: proof__.unwrap_or_default(),
+                    height: height__,
                 })
             }
         }
@@ -326,6 +346,311 @@ impl<'de> serde::Deserialize<'de> for key_value_response::Value {
         deserializer.deserialize_struct("penumbra.storage.v1alpha1.KeyValueResponse.Value", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for NonVerifiableRangeRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.height.is_some() {
+            len += 1;
+        }
+        if !self.prefix.is_empty() {
+            len += 1;
+        }
+        if !self.start.is_empty() {
+            len += 1;
+        }
+        if !self.end.is_empty() {
+            len += 1;
+        }
+        if self.limit != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.storage.v1alpha1.NonVerifiableRangeRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if let Some(v) = self.height.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&v).as_str())?;
+        }
+        if !self.prefix.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("prefix", pbjson::private::base64::encode(&self.prefix).as_str())?;
+        }
+        if !self.start.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("start", pbjson::private::base64::encode(&self.start).as_str())?;
+        }
+        if !self.end.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("end", pbjson::private::base64::encode(&self.end).as_str())?;
+        }
+        if self.limit != 0 {
+            struct_ser.serialize_field("limit", &self.limit)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NonVerifiableRangeRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "height",
+            "prefix",
+            "start",
+            "end",
+            "limit",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            Height,
+            Prefix,
+            Start,
+            End,
+            Limit,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "height" => Ok(GeneratedField::Height),
+                            "prefix" => Ok(GeneratedField::Prefix),
+                            "start" => Ok(GeneratedField::Start),
+                            "end" => Ok(GeneratedField::End),
+                            "limit" => Ok(GeneratedField::Limit),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NonVerifiableRangeRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.storage.v1alpha1.NonVerifiableRangeRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NonVerifiableRangeRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut height__ = None;
+                let mut prefix__ = None;
+                let mut start__ = None;
+                let mut end__ = None;
+                let mut limit__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::Prefix => {
+                            if prefix__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prefix"));
+                            }
+                            prefix__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Start => {
+                            if start__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("start"));
+                            }
+                            start__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::End => {
+                            if end__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("end"));
+                            }
+                            end__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Limit => {
+                            if limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limit"));
+                            }
+                            limit__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(NonVerifiableRangeRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    height: height__,
+                    prefix: prefix__.unwrap_or_default(),
+                    start: start__.unwrap_or_default(),
+                    end: end__.unwrap_or_default(),
+                    limit: limit__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.storage.v1alpha1.NonVerifiableRangeRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NonVerifiableRangeResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.key.is_empty() {
+            len += 1;
+        }
+        if !self.value.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.storage.v1alpha1.NonVerifiableRangeResponse", len)?;
+        if !self.key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("key", pbjson::private::base64::encode(&self.key).as_str())?;
+        }
+        if !self.value.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("value", pbjson::private::base64::encode(&self.value).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NonVerifiableRangeResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "key",
+            "value",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Key,
+            Value,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "key" => Ok(GeneratedField::Key),
+                            "value" => Ok(GeneratedField::Value),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NonVerifiableRangeResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.storage.v1alpha1.NonVerifiableRangeResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NonVerifiableRangeResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut key__ = None;
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Key => {
+                            if key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("key"));
+                            }
+                            key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(NonVerifiableRangeResponse {
+                    key: key__.unwrap_or_default(),
+                    value: value__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.storage.v1alpha1.NonVerifiableRangeResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for PrefixValueRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -340,6 +665,15 @@ impl serde::Serialize for PrefixValueRequest {
         if !self.prefix.is_empty() {
             len += 1;
         }
+        if self.height.is_some() {
+            len += 1;
+        }
+        if !self.start_after.is_empty() {
+            len += 1;
+        }
+        if self.limit != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.storage.v1alpha1.PrefixValueRequest", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -347,6 +681,16 @@ impl serde::Serialize for PrefixValueRequest {
         if !self.prefix.is_empty() {
             struct_ser.serialize_field("prefix", &self.prefix)?;
         }
+        if let Some(v) = self.height.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&v).as_str())?;
+        }
+        if !self.start_after.is_empty() {
+            struct_ser.serialize_field("startAfter", &self.start_after)?;
+        }
+        if self.limit != 0 {
+            struct_ser.serialize_field("limit", &self.limit)?;
+        }
         struct_ser.end()
     }
 }
@@ -360,12 +704,19 @@ impl<'de> serde::Deserialize<'de> for PrefixValueRequest {
             "chain_id",
             "chainId",
             "prefix",
+            "height",
+            "start_after",
+            "startAfter",
+            "limit",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ChainId,
             Prefix,
+            Height,
+            StartAfter,
+            Limit,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -389,6 +740,9 @@ impl<'de> serde::Deserialize<'de> for PrefixValueRequest {
                         match value {
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
                             "prefix" => Ok(GeneratedField::Prefix),
+                            "height" => Ok(GeneratedField::Height),
+                            "startAfter" | "start_after" => Ok(GeneratedField::StartAfter),
+                            "limit" => Ok(GeneratedField::Limit),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -410,6 +764,9 @@ impl<'de> serde::Deserialize<'de> for PrefixValueRequest {
             {
                 let mut chain_id__ = None;
                 let mut prefix__ = None;
+                let mut height__ = None;
+                let mut start_after__ = None;
+                let mut limit__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -424,11 +781,36 @@ impl<'de> serde::Deserialize<'de> for PrefixValueRequest {
                             }
                             prefix__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::StartAfter => {
+                            if start_after__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startAfter"));
+                            }
+                            start_after__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Limit => {
+                            if limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limit"));
+                            }
+                            limit__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(PrefixValueRequest {
                     chain_id: chain_id__.unwrap_or_default(),
                     prefix: prefix__.unwrap_or_default(),
+                    height: height__,
+                    start_after: start_after__.unwrap_or_default(),
+                    limit: limit__.unwrap_or_default(),
                 })
             }
         }
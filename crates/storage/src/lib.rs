@@ -61,7 +61,7 @@ pub use escaped_byte_slice::EscapedByteSlice;
 pub use jmt::{ics23_spec, RootHash};
 pub use read::StateRead;
 pub use snapshot::Snapshot;
-pub use storage::{Storage, TempStorage};
+pub use storage::{ExpectedRoots, PruningConfig, Restore, Storage, SubstoreChunk, TempStorage};
 pub use write::StateWrite;
 
 pub mod future;
@@ -18,19 +18,42 @@ impl Server {
     pub fn new(storage: Storage) -> Self {
         Self { storage }
     }
+
+    /// Returns the snapshot of the state at the given height, or the latest
+    /// snapshot if no height is given.
+    fn snapshot(&self, height: Option<u64>) -> Result<Snapshot, Status> {
+        let latest = self.storage.latest_snapshot();
+        let Some(height) = height else {
+            return Ok(latest);
+        };
+
+        let latest_version = latest.version();
+        if height > latest_version {
+            return Err(Status::out_of_range(format!(
+                "height {height} is greater than the latest height {latest_version}"
+            )));
+        }
+
+        self.storage.snapshot(height).ok_or_else(|| {
+            Status::not_found(format!(
+                "state at height {height} is no longer retained (latest height is {latest_version})"
+            ))
+        })
+    }
 }
-use std::pin::Pin;
+use std::{ops::Bound, pin::Pin};
 
 use crate::read::StateRead;
 use crate::rpc::proto::v1alpha1::{
     key_value_response::Value, query_service_server::QueryService, KeyValueRequest,
-    KeyValueResponse, PrefixValueRequest, PrefixValueResponse,
+    KeyValueResponse, NonVerifiableRangeRequest, NonVerifiableRangeResponse, PrefixValueRequest,
+    PrefixValueResponse,
 };
 use futures::{StreamExt, TryStreamExt};
 use tonic::Status;
 use tracing::instrument;
 
-use crate::Storage;
+use crate::{Snapshot, Storage};
 #[tonic::async_trait]
 impl QueryService for Server {
     #[instrument(skip(self, request))]
@@ -38,11 +61,11 @@ impl QueryService for Server {
         &self,
         request: tonic::Request<KeyValueRequest>,
     ) -> Result<tonic::Response<KeyValueResponse>, Status> {
-        let state = self.storage.latest_snapshot();
         // We map the error here to avoid including `tonic` as a dependency
         // in the `chain` crate, to support its compilation to wasm.
         let request = request.into_inner();
         tracing::debug!(?request, "processing key_value request");
+        let state = self.snapshot(request.height)?;
 
         if request.key.is_empty() {
             return Err(Status::invalid_argument("key is empty"));
@@ -82,27 +105,81 @@ impl QueryService for Server {
         &self,
         request: tonic::Request<PrefixValueRequest>,
     ) -> Result<tonic::Response<Self::PrefixValueStream>, Status> {
-        let state = self.storage.latest_snapshot();
         let request = request.into_inner();
         tracing::debug!(?request);
+        let state = self.snapshot(request.height)?;
 
         if request.prefix.is_empty() {
             return Err(Status::invalid_argument("prefix is empty"));
         }
 
-        Ok(tonic::Response::new(
+        // Keys are returned in order, so a page starts after the last key of
+        // the previous one.
+        let stream = if request.start_after.is_empty() {
+            state.prefix_raw(&request.prefix)
+        } else {
+            state.prefix_raw_after(&request.prefix, &request.start_after)
+        };
+        let stream = stream
+            .map_ok(|i: (String, Vec<u8>)| {
+                let (key, value) = i;
+                PrefixValueResponse { key, value }
+            })
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!("error getting prefix value from storage: {e}"))
+            });
+
+        Ok(tonic::Response::new(if request.limit > 0 {
+            stream.take(request.limit as usize).boxed()
+        } else {
+            stream.boxed()
+        }))
+    }
+
+    type NonVerifiableRangeStream = Pin<
+        Box<dyn futures::Stream<Item = Result<NonVerifiableRangeResponse, tonic::Status>> + Send>,
+    >;
+
+    #[instrument(skip(self, request))]
+    async fn non_verifiable_range(
+        &self,
+        request: tonic::Request<NonVerifiableRangeRequest>,
+    ) -> Result<tonic::Response<Self::NonVerifiableRangeStream>, Status> {
+        let request = request.into_inner();
+        tracing::debug!(?request);
+        let state = self.snapshot(request.height)?;
+
+        if request.prefix.is_empty() {
+            return Err(Status::invalid_argument("prefix is empty"));
+        }
+
+        let stream = if request.start.is_empty() && request.end.is_empty() {
+            state.nonverifiable_prefix_raw(&request.prefix).boxed()
+        } else {
+            let start = Bound::Included(request.start);
+            let end = if request.end.is_empty() {
+                Bound::Unbounded
+            } else {
+                Bound::Excluded(request.end)
+            };
             state
-                .prefix_raw(&request.prefix)
-                .map_ok(|i: (String, Vec<u8>)| {
-                    let (key, value) = i;
-                    PrefixValueResponse { key, value }
-                })
-                .map_err(|e: anyhow::Error| {
-                    tonic::Status::unavailable(format!(
-                        "error getting prefix value from storage: {e}"
-                    ))
-                })
-                .boxed(),
-        ))
+                .nonverifiable_range_raw(Some(&request.prefix), (start, end))
+                .map_err(|e| Status::invalid_argument(format!("invalid range: {e}")))?
+                .boxed()
+        };
+
+        let stream = stream
+            .map_ok(|(key, value)| NonVerifiableRangeResponse { key, value })
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!(
+                    "error getting nonverifiable range from storage: {e}"
+                ))
+            });
+
+        Ok(tonic::Response::new(if request.limit > 0 {
+            stream.take(request.limit as usize).boxed()
+        } else {
+            stream.boxed()
+        }))
     }
 }
@@ -2,14 +2,16 @@ use std::{any::Any, sync::Arc};
 
 use anyhow::Result;
 use async_trait::async_trait;
+use borsh::BorshSerialize;
 use ibc_types::core::commitment::MerkleProof;
+use jmt::storage::HasPreimage;
 use tokio::sync::mpsc;
 use tracing::Span;
 
 #[cfg(feature = "metrics")]
 use crate::metrics;
 use crate::store::multistore::{self, MultistoreCache};
-use crate::{store, StateRead};
+use crate::{store, StateRead, SubstoreChunk};
 
 mod rocks_wrapper;
 
@@ -43,9 +45,20 @@ impl Snapshot {
         db: Arc<rocksdb::DB>,
         version: jmt::Version,
         multistore_cache: multistore::MultistoreCache,
+    ) -> Self {
+        let snapshot = Arc::new(RocksDbSnapshot::new(db.clone()));
+        Self::from_rocksdb_snapshot(db, snapshot, version, multistore_cache)
+    }
+
+    /// Creates a new `Snapshot` on top of an existing RocksDB snapshot.
+    pub(crate) fn from_rocksdb_snapshot(
+        db: Arc<rocksdb::DB>,
+        snapshot: Arc<RocksDbSnapshot>,
+        version: jmt::Version,
+        multistore_cache: multistore::MultistoreCache,
     ) -> Self {
         Self(Arc::new(Inner {
-            snapshot: Arc::new(RocksDbSnapshot::new(db.clone())),
+            snapshot,
             version,
             db,
             multistore_cache,
@@ -185,6 +198,200 @@ impl Snapshot {
         self.prefix_root_hash("").await
     }
 
+    /// Returns a stream of all key-value pairs with the given prefix whose keys
+    /// sort strictly after `start_after`.
+    ///
+    /// This seeks directly to `start_after` rather than skipping over the
+    /// output of [`StateRead::prefix_raw`], so resuming a paged scan doesn't
+    /// re-read the keys that were already returned.
+    pub fn prefix_raw_after(
+        &self,
+        prefix: &str,
+        start_after: &str,
+    ) -> <Self as StateRead>::PrefixRawStream {
+        self.prefix_raw_from(prefix, Some(start_after))
+    }
+
+    /// Returns a stream of the key-value pairs with the given prefix, starting
+    /// strictly after `start_after` if it is set.
+    fn prefix_raw_from(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+    ) -> <Self as StateRead>::PrefixRawStream {
+        let span = Span::current();
+
+        let rocksdb_snapshot = self.0.snapshot.clone();
+        let db = self.0.db.clone();
+
+        let (prefix_truncated, config) = self.0.multistore_cache.config.match_prefix_str(prefix);
+        tracing::debug!(substore_key = prefix_truncated,  substore_prefix = config.prefix, prefix_supplied = ?prefix, "matched prefix, fetching substore");
+
+        let version = self
+            .substore_version(&config)
+            .expect("the substore exists and has been initialized");
+
+        let substore = store::substore::SubstoreSnapshot {
+            config,
+            rocksdb_snapshot,
+            version,
+            db,
+        };
+
+        // Key preimages are stored relative to their substore, so the cursor
+        // has to be translated the same way as the prefix before seeking.
+        let start_after = start_after.map(|key| {
+            let key = if substore.config.prefix.is_empty() {
+                key
+            } else {
+                key.strip_prefix(&substore.config.prefix_with_delimiter)
+                    .unwrap_or(key)
+            };
+            key.as_bytes().to_vec()
+        });
+        // Seeking below the start of the prefix range is the same as not
+        // seeking at all.
+        let seek_to = start_after
+            .clone()
+            .filter(|key| key.as_slice() > prefix_truncated.as_bytes());
+
+        let mut options = rocksdb::ReadOptions::default();
+        options.set_iterate_range(rocksdb::PrefixRange(prefix_truncated.as_bytes()));
+        let (tx_prefix_item, rx_prefix_query) = mpsc::channel(10);
+
+        // Since the JMT keys are hashed, we can't use a prefix iterator directly.
+        // We need to first prefix range the key preimages column family, then use the hashed matches to fetch the values
+        // from the JMT column family.
+        tokio::task::Builder::new()
+            .name("Snapshot::prefix_raw")
+            .spawn_blocking(move || {
+                span.in_scope(|| {
+                    let cf_jmt_keys = substore.config.cf_jmt_keys(&substore.db);
+                    let mode = match seek_to.as_deref() {
+                        Some(key) => rocksdb::IteratorMode::From(key, rocksdb::Direction::Forward),
+                        None => rocksdb::IteratorMode::Start,
+                    };
+                    let jmt_keys_iterator =
+                        substore
+                            .rocksdb_snapshot
+                            .iterator_cf_opt(cf_jmt_keys, options, mode);
+
+                    for tuple in jmt_keys_iterator {
+                        // For each key that matches the prefix, fetch the value from the JMT column family.
+                        let (key_preimage, _) = tuple?;
+
+                        // The seek lands on the cursor itself if it still exists.
+                        if start_after.as_deref() == Some(key_preimage.as_ref()) {
+                            continue;
+                        }
+
+                        let k = std::str::from_utf8(key_preimage.as_ref())
+                            .expect("saved jmt keys are utf-8 strings")
+                            .to_string();
+
+                        let key_hash = jmt::KeyHash::with::<sha2::Sha256>(k.as_bytes());
+
+                        let v = substore
+                            .get_jmt(key_hash)?
+                            .expect("keys in jmt_keys should have a corresponding value in jmt");
+
+                        tx_prefix_item.blocking_send(Ok((k, v)))?;
+                    }
+                    anyhow::Ok(())
+                })
+            })
+            .expect("should be able to spawn_blocking");
+
+        tokio_stream::wrappers::ReceiverStream::new(rx_prefix_query)
+    }
+
+    /// Returns a stream of the leaves of the tree of the substore with the given
+    /// prefix, in key hash order, split into chunks of about `chunk_size` bytes,
+    /// each carrying a proof that a [`Restore`](crate::Restore) can check.
+    ///
+    /// An empty substore has no chunks.
+    pub fn substore_chunks(
+        &self,
+        prefix: &str,
+        chunk_size: usize,
+    ) -> tokio_stream::wrappers::ReceiverStream<Result<SubstoreChunk>> {
+        let span = Span::current();
+        let (tx_chunk, rx_chunk) = mpsc::channel(2);
+
+        let config = self
+            .0
+            .multistore_cache
+            .config
+            .find_substore(prefix.as_bytes());
+        let prefix = prefix.to_string();
+        let substore = self
+            .substore_version(&config)
+            .filter(|_| config.prefix == prefix)
+            .map(|version| store::substore::SubstoreSnapshot {
+                config,
+                rocksdb_snapshot: self.0.snapshot.clone(),
+                version,
+                db: self.0.db.clone(),
+            });
+
+        tokio::task::Builder::new()
+            .name("Snapshot::substore_chunks")
+            .spawn_blocking(move || {
+                span.in_scope(|| {
+                    let Some(substore) = substore else {
+                        anyhow::bail!("requested substore (prefix={prefix}) does not exist")
+                    };
+                    let version = substore.version;
+                    if jmt::Sha256Jmt::new(&substore)
+                        .get_root_hash_option(version)?
+                        .is_none()
+                    {
+                        return Ok(());
+                    }
+
+                    let substore = Arc::new(substore);
+                    let leaves = jmt::JellyfishMerkleIterator::new(
+                        substore.clone(),
+                        version,
+                        jmt::KeyHash([0; 32]),
+                    )?;
+                    let mut chunk = Vec::new();
+                    let mut size = 0;
+                    let mut leaves = leaves.peekable();
+                    while let Some(leaf) = leaves.next() {
+                        let (key_hash, value) = leaf?;
+                        let key = substore
+                            .preimage(key_hash)?
+                            .ok_or_else(|| anyhow::anyhow!("missing preimage for {key_hash:?}"))?;
+                        let key = String::from_utf8(key)?;
+                        size += key.len() + value.len();
+                        chunk.push((key, value));
+
+                        if size >= chunk_size || leaves.peek().is_none() {
+                            // The range proof of the last leaf of the chunk covers
+                            // every leaf up to it, including the previous chunks.
+                            let proof = jmt::Sha256Jmt::new(substore.as_ref())
+                                .get_range_proof(key_hash, version)?;
+                            tx_chunk.blocking_send(Ok(SubstoreChunk {
+                                prefix: prefix.clone(),
+                                version,
+                                leaves: std::mem::take(&mut chunk),
+                                proof: proof.try_to_vec()?,
+                            }))?;
+                            size = 0;
+                        }
+                    }
+                    anyhow::Ok(())
+                })
+                .map_err(|e| {
+                    let _ = tx_chunk.blocking_send(Err(e));
+                })
+            })
+            .expect("should be able to spawn_blocking");
+
+        tokio_stream::wrappers::ReceiverStream::new(rx_chunk)
+    }
+
     pub(crate) fn substore_version(
         &self,
         prefix: &Arc<store::substore::SubstoreConfig>,
@@ -286,65 +493,7 @@ impl StateRead for Snapshot {
 
     /// Returns a stream of all key-value pairs with the given prefix.
     fn prefix_raw(&self, prefix: &str) -> Self::PrefixRawStream {
-        let span = Span::current();
-
-        let rocksdb_snapshot = self.0.snapshot.clone();
-        let db = self.0.db.clone();
-
-        let (prefix_truncated, config) = self.0.multistore_cache.config.match_prefix_str(prefix);
-        tracing::debug!(substore_key = prefix_truncated,  substore_prefix = config.prefix, prefix_supplied = ?prefix, "matched prefix, fetching substore");
-
-        let version = self
-            .substore_version(&config)
-            .expect("the substore exists and has been initialized");
-
-        let substore = store::substore::SubstoreSnapshot {
-            config,
-            rocksdb_snapshot,
-            version,
-            db,
-        };
-
-        let mut options = rocksdb::ReadOptions::default();
-        options.set_iterate_range(rocksdb::PrefixRange(prefix_truncated.as_bytes()));
-        let mode = rocksdb::IteratorMode::Start;
-        let (tx_prefix_item, rx_prefix_query) = mpsc::channel(10);
-
-        // Since the JMT keys are hashed, we can't use a prefix iterator directly.
-        // We need to first prefix range the key preimages column family, then use the hashed matches to fetch the values
-        // from the JMT column family.
-        tokio::task::Builder::new()
-            .name("Snapshot::prefix_raw")
-            .spawn_blocking(move || {
-                span.in_scope(|| {
-                    let cf_jmt_keys = substore.config.cf_jmt_keys(&substore.db);
-                    let jmt_keys_iterator =
-                        substore
-                            .rocksdb_snapshot
-                            .iterator_cf_opt(cf_jmt_keys, options, mode);
-
-                    for tuple in jmt_keys_iterator {
-                        // For each key that matches the prefix, fetch the value from the JMT column family.
-                        let (key_preimage, _) = tuple?;
-
-                        let k = std::str::from_utf8(key_preimage.as_ref())
-                            .expect("saved jmt keys are utf-8 strings")
-                            .to_string();
-
-                        let key_hash = jmt::KeyHash::with::<sha2::Sha256>(k.as_bytes());
-
-                        let v = substore
-                            .get_jmt(key_hash)?
-                            .expect("keys in jmt_keys should have a corresponding value in jmt");
-
-                        tx_prefix_item.blocking_send(Ok((k, v)))?;
-                    }
-                    anyhow::Ok(())
-                })
-            })
-            .expect("should be able to spawn_blocking");
-
-        tokio_stream::wrappers::ReceiverStream::new(rx_prefix_query)
+        self.prefix_raw_from(prefix, None)
     }
 
     // NOTE: this implementation is almost the same as the above, but without
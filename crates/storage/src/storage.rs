@@ -17,7 +17,11 @@ use crate::{
 };
 use crate::{snapshot_cache::SnapshotCache, StateDelta};
 
+mod prune;
+mod restore;
 mod temp;
+pub use prune::PruningConfig;
+pub use restore::{Restore, SubstoreChunk};
 pub use temp::TempStorage;
 
 /// A handle for a storage instance, backed by RocksDB.
@@ -141,6 +145,8 @@ impl Storage {
 
                     tracing::info!(?path, "opening rocksdb");
                     let cf_config_string = "config".to_string();
+                    let cf_versions_string = prune::CF_VERSIONS.to_string();
+                    let cf_restore_string = restore::CF_RESTORE.to_string();
                     // RocksDB setup: define options, collect all the columns, and open the database.
                     // Each substore defines a prefix and its own set of columns.
                     // See [`crate::store::SubstoreConfig`] for more details.
@@ -148,10 +154,21 @@ impl Storage {
                     opts.create_if_missing(true);
                     opts.create_missing_column_families(true);
                     columns.push(&cf_config_string);
+                    columns.push(&cf_versions_string);
+                    columns.push(&cf_restore_string);
 
                     let db = DB::open_cf(&opts, path, columns)?;
                     let shared_db = Arc::new(db);
 
+                    // A restore that did not complete leaves a partial state behind,
+                    // which cannot be told apart from a committed one.
+                    if shared_db
+                        .get_cf(restore::cf_restore(&shared_db), restore::RESTORE_IN_PROGRESS)?
+                        .is_some()
+                    {
+                        bail!("the database holds a partially restored snapshot, and must be deleted");
+                    }
+
                     // Initialize the substore cache with the latest version of each substore.
                     // Note: for compatibility reasons with Tendermint/CometBFT, we set the "pre-genesis"
                     // jmt version to be u64::MAX, corresponding to -1 mod 2^64.
@@ -251,10 +268,23 @@ impl Storage {
         self.0.snapshots.read().latest()
     }
 
-    /// Fetches the [`Snapshot`] corresponding to the supplied `jmt::Version` from
-    /// the [`SnapshotCache`]. Returns `None` if no match was found.
+    /// Fetches the [`Snapshot`] corresponding to the supplied `jmt::Version`.
+    ///
+    /// Recent versions are served from the [`SnapshotCache`], older versions are
+    /// opened from disk if they have not been pruned. Returns `None` if no match
+    /// was found.
     pub fn snapshot(&self, version: jmt::Version) -> Option<Snapshot> {
-        self.0.snapshots.read().get(version)
+        if let Some(snapshot) = self.0.snapshots.read().get(version) {
+            return Some(snapshot);
+        }
+
+        match self.retained_snapshot(version) {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                tracing::warn!(?e, ?version, "failed to open a retained snapshot");
+                None
+            }
+        }
     }
 
     /// Commits the provided [`StateDelta`] to persistent storage as the latest
@@ -272,7 +302,7 @@ impl Storage {
             anyhow::bail!("version mismatch in commit: expected state forked from version {} but found state forked from version {}", old_version, snapshot.version());
         }
 
-        self.commit_inner(snapshot, changes, new_version, false)
+        self.commit_inner(snapshot, changes, new_version, false, None)
             .await
     }
 
@@ -289,6 +319,7 @@ impl Storage {
         cache: Cache,
         version: jmt::Version,
         perform_migration: bool,
+        expected_roots: Option<&ExpectedRoots>,
     ) -> Result<crate::RootHash> {
         tracing::debug!(new_jmt_version = ?version, "committing state delta");
         let mut changes_by_substore = cache.shard_by_prefix(&self.0.multistore_config);
@@ -398,7 +429,7 @@ impl Storage {
             substore_snapshot: main_store_snapshot,
         };
 
-        let (global_root_hash, write_batch) = main_store_storage
+        let (global_root_hash, mut write_batch) = main_store_storage
             .commit(main_store_changes, write_batch, version)
             .await?;
         tracing::debug!(
@@ -406,22 +437,40 @@ impl Storage {
             ?version,
             "added main store to write batch"
         );
-        db.write(write_batch).expect("can write to db");
 
         /* update multistore versions */
-        for (config, root_hash, new_version) in substore_roots {
+        for (config, root_hash, new_version) in substore_roots.iter() {
             tracing::debug!(
                 ?root_hash,
                 prefix = ?config.prefix,
                 ?new_version,
                 "updating substore version"
             );
-            multistore_versions.set_version(config, new_version);
+            multistore_versions.set_version(config.clone(), *new_version);
         }
 
         tracing::debug!(?global_root_hash, ?version, "updating main store version");
         multistore_versions.set_version(main_store_config, version);
 
+        // Record the version of every substore at this version of the main store,
+        // so that retained versions can be opened, and pruned, later on.
+        let cf_versions = prune::cf_versions(&db);
+        for (config, substore_version) in multistore_versions.substores.iter() {
+            write_batch.put_cf(
+                cf_versions,
+                prune::version_index_key(version, &config.prefix),
+                substore_version.to_be_bytes(),
+            );
+        }
+
+        // Nothing has been written yet, so a state that does not hash to the expected
+        // roots can be dropped without leaving a trace in the database.
+        if let Some(expected) = expected_roots {
+            expected.check(&global_root_hash, &substore_roots)?;
+        }
+
+        db.write(write_batch).expect("can write to db");
+
         /* hydrate the snapshot cache */
         if perform_migration {
             tracing::debug!("skipping snapshot cache update");
@@ -454,7 +503,21 @@ impl Storage {
     pub async fn commit_in_place(&self, delta: StateDelta<Snapshot>) -> Result<crate::RootHash> {
         let (snapshot, changes) = delta.flatten();
         let old_version = self.latest_version();
-        self.commit_inner(snapshot, changes, old_version, true)
+        self.commit_inner(snapshot, changes, old_version, true, None)
+            .await
+    }
+
+    #[cfg(feature = "migration")]
+    /// Commits the provided [`StateDelta`] in place, like [`Storage::commit_in_place`], but only
+    /// if the migrated state hashes to `expected_roots`. On a mismatch, nothing is written.
+    pub async fn commit_in_place_expecting(
+        &self,
+        delta: StateDelta<Snapshot>,
+        expected_roots: &ExpectedRoots,
+    ) -> Result<crate::RootHash> {
+        let (snapshot, changes) = delta.flatten();
+        let old_version = self.latest_version();
+        self.commit_inner(snapshot, changes, old_version, true, Some(expected_roots))
             .await
     }
 
@@ -477,6 +540,48 @@ impl Storage {
     }
 }
 
+/// The root hashes that a state committed in place, such as a migrated one, must have,
+/// as obtained from a trusted source.
+#[derive(Clone, Debug)]
+pub struct ExpectedRoots {
+    /// The root hash of the whole state, i.e. the app hash.
+    pub root_hash: crate::RootHash,
+    /// The root hashes of the substores, keyed by prefix.
+    pub substore_root_hashes: Vec<(String, crate::RootHash)>,
+}
+
+impl ExpectedRoots {
+    /// Checks the root hashes computed for a commit against the expected ones.
+    fn check(
+        &self,
+        root_hash: &crate::RootHash,
+        substore_roots: &[(Arc<SubstoreConfig>, crate::RootHash, jmt::Version)],
+    ) -> Result<()> {
+        if *root_hash != self.root_hash {
+            bail!(
+                "root hash mismatch: expected {}, computed {}",
+                hex::encode(self.root_hash.0),
+                hex::encode(root_hash.0)
+            );
+        }
+        // The main store commits to the root of every substore that has state, so
+        // the check above already covers them; checking them one by one tells us
+        // which substore diverged. Empty substores are not part of the commit.
+        for (prefix, expected) in self.substore_root_hashes.iter() {
+            let Some((_, computed, _)) = substore_roots
+                .iter()
+                .find(|(config, _, _)| config.prefix == *prefix)
+            else {
+                continue;
+            };
+            if computed != expected {
+                bail!("root hash mismatch for substore {prefix}");
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Inner {
     pub(crate) async fn shutdown(&mut self) {
         if let Some(jh) = self.jh_dispatcher.take() {
@@ -0,0 +1,321 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use anyhow::{bail, Result};
+use jmt::KeyHash;
+use rocksdb::{ColumnFamily, IteratorMode, ReadOptions, WriteBatch, DB};
+use tracing::Span;
+
+use crate::{
+    snapshot::{RocksDbSnapshot, Snapshot},
+    store::{
+        multistore::MultistoreCache,
+        substore::{DbNodeKey, StaleEntry, StaleIndexKey, SubstoreConfig, VersionedKeyHash},
+    },
+};
+
+use super::Storage;
+
+/// name: "multistore-versions"
+/// role: records the version of every substore at each version of the main store.
+/// maps: BE(version) || substore prefix to BE(substore version)
+pub(crate) const CF_VERSIONS: &str = "multistore-versions";
+
+/// The number of deletions to accumulate before writing them to the database.
+const MAX_BATCH_SIZE: usize = 10_000;
+
+pub(crate) fn cf_versions(db: &Arc<DB>) -> &ColumnFamily {
+    db.cf_handle(CF_VERSIONS)
+        .expect("multistore-versions column family is created if missing")
+}
+
+pub(crate) fn version_index_key(version: jmt::Version, prefix: &str) -> Vec<u8> {
+    let mut key = version.to_be_bytes().to_vec();
+    key.extend_from_slice(prefix.as_bytes());
+    key
+}
+
+fn decode_version_index_entry(key: &[u8], value: &[u8]) -> Result<(jmt::Version, String, u64)> {
+    if key.len() < 8 {
+        bail!("version index key is too short")
+    }
+    let version = u64::from_be_bytes(key[0..8].try_into().expect("key is at least 8 bytes"));
+    let prefix = String::from_utf8(key[8..].to_vec())?;
+    let substore_version = u64::from_be_bytes(
+        value
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("invalid substore version length"))?,
+    );
+    Ok((version, prefix, substore_version))
+}
+
+/// Configures which versions of the state are retained by [`Storage::prune`].
+///
+/// Only the nodes and values made stale by versions committed with a stale
+/// index can be pruned, so older versions of a database that predates the
+/// index are never removed.
+#[derive(Clone, Debug)]
+pub struct PruningConfig {
+    /// The number of most recent versions to retain.
+    pub keep_recent: u64,
+    /// Older versions to retain, in addition to the most recent ones.
+    pub checkpoints: BTreeSet<jmt::Version>,
+    /// If set, older versions that are a multiple of this interval are also retained.
+    pub checkpoint_interval: Option<u64>,
+}
+
+impl PruningConfig {
+    /// Retains the `keep_recent` most recent versions, and no checkpoints.
+    pub fn new(keep_recent: u64) -> Self {
+        Self {
+            keep_recent,
+            checkpoints: BTreeSet::new(),
+            checkpoint_interval: None,
+        }
+    }
+
+    /// Returns whether `version` is a checkpoint that must be retained.
+    pub fn is_checkpoint(&self, version: jmt::Version) -> bool {
+        self.checkpoints.contains(&version)
+            || matches!(self.checkpoint_interval, Some(interval) if interval != 0 && version % interval == 0)
+    }
+}
+
+impl Storage {
+    /// Opens a [`Snapshot`] of a version that has not been pruned, using the
+    /// version index to recover the version of each substore.
+    ///
+    /// Returns `None` if the version is unknown, has been pruned, or was
+    /// committed before the version index existed.
+    pub(super) fn retained_snapshot(&self, version: jmt::Version) -> Result<Option<Snapshot>> {
+        let db = self.0.db.clone();
+
+        // We read the index through the snapshot, so that if the version is
+        // pruned concurrently, the snapshot still observes its nodes.
+        let rocksdb_snapshot = Arc::new(RocksDbSnapshot::new(db.clone()));
+        let mut options = ReadOptions::default();
+        options.set_iterate_range(rocksdb::PrefixRange(version.to_be_bytes().to_vec()));
+
+        let mut substore_versions = BTreeMap::new();
+        for item in rocksdb_snapshot.iterator_cf_opt(cf_versions(&db), options, IteratorMode::Start)
+        {
+            let (key, value) = item?;
+            let (_, prefix, substore_version) = decode_version_index_entry(&key, &value)?;
+            substore_versions.insert(prefix, substore_version);
+        }
+
+        let main_store = self.0.multistore_config.main_store.clone();
+        if !substore_versions.contains_key(&main_store.prefix) {
+            return Ok(None);
+        }
+
+        let mut multistore_cache = MultistoreCache::from_config(self.0.multistore_config.clone());
+        for config in self.0.multistore_config.iter() {
+            // Substores added after this version did not exist yet.
+            let substore_version = substore_versions
+                .get(&config.prefix)
+                .copied()
+                .unwrap_or(u64::MAX);
+            multistore_cache.set_version(config.clone(), substore_version);
+        }
+        multistore_cache.set_version(main_store, version);
+
+        Ok(Some(Snapshot::from_rocksdb_snapshot(
+            db,
+            rocksdb_snapshot,
+            version,
+            multistore_cache,
+        )))
+    }
+
+    /// Removes the nodes and values that are only needed by versions older
+    /// than the `keep_recent` most recent ones, except for checkpoints.
+    ///
+    /// Pruning does not affect the root hashes or proofs of retained versions,
+    /// nor existing [`Snapshot`]s, which keep observing the data they were
+    /// created with.
+    pub async fn prune(&self, config: &PruningConfig) -> Result<()> {
+        let span = Span::current();
+        let storage = self.clone();
+        let config = config.clone();
+
+        tokio::task::Builder::new()
+            .name("Storage::prune")
+            .spawn_blocking(move || span.in_scope(|| storage.prune_blocking(&config)))?
+            .await?
+    }
+
+    /// Spawns a task that prunes the storage according to `config` every time
+    /// a new version is committed, without blocking the commit itself.
+    pub fn spawn_pruner(&self, config: PruningConfig) -> tokio::task::JoinHandle<()> {
+        let storage = self.clone();
+        tokio::spawn(async move {
+            let mut rx = storage.subscribe();
+            while rx.changed().await.is_ok() {
+                let version = rx.borrow_and_update().version();
+                if let Err(e) = storage.prune(&config).await {
+                    tracing::error!(?e, ?version, "failed to prune storage");
+                }
+            }
+        })
+    }
+
+    fn prune_blocking(&self, config: &PruningConfig) -> Result<()> {
+        if config.keep_recent == 0 {
+            bail!("pruning must keep at least one recent version")
+        }
+
+        let latest_version = self.latest_version();
+        if latest_version == u64::MAX {
+            return Ok(());
+        }
+
+        // Every version older than the floor version is pruned, unless it is
+        // a checkpoint.
+        let floor = match (latest_version + 1).checked_sub(config.keep_recent) {
+            Some(floor) if floor > 0 => floor,
+            _ => return Ok(()),
+        };
+
+        let db = &self.0.db;
+        let cf_versions = cf_versions(db);
+
+        // First, remove pruned versions from the index, so that they can no
+        // longer be opened while their nodes are being deleted.
+        let mut floor_versions = BTreeMap::new();
+        let mut checkpoint_versions: BTreeMap<String, BTreeSet<u64>> = BTreeMap::new();
+        let mut batch = WriteBatch::default();
+        let mut options = ReadOptions::default();
+        options.set_iterate_upper_bound((floor + 1).to_be_bytes().to_vec());
+        for item in db.iterator_cf_opt(cf_versions, options, IteratorMode::Start) {
+            let (key, value) = item?;
+            let (version, prefix, substore_version) = decode_version_index_entry(&key, &value)?;
+            if version == floor {
+                floor_versions.insert(prefix, substore_version);
+            } else if config.is_checkpoint(version) {
+                if substore_version != u64::MAX {
+                    checkpoint_versions
+                        .entry(prefix)
+                        .or_default()
+                        .insert(substore_version);
+                }
+            } else {
+                batch.delete_cf(cf_versions, key);
+            }
+        }
+        db.write(batch)?;
+
+        // Then, prune each store up to its own version at the floor version.
+        let main_store = self.0.multistore_config.main_store.clone();
+        for store in std::iter::once(&main_store).chain(self.0.multistore_config.iter()) {
+            let Some(&floor_version) = floor_versions.get(&store.prefix) else {
+                tracing::debug!(prefix = ?store.prefix, ?floor, "no version index at floor, skipping");
+                continue;
+            };
+            if floor_version == u64::MAX {
+                continue;
+            }
+            let checkpoints = checkpoint_versions
+                .remove(&store.prefix)
+                .unwrap_or_default();
+            prune_substore(db, store, floor_version, &checkpoints)?;
+        }
+
+        tracing::debug!(?floor, ?latest_version, "pruned storage");
+        Ok(())
+    }
+}
+
+/// Deletes the nodes and values of a substore that were made stale at or
+/// before `floor_version`, unless they are needed by one of the `checkpoints`.
+fn prune_substore(
+    db: &Arc<DB>,
+    config: &SubstoreConfig,
+    floor_version: jmt::Version,
+    checkpoints: &BTreeSet<jmt::Version>,
+) -> Result<()> {
+    let cf_jmt = config.cf_jmt(db);
+    let cf_jmt_values = config.cf_jmt_values(db);
+    let cf_jmt_stale = config.cf_jmt_stale(db);
+
+    let mut options = ReadOptions::default();
+    options.set_iterate_upper_bound((floor_version + 1).to_be_bytes().to_vec());
+
+    let mut batch = WriteBatch::default();
+    let (mut pruned_nodes, mut pruned_values) = (0usize, 0usize);
+    for item in db.iterator_cf_opt(cf_jmt_stale, options, IteratorMode::Start) {
+        let (key, _) = item?;
+        let stale = StaleIndexKey::decode(&key)?;
+        match stale.entry {
+            StaleEntry::Node(node_key) => {
+                // A node is part of every version from the one that created it,
+                // up to the one that made it stale.
+                let needed = checkpoints
+                    .range(node_key.version()..stale.stale_since_version)
+                    .next()
+                    .is_some();
+                if !needed {
+                    batch.delete_cf(cf_jmt, DbNodeKey::from(node_key).encode()?);
+                    pruned_nodes += 1;
+                }
+            }
+            StaleEntry::Value(key_hash) => {
+                for versioned_key in
+                    stale_values(db, config, key_hash, stale.stale_since_version, checkpoints)?
+                {
+                    batch.delete_cf(cf_jmt_values, versioned_key.encode());
+                    pruned_values += 1;
+                }
+            }
+        }
+        batch.delete_cf(cf_jmt_stale, key);
+
+        if batch.len() >= MAX_BATCH_SIZE {
+            db.write(std::mem::take(&mut batch))?;
+        }
+    }
+    db.write(batch)?;
+
+    tracing::debug!(
+        prefix = ?config.prefix,
+        ?floor_version,
+        pruned_nodes,
+        pruned_values,
+        "pruned substore"
+    );
+    Ok(())
+}
+
+/// Returns the values of `key_hash` written before `stale_since_version`,
+/// except the newest value at each checkpoint.
+fn stale_values(
+    db: &Arc<DB>,
+    config: &SubstoreConfig,
+    key_hash: KeyHash,
+    stale_since_version: jmt::Version,
+    checkpoints: &BTreeSet<jmt::Version>,
+) -> Result<Vec<VersionedKeyHash>> {
+    let mut options = ReadOptions::default();
+    options.set_iterate_lower_bound(VersionedKeyHash::new(0, key_hash).encode());
+    options.set_iterate_upper_bound(VersionedKeyHash::new(stale_since_version, key_hash).encode());
+
+    let mut versions = Vec::new();
+    for item in db.iterator_cf_opt(config.cf_jmt_values(db), options, IteratorMode::Start) {
+        let (key, _) = item?;
+        versions.push(VersionedKeyHash::decode(key.to_vec())?.version);
+    }
+
+    // Each checkpoint reads the newest value written at or before it.
+    let kept: BTreeSet<jmt::Version> = checkpoints
+        .range(..stale_since_version)
+        .filter_map(|checkpoint| versions.iter().rev().find(|v| *v <= checkpoint).copied())
+        .collect();
+
+    Ok(versions
+        .into_iter()
+        .filter(|version| !kept.contains(version))
+        .map(|version| VersionedKeyHash::new(version, key_hash))
+        .collect())
+}
@@ -0,0 +1,451 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use anyhow::{anyhow, bail, Result};
+use borsh::BorshDeserialize;
+use jmt::{
+    proof::SparseMerkleRangeProof,
+    restore::{JellyfishMerkleRestore, StateSnapshotReceiver},
+    KeyHash, RootHash,
+};
+use rocksdb::{ColumnFamily, IteratorMode, WriteBatch, DB};
+use tokio::sync::{mpsc, oneshot};
+use tracing::Span;
+
+use crate::{
+    snapshot::{RocksDbSnapshot, Snapshot},
+    snapshot_cache::SnapshotCache,
+    store::{
+        multistore::{MultistoreCache, MultistoreConfig},
+        substore::{SubstoreConfig, SubstoreSnapshot, SubstoreStorage},
+    },
+    StateDelta,
+};
+
+use super::{prune, Storage};
+
+/// name: "restore"
+/// role: marks a database whose state is being restored from a snapshot.
+/// maps: `RESTORE_IN_PROGRESS` to BE(version being restored)
+pub(crate) const CF_RESTORE: &str = "restore";
+
+pub(crate) const RESTORE_IN_PROGRESS: &[u8] = b"in_progress";
+
+/// The number of deletions to accumulate before writing them to the database.
+const MAX_BATCH_SIZE: usize = 10_000;
+
+/// The root hash of a tree with no leaves, for which no chunks are exported.
+const EMPTY_ROOT_HASH: RootHash = RootHash(*b"SPARSE_MERKLE_PLACEHOLDER_HASH__");
+
+pub(crate) fn cf_restore(db: &Arc<DB>) -> &ColumnFamily {
+    db.cf_handle(CF_RESTORE)
+        .expect("restore column family is created if missing")
+}
+
+/// A run of consecutive leaves of the tree of a substore, in key hash order,
+/// as exported by [`Snapshot::substore_chunks`].
+#[derive(Clone, Debug)]
+pub struct SubstoreChunk {
+    /// The prefix of the substore, empty for the main store.
+    pub prefix: String,
+    /// The version of the tree of the substore the leaves were exported from,
+    /// which the substore is restored at.
+    pub version: jmt::Version,
+    /// The keys and values of the leaves, with keys relative to the substore.
+    pub leaves: Vec<(String, Vec<u8>)>,
+    /// A borsh-encoded range proof that these leaves, together with the ones
+    /// of the previous chunks of the substore, are all the leaves of its tree
+    /// up to the last one.
+    pub proof: Vec<u8>,
+}
+
+/// A restore of the chain state from a snapshot, in progress.
+///
+/// Chunks are checked against the trusted root hash as they are added, and
+/// written to the database right away, so that the state never has to be
+/// held in memory. The restored state is only made visible once it is
+/// committed, and a database whose restore was interrupted is refused by
+/// [`Storage::load`].
+pub struct Restore {
+    storage: Storage,
+    version: jmt::Version,
+    requests: Option<mpsc::Sender<Request>>,
+    worker: Option<tokio::task::JoinHandle<()>>,
+    /// The versions of the restored substores, once every chunk has been added.
+    restored: Option<MultistoreCache>,
+}
+
+impl std::fmt::Debug for Restore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Restore")
+            .field("version", &self.version)
+            .finish_non_exhaustive()
+    }
+}
+
+enum Request {
+    AddChunk(SubstoreChunk, oneshot::Sender<Result<()>>),
+    Finish(oneshot::Sender<Result<MultistoreCache>>),
+}
+
+impl Storage {
+    /// Starts restoring the chain state at `version` from a snapshot, whose
+    /// chunks must hash to the trusted `root_hash`.
+    ///
+    /// # Errors
+    /// Returns an error if the storage has already been initialized.
+    pub async fn begin_restore(
+        &self,
+        version: jmt::Version,
+        root_hash: RootHash,
+    ) -> Result<Restore> {
+        if self.latest_version() != u64::MAX {
+            bail!("can only restore state into an empty storage");
+        }
+        let db = self.0.db.clone();
+        if db.get_cf(cf_restore(&db), RESTORE_IN_PROGRESS)?.is_some() {
+            bail!("a restore is already in progress");
+        }
+        db.put_cf(cf_restore(&db), RESTORE_IN_PROGRESS, version.to_be_bytes())?;
+
+        // The JMT restore is not `Send`, so it lives on its own blocking task,
+        // which processes the chunks as they are handed over.
+        let config = self.0.multistore_config.clone();
+        let (tx, mut rx) = mpsc::channel(1);
+        let span = Span::current();
+        let handle = tokio::task::Builder::new()
+            .name("Storage::restore")
+            .spawn_blocking(move || {
+                span.in_scope(|| {
+                    let mut worker = Worker {
+                        db,
+                        config,
+                        version,
+                        root_hash,
+                        substore_roots: BTreeMap::new(),
+                        restored: Vec::new(),
+                        versions: BTreeMap::new(),
+                        current: None,
+                    };
+                    while let Some(request) = rx.blocking_recv() {
+                        match request {
+                            Request::AddChunk(chunk, reply) => {
+                                let _ = reply.send(worker.add_chunk(chunk));
+                            }
+                            Request::Finish(reply) => {
+                                let _ = reply.send(worker.finish());
+                                return;
+                            }
+                        }
+                    }
+                })
+            })?;
+
+        Ok(Restore {
+            storage: self.clone(),
+            version,
+            requests: Some(tx),
+            worker: Some(handle),
+            restored: None,
+        })
+    }
+}
+
+impl Restore {
+    /// Checks a chunk against the root hash of its substore, and writes it.
+    ///
+    /// The main store must be restored first, since it commits to the root
+    /// hashes of the substores. The chunks of each substore must be contiguous.
+    pub async fn add_chunk(&mut self, chunk: SubstoreChunk) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        self.requests
+            .as_ref()
+            .ok_or_else(|| anyhow!("all chunks have already been added"))?
+            .send(Request::AddChunk(chunk, tx))
+            .await
+            .map_err(|_| anyhow!("restore worker has stopped"))?;
+        rx.await?
+    }
+
+    /// Checks that every substore committed to by the main store was restored
+    /// in full, and returns a snapshot of the restored state, from which the
+    /// nonverifiable state can be rebuilt.
+    pub async fn finish(&mut self) -> Result<Snapshot> {
+        let requests = self
+            .requests
+            .take()
+            .ok_or_else(|| anyhow!("all chunks have already been added"))?;
+        let (tx, rx) = oneshot::channel();
+        requests
+            .send(Request::Finish(tx))
+            .await
+            .map_err(|_| anyhow!("restore worker has stopped"))?;
+        let restored = rx.await??;
+        self.restored = Some(restored.clone());
+        Ok(Snapshot::new(
+            self.storage.0.db.clone(),
+            self.version,
+            restored,
+        ))
+    }
+
+    /// Writes the nonverifiable state rebuilt on top of the restored state,
+    /// and makes the restored state the latest version of the storage.
+    ///
+    /// # Errors
+    /// Returns an error if the restore has not been finished, or if `delta`
+    /// contains verifiable changes.
+    pub async fn commit(mut self, delta: StateDelta<Snapshot>) -> Result<()> {
+        let restored = self
+            .restored
+            .take()
+            .ok_or_else(|| anyhow!("the restore has not been finished"))?;
+        let (_, changes) = delta.flatten();
+        if !changes.unwritten_changes.is_empty() {
+            bail!("only nonverifiable state can be written on top of a restored state");
+        }
+
+        let storage = self.storage.clone();
+        let db = storage.0.db.clone();
+        let mut write_batch = WriteBatch::default();
+        for (config, cache) in changes.shard_by_prefix(&storage.0.multistore_config) {
+            let cf_nonverifiable = config.cf_nonverifiable(&db);
+            for (key, value) in cache.nonverifiable_changes {
+                match value {
+                    Some(value) => write_batch.put_cf(cf_nonverifiable, key, value),
+                    None => write_batch.delete_cf(cf_nonverifiable, key),
+                }
+            }
+        }
+
+        let cf_versions = prune::cf_versions(&db);
+        for (config, substore_version) in restored.substores.iter() {
+            write_batch.put_cf(
+                cf_versions,
+                prune::version_index_key(self.version, &config.prefix),
+                substore_version.to_be_bytes(),
+            );
+        }
+        write_batch.delete_cf(cf_restore(&db), RESTORE_IN_PROGRESS);
+        db.write(write_batch)?;
+
+        let latest_snapshot = Snapshot::new(db, self.version, restored);
+        *storage.0.snapshots.write() = SnapshotCache::new(latest_snapshot.clone(), 10);
+        let _ = storage.0.tx_dispatcher.send(latest_snapshot);
+        Ok(())
+    }
+
+    /// Deletes everything written so far, leaving the storage empty, so that
+    /// another snapshot can be restored into it.
+    pub async fn abort(mut self) -> Result<()> {
+        // Stop the worker before deleting what it wrote.
+        self.requests.take();
+        if let Some(worker) = self.worker.take() {
+            worker.await?;
+        }
+
+        let storage = self.storage.clone();
+        let span = Span::current();
+        tokio::task::Builder::new()
+            .name("Restore::abort")
+            .spawn_blocking(move || span.in_scope(|| storage.clear_restored_state()))?
+            .await?
+    }
+}
+
+impl Storage {
+    /// Deletes every entry of the substores and of the version index, and
+    /// the restore marker last.
+    fn clear_restored_state(&self) -> Result<()> {
+        let db = &self.0.db;
+        let config = &self.0.multistore_config;
+        let columns = std::iter::once(&config.main_store)
+            .chain(config.iter())
+            .flat_map(|config| config.columns())
+            .map(String::as_str)
+            .chain(std::iter::once(prune::CF_VERSIONS));
+
+        for column in columns {
+            let cf = db
+                .cf_handle(column)
+                .ok_or_else(|| anyhow!("missing column family {column}"))?;
+            let mut batch = WriteBatch::default();
+            for item in db.iterator_cf(cf, IteratorMode::Start) {
+                let (key, _) = item?;
+                batch.delete_cf(cf, key);
+                if batch.len() >= MAX_BATCH_SIZE {
+                    db.write(std::mem::take(&mut batch))?;
+                }
+            }
+            db.write(batch)?;
+        }
+
+        db.delete_cf(cf_restore(db), RESTORE_IN_PROGRESS)?;
+        Ok(())
+    }
+}
+
+/// The state of a restore, owned by its blocking task.
+struct Worker {
+    db: Arc<DB>,
+    config: MultistoreConfig,
+    version: jmt::Version,
+    root_hash: RootHash,
+    /// The root hash of each substore, as committed to by the main store.
+    substore_roots: BTreeMap<String, RootHash>,
+    /// The prefixes of the substores restored so far, in order.
+    restored: Vec<String>,
+    /// The version each restored substore is restored at.
+    versions: BTreeMap<String, jmt::Version>,
+    /// The restore of the current substore, and the root hash it must have.
+    current: Option<(JellyfishMerkleRestore<sha2::Sha256>, RootHash)>,
+}
+
+impl Worker {
+    fn add_chunk(&mut self, chunk: SubstoreChunk) -> Result<()> {
+        let config = self.substore(&chunk.prefix)?;
+        if self.restored.last() != Some(&chunk.prefix) {
+            self.finish_current()?;
+            if self.restored.contains(&chunk.prefix) {
+                bail!("chunks of substore {:?} are not contiguous", chunk.prefix);
+            }
+            let expected_root = if chunk.prefix.is_empty() {
+                if !self.restored.is_empty() {
+                    bail!("the main store must be restored first");
+                }
+                if chunk.version != self.version {
+                    bail!(
+                        "the main store must be restored at version {}",
+                        self.version
+                    );
+                }
+                self.root_hash
+            } else {
+                *self.substore_roots.get(&chunk.prefix).ok_or_else(|| {
+                    anyhow!("the main store has no root for substore {:?}", chunk.prefix)
+                })?
+            };
+            // Substores are only committed when they change, so they lag behind
+            // the main store, but never get ahead of it.
+            if chunk.version > self.version {
+                bail!(
+                    "substore {:?} is at version {}, past the restored version {}",
+                    chunk.prefix,
+                    chunk.version,
+                    self.version
+                );
+            }
+            self.versions.insert(chunk.prefix.clone(), chunk.version);
+            let restore = JellyfishMerkleRestore::new_overwrite(
+                Arc::new(self.substore_storage(&config)),
+                chunk.version,
+                expected_root,
+                true,
+            )?;
+            self.current = Some((restore, expected_root));
+            self.restored.push(chunk.prefix.clone());
+        } else if self.versions.get(&chunk.prefix) != Some(&chunk.version) {
+            bail!(
+                "chunks of substore {:?} are not all at the same version",
+                chunk.prefix
+            );
+        }
+
+        let leaves = chunk
+            .leaves
+            .iter()
+            .map(|(key, value)| (KeyHash::with::<sha2::Sha256>(key.as_bytes()), value.clone()))
+            .collect();
+        let proof = SparseMerkleRangeProof::<sha2::Sha256>::try_from_slice(&chunk.proof)?;
+        let (restore, _) = self.current.as_mut().expect("a substore is being restored");
+        restore.add_chunk(leaves, proof)?;
+
+        // The leaves are part of the tree, so we can index their preimages.
+        let cf_jmt_keys = config.cf_jmt_keys(&self.db);
+        let cf_jmt_keys_by_keyhash = config.cf_jmt_keys_by_keyhash(&self.db);
+        let mut write_batch = WriteBatch::default();
+        for (key, value) in chunk.leaves {
+            let key_hash = KeyHash::with::<sha2::Sha256>(key.as_bytes());
+            write_batch.put_cf(cf_jmt_keys, &key, key_hash.0);
+            write_batch.put_cf(cf_jmt_keys_by_keyhash, key_hash.0, &key);
+
+            if chunk.prefix.is_empty() && self.config.iter().any(|c| c.prefix == key) {
+                let root_hash = value
+                    .try_into()
+                    .map_err(|_| anyhow!("invalid root hash for substore {key:?}"))?;
+                self.substore_roots.insert(key, RootHash(root_hash));
+            }
+        }
+        self.db.write(write_batch)?;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<MultistoreCache> {
+        self.finish_current()?;
+        if self.restored.first().map(String::as_str) != Some("") {
+            bail!("the main store was not restored");
+        }
+        for (prefix, root_hash) in self.substore_roots.iter() {
+            if !self.restored.contains(prefix) && *root_hash != EMPTY_ROOT_HASH {
+                bail!("substore {prefix:?} was not restored");
+            }
+        }
+
+        let mut versions = MultistoreCache::from_config(self.config.clone());
+        for config in self.config.iter() {
+            // A substore with an empty tree has no chunks, and starts over
+            // from the pre-genesis version, like on a fresh node.
+            versions.set_version(config.clone(), self.substore_version(config));
+        }
+        versions.set_version(self.config.main_store.clone(), self.version);
+        Ok(versions)
+    }
+
+    /// Writes the remaining nodes of the substore being restored, and checks
+    /// that the tree is complete.
+    fn finish_current(&mut self) -> Result<()> {
+        let Some((restore, expected_root)) = self.current.take() else {
+            return Ok(());
+        };
+        restore.finish()?;
+
+        let prefix = self.restored.last().expect("a substore was restored");
+        let config = self.substore(prefix)?;
+        let root_hash = self
+            .substore_storage(&config)
+            .substore_snapshot
+            .root_hash()?;
+        if root_hash != expected_root {
+            bail!("substore {prefix:?} is missing leaves");
+        }
+        Ok(())
+    }
+
+    fn substore(&self, prefix: &str) -> Result<Arc<SubstoreConfig>> {
+        if prefix.is_empty() {
+            return Ok(self.config.main_store.clone());
+        }
+        self.config
+            .iter()
+            .find(|config| config.prefix == prefix)
+            .cloned()
+            .ok_or_else(|| anyhow!("unknown substore {prefix:?}"))
+    }
+
+    fn substore_version(&self, config: &Arc<SubstoreConfig>) -> jmt::Version {
+        self.versions
+            .get(&config.prefix)
+            .copied()
+            .unwrap_or(u64::MAX)
+    }
+
+    fn substore_storage(&self, config: &Arc<SubstoreConfig>) -> SubstoreStorage {
+        SubstoreStorage {
+            substore_snapshot: SubstoreSnapshot {
+                config: config.clone(),
+                rocksdb_snapshot: Arc::new(RocksDbSnapshot::new(self.db.clone())),
+                version: self.substore_version(config),
+                db: self.db.clone(),
+            },
+        }
+    }
+}
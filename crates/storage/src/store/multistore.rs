@@ -182,7 +182,7 @@ impl Default for MultistoreConfig {
 }
 
 /// Tracks the latest version of each substore, and wraps a `MultistoreConfig`.
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct MultistoreCache {
     pub config: MultistoreConfig,
     pub substores: std::collections::BTreeMap<Arc<SubstoreConfig>, jmt::Version>,
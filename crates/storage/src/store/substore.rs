@@ -47,6 +47,11 @@ pub struct SubstoreConfig {
     /// part of consensus.
     /// maps: arbitrary keys to arbitrary values.
     cf_nonverifiable: String,
+    /// name: "substore-{prefix}-jmt-stale"
+    /// role: index of the JMT nodes and values that each version made stale,
+    /// used to prune versions that are no longer needed.
+    /// maps: `StaleIndexKey` to an empty value.
+    cf_jmt_stale: String,
 }
 
 impl SubstoreConfig {
@@ -58,6 +63,7 @@ impl SubstoreConfig {
             cf_jmt_values: format!("substore-{}-jmt-values", prefix),
             cf_jmt_keys_by_keyhash: format!("substore-{}-jmt-keys-by-keyhash", prefix),
             cf_nonverifiable: format!("substore-{}-nonverifiable", prefix),
+            cf_jmt_stale: format!("substore-{}-jmt-stale", prefix),
             prefix_with_delimiter: format!("{}/", prefix),
             prefix,
         }
@@ -71,6 +77,7 @@ impl SubstoreConfig {
             .chain(std::iter::once(&self.cf_jmt_values))
             .chain(std::iter::once(&self.cf_jmt_keys_by_keyhash))
             .chain(std::iter::once(&self.cf_nonverifiable))
+            .chain(std::iter::once(&self.cf_jmt_stale))
     }
 
     pub fn cf_jmt<'s>(&self, db_handle: &'s Arc<rocksdb::DB>) -> &'s ColumnFamily {
@@ -113,6 +120,14 @@ impl SubstoreConfig {
         ))
     }
 
+    pub fn cf_jmt_stale<'s>(&self, db_handle: &'s Arc<rocksdb::DB>) -> &'s ColumnFamily {
+        let column = self.cf_jmt_stale.as_str();
+        db_handle.cf_handle(column).expect(&format!(
+            "jmt-stale column family not found for prefix: {}, substore: {}",
+            column, self.prefix
+        ))
+    }
+
     pub fn latest_version_from_db(
         &self,
         db_handle: &Arc<rocksdb::DB>,
@@ -394,6 +409,8 @@ impl SubstoreStorage {
                             };
                         }
 
+                        let changed_keyhashes: Vec<KeyHash> = unwritten_changes.iter().map(|(keyhash, _, _)| *keyhash).collect();
+
                         let (root_hash, batch) = jmt.put_value_set(
                             unwritten_changes.into_iter().map(|(keyhash, _key, some_value)| (keyhash, some_value)),
                             new_version,
@@ -402,6 +419,29 @@ impl SubstoreStorage {
                         self.write_node_batch(&batch.node_batch)?;
                         tracing::trace!(?root_hash, "wrote node batch to backing store");
 
+                        /* Stale index, used for pruning */
+                        let cf_jmt_stale = self.substore_snapshot.config.cf_jmt_stale(&self.substore_snapshot.db);
+                        for stale_node in batch.stale_node_index_batch.iter() {
+                            // A migration rewrites nodes in place, so the nodes it makes stale
+                            // can share their key with the nodes that replace them.
+                            if stale_node.node_key.version() >= stale_node.stale_since_version {
+                                continue;
+                            }
+                            let key = StaleIndexKey {
+                                stale_since_version: stale_node.stale_since_version,
+                                entry: StaleEntry::Node(stale_node.node_key.clone()),
+                            };
+                            write_batch.put_cf(cf_jmt_stale, key.encode()?, b"");
+                        }
+                        for keyhash in changed_keyhashes {
+                            // Every older value of a key written in this version is stale.
+                            let key = StaleIndexKey {
+                                stale_since_version: new_version,
+                                entry: StaleEntry::Value(keyhash),
+                            };
+                            write_batch.put_cf(cf_jmt_stale, key.encode()?, b"");
+                        }
+
                         for (k, v) in cache.nonverifiable_changes.into_iter() {
                             let cf_nonverifiable = self.substore_snapshot.config.cf_nonverifiable(&self.substore_snapshot.db);
                             match v {
@@ -514,7 +554,6 @@ impl VersionedKeyHash {
         buf
     }
 
-    #[allow(dead_code)]
     pub fn decode(buf: Vec<u8>) -> Result<Self> {
         if buf.len() != 40 {
             Err(anyhow::anyhow!(
@@ -535,3 +574,67 @@ impl VersionedKeyHash {
         }
     }
 }
+
+/// A JMT node or value recorded in the stale index.
+#[derive(Clone, Debug)]
+pub enum StaleEntry {
+    /// A node that is no longer part of the tree.
+    Node(NodeKey),
+    /// A key whose values written before the stale version are no longer current.
+    Value(KeyHash),
+}
+
+/// A key of the stale index, ordered by the version that made the entry stale.
+///
+/// Encoded as `BE(stale_since_version) || 0x00 || DbNodeKey` for nodes, and
+/// `BE(stale_since_version) || 0x01 || KeyHash` for values.
+#[derive(Clone, Debug)]
+pub struct StaleIndexKey {
+    pub stale_since_version: jmt::Version,
+    pub entry: StaleEntry,
+}
+
+impl StaleIndexKey {
+    const NODE: u8 = 0;
+    const VALUE: u8 = 1;
+
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut buf = self.stale_since_version.to_be_bytes().to_vec();
+        match &self.entry {
+            StaleEntry::Node(node_key) => {
+                buf.push(Self::NODE);
+                buf.extend(DbNodeKey::from(node_key.clone()).encode()?);
+            }
+            StaleEntry::Value(key_hash) => {
+                buf.push(Self::VALUE);
+                buf.extend_from_slice(&key_hash.0);
+            }
+        }
+        Ok(buf)
+    }
+
+    pub fn decode(bytes: impl AsRef<[u8]>) -> Result<Self> {
+        let bytes = bytes.as_ref();
+        if bytes.len() < 9 {
+            anyhow::bail!("byte slice is too short")
+        }
+        let stale_since_version = u64::from_be_bytes(
+            bytes[0..8]
+                .try_into()
+                .expect("buffer is at least 9 bytes wide"),
+        );
+        let entry = match bytes[8] {
+            Self::NODE => StaleEntry::Node(DbNodeKey::decode(&bytes[9..])?.into_inner()),
+            Self::VALUE => StaleEntry::Value(KeyHash(
+                bytes[9..]
+                    .try_into()
+                    .map_err(|_| anyhow::anyhow!("invalid key hash length"))?,
+            )),
+            kind => anyhow::bail!("unknown stale entry kind {kind}"),
+        };
+        Ok(Self {
+            stale_since_version,
+            entry,
+        })
+    }
+}
@@ -1,5 +1,6 @@
 #![cfg(feature = "migration")]
 use jmt::RootHash;
+use penumbra_storage::ExpectedRoots;
 use penumbra_storage::StateDelta;
 use penumbra_storage::StateRead;
 use penumbra_storage::StateWrite;
@@ -223,3 +224,94 @@ async fn test_substore_migration() -> anyhow::Result<()> {
         });
     Ok(())
 }
+
+/// Commits a few versions to a fresh storage, writing to the `dex` substore only
+/// once, so that it lags behind the main store and the `ibc` substore.
+async fn storage_with_lagging_substore(path: std::path::PathBuf) -> anyhow::Result<Storage> {
+    let storage = Storage::load(path, vec!["ibc".to_string(), "dex".to_string()]).await?;
+    for i in 0..5 {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw(format!("key_{i}"), b"value".to_vec());
+        delta.put_raw(format!("ibc/key_{i}"), b"value".to_vec());
+        if i == 0 {
+            delta.put_raw("dex/key".to_string(), b"value".to_vec());
+        }
+        let _ = storage.commit(delta).await?;
+    }
+    Ok(storage)
+}
+
+/// Writes the migration to a delta on top of `storage`.
+fn migration_delta(storage: &Storage) -> StateDelta<penumbra_storage::Snapshot> {
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    delta.put_raw("migration".to_string(), b"migration data".to_vec());
+    delta.put_raw("ibc/migration".to_string(), b"migration data".to_vec());
+    delta
+}
+
+#[tokio::test]
+/// Test that a migration checked against an expected root hash keeps the version index
+/// consistent, so that the migrated version can be opened once it leaves the snapshot cache.
+async fn test_version_index_after_checked_migration() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+
+    // The expected root hash is computed independently, by migrating another storage.
+    let reference_dir = tempfile::tempdir()?;
+    let reference = storage_with_lagging_substore(reference_dir.path().join("db")).await?;
+    let expected_root = reference
+        .commit_in_place(migration_delta(&reference))
+        .await?;
+
+    let tmpdir = tempfile::tempdir()?;
+    let db_path = tmpdir.path().join("db");
+    let storage = storage_with_lagging_substore(db_path.clone()).await?;
+    let version = storage.latest_version();
+    let premigration = storage.latest_snapshot();
+    let ibc_version = premigration.prefix_version("ibc")?;
+    let dex_version = premigration.prefix_version("dex")?;
+    assert_ne!(ibc_version, dex_version);
+
+    // A mismatched migration writes nothing, and the checked one goes through.
+    let wrong_roots = ExpectedRoots {
+        root_hash: RootHash([1; 32]),
+        substore_root_hashes: vec![],
+    };
+    assert!(storage
+        .commit_in_place_expecting(migration_delta(&storage), &wrong_roots)
+        .await
+        .is_err());
+    let expected_roots = ExpectedRoots {
+        root_hash: expected_root,
+        substore_root_hashes: vec![],
+    };
+    let migrated_root = storage
+        .commit_in_place_expecting(migration_delta(&storage), &expected_roots)
+        .await?;
+    assert_eq!(migrated_root, expected_root);
+
+    std::mem::drop(premigration);
+    storage.release().await;
+    let storage = Storage::load(db_path, vec!["ibc".to_string(), "dex".to_string()]).await?;
+
+    // Push the migrated version out of the snapshot cache, so that it has to be
+    // opened through the version index.
+    for i in 0..12 {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw(format!("after_migration_{i}"), b"value".to_vec());
+        let _ = storage.commit(delta).await?;
+    }
+
+    let migrated = storage
+        .snapshot(version)
+        .expect("the migrated version is retained");
+    assert_eq!(migrated.version(), version);
+    assert_eq!(migrated.root_hash().await?, expected_root);
+    assert_eq!(migrated.prefix_version("ibc")?, ibc_version);
+    assert_eq!(migrated.prefix_version("dex")?, dex_version);
+    assert_eq!(
+        migrated.get_raw("ibc/migration").await?,
+        Some(b"migration data".to_vec())
+    );
+
+    Ok(())
+}
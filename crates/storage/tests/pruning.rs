@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+
+use penumbra_storage::PruningConfig;
+use penumbra_storage::StateDelta;
+use penumbra_storage::StateRead;
+use penumbra_storage::StateWrite;
+use penumbra_storage::Storage;
+use tempfile;
+use tokio;
+
+/*
+ * Pruning tests.
+ *
+ * Pruning removes the nodes and values that are only needed by old versions
+ * of the state. The versions that are retained, either because they are
+ * recent or because they are checkpoints, must keep their root hashes and
+ * produce the same proofs as before pruning.
+ */
+
+#[tokio::test]
+/// Test that pruning retains the most recent versions and the checkpoints, with
+/// unchanged root hashes and proofs, and that pruned versions can't be opened.
+async fn test_prune_keeps_retained_versions() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let tmpdir = tempfile::tempdir()?;
+    let db_path = tmpdir.path().join("storage");
+    let substore_prefixes = vec!["ibc".to_string(), "dex".to_string()];
+    let storage = Storage::load(db_path.clone(), substore_prefixes.clone()).await?;
+
+    // Every version overwrites the same keys, so that each one makes the
+    // nodes and values of the previous one stale. The `dex` substore is only
+    // written to every third version, so its versions lag behind.
+    let keys = ["ibc/key", "main_key", "dex/key"];
+    let num_writes = 20;
+    let mut expected = BTreeMap::new();
+    for i in 0..num_writes {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw("ibc/key".to_string(), format!("ibc_{i}").into_bytes());
+        delta.put_raw(format!("ibc/key_{i}"), b"extra".to_vec());
+        delta.put_raw("main_key".to_string(), format!("main_{i}").into_bytes());
+        if i % 3 == 0 {
+            delta.put_raw("dex/key".to_string(), format!("dex_{i}").into_bytes());
+        }
+        let _ = storage.commit(delta).await?;
+
+        let snapshot = storage.latest_snapshot();
+        let mut proofs = Vec::new();
+        for key in keys {
+            proofs.push(snapshot.get_with_proof(key.as_bytes().to_vec()).await?);
+        }
+        expected.insert(i, (snapshot.root_hash().await?, proofs));
+    }
+
+    let mut config = PruningConfig::new(5);
+    config.checkpoints.insert(7);
+    storage.prune(&config).await?;
+
+    // Reload the storage, so that every version is read back from disk,
+    // rather than from snapshots taken before pruning.
+    storage.release().await;
+    let storage = Storage::load(db_path.clone(), substore_prefixes.clone()).await?;
+
+    for version in [7, 15, 16, 17, 18, 19] {
+        let snapshot = storage
+            .snapshot(version)
+            .unwrap_or_else(|| panic!("version {version} should be retained"));
+        let (expected_root, expected_proofs) = &expected[&version];
+        assert_eq!(
+            &snapshot.root_hash().await?,
+            expected_root,
+            "root hash mismatch at version {version}"
+        );
+        for (key, (expected_value, expected_proof)) in keys.iter().zip(expected_proofs) {
+            let (value, proof) = snapshot.get_with_proof(key.as_bytes().to_vec()).await?;
+            assert_eq!(
+                &value, expected_value,
+                "value mismatch for {key} at {version}"
+            );
+            assert_eq!(
+                proof.proofs, expected_proof.proofs,
+                "proof mismatch for {key} at {version}"
+            );
+        }
+    }
+
+    for version in [0, 3, 6, 8, 14] {
+        assert!(
+            storage.snapshot(version).is_none(),
+            "version {version} should have been pruned"
+        );
+    }
+
+    // We can keep committing and pruning on top of the pruned storage.
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    delta.put_raw("ibc/key".to_string(), b"after_pruning".to_vec());
+    let _ = storage.commit(delta).await?;
+    storage.prune(&config).await?;
+
+    let snapshot = storage.latest_snapshot();
+    assert_eq!(snapshot.version(), num_writes);
+    assert_eq!(
+        snapshot.get_raw("ibc/key").await?,
+        Some(b"after_pruning".to_vec())
+    );
+    assert_eq!(snapshot.get_raw("dex/key").await?, Some(b"dex_18".to_vec()));
+    assert!(storage.snapshot(15).is_none());
+    assert!(storage.snapshot(7).is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that pruning must keep at least one version.
+async fn test_prune_requires_recent_versions() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let tmpdir = tempfile::tempdir()?;
+    let storage = Storage::load(tmpdir.path().join("storage"), vec![]).await?;
+
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    delta.put_raw("key".to_string(), b"value".to_vec());
+    let _ = storage.commit(delta).await?;
+
+    assert!(storage.prune(&PruningConfig::new(0)).await.is_err());
+    storage.prune(&PruningConfig::new(1)).await?;
+    assert_eq!(
+        storage.latest_snapshot().get_raw("key").await?,
+        Some(b"value".to_vec())
+    );
+
+    Ok(())
+}
@@ -0,0 +1,216 @@
+use penumbra_storage::Snapshot;
+use penumbra_storage::StateDelta;
+use penumbra_storage::StateRead;
+use penumbra_storage::StateWrite;
+use penumbra_storage::Storage;
+use penumbra_storage::SubstoreChunk;
+use tempfile;
+use tokio;
+use tokio_stream::StreamExt;
+
+/*
+ * Restore tests.
+ *
+ * Nodes joining the network through state sync receive a copy of the
+ * chain state at some height, and hydrate an empty storage with it. The
+ * restored state must be committed at the version it was exported from,
+ * must produce the same root hashes as the original state, and every chunk
+ * must be checked against the trusted root hash before it is written.
+ */
+
+#[tokio::test]
+/// Test that restoring the chunks of a storage at a given version into an empty
+/// storage yields the same root hashes, and that we can keep committing on top of it.
+async fn test_restore_at_version() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let substore_prefixes = vec!["ibc".to_string(), "dex".to_string()];
+    let all_substores = vec!["".to_string(), "ibc".to_string(), "dex".to_string()];
+
+    let source_dir = tempfile::tempdir()?;
+    let source = Storage::load(source_dir.path().join("source"), substore_prefixes.clone()).await?;
+
+    let num_writes = 10;
+    for i in 0..num_writes {
+        let mut delta = StateDelta::new(source.latest_snapshot());
+        for substore in all_substores.iter() {
+            let key = format!("{substore}/key_{i}");
+            let value = format!("{substore}value_{i}").as_bytes().to_vec();
+            delta.put_raw(key.clone(), value);
+            delta.nonverifiable_put_raw(key.into_bytes(), b"nonverifiable".to_vec());
+        }
+        let _ = source.commit(delta).await?;
+    }
+    // Substores are only committed when they change, so the substores lag
+    // behind the main store after a few commits that only touch the latter.
+    let num_main_writes = 3;
+    for i in 0..num_main_writes {
+        let mut delta = StateDelta::new(source.latest_snapshot());
+        delta.put_raw(format!("main_only_{i}"), b"value".to_vec());
+        let _ = source.commit(delta).await?;
+    }
+
+    let exported = source.latest_snapshot();
+    assert_eq!(exported.version(), num_writes + num_main_writes - 1);
+    assert_eq!(exported.prefix_version("ibc")?, Some(num_writes - 1));
+    let root_hash = exported.root_hash().await?;
+
+    // Small chunks, so that every substore is split across several of them.
+    let chunks = export_chunks(&exported, &all_substores, 64).await?;
+    assert!(chunks.len() > all_substores.len());
+
+    let target_dir = tempfile::tempdir()?;
+    let target = Storage::load(target_dir.path().join("target"), substore_prefixes.clone()).await?;
+
+    let mut restore = target.begin_restore(exported.version(), root_hash).await?;
+    for chunk in chunks {
+        restore.add_chunk(chunk).await?;
+    }
+    let restored = restore.finish().await?;
+    // Nothing is visible until the restore is committed.
+    assert_eq!(target.latest_version(), u64::MAX);
+    assert_eq!(
+        restored.get_raw("ibc/key_3").await?,
+        Some(b"ibcvalue_3".to_vec())
+    );
+
+    // The nonverifiable state is not part of the snapshot, and has to be rebuilt.
+    assert_eq!(restored.nonverifiable_get_raw(b"dex/key_3").await?, None);
+    let mut delta = StateDelta::new(restored);
+    delta.nonverifiable_put_raw(b"dex/key_3".to_vec(), b"rebuilt".to_vec());
+    restore.commit(delta).await?;
+
+    let restored = target.latest_snapshot();
+    assert_eq!(restored.version(), exported.version());
+    // Substores are restored at the versions they were exported from.
+    for substore in substore_prefixes.iter() {
+        assert_eq!(
+            restored.prefix_version(substore)?,
+            exported.prefix_version(substore)?,
+            "version mismatch for substore {substore}"
+        );
+    }
+    for substore in all_substores.iter() {
+        assert_eq!(
+            restored.prefix_root_hash(substore).await?,
+            exported.prefix_root_hash(substore).await?,
+            "root hash mismatch for substore {substore}"
+        );
+    }
+    let value = restored.get_raw("dex/key_7").await?;
+    assert_eq!(value, Some(b"dexvalue_7".to_vec()));
+    let value = restored.nonverifiable_get_raw(b"dex/key_3").await?;
+    assert_eq!(value, Some(b"rebuilt".to_vec()));
+
+    // Range queries go through the restored key preimages.
+    let keys: Vec<String> = restored
+        .prefix_keys("ibc/")
+        .collect::<Result<Vec<_>, _>>()
+        .await?;
+    assert_eq!(keys.len(), num_writes as usize);
+
+    // Restoring a second time must fail, since the storage is no longer empty.
+    assert!(target
+        .begin_restore(exported.version(), root_hash)
+        .await
+        .is_err());
+
+    /* ************************************************ */
+    /* both storages must agree on the following commit */
+    /* ************************************************ */
+    for storage in [&source, &target] {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw("ibc/after_restore".to_string(), b"value".to_vec());
+        delta.put_raw("after_restore".to_string(), b"value".to_vec());
+        let _ = storage.commit(delta).await?;
+    }
+
+    assert_eq!(target.latest_version(), num_writes + num_main_writes);
+    assert_eq!(
+        target.latest_snapshot().root_hash().await?,
+        source.latest_snapshot().root_hash().await?
+    );
+    assert_eq!(
+        target.latest_snapshot().prefix_version("ibc")?,
+        source.latest_snapshot().prefix_version("ibc")?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that tampered, missing and misordered chunks are rejected, and that an
+/// aborted restore leaves the storage empty for the next attempt.
+async fn test_restore_rejects_invalid_chunks() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let substore_prefixes = vec!["ibc".to_string()];
+    let all_substores = vec!["".to_string(), "ibc".to_string()];
+
+    let source_dir = tempfile::tempdir()?;
+    let source = Storage::load(source_dir.path().join("source"), substore_prefixes.clone()).await?;
+    for i in 0..5 {
+        let mut delta = StateDelta::new(source.latest_snapshot());
+        delta.put_raw(format!("key_{i}"), b"value".to_vec());
+        delta.put_raw(format!("ibc/key_{i}"), b"value".to_vec());
+        let _ = source.commit(delta).await?;
+    }
+    let exported = source.latest_snapshot();
+    let version = exported.version();
+    let root_hash = exported.root_hash().await?;
+    let chunks = export_chunks(&exported, &all_substores, 32).await?;
+
+    let target_dir = tempfile::tempdir()?;
+    let target = Storage::load(target_dir.path().join("target"), substore_prefixes.clone()).await?;
+
+    // A tampered value does not match the proof, and is not written.
+    let mut restore = target.begin_restore(version, root_hash).await?;
+    let mut tampered = chunks[0].clone();
+    tampered.leaves[0].1 = b"forged".to_vec();
+    assert!(restore.add_chunk(tampered).await.is_err());
+    restore.abort().await?;
+
+    // A substore cannot be restored before the main store commits to its root.
+    let mut restore = target.begin_restore(version, root_hash).await?;
+    let substore_chunk = chunks
+        .iter()
+        .find(|chunk| chunk.prefix == "ibc")
+        .expect("the substore has chunks")
+        .clone();
+    assert!(restore.add_chunk(substore_chunk).await.is_err());
+    restore.abort().await?;
+
+    // A snapshot that is missing its last chunk is incomplete.
+    let mut restore = target.begin_restore(version, root_hash).await?;
+    for chunk in chunks[..chunks.len() - 1].iter() {
+        restore.add_chunk(chunk.clone()).await?;
+    }
+    assert!(restore.finish().await.is_err());
+    restore.abort().await?;
+    assert_eq!(target.latest_version(), u64::MAX);
+
+    // After all these attempts, the storage is still empty and can be restored.
+    let mut restore = target.begin_restore(version, root_hash).await?;
+    for chunk in chunks {
+        restore.add_chunk(chunk).await?;
+    }
+    let restored = restore.finish().await?;
+    restore.commit(StateDelta::new(restored)).await?;
+    assert_eq!(target.latest_snapshot().root_hash().await?, root_hash);
+
+    Ok(())
+}
+
+/// Exports the chunks of every substore of `exported`, main store first.
+async fn export_chunks(
+    exported: &Snapshot,
+    all_substores: &[String],
+    chunk_size: usize,
+) -> anyhow::Result<Vec<SubstoreChunk>> {
+    let mut chunks = Vec::new();
+    for substore in all_substores.iter() {
+        let mut stream = exported.substore_chunks(substore, chunk_size);
+        while let Some(chunk) = stream.next().await {
+            chunks.push(chunk?);
+        }
+    }
+    Ok(chunks)
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "rpc")]
+
+use futures::TryStreamExt;
+use penumbra_storage::rpc::proto::v1alpha1::{
+    query_service_server::QueryService, KeyValueRequest, PrefixValueRequest,
+};
+use penumbra_storage::rpc::Server;
+use penumbra_storage::StateDelta;
+use penumbra_storage::StateWrite;
+use penumbra_storage::Storage;
+use tempfile;
+use tokio;
+
+/*
+ * Query service tests.
+ *
+ * The query service reads from a snapshot at an optional height, and pages
+ * through prefix queries using the last key of the previous page as a cursor.
+ */
+
+/// Collects one page of a prefix query into its key-value pairs.
+async fn prefix_page(
+    server: &Server,
+    prefix: &str,
+    start_after: &str,
+    limit: u32,
+    height: Option<u64>,
+) -> anyhow::Result<Vec<(String, Vec<u8>)>> {
+    let stream = server
+        .prefix_value(tonic::Request::new(PrefixValueRequest {
+            prefix: prefix.to_string(),
+            start_after: start_after.to_string(),
+            limit,
+            height,
+            ..Default::default()
+        }))
+        .await?
+        .into_inner();
+    Ok(stream
+        .map_ok(|rsp| (rsp.key, rsp.value))
+        .try_collect()
+        .await?)
+}
+
+#[tokio::test]
+/// Test that paging through a prefix query returns every key exactly once and
+/// in order, in both the main store and a substore.
+async fn test_prefix_value_paging() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let tmpdir = tempfile::tempdir()?;
+    let db_path = tmpdir.path().join("storage");
+    let storage = Storage::load(db_path, vec!["ibc".to_string()]).await?;
+
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    for i in 0..10 {
+        delta.put_raw(format!("main/key_{i}"), format!("main_{i}").into_bytes());
+        delta.put_raw(format!("ibc/key_{i}"), format!("ibc_{i}").into_bytes());
+    }
+    // A key that sorts right after the prefix range must not leak into it.
+    delta.put_raw("main0".to_string(), b"outside".to_vec());
+    storage.commit(delta).await?;
+
+    let server = Server::new(storage.clone());
+    for prefix in ["main/", "ibc/"] {
+        let all = prefix_page(&server, prefix, "", 0, None).await?;
+        assert_eq!(all.len(), 10, "prefix {prefix} should have ten keys");
+
+        let mut paged = vec![];
+        let mut start_after = String::new();
+        loop {
+            let page = prefix_page(&server, prefix, &start_after, 3, None).await?;
+            assert!(page.len() <= 3, "pages should respect the limit");
+            let Some((last_key, _)) = page.last() else {
+                break;
+            };
+            start_after = last_key.clone();
+            paged.extend(page);
+        }
+        assert_eq!(paged, all, "paging should return the full prefix in order");
+    }
+
+    // A cursor that no longer exists still resumes after its position.
+    let page = prefix_page(&server, "main/", "main/key_4a", 2, None).await?;
+    let keys: Vec<_> = page.into_iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec!["main/key_5", "main/key_6"]);
+
+    // A cursor before the prefix starts from the beginning of the prefix.
+    let page = prefix_page(&server, "main/", "a", 1, None).await?;
+    assert_eq!(page[0].0, "main/key_0");
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that queries at a historical height read the state at that height, and
+/// that the latest height is used only when no height is given.
+async fn test_queries_at_historical_height() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let tmpdir = tempfile::tempdir()?;
+    let db_path = tmpdir.path().join("storage");
+    let storage = Storage::load(db_path, vec![]).await?;
+
+    for i in 0..3u64 {
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw("key".to_string(), format!("value_{i}").into_bytes());
+        delta.put_raw(format!("prefix/key_{i}"), format!("value_{i}").into_bytes());
+        storage.commit(delta).await?;
+    }
+    let latest = storage.latest_snapshot().version();
+    assert_eq!(latest, 2);
+
+    let server = Server::new(storage.clone());
+    let key_value = |height: Option<u64>| {
+        server.key_value(tonic::Request::new(KeyValueRequest {
+            key: "key".to_string(),
+            height,
+            ..Default::default()
+        }))
+    };
+
+    for height in 0..=latest {
+        let rsp = key_value(Some(height)).await?.into_inner();
+        let value = rsp.value.expect("key is set at every height").value;
+        assert_eq!(value, format!("value_{height}").into_bytes());
+
+        let page = prefix_page(&server, "prefix/", "", 0, Some(height)).await?;
+        assert_eq!(page.len() as u64, height + 1);
+    }
+
+    // Height zero is a real height, distinct from the latest one.
+    let rsp = key_value(None).await?.into_inner();
+    assert_eq!(rsp.value.expect("key is set").value, b"value_2".to_vec());
+    let rsp = key_value(Some(0)).await?.into_inner();
+    assert_eq!(rsp.value.expect("key is set").value, b"value_0".to_vec());
+
+    let status = key_value(Some(latest + 1))
+        .await
+        .expect_err("heights past the latest one are rejected");
+    assert_eq!(status.code(), tonic::Code::OutOfRange);
+
+    Ok(())
+}
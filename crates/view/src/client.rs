@@ -3,7 +3,10 @@ use std::{collections::BTreeMap, future::Future, pin::Pin};
 use anyhow::Result;
 use futures::{FutureExt, Stream, StreamExt, TryStreamExt};
 use penumbra_app::params::AppParameters;
-use penumbra_asset::asset::{self, DenomMetadata, Id};
+use penumbra_asset::{
+    asset::{self, DenomMetadata, Id},
+    Value,
+};
 use penumbra_chain::params::FmdParameters;
 use penumbra_dex::{
     lp::position::{self},
@@ -28,7 +31,7 @@ use penumbra_transaction::{plan::TransactionPlan, Transaction, WitnessData};
 use tonic::codegen::Bytes;
 use tracing::instrument;
 
-use crate::{SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionInfo};
+use crate::{LedgerEntry, SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionInfo};
 
 /// The view protocol is used by a view client, who wants to do some
 /// transaction-related actions, to request data from a view service, which is
@@ -92,6 +95,12 @@ pub trait ViewClient {
         Box<dyn Future<Output = Result<Vec<(SpendableNoteRecord, IdentityKey)>>> + Send + 'static>,
     >;
 
+    /// Queries for notes received in payment of a payment request.
+    fn notes_for_payment_request(
+        &mut self,
+        request: pb::NotesForPaymentRequestRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<SpendableNoteRecord>>> + Send + 'static>>;
+
     /// Queries for account balance by address
     fn balances(
         &mut self,
@@ -166,6 +175,13 @@ pub trait ViewClient {
         trading_pair: Option<TradingPair>,
     ) -> Pin<Box<dyn Future<Output = Result<Vec<position::Id>>> + Send + 'static>>;
 
+    /// Queries for the liquidity incentives claimable by withdrawn positions
+    /// owned by the full viewing key.
+    fn position_rewards(
+        &mut self,
+        trading_pair: Option<TradingPair>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<(position::Id, Value)>>> + Send + 'static>>;
+
     /// Generates a full perspective for a selected transaction using a full viewing key
     fn transaction_info_by_hash(
         &mut self,
@@ -179,6 +195,15 @@ pub trait ViewClient {
         end_height: Option<u64>,
     ) -> Pin<Box<dyn Future<Output = Result<Vec<TransactionInfo>>> + Send + 'static>>;
 
+    /// Queries for the wallet's ledger entries in a range of block heights, optionally only for
+    /// one account.
+    fn transaction_ledger(
+        &mut self,
+        start_height: Option<u64>,
+        end_height: Option<u64>,
+        account: Option<u32>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<LedgerEntry>>> + Send + 'static>>;
+
     fn broadcast_transaction(
         &mut self,
         transaction: Transaction,
@@ -484,6 +509,30 @@ where
         .boxed()
     }
 
+    fn notes_for_payment_request(
+        &mut self,
+        request: pb::NotesForPaymentRequestRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<SpendableNoteRecord>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let req = self2.notes_for_payment_request(tonic::Request::new(request));
+            let pb_notes: Vec<_> = req.await?.into_inner().try_collect().await?;
+
+            pb_notes
+                .into_iter()
+                .map(|note_rsp| {
+                    note_rsp
+                        .note_record
+                        .ok_or_else(|| {
+                            anyhow::anyhow!("empty NotesForPaymentRequestResponse message")
+                        })?
+                        .try_into()
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
     fn note_by_commitment(
         &mut self,
         wallet_id: WalletId,
@@ -751,6 +800,39 @@ where
         .boxed()
     }
 
+    fn position_rewards(
+        &mut self,
+        trading_pair: Option<TradingPair>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<(position::Id, Value)>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewProtocolServiceClient::position_rewards(
+                &mut self2,
+                tonic::Request::new(pb::PositionRewardsRequest {
+                    trading_pair: trading_pair.map(TryInto::try_into).transpose()?,
+                }),
+            );
+
+            let pb_rewards: Vec<_> = rsp.await?.into_inner().try_collect().await?;
+
+            pb_rewards
+                .into_iter()
+                .map(|r| {
+                    let position_id = r
+                        .position_id
+                        .ok_or_else(|| anyhow::anyhow!("empty PositionRewardsResponse message"))?
+                        .try_into()?;
+                    let rewards = r
+                        .rewards
+                        .ok_or_else(|| anyhow::anyhow!("empty PositionRewardsResponse message"))?
+                        .try_into()?;
+                    Ok((position_id, rewards))
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
     fn transaction_info_by_hash(
         &mut self,
         id: penumbra_transaction::Id,
@@ -857,6 +939,34 @@ where
         .boxed()
     }
 
+    fn transaction_ledger(
+        &mut self,
+        start_height: Option<u64>,
+        end_height: Option<u64>,
+        account: Option<u32>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<LedgerEntry>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = self2.transaction_ledger(tonic::Request::new(pb::TransactionLedgerRequest {
+                start_height: start_height.unwrap_or(0),
+                end_height: end_height.unwrap_or(0),
+                account_filter: account.map(|account| AddressIndex::new(account).into()),
+                wallet_id: None,
+            }));
+            let pb_entries: Vec<_> = rsp.await?.into_inner().try_collect().await?;
+
+            pb_entries
+                .into_iter()
+                .map(|rsp| {
+                    rsp.entry
+                        .ok_or_else(|| anyhow::anyhow!("empty TransactionLedgerResponse message"))?
+                        .try_into()
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
     fn broadcast_transaction(
         &mut self,
         transaction: Transaction,
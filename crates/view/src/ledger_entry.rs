@@ -0,0 +1,592 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use penumbra_asset::{Balance, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_dex::{
+    swap::{SwapPlaintext, SwapView},
+    swap_claim::SwapClaimView,
+    BatchSwapOutputData,
+};
+use penumbra_fee::Fee;
+use penumbra_keys::{Address, FullViewingKey};
+use penumbra_proto::{view::v1alpha1 as pb, DomainType};
+use penumbra_stake::{DelegationToken, UnbondingToken};
+use penumbra_tct as tct;
+use penumbra_transaction::{
+    view::action_view::{ActionView, OutputView, SpendView},
+    Id, MemoView,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{SwapRecord, TransactionInfo};
+
+/// The effect of a transaction on one account of the wallet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::LedgerEntry", into = "pb::LedgerEntry")]
+pub struct LedgerEntry {
+    /// The height the transaction was included in a block.
+    pub height: u64,
+    /// The hash of the transaction.
+    pub transaction_id: Id,
+    /// The account the entry is for.
+    pub account: u32,
+    /// The net value the account received in the transaction, per asset.
+    pub inflows: Vec<Value>,
+    /// The net value the account sent in the transaction, per asset, including any fee.
+    pub outflows: Vec<Value>,
+    /// The fee of the transaction, if the account paid it.
+    pub fee: Option<Fee>,
+    /// The swaps of the transaction claimed by the account.
+    pub swap_fills: Vec<SwapFill>,
+    /// The delegations, undelegations and redelegations of the transaction, if the account funded it.
+    pub delegation_conversions: Vec<DelegationConversion>,
+    /// The text of the transaction's memo, if visible and not empty.
+    pub memo: Option<String>,
+}
+
+/// A swap, and how it was filled by its batch.
+#[derive(Debug, Clone)]
+pub struct SwapFill {
+    /// The nonzero inputs of the swap.
+    pub inputs: Vec<Value>,
+    /// The nonzero outputs of the swap, which are its inputs if it was refunded.
+    pub outputs: Vec<Value>,
+}
+
+/// A conversion between staking, delegation, unbonding and redelegation tokens at a validator's rate.
+#[derive(Debug, Clone)]
+pub struct DelegationConversion {
+    /// The value converted.
+    pub input: Value,
+    /// The value it was converted into.
+    pub output: Value,
+}
+
+impl SwapFill {
+    /// The fill of the swap with the given plaintext by the batch with the given output data.
+    ///
+    /// A swap whose minimum output the batch couldn't honor is refunded, so its outputs are its
+    /// inputs.
+    pub fn new(swap: &SwapPlaintext, output_data: &BatchSwapOutputData) -> Self {
+        let (asset_1, asset_2) = (swap.trading_pair.asset_1(), swap.trading_pair.asset_2());
+        let (lambda_1_i, lambda_2_i) =
+            output_data.swap_outputs((swap.delta_1_i, swap.delta_2_i), swap.min_output);
+        let nonzero = |values: [Value; 2]| {
+            values
+                .into_iter()
+                .filter(|value| value.amount != 0u64.into())
+                .collect()
+        };
+
+        Self {
+            inputs: nonzero([
+                Value {
+                    amount: swap.delta_1_i,
+                    asset_id: asset_1,
+                },
+                Value {
+                    amount: swap.delta_2_i,
+                    asset_id: asset_2,
+                },
+            ]),
+            outputs: nonzero([
+                Value {
+                    amount: lambda_1_i,
+                    asset_id: asset_1,
+                },
+                Value {
+                    amount: lambda_2_i,
+                    asset_id: asset_2,
+                },
+            ]),
+        }
+    }
+}
+
+impl LedgerEntry {
+    /// Renders a transaction into ledger entries, one for each account of the wallet it affects.
+    ///
+    /// Value flows come from the notes visible in the transaction's view. The account whose notes
+    /// the transaction spends is the one that funded it, and its entry also carries the fee and any
+    /// delegation conversions. Swap fills are computed from the wallet's `swap_records`, by swap
+    /// commitment, and go to the account the swap is claimed to.
+    pub fn from_transaction_info(
+        info: &TransactionInfo,
+        fvk: &FullViewingKey,
+        swap_records: &BTreeMap<tct::StateCommitment, SwapRecord>,
+    ) -> Vec<LedgerEntry> {
+        let account_of = |address: Address| fvk.address_index(&address).map(|index| index.account);
+
+        let mut balances = BTreeMap::<u32, Balance>::new();
+        let mut funding_account = None;
+        let mut swap_fills = Vec::new();
+        let mut delegation_conversions = Vec::new();
+
+        for action_view in info.view.action_views() {
+            match action_view {
+                ActionView::Spend(SpendView::Visible { note, .. }) => {
+                    if let Some(account) = account_of(note.address()) {
+                        funding_account.get_or_insert(account);
+                        *balances.entry(account).or_default() -= note.value.value();
+                    }
+                }
+                ActionView::Output(OutputView::Visible { note, .. }) => {
+                    if let Some(account) = account_of(note.address()) {
+                        *balances.entry(account).or_default() += note.value.value();
+                    }
+                }
+                ActionView::SwapClaim(SwapClaimView::Visible {
+                    output_1, output_2, ..
+                }) => {
+                    for note in [output_1, output_2] {
+                        if let Some(account) = account_of(note.address()) {
+                            *balances.entry(account).or_default() += note.value.value();
+                        }
+                    }
+                }
+                ActionView::Swap(SwapView::Visible {
+                    swap,
+                    swap_plaintext,
+                }) => {
+                    if let Some(record) = swap_records.get(&swap.body.payload.commitment) {
+                        swap_fills.push((
+                            account_of(swap_plaintext.claim_address),
+                            SwapFill::new(swap_plaintext, &record.output_data),
+                        ));
+                    }
+                }
+                ActionView::Delegate(delegate) => {
+                    delegation_conversions.push(DelegationConversion {
+                        input: Value {
+                            amount: delegate.unbonded_amount,
+                            asset_id: *STAKING_TOKEN_ASSET_ID,
+                        },
+                        output: Value {
+                            amount: delegate.delegation_amount,
+                            asset_id: DelegationToken::new(delegate.validator_identity).id(),
+                        },
+                    });
+                }
+                ActionView::Undelegate(undelegate) => {
+                    delegation_conversions.push(DelegationConversion {
+                        input: Value {
+                            amount: undelegate.delegation_amount,
+                            asset_id: DelegationToken::new(undelegate.validator_identity).id(),
+                        },
+                        output: Value {
+                            amount: undelegate.unbonded_amount,
+                            asset_id: UnbondingToken::new(
+                                undelegate.validator_identity,
+                                undelegate.start_epoch_index,
+                            )
+                            .id(),
+                        },
+                    });
+                }
+                ActionView::Redelegate(redelegate) => {
+                    delegation_conversions.push(DelegationConversion {
+                        input: Value {
+                            amount: redelegate.from_delegation_amount,
+                            asset_id: DelegationToken::new(redelegate.from_validator_identity).id(),
+                        },
+                        output: Value {
+                            amount: redelegate.to_delegation_amount,
+                            asset_id: redelegate.redelegation_token().id(),
+                        },
+                    });
+                }
+                ActionView::RedelegateClaim(claim) => {
+                    delegation_conversions.push(DelegationConversion {
+                        input: Value {
+                            amount: claim.redelegation_amount,
+                            asset_id: claim.redelegation_token().id(),
+                        },
+                        output: Value {
+                            amount: claim.delegation_amount(),
+                            asset_id: claim.delegation_token().id(),
+                        },
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        // Swaps claimed to addresses outside the wallet are attributed to the funding account.
+        let swap_fills: Vec<_> = swap_fills
+            .into_iter()
+            .map(|(account, fill)| (account.or(funding_account), fill))
+            .collect();
+
+        let memo = match &info.view.body_view.memo_view {
+            Some(MemoView::Visible { plaintext, .. }) if !plaintext.text.is_empty() => {
+                Some(plaintext.text.clone())
+            }
+            _ => None,
+        };
+
+        let accounts: BTreeSet<u32> = balances
+            .keys()
+            .copied()
+            .chain(funding_account)
+            .chain(swap_fills.iter().filter_map(|(account, _)| *account))
+            .collect();
+
+        accounts
+            .into_iter()
+            .map(|account| {
+                let balance = balances.remove(&account).unwrap_or_default();
+                let funded = funding_account == Some(account);
+                LedgerEntry {
+                    height: info.height,
+                    transaction_id: info.id,
+                    account,
+                    inflows: balance.provided().collect(),
+                    outflows: balance.required().collect(),
+                    fee: funded.then(|| info.view.body_view.fee.clone()),
+                    swap_fills: swap_fills
+                        .iter()
+                        .filter(|(fill_account, _)| *fill_account == Some(account))
+                        .map(|(_, fill)| fill.clone())
+                        .collect(),
+                    delegation_conversions: if funded {
+                        delegation_conversions.clone()
+                    } else {
+                        Vec::new()
+                    },
+                    memo: memo.clone(),
+                }
+            })
+            .collect()
+    }
+}
+
+impl DomainType for LedgerEntry {
+    type Proto = pb::LedgerEntry;
+}
+
+impl From<LedgerEntry> for pb::LedgerEntry {
+    fn from(entry: LedgerEntry) -> Self {
+        pb::LedgerEntry {
+            height: entry.height,
+            transaction_id: Some(entry.transaction_id.into()),
+            account: entry.account,
+            inflows: entry.inflows.into_iter().map(Into::into).collect(),
+            outflows: entry.outflows.into_iter().map(Into::into).collect(),
+            fee: entry.fee.map(Into::into),
+            swap_fills: entry
+                .swap_fills
+                .into_iter()
+                .map(|fill| pb::ledger_entry::SwapFill {
+                    inputs: fill.inputs.into_iter().map(Into::into).collect(),
+                    outputs: fill.outputs.into_iter().map(Into::into).collect(),
+                })
+                .collect(),
+            delegation_conversions: entry
+                .delegation_conversions
+                .into_iter()
+                .map(|conversion| pb::ledger_entry::DelegationConversion {
+                    input: Some(conversion.input.into()),
+                    output: Some(conversion.output.into()),
+                })
+                .collect(),
+            memo: entry.memo.unwrap_or_default(),
+        }
+    }
+}
+
+impl TryFrom<pb::LedgerEntry> for LedgerEntry {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::LedgerEntry) -> Result<Self, Self::Error> {
+        Ok(Self {
+            height: value.height,
+            transaction_id: value
+                .transaction_id
+                .ok_or_else(|| anyhow::anyhow!("missing transaction_id"))?
+                .try_into()?,
+            account: value.account,
+            inflows: value
+                .inflows
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+            outflows: value
+                .outflows
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+            fee: value.fee.map(TryInto::try_into).transpose()?,
+            swap_fills: value
+                .swap_fills
+                .into_iter()
+                .map(|fill| {
+                    Ok(SwapFill {
+                        inputs: fill
+                            .inputs
+                            .into_iter()
+                            .map(TryInto::try_into)
+                            .collect::<anyhow::Result<_>>()?,
+                        outputs: fill
+                            .outputs
+                            .into_iter()
+                            .map(TryInto::try_into)
+                            .collect::<anyhow::Result<_>>()?,
+                    })
+                })
+                .collect::<anyhow::Result<_>>()?,
+            delegation_conversions: value
+                .delegation_conversions
+                .into_iter()
+                .map(|conversion| {
+                    Ok(DelegationConversion {
+                        input: conversion
+                            .input
+                            .ok_or_else(|| anyhow::anyhow!("missing input"))?
+                            .try_into()?,
+                        output: conversion
+                            .output
+                            .ok_or_else(|| anyhow::anyhow!("missing output"))?
+                            .try_into()?,
+                    })
+                })
+                .collect::<anyhow::Result<_>>()?,
+            memo: if value.memo.is_empty() {
+                None
+            } else {
+                Some(value.memo)
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::asset;
+    use penumbra_chain::NoteSource;
+    use penumbra_dex::{
+        swap::{Swap, SwapPlan},
+        TradingPair,
+    };
+    use penumbra_keys::{keys::AddressIndex, test_keys::FULL_VIEWING_KEY, PayloadKey};
+    use penumbra_num::{fixpoint::U128x128, Amount};
+    use penumbra_proto::core::component::{
+        dex::v1alpha1 as dex_pb, shielded_pool::v1alpha1 as sp_pb,
+    };
+    use penumbra_sct::Nullifier;
+    use penumbra_shielded_pool::{Note, Output, OutputPlan, Spend, SpendPlan};
+    use penumbra_transaction::{
+        view::TransactionBodyView, TransactionPerspective, TransactionView,
+    };
+    use rand_core::OsRng;
+
+    use super::*;
+
+    // Ledger entries don't depend on proofs or signatures, so the actions below carry placeholders.
+    const PROOF_LEN: usize = 192;
+
+    fn address(account: u32) -> Address {
+        FULL_VIEWING_KEY
+            .payment_address(AddressIndex::new(account))
+            .0
+    }
+
+    fn value(amount: u64, asset_id: asset::Id) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id,
+        }
+    }
+
+    fn upenumbra(amount: u64) -> Value {
+        value(amount, *STAKING_TOKEN_ASSET_ID)
+    }
+
+    fn spend(value: Value, account: u32) -> ActionView {
+        let note = Note::generate(&mut OsRng, &address(account), value);
+        let plan = SpendPlan::new(&mut OsRng, note.clone(), 0u64.into());
+        ActionView::Spend(SpendView::Visible {
+            spend: Spend {
+                body: plan.spend_body(&FULL_VIEWING_KEY),
+                auth_sig: [0u8; 64].into(),
+                proof: sp_pb::ZkSpendProof {
+                    inner: vec![0; PROOF_LEN],
+                }
+                .try_into()
+                .expect("proof has the right length"),
+            },
+            note: TransactionPerspective::default().view_note(note),
+        })
+    }
+
+    fn output(value: Value, account: u32) -> ActionView {
+        let plan = OutputPlan::new(&mut OsRng, value, address(account));
+        let payload_key = PayloadKey::random_key(&mut OsRng);
+        ActionView::Output(OutputView::Visible {
+            output: Output {
+                body: plan.output_body(FULL_VIEWING_KEY.outgoing(), &payload_key),
+                proof: sp_pb::ZkOutputProof {
+                    inner: vec![0; PROOF_LEN],
+                }
+                .try_into()
+                .expect("proof has the right length"),
+            },
+            note: TransactionPerspective::default().view_note(plan.output_note()),
+            payload_key,
+        })
+    }
+
+    fn swap(swap_plaintext: &SwapPlaintext) -> ActionView {
+        let plan = SwapPlan::new(&mut OsRng, swap_plaintext.clone());
+        ActionView::Swap(SwapView::Visible {
+            swap: Swap {
+                body: plan.swap_body(&FULL_VIEWING_KEY),
+                proof: dex_pb::ZkSwapProof {
+                    inner: vec![0; PROOF_LEN],
+                }
+                .try_into()
+                .expect("proof has the right length"),
+            },
+            swap_plaintext: swap_plaintext.clone(),
+        })
+    }
+
+    fn swap_record(swap: &SwapPlaintext, output_data: BatchSwapOutputData) -> SwapRecord {
+        SwapRecord {
+            swap_commitment: swap.swap_commitment(),
+            swap: swap.clone(),
+            position: 0u64.into(),
+            nullifier: Nullifier(decaf377::Fq::from(1u64)),
+            output_data,
+            height_claimed: None,
+            source: NoteSource::Unknown,
+        }
+    }
+
+    /// A transaction with the given actions, paying a fee of 10 upenumbra.
+    fn transaction_info(action_views: Vec<ActionView>) -> TransactionInfo {
+        let view = TransactionView {
+            body_view: TransactionBodyView {
+                action_views,
+                transaction_parameters: Default::default(),
+                fee: Fee::from_staking_token_amount(10u64.into()),
+                detection_data: None,
+                memo_view: None,
+            },
+            binding_sig: [0u8; 64].into(),
+            anchor: tct::Tree::new().root(),
+        };
+        TransactionInfo {
+            height: 7,
+            id: Id([1; 32]),
+            transaction: view.transaction(),
+            perspective: TransactionPerspective::default(),
+            view,
+        }
+    }
+
+    fn trading_pair() -> TradingPair {
+        TradingPair::new(*STAKING_TOKEN_ASSET_ID, asset::Id(decaf377::Fq::from(1u64)))
+    }
+
+    /// A batch in which 100 of asset 1 were swapped for 200 of asset 2, whose 1=>2 swaps demanding
+    /// more than 2 of asset 2 per unit of asset 1 were refunded.
+    fn output_data() -> BatchSwapOutputData {
+        BatchSwapOutputData {
+            delta_1: 100u64.into(),
+            delta_2: Amount::zero(),
+            lambda_1: Amount::zero(),
+            lambda_2: 200u64.into(),
+            unfilled_1: Amount::zero(),
+            unfilled_2: Amount::zero(),
+            height: 5,
+            trading_pair: trading_pair(),
+            epoch_starting_height: 0,
+            limit_price_1: U128x128::from(2u64),
+            limit_price_2: U128x128::default(),
+        }
+    }
+
+    /// A swap of 50 of asset 1, claimed to `account`, demanding at least `min_output` of asset 2.
+    fn swap_plaintext(account: u32, min_output: u64) -> SwapPlaintext {
+        SwapPlaintext::new(
+            &mut OsRng,
+            trading_pair(),
+            50u64.into(),
+            Amount::zero(),
+            Fee::default(),
+            address(account),
+        )
+        .with_min_output(min_output.into())
+    }
+
+    #[test]
+    fn spends_and_outputs_are_netted_per_account() {
+        // Account 0 spends 100, sends 30 to account 1, and keeps 60 in change: the rest is the fee.
+        let info = transaction_info(vec![
+            spend(upenumbra(100), 0),
+            output(upenumbra(30), 1),
+            output(upenumbra(60), 0),
+        ]);
+        let entries =
+            LedgerEntry::from_transaction_info(&info, &FULL_VIEWING_KEY, &BTreeMap::new());
+        assert_eq!(entries.len(), 2);
+
+        let funding = &entries[0];
+        assert_eq!(funding.account, 0);
+        assert_eq!(funding.height, 7);
+        assert_eq!(funding.transaction_id, Id([1; 32]));
+        assert!(funding.inflows.is_empty());
+        assert_eq!(funding.outflows, vec![upenumbra(40)]);
+        assert_eq!(
+            funding.fee,
+            Some(Fee::from_staking_token_amount(10u64.into()))
+        );
+
+        let receiving = &entries[1];
+        assert_eq!(receiving.account, 1);
+        assert_eq!(receiving.inflows, vec![upenumbra(30)]);
+        assert!(receiving.outflows.is_empty());
+        assert_eq!(receiving.fee, None);
+    }
+
+    #[test]
+    fn swap_fills_use_the_batch_outputs() {
+        let (asset_1, asset_2) = (trading_pair().asset_1(), trading_pair().asset_2());
+
+        // Demanding 2 per unit, the swap is filled pro rata.
+        let fill = SwapFill::new(&swap_plaintext(0, 100), &output_data());
+        assert_eq!(fill.inputs, vec![value(50, asset_1)]);
+        assert_eq!(fill.outputs, vec![value(100, asset_2)]);
+
+        // Demanding 3 per unit, the swap is refunded.
+        let refund = SwapFill::new(&swap_plaintext(0, 150), &output_data());
+        assert_eq!(refund.inputs, vec![value(50, asset_1)]);
+        assert_eq!(refund.outputs, vec![value(50, asset_1)]);
+    }
+
+    #[test]
+    fn swap_fills_go_to_the_claiming_account() {
+        let (asset_1, asset_2) = (trading_pair().asset_1(), trading_pair().asset_2());
+        let filled = swap_plaintext(1, 0);
+        let refunded = swap_plaintext(1, 150);
+        let swap_records: BTreeMap<_, _> = [&filled, &refunded]
+            .into_iter()
+            .map(|swap| (swap.swap_commitment(), swap_record(swap, output_data())))
+            .collect();
+
+        let info = transaction_info(vec![
+            spend(value(100, asset_1), 0),
+            swap(&filled),
+            swap(&refunded),
+        ]);
+        let entries = LedgerEntry::from_transaction_info(&info, &FULL_VIEWING_KEY, &swap_records);
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].swap_fills.is_empty());
+
+        let claiming = &entries[1];
+        assert_eq!(claiming.account, 1);
+        assert_eq!(claiming.fee, None);
+        assert_eq!(claiming.swap_fills.len(), 2);
+        assert_eq!(claiming.swap_fills[0].outputs, vec![value(100, asset_2)]);
+        assert_eq!(claiming.swap_fills[1].outputs, vec![value(50, asset_1)]);
+    }
+}
@@ -1,6 +1,7 @@
 #![deny(clippy::unwrap_used)]
 #![recursion_limit = "256"]
 mod client;
+mod ledger_entry;
 mod metrics;
 mod note_record;
 mod planner;
@@ -16,6 +17,7 @@ use worker::Worker;
 
 pub use crate::metrics::register_metrics;
 pub use client::ViewClient;
+pub use ledger_entry::{DelegationConversion, LedgerEntry, SwapFill};
 pub use note_record::SpendableNoteRecord;
 pub use planner::Planner;
 pub use service::ViewService;
@@ -23,3 +25,4 @@ pub use status::StatusStreamResponse;
 pub use storage::Storage;
 pub use swap_record::SwapRecord;
 pub use transaction_info::TransactionInfo;
+pub use worker::SyncMode;
@@ -6,12 +6,12 @@ use std::{
 
 use anyhow::Result;
 
-use penumbra_asset::{asset, Balance, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_asset::{asset, Balance, Value};
 use penumbra_chain::params::{ChainParameters, FmdParameters};
 use penumbra_dao::DaoDeposit;
 use penumbra_dex::{
     lp::action::{PositionClose, PositionOpen},
-    lp::plan::PositionWithdrawPlan,
+    lp::plan::{PositionRewardClaimPlan, PositionWithdrawPlan},
     lp::position::{self, Position},
     lp::Reserves,
     swap::SwapPlaintext,
@@ -33,12 +33,13 @@ use penumbra_num::Amount;
 use penumbra_proto::view::v1alpha1::{NotesForVotingRequest, NotesRequest};
 use penumbra_shielded_pool::{Ics20Withdrawal, Note, OutputPlan, SpendPlan};
 use penumbra_stake::{rate::RateData, validator};
-use penumbra_stake::{IdentityKey, UndelegateClaimPlan};
+use penumbra_stake::{IdentityKey, RedelegateClaim, UndelegateClaimPlan};
 use penumbra_tct as tct;
 use penumbra_transaction::{
     gas::GasCost,
     memo::MemoPlaintext,
     plan::{ActionPlan, MemoPlan, TransactionPlan},
+    PaymentRequest,
 };
 use rand::{CryptoRng, RngCore};
 use tracing::instrument;
@@ -54,6 +55,8 @@ pub struct Planner<R: RngCore + CryptoRng> {
     plan: TransactionPlan,
     ibc_actions: Vec<IbcRelay>,
     gas_prices: GasPrices,
+    alt_gas_prices: Vec<GasPrices>,
+    fee_asset_id: Option<asset::Id>,
     // IMPORTANT: if you add more fields here, make sure to clear them when the planner is finished
 }
 
@@ -84,6 +87,8 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             plan: TransactionPlan::default(),
             ibc_actions: Vec::new(),
             gas_prices: GasPrices::zero(),
+            alt_gas_prices: Vec::new(),
+            fee_asset_id: None,
         }
     }
 
@@ -94,6 +99,70 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Set the gas prices of the alternative fee assets approved by governance.
+    ///
+    /// When planning with a view service, these are filled in from the current
+    /// fee parameters.
+    #[instrument(skip(self))]
+    pub fn set_alt_gas_prices(&mut self, alt_gas_prices: Vec<GasPrices>) -> &mut Self {
+        self.alt_gas_prices = alt_gas_prices;
+        self
+    }
+
+    /// Set the asset in which to pay the transaction's gas fees, which must be the
+    /// staking token or one of the alternative fee assets.
+    ///
+    /// When planning with a view service, the fee asset is chosen automatically from
+    /// the balances of the source account if it isn't set.
+    #[instrument(skip(self))]
+    pub fn fee_asset(&mut self, asset_id: asset::Id) -> &mut Self {
+        self.fee_asset_id = Some(asset_id);
+        self
+    }
+
+    /// Get the gas prices for the given fee asset.
+    fn gas_prices_for(&self, asset_id: asset::Id) -> Result<GasPrices> {
+        if asset_id == self.gas_prices.asset_id {
+            return Ok(self.gas_prices);
+        }
+        self.alt_gas_prices
+            .iter()
+            .find(|prices| prices.asset_id == asset_id)
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("asset {} is not an accepted fee asset", asset_id))
+    }
+
+    /// Choose the asset to pay the transaction's gas fees with, given the balances
+    /// available to spend.
+    ///
+    /// The staking token is preferred, followed by the alternative fee assets in the
+    /// order they were approved; the first one whose balance covers both the fee and
+    /// the rest of the transaction is chosen. If none do, the staking token is chosen,
+    /// so that planning fails with an insufficient balance for it.
+    pub fn select_fee_asset(&self, balances: &[(asset::Id, Amount)]) -> asset::Id {
+        let gas_cost = self.plan.gas_cost();
+        std::iter::once(&self.gas_prices)
+            .chain(self.alt_gas_prices.iter())
+            .find(|prices| {
+                // Account for the overestimate of the fee made by `add_gas_fees`.
+                let fee = prices.price(&gas_cost) * Amount::from(2u32);
+                let required = self
+                    .balance
+                    .required()
+                    .filter(|value| value.asset_id == prices.asset_id)
+                    .map(|value| value.amount)
+                    .sum::<Amount>();
+                let available = balances
+                    .iter()
+                    .filter(|(asset_id, _)| *asset_id == prices.asset_id)
+                    .map(|(_, amount)| *amount)
+                    .sum::<Amount>();
+                available >= required + fee
+            })
+            .map(|prices| prices.asset_id)
+            .unwrap_or(self.gas_prices.asset_id)
+    }
+
     /// Get the current transaction balance of the planner.
     pub fn balance(&self) -> &Balance {
         &self.balance
@@ -160,21 +229,25 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
-    /// Calculate gas cost-based fees and add to the transaction plan.
+    /// Calculate gas cost-based fees in the fee asset and add to the transaction plan.
     ///
     /// This function should be called once.
     #[instrument(skip(self))]
-    pub fn add_gas_fees(&mut self) -> &mut Self {
-        let minimum_fee = self.gas_prices.price(&self.plan.gas_cost());
+    pub fn add_gas_fees(&mut self) -> Result<&mut Self> {
+        let asset_id = self.fee_asset_id.unwrap_or(self.gas_prices.asset_id);
+        let minimum_fee = self.gas_prices_for(asset_id)?.price(&self.plan.gas_cost());
 
         // Since paying the fee possibly requires adding an additional Spend to the
         // transaction, which would then change the fee calculation, we multiply the
         // fee here by a factor of 2 and then recalculate and capture the excess as
         // change outputs.
-        let fee = Fee::from_staking_token_amount(minimum_fee * Amount::from(2u32));
+        let fee = Fee(Value {
+            amount: minimum_fee * Amount::from(2u32),
+            asset_id,
+        });
         self.balance += fee.0;
         self.plan.fee = fee;
-        self
+        Ok(self)
     }
 
     /// Spend a specific positioned note in the transaction.
@@ -218,6 +291,19 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Claim the liquidity incentives accrued to a withdrawn liquidity position.
+    #[instrument(skip(self))]
+    pub fn position_reward_claim(
+        &mut self,
+        position_id: position::Id,
+        rewards: Amount,
+    ) -> &mut Self {
+        self.action(ActionPlan::PositionRewardClaim(
+            PositionRewardClaimPlan::new(position_id, rewards),
+        ));
+        self
+    }
+
     /// Perform a swap claim based on an input swap NFT with a pre-paid fee.
     #[instrument(skip(self))]
     pub fn swap_claim(&mut self, plan: SwapClaimPlan) -> &mut Self {
@@ -231,11 +317,15 @@ impl<R: RngCore + CryptoRng> Planner<R> {
     }
 
     /// Perform a swap based on input notes in the transaction.
+    ///
+    /// If the batch can't output at least `min_output` of `into_asset`, the
+    /// input is refunded instead; a zero `min_output` accepts any output.
     #[instrument(skip(self))]
     pub fn swap(
         &mut self,
         input_value: Value,
         into_asset: asset::Id,
+        min_output: Amount,
         swap_claim_fee: Fee,
         claim_address: Address,
     ) -> Result<&mut Self> {
@@ -266,7 +356,8 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             delta_2,
             swap_claim_fee,
             claim_address,
-        );
+        )
+        .with_min_output(min_output);
 
         let swap = SwapPlan::new(&mut self.rng, swap_plaintext).into();
         self.action(swap);
@@ -285,6 +376,26 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Pay the given [`PaymentRequest`], setting the memo of the transaction to refer to it.
+    ///
+    /// Errors if the request's invoice signature is invalid. If the request has an expiry height,
+    /// the transaction expires at that height too.
+    #[instrument(skip(self, request))]
+    pub fn payment_request(
+        &mut self,
+        request: &PaymentRequest,
+        return_address: Address,
+    ) -> anyhow::Result<&mut Self> {
+        request.verify_invoice()?;
+        for value in &request.values {
+            self.output(*value, request.address);
+        }
+        if let Some(expiry_height) = request.expiry_height {
+            self.expiry_height(expiry_height);
+        }
+        self.memo(request.memo_plaintext(return_address))
+    }
+
     /// Add a delegation to this transaction.
     ///
     /// If you don't specify spends or outputs as well, they will be filled in automatically.
@@ -305,6 +416,29 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Add a redelegation to this transaction, moving `delegation_amount` of
+    /// the source validator's delegation tokens to the destination validator.
+    #[instrument(skip(self))]
+    pub fn redelegate(
+        &mut self,
+        delegation_amount: Amount,
+        from_rate_data: RateData,
+        to_rate_data: RateData,
+    ) -> &mut Self {
+        let redelegation = from_rate_data
+            .build_redelegate(&to_rate_data, delegation_amount)
+            .into();
+        self.action(redelegation);
+        self
+    }
+
+    /// Add a redelegate claim to this transaction.
+    #[instrument(skip(self))]
+    pub fn redelegate_claim(&mut self, claim: RedelegateClaim) -> &mut Self {
+        self.action(ActionPlan::RedelegateClaim(claim));
+        self
+    }
+
     /// Add an undelegate claim to this transaction.
     #[instrument(skip(self))]
     pub fn undelegate_claim(&mut self, claim_plan: UndelegateClaimPlan) -> &mut Self {
@@ -319,6 +453,13 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Release a jailed validator from jail in this transaction.
+    #[instrument(skip(self))]
+    pub fn validator_unjail(&mut self, unjail: validator::Unjail) -> &mut Self {
+        self.action(ActionPlan::ValidatorUnjail(unjail));
+        self
+    }
+
     /// Submit a new governance proposal in this transaction.
     #[instrument(skip(self))]
     pub fn proposal_submit(&mut self, proposal: Proposal, deposit_amount: Amount) -> &mut Self {
@@ -456,14 +597,23 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         source: AddressIndex,
     ) -> anyhow::Result<TransactionPlan> {
         // Gather all the information needed from the view service
-        let chain_params = view.app_params().await?.chain_params;
+        let app_params = view.app_params().await?;
+        let chain_params = app_params.chain_params;
         let fmd_params = view.fmd_parameters().await?;
 
+        // The alternative fee assets are the ones currently approved by governance.
+        self.set_alt_gas_prices(app_params.fee_params.alt_gas_prices);
+        if self.fee_asset_id.is_none() {
+            let balances = view.balances(source, None).await?;
+            let fee_asset_id = self.select_fee_asset(&balances);
+            self.fee_asset(fee_asset_id);
+        }
+
         // Calculate the gas that needs to be paid for the transaction based on the configured gas prices.
         // Note that _paying the fee might incur an additional `Spend` action_, thus increasing the fee,
         // so we slightly overpay here and then capture the excess as change later during `plan_with_spendable_and_votable_notes`.
         // Add the fee to the planner's internal balance.
-        self.add_gas_fees();
+        self.add_gas_fees()?;
 
         let mut spendable_notes = Vec::new();
         let mut voting_notes = Vec::new();
@@ -592,13 +742,19 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         // for the cost of any additional `Spend` actions necessary to pay the fee, we need
         // to now calculate the transaction's fee again and capture the excess as change
         // by subtracting the excess from the required value balance.
-        let tx_real_fee = self.gas_prices.price(&self.plan.gas_cost());
+        let fee_asset_id = self.plan.fee.asset_id();
+        let tx_real_fee = self
+            .gas_prices_for(fee_asset_id)?
+            .price(&self.plan.gas_cost());
         let excess_fee_spent = self.plan.fee.amount() - tx_real_fee;
         self.balance -= Value {
             amount: excess_fee_spent,
-            asset_id: *STAKING_TOKEN_ASSET_ID,
+            asset_id: fee_asset_id,
         };
-        self.plan.fee = Fee::from_staking_token_amount(tx_real_fee);
+        self.plan.fee = Fee(Value {
+            amount: tx_real_fee,
+            asset_id: fee_asset_id,
+        });
 
         // For any remaining provided balance, make a single change note for each
         for value in self.balance.provided().collect::<Vec<_>>() {
@@ -639,8 +795,84 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self.vote_intents = BTreeMap::new();
         self.ibc_actions = Vec::new();
         self.gas_prices = GasPrices::zero();
+        self.alt_gas_prices = Vec::new();
+        self.fee_asset_id = None;
         let plan = mem::take(&mut self.plan);
 
         Ok(plan)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn gas_prices(asset_id: asset::Id, execution_price: u64) -> GasPrices {
+        GasPrices {
+            asset_id,
+            execution_price,
+            ..GasPrices::zero()
+        }
+    }
+
+    /// A planner for a DAO deposit of 5 of the staking token, which costs 10 units of
+    /// execution gas: 10 of the staking token, or 20 of the alternative fee asset.
+    fn dao_deposit_planner(alt_asset: asset::Id) -> Planner<OsRng> {
+        let mut planner = Planner::new(OsRng);
+        planner
+            .set_gas_prices(gas_prices(*STAKING_TOKEN_ASSET_ID, 1_000))
+            .set_alt_gas_prices(vec![gas_prices(alt_asset, 2_000)])
+            .dao_deposit(Value {
+                amount: 5u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            });
+        planner
+    }
+
+    #[test]
+    fn select_fee_asset_prefers_the_staking_token() {
+        let alt_asset = asset::Id(decaf377::Fq::from(1u64));
+        let planner = dao_deposit_planner(alt_asset);
+
+        // The fee is overestimated twofold, so the staking token must cover 5 + 2 * 10.
+        let balances = [
+            (*STAKING_TOKEN_ASSET_ID, 25u64.into()),
+            (alt_asset, 100u64.into()),
+        ];
+        assert_eq!(planner.select_fee_asset(&balances), *STAKING_TOKEN_ASSET_ID);
+
+        // Otherwise, the alternative fee asset must cover 2 * 20.
+        let balances = [
+            (*STAKING_TOKEN_ASSET_ID, 24u64.into()),
+            (alt_asset, 40u64.into()),
+        ];
+        assert_eq!(planner.select_fee_asset(&balances), alt_asset);
+
+        // If neither does, the staking token is chosen, so that planning fails on it.
+        let balances = [
+            (*STAKING_TOKEN_ASSET_ID, 24u64.into()),
+            (alt_asset, 39u64.into()),
+        ];
+        assert_eq!(planner.select_fee_asset(&balances), *STAKING_TOKEN_ASSET_ID);
+    }
+
+    #[test]
+    fn add_gas_fees_in_fee_asset() -> anyhow::Result<()> {
+        let alt_asset = asset::Id(decaf377::Fq::from(1u64));
+
+        let mut planner = dao_deposit_planner(alt_asset);
+        planner.fee_asset(alt_asset).add_gas_fees()?;
+        assert_eq!(planner.plan.fee.asset_id(), alt_asset);
+        assert_eq!(planner.plan.fee.amount(), 40u64.into());
+
+        // Fees cannot be paid in assets that are not accepted.
+        let mut planner = dao_deposit_planner(alt_asset);
+        planner.fee_asset(asset::Id(decaf377::Fq::from(2u64)));
+        assert!(planner.add_gas_fees().is_err());
+
+        Ok(())
+    }
+}
@@ -35,7 +35,13 @@ use penumbra_keys::{
 use penumbra_num::Amount;
 use penumbra_proto::view::v1alpha1::{WalletIdRequest, WalletIdResponse};
 use penumbra_proto::{
-    core::keys::v1alpha1 as pbc,
+    core::{
+        component::dex::v1alpha1::{
+            query_service_client::QueryServiceClient as DexQueryServiceClient,
+            LiquidityPositionRewardsRequest,
+        },
+        keys::v1alpha1 as pbc,
+    },
     util::tendermint_proxy::v1alpha1::{
         tendermint_proxy_service_client::TendermintProxyServiceClient, BroadcastTxSyncRequest,
         GetStatusRequest,
@@ -52,10 +58,11 @@ use penumbra_proto::{
 use penumbra_stake::rate::RateData;
 use penumbra_tct::{Proof, StateCommitment};
 use penumbra_transaction::{
-    plan::TransactionPlan, AuthorizationData, Transaction, TransactionPerspective, WitnessData,
+    plan::TransactionPlan, AuthorizationData, PaymentRequest, Transaction, TransactionPerspective,
+    WitnessData,
 };
 
-use crate::{Planner, Storage, Worker};
+use crate::{LedgerEntry, Planner, Storage, SyncMode, TransactionInfo, Worker};
 
 /// A service that synchronizes private chain state and responds to queries
 /// about it.
@@ -100,8 +107,18 @@ impl ViewService {
     /// by this method, rather than calling it multiple times.  That way, each clone
     /// will be backed by the same scanning task, rather than each spawning its own.
     pub async fn new(storage: Storage, node: Url) -> anyhow::Result<Self> {
+        Self::new_with_sync_mode(storage, node, SyncMode::default()).await
+    }
+
+    /// Constructs a new [`ViewService`] like [`Self::new`], with the sync task
+    /// finding relevant blocks according to the given [`SyncMode`].
+    pub async fn new_with_sync_mode(
+        storage: Storage,
+        node: Url,
+        sync_mode: SyncMode,
+    ) -> anyhow::Result<Self> {
         let (worker, sct, error_slot, sync_height_rx) =
-            Worker::new(storage.clone(), node.clone()).await?;
+            Worker::new(storage.clone(), node.clone(), sync_mode).await?;
 
         tokio::spawn(worker.run());
 
@@ -314,6 +331,12 @@ impl ViewProtocolService for ViewService {
     type NotesForVotingStream = Pin<
         Box<dyn futures::Stream<Item = Result<pb::NotesForVotingResponse, tonic::Status>> + Send>,
     >;
+    type NotesForPaymentRequestStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<pb::NotesForPaymentRequestResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
     type AssetsStream =
         Pin<Box<dyn futures::Stream<Item = Result<pb::AssetsResponse, tonic::Status>> + Send>>;
     type StatusStreamStream = Pin<
@@ -322,6 +345,11 @@ impl ViewProtocolService for ViewService {
     type TransactionInfoStream = Pin<
         Box<dyn futures::Stream<Item = Result<pb::TransactionInfoResponse, tonic::Status>> + Send>,
     >;
+    type TransactionLedgerStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<pb::TransactionLedgerResponse, tonic::Status>> + Send,
+        >,
+    >;
     type BalancesStream =
         Pin<Box<dyn futures::Stream<Item = Result<pb::BalancesResponse, tonic::Status>> + Send>>;
     type OwnedPositionIdsStream = Pin<
@@ -330,6 +358,9 @@ impl ViewProtocolService for ViewService {
     type UnclaimedSwapsStream = Pin<
         Box<dyn futures::Stream<Item = Result<pb::UnclaimedSwapsResponse, tonic::Status>> + Send>,
     >;
+    type PositionRewardsStream = Pin<
+        Box<dyn futures::Stream<Item = Result<pb::PositionRewardsResponse, tonic::Status>> + Send>,
+    >;
 
     async fn broadcast_transaction(
         &self,
@@ -398,6 +429,19 @@ impl ViewProtocolService for ViewService {
             )
             .expiry_height(prq.expiry_height);
 
+        let gas_prices =
+            self.storage.gas_prices().await.map_err(|e| {
+                tonic::Status::internal(format!("could not get gas prices: {:#}", e))
+            })?;
+        planner.set_gas_prices(gas_prices);
+
+        if let Some(fee_asset_id) = prq.fee_asset_id {
+            let fee_asset_id: asset::Id = fee_asset_id.try_into().map_err(|e| {
+                tonic::Status::invalid_argument(format!("Could not parse fee asset id: {e:#}"))
+            })?;
+            planner.fee_asset(fee_asset_id);
+        }
+
         for output in prq.outputs {
             let address: Address = output
                 .address
@@ -451,8 +495,17 @@ impl ViewProtocolService for ViewService {
                     tonic::Status::invalid_argument(format!("Could not parse claim address: {e:#}"))
                 })?;
 
+            let min_output: Amount = swap
+                .min_output
+                .map(TryInto::try_into)
+                .transpose()
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!("Could not parse min output: {e:#}"))
+                })?
+                .unwrap_or_default();
+
             planner
-                .swap(value, target_asset, fee, claim_address)
+                .swap(value, target_asset, min_output, fee, claim_address)
                 .map_err(|e| {
                     tonic::Status::invalid_argument(format!("Could not plan swap: {e:#}"))
                 })?;
@@ -531,6 +584,38 @@ impl ViewProtocolService for ViewService {
             planner.undelegate(value.amount, rate_data);
         }
 
+        for redelegation in prq.redelegations {
+            let value: Value = redelegation
+                .value
+                .ok_or_else(|| tonic::Status::invalid_argument("Missing value"))?
+                .try_into()
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!("Could not parse value: {e:#}"))
+                })?;
+
+            let from_rate_data: RateData = redelegation
+                .from_rate_data
+                .ok_or_else(|| tonic::Status::invalid_argument("Missing source rate data"))?
+                .try_into()
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!(
+                        "Could not parse source rate data: {e:#}"
+                    ))
+                })?;
+
+            let to_rate_data: RateData = redelegation
+                .to_rate_data
+                .ok_or_else(|| tonic::Status::invalid_argument("Missing destination rate data"))?
+                .try_into()
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!(
+                        "Could not parse destination rate data: {e:#}"
+                    ))
+                })?;
+
+            planner.redelegate(value.amount, from_rate_data, to_rate_data);
+        }
+
         for position_open in prq.position_opens {
             let position: Position = position_open
                 .position
@@ -583,6 +668,26 @@ impl ViewProtocolService for ViewService {
             planner.position_withdraw(position_id, reserves, trading_pair);
         }
 
+        for position_reward_claim in prq.position_reward_claims {
+            let position_id: position::Id = position_reward_claim
+                .position_id
+                .ok_or_else(|| tonic::Status::invalid_argument("Missing position_id"))?
+                .try_into()
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!("Could not parse position ID: {e:#}"))
+                })?;
+
+            let rewards: Amount = position_reward_claim
+                .rewards
+                .ok_or_else(|| tonic::Status::invalid_argument("Missing rewards"))?
+                .try_into()
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!("Could not parse rewards: {e:#}"))
+                })?;
+
+            planner.position_reward_claim(position_id, rewards);
+        }
+
         // Insert any ICS20 withdrawals.
         for ics20_withdrawal in prq.ics20_withdrawals {
             planner.ics20_withdrawal(
@@ -614,6 +719,25 @@ impl ViewProtocolService for ViewService {
             // ... or just use the default account if not.
             .unwrap_or(0u32);
 
+        if let Some(payment_request) = prq.payment_request {
+            if prq.memo.is_some() {
+                return Err(tonic::Status::invalid_argument(
+                    "A memo can't be set when paying a payment request",
+                ));
+            }
+            let payment_request: PaymentRequest = payment_request.try_into().map_err(|e| {
+                tonic::Status::invalid_argument(format!("Could not parse payment request: {e:#}"))
+            })?;
+            let (return_address, _dtk) = fvk.ephemeral_address(OsRng, source.into());
+            planner
+                .payment_request(&payment_request, return_address)
+                .map_err(|e| {
+                    tonic::Status::invalid_argument(format!(
+                        "Could not plan payment request: {e:#}"
+                    ))
+                })?;
+        }
+
         let plan = planner
             .plan(&mut client_of_self, fvk.wallet_id(), source.into())
             .await
@@ -1156,6 +1280,46 @@ impl ViewProtocolService for ViewService {
         ))
     }
 
+    async fn notes_for_payment_request(
+        &self,
+        request: tonic::Request<pb::NotesForPaymentRequestRequest>,
+    ) -> Result<tonic::Response<Self::NotesForPaymentRequestStream>, tonic::Status> {
+        self.check_worker().await?;
+        self.check_wallet_id(request.get_ref().wallet_id.as_ref())
+            .await?;
+
+        let payment_request: PaymentRequest = request
+            .into_inner()
+            .payment_request
+            .ok_or_else(|| tonic::Status::invalid_argument("missing payment request"))?
+            .try_into()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("invalid payment request: {e:#}"))
+            })?;
+
+        let notes = self
+            .storage
+            .notes_for_payment_request(&payment_request)
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error fetching notes: {e}")))?;
+
+        let stream = try_stream! {
+            for note in notes {
+                yield pb::NotesForPaymentRequestResponse {
+                    note_record: Some(note.into()),
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting notes: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+
     async fn assets(
         &self,
         request: tonic::Request<pb::AssetsRequest>,
@@ -1271,6 +1435,92 @@ impl ViewProtocolService for ViewService {
         ))
     }
 
+    async fn transaction_ledger(
+        &self,
+        request: tonic::Request<pb::TransactionLedgerRequest>,
+    ) -> Result<tonic::Response<Self::TransactionLedgerStream>, tonic::Status> {
+        self.check_worker().await?;
+        self.check_wallet_id(request.get_ref().wallet_id.as_ref())
+            .await?;
+
+        let request = request.into_inner();
+
+        // Unpack optional start/end heights.
+        let start_height = if request.start_height == 0 {
+            None
+        } else {
+            Some(request.start_height)
+        };
+        let end_height = if request.end_height == 0 {
+            None
+        } else {
+            Some(request.end_height)
+        };
+
+        let account_filter = request
+            .account_filter
+            .map(AddressIndex::try_from)
+            .transpose()
+            .map_err(|_| tonic::Status::invalid_argument("invalid account filter"))?
+            .map(|index| index.account);
+
+        let fvk =
+            self.storage.full_viewing_key().await.map_err(|_| {
+                tonic::Status::failed_precondition("Error retrieving full viewing key")
+            })?;
+
+        // Fetch transactions from storage.
+        let txs = self
+            .storage
+            .transactions(start_height, end_height)
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error fetching transactions: {e}")))?;
+
+        let self2 = self.clone();
+        let stream = try_stream! {
+            for (_height, _hash, tx) in txs {
+                let tx_info: TransactionInfo = self2
+                    .transaction_info_by_hash(tonic::Request::new(pb::TransactionInfoByHashRequest {
+                        id: Some(tx.id().into()),
+                    }))
+                    .await?
+                    .into_inner()
+                    .tx_info
+                    .ok_or_else(|| anyhow::anyhow!("missing transaction info"))?
+                    .try_into()?;
+
+                // Look up how the transaction's swaps were filled by their batches.
+                let mut swap_records = BTreeMap::new();
+                for action_view in tx_info.view.action_views() {
+                    use penumbra_dex::swap::SwapView;
+                    use penumbra_transaction::view::action_view::ActionView;
+                    if let ActionView::Swap(SwapView::Visible { swap, .. }) = action_view {
+                        let commitment = swap.body.payload.commitment;
+                        if let Ok(record) = self2.storage.swap_by_commitment(commitment, false).await {
+                            swap_records.insert(commitment, record);
+                        }
+                    }
+                }
+
+                for entry in LedgerEntry::from_transaction_info(&tx_info, &fvk, &swap_records) {
+                    if account_filter.map_or(true, |account| account == entry.account) {
+                        yield pb::TransactionLedgerResponse {
+                            entry: Some(entry.into()),
+                        }
+                    }
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting transaction ledger: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+
     async fn witness(
         &self,
         request: tonic::Request<pb::WitnessRequest>,
@@ -1522,6 +1772,72 @@ impl ViewProtocolService for ViewService {
         ))
     }
 
+    async fn position_rewards(
+        &self,
+        request: tonic::Request<pb::PositionRewardsRequest>,
+    ) -> Result<tonic::Response<Self::PositionRewardsStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        let trading_pair: Option<TradingPair> = request
+            .into_inner()
+            .trading_pair
+            .map(|pair| pair.try_into())
+            .transpose()
+            .map_err(|e: anyhow::Error| e.context("could not decode trading pair"))
+            .map_err(|e| tonic::Status::invalid_argument(format!("{:#}", e)))?;
+
+        // Only withdrawn positions can claim their rewards.
+        let ids = self
+            .storage
+            .owned_position_ids(Some(position::State::Withdrawn), trading_pair)
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting position ids: {e}")))?;
+
+        let chain_id = self
+            .storage
+            .app_params()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting app params: {e}")))?
+            .chain_params
+            .chain_id;
+
+        let mut client = DexQueryServiceClient::connect(self.node.to_string())
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error connecting to node: {e}")))?;
+
+        let stream = try_stream! {
+            for id in ids {
+                let rewards: Value = client
+                    .liquidity_position_rewards(LiquidityPositionRewardsRequest {
+                        chain_id: chain_id.clone(),
+                        position_id: Some(id.into()),
+                    })
+                    .await?
+                    .into_inner()
+                    .rewards
+                    .ok_or_else(|| anyhow!("missing rewards in response"))?
+                    .try_into()?;
+
+                if rewards.amount == Amount::zero() {
+                    continue;
+                }
+
+                yield pb::PositionRewardsResponse {
+                    position_id: Some(id.into()),
+                    rewards: Some(rewards.into()),
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting position rewards: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+
     async fn authorize_and_build(
         &self,
         _request: tonic::Request<pb::AuthorizeAndBuildRequest>,
@@ -9,7 +9,7 @@ use penumbra_chain::params::{ChainParameters, FmdParameters};
 use penumbra_dao::params::DaoParameters;
 use penumbra_dex::{
     lp::position::{self, Position, State},
-    TradingPair,
+    DexParameters, TradingPair,
 };
 use penumbra_distributions::params::DistributionsParameters;
 use penumbra_fee::{FeeParameters, GasPrices};
@@ -27,7 +27,7 @@ use penumbra_sct::Nullifier;
 use penumbra_shielded_pool::{note, Note, Rseed};
 use penumbra_stake::{params::StakeParameters, DelegationToken, IdentityKey};
 use penumbra_tct as tct;
-use penumbra_transaction::Transaction;
+use penumbra_transaction::{PaymentRequest, Transaction};
 use r2d2_sqlite::{
     rusqlite::{OpenFlags, OptionalExtension},
     SqliteConnectionManager,
@@ -235,6 +235,12 @@ impl Storage {
                 [distributions_params_bytes],
             )?;
 
+            let dex_params_bytes = &DexParameters::encode_to_vec(&params.dex_params)[..];
+            tx.execute(
+                "INSERT INTO dex_params (bytes) VALUES (?1)",
+                [dex_params_bytes],
+            )?;
+
             let governance_params_bytes =
                 &GovernanceParameters::encode_to_vec(&params.governance_params)[..];
             tx.execute(
@@ -603,6 +609,11 @@ impl Storage {
                 .prepare_cached("SELECT bytes FROM distributions_params LIMIT 1")?
                 .query_row([], |row| row.get::<_, Option<Vec<u8>>>("bytes"))?
                 .ok_or_else(|| anyhow!("missing distributions params"))?;
+            let dex_bytes = pool
+                .get()?
+                .prepare_cached("SELECT bytes FROM dex_params LIMIT 1")?
+                .query_row([], |row| row.get::<_, Option<Vec<u8>>>("bytes"))?
+                .ok_or_else(|| anyhow!("missing dex params"))?;
 
             Ok(AppParameters {
                 chain_params: ChainParameters::decode(chain_bytes.as_slice())?,
@@ -614,6 +625,7 @@ impl Storage {
                 distributions_params: DistributionsParameters::decode(
                     distributions_bytes.as_slice(),
                 )?,
+                dex_params: DexParameters::decode(dex_bytes.as_slice())?,
             })
         })
         .await?
@@ -1397,6 +1409,12 @@ impl Storage {
                     [distributions_params_bytes],
                 )?;
 
+                let dex_params_bytes = &DexParameters::encode_to_vec(&params.dex_params)[..];
+                dbtx.execute(
+                    "UPDATE dex_params SET bytes = ?1",
+                    [dex_params_bytes],
+                )?;
+
                 let governance_params_bytes =
                     &GovernanceParameters::encode_to_vec(&params.governance_params)[..];
                 dbtx.execute(
@@ -1560,6 +1578,8 @@ impl Storage {
                 let fmd_parameters_bytes =
                     &FmdParameters::encode_to_vec(&filtered_block.fmd_parameters.ok_or_else(|| anyhow::anyhow!("missing fmd parameters in filtered block"))?)[..];
 
+                // Replace the previous parameters, so that planning uses the ones valid as of the sync height.
+                dbtx.execute("DELETE FROM fmd_parameters", ())?;
                 dbtx.execute("INSERT INTO fmd_parameters (bytes) VALUES (?1)", [&fmd_parameters_bytes])?;
             }
 
@@ -1568,6 +1588,8 @@ impl Storage {
                 let gas_prices_bytes =
                     &GasPrices::encode_to_vec(&filtered_block.gas_prices.ok_or_else(|| anyhow::anyhow!("missing gas prices in filtered block"))?)[..];
 
+                // Replace the previous prices, so that the latest ones are the ones read back.
+                dbtx.execute("DELETE FROM gas_prices", ())?;
                 dbtx.execute("INSERT INTO gas_prices (bytes) VALUES (?1)", [&gas_prices_bytes])?;
             }
 
@@ -1695,4 +1717,59 @@ impl Storage {
 
         Ok(records)
     }
+
+    /// Returns the notes received in payment of the given [`PaymentRequest`], that is, the notes
+    /// sent to its address by transactions whose memo refers to it.
+    pub async fn notes_for_payment_request(
+        &self,
+        payment_request: &PaymentRequest,
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
+        let pool = self.pool.clone();
+        let fvk = self.full_viewing_key().await?;
+
+        let query = "SELECT notes.note_commitment,
+            spendable_notes.height_created,
+            notes.address,
+            notes.amount,
+            notes.asset_id,
+            notes.rseed,
+            spendable_notes.address_index,
+            spendable_notes.source,
+            spendable_notes.height_spent,
+            spendable_notes.nullifier,
+            spendable_notes.position,
+            tx.tx_bytes
+            FROM notes
+            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            JOIN tx ON spendable_notes.source = tx.tx_hash
+            WHERE notes.address = ?1";
+
+        let address = payment_request.address.to_vec();
+
+        let records = spawn_blocking(move || {
+            pool.get()?
+                .prepare(query)?
+                .query_and_then([address], |row| {
+                    let record: SpendableNoteRecord = row.try_into()?;
+                    let tx_bytes: Vec<u8> = row.get("tx_bytes")?;
+                    anyhow::Ok((record, tx_bytes))
+                })?
+                .collect::<anyhow::Result<Vec<_>>>()
+        })
+        .await??;
+
+        let mut notes = Vec::new();
+        for (record, tx_bytes) in records {
+            let tx = Transaction::decode(tx_bytes.as_slice())?;
+            // Transactions without a memo can't be payments of a request.
+            let Ok(memo) = tx.decrypt_memo(&fvk) else {
+                continue;
+            };
+            if payment_request.is_paid_by(&memo) {
+                notes.push(record);
+            }
+        }
+
+        Ok(notes)
+    }
 }
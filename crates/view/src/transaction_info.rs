@@ -1,3 +1,4 @@
+use penumbra_proto::view::v1alpha1 as pb;
 use penumbra_transaction::{Id, Transaction, TransactionPerspective, TransactionView};
 
 #[derive(Debug, Clone)]
@@ -13,3 +14,29 @@ pub struct TransactionInfo {
     // A precomputed transaction view of `transaction` from `perspective`, included for convenience of clients that don't have support for viewing transactions on their own.
     pub view: TransactionView,
 }
+
+impl TryFrom<pb::TransactionInfo> for TransactionInfo {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::TransactionInfo) -> Result<Self, Self::Error> {
+        Ok(Self {
+            height: value.height,
+            id: value
+                .id
+                .ok_or_else(|| anyhow::anyhow!("missing id"))?
+                .try_into()?,
+            transaction: value
+                .transaction
+                .ok_or_else(|| anyhow::anyhow!("missing transaction"))?
+                .try_into()?,
+            perspective: value
+                .perspective
+                .ok_or_else(|| anyhow::anyhow!("missing perspective"))?
+                .try_into()?,
+            view: value
+                .view
+                .ok_or_else(|| anyhow::anyhow!("missing view"))?
+                .try_into()?,
+        })
+    }
+}
@@ -7,11 +7,14 @@ use std::{
 use anyhow::Context;
 use penumbra_compact_block::CompactBlock;
 use penumbra_dex::lp::{position, LpNft};
-use penumbra_keys::FullViewingKey;
+use penumbra_keys::{keys::AddressIndex, FullViewingKey};
 use penumbra_proto::{
     self as proto,
     core::{
-        app::v1alpha1::query_service_client::QueryServiceClient as AppQueryServiceClient,
+        app::v1alpha1::{
+            query_service_client::QueryServiceClient as AppQueryServiceClient,
+            DetectedBlockRangeRequest,
+        },
         component::{
             compact_block::v1alpha1::{
                 query_service_client::QueryServiceClient as CompactBlockQueryServiceClient,
@@ -27,6 +30,7 @@ use penumbra_proto::{
 use penumbra_sct::Nullifier;
 use penumbra_transaction::Transaction;
 use proto::core::app::v1alpha1::TransactionsByHeightRequest;
+use serde::{Deserialize, Serialize};
 use tokio::sync::{watch, RwLock};
 use tonic::transport::Channel;
 use url::Url;
@@ -36,6 +40,24 @@ use crate::{
     Storage,
 };
 
+/// How the view worker finds the payloads relevant to it in each block.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncMode {
+    /// Stream every compact block, and trial-decrypt every payload.
+    #[default]
+    TrialDecryption,
+    /// Hand the detection keys of the given accounts to the node's detection
+    /// service, and only trial-decrypt the payloads of the transactions it detects.
+    ///
+    /// Only notes sent to the default addresses of these accounts are detected:
+    /// notes sent to other addresses, such as ephemeral addresses, are missed.
+    /// Since the addresses a wallet will receive notes at can't be known in advance,
+    /// the worker only finds out about other addresses from notes it has already
+    /// recorded, and falls back to trial decryption once there are any.
+    Detection { accounts: Vec<u32> },
+}
+
 pub struct Worker {
     storage: Storage,
     sct: Arc<RwLock<penumbra_tct::Tree>>,
@@ -45,6 +67,7 @@ pub struct Worker {
     /// Tonic channel used to create GRPC clients.
     channel: Channel,
     node: Url,
+    sync_mode: SyncMode,
 }
 
 impl Worker {
@@ -57,6 +80,7 @@ impl Worker {
     pub async fn new(
         storage: Storage,
         node: Url,
+        sync_mode: SyncMode,
     ) -> Result<
         (
             Self,
@@ -93,6 +117,7 @@ impl Worker {
                 sync_height_tx,
                 channel,
                 node,
+                sync_mode,
             },
             sct,
             error_slot,
@@ -100,9 +125,16 @@ impl Worker {
         ))
     }
 
+    /// Fetches the transactions relevant to the block.
+    ///
+    /// If the transactions detected by the detection service are provided, they're used
+    /// instead of fetching the whole block, unless one of the relevant transactions is
+    /// missing from them: this happens when a transaction spends our notes without
+    /// creating any output for us.
     pub async fn fetch_transactions(
         &self,
         filtered_block: &FilteredBlock,
+        detected: Option<Vec<Transaction>>,
     ) -> anyhow::Result<Vec<Transaction>> {
         let inbound_transaction_ids = filtered_block.inbound_transaction_ids();
         let spent_nullifiers = filtered_block
@@ -117,6 +149,33 @@ impl Worker {
             return Ok(Vec::new());
         }
 
+        // Check if the transaction is a known inbound transaction or spends one of our nullifiers.
+        let is_relevant = |tx: &Transaction| {
+            inbound_transaction_ids.contains(&tx.id().0)
+                || tx
+                    .spent_nullifiers()
+                    .any(|nf| spent_nullifiers.contains(&nf))
+        };
+
+        if let Some(detected) = detected {
+            let transactions = detected.into_iter().filter(is_relevant).collect::<Vec<_>>();
+
+            let covered_ids = transactions
+                .iter()
+                .map(|tx| tx.id().0)
+                .collect::<BTreeSet<_>>();
+            let covered_nullifiers = transactions
+                .iter()
+                .flat_map(|tx| tx.spent_nullifiers().collect::<Vec<_>>())
+                .collect::<BTreeSet<_>>();
+            if covered_ids.is_superset(&inbound_transaction_ids)
+                && covered_nullifiers.is_superset(&spent_nullifiers)
+            {
+                tracing::debug!(matched = transactions.len(), "used detected transactions");
+                return Ok(transactions);
+            }
+        }
+
         tracing::debug!(
             height = filtered_block.height,
             "fetching full transaction data"
@@ -124,20 +183,8 @@ impl Worker {
 
         let transactions = fetch_transactions(self.channel.clone(), filtered_block.height)
             .await?
-            .iter()
-            .filter_map(|tx| {
-                let tx_id = tx.id().0;
-
-                // Check if the transaction is a known inbound transaction or spends one of our nullifiers.
-                if inbound_transaction_ids.contains(&tx_id)
-                    || tx
-                        .spent_nullifiers()
-                        .any(|nf| spent_nullifiers.contains(&nf))
-                {
-                    return Some(tx.clone());
-                }
-                None
-            })
+            .into_iter()
+            .filter(is_relevant)
             .collect::<Vec<_>>();
 
         tracing::debug!(
@@ -148,6 +195,25 @@ impl Worker {
         Ok(transactions)
     }
 
+    /// Returns the sync mode to use, which is the configured one unless detection
+    /// would miss notes at addresses that are known to be in use.
+    async fn effective_sync_mode(&self) -> anyhow::Result<SyncMode> {
+        if let SyncMode::Detection { accounts } = &self.sync_mode {
+            let notes = self.storage.notes(true, None, None, None).await?;
+            let undetected = notes.iter().find(|note| {
+                note.address_index.is_ephemeral() || !accounts.contains(&note.address_index.account)
+            });
+            if let Some(note) = undetected {
+                tracing::warn!(
+                    address_index = ?note.address_index,
+                    "notes were received at an address that detection doesn't cover, falling back to trial decryption"
+                );
+                return Ok(SyncMode::TrialDecryption);
+            }
+        }
+        Ok(self.sync_mode.clone())
+    }
+
     pub async fn sync(&mut self) -> anyhow::Result<()> {
         // Do a single sync run, up to whatever the latest block height is
         tracing::info!("starting client sync");
@@ -161,34 +227,83 @@ impl Worker {
             .map(|h| h + 1)
             .unwrap_or(0);
 
-        let mut client = CompactBlockQueryServiceClient::new(self.channel.clone());
-        let mut stream = client
-            .compact_block_range(tonic::Request::new(CompactBlockRangeRequest {
-                chain_id: chain_id.clone(),
-                start_height,
-                end_height: 0,
-                // Instruct the server to keep feeding us blocks as they're created.
-                keep_alive: true,
-            }))
-            .await?
-            .into_inner();
-
         // Spawn a task to consume items from the stream (somewhat)
         // independently of the execution of the block scanning.  This has two
         // purposes: first, it allows buffering to smooth performance; second,
         // it makes it slightly more difficult for a remote server to observe
         // the exact timings of the scanning of each CompactBlock.
         let (tx, mut buffered_stream) = tokio::sync::mpsc::channel(1000);
-        tokio::spawn(async move {
-            while let Some(block) = stream.message().await.transpose() {
-                if tx.send(block).await.is_err() {
-                    break;
-                }
+        match self.effective_sync_mode().await? {
+            SyncMode::TrialDecryption => {
+                let mut client = CompactBlockQueryServiceClient::new(self.channel.clone());
+                let mut stream = client
+                    .compact_block_range(tonic::Request::new(CompactBlockRangeRequest {
+                        chain_id: chain_id.clone(),
+                        start_height,
+                        end_height: 0,
+                        // Instruct the server to keep feeding us blocks as they're created.
+                        keep_alive: true,
+                    }))
+                    .await?
+                    .into_inner();
+
+                tokio::spawn(async move {
+                    while let Some(block) = stream.message().await.transpose() {
+                        let block = block
+                            .map_err(anyhow::Error::from)
+                            .and_then(|rsp| Ok((CompactBlock::try_from(rsp)?, None)));
+                        if tx.send(block).await.is_err() {
+                            break;
+                        }
+                    }
+                });
             }
-        });
+            SyncMode::Detection { accounts } => {
+                let detection_keys = accounts
+                    .iter()
+                    .map(|account| {
+                        let (_, dtk) = self.fvk.payment_address(AddressIndex::from(*account));
+                        dtk.to_bytes().to_vec()
+                    })
+                    .collect();
+
+                let mut client = AppQueryServiceClient::new(self.channel.clone());
+                let mut stream = client
+                    .detected_block_range(tonic::Request::new(DetectedBlockRangeRequest {
+                        chain_id: chain_id.clone(),
+                        start_height,
+                        end_height: 0,
+                        // Instruct the server to keep feeding us blocks as they're created.
+                        keep_alive: true,
+                        detection_keys,
+                    }))
+                    .await?
+                    .into_inner();
+
+                tokio::spawn(async move {
+                    while let Some(block) = stream.message().await.transpose() {
+                        let block = block.map_err(anyhow::Error::from).and_then(|rsp| {
+                            let block: CompactBlock = rsp
+                                .compact_block
+                                .context("missing compact block in response")?
+                                .try_into()?;
+                            let transactions = rsp
+                                .transactions
+                                .into_iter()
+                                .map(TryInto::try_into)
+                                .collect::<anyhow::Result<Vec<Transaction>>>()?;
+                            Ok((block, Some(transactions)))
+                        });
+                        if tx.send(block).await.is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        }
 
         while let Some(block) = buffered_stream.recv().await {
-            let block: CompactBlock = block?.try_into()?;
+            let (block, detected) = block?;
 
             let height = block.height;
 
@@ -215,7 +330,7 @@ impl Worker {
                     scan_block(&self.fvk, &mut sct_guard, block, &self.storage).await?;
 
                 // Download any transactions we detected.
-                let transactions = self.fetch_transactions(&filtered_block).await?;
+                let transactions = self.fetch_transactions(&filtered_block, detected).await?;
 
                 // LPNFT asset IDs won't be known to the chain, so we need to pre-populate them in the local
                 // registry based on transaction contents.
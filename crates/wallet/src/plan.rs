@@ -42,6 +42,26 @@ where
         .context("can't build validator definition plan")
 }
 
+pub async fn validator_unjail<V, R>(
+    wallet_id: WalletId,
+    view: &mut V,
+    rng: R,
+    unjail: validator::Unjail,
+    fee: Fee,
+    source_address: AddressIndex,
+) -> Result<TransactionPlan>
+where
+    V: ViewClient,
+    R: RngCore + CryptoRng,
+{
+    Planner::new(rng)
+        .fee(fee)
+        .validator_unjail(unjail)
+        .plan(view, wallet_id, source_address)
+        .await
+        .context("can't build validator unjail plan")
+}
+
 pub async fn validator_vote<V, R>(
     wallet_id: WalletId,
     view: &mut V,
@@ -280,15 +300,16 @@ where
     V: ViewClient,
     R: RngCore + CryptoRng,
 {
+    let deposit_amount = view
+        .app_params()
+        .await?
+        .governance_params
+        .for_kind(proposal.kind())
+        .deposit_amount;
+
     Planner::new(rng)
         .fee(fee)
-        .proposal_submit(
-            proposal,
-            view.app_params()
-                .await?
-                .governance_params
-                .proposal_deposit_amount,
-        )
+        .proposal_submit(proposal, deposit_amount)
         .plan(view, wallet_id, source_address)
         .await
         .context("can't build proposal submit transaction")
@@ -7,7 +7,7 @@ use std::{
 use anyhow::{anyhow, Result};
 use rand_core::{CryptoRng, RngCore};
 
-use penumbra_asset::{asset::DenomMetadata, Balance, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_asset::{asset::DenomMetadata, Balance, Value};
 use penumbra_chain::params::{ChainParameters, FmdParameters};
 use penumbra_dex::{
     lp::action::{PositionClose, PositionOpen},
@@ -31,7 +31,7 @@ use penumbra_proto::core::keys::v1alpha1::AddressIndex;
 use penumbra_proto::view::v1alpha1::{NotesForVotingRequest, NotesRequest};
 use penumbra_shielded_pool::{Ics20Withdrawal, Note, OutputPlan, SpendPlan};
 use penumbra_stake::{rate::RateData, validator};
-use penumbra_stake::{IdentityKey, UndelegateClaimPlan};
+use penumbra_stake::{IdentityKey, RedelegateClaim, UndelegateClaimPlan};
 use penumbra_tct as tct;
 use penumbra_transaction::gas::GasCost;
 use penumbra_transaction::{
@@ -153,6 +153,8 @@ impl<R: RngCore + CryptoRng> Planner<R> {
 
     /// Calculate gas cost-based fees and add to the transaction plan.
     ///
+    /// The fee is paid in the asset the gas prices are denominated in.
+    ///
     /// This function should be called once.
     pub fn add_gas_fees(&mut self) -> &mut Self {
         let minimum_fee = self.gas_prices.price(&self.plan.gas_cost());
@@ -161,7 +163,10 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         // transaction, which would then change the fee calculation, we multiply the
         // fee here by a factor of 2 and then recalculate and capture the excess as
         // change outputs.
-        let fee = Fee::from_staking_token_amount(minimum_fee * Amount::from(2u32));
+        let fee = Fee(Value {
+            amount: minimum_fee * Amount::from(2u32),
+            asset_id: self.gas_prices.asset_id,
+        });
         self.balance += fee.0;
         self.plan.fee = fee;
         self
@@ -286,6 +291,27 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Add a redelegation to this transaction, moving `delegation_amount` of
+    /// the source validator's delegation tokens to the destination validator.
+    pub fn redelegate(
+        &mut self,
+        delegation_amount: Amount,
+        from_rate_data: RateData,
+        to_rate_data: RateData,
+    ) -> &mut Self {
+        let redelegation = from_rate_data
+            .build_redelegate(&to_rate_data, delegation_amount)
+            .into();
+        self.action(redelegation);
+        self
+    }
+
+    /// Add a redelegate claim to this transaction.
+    pub fn redelegate_claim(&mut self, claim: RedelegateClaim) -> &mut Self {
+        self.action(ActionPlan::RedelegateClaim(claim));
+        self
+    }
+
     /// Add an undelegate claim to this transaction.
     pub fn undelegate_claim(&mut self, claim_plan: UndelegateClaimPlan) -> &mut Self {
         self.action(ActionPlan::UndelegateClaim(claim_plan));
@@ -298,6 +324,12 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Release a jailed validator from jail in this transaction.
+    pub fn validator_unjail(&mut self, unjail: validator::Unjail) -> &mut Self {
+        self.action(ActionPlan::ValidatorUnjail(unjail));
+        self
+    }
+
     /// Submit a new governance proposal in this transaction.
     pub fn proposal_submit(&mut self, proposal: Proposal, deposit_amount: Amount) -> &mut Self {
         self.action(ActionPlan::ProposalSubmit(ProposalSubmit {
@@ -507,9 +539,12 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         let excess_fee_spent = self.plan.fee.amount() - tx_real_fee;
         self.balance -= Value {
             amount: excess_fee_spent,
-            asset_id: *STAKING_TOKEN_ASSET_ID,
+            asset_id: self.gas_prices.asset_id,
         };
-        self.plan.fee = Fee::from_staking_token_amount(tx_real_fee);
+        self.plan.fee = Fee(Value {
+            amount: tx_real_fee,
+            asset_id: self.gas_prices.asset_id,
+        });
 
         // For any remaining provided balance, make a single change note for each
         for value in self.balance.provided().collect::<Vec<_>>() {
@@ -111,6 +111,7 @@ mod tests {
         let chain_params = ChainParameters {
             chain_id: "penumbra-testnet-iapetus".to_string(),
             epoch_duration: 5u64,
+            ..Default::default()
         };
 
         let fmd_params = FmdParameters {